@@ -0,0 +1,77 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_infallible::RwLock;
+use futures::future::AbortHandle;
+
+/// Cancels a query cooperatively.
+/// The running streams and processors check it between blocks, and the registered
+/// abort handles wake up the streams which are waiting for the data.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancellationInner>,
+}
+
+#[derive(Default)]
+struct CancellationInner {
+    reason: RwLock<Option<ErrorCode>>,
+    abort_handles: Mutex<Vec<AbortHandle>>,
+}
+
+impl CancellationToken {
+    pub fn create() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Cancel with the reason, only the first reason is kept if it's cancelled more than once.
+    pub fn cancel(&self, reason: ErrorCode) {
+        {
+            let mut cancelled_reason = self.inner.reason.write();
+            if cancelled_reason.is_none() {
+                *cancelled_reason = Some(reason);
+            }
+        }
+
+        let mut abort_handles = self.inner.abort_handles.lock();
+        while let Some(abort_handle) = abort_handles.pop() {
+            abort_handle.abort();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.reason.read().is_some()
+    }
+
+    /// Returns the cancelled reason as the error if it's cancelled.
+    pub fn check(&self) -> Result<()> {
+        match &*self.inner.reason.read() {
+            None => Ok(()),
+            Some(reason) => Err(reason.clone()),
+        }
+    }
+
+    /// Abort the handle when it's cancelled, or at once if it's already cancelled.
+    pub fn register_abort_handle(&self, abort_handle: AbortHandle) {
+        let mut abort_handles = self.inner.abort_handles.lock();
+        match self.is_cancelled() {
+            true => abort_handle.abort(),
+            false => abort_handles.push(abort_handle),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::AbortHandle;
+use futures::future::Abortable;
+
+use crate::CancellationToken;
+
+#[test]
+fn test_cancellation_token() -> Result<()> {
+    let token = CancellationToken::create();
+    assert!(!token.is_cancelled());
+    assert!(token.check().is_ok());
+
+    let (handle, registration) = AbortHandle::new_pair();
+    let registered = Abortable::new(futures::future::pending::<()>(), registration);
+    token.register_abort_handle(handle);
+
+    // The clones share the state, and the first reason wins.
+    let cloned = token.clone();
+    cloned.cancel(ErrorCode::Timeout("timeout"));
+    token.cancel(ErrorCode::AbortedQuery("killed"));
+
+    assert!(token.is_cancelled());
+    assert!(registered.is_aborted());
+    match token.check() {
+        Ok(_) => panic!("the token must be cancelled"),
+        Err(cause) => {
+            assert_eq!(cause.code(), ErrorCode::Timeout("").code());
+            assert_eq!(cause.message(), "timeout");
+        }
+    }
+
+    // Registered after cancelled, it is aborted at once.
+    let (handle, registration) = AbortHandle::new_pair();
+    let late = Abortable::new(futures::future::pending::<()>(), registration);
+    token.register_abort_handle(handle);
+    assert!(late.is_aborted());
+
+    Ok(())
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod cancellation_test;
+
 #[cfg(test)]
 mod runtime_test;
 
@@ -21,10 +24,12 @@ mod progress_test;
 #[cfg(test)]
 mod stoppable_test;
 
+mod cancellation;
 mod profiling;
 mod progress;
 mod runtime;
 
+pub use cancellation::CancellationToken;
 pub use profiling::Profiling;
 pub use progress::Progress;
 pub use progress::ProgressCallback;
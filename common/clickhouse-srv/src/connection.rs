@@ -18,10 +18,11 @@ use std::sync::Arc;
 use bytes::Buf;
 use bytes::BytesMut;
 use chrono_tz::Tz;
+use tokio::io::AsyncRead;
 use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
 use tokio::io::AsyncWriteExt;
 use tokio::io::BufWriter;
-use tokio::net::TcpStream;
 
 use crate::binary::Encoder;
 use crate::binary::Parser;
@@ -35,11 +36,16 @@ use crate::types::Progress;
 use crate::CHContext;
 use crate::ClickHouseSession;
 
+/// The transport of a `Connection`, a plain `TcpStream` or a TLS stream on top of it.
+pub trait ConnectionStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> ConnectionStream for T {}
+
 /// Send and receive `Packet` values from a remote peer.
 ///
 /// When implementing networking protocols, a message on that protocol is
 /// often composed of several smaller messages known as frames. The purpose of
-/// `Connection` is to read and write frames on the underlying `TcpStream`.
+/// `Connection` is to read and write frames on the underlying stream.
 ///
 /// To read frames, the `Connection` uses an internal buffer, which is filled
 /// up until there are enough bytes to create a full frame. Once this happens,
@@ -48,12 +54,12 @@ use crate::ClickHouseSession;
 /// When sending frames, the frame is first encoded into the write buffer.
 /// The contents of the write buffer are then written to the socket.
 pub struct Connection {
-    // The `TcpStream`. It is decorated with a `BufWriter`, which provides write
+    // The `ConnectionStream`. It is decorated with a `BufWriter`, which provides write
     // level buffering. The `BufWriter` implementation provided by Tokio is
     // sufficient for our needs.
     pub buffer: BytesMut,
 
-    stream: BufWriter<TcpStream>,
+    stream: BufWriter<Box<dyn ConnectionStream>>,
     pub session: Arc<dyn ClickHouseSession>,
 
     // The buffer for reading frames.
@@ -66,7 +72,7 @@ impl Connection {
     /// Create a new `Connection`, backed by `socket`. Read and write buffers
     /// are initialized.
     pub fn new(
-        stream: TcpStream,
+        stream: Box<dyn ConnectionStream>,
         session: Arc<dyn ClickHouseSession>,
         timezone: String,
     ) -> Result<Connection> {
@@ -89,7 +95,7 @@ impl Connection {
     ///
     /// # Returns
     ///
-    /// On success, the received frame is returned. If the `TcpStream`
+    /// On success, the received frame is returned. If the stream
     /// is closed in a way that doesn't break a frame in half, it returns
     /// `None`. Otherwise, an error is returned.
     pub async fn read_packet(&mut self, ctx: &mut CHContext) -> crate::Result<Option<Packet>> {
@@ -17,12 +17,12 @@ use std::sync::Arc;
 use errors::Result;
 use log::debug;
 use protocols::Stage;
-use tokio::net::TcpStream;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::Notify;
 
 use crate::cmd::Cmd;
 use crate::connection::Connection;
+use crate::connection::ConnectionStream;
 use crate::protocols::HelloRequest;
 use crate::types::Block;
 use crate::types::Progress;
@@ -129,22 +129,29 @@ impl CHContext {
 pub struct ClickHouseServer {}
 
 impl ClickHouseServer {
-    pub async fn run_on_stream(
+    pub async fn run_on_stream<S: ConnectionStream + 'static>(
         session: Arc<dyn ClickHouseSession>,
-        stream: TcpStream,
+        stream: S,
     ) -> Result<()> {
-        ClickHouseServer::run_on(session, stream).await
+        ClickHouseServer::run_on(session, Box::new(stream)).await
     }
 }
 
 impl ClickHouseServer {
-    async fn run_on(session: Arc<dyn ClickHouseSession>, stream: TcpStream) -> Result<()> {
+    async fn run_on(
+        session: Arc<dyn ClickHouseSession>,
+        stream: Box<dyn ConnectionStream>,
+    ) -> Result<()> {
         let mut srv = ClickHouseServer {};
         srv.run(session, stream).await?;
         Ok(())
     }
 
-    async fn run(&mut self, session: Arc<dyn ClickHouseSession>, stream: TcpStream) -> Result<()> {
+    async fn run(
+        &mut self,
+        session: Arc<dyn ClickHouseSession>,
+        stream: Box<dyn ConnectionStream>,
+    ) -> Result<()> {
         debug!("Handle New session");
         let tz = session.timezone().to_string();
         let mut ctx = CHContext::new(QueryState::default());
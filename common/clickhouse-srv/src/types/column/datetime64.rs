@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use chrono::prelude::*;
 use chrono_tz::Tz;
 
@@ -21,6 +23,9 @@ use crate::errors::Result;
 use crate::types::column::column_data::BoxColumnData;
 use crate::types::column::column_data::ColumnData;
 use crate::types::column::list::List;
+use crate::types::column::nullable::NullableColumnData;
+use crate::types::column::ColumnFrom;
+use crate::types::column::ColumnWrapper;
 use crate::types::DateTimeType;
 use crate::types::SqlType;
 use crate::types::Value;
@@ -57,6 +62,45 @@ impl DateTime64ColumnData {
     }
 }
 
+/// DateTime64 ticks of the same precision and timezone, unlike `Vec<DateTime<Tz>>`
+/// the column is not truncated to seconds.
+pub struct DateTime64Column<T> {
+    pub precision: u32,
+    pub tz: Tz,
+    pub values: Vec<T>,
+}
+
+impl ColumnFrom for DateTime64Column<i64> {
+    fn column_from<W: ColumnWrapper>(source: Self) -> W::Wrapper {
+        let mut data = List::<i64>::with_capacity(source.values.len());
+        source.values.into_iter().for_each(|v| data.push(v));
+        W::wrap(DateTime64ColumnData {
+            data,
+            params: (source.precision, source.tz),
+        })
+    }
+}
+
+impl ColumnFrom for DateTime64Column<Option<i64>> {
+    fn column_from<W: ColumnWrapper>(source: Self) -> W::Wrapper {
+        let nulls: Vec<u8> = source.values.iter().map(|v| v.is_none() as u8).collect();
+        let mut data = List::<i64>::with_capacity(source.values.len());
+        source
+            .values
+            .into_iter()
+            .for_each(|v| data.push(v.unwrap_or(0)));
+        let inner = DateTime64ColumnData {
+            data,
+            params: (source.precision, source.tz),
+        };
+
+        W::wrap(NullableColumnData {
+            nulls,
+            inner: Arc::new(inner),
+        })
+    }
+}
+
 impl ColumnData for DateTime64ColumnData {
     fn sql_type(&self) -> SqlType {
         let (precision, tz) = self.params;
@@ -128,6 +172,7 @@ pub(crate) fn to_datetime(value: i64, precision: u32, tz: Tz) -> DateTime<Tz> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::types::Block;
 
     #[test]
     fn test_to_datetime() {
@@ -142,4 +187,36 @@ mod test {
         let actual = from_datetime(origin, 3);
         assert_eq!(actual, 1_546_300_800_000)
     }
+
+    #[test]
+    fn test_datetime64_column() {
+        let block = Block::new()
+            .column("a", DateTime64Column {
+                precision: 6,
+                tz: Tz::Asia__Shanghai,
+                values: vec![1_546_300_800_123_456_i64],
+            })
+            .column("b", DateTime64Column {
+                precision: 3,
+                tz: Tz::UTC,
+                values: vec![Some(1_546_300_800_000_i64), None],
+            });
+
+        assert_eq!(
+            block.columns()[0].sql_type(),
+            SqlType::DateTime(DateTimeType::DateTime64(6, Tz::Asia__Shanghai))
+        );
+        assert_eq!(
+            block.columns()[1].sql_type(),
+            SqlType::Nullable(&SqlType::DateTime(DateTimeType::DateTime64(3, Tz::UTC)))
+        );
+
+        let values: Vec<DateTime<Tz>> =
+            block.columns()[0].iter::<DateTime<Tz>>().unwrap().collect();
+        assert_eq!(values, vec![to_datetime(
+            1_546_300_800_123_456,
+            6,
+            Tz::Asia__Shanghai
+        )]);
+    }
 }
@@ -155,6 +155,67 @@ impl ColumnFrom for Vec<Option<Decimal>> {
     }
 }
 
+/// Decimal values of the same precision and scale, unlike `Vec<Decimal>` the
+/// column keeps its type even if it has no value.
+pub struct DecimalColumn<T> {
+    pub precision: u8,
+    pub scale: u8,
+    pub values: Vec<T>,
+}
+
+fn decimal_column_data(
+    precision: u8,
+    scale: u8,
+    values: impl ExactSizeIterator<Item = i64>,
+) -> DecimalColumnData {
+    let nobits = NoBits::from_precision(precision).unwrap_or(NoBits::N64);
+    let inner: Box<dyn ColumnData + Send + Sync> = match nobits {
+        NoBits::N32 => {
+            let mut data = List::<i32>::with_capacity(values.len());
+            values.for_each(|v| data.push(v as i32));
+            Box::new(VectorColumnData { data })
+        }
+        NoBits::N64 => {
+            let mut data = List::<i64>::with_capacity(values.len());
+            values.for_each(|v| data.push(v));
+            Box::new(VectorColumnData { data })
+        }
+    };
+
+    DecimalColumnData {
+        inner,
+        precision,
+        scale,
+        nobits,
+    }
+}
+
+impl ColumnFrom for DecimalColumn<i64> {
+    fn column_from<W: ColumnWrapper>(source: Self) -> W::Wrapper {
+        W::wrap(decimal_column_data(
+            source.precision,
+            source.scale,
+            source.values.into_iter(),
+        ))
+    }
+}
+
+impl ColumnFrom for DecimalColumn<Option<i64>> {
+    fn column_from<W: ColumnWrapper>(source: Self) -> W::Wrapper {
+        let nulls: Vec<u8> = source.values.iter().map(|v| v.is_none() as u8).collect();
+        let inner = decimal_column_data(
+            source.precision,
+            source.scale,
+            source.values.into_iter().map(|v| v.unwrap_or(0)),
+        );
+
+        W::wrap(NullableColumnData {
+            nulls,
+            inner: Arc::new(inner),
+        })
+    }
+}
+
 impl ColumnData for DecimalColumnData {
     fn sql_type(&self) -> SqlType {
         SqlType::Decimal(self.precision, self.scale)
@@ -323,3 +384,40 @@ impl<K: ColumnType> ColumnData for NullableDecimalAdapter<K> {
         unimplemented!()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::Block;
+
+    #[test]
+    fn test_decimal_column() {
+        let block = Block::new()
+            .column("a", DecimalColumn {
+                precision: 9,
+                scale: 2,
+                values: vec![123_i64, -5],
+            })
+            .column("b", DecimalColumn {
+                precision: 18,
+                scale: 4,
+                values: vec![Some(10_000_i64), None],
+            });
+
+        assert_eq!(block.columns()[0].sql_type(), SqlType::Decimal(9, 2));
+        assert_eq!(
+            block.columns()[1].sql_type(),
+            SqlType::Nullable(&SqlType::Decimal(18, 4))
+        );
+
+        let values: Vec<Decimal> = block.columns()[0].iter::<Decimal>().unwrap().collect();
+        assert_eq!(values, vec![Decimal::new(123, 2), Decimal::new(-5, 2)]);
+
+        let empty = Block::new().column("c", DecimalColumn::<i64> {
+            precision: 10,
+            scale: 3,
+            values: vec![],
+        });
+        assert_eq!(empty.columns()[0].sql_type(), SqlType::Decimal(10, 3));
+    }
+}
@@ -24,6 +24,8 @@ use chrono_tz::Tz;
 use self::chunk::ChunkColumnData;
 pub(crate) use self::column_data::ColumnData;
 pub use self::concat::ConcatColumnData;
+pub use self::datetime64::DateTime64Column;
+pub use self::decimal::DecimalColumn;
 pub use self::numeric::VectorColumnData;
 pub(crate) use self::string_pool::StringPool;
 use crate::binary::Encoder;
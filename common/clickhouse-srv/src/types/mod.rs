@@ -33,6 +33,8 @@ pub use self::block::Rows;
 pub use self::column::Column;
 pub use self::column::ColumnType;
 pub use self::column::Complex;
+pub use self::column::DateTime64Column;
+pub use self::column::DecimalColumn;
 pub use self::column::Simple;
 pub(crate) use self::date_converter::DateConverter;
 pub use self::decimal::Decimal;
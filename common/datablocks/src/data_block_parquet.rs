@@ -0,0 +1,284 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::DictionaryArray;
+use common_arrow::arrow::array::LargeBinaryArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::buffer::MutableBuffer;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::write::write_file;
+use common_arrow::arrow::io::parquet::write::Compression;
+use common_arrow::arrow::io::parquet::write::Encoding;
+use common_arrow::arrow::io::parquet::write::RowGroupIterator;
+use common_arrow::arrow::io::parquet::write::Version;
+use common_arrow::arrow::io::parquet::write::WriteOptions;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DataBlock;
+
+pub const OPT_KEY_COMPRESSION: &str = "compression";
+pub const OPT_KEY_DICTIONARY_ENCODING: &str = "dictionary_encoding";
+pub const OPT_KEY_ROW_GROUP_SIZE: &str = "row_group_size";
+
+const DEFAULT_ROW_GROUP_SIZE: usize = 1024 * 1024;
+
+/// Compression codecs of the parquet files written by us.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParquetCompression {
+    Uncompressed,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl FromStr for ParquetCompression {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "none" | "uncompressed" => Ok(ParquetCompression::Uncompressed),
+            "snappy" => Ok(ParquetCompression::Snappy),
+            "lz4" => Ok(ParquetCompression::Lz4),
+            "zstd" => Ok(ParquetCompression::Zstd),
+            other => Err(ErrorCode::BadOption(format!(
+                "Unsupported compression: {}, expects one of 'none', 'snappy', 'lz4', 'zstd'",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ParquetCompression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParquetCompression::Uncompressed => write!(f, "none"),
+            ParquetCompression::Snappy => write!(f, "snappy"),
+            ParquetCompression::Lz4 => write!(f, "lz4"),
+            ParquetCompression::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(v: ParquetCompression) -> Self {
+        match v {
+            ParquetCompression::Uncompressed => Compression::Uncompressed,
+            ParquetCompression::Snappy => Compression::Snappy,
+            ParquetCompression::Lz4 => Compression::Lz4,
+            ParquetCompression::Zstd => Compression::Zstd,
+        }
+    }
+}
+
+/// How a block is laid out in parquet, derived from the table options:
+///
+/// - `COMPRESSION`: 'none' | 'snappy' | 'lz4' | 'zstd', defaults to 'none'
+/// - `DICTIONARY_ENCODING`: 'true' | 'false', dictionary encodes the string columns, defaults to 'false'
+/// - `ROW_GROUP_SIZE`: max number of rows of a row group, defaults to 1M
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParquetWriteOptions {
+    pub compression: ParquetCompression,
+    pub dictionary_encoding: bool,
+    pub row_group_size: usize,
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        ParquetWriteOptions {
+            compression: ParquetCompression::Uncompressed,
+            dictionary_encoding: false,
+            row_group_size: DEFAULT_ROW_GROUP_SIZE,
+        }
+    }
+}
+
+impl ParquetWriteOptions {
+    /// Keys of `options` are expected to be lower case, unrelated keys are ignored.
+    pub fn from_table_options(options: &HashMap<String, String>) -> Result<Self> {
+        let mut res = ParquetWriteOptions::default();
+
+        if let Some(v) = options.get(OPT_KEY_COMPRESSION) {
+            res.compression = v.parse()?;
+        }
+
+        if let Some(v) = options.get(OPT_KEY_DICTIONARY_ENCODING) {
+            res.dictionary_encoding = match v.to_lowercase().as_str() {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                other => {
+                    return Err(ErrorCode::BadOption(format!(
+                        "Invalid value of dictionary_encoding: {}, expects 'true' or 'false'",
+                        other
+                    )))
+                }
+            };
+        }
+
+        if let Some(v) = options.get(OPT_KEY_ROW_GROUP_SIZE) {
+            res.row_group_size = match v.parse::<usize>() {
+                Ok(size) if size > 0 => size,
+                _ => {
+                    return Err(ErrorCode::BadOption(format!(
+                        "Invalid value of row_group_size: {}, expects a positive integer",
+                        v
+                    )))
+                }
+            };
+        }
+
+        Ok(res)
+    }
+
+    pub fn write_options(&self) -> WriteOptions {
+        WriteOptions {
+            write_statistics: true,
+            compression: self.compression.into(),
+            version: Version::V2,
+        }
+    }
+
+    pub fn row_group_count(&self, num_rows: usize) -> usize {
+        (num_rows + self.row_group_size - 1) / self.row_group_size
+    }
+
+    fn use_dictionary(&self, data_type: &ArrowDataType) -> bool {
+        self.dictionary_encoding && data_type == &ArrowDataType::LargeBinary
+    }
+}
+
+impl DataBlock {
+    /// Writes the block as a parquet file, returns the size of the file.
+    ///
+    /// The block is split into row groups of at most `row_group_size` rows.
+    pub fn write_parquet<W: Write>(
+        block: DataBlock,
+        writer: &mut W,
+        options: &ParquetWriteOptions,
+    ) -> Result<u64> {
+        let arrow_schema = block.schema().to_arrow();
+        let write_options = options.write_options();
+
+        // The dictionary encoder of arrow2 only accepts dictionary arrays, the string columns
+        // are re-encoded before writing, the file schema keeps the original types.
+        let encoded_fields = arrow_schema
+            .fields()
+            .iter()
+            .map(|f| {
+                if options.use_dictionary(f.data_type()) {
+                    let data_type = ArrowDataType::Dictionary(
+                        Box::new(ArrowDataType::Int32),
+                        Box::new(f.data_type().clone()),
+                    );
+                    ArrowField::new(f.name(), data_type, f.is_nullable())
+                } else {
+                    f.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        let encoded_schema = Arc::new(ArrowSchema::new(encoded_fields));
+        let encodings = encoded_schema
+            .fields()
+            .iter()
+            .map(|f| match f.data_type() {
+                ArrowDataType::Dictionary(_, _) => Encoding::RleDictionary,
+                _ => Encoding::Plain,
+            })
+            .collect::<Vec<_>>();
+
+        let batches = DataBlock::split_block_by_size(&block, options.row_group_size)?
+            .into_iter()
+            .map(|b| {
+                let batch = RecordBatch::try_from(b)?;
+                let columns = batch
+                    .columns()
+                    .iter()
+                    .map(|c| {
+                        if options.use_dictionary(c.data_type()) {
+                            dictionary_encode(c.as_ref())
+                        } else {
+                            Ok(c.clone())
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(RecordBatch::try_new(encoded_schema.clone(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let row_groups = RowGroupIterator::try_new(
+            batches.into_iter().map(Ok),
+            encoded_schema.as_ref(),
+            write_options,
+            encodings,
+        )?;
+        let parquet_schema = row_groups.parquet_schema().clone();
+        let len = write_file(
+            writer,
+            row_groups,
+            &arrow_schema,
+            parquet_schema,
+            write_options,
+            None,
+        )?;
+        Ok(len)
+    }
+}
+
+fn dictionary_encode(array: &dyn Array) -> Result<ArrayRef> {
+    let array = array
+        .as_any()
+        .downcast_ref::<LargeBinaryArray>()
+        .ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "Cannot dictionary encode array of type {:?}",
+                array.data_type()
+            ))
+        })?;
+
+    let mut dict: HashMap<&[u8], i32> = HashMap::new();
+    let mut values: Vec<&[u8]> = vec![];
+    let mut keys = MutableBuffer::<i32>::with_capacity(array.len());
+    for idx in 0..array.len() {
+        // the keys of null slots are masked by the validity, any valid key is fine
+        let value = array.value(idx);
+        let key = *dict.entry(value).or_insert_with(|| {
+            values.push(value);
+            (values.len() - 1) as i32
+        });
+        keys.push(key);
+    }
+
+    let keys = PrimitiveArray::<i32>::from_data(
+        ArrowDataType::Int32,
+        keys.into(),
+        array.validity().clone(),
+    );
+    let values = LargeBinaryArray::from_iter_values(values.iter());
+    Ok(Arc::new(DictionaryArray::<i32>::from_data(
+        keys,
+        Arc::new(values),
+    )))
+}
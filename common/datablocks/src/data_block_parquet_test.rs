@@ -0,0 +1,193 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::Cursor;
+
+use common_arrow::arrow::io::parquet::read;
+use common_arrow::arrow::io::parquet::write::Compression;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::*;
+
+#[test]
+fn test_parquet_write_options() -> Result<()> {
+    let default = ParquetWriteOptions::from_table_options(&HashMap::new())?;
+    assert_eq!(default, ParquetWriteOptions::default());
+    assert_eq!(default.compression, ParquetCompression::Uncompressed);
+    assert_eq!(
+        default.write_options().compression,
+        Compression::Uncompressed
+    );
+
+    let mut options = HashMap::new();
+    options.insert("compression".to_string(), "ZSTD".to_string());
+    options.insert("dictionary_encoding".to_string(), "true".to_string());
+    options.insert("row_group_size".to_string(), "2".to_string());
+    options.insert("location".to_string(), "/tmp".to_string());
+    let parsed = ParquetWriteOptions::from_table_options(&options)?;
+    assert_eq!(parsed, ParquetWriteOptions {
+        compression: ParquetCompression::Zstd,
+        dictionary_encoding: true,
+        row_group_size: 2,
+    });
+    assert_eq!(parsed.row_group_count(5), 3);
+    assert_eq!(parsed.row_group_count(4), 2);
+
+    let mut options = HashMap::new();
+    options.insert("compression".to_string(), "gzip".to_string());
+    let result = ParquetWriteOptions::from_table_options(&options);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Unsupported compression: gzip, expects one of 'none', 'snappy', 'lz4', 'zstd'"
+    );
+
+    let mut options = HashMap::new();
+    options.insert("row_group_size".to_string(), "0".to_string());
+    let result = ParquetWriteOptions::from_table_options(&options);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Invalid value of row_group_size: 0, expects a positive integer"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_write_parquet() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::String, true),
+    ]);
+
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1i64, 2, 3, 4, 5]),
+        Series::new(vec![Some("x"), Some("y"), None, Some("x"), Some("x")]),
+    ]);
+
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | x |",
+        "| 2 | y |",
+        "| 3 |   |",
+        "| 4 | x |",
+        "| 5 | x |",
+        "+---+---+",
+    ];
+
+    for compression in [
+        ParquetCompression::Uncompressed,
+        ParquetCompression::Snappy,
+        ParquetCompression::Lz4,
+        ParquetCompression::Zstd,
+    ] {
+        for dictionary_encoding in [false, true] {
+            let options = ParquetWriteOptions {
+                compression,
+                dictionary_encoding,
+                row_group_size: 2,
+            };
+
+            let mut buffer = Cursor::new(vec![]);
+            let len = DataBlock::write_parquet(block.clone(), &mut buffer, &options)?;
+            assert_eq!(len as usize, buffer.get_ref().len());
+
+            let mut cursor = Cursor::new(buffer.into_inner());
+            let metadata = read::read_metadata(&mut cursor)
+                .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+            assert_eq!(metadata.row_groups.len(), 3);
+
+            let reader = read::RecordReader::try_new(cursor, None, None, None, None)?;
+            let blocks = reader
+                .map(|batch| DataBlock::try_from(batch?))
+                .collect::<Result<Vec<_>>>()?;
+            assert_blocks_eq(expected.clone(), &blocks);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_write_parquet_decimal() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "price",
+        DataType::Decimal(10, 2),
+        true,
+    )]);
+
+    let block = DataBlock::create_by_array(schema, vec![Series::new(vec![
+        Some(12345i64),
+        None,
+        Some(-5),
+    ])]);
+
+    let mut buffer = Cursor::new(vec![]);
+    DataBlock::write_parquet(block, &mut buffer, &ParquetWriteOptions::default())?;
+
+    let cursor = Cursor::new(buffer.into_inner());
+    let reader = read::RecordReader::try_new(cursor, None, None, None, None)?;
+    let blocks = reader
+        .map(|batch| DataBlock::try_from(batch?))
+        .collect::<Result<Vec<_>>>()?;
+
+    // The precision and scale are kept in the field metadata.
+    assert_eq!(
+        blocks[0].schema().field(0).data_type(),
+        &DataType::Decimal(10, 2)
+    );
+    assert_eq!(
+        blocks[0].column(0).to_array()?.i64()?.collect_values(),
+        vec![Some(12345), None, Some(-5)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_data_block_write_parquet_variant() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("v", DataType::Variant, true)]);
+
+    let block = DataBlock::create_by_array(schema, vec![Series::new(vec![
+        Some(r#"{"a":[1,2]}"#),
+        None,
+        Some("null"),
+    ])]);
+
+    let mut buffer = Cursor::new(vec![]);
+    DataBlock::write_parquet(block, &mut buffer, &ParquetWriteOptions::default())?;
+
+    let cursor = Cursor::new(buffer.into_inner());
+    let reader = read::RecordReader::try_new(cursor, None, None, None, None)?;
+    let blocks = reader
+        .map(|batch| DataBlock::try_from(batch?))
+        .collect::<Result<Vec<_>>>()?;
+
+    // The variant is stored as the JSON text and restored by the field metadata.
+    assert_eq!(blocks[0].schema().field(0).data_type(), &DataType::Variant);
+    assert_eq!(
+        blocks[0].column(0).to_array()?.string()?.collect_values(),
+        vec![
+            Some(br#"{"a":[1,2]}"#.to_vec()),
+            None,
+            Some(b"null".to_vec())
+        ]
+    );
+    Ok(())
+}
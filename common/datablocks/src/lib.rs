@@ -14,13 +14,17 @@
 
 #![feature(hash_raw_entry)]
 
+#[cfg(test)]
+mod data_block_parquet_test;
 #[cfg(test)]
 mod data_block_test;
 
 mod data_block;
 mod data_block_debug;
+mod data_block_parquet;
 mod kernels;
 
 pub use data_block::DataBlock;
 pub use data_block_debug::*;
+pub use data_block_parquet::*;
 pub use kernels::*;
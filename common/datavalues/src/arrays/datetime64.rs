@@ -0,0 +1,255 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+//! Kernels of the DateTime64 logical type.
+//! A DateTime64(P, TZ) value is stored in an Int64 array as the ticks of 10^-P seconds
+//! since UNIX epoch, the timezone only matters when the value is displayed or split into
+//! calendar fields.
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub const DATETIME64_MAX_PRECISION: u8 = 9;
+
+#[inline]
+pub fn datetime64_tick_multiplier(precision: u8) -> i64 {
+    10_i64.pow(precision as u32)
+}
+
+/// Parses the timezone name such as "UTC" or "Asia/Shanghai".
+pub fn parse_timezone(tz: &str) -> Result<Tz> {
+    tz.parse::<Tz>()
+        .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {}", tz)))
+}
+
+/// Returns the timezone of DateTime32/DateTime64, None if it's not specified.
+pub fn timezone_of(data_type: &DataType) -> Result<Option<Tz>> {
+    match data_type {
+        DataType::DateTime32(Some(tz)) | DataType::DateTime64(_, Some(tz)) => {
+            parse_timezone(tz).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Converts the ticks of DateTime64(`precision`) to a chrono datetime in the timezone.
+pub fn ticks_to_datetime(ticks: i64, precision: u8, tz: &Tz) -> Result<DateTime<Tz>> {
+    let multiplier = datetime64_tick_multiplier(precision);
+    let seconds = ticks.div_euclid(multiplier);
+    let nanos = ticks.rem_euclid(multiplier) * datetime64_tick_multiplier(9 - precision);
+    tz.timestamp_opt(seconds, nanos as u32)
+        .single()
+        .ok_or_else(|| ErrorCode::Overflow(format!("Overflow on datetime64 with ticks {}", ticks)))
+}
+
+/// Converts a chrono datetime to the ticks of DateTime64(`precision`), extra digits are truncated.
+pub fn datetime_to_ticks<T: TimeZone>(datetime: &DateTime<T>, precision: u8) -> i64 {
+    datetime.timestamp() * datetime64_tick_multiplier(precision)
+        + datetime.timestamp_subsec_nanos() as i64 / datetime64_tick_multiplier(9 - precision)
+}
+
+fn rescale_ticks(ticks: i64, from_precision: u8, to_precision: u8) -> Result<i64> {
+    if to_precision >= from_precision {
+        ticks
+            .checked_mul(datetime64_tick_multiplier(to_precision - from_precision))
+            .ok_or_else(|| ErrorCode::Overflow("DateTime64 overflow when rescaling"))
+    } else {
+        Ok(ticks.div_euclid(datetime64_tick_multiplier(from_precision - to_precision)))
+    }
+}
+
+fn try_map_ticks<I, T, F>(iter: I, f: F) -> Result<DFInt64Array>
+where
+    I: Iterator<Item = Option<T>>,
+    F: Fn(T) -> Result<i64>,
+{
+    iter.map(|v| v.map(&f).transpose())
+        .collect::<Result<Vec<_>>>()
+        .map(|values| values.into_iter().collect())
+}
+
+/// Casts the series of `from_type` to DateTime64(`precision`), numbers are seconds since
+/// UNIX epoch, dates and strings are interpreted in the timezone.
+pub fn datetime64_cast_from(
+    series: &Series,
+    from_type: &DataType,
+    precision: u8,
+    tz: &Tz,
+) -> Result<DFInt64Array> {
+    let multiplier = datetime64_tick_multiplier(precision);
+    let seconds_to_ticks = |seconds: i64| {
+        seconds
+            .checked_mul(multiplier)
+            .ok_or_else(|| ErrorCode::Overflow(format!("DateTime64 overflow with {}", seconds)))
+    };
+
+    match from_type {
+        DataType::DateTime64(from_precision, _) => try_map_ticks(series.i64()?.into_iter(), |v| {
+            rescale_ticks(*v, *from_precision, precision)
+        }),
+        DataType::Null => Ok(DFInt64Array::full_null(series.len())),
+        DataType::DateTime32(_) => {
+            let series = series.cast_with_type(&DataType::Int64)?;
+            try_map_ticks(series.i64()?.into_iter(), |v| seconds_to_ticks(*v))
+        }
+        DataType::Date16 | DataType::Date32 => {
+            let series = series.cast_with_type(&DataType::Int64)?;
+            try_map_ticks(series.i64()?.into_iter(), |days| {
+                let date = NaiveDate::from_ymd(1970, 1, 1)
+                    .checked_add_signed(Duration::days(*days))
+                    .ok_or_else(|| ErrorCode::Overflow(format!("Overflow on date {}", days)))?;
+                let datetime = tz
+                    .from_local_datetime(&date.and_hms(0, 0, 0))
+                    .earliest()
+                    .ok_or_else(|| {
+                        ErrorCode::BadArguments(format!("Invalid local time of date {}", date))
+                    })?;
+                Ok(datetime_to_ticks(&datetime, precision))
+            })
+        }
+        DataType::Float32 | DataType::Float64 => {
+            let series = series.cast_with_type(&DataType::Float64)?;
+            try_map_ticks(series.f64()?.into_iter(), |v| {
+                let ticks = (v * multiplier as f64).round();
+                if !ticks.is_finite() || ticks.abs() >= i64::MAX as f64 {
+                    return Err(ErrorCode::Overflow(format!(
+                        "DateTime64 overflow with {}",
+                        v
+                    )));
+                }
+                Ok(ticks as i64)
+            })
+        }
+        DataType::String => try_map_ticks(series.string()?.into_iter(), |v| {
+            parse_datetime64(v, precision, tz)
+        }),
+        data_type if is_integer(data_type) => {
+            let series = series.cast_with_type(&DataType::Int64)?;
+            try_map_ticks(series.i64()?.into_iter(), |v| seconds_to_ticks(*v))
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from {:?} to DateTime64({})",
+            other, precision
+        ))),
+    }
+}
+
+/// Casts DateTime64(`precision`) in the timezone to `to_type`.
+pub fn datetime64_cast_to(
+    array: &DFInt64Array,
+    precision: u8,
+    tz: &Tz,
+    to_type: &DataType,
+) -> Result<Series> {
+    let multiplier = datetime64_tick_multiplier(precision);
+    match to_type {
+        DataType::DateTime64(to_precision, _) => Ok(try_map_ticks(array.into_iter(), |v| {
+            rescale_ticks(*v, precision, *to_precision)
+        })?
+        .into_series()),
+        DataType::DateTime32(_) => {
+            let array: DFInt64Array = array
+                .into_iter()
+                .map(|v| v.map(|v| v.div_euclid(multiplier)))
+                .collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        DataType::Date16 | DataType::Date32 => {
+            let epoch = NaiveDate::from_ymd(1970, 1, 1);
+            let days = try_map_ticks(array.into_iter(), |v| {
+                let datetime = ticks_to_datetime(*v, precision, tz)?;
+                Ok((datetime.naive_local().date() - epoch).num_days())
+            })?;
+            days.into_series().cast_with_type(to_type)
+        }
+        DataType::Float32 | DataType::Float64 => {
+            let array: DFFloat64Array = array
+                .into_iter()
+                .map(|v| v.map(|v| *v as f64 / multiplier as f64))
+                .collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        DataType::String => {
+            let array = array
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| format_datetime64(*v, precision, tz).map(|v| v.into_bytes()))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let array: DFStringArray = array.into_iter().collect();
+            Ok(array.into_series())
+        }
+        data_type if is_integer(data_type) => {
+            // the fraction of second is truncated
+            let array: DFInt64Array = array
+                .into_iter()
+                .map(|v| v.map(|v| v.div_euclid(multiplier)))
+                .collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from DateTime64({}) to {:?}",
+            precision, other
+        ))),
+    }
+}
+
+/// Formats the ticks in the timezone, e.g. "2021-10-01 12:30:00.123456" for precision 6.
+pub fn format_datetime64(ticks: i64, precision: u8, tz: &Tz) -> Result<String> {
+    let datetime = ticks_to_datetime(ticks, precision, tz)?;
+    let formatted = datetime.format("%Y-%m-%d %H:%M:%S").to_string();
+    if precision == 0 {
+        return Ok(formatted);
+    }
+
+    let fraction = ticks.rem_euclid(datetime64_tick_multiplier(precision));
+    Ok(format!(
+        "{}.{:0width$}",
+        formatted,
+        fraction,
+        width = precision as usize
+    ))
+}
+
+/// Parses "YYYY-MM-DD hh:mm:ss[.fraction]" or "YYYY-MM-DD" in the timezone into the ticks
+/// of DateTime64(`precision`), extra fraction digits are truncated.
+pub fn parse_datetime64(text: &[u8], precision: u8, tz: &Tz) -> Result<i64> {
+    let bad_bytes = || {
+        ErrorCode::BadBytes(format!(
+            "Incorrect datetime value: {}",
+            String::from_utf8_lossy(text)
+        ))
+    };
+
+    let value = std::str::from_utf8(text).map_err(|_| bad_bytes())?.trim();
+    let datetime = match tz.datetime_from_str(value, "%Y-%m-%d %H:%M:%S%.f") {
+        Ok(datetime) => datetime,
+        Err(_) => {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| bad_bytes())?;
+            tz.from_local_datetime(&date.and_hms(0, 0, 0))
+                .earliest()
+                .ok_or_else(bad_bytes)?
+        }
+    };
+    Ok(datetime_to_ticks(&datetime, precision))
+}
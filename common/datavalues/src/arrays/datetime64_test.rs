@@ -0,0 +1,83 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::Tz;
+
+#[test]
+fn test_parse_and_format_datetime64() -> Result<()> {
+    let utc = parse_timezone("UTC")?;
+    let shanghai = parse_timezone("Asia/Shanghai")?;
+    assert!(parse_timezone("Mars/Olympus").is_err());
+
+    // 2021-10-01 12:30:00.123456 UTC
+    let ticks = 1633091400123456;
+    assert_eq!(
+        format_datetime64(ticks, 6, &utc)?,
+        "2021-10-01 12:30:00.123456"
+    );
+    assert_eq!(
+        format_datetime64(ticks, 6, &shanghai)?,
+        "2021-10-01 20:30:00.123456"
+    );
+    assert_eq!(format_datetime64(-1, 3, &utc)?, "1969-12-31 23:59:59.999");
+
+    // extra fraction digits are truncated
+    assert_eq!(
+        parse_datetime64(b"2021-10-01 20:30:00.123456789", 6, &shanghai)?,
+        ticks
+    );
+    assert_eq!(parse_datetime64(b"2021-10-01", 3, &utc)?, 1633046400000);
+    assert!(parse_datetime64(b"2021-13-01", 3, &utc).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_datetime64_cast() -> Result<()> {
+    let utc: Tz = parse_timezone("UTC")?;
+    let shanghai = parse_timezone("Asia/Shanghai")?;
+    let new_york = parse_timezone("America/New_York")?;
+
+    let datetimes = DFUInt32Array::new_from_opt_slice(&[Some(1633091400), None]);
+    let result = datetime64_cast_from(
+        &datetimes.into_series(),
+        &DataType::DateTime32(None),
+        3,
+        &utc,
+    )?;
+    assert_eq!(result.collect_values(), vec![Some(1633091400000), None]);
+
+    // the midnight of 2021-10-01 in Asia/Shanghai
+    let dates = DFUInt16Array::new_from_slice(&[18901]);
+    let result = datetime64_cast_from(&dates.into_series(), &DataType::Date16, 0, &shanghai)?;
+    assert_eq!(result.collect_values(), vec![Some(1633017600)]);
+
+    let ticks = DFInt64Array::new_from_slice(&[1633091400123456]);
+    let result = datetime64_cast_to(&ticks, 6, &utc, &DataType::DateTime64(3, None))?;
+    assert_eq!(result.i64()?.collect_values(), vec![Some(1633091400123)]);
+
+    let result = datetime64_cast_to(&ticks, 6, &utc, &DataType::DateTime32(None))?;
+    assert_eq!(result.u32()?.collect_values(), vec![Some(1633091400)]);
+
+    // 2021-10-01 02:00:00 UTC is still 2021-09-30 in America/New_York
+    let ticks = DFInt64Array::new_from_slice(&[1633053600]);
+    let result = datetime64_cast_to(&ticks, 0, &new_york, &DataType::Date16)?;
+    assert_eq!(result.u16()?.collect_values(), vec![Some(18900)]);
+    let result = datetime64_cast_to(&ticks, 0, &utc, &DataType::Date16)?;
+    assert_eq!(result.u16()?.collect_values(), vec![Some(18901)]);
+    Ok(())
+}
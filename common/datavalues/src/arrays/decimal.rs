@@ -0,0 +1,390 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+//! Kernels of the Decimal logical type.
+//! A Decimal(P, S) value is stored in an Int64 array as `value * 10^S`,
+//! all intermediate results are computed in i128 and checked against the precision.
+
+use std::cmp;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::DataValueArithmeticOperator;
+use crate::DataValueComparisonOperator;
+
+pub const DECIMAL_MAX_PRECISION: u8 = 18;
+
+#[inline]
+pub fn decimal_scale_multiplier(scale: u8) -> i128 {
+    10_i128.pow(scale as u32)
+}
+
+/// Checks the scaled value has no more than `precision` digits.
+#[inline]
+pub fn check_decimal_precision(value: i128, precision: u8) -> Result<i64> {
+    let max = decimal_scale_multiplier(precision) - 1;
+    if value > max || value < -max {
+        return Err(ErrorCode::Overflow(format!(
+            "Decimal overflow, the value exceeds {} digits",
+            precision
+        )));
+    }
+    Ok(value as i64)
+}
+
+// Integer division rounding half away from zero.
+#[inline]
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder.abs() * 2 >= denominator.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+#[inline]
+fn rescale_value(value: i128, from_scale: u8, to_scale: u8) -> Result<i128> {
+    if to_scale >= from_scale {
+        value
+            .checked_mul(decimal_scale_multiplier(to_scale - from_scale))
+            .ok_or_else(|| ErrorCode::Overflow("Decimal overflow when rescaling"))
+    } else {
+        Ok(div_round(
+            value,
+            decimal_scale_multiplier(from_scale - to_scale),
+        ))
+    }
+}
+
+fn decimal_binary<T, F>(lhs: &DFInt64Array, rhs: &DFInt64Array, op: F) -> Result<Vec<Option<T>>>
+where F: Fn(i128, i128) -> Result<T> {
+    let lhs = lhs.collect_values();
+    let rhs = rhs.collect_values();
+    let len = match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => a,
+        (a, 1) => a,
+        (1, b) => b,
+        (a, b) => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Decimal operation requires arrays of the same length, but got {} and {}",
+                a, b
+            )))
+        }
+    };
+
+    (0..len)
+        .map(|i| {
+            let l = if lhs.len() == 1 { lhs[0] } else { lhs[i] };
+            let r = if rhs.len() == 1 { rhs[0] } else { rhs[i] };
+            match (l, r) {
+                (Some(l), Some(r)) => op(l as i128, r as i128).map(Some),
+                _ => Ok(None),
+            }
+        })
+        .collect()
+}
+
+/// Rescales decimals from `from_scale` to Decimal(`precision`, `scale`), rounding half away from zero.
+pub fn decimal_rescale(
+    array: &DFInt64Array,
+    from_scale: u8,
+    precision: u8,
+    scale: u8,
+) -> Result<DFInt64Array> {
+    array
+        .into_iter()
+        .map(|v| match v {
+            Some(v) => {
+                let value = rescale_value(*v as i128, from_scale, scale)?;
+                check_decimal_precision(value, precision).map(Some)
+            }
+            None => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(|values| values.into_iter().collect())
+}
+
+/// Applies the arithmetic operator on two decimal arrays, the result is Decimal(`precision`, `scale`).
+/// Integers can be passed as decimals with zero scale.
+#[allow(clippy::too_many_arguments)]
+pub fn decimal_arithmetic(
+    op: &DataValueArithmeticOperator,
+    lhs: &DFInt64Array,
+    lhs_scale: u8,
+    rhs: &DFInt64Array,
+    rhs_scale: u8,
+    precision: u8,
+    scale: u8,
+) -> Result<DFInt64Array> {
+    let common_scale = cmp::max(lhs_scale, rhs_scale);
+    let lhs_multiplier = decimal_scale_multiplier(common_scale - lhs_scale);
+    let rhs_multiplier = decimal_scale_multiplier(common_scale - rhs_scale);
+
+    let values = decimal_binary(lhs, rhs, |l, r| {
+        let (value, value_scale) = match op {
+            DataValueArithmeticOperator::Plus => {
+                (l * lhs_multiplier + r * rhs_multiplier, common_scale)
+            }
+            DataValueArithmeticOperator::Minus => {
+                (l * lhs_multiplier - r * rhs_multiplier, common_scale)
+            }
+            DataValueArithmeticOperator::Mul => (l * r, lhs_scale + rhs_scale),
+            DataValueArithmeticOperator::Div => {
+                if r == 0 {
+                    return Err(ErrorCode::BadArguments("Division by zero"));
+                }
+                // l / 10^ls / (r / 10^rs) * 10^scale
+                let exponent = scale as i32 + rhs_scale as i32 - lhs_scale as i32;
+                let value = if exponent >= 0 {
+                    let l = l
+                        .checked_mul(decimal_scale_multiplier(exponent as u8))
+                        .ok_or_else(|| ErrorCode::Overflow("Decimal overflow in division"))?;
+                    div_round(l, r)
+                } else {
+                    div_round(l, r * decimal_scale_multiplier((-exponent) as u8))
+                };
+                (value, scale)
+            }
+            DataValueArithmeticOperator::Modulo => {
+                if r == 0 {
+                    return Err(ErrorCode::BadArguments("Division by zero"));
+                }
+                ((l * lhs_multiplier) % (r * rhs_multiplier), common_scale)
+            }
+        };
+
+        let value = rescale_value(value, value_scale, scale)?;
+        check_decimal_precision(value, precision)
+    })?;
+
+    Ok(values.into_iter().collect())
+}
+
+/// Compares two decimal arrays with different scales.
+pub fn decimal_compare(
+    op: &DataValueComparisonOperator,
+    lhs: &DFInt64Array,
+    lhs_scale: u8,
+    rhs: &DFInt64Array,
+    rhs_scale: u8,
+) -> Result<DFBooleanArray> {
+    let common_scale = cmp::max(lhs_scale, rhs_scale);
+    let lhs_multiplier = decimal_scale_multiplier(common_scale - lhs_scale);
+    let rhs_multiplier = decimal_scale_multiplier(common_scale - rhs_scale);
+
+    let cmp: fn(&i128, &i128) -> bool = match op {
+        DataValueComparisonOperator::Eq => i128::eq,
+        DataValueComparisonOperator::Lt => i128::lt,
+        DataValueComparisonOperator::LtEq => i128::le,
+        DataValueComparisonOperator::Gt => i128::gt,
+        DataValueComparisonOperator::GtEq => i128::ge,
+        DataValueComparisonOperator::NotEq => i128::ne,
+        other => {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported comparison operator {} for decimal",
+                other
+            )))
+        }
+    };
+
+    let values = decimal_binary(lhs, rhs, |l, r| {
+        Ok(cmp(&(l * lhs_multiplier), &(r * rhs_multiplier)))
+    })?;
+    Ok(values.into_iter().collect())
+}
+
+/// Casts the series of `from_type` to Decimal(`precision`, `scale`).
+pub fn decimal_cast_from(
+    series: &Series,
+    from_type: &DataType,
+    precision: u8,
+    scale: u8,
+) -> Result<DFInt64Array> {
+    match from_type {
+        DataType::Decimal(_, from_scale) => {
+            decimal_rescale(series.i64()?, *from_scale, precision, scale)
+        }
+        DataType::Null => Ok(DFInt64Array::full_null(series.len())),
+        DataType::Float32 | DataType::Float64 => {
+            let multiplier = decimal_scale_multiplier(scale) as f64;
+            let series = series.cast_with_type(&DataType::Float64)?;
+            series
+                .f64()?
+                .into_iter()
+                .map(|v| match v {
+                    Some(v) => {
+                        let value = (v * multiplier).round();
+                        if !value.is_finite() || value.abs() >= 1e38 {
+                            return Err(ErrorCode::Overflow(format!(
+                                "Decimal overflow, can't cast {} to Decimal({}, {})",
+                                v, precision, scale
+                            )));
+                        }
+                        check_decimal_precision(value as i128, precision).map(Some)
+                    }
+                    None => Ok(None),
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(|values| values.into_iter().collect())
+        }
+        DataType::String => series
+            .string()?
+            .into_iter()
+            .map(|v| match v {
+                Some(v) => parse_decimal(v, precision, scale).map(Some),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(|values| values.into_iter().collect()),
+        data_type if is_integer(data_type) || data_type == &DataType::Boolean => {
+            let series = series.cast_with_type(&DataType::Int64)?;
+            decimal_rescale(series.i64()?, 0, precision, scale)
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from {:?} to Decimal({}, {})",
+            other, precision, scale
+        ))),
+    }
+}
+
+/// Casts decimals with the given scale to `to_type`.
+pub fn decimal_cast_to(array: &DFInt64Array, scale: u8, to_type: &DataType) -> Result<Series> {
+    let multiplier = decimal_scale_multiplier(scale);
+    match to_type {
+        DataType::Decimal(to_precision, to_scale) => {
+            Ok(decimal_rescale(array, scale, *to_precision, *to_scale)?.into_series())
+        }
+        DataType::Float32 | DataType::Float64 => {
+            let array: DFFloat64Array = array
+                .into_iter()
+                .map(|v| v.map(|v| *v as f64 / multiplier as f64))
+                .collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        DataType::Boolean => {
+            let array: DFBooleanArray = array.into_iter().map(|v| v.map(|v| *v != 0)).collect();
+            Ok(array.into_series())
+        }
+        DataType::String => {
+            let array: DFStringArray = array
+                .into_iter()
+                .map(|v| v.map(|v| format_decimal(*v, scale).into_bytes()))
+                .collect();
+            Ok(array.into_series())
+        }
+        data_type if is_integer(data_type) => {
+            // the fraction is truncated
+            let array: DFInt64Array = array
+                .into_iter()
+                .map(|v| v.map(|v| (*v as i128 / multiplier) as i64))
+                .collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from Decimal to {:?}",
+            other
+        ))),
+    }
+}
+
+/// Formats a scaled decimal value, e.g. 12345 with scale 2 is formatted as 123.45
+pub fn format_decimal(value: i64, scale: u8) -> String {
+    let multiplier = decimal_scale_multiplier(scale);
+    let sign = if value < 0 { "-" } else { "" };
+    let value = (value as i128).abs();
+
+    if scale == 0 {
+        format!("{}{}", sign, value)
+    } else {
+        format!(
+            "{}{}.{:0width$}",
+            sign,
+            value / multiplier,
+            value % multiplier,
+            width = scale as usize
+        )
+    }
+}
+
+/// Parses the text into a scaled decimal value of Decimal(`precision`, `scale`),
+/// extra fraction digits are rounded half away from zero.
+pub fn parse_decimal(text: &[u8], precision: u8, scale: u8) -> Result<i64> {
+    let bad_bytes = || {
+        ErrorCode::BadBytes(format!(
+            "Incorrect decimal value: {}",
+            String::from_utf8_lossy(text)
+        ))
+    };
+
+    let mut digits = text;
+    while let [first, rest @ ..] = digits {
+        if !first.is_ascii_whitespace() {
+            break;
+        }
+        digits = rest;
+    }
+    while let [rest @ .., last] = digits {
+        if !last.is_ascii_whitespace() {
+            break;
+        }
+        digits = rest;
+    }
+
+    let (negative, digits) = match digits {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        _ => (false, digits),
+    };
+
+    let (integral, fraction) = match digits.iter().position(|c| *c == b'.') {
+        Some(pos) => (&digits[..pos], &digits[pos + 1..]),
+        None => (digits, &digits[digits.len()..]),
+    };
+
+    if (integral.is_empty() && fraction.is_empty())
+        || !integral.iter().all(|c| c.is_ascii_digit())
+        || !fraction.iter().all(|c| c.is_ascii_digit())
+    {
+        return Err(bad_bytes());
+    }
+
+    let used = cmp::min(fraction.len(), scale as usize);
+    let mut value: i128 = 0;
+    for c in integral.iter().chain(fraction[..used].iter()) {
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((c - b'0') as i128))
+            .ok_or_else(|| {
+                ErrorCode::Overflow(format!(
+                    "Decimal overflow, {} exceeds {} digits",
+                    String::from_utf8_lossy(text),
+                    precision
+                ))
+            })?;
+    }
+    value = rescale_value(value, used as u8, scale)?;
+    if fraction.len() > used && fraction[used] >= b'5' {
+        value += 1;
+    }
+
+    if negative {
+        value = -value;
+    }
+    check_decimal_precision(value, precision)
+}
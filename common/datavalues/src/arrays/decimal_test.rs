@@ -0,0 +1,141 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+#[test]
+fn test_decimal_arithmetic() -> Result<()> {
+    // Decimal(10, 2): [1.10, 2.25, null]
+    let lhs = DFInt64Array::new_from_opt_slice(&[Some(110), Some(225), None]);
+    // Decimal(10, 1): [0.3]
+    let rhs = DFInt64Array::new_from_slice(&[3]);
+
+    let result = decimal_arithmetic(&DataValueArithmeticOperator::Plus, &lhs, 2, &rhs, 1, 12, 2)?;
+    assert_eq!(result.collect_values(), vec![Some(140), Some(255), None]);
+
+    let result = decimal_arithmetic(&DataValueArithmeticOperator::Minus, &lhs, 2, &rhs, 1, 12, 2)?;
+    assert_eq!(result.collect_values(), vec![Some(80), Some(195), None]);
+
+    let result = decimal_arithmetic(&DataValueArithmeticOperator::Mul, &lhs, 2, &rhs, 1, 18, 3)?;
+    assert_eq!(result.collect_values(), vec![Some(330), Some(675), None]);
+
+    // 1.10 / 0.3 = 3.666..., 2.25 / 0.3 = 7.5
+    let result = decimal_arithmetic(&DataValueArithmeticOperator::Div, &lhs, 2, &rhs, 1, 18, 2)?;
+    assert_eq!(result.collect_values(), vec![Some(367), Some(750), None]);
+
+    let result = decimal_arithmetic(
+        &DataValueArithmeticOperator::Modulo,
+        &lhs,
+        2,
+        &rhs,
+        1,
+        12,
+        2,
+    )?;
+    assert_eq!(result.collect_values(), vec![Some(20), Some(15), None]);
+
+    let zero = DFInt64Array::new_from_slice(&[0]);
+    let result = decimal_arithmetic(&DataValueArithmeticOperator::Div, &lhs, 2, &zero, 0, 18, 2);
+    assert!(result.is_err());
+
+    // 99.99 + 0.01 overflows Decimal(4, 2)
+    let lhs = DFInt64Array::new_from_slice(&[9999]);
+    let rhs = DFInt64Array::new_from_slice(&[1]);
+    let result = decimal_arithmetic(&DataValueArithmeticOperator::Plus, &lhs, 2, &rhs, 2, 4, 2);
+    assert_eq!(result.unwrap_err().code(), 49);
+    Ok(())
+}
+
+#[test]
+fn test_decimal_compare() -> Result<()> {
+    // Decimal(10, 2): [1.10, 2.25, null]
+    let lhs = DFInt64Array::new_from_opt_slice(&[Some(110), Some(225), None]);
+    // Decimal(10, 1): [1.1]
+    let rhs = DFInt64Array::new_from_slice(&[11]);
+
+    let result = decimal_compare(&DataValueComparisonOperator::Eq, &lhs, 2, &rhs, 1)?;
+    assert_eq!(result.collect_values(), vec![Some(true), Some(false), None]);
+
+    let result = decimal_compare(&DataValueComparisonOperator::Gt, &lhs, 2, &rhs, 1)?;
+    assert_eq!(result.collect_values(), vec![Some(false), Some(true), None]);
+
+    let result = decimal_compare(&DataValueComparisonOperator::Like, &lhs, 2, &rhs, 1);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decimal_cast() -> Result<()> {
+    let strings =
+        DFStringArray::new_from_opt_slice(&[Some("1.005"), Some("-0.5"), Some("12"), None]);
+    let result = decimal_cast_from(&strings.into_series(), &DataType::String, 10, 2)?;
+    assert_eq!(result.collect_values(), vec![
+        Some(101),
+        Some(-50),
+        Some(1200),
+        None
+    ]);
+
+    let floats = DFFloat64Array::new_from_slice(&[0.1, 2.675]);
+    let result = decimal_cast_from(&floats.into_series(), &DataType::Float64, 10, 1)?;
+    assert_eq!(result.collect_values(), vec![Some(1), Some(27)]);
+
+    let integers = DFInt32Array::new_from_slice(&[7, -3]);
+    let result = decimal_cast_from(&integers.into_series(), &DataType::Int32, 10, 3)?;
+    assert_eq!(result.collect_values(), vec![Some(7000), Some(-3000)]);
+
+    let decimals = DFInt64Array::new_from_opt_slice(&[Some(12345), Some(-5), None]);
+    let result = decimal_cast_to(&decimals, 2, &DataType::String)?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"123.45".to_vec()),
+        Some(b"-0.05".to_vec()),
+        None
+    ]);
+
+    let result = decimal_cast_to(&decimals, 2, &DataType::Int32)?;
+    assert_eq!(result.i32()?.collect_values(), vec![
+        Some(123),
+        Some(0),
+        None
+    ]);
+
+    let result = decimal_cast_to(&decimals, 2, &DataType::Decimal(18, 1))?;
+    assert_eq!(result.i64()?.collect_values(), vec![
+        Some(1235),
+        Some(-1),
+        None
+    ]);
+
+    let result = decimal_cast_to(&decimals, 2, &DataType::Decimal(3, 2));
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_and_format_decimal() -> Result<()> {
+    assert_eq!(parse_decimal(b" 123.456 ", 10, 2)?, 12346);
+    assert_eq!(parse_decimal(b"-.5", 10, 1)?, -5);
+    assert_eq!(parse_decimal(b"+7", 10, 0)?, 7);
+    assert!(parse_decimal(b"1.2.3", 10, 2).is_err());
+    assert!(parse_decimal(b"abc", 10, 2).is_err());
+    assert!(parse_decimal(b"1000", 3, 0).is_err());
+
+    assert_eq!(format_decimal(12345, 2), "123.45");
+    assert_eq!(format_decimal(-5, 3), "-0.005");
+    assert_eq!(format_decimal(42, 0), "42");
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+#[test]
+fn test_list_flatten() -> Result<()> {
+    let values = Series::new(vec![1i64, 2, 3, 4]);
+    let lists = DFListArray::from_flatten(&values, vec![0, 1, 1, 4], Some(vec![true, false, true]));
+    assert_eq!(lists.len(), 3);
+    assert!(lists.is_null(1));
+    assert_eq!(lists.sub_data_type(), &DataType::Int64);
+
+    // the offsets are rebased to 0 after slicing
+    let (values, offsets) = lists.slice(2, 1).flatten();
+    assert_eq!(offsets, vec![0, 3]);
+    assert_eq!(values.i64()?.collect_values(), vec![
+        Some(2),
+        Some(3),
+        Some(4)
+    ]);
+
+    let value = unsafe { lists.slice(2, 1).try_get(0)? };
+    assert_eq!(
+        value,
+        DataValue::List(
+            Some(vec![
+                DataValue::Int64(Some(2)),
+                DataValue::Int64(Some(3)),
+                DataValue::Int64(Some(4))
+            ]),
+            DataType::Int64
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_list_from_data_values() -> Result<()> {
+    let item = DataField::new("item", DataType::Int64, true);
+    let values = vec![
+        DataValue::List(
+            Some(vec![DataValue::Int64(Some(1)), DataValue::Int64(Some(2))]),
+            DataType::Int64,
+        ),
+        DataValue::List(None, DataType::Int64),
+        DataValue::List(Some(vec![]), DataType::Int64),
+    ];
+
+    let series = DataValue::try_into_data_array(&values, &DataType::List(Box::new(item)))?;
+    assert_eq!(series.len(), 3);
+    for (row, value) in values.iter().enumerate() {
+        assert_eq!(&series.try_get(row)?, value);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_list_serializer() -> Result<()> {
+    let item = DataField::new("item", DataType::String, true);
+    let data_type = DataType::List(Box::new(item));
+    let mut serializer = data_type.create_serializer(3)?;
+    serializer.de_text(br#"["a", "b'c", null]"#)?;
+    serializer.de_null();
+    serializer.de_text(b"[]")?;
+    let series = serializer.finish_to_series();
+    assert_eq!(series.len(), 3);
+    assert_eq!(serializer.serialize_strings(&series.into())?, vec![
+        "['a','b\\'c',NULL]",
+        "NULL",
+        "[]"
+    ]);
+
+    let data_type = DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32));
+    let mut serializer = data_type.create_serializer(1)?;
+    serializer.de_text(br#"{"a": 1, "b": null}"#)?;
+    let series = serializer.finish_to_series();
+    assert_eq!(serializer.serialize_strings(&series.into())?, vec![
+        "{'a':1,'b':NULL}"
+    ]);
+
+    // the text must be a JSON array
+    let data_type = DataType::List(Box::new(DataField::new("item", DataType::Int32, true)));
+    let mut serializer = data_type.create_serializer(1)?;
+    assert!(serializer.de_text(b"1").is_err());
+    Ok(())
+}
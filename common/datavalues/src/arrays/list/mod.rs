@@ -13,9 +13,16 @@
 // limitations under the License.
 
 use common_arrow::arrow::array::*;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::buffer::Buffer;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+#[cfg(test)]
+mod list_test;
+
 mod builder;
 mod iterator;
 use std::sync::Arc;
@@ -124,6 +131,35 @@ impl DFListArray {
         }
     }
 
+    /// Creates the lists from the flattened values and the offsets of each list in it,
+    /// `validity` is false for the null lists.
+    pub fn from_flatten(values: &Series, offsets: Vec<i64>, validity: Option<Vec<bool>>) -> Self {
+        let values = values.get_array_ref();
+        let item = ArrowField::new("item", values.data_type().clone(), true);
+        let array = LargeListArray::from_data(
+            ArrowDataType::LargeList(Box::new(item)),
+            Buffer::from_trusted_len_iter(offsets.into_iter()),
+            values,
+            validity.map(|validity| validity.into_iter().collect::<Bitmap>()),
+        );
+        Self::new(array)
+    }
+
+    /// The flattened values of all lists and the offsets of each list in it,
+    /// the first offset is 0.
+    pub fn flatten(&self) -> (Series, Vec<i64>) {
+        let offsets = self.array.offsets().as_slice();
+        let start = offsets[0];
+        let end = offsets[offsets.len() - 1];
+        let values: ArrayRef = Arc::from(
+            self.array
+                .values()
+                .slice(start as usize, (end - start) as usize),
+        );
+        let offsets = offsets.iter().map(|offset| offset - start).collect();
+        (values.into_series(), offsets)
+    }
+
     pub fn sub_data_type(&self) -> &DataType {
         match self.data_type() {
             DataType::List(sub_types) => sub_types.data_type(),
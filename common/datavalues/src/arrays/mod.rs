@@ -14,14 +14,23 @@
 
 #[cfg(test)]
 mod arithmetic_test;
+#[cfg(test)]
+mod datetime64_test;
+#[cfg(test)]
+mod decimal_test;
+#[cfg(test)]
+mod variant_test;
 
 #[macro_use]
 mod arithmetic;
 mod builder;
 mod comparison;
+mod datetime64;
+mod decimal;
 mod ops;
 mod trusted_len;
 mod upstream_traits;
+mod variant;
 
 mod boolean;
 mod list;
@@ -34,6 +43,8 @@ pub use arithmetic::*;
 pub use boolean::*;
 pub use builder::*;
 pub use comparison::*;
+pub use datetime64::*;
+pub use decimal::*;
 pub use list::*;
 pub use null::*;
 pub use ops::*;
@@ -42,3 +53,4 @@ pub use r#struct::*;
 pub use string::*;
 pub use trusted_len::*;
 pub use upstream_traits::*;
+pub use variant::*;
@@ -78,3 +78,16 @@ fn test_take() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_take_opt_iter() -> Result<()> {
+    let series = Series::new(vec!["a", "b", "c"]);
+    let mut indices = vec![Some(2), None, Some(0)].into_iter();
+    let taked = unsafe { series.take_opt_iter_unchecked(&mut indices)? };
+    assert_eq!(taked.string()?.collect_values(), vec![
+        Some(b"c".to_vec()),
+        None,
+        Some(b"a".to_vec())
+    ]);
+    Ok(())
+}
@@ -57,10 +57,11 @@ impl DFStructArray {
     /// # Safety
     /// Note this doesn't do any bound checking, for performance reason.
     pub unsafe fn try_get(&self, index: usize) -> Result<DataValue> {
-        let nested_array = self.array.values()[index].clone();
-        let series = nested_array.into_series();
-        let scalar_vec = (0..series.len())
-            .map(|i| series.try_get(i))
+        let scalar_vec = self
+            .array
+            .values()
+            .iter()
+            .map(|field| field.clone().into_series().try_get(index))
             .collect::<Result<Vec<_>>>()?;
         Ok(DataValue::Struct(scalar_vec))
     }
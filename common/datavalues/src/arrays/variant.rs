@@ -0,0 +1,300 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+//! Kernels of the Variant logical type.
+//! A Variant value is stored in a String array as it's compact JSON text,
+//! a SQL NULL is a null value while a JSON null is the text `null`.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use crate::prelude::*;
+
+/// An element of the path to extract from a variant value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariantPathElement {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses the JSON text into a variant value.
+pub fn parse_variant(text: &[u8]) -> Result<JsonValue> {
+    serde_json::from_slice(text).map_err(|e| {
+        ErrorCode::BadBytes(format!(
+            "Incorrect JSON value: {}, cause: {}",
+            String::from_utf8_lossy(text),
+            e
+        ))
+    })
+}
+
+/// Formats the variant value into the compact JSON text.
+pub fn format_variant(value: &JsonValue) -> Vec<u8> {
+    // serializing a JSON value never fails, the keys are always strings
+    serde_json::to_vec(value).unwrap_or_default()
+}
+
+/// Parses the path like `a.b[0]`, `a['b c'][1]` or `$.a`.
+pub fn parse_variant_path(path: &str) -> Result<Vec<VariantPathElement>> {
+    let bad_path = || ErrorCode::BadArguments(format!("Incorrect variant path: {}", path));
+    let chars: Vec<char> = path.trim().chars().collect();
+    let mut elements = vec![];
+    let mut pos = 0;
+    if chars.first() == Some(&'$') {
+        pos += 1;
+    }
+
+    while pos < chars.len() {
+        match chars[pos] {
+            '[' => {
+                pos += 1;
+                let quote = chars.get(pos).copied().ok_or_else(bad_path)?;
+                if quote == '\'' || quote == '"' {
+                    let start = pos + 1;
+                    let end = chars[start..]
+                        .iter()
+                        .position(|c| *c == quote)
+                        .map(|p| start + p)
+                        .ok_or_else(bad_path)?;
+                    elements.push(VariantPathElement::Key(chars[start..end].iter().collect()));
+                    pos = end + 1;
+                } else {
+                    let start = pos;
+                    while pos < chars.len() && chars[pos].is_ascii_digit() {
+                        pos += 1;
+                    }
+                    let index: String = chars[start..pos].iter().collect();
+                    let index = index.parse::<usize>().map_err(|_| bad_path())?;
+                    elements.push(VariantPathElement::Index(index));
+                }
+                if chars.get(pos) != Some(&']') {
+                    return Err(bad_path());
+                }
+                pos += 1;
+            }
+            c if c == '.' || elements.is_empty() => {
+                if c == '.' {
+                    pos += 1;
+                }
+                let start = pos;
+                while pos < chars.len() && chars[pos] != '.' && chars[pos] != '[' {
+                    pos += 1;
+                }
+                if start == pos {
+                    return Err(bad_path());
+                }
+                elements.push(VariantPathElement::Key(chars[start..pos].iter().collect()));
+            }
+            _ => return Err(bad_path()),
+        }
+    }
+    Ok(elements)
+}
+
+/// Gets the value at the path, returns None if the path does not exist.
+pub fn variant_get_path<'a>(
+    value: &'a JsonValue,
+    path: &[VariantPathElement],
+) -> Option<&'a JsonValue> {
+    path.iter().try_fold(value, |value, element| match element {
+        VariantPathElement::Key(key) => value.as_object()?.get(key),
+        VariantPathElement::Index(index) => value.as_array()?.get(*index),
+    })
+}
+
+/// The text of a variant value, strings are unquoted and the others are JSON text.
+pub fn variant_to_text(value: &JsonValue) -> Vec<u8> {
+    match value {
+        JsonValue::String(v) => v.as_bytes().to_vec(),
+        other => format_variant(other),
+    }
+}
+
+/// Casts the series of `from_type` to Variant, strings are parsed as JSON text.
+pub fn variant_cast_from(series: &Series, from_type: &DataType) -> Result<DFStringArray> {
+    let rows = series.len();
+    let mut builder = StringArrayBuilder::with_capacity(rows);
+    match from_type {
+        DataType::Variant => return Ok(series.string()?.clone()),
+        DataType::Null => return Ok(DFStringArray::full_null(rows)),
+        DataType::String => {
+            for v in series.string()?.into_iter() {
+                let v = v.map(parse_variant).transpose()?;
+                builder.append_option(v.as_ref().map(format_variant));
+            }
+        }
+        DataType::Boolean => {
+            for v in series.bool()?.into_iter() {
+                builder.append_option(v.map(|v| format_variant(&JsonValue::Bool(v))));
+            }
+        }
+        data_type if is_floating(data_type) => {
+            let series = series.cast_with_type(&DataType::Float64)?;
+            for v in series.f64()?.into_iter() {
+                // NaN and infinity are not valid JSON numbers, they become JSON null
+                builder.append_option(v.map(|v| format_variant(&JsonValue::from(*v))));
+            }
+        }
+        data_type if is_integer(data_type) && is_signed_numeric(data_type) => {
+            let series = series.cast_with_type(&DataType::Int64)?;
+            for v in series.i64()?.into_iter() {
+                builder.append_option(v.map(|v| format_variant(&JsonValue::from(*v))));
+            }
+        }
+        data_type if is_integer(data_type) => {
+            let series = series.cast_with_type(&DataType::UInt64)?;
+            for v in series.u64()?.into_iter() {
+                builder.append_option(v.map(|v| format_variant(&JsonValue::from(*v))));
+            }
+        }
+        data_type if is_decimal(data_type) || is_date_or_date_time(data_type) => {
+            // decimals become JSON numbers and dates become JSON strings
+            let texts = data_type
+                .create_serializer(0)?
+                .serialize_strings(&series.into())?;
+            for (row, text) in texts.into_iter().enumerate() {
+                if series.is_null(row) {
+                    builder.append_null();
+                } else if is_decimal(data_type) {
+                    // The decimal text is a valid JSON number, it's kept as is because
+                    // parsing it into a JSON number would drop the trailing zeros of the scale.
+                    builder.append_value(text.as_bytes());
+                } else {
+                    builder.append_value(format_variant(&JsonValue::String(text)));
+                }
+            }
+        }
+        other => {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported cast from {:?} to Variant",
+                other
+            )))
+        }
+    }
+    Ok(builder.finish())
+}
+
+fn variant_to_bool(value: &JsonValue) -> Option<bool> {
+    match value {
+        JsonValue::Bool(v) => Some(*v),
+        JsonValue::String(v) => v.parse::<bool>().ok(),
+        _ => None,
+    }
+}
+
+fn variant_to_f64(value: &JsonValue) -> Option<f64> {
+    match value {
+        JsonValue::Number(v) => v.as_f64(),
+        JsonValue::String(v) => v.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+fn variant_to_i64(value: &JsonValue) -> Option<i64> {
+    match value {
+        JsonValue::Number(v) => v.as_i64(),
+        JsonValue::String(v) => v.trim().parse::<i64>().ok(),
+        _ => None,
+    }
+}
+
+fn variant_to_u64(value: &JsonValue) -> Option<u64> {
+    match value {
+        JsonValue::Number(v) => v.as_u64(),
+        JsonValue::String(v) => v.trim().parse::<u64>().ok(),
+        _ => None,
+    }
+}
+
+/// Converts each variant with `f`, a SQL NULL or a JSON null becomes NULL and
+/// a value which can't be converted is an error.
+fn try_map_variants<T, F>(
+    array: &DFStringArray,
+    to_type: &DataType,
+    f: F,
+) -> Result<Vec<Option<T>>>
+where
+    F: Fn(&JsonValue) -> Option<T>,
+{
+    array
+        .into_iter()
+        .map(|v| {
+            let value = match v {
+                Some(v) => parse_variant(v)?,
+                None => return Ok(None),
+            };
+            if value.is_null() {
+                return Ok(None);
+            }
+            match f(&value) {
+                Some(v) => Ok(Some(v)),
+                None => Err(ErrorCode::BadDataValueType(format!(
+                    "Can't cast variant {} to {:?}",
+                    value, to_type
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Casts variants to `to_type`, a JSON null is casted to NULL.
+pub fn variant_cast_to(array: &DFStringArray, to_type: &DataType) -> Result<Series> {
+    match to_type {
+        DataType::Variant => Ok(array.clone().into_series()),
+        DataType::Boolean => {
+            let values = try_map_variants(array, to_type, variant_to_bool)?;
+            let array: DFBooleanArray = values.into_iter().collect();
+            Ok(array.into_series())
+        }
+        data_type if is_floating(data_type) => {
+            let values = try_map_variants(array, to_type, variant_to_f64)?;
+            let array: DFFloat64Array = values.into_iter().collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        data_type if is_integer(data_type) && is_signed_numeric(data_type) => {
+            let values = try_map_variants(array, to_type, variant_to_i64)?;
+            let array: DFInt64Array = values.into_iter().collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        data_type if is_integer(data_type) => {
+            let values = try_map_variants(array, to_type, variant_to_u64)?;
+            let array: DFUInt64Array = values.into_iter().collect();
+            array.into_series().cast_with_type(to_type)
+        }
+        DataType::String => {
+            let values = try_map_variants(array, to_type, |v| Some(variant_to_text(v)))?;
+            let array: DFStringArray = values.into_iter().collect();
+            Ok(array.into_series())
+        }
+        DataType::Decimal(precision, scale) => {
+            let texts = variant_cast_to(array, &DataType::String)?;
+            Ok(decimal_cast_from(&texts, &DataType::String, *precision, *scale)?.into_series())
+        }
+        DataType::DateTime64(precision, tz) => {
+            let tz = parse_timezone(tz.as_deref().unwrap_or("UTC"))?;
+            let texts = variant_cast_to(array, &DataType::String)?;
+            Ok(datetime64_cast_from(&texts, &DataType::String, *precision, &tz)?.into_series())
+        }
+        data_type if is_date_or_date_time(data_type) => {
+            let texts = variant_cast_to(array, &DataType::String)?;
+            texts.cast_with_type(to_type)
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from Variant to {:?}",
+            other
+        ))),
+    }
+}
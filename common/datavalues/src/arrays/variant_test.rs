@@ -0,0 +1,112 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+#[test]
+fn test_parse_variant_path() -> Result<()> {
+    use VariantPathElement::*;
+
+    assert_eq!(parse_variant_path("a.b[0]")?, vec![
+        Key("a".to_string()),
+        Key("b".to_string()),
+        Index(0)
+    ]);
+    assert_eq!(parse_variant_path("$.a['b c'][12]")?, vec![
+        Key("a".to_string()),
+        Key("b c".to_string()),
+        Index(12)
+    ]);
+    assert_eq!(parse_variant_path("[1][\"k\"]")?, vec![
+        Index(1),
+        Key("k".to_string())
+    ]);
+    assert!(parse_variant_path("a..b").is_err());
+    assert!(parse_variant_path("a[x]").is_err());
+    assert!(parse_variant_path("a['b'").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_variant_get_path() -> Result<()> {
+    let value = parse_variant(br#"{"a": {"b": [1, "x", null]}, "c": true}"#)?;
+    let get = |path: &str| -> Result<Option<String>> {
+        let path = parse_variant_path(path)?;
+        Ok(variant_get_path(&value, &path)
+            .map(|v| String::from_utf8_lossy(&format_variant(v)).to_string()))
+    };
+
+    assert_eq!(get("a.b[1]")?, Some("\"x\"".to_string()));
+    assert_eq!(get("a.b[2]")?, Some("null".to_string()));
+    assert_eq!(get("a.b")?, Some("[1,\"x\",null]".to_string()));
+    assert_eq!(get("a.b[3]")?, None);
+    assert_eq!(get("c.d")?, None);
+    Ok(())
+}
+
+#[test]
+fn test_variant_cast() -> Result<()> {
+    let texts = DFStringArray::new_from_opt_slice(&[Some(" { \"a\" : 1 } "), Some("null"), None]);
+    let result = variant_cast_from(&texts.into_series(), &DataType::String)?;
+    assert_eq!(result.collect_values(), vec![
+        Some(b"{\"a\":1}".to_vec()),
+        Some(b"null".to_vec()),
+        None
+    ]);
+
+    let texts = DFStringArray::new_from_opt_slice(&[Some("{\"a\":")]);
+    assert!(variant_cast_from(&texts.into_series(), &DataType::String).is_err());
+
+    let numbers = DFInt32Array::new_from_opt_slice(&[Some(-1), None]);
+    let result = variant_cast_from(&numbers.into_series(), &DataType::Int32)?;
+    assert_eq!(result.collect_values(), vec![Some(b"-1".to_vec()), None]);
+
+    // Decimal(10, 2): [1.50, -0.05, null], the scale is kept
+    let decimals = DFInt64Array::new_from_opt_slice(&[Some(150), Some(-5), None]);
+    let result = variant_cast_from(&decimals.into_series(), &DataType::Decimal(10, 2))?;
+    assert_eq!(result.collect_values(), vec![
+        Some(b"1.50".to_vec()),
+        Some(b"-0.05".to_vec()),
+        None
+    ]);
+
+    let variants = DFStringArray::new_from_opt_slice(&[Some("12"), Some("\"34\""), Some("null")]);
+    let result = variant_cast_to(&variants, &DataType::Int64)?;
+    assert_eq!(result.i64()?.collect_values(), vec![
+        Some(12),
+        Some(34),
+        None
+    ]);
+
+    let result = variant_cast_to(&variants, &DataType::String)?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"12".to_vec()),
+        Some(b"34".to_vec()),
+        None
+    ]);
+
+    let result = variant_cast_to(&variants, &DataType::Decimal(5, 2))?;
+    assert_eq!(result.i64()?.collect_values(), vec![
+        Some(1200),
+        Some(3400),
+        None
+    ]);
+
+    let variants = DFStringArray::new_from_opt_slice(&[Some("[1]")]);
+    assert!(variant_cast_to(&variants, &DataType::Int64).is_err());
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+//! DateTime64 operations on DataColumnWithField, the logical type is required to know the
+//! precision and the timezone.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::DataValueComparisonOperator;
+
+impl DataColumnWithField {
+    /// The column as the ticks of DateTime64(`precision`), dates and strings are interpreted
+    /// in the timezone of `target` or UTC.
+    pub fn to_datetime64_array(&self, target: &DataType) -> Result<DFInt64Array> {
+        let (precision, tz) = match target {
+            DataType::DateTime64(precision, tz) => (*precision, tz),
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Expected a DateTime64 type, but got {:?}",
+                    other
+                )))
+            }
+        };
+        let tz = parse_timezone(tz.as_deref().unwrap_or("UTC"))?;
+        let series = self.column().to_minimal_array()?;
+        datetime64_cast_from(&series, self.data_type(), precision, &tz)
+    }
+
+    /// Comparison where at least one side is datetime64, both sides are converted to
+    /// the common DateTime64 type first.
+    pub fn datetime64_compare(
+        &self,
+        op: DataValueComparisonOperator,
+        rhs: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let common_type = datetime64_coercion(self.data_type(), rhs.data_type()).map_err(|_| {
+            ErrorCode::BadDataValueType(format!(
+                "Can't compare {:?} with {:?}",
+                self.data_type(),
+                rhs.data_type()
+            ))
+        })?;
+
+        let lhs = DataColumn::from(self.to_datetime64_array(&common_type)?.into_series())
+            .resize_constant(self.column().len());
+        let rhs = DataColumn::from(rhs.to_datetime64_array(&common_type)?.into_series())
+            .resize_constant(rhs.column().len());
+        let result = lhs.compare(op, &rhs)?;
+        Ok(result.resize_constant(self.column().len()))
+    }
+}
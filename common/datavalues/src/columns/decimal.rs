@@ -0,0 +1,93 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+//! Decimal operations on DataColumnWithField, the logical type is required to know the scale.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::DataValueArithmeticOperator;
+use crate::DataValueComparisonOperator;
+
+impl DataColumnWithField {
+    /// The column as decimals with its scale, integers have zero scale.
+    pub fn to_decimal_array(&self) -> Result<(DFInt64Array, u8)> {
+        let (_, scale) = decimal_precision_scale(self.data_type())?;
+        let series = self.column().to_minimal_array()?;
+        let array = decimal_cast_from(&series, self.data_type(), DECIMAL_MAX_PRECISION, scale)?;
+        Ok((array, scale))
+    }
+
+    fn to_float64_column(&self) -> Result<DataColumn> {
+        let series = self.column().to_minimal_array()?;
+        let series = match self.data_type() {
+            DataType::Decimal(_, scale) => {
+                decimal_cast_to(series.i64()?, *scale, &DataType::Float64)?
+            }
+            _ => series.cast_with_type(&DataType::Float64)?,
+        };
+        Ok(series.into())
+    }
+
+    /// Arithmetic where at least one side is decimal, the result type follows
+    /// `decimal_arithmetic_coercion`.
+    pub fn decimal_arithmetic(
+        &self,
+        op: DataValueArithmeticOperator,
+        rhs: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let result = match decimal_arithmetic_coercion(&op, self.data_type(), rhs.data_type())? {
+            DataType::Decimal(precision, scale) => {
+                let (lhs, lhs_scale) = self.to_decimal_array()?;
+                let (rhs, rhs_scale) = rhs.to_decimal_array()?;
+                let array =
+                    decimal_arithmetic(&op, &lhs, lhs_scale, &rhs, rhs_scale, precision, scale)?;
+                DataColumn::from(array.into_series())
+            }
+            _ => self
+                .to_float64_column()?
+                .arithmetic(op, &rhs.to_float64_column()?)?,
+        };
+        Ok(result.resize_constant(self.column().len()))
+    }
+
+    /// Comparison where at least one side is decimal, floats are compared as Float64.
+    pub fn decimal_compare(
+        &self,
+        op: DataValueComparisonOperator,
+        rhs: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let result = match decimal_coercion(self.data_type(), rhs.data_type()) {
+            Ok(DataType::Decimal(_, _)) => {
+                let (lhs, lhs_scale) = self.to_decimal_array()?;
+                let (rhs, rhs_scale) = rhs.to_decimal_array()?;
+                let array = decimal_compare(&op, &lhs, lhs_scale, &rhs, rhs_scale)?;
+                DataColumn::from(array.into_series())
+            }
+            Ok(_) => self
+                .to_float64_column()?
+                .compare(op, &rhs.to_float64_column()?)?,
+            Err(_) => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Can't compare {:?} with {:?}",
+                    self.data_type(),
+                    rhs.data_type()
+                )))
+            }
+        };
+        Ok(result.resize_constant(self.column().len()))
+    }
+}
@@ -17,8 +17,11 @@ mod common;
 mod comparison;
 mod conditional;
 mod data_column;
+mod datetime64;
+mod decimal;
 mod logic;
 mod nullable;
+mod variant;
 
 pub use common::*;
 pub use comparison::*;
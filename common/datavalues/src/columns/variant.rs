@@ -0,0 +1,57 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+//! Variant operations on DataColumnWithField, the variant side is casted to the scalar type
+//! of the other side.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::DataValueComparisonOperator;
+
+impl DataColumnWithField {
+    /// The column casted to `target`, variants are extracted and the others are kept.
+    fn to_variant_compare_series(&self, target: &DataType) -> Result<Series> {
+        let series = self.column().to_minimal_array()?;
+        match self.data_type() {
+            DataType::Variant => variant_cast_to(series.string()?, target),
+            _ => Ok(series),
+        }
+    }
+
+    /// Comparison where at least one side is variant, two variants are compared by their
+    /// JSON text.
+    pub fn variant_compare(
+        &self,
+        op: DataValueComparisonOperator,
+        rhs: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let common_type = equal_coercion(self.data_type(), rhs.data_type()).map_err(|_| {
+            ErrorCode::BadDataValueType(format!(
+                "Can't compare {:?} with {:?}",
+                self.data_type(),
+                rhs.data_type()
+            ))
+        })?;
+
+        let lhs = DataColumn::from(self.to_variant_compare_series(&common_type)?)
+            .resize_constant(self.column().len());
+        let rhs = DataColumn::from(rhs.to_variant_compare_series(&common_type)?)
+            .resize_constant(rhs.column().len());
+        let result = lhs.compare(op, &rhs)?;
+        Ok(result.resize_constant(self.column().len()))
+    }
+}
@@ -68,11 +68,21 @@ impl DataField {
             DataType::Date16 => Some("Date16"),
             DataType::Date32 => Some("Date32"),
             DataType::DateTime32(_) => Some("DateTime32"),
+            DataType::DateTime64(_, _) => Some("DateTime64"),
+            DataType::Decimal(_, _) => Some("Decimal"),
+            DataType::Variant => Some("Variant"),
+            DataType::Map(_, _) => Some("Map"),
             _ => None,
         };
 
         let custom_metadata = match self.data_type() {
             DataType::DateTime32(tz) => tz.clone(),
+            DataType::DateTime64(precision, tz) => match tz {
+                Some(tz) => Some(format!("{},{}", precision, tz)),
+                None => Some(format!("{}", precision)),
+            },
+            DataType::Decimal(precision, scale) => Some(format!("{},{}", precision, scale)),
+            DataType::Map(key, value) => serde_json::to_string(&(key, value)).ok(),
             _ => None,
         };
 
@@ -104,6 +114,28 @@ impl From<&ArrowField> for DataField {
                     "Date16" => dt = DataType::Date16,
                     "Date32" => dt = DataType::Date32,
                     "DateTime32" => dt = DataType::DateTime32(metatada.cloned()),
+                    "Variant" => dt = DataType::Variant,
+                    "Map" => {
+                        if let Some((key, value)) =
+                            metatada.and_then(|m| serde_json::from_str(m.as_str()).ok())
+                        {
+                            dt = DataType::Map(key, value);
+                        }
+                    }
+                    "DateTime64" => {
+                        if let Some(datetime) =
+                            metatada.and_then(|m| parse_datetime64_metadata(m.as_str()))
+                        {
+                            dt = datetime;
+                        }
+                    }
+                    "Decimal" => {
+                        if let Some(decimal) =
+                            metatada.and_then(|m| parse_decimal_metadata(m.as_str()))
+                        {
+                            dt = decimal;
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -112,6 +144,22 @@ impl From<&ArrowField> for DataField {
     }
 }
 
+// The metadata of decimal is formatted as "precision,scale"
+fn parse_decimal_metadata(metadata: &str) -> Option<DataType> {
+    let mut parts = metadata.split(',');
+    let precision = parts.next()?.trim().parse::<u8>().ok()?;
+    let scale = parts.next()?.trim().parse::<u8>().ok()?;
+    Some(DataType::Decimal(precision, scale))
+}
+
+// The metadata of datetime64 is formatted as "precision" or "precision,timezone"
+fn parse_datetime64_metadata(metadata: &str) -> Option<DataType> {
+    let mut parts = metadata.splitn(2, ',');
+    let precision = parts.next()?.trim().parse::<u8>().ok()?;
+    let tz = parts.next().map(|tz| tz.trim().to_string());
+    Some(DataType::DateTime64(precision, tz))
+}
+
 impl std::fmt::Display for DataField {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{:?}", self)
@@ -183,6 +183,10 @@ impl DataValue {
                     }
                     Ok(builder.finish().into_series())
                 }
+                DataType::List(_) => {
+                    let values = vec![self.clone(); size];
+                    DataValue::try_into_data_array(&values, &self.data_type())
+                }
                 other => Result::Err(ErrorCode::BadDataValueType(format!(
                     "Unexpected type:{} for DataValue List",
                     other
@@ -247,6 +251,25 @@ impl DataValue {
             ))),
         }
     }
+
+    pub fn as_f64(&self) -> Result<f64> {
+        match self {
+            DataValue::Float32(Some(v)) => Ok(*v as f64),
+            DataValue::Float64(Some(v)) => Ok(*v),
+            DataValue::Int8(Some(_))
+            | DataValue::Int16(Some(_))
+            | DataValue::Int32(Some(_))
+            | DataValue::Int64(Some(_)) => Ok(self.as_i64()? as f64),
+            DataValue::UInt8(Some(_))
+            | DataValue::UInt16(Some(_))
+            | DataValue::UInt32(Some(_))
+            | DataValue::UInt64(Some(_)) => Ok(self.as_u64()? as f64),
+            other => Result::Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to get f64 number",
+                other.data_type()
+            ))),
+        }
+    }
 }
 
 // Did not use std::convert:TryFrom
@@ -335,10 +358,13 @@ impl From<&DataType> for DataValue {
             DataType::Date16 => DataValue::UInt16(None),
             DataType::Date32 => DataValue::UInt32(None),
             DataType::DateTime32(_) => DataValue::UInt32(None),
+            DataType::DateTime64(_, _) => DataValue::Int64(None),
+            DataType::Decimal(_, _) => DataValue::Int64(None),
             DataType::List(f) => DataValue::List(None, f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
-            DataType::String => DataValue::String(None),
+            DataType::String | DataType::Variant => DataValue::String(None),
             DataType::Interval(_) => DataValue::Int64(None),
+            DataType::Map(key, value) => DataValue::List(None, map_entries_type(key, value)),
         }
     }
 }
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -85,6 +88,59 @@ impl DataValue {
             }
             DataType::Boolean => try_build_array! {values},
             DataType::String => try_build_array! {String, values},
+            DataType::List(field) => {
+                let mut items = vec![];
+                let mut offsets = Vec::with_capacity(values.len() + 1);
+                let mut validity = Vec::with_capacity(values.len());
+                offsets.push(0i64);
+                for value in values {
+                    match value {
+                        DataValue::List(Some(list), _) => {
+                            items.extend_from_slice(list);
+                            validity.push(true);
+                        }
+                        DataValue::List(None, _) | DataValue::Null => validity.push(false),
+                        other => {
+                            return Err(ErrorCode::BadDataValueType(format!(
+                                "Unexpected type:{} for DataValue List",
+                                other.data_type()
+                            )));
+                        }
+                    }
+                    offsets.push(items.len() as i64);
+                }
+                let item_type = data_type_physical(field.data_type().clone());
+                let items = DataValue::try_into_data_array(&items, &item_type)?;
+                Ok(DFListArray::from_flatten(&items, offsets, Some(validity)).into_series())
+            }
+            DataType::Struct(fields) => {
+                let mut arrays = Vec::with_capacity(fields.len());
+                let mut arrow_fields = Vec::with_capacity(fields.len());
+                for (i, field) in fields.iter().enumerate() {
+                    let field_values = values
+                        .iter()
+                        .map(|value| match value {
+                            DataValue::Struct(v) if v.len() == fields.len() => Ok(v[i].clone()),
+                            other => Err(ErrorCode::BadDataValueType(format!(
+                                "Unexpected type:{} for DataValue Struct",
+                                other.data_type()
+                            ))),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    let field_type = data_type_physical(field.data_type().clone());
+                    let array = DataValue::try_into_data_array(&field_values, &field_type)?;
+                    let array = array.get_array_ref();
+                    arrow_fields.push(ArrowField::new(
+                        field.name(),
+                        array.data_type().clone(),
+                        true,
+                    ));
+                    arrays.push(array);
+                }
+                let arrow_type = ArrowDataType::Struct(arrow_fields);
+                let array = StructArray::from_data(arrow_type, arrays, None);
+                Ok(DFStructArray::new(array).into_series())
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{} for DataValue List",
                 other
@@ -175,6 +175,14 @@ pub trait SeriesTrait: Send + Sync + fmt::Debug {
         )))
     }
 
+    /// Unpack to DFArray of data_type list
+    fn list(&self) -> Result<&DFListArray> {
+        Err(ErrorCode::IllegalDataType(format!(
+            "{:?} != list",
+            self.data_type()
+        )))
+    }
+
     /// Take by index from an iterator. This operation clones the data.
     ///
     /// # Safety
@@ -189,6 +197,16 @@ pub trait SeriesTrait: Send + Sync + fmt::Debug {
     /// This doesn't check any bounds or null validity.
     unsafe fn take_iter_unchecked(&self, _iter: &mut dyn Iterator<Item = usize>) -> Result<Series>;
 
+    /// Take by optional index from an iterator, a None index takes a null value.
+    ///
+    /// # Safety
+    ///
+    /// This doesn't check any bounds.
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        _iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Result<Series>;
+
     /// scatter the arrays by indices, the size of indices must be equal to the size of array
     /// # Safety
     /// Note this doesn't do any bound checking, for performance reason.
@@ -300,6 +300,18 @@ macro_rules! impl_dyn_array {
                 }
             }
 
+            /// Unpack to DFArray of data_type list
+            fn list(&self) -> Result<&DFListArray> {
+                if matches!(self.0.data_type(), &DataType::List(_)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFListArray)) }
+                } else {
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "cannot unpack Series of type {:?} into list",
+                        self.data_type(),
+                    )))
+                }
+            }
+
             fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Result<Series> {
                 Ok(ArrayTake::take(&self.0, iter.into())?.into_series())
             }
@@ -311,6 +323,14 @@ macro_rules! impl_dyn_array {
                 Ok(ArrayTake::take_unchecked(&self.0, iter.into())?.into_series())
             }
 
+            unsafe fn take_opt_iter_unchecked(
+                &self,
+                iter: &mut dyn Iterator<Item = Option<usize>>,
+            ) -> Result<Series> {
+                let indices: TakeIdxIterNull<_> = TakeIdx::IterNulls(iter);
+                Ok(ArrayTake::take_unchecked(&self.0, indices)?.into_series())
+            }
+
             /// scatter the arrays by indices, the size of indices must be equal to the size of array
             unsafe fn scatter_unchecked(
                 &self,
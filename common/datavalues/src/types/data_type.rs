@@ -45,11 +45,29 @@ pub enum DataType {
     /// Option<String> indicates the timezone, if it's None, it's UTC
     DateTime32(Option<String>),
 
+    /// A 64-bit datetime with sub-second precision, it's physical type is Int64 which
+    /// stores the ticks of 10^-precision seconds since UNIX epoch (1970-01-01).
+    /// The max precision is 9 (nanoseconds), Option<String> indicates the timezone
+    DateTime64(u8, Option<String>),
+
+    /// A fixed-point decimal with precision (total number of digits) and scale
+    /// (number of digits after the point), the value is stored scaled by 10^scale.
+    /// The max precision is 18 and it's physical type is Int64
+    Decimal(u8, u8),
+
     Interval(IntervalUnit),
 
     List(Box<DataField>),
     Struct(Vec<DataField>),
     String,
+
+    /// A map from the key type to the value type, it's physical type is a List of
+    /// the (key, value) Struct, see `map_entries_type`
+    Map(Box<DataType>, Box<DataType>),
+
+    /// A semi-structured JSON value, it's physical type is String which stores
+    /// the compact JSON text, a JSON null is stored as the text `null`
+    Variant,
 }
 
 #[derive(
@@ -94,17 +112,31 @@ impl DataType {
             Date32 => ArrowDataType::Int32,
             // we don't use DataType::Extension because extension types are not supported in parquet
             DateTime32(_) => ArrowDataType::UInt32,
+            DateTime64(_, _) => ArrowDataType::Int64,
+            Decimal(_, _) => ArrowDataType::Int64,
             List(dt) => ArrowDataType::LargeList(Box::new(dt.to_arrow())),
             Struct(fs) => {
                 let arrows_fields = fs.iter().map(|f| f.to_arrow()).collect();
                 ArrowDataType::Struct(arrows_fields)
             }
-            String => ArrowDataType::LargeBinary,
+            String | Variant => ArrowDataType::LargeBinary,
             Interval(_) => ArrowDataType::Int64,
+            Map(key, value) => {
+                let entries = DataField::new("item", map_entries_type(key, value), true);
+                ArrowDataType::LargeList(Box::new(entries.to_arrow()))
+            }
         }
     }
 }
 
+/// The type of the entries of Map(`key`, `value`).
+pub fn map_entries_type(key: &DataType, value: &DataType) -> DataType {
+    DataType::Struct(vec![
+        DataField::new("key", key.clone(), true),
+        DataField::new("value", value.clone(), true),
+    ])
+}
+
 impl PartialEq<ArrowDataType> for DataType {
     fn eq(&self, other: &ArrowDataType) -> bool {
         let arrow_type = self.to_arrow();
@@ -131,6 +163,9 @@ impl From<&ArrowDataType> for DataType {
                 let f: DataField = (f.as_ref()).into();
                 DataType::List(Box::new(f))
             }
+            ArrowDataType::Struct(fields) => {
+                DataType::Struct(fields.iter().map(|f| f.into()).collect())
+            }
             ArrowDataType::Binary | ArrowDataType::LargeBinary => DataType::String,
             ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => DataType::String,
 
@@ -142,6 +177,7 @@ impl From<&ArrowDataType> for DataType {
                 "Date16" => DataType::Date16,
                 "Date32" => DataType::Date32,
                 "DateTime32" => DataType::DateTime32(extra.clone()),
+                "Variant" => DataType::Variant,
                 _ => unimplemented!("data_type: {}", dt),
             },
 
@@ -185,9 +221,19 @@ impl fmt::Debug for DataType {
                     write!(f, "DateTime32")
                 }
             }
+            Self::DateTime64(precision, arg1) => {
+                if let Some(tz) = arg1 {
+                    write!(f, "DateTime64({}, {:?})", precision, tz)
+                } else {
+                    write!(f, "DateTime64({})", precision)
+                }
+            }
+            Self::Decimal(precision, scale) => write!(f, "Decimal({}, {})", precision, scale),
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
             Self::Struct(arg0) => f.debug_tuple("Struct").field(arg0).finish(),
             Self::String => write!(f, "String"),
+            Self::Variant => write!(f, "Variant"),
+            Self::Map(key, value) => write!(f, "Map({:?}, {:?})", key, value),
             Self::Interval(unit) => write!(f, "Interval({})", unit.to_string()),
         }
     }
@@ -17,6 +17,7 @@ use std::cmp;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::arrays::DECIMAL_MAX_PRECISION;
 use crate::prelude::DataType;
 use crate::DataField;
 use crate::DataValueArithmeticOperator;
@@ -61,20 +62,64 @@ pub fn is_floating(dt: &DataType) -> bool {
 pub fn is_date_or_date_time(dt: &DataType) -> bool {
     matches!(
         dt,
-        DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+        DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) | DataType::DateTime64(_, _)
     )
 }
 
+pub fn is_datetime64(dt: &DataType) -> bool {
+    matches!(dt, DataType::DateTime64(_, _))
+}
+
 pub fn is_integer(dt: &DataType) -> bool {
     is_numeric(dt) && !is_floating(dt)
 }
 
+pub fn is_decimal(dt: &DataType) -> bool {
+    matches!(dt, DataType::Decimal(_, _))
+}
+
+pub fn is_variant(dt: &DataType) -> bool {
+    matches!(dt, DataType::Variant)
+}
+
+fn is_variant_scalar(dt: &DataType) -> bool {
+    is_numeric(dt)
+        || is_decimal(dt)
+        || is_date_or_date_time(dt)
+        || matches!(dt, DataType::Boolean | DataType::String | DataType::Variant)
+}
+
+/// The precision and scale of a decimal, integers are treated as decimals with zero scale
+pub fn decimal_precision_scale(dt: &DataType) -> Result<(u8, u8)> {
+    match dt {
+        DataType::Decimal(precision, scale) => Ok((*precision, *scale)),
+        DataType::Int8 | DataType::UInt8 => Ok((3, 0)),
+        DataType::Int16 | DataType::UInt16 => Ok((5, 0)),
+        DataType::Int32 | DataType::UInt32 => Ok((10, 0)),
+        DataType::Int64 | DataType::UInt64 => Ok((DECIMAL_MAX_PRECISION, 0)),
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't convert type {:?} to decimal",
+            dt
+        ))),
+    }
+}
+
+pub fn construct_decimal_type(precision: u8, scale: u8) -> Result<DataType> {
+    if precision == 0 || precision > DECIMAL_MAX_PRECISION || scale > precision {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "Decimal precision must be between 1 and {} and scale can't be greater than precision, but got Decimal({}, {})",
+            DECIMAL_MAX_PRECISION, precision, scale
+        )));
+    }
+    Ok(DataType::Decimal(precision, scale))
+}
+
 pub fn numeric_byte_size(dt: &DataType) -> Result<usize> {
     match dt {
         DataType::Int8 | DataType::UInt8 => Ok(1),
         DataType::Int16 | DataType::UInt16 => Ok(2),
         DataType::Int32 | DataType::UInt32 | DataType::Float32 => Ok(4),
-        DataType::Int64 | DataType::UInt64 | DataType::Float64 => Ok(8),
+        DataType::Int64 | DataType::UInt64 | DataType::Float64 | DataType::Decimal(_, _) => Ok(8),
         _ => Result::Err(ErrorCode::BadArguments(format!(
             "Function number_byte_size argument must be numeric types, but got {:?}",
             dt
@@ -251,6 +296,123 @@ pub fn numerical_arithmetic_coercion(
     }
 }
 
+/// Coercion rule for decimal arithmetic, the result keeps the scale of the operands
+/// so that no digit is lost, a decimal mixed with a float falls back to Float64
+#[inline]
+pub fn decimal_arithmetic_coercion(
+    op: &DataValueArithmeticOperator,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Result<DataType> {
+    if is_floating(lhs_type) || is_floating(rhs_type) {
+        return Ok(DataType::Float64);
+    }
+
+    let e = Result::Err(ErrorCode::BadDataValueType(format!(
+        "DataValue Error: Unsupported decimal coercion ({:?}) {} ({:?})",
+        lhs_type, op, rhs_type
+    )));
+
+    let (lhs_precision, lhs_scale) = match decimal_precision_scale(lhs_type) {
+        Ok(v) => v,
+        Err(_) => return e,
+    };
+    let (rhs_precision, rhs_scale) = match decimal_precision_scale(rhs_type) {
+        Ok(v) => v,
+        Err(_) => return e,
+    };
+
+    let max_scale = cmp::max(lhs_scale, rhs_scale);
+    let max_integral = cmp::max(
+        lhs_precision.saturating_sub(lhs_scale),
+        rhs_precision.saturating_sub(rhs_scale),
+    );
+    let (precision, scale) = match op {
+        DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+            (max_integral + max_scale + 1, max_scale)
+        }
+        DataValueArithmeticOperator::Mul => (lhs_precision + rhs_precision, lhs_scale + rhs_scale),
+        DataValueArithmeticOperator::Div => (DECIMAL_MAX_PRECISION, max_scale),
+        DataValueArithmeticOperator::Modulo => (max_integral + max_scale, max_scale),
+    };
+
+    if scale > DECIMAL_MAX_PRECISION {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "Decimal scale {} of ({:?}) {} ({:?}) exceeds the max precision {}",
+            scale, lhs_type, op, rhs_type, DECIMAL_MAX_PRECISION
+        )));
+    }
+
+    // the result is checked for overflow when it's computed
+    construct_decimal_type(cmp::min(precision, DECIMAL_MAX_PRECISION), scale)
+}
+
+/// Coercion rule for a decimal compared or merged with another numeric type
+pub fn decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    if is_floating(lhs_type) || is_floating(rhs_type) {
+        return Ok(DataType::Float64);
+    }
+
+    let (lhs_precision, lhs_scale) = decimal_precision_scale(lhs_type)?;
+    let (rhs_precision, rhs_scale) = decimal_precision_scale(rhs_type)?;
+    let scale = cmp::max(lhs_scale, rhs_scale);
+    let integral = cmp::max(
+        lhs_precision.saturating_sub(lhs_scale),
+        rhs_precision.saturating_sub(rhs_scale),
+    );
+    construct_decimal_type(cmp::min(integral + scale, DECIMAL_MAX_PRECISION), scale)
+}
+
+/// Coercion rule for a datetime64 compared or merged with another date/datetime or string,
+/// the higher precision wins and the timezone of the left side is kept.
+pub fn datetime64_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    match (lhs_type, rhs_type) {
+        (DataType::DateTime64(p1, tz1), DataType::DateTime64(p2, tz2)) => Ok(DataType::DateTime64(
+            cmp::max(*p1, *p2),
+            tz1.clone().or_else(|| tz2.clone()),
+        )),
+        (DataType::DateTime64(_, _), other) | (other, DataType::DateTime64(_, _))
+            if is_date_or_date_time(other) || other == &DataType::String =>
+        {
+            if is_datetime64(lhs_type) {
+                Ok(lhs_type.clone())
+            } else {
+                Ok(rhs_type.clone())
+            }
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't coerce {:?} with {:?}",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
+/// Coercion rule for a variant compared with another type, the variant is casted to the scalar type.
+pub fn variant_compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    match (lhs_type, rhs_type) {
+        (DataType::Variant, other) | (other, DataType::Variant) if is_variant_scalar(other) => {
+            Ok(other.clone())
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't coerce {:?} with {:?}",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
+/// Coercion rule for a variant merged with another type, the scalar is casted to variant.
+pub fn variant_merge_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    match (lhs_type, rhs_type) {
+        (DataType::Variant, other) | (other, DataType::Variant) if is_variant_scalar(other) => {
+            Ok(DataType::Variant)
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't merge types from {} and {}",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
 #[inline]
 pub fn datetime_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
@@ -280,8 +442,15 @@ pub fn datetime_arithmetic_coercion(
             if is_numeric(&b) || is_interval(&b) {
                 Ok(a)
             } else {
-                // Date minus Date or DateTime minus DateTime
-                Ok(DataType::Int32)
+                match (&a, &b) {
+                    // DateTime64 minus DateTime64 is the difference of ticks
+                    (DataType::DateTime64(p1, _), DataType::DateTime64(p2, _)) if p1 == p2 => {
+                        Ok(DataType::Int64)
+                    }
+                    (DataType::DateTime64(_, _), _) | (_, DataType::DateTime64(_, _)) => e,
+                    // Date minus Date or DateTime minus DateTime
+                    _ => Ok(DataType::Int32),
+                }
             }
         }
         _ => e,
@@ -323,6 +492,18 @@ pub fn numerical_unary_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
     val_type: &DataType,
 ) -> Result<DataType> {
+    if is_decimal(val_type) {
+        return match op {
+            DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+                Ok(val_type.clone())
+            }
+            other => Result::Err(ErrorCode::UnknownFunction(format!(
+                "Unexpected operator:{:?} to unary function",
+                other
+            ))),
+        };
+    }
+
     // error on any non-numeric type
     if !is_numeric(val_type) {
         return Result::Err(ErrorCode::BadDataValueType(format!(
@@ -351,6 +532,70 @@ pub fn numerical_unary_arithmetic_coercion(
     }
 }
 
+/// Checks the arguments of a numeric function are numeric or integer types
+pub fn numerical_args_check(name: &str, args: &[DataType], integer_only: bool) -> Result<()> {
+    for arg in args {
+        if !is_numeric(arg) || (integer_only && !is_integer(arg)) {
+            return Result::Err(ErrorCode::BadDataValueType(format!(
+                "DataValue Error: Unsupported type {:?} of function {}, expect {} types",
+                arg,
+                name,
+                if integer_only { "integer" } else { "numeric" }
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Coercion rule for the math functions computed in floating point, such as `sqrt` and `pow`
+pub fn numerical_math_coercion(name: &str, args: &[DataType]) -> Result<DataType> {
+    numerical_args_check(name, args, false)?;
+    Ok(DataType::Float64)
+}
+
+/// Coercion rule for `abs`, the signed integers become the unsigned ones of the same size
+/// so that the absolute value of the minimum fits
+pub fn numerical_abs_coercion(val_type: &DataType) -> Result<DataType> {
+    numerical_args_check("abs", &[val_type.clone()], false)?;
+    match is_floating(val_type) {
+        true => Ok(val_type.clone()),
+        false => construct_numeric_type(false, false, numeric_byte_size(val_type)?),
+    }
+}
+
+/// Coercion rule for the rounding functions, the value keeps its type
+pub fn numerical_round_coercion(name: &str, args: &[DataType]) -> Result<DataType> {
+    numerical_args_check(name, args, false)?;
+    if args.len() > 1 && !is_integer(&args[1]) {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: The precision of function {} must be an integer, but got {:?}",
+            name, args[1]
+        )));
+    }
+    Ok(args[0].clone())
+}
+
+/// Coercion rule for `intDiv`, the result is signed if any operand is signed and has the
+/// size of the dividend, a floating operand makes it Int64
+pub fn numerical_int_div_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    numerical_args_check("intDiv", &[lhs_type.clone(), rhs_type.clone()], false)?;
+    if is_floating(lhs_type) || is_floating(rhs_type) {
+        return Ok(DataType::Int64);
+    }
+    let has_signed = is_signed_numeric(lhs_type) || is_signed_numeric(rhs_type);
+    construct_numeric_type(has_signed, false, numeric_byte_size(lhs_type)?)
+}
+
+/// Coercion rule for the bitwise operations of two integers
+pub fn numerical_bitwise_coercion(
+    name: &str,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Result<DataType> {
+    numerical_args_check(name, &[lhs_type.clone(), rhs_type.clone()], true)?;
+    numerical_coercion(lhs_type, rhs_type, true)
+}
+
 // coercion rules for equality operations. This is a superset of all numerical coercion rules.
 pub fn equal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
     if lhs_type == rhs_type {
@@ -358,6 +603,18 @@ pub fn equal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataTy
         return Ok(lhs_type.clone());
     }
 
+    if is_variant(lhs_type) || is_variant(rhs_type) {
+        return variant_compare_coercion(lhs_type, rhs_type);
+    }
+
+    if is_decimal(lhs_type) || is_decimal(rhs_type) {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
+    if is_datetime64(lhs_type) || is_datetime64(rhs_type) {
+        return datetime64_coercion(lhs_type, rhs_type);
+    }
+
     numerical_coercion(lhs_type, rhs_type, true)
 }
 
@@ -424,6 +681,15 @@ pub fn merge_types(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType>
             if lhs_type == rhs_type {
                 return Ok(lhs_type.clone());
             }
+            if is_variant(lhs_type) || is_variant(rhs_type) {
+                return variant_merge_coercion(lhs_type, rhs_type);
+            }
+            if is_decimal(lhs_type) || is_decimal(rhs_type) {
+                return decimal_coercion(lhs_type, rhs_type);
+            }
+            if is_datetime64(lhs_type) || is_datetime64(rhs_type) {
+                return datetime64_coercion(lhs_type, rhs_type);
+            }
             if is_numeric(lhs_type) && is_numeric(rhs_type) {
                 numerical_coercion(lhs_type, rhs_type, false)
             } else {
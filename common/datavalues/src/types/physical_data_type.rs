@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::map_entries_type;
 use crate::DataField;
 use crate::DataType;
 
@@ -49,13 +50,18 @@ impl From<DataType> for PhysicalDataType {
             DataType::Int8 => Int8,
             DataType::Int16 => Int16,
             DataType::Int32 => Int32,
-            DataType::Int64 => Int64,
+            DataType::Int64 | DataType::DateTime64(_, _) | DataType::Decimal(_, _) => Int64,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::List(x) => List(x),
             DataType::Struct(x) => Struct(x),
-            DataType::String => String,
+            DataType::String | DataType::Variant => String,
             DataType::Interval(_) => Int64,
+            DataType::Map(key, value) => List(Box::new(DataField::new(
+                "item",
+                map_entries_type(&key, &value),
+                true,
+            ))),
         }
     }
 }
@@ -48,7 +48,10 @@ where
                     let mut dt = NaiveDateTime::from_timestamp(0, 0);
                     let d = Duration::seconds(v.to_i64().unwrap());
                     dt.add_assign(d);
-                    dt.format("%Y-%m-%d %H:%M:%S").to_string()
+                    self.tz
+                        .from_utc_datetime(&dt)
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
                 })
                 .unwrap_or_else(|| "NULL".to_owned())
             })
@@ -105,3 +108,63 @@ where
         self.builder.finish().into_series()
     }
 }
+
+pub struct DateTime64Serializer {
+    pub builder: PrimitiveArrayBuilder<i64>,
+    pub precision: u8,
+    pub tz: Tz,
+}
+
+impl TypeSerializer for DateTime64Serializer {
+    fn serialize_strings(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array: &DFInt64Array = array.static_cast();
+
+        array
+            .iter()
+            .map(|x| match x {
+                Some(v) => format_datetime64(*v, self.precision, &self.tz),
+                None => Ok("NULL".to_owned()),
+            })
+            .collect()
+    }
+
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: i64 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: i64 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        if reader.eq_ignore_ascii_case(b"null") {
+            self.builder.append_null();
+            return Ok(());
+        }
+
+        match lexical_core::parse::<i64>(reader) {
+            Ok(v) => self.builder.append_value(v),
+            Err(_) => {
+                let v = parse_datetime64(reader, self.precision, &self.tz)?;
+                self.builder.append_value(v);
+            }
+        }
+        Ok(())
+    }
+
+    fn de_null(&mut self) {
+        self.builder.append_null()
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        self.builder.finish().into_series()
+    }
+}
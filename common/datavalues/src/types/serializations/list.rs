@@ -0,0 +1,175 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use crate::prelude::*;
+
+/// Lists are serialized as the text like `[1,2]` or `['a','b']` and maps are serialized as
+/// the text like `{'a':1}`, both are deserialized from the JSON text like `[1, 2]` or `{"a": 1}`.
+pub struct ListSerializer {
+    pub data_type: DataType,
+    pub values: Vec<DataValue>,
+}
+
+impl ListSerializer {
+    pub fn try_create(data_type: DataType, capacity: usize) -> Result<Self> {
+        if !is_list_item_supported(&data_type) {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "create_serializer does not support type '{:?}'",
+                data_type
+            )));
+        }
+        Ok(Self {
+            data_type,
+            values: Vec::with_capacity(capacity),
+        })
+    }
+
+    fn append_text(&mut self, text: &[u8]) -> Result<()> {
+        let value = parse_variant(text)?;
+        let value = json_to_data_value(&value, &self.data_type)?;
+        self.values.push(value);
+        Ok(())
+    }
+}
+
+impl TypeSerializer for ListSerializer {
+    fn serialize_strings(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array = array.list()?;
+        let (values, offsets) = array.flatten();
+
+        let (items, open, close) = match &self.data_type {
+            DataType::Map(key, value) => {
+                let entries = DFStructArray::from_arrow_array(values.get_array_ref().as_ref());
+                let fields = entries.inner().values();
+                let keys = serialize_items(key, fields[0].clone().into_series())?;
+                let values = serialize_items(value, fields[1].clone().into_series())?;
+                let items = keys
+                    .into_iter()
+                    .zip(values.into_iter())
+                    .map(|(key, value)| format!("{}:{}", key, value))
+                    .collect::<Vec<_>>();
+                (items, "{", "}")
+            }
+            DataType::List(field) => (serialize_items(field.data_type(), values)?, "[", "]"),
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unexpected type:{:?} for ListSerializer",
+                    other
+                )))
+            }
+        };
+
+        let result = (0..array.len())
+            .map(|row| match array.is_null(row) {
+                true => "NULL".to_owned(),
+                false => {
+                    let items = &items[offsets[row] as usize..offsets[row + 1] as usize];
+                    format!("{}{}{}", open, items.join(","), close)
+                }
+            })
+            .collect();
+        Ok(result)
+    }
+
+    fn de(&mut self, _reader: &mut &[u8]) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "Binary deserialization of {:?} is not supported",
+            self.data_type
+        )))
+    }
+
+    fn de_batch(&mut self, _reader: &[u8], _step: usize, _rows: usize) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "Binary deserialization of {:?} is not supported",
+            self.data_type
+        )))
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        self.append_text(reader)
+    }
+
+    fn de_null(&mut self) {
+        self.values.push(DataValue::from(&self.data_type));
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        let values = std::mem::take(&mut self.values);
+        let data_type = data_type_physical(self.data_type.clone());
+        DataValue::try_into_data_array(&values, &data_type)
+            .expect("the item types are checked when the serializer is created")
+    }
+}
+
+/// Whether the lists of `data_type` can be built from the data values.
+fn is_list_item_supported(data_type: &DataType) -> bool {
+    match data_type_physical(data_type.clone()) {
+        DataType::Boolean | DataType::String => true,
+        DataType::List(field) => is_list_item_supported(field.data_type()),
+        DataType::Struct(fields) => fields.iter().all(|f| is_list_item_supported(f.data_type())),
+        other => is_numeric(&other),
+    }
+}
+
+/// The texts of the items, strings and dates are quoted.
+fn serialize_items(data_type: &DataType, items: Series) -> Result<Vec<String>> {
+    let texts = data_type
+        .create_serializer(0)?
+        .serialize_strings(&items.clone().into())?;
+    let quoted = matches!(data_type, DataType::String) || is_date_or_date_time(data_type);
+
+    let result = texts
+        .into_iter()
+        .enumerate()
+        .map(|(row, text)| match quoted && !items.is_null(row) {
+            true => format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'")),
+            false => text,
+        })
+        .collect();
+    Ok(result)
+}
+
+fn json_to_data_value(value: &JsonValue, data_type: &DataType) -> Result<DataValue> {
+    match (value, data_type) {
+        (JsonValue::Null, _) => Ok(DataValue::from(data_type)),
+        (JsonValue::Array(items), DataType::List(field)) => {
+            let items = items
+                .iter()
+                .map(|item| json_to_data_value(item, field.data_type()))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(DataValue::List(Some(items), field.data_type().clone()))
+        }
+        (JsonValue::Object(entries), DataType::Map(key, value)) => {
+            let mut items = Vec::with_capacity(entries.len());
+            for (k, v) in entries.iter() {
+                let k = json_to_data_value(&JsonValue::String(k.clone()), key)?;
+                let v = json_to_data_value(v, value)?;
+                items.push(DataValue::Struct(vec![k, v]));
+            }
+            Ok(DataValue::List(Some(items), map_entries_type(key, value)))
+        }
+        (_, DataType::List(_) | DataType::Map(_, _) | DataType::Struct(_)) => Err(
+            ErrorCode::BadBytes(format!("Incorrect {:?} value: {}", data_type, value)),
+        ),
+        (value, data_type) => {
+            let array: DFStringArray = std::iter::once(Some(format_variant(value))).collect();
+            variant_cast_to(&array, data_type)?.try_get(0)
+        }
+    }
+}
@@ -22,14 +22,20 @@ use crate::DataType;
 mod boolean;
 mod date;
 mod date_time;
+mod decimal;
+mod list;
 mod number;
 mod string;
+mod variant;
 
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use decimal::*;
+pub use list::*;
 pub use number::*;
 pub use string::*;
+pub use variant::*;
 
 // capacity.
 pub trait TypeSerializer {
@@ -70,12 +76,31 @@ impl DataType {
                         tz: tz.parse::<Tz>().unwrap(),
                     }))
                 }
+                DataType::DateTime64(precision, tz) => {
+                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
+                    Ok(Box::new(DateTime64Serializer {
+                        builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                        precision,
+                        tz: parse_timezone(&tz)?,
+                    }))
+                }
+                DataType::Decimal(precision, scale) => Ok(Box::new(DecimalSerializer {
+                    builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                    precision,
+                    scale,
+                })),
                 DataType::String => Ok(Box::new(StringSerializer {
                     builder: StringArrayBuilder::with_capacity(capacity),
                 })),
+                DataType::Variant => Ok(Box::new(VariantSerializer {
+                    builder: StringArrayBuilder::with_capacity(capacity),
+                })),
                 DataType::Interval(_) => Ok(Box::new(DateSerializer::<i64> {
                     builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
                 })),
+                DataType::List(_) | DataType::Map(_, _) => {
+                    Ok(Box::new(ListSerializer::try_create(data_type, capacity)?))
+                }
                 other => Err(ErrorCode::BadDataValueType(format!(
                     "create_serializer does not support type '{:?}'",
                     other
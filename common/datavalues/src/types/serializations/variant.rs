@@ -0,0 +1,76 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_io::prelude::BinaryRead;
+
+use crate::prelude::*;
+
+/// The variant is serialized as the compact JSON text, the text is validated when it's
+/// deserialized.
+pub struct VariantSerializer {
+    pub builder: StringArrayBuilder,
+}
+
+impl VariantSerializer {
+    fn append_text(&mut self, text: &[u8]) -> Result<()> {
+        let value = parse_variant(text)?;
+        self.builder.append_value(format_variant(&value));
+        Ok(())
+    }
+}
+
+impl TypeSerializer for VariantSerializer {
+    fn serialize_strings(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array: &DFStringArray = array.static_cast();
+
+        let result: Vec<String> = array
+            .into_iter()
+            .map(|x| {
+                x.map(|v| String::from_utf8_lossy(v).to_string())
+                    .unwrap_or_else(|| "NULL".to_owned())
+            })
+            .collect();
+        Ok(result)
+    }
+
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let offset: u64 = reader.read_uvarint()?;
+        let (text, rest) = reader.split_at(offset as usize);
+        self.append_text(text)?;
+        *reader = rest;
+        Ok(())
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let end = std::cmp::min(step * (row + 1), reader.len());
+            self.append_text(&reader[step * row..end])?;
+        }
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        self.append_text(reader)
+    }
+
+    fn de_null(&mut self) {
+        self.builder.append_null()
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        self.builder.finish().into_series()
+    }
+}
@@ -16,6 +16,7 @@
 use common_datavalues::DataSchemaRef;
 use common_dfs_api_vo::AppendResult;
 use common_dfs_api_vo::BlockStream;
+use common_dfs_api_vo::FileStream;
 use common_dfs_api_vo::ReadAction;
 use common_streams::SendableDataBlockStream;
 
@@ -36,6 +37,21 @@ pub trait StorageApi: Send + Sync {
         mut block_stream: BlockStream,
     ) -> common_exception::Result<AppendResult>;
 
+    /// Reads `len` bytes of file `key` from `offset`, or till the end of file if `len` is None.
+    /// The bytes are streamed back in chunks, instead of transferring the whole file at once.
+    async fn read_file(
+        &self,
+        key: &str,
+        offset: u64,
+        len: Option<u64>,
+    ) -> common_exception::Result<FileStream>;
+
+    /// Returns the size of file `key` in bytes.
+    async fn file_size(&self, key: &str) -> common_exception::Result<u64>;
+
+    /// Adds file `key`, fails if it already exists.
+    async fn add_file(&self, key: &str, data: Vec<u8>) -> common_exception::Result<()>;
+
     // async fn truncate(
     //     &self,
     //     db: String,
@@ -22,6 +22,11 @@ use common_planners::Statistics;
 pub type BlockStream =
     std::pin::Pin<Box<dyn futures::stream::Stream<Item = DataBlock> + Sync + Send + 'static>>;
 
+/// Consecutive chunks of a file read from dfs.
+pub type FileStream = std::pin::Pin<
+    Box<dyn futures::stream::Stream<Item = common_exception::Result<Vec<u8>>> + Send + 'static>,
+>;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct DataPartInfo {
     pub part: Part,
@@ -178,6 +178,10 @@ build_exceptions! {
     TLSConfigurationFailure(52),
     UnknownSession(53),
     UnexpectedError(54),
+    PermissionDenied(55),
+    QuotaExceeded(56),
+    ResourceGroupQueueFull(57),
+    UnknownQuery(58),
 
     // uncategorized
     UnexpectedResponseType(600),
@@ -195,6 +199,7 @@ build_exceptions! {
     // dfs node errors
 
     UnknownNode(2101),
+    ReplicationQuorumNotReached(2102),
 
     // meta service errors
 
@@ -239,6 +244,13 @@ build_exceptions! {
     UnknownUser(3000),
     UserAlreadyExists(3001),
     IllegalUserInfoFormat(3002),
+    UnknownRole(3003),
+    RoleAlreadyExists(3004),
+    IllegalRoleInfoFormat(3005),
+    UnknownResourceGroup(3006),
+    ResourceGroupAlreadyExists(3007),
+    IllegalResourceGroupInfoFormat(3008),
+    IllegalUserSettingFormat(3009),
 
     // meta-api error codes
     DatabaseAlreadyExists(4001),
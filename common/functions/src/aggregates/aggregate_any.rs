@@ -0,0 +1,174 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+struct AggregateAnyState {
+    value: Option<DataValue>,
+}
+
+impl AggregateAnyState {
+    #[inline(always)]
+    fn add(&mut self, value: DataValue, last: bool) {
+        if !value.is_null() && (last || self.value.is_none()) {
+            self.value = Some(value);
+        }
+    }
+}
+
+/// any(x) keeps the first non-NULL value it meets and anyLast(x) the last one,
+/// which one is "first" depends on the order blocks arrive in.
+#[derive(Clone)]
+pub struct AggregateAnyFunction {
+    display_name: String,
+    arguments: Vec<DataField>,
+    last: bool,
+}
+
+impl AggregateAnyFunction {
+    pub fn try_create_any(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create(display_name, arguments, false)
+    }
+
+    pub fn try_create_any_last(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create(display_name, arguments, true)
+    }
+
+    fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        last: bool,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            arguments,
+            last,
+        }))
+    }
+}
+
+impl AggregateFunction for AggregateAnyFunction {
+    fn name(&self) -> &str {
+        "AggregateAnyFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.arguments[0].data_type().clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateAnyState { value: None });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateAnyState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        for row in 0..input_rows {
+            if !self.last && state.value.is_some() {
+                break;
+            }
+            state.add(arrays[0].try_get(row)?, self.last);
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateAnyState>();
+            if self.last || state.value.is_none() {
+                state.add(arrays[0].try_get(row)?, self.last);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        match &state.value {
+            Some(value) => {
+                writer.write_scalar(&1u8)?;
+                value.serialize_to_buf(writer)
+            }
+            None => writer.write_scalar(&0u8),
+        }
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        let is_some: u8 = reader.read_scalar()?;
+        state.value = match is_some {
+            0 => None,
+            _ => Some(DataValue::deserialize(reader)?),
+        };
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        let rhs = rhs.get::<AggregateAnyState>();
+        if let Some(value) = &rhs.value {
+            state.add(value.clone(), self.last);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateAnyState>();
+        match &state.value {
+            Some(value) => Ok(value.clone()),
+            None => Ok(DataValue::from(self.arguments[0].data_type())),
+        }
+    }
+}
+
+impl fmt::Display for AggregateAnyFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::hash_map::DefaultHasher;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// 2^12 registers, the standard error is about 1.04 / sqrt(4096) = 1.6%.
+const HLL_PRECISION: u32 = 12;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// A dense HyperLogLog sketch. Registers are allocated on the first insert so
+/// that empty groups stay cheap, and merging two sketches is a register-wise max.
+struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    #[inline(always)]
+    fn add_hash(&mut self, hash: u64) {
+        if self.registers.is_empty() {
+            self.registers = vec![0; HLL_REGISTERS];
+        }
+
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        let rank = ((hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1))).leading_zeros() + 1;
+        if self.registers[index] < rank as u8 {
+            self.registers[index] = rank as u8;
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.registers.is_empty() {
+            return;
+        }
+        if self.registers.is_empty() {
+            self.registers = other.registers.clone();
+            return;
+        }
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    fn count(&self) -> u64 {
+        if self.registers.is_empty() {
+            return 0;
+        }
+
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let mut zeros = 0;
+        let mut sum = 0.0;
+        for register in self.registers.iter() {
+            if *register == 0 {
+                zeros += 1;
+            }
+            sum += 1.0 / (1u64 << *register) as f64;
+        }
+
+        let estimate = alpha * m * m / sum;
+        if estimate <= 2.5 * m && zeros > 0 {
+            // Small range correction: linear counting.
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateApproxCountDistinctFunction {
+    display_name: String,
+    _arguments: Vec<DataField>,
+}
+
+impl AggregateApproxCountDistinctFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 32))?;
+
+        for argument in arguments.iter() {
+            let data_type = argument.data_type();
+            if !is_numeric(data_type)
+                && !matches!(
+                    data_type,
+                    DataType::Boolean | DataType::String | DataType::Date16 | DataType::Date32
+                )
+            {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "AggregateApproxCountDistinctFunction does not support type '{:?}'",
+                    data_type
+                )));
+            }
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+        }))
+    }
+
+    // The hash must be stable across nodes since sketches are merged in the
+    // final stage, so use SipHash with the fixed default keys.
+    fn hash_row(arrays: &[Series], row: usize) -> Result<Option<u64>> {
+        let mut hasher = DefaultHasher::new();
+        for array in arrays {
+            let value = array.try_get(row)?;
+            if value.is_null() {
+                return Ok(None);
+            }
+            DataGroupValue::try_from(&value)?.hash(&mut hasher);
+        }
+        Ok(Some(hasher.finish()))
+    }
+}
+
+impl AggregateFunction for AggregateApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxCountDistinctFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| HyperLogLog { registers: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<HyperLogLog>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        for row in 0..input_rows {
+            if let Some(hash) = Self::hash_row(arrays, row)? {
+                state.add_hash(hash);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            if let Some(hash) = Self::hash_row(arrays, row)? {
+                let place = place.next(offset);
+                place.get::<HyperLogLog>().add_hash(hash);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        state.registers.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        state.registers = Vec::<u8>::deserialize(reader)?;
+        if !state.registers.is_empty() && state.registers.len() != HLL_REGISTERS {
+            return Err(ErrorCode::BadBytes(format!(
+                "HyperLogLog state must have {} registers, but got {}",
+                HLL_REGISTERS,
+                state.registers.len()
+            )));
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        let rhs = rhs.get::<HyperLogLog>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<HyperLogLog>();
+        Ok(DataValue::UInt64(Some(state.count())))
+    }
+}
+
+impl fmt::Display for AggregateApproxCountDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
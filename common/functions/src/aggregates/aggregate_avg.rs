@@ -27,8 +27,10 @@ use num::NumCast;
 
 use super::StateAddr;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateDecimalSumFunction;
 use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
+use crate::aggregates::DecimalSumKind;
 use crate::with_match_primitive_type;
 
 // count = 0 means it's all nullable
@@ -185,6 +187,14 @@ pub fn try_create_aggregate_avg_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if let DataType::Decimal(_, _) = data_type {
+        return AggregateDecimalSumFunction::try_create(
+            DecimalSumKind::Avg,
+            display_name,
+            arguments,
+        );
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateAvgFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
             display_name,
@@ -0,0 +1,224 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CovarianceKind {
+    CovarPop,
+    CovarSamp,
+    Corr,
+}
+
+#[derive(Clone, Default)]
+struct AggregateCovarianceState {
+    count: u64,
+    mean_x: f64,
+    mean_y: f64,
+    co_moment: f64,
+    m2_x: f64,
+    m2_y: f64,
+}
+
+impl AggregateCovarianceState {
+    #[inline(always)]
+    fn add(&mut self, x: f64, y: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / n;
+        self.mean_y += dy / n;
+        self.co_moment += dx * (y - self.mean_y);
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let n1 = self.count as f64;
+        let n2 = other.count as f64;
+        let factor = n1 * n2 / (n1 + n2);
+        let dx = other.mean_x - self.mean_x;
+        let dy = other.mean_y - self.mean_y;
+
+        self.co_moment += other.co_moment + dx * dy * factor;
+        self.m2_x += other.m2_x + dx * dx * factor;
+        self.m2_y += other.m2_y + dy * dy * factor;
+        self.mean_x += dx * n2 / (n1 + n2);
+        self.mean_y += dy * n2 / (n1 + n2);
+        self.count += other.count;
+    }
+}
+
+/// covar_pop, covar_samp and corr over two numeric columns.
+#[derive(Clone)]
+pub struct AggregateCovarianceFunction {
+    display_name: String,
+    kind: CovarianceKind,
+}
+
+impl AggregateCovarianceFunction {
+    pub fn try_create(
+        kind: CovarianceKind,
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_binary_arguments(display_name, arguments.len())?;
+
+        for argument in arguments.iter() {
+            if !is_numeric(argument.data_type()) {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "AggregateCovarianceFunction does not support type '{:?}'",
+                    argument.data_type()
+                )));
+            }
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            kind,
+        }))
+    }
+
+    // Both sides are widened to Float64 up front instead of dispatching on
+    // every pair of numeric types.
+    fn for_each_pair<F>(arrays: &[Series], mut f: F) -> Result<()>
+    where F: FnMut(usize, f64, f64) {
+        let x = arrays[0].cast_with_type(&DataType::Float64)?;
+        let y = arrays[1].cast_with_type(&DataType::Float64)?;
+
+        x.f64()?
+            .iter()
+            .zip(y.f64()?.iter())
+            .enumerate()
+            .for_each(|(row, pair)| {
+                if let (Some(x), Some(y)) = pair {
+                    f(row, *x, *y);
+                }
+            });
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateCovarianceFunction {
+    fn name(&self) -> &str {
+        "AggregateCovarianceFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateCovarianceState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateCovarianceState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        Self::for_each_pair(arrays, |_, x, y| state.add(x, y))
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        Self::for_each_pair(arrays, |row, x, y| {
+            let place = places[row].next(offset);
+            place.get::<AggregateCovarianceState>().add(x, y);
+        })
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        state.count.serialize_to_buf(writer)?;
+        state.mean_x.serialize_to_buf(writer)?;
+        state.mean_y.serialize_to_buf(writer)?;
+        state.co_moment.serialize_to_buf(writer)?;
+        state.m2_x.serialize_to_buf(writer)?;
+        state.m2_y.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        state.count = u64::deserialize(reader)?;
+        state.mean_x = f64::deserialize(reader)?;
+        state.mean_y = f64::deserialize(reader)?;
+        state.co_moment = f64::deserialize(reader)?;
+        state.m2_x = f64::deserialize(reader)?;
+        state.m2_y = f64::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        let rhs = rhs.get::<AggregateCovarianceState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateCovarianceState>();
+        let result = match self.kind {
+            CovarianceKind::CovarPop if state.count > 0 => {
+                Some(state.co_moment / state.count as f64)
+            }
+            CovarianceKind::CovarSamp if state.count > 1 => {
+                Some(state.co_moment / (state.count - 1) as f64)
+            }
+            CovarianceKind::Corr if state.m2_x > 0.0 && state.m2_y > 0.0 => {
+                Some(state.co_moment / (state.m2_x * state.m2_y).sqrt())
+            }
+            _ => None,
+        };
+        Ok(DataValue::Float64(result))
+    }
+}
+
+impl fmt::Display for AggregateCovarianceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecimalSumKind {
+    Sum,
+    Avg,
+}
+
+// The scaled values are summed in i128, the result is checked against
+// the max precision when it is returned.
+#[derive(Default)]
+struct AggregateDecimalSumState {
+    value: i128,
+    count: u64,
+}
+
+impl AggregateDecimalSumState {
+    #[inline(always)]
+    fn add(&mut self, value: i128, count: u64) -> Result<()> {
+        self.value = self
+            .value
+            .checked_add(value)
+            .ok_or_else(|| ErrorCode::Overflow("Decimal overflow when summing"))?;
+        self.count += count;
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        ((self.value >> 64) as i64).serialize_to_buf(writer)?;
+        (self.value as u64).serialize_to_buf(writer)?;
+        self.count.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let high = i64::deserialize(reader)? as i128;
+        let low = u64::deserialize(reader)? as i128;
+        self.value = (high << 64) | low;
+        self.count = u64::deserialize(reader)?;
+        Ok(())
+    }
+}
+
+/// sum and avg of a Decimal(P, S) column, the result is Decimal(18, S).
+#[derive(Clone)]
+pub struct AggregateDecimalSumFunction {
+    display_name: String,
+    kind: DecimalSumKind,
+    scale: u8,
+}
+
+impl AggregateDecimalSumFunction {
+    pub fn try_create(
+        kind: DecimalSumKind,
+        display_name: &str,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        let scale = match arguments[0].data_type() {
+            DataType::Decimal(_, scale) => *scale,
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "AggregateDecimalSumFunction does not support type '{:?}'",
+                    other
+                )))
+            }
+        };
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            kind,
+            scale,
+        }))
+    }
+}
+
+impl AggregateFunction for AggregateDecimalSumFunction {
+    fn name(&self) -> &str {
+        "AggregateDecimalSumFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Decimal(DECIMAL_MAX_PRECISION, self.scale))
+    }
+
+    // NULL is returned if there is no value
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateDecimalSumState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateDecimalSumState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumState>();
+        let mut sum = 0i128;
+        let mut count = 0u64;
+        for v in arrays[0].i64()?.iter().flatten() {
+            // At most 2^64 values of less than 2^63 each, the sum fits in i128
+            sum += *v as i128;
+            count += 1;
+        }
+        state.add(sum, count)
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (v, place) in arrays[0].i64()?.iter().zip(places.iter()) {
+            if let Some(v) = v {
+                let place = place.next(offset);
+                let state = place.get::<AggregateDecimalSumState>();
+                state.add(*v as i128, 1)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumState>();
+        let rhs = rhs.get::<AggregateDecimalSumState>();
+        state.add(rhs.value, rhs.count)
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateDecimalSumState>();
+        if state.count == 0 {
+            return Ok(DataValue::from(&self.return_type()?));
+        }
+
+        let value = match self.kind {
+            DecimalSumKind::Sum => state.value,
+            DecimalSumKind::Avg => {
+                // Integer division rounding half away from zero, so no digit is lost by floats
+                let count = state.count as i128;
+                let mut avg = state.value / count;
+                if (state.value % count).abs() * 2 >= count {
+                    avg += state.value.signum();
+                }
+                avg
+            }
+        };
+
+        let value = check_decimal_precision(value, DECIMAL_MAX_PRECISION)?;
+        Ok(DataValue::Int64(Some(value)))
+    }
+}
+
+impl fmt::Display for AggregateDecimalSumFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use bumpalo::Bump;
+use bytes::BytesMut;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use pretty_assertions::assert_eq;
@@ -43,6 +44,10 @@ fn test_aggregate_function() -> Result<()> {
         DataField::new("b", DataType::Int64, false),
     ];
 
+    // Decimal(10, 2) on the same physical values: [0.04, 0.03, 0.02, 0.01]
+    let decimal_arg = DataField::new("c", DataType::Decimal(10, 2), false);
+    const MAX_DECIMAL: i64 = 999_999_999_999_999_999;
+
     let tests = vec![
         Test {
             name: "count-passed",
@@ -99,6 +104,58 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::Int64(Some(10)),
             error: "",
         },
+        Test {
+            name: "sum-decimal-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![decimal_arg.clone()],
+            display: "sum",
+            func_name: "sum",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(10)),
+            error: "",
+        },
+        Test {
+            name: "avg-decimal-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![decimal_arg.clone()],
+            display: "avg",
+            func_name: "avg",
+            arrays: vec![arrays[0].clone()],
+            // 0.025 is rounded to 0.03
+            expect: DataValue::Int64(Some(3)),
+            error: "",
+        },
+        Test {
+            name: "sum-decimal-beyond-i64-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![decimal_arg.clone()],
+            display: "sum",
+            func_name: "sum",
+            // The running sum exceeds i64::MAX before the negative values are added
+            arrays: vec![Series::new(
+                [MAX_DECIMAL; 10]
+                    .iter()
+                    .chain([-MAX_DECIMAL; 10].iter())
+                    .copied()
+                    .collect::<Vec<i64>>(),
+            )],
+            expect: DataValue::Int64(Some(0)),
+            error: "",
+        },
+        Test {
+            name: "sum-decimal-overflow",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![decimal_arg],
+            display: "sum",
+            func_name: "sum",
+            arrays: vec![Series::new(vec![MAX_DECIMAL, 1])],
+            expect: DataValue::Null,
+            error: "Code: 49, displayText = Decimal overflow, the value exceeds 18 digits.",
+        },
         Test {
             name: "argMax-passed",
             eval_nums: 1,
@@ -176,6 +233,211 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::Float64(Some(1.118033988749895)),
             error: "",
         },
+        Test {
+            name: "groupArray-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(
+                    [4i64, 3, 2, 1, 4, 3, 2, 1]
+                        .iter()
+                        .map(|v| DataValue::Int64(Some(*v)))
+                        .collect(),
+                ),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "approx-count-distinct-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "median-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(1.0))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(4.0)),
+            error: "",
+        },
+        Test {
+            name: "quantiles-passed",
+            eval_nums: 1,
+            params: vec![
+                DataValue::Float64(Some(0.25)),
+                DataValue::Float64(Some(0.75)),
+            ],
+            args: vec![args[0].clone()],
+            display: "quantiles",
+            func_name: "quantiles",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![
+                    DataValue::Float64(Some(1.5)),
+                    DataValue::Float64(Some(3.5)),
+                ]),
+                DataType::Float64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "var-pop-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "var_pop",
+            func_name: "var_pop",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.25)),
+            error: "",
+        },
+        Test {
+            name: "var-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "var_samp",
+            func_name: "var_samp",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.6666666666666667)),
+            error: "",
+        },
+        Test {
+            name: "stddev-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "stddev_samp",
+            func_name: "stddev_samp",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.2909944487358056)),
+            error: "",
+        },
+        Test {
+            name: "covar-pop-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: args.clone(),
+            display: "covar_pop",
+            func_name: "covar_pop",
+            arrays: arrays.clone(),
+            expect: DataValue::Float64(Some(-1.25)),
+            error: "",
+        },
+        Test {
+            name: "covar-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: args.clone(),
+            display: "covar_samp",
+            func_name: "covar_samp",
+            arrays: arrays.clone(),
+            expect: DataValue::Float64(Some(-1.6666666666666667)),
+            error: "",
+        },
+        Test {
+            name: "corr-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: args.clone(),
+            display: "corr",
+            func_name: "corr",
+            arrays: arrays.clone(),
+            expect: DataValue::Float64(Some(-1.0)),
+            error: "",
+        },
+        Test {
+            name: "any-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "any",
+            func_name: "any",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "anyLast-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "anyLast",
+            func_name: "anyLast",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(1)),
+            error: "",
+        },
+        Test {
+            name: "groupUniqArray-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupUniqArray",
+            func_name: "groupUniqArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(
+                    [4i64, 3, 2, 1]
+                        .iter()
+                        .map(|v| DataValue::Int64(Some(*v)))
+                        .collect(),
+                ),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "topK-passed",
+            eval_nums: 2,
+            params: vec![DataValue::UInt64(Some(2))],
+            args: vec![args[0].clone()],
+            display: "topK",
+            func_name: "topK",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![DataValue::Int64(Some(4)), DataValue::Int64(Some(3))]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "quantile-level-notpassed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(1.5))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Null,
+            error:
+                "Code: 6, displayText = quantile level must be in the range [0, 1], but got 1.5.",
+        },
     ];
 
     for t in tests {
@@ -203,6 +465,14 @@ fn test_aggregate_function() -> Result<()> {
             func.merge(addr1.into(), addr2.into())?;
             let result = func.merge_result(addr1.into())?;
             assert_eq!(&t.expect, &result, "{}", t.name);
+            if let DataType::Decimal(_, scale) = t.args[0].data_type() {
+                assert_eq!(
+                    func.return_type()?,
+                    DataType::Decimal(18, *scale),
+                    "{}",
+                    t.name
+                );
+            }
             assert_eq!(t.display, format!("{:}", func), "{}", t.name);
             Ok(())
         };
@@ -360,6 +630,72 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
             expect: DataValue::Float64(None),
             error: "",
         },
+        Test {
+            name: "groupArray-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(Some(vec![]), DataType::Int64),
+            error: "",
+        },
+        Test {
+            name: "approx-count-distinct-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(0)),
+            error: "",
+        },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "var-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "var_samp",
+            func_name: "var_samp",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "any-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "any",
+            func_name: "any",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(None),
+            error: "",
+        },
+        Test {
+            name: "topK-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "topK",
+            func_name: "topK",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(Some(vec![]), DataType::Int64),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -396,3 +732,98 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_aggregate_decimal_function_on_empty_data() -> Result<()> {
+    // sum and avg of decimals are nullable, NULL of the decimal type is returned if there is no value.
+    let arena = Bump::new();
+    let arrays = vec![DFInt64Array::new_from_slice(&[]).into_series()];
+    let args = vec![DataField::new("c", DataType::Decimal(10, 2), true)];
+    let schema = DataSchemaRefExt::create(args.clone());
+
+    for name in ["sum", "avg"] {
+        let func = AggregateFunctionFactory::get(name, vec![], args.clone())?;
+        assert!(func.nullable(&schema)?, "{}", name);
+
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        func.accumulate(addr.into(), &arrays, 0)?;
+
+        let result = func.merge_result(addr.into())?;
+        assert!(result.is_null(), "{}", name);
+        assert_eq!(
+            DataValue::from(&DataType::Decimal(18, 2)),
+            result,
+            "{}",
+            name
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_serialize() -> Result<()> {
+    let arrays: Vec<Series> = vec![
+        Series::new(vec![4i64, 3, 2, 1, 3]),
+        Series::new(vec![1i64, 2, 3, 4, 2]),
+    ];
+
+    let args = vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+    ];
+
+    let tests: Vec<(&str, Vec<DataValue>, Vec<DataField>)> = vec![
+        ("approx_count_distinct", vec![], vec![args[0].clone()]),
+        ("quantile", vec![DataValue::Float64(Some(0.9))], vec![args
+            [0]
+        .clone()]),
+        (
+            "quantiles",
+            vec![DataValue::Float64(Some(0.1)), DataValue::Float64(Some(0.5))],
+            vec![args[0].clone()],
+        ),
+        ("var_samp", vec![], vec![args[0].clone()]),
+        ("corr", vec![], args.clone()),
+        ("anyLast", vec![], vec![args[0].clone()]),
+        ("groupUniqArray", vec![], vec![args[0].clone()]),
+        ("topK", vec![DataValue::UInt64(Some(1))], vec![
+            args[0].clone()
+        ]),
+    ];
+
+    for (name, params, args) in tests {
+        let arena = Bump::new();
+        let func = AggregateFunctionFactory::get(name, params, args.clone())?;
+        let arrays = &arrays[0..args.len()];
+
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.accumulate(addr1.into(), arrays, 5)?;
+
+        let mut buffer = BytesMut::new();
+        func.serialize(addr1.into(), &mut buffer)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.deserialize(addr2.into(), &mut buffer.as_ref())?;
+        assert_eq!(
+            func.merge_result(addr1.into())?,
+            func.merge_result(addr2.into())?,
+            "{}",
+            name
+        );
+
+        // A deserialized state can be merged like a local one.
+        let addr3 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr3.into());
+        func.merge(addr3.into(), addr2.into())?;
+        assert_eq!(
+            func.merge_result(addr1.into())?,
+            func.merge_result(addr3.into())?,
+            "{}",
+            name
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,162 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataGroupValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use indexmap::IndexSet;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+struct AggregateGroupUniqArrayState {
+    values: IndexSet<DataGroupValue>,
+}
+
+impl AggregateGroupUniqArrayState {
+    #[inline(always)]
+    fn add(&mut self, value: DataValue) -> Result<()> {
+        if !value.is_null() {
+            self.values.insert(DataGroupValue::try_from(&value)?);
+        }
+        Ok(())
+    }
+}
+
+/// groupUniqArray(x) collects the distinct non-NULL values in the order they are first seen.
+#[derive(Clone)]
+pub struct AggregateGroupUniqArrayFunction {
+    display_name: String,
+    arguments: Vec<DataField>,
+}
+
+impl AggregateFunction for AggregateGroupUniqArrayFunction {
+    fn name(&self) -> &str {
+        "AggregateGroupUniqArrayFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        let item = DataField::new("item", self.arguments[0].data_type().clone(), true);
+        Ok(DataType::List(Box::new(item)))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateGroupUniqArrayState {
+            values: IndexSet::new(),
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateGroupUniqArrayState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateGroupUniqArrayState>();
+        for row in 0..input_rows {
+            state.add(arrays[0].try_get(row)?)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateGroupUniqArrayState>();
+            state.add(arrays[0].try_get(row)?)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateGroupUniqArrayState>();
+        writer.write_uvarint(state.values.len() as u64)?;
+        for value in state.values.iter() {
+            DataValue::from(value).serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateGroupUniqArrayState>();
+        let size = reader.read_uvarint()?;
+        state.values = IndexSet::with_capacity(size as usize);
+        for _i in 0..size {
+            state.add(DataValue::deserialize(reader)?)?;
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateGroupUniqArrayState>();
+        let rhs = rhs.get::<AggregateGroupUniqArrayState>();
+        state.values.extend(rhs.values.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateGroupUniqArrayState>();
+        Ok(DataValue::List(
+            Some(state.values.iter().map(DataValue::from).collect()),
+            self.arguments[0].data_type().clone(),
+        ))
+    }
+}
+
+impl fmt::Display for AggregateGroupUniqArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateGroupUniqArrayFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        let data_type = arguments[0].data_type();
+        if !is_numeric(data_type) && !matches!(data_type, DataType::Boolean | DataType::String) {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "AggregateGroupUniqArrayFunction does not support type '{:?}'",
+                data_type
+            )));
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            arguments,
+        }))
+    }
+}
@@ -0,0 +1,388 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::with_match_primitive_type;
+
+const TDIGEST_COMPRESSION: f64 = 100.0;
+const TDIGEST_BUFFER_SIZE: usize = 512;
+
+#[derive(Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging t-digest: values are buffered and folded into centroids whose
+/// size is bounded by `4 * n * q * (1 - q) / compression`, so the tails stay
+/// accurate while the middle is summarized.
+struct TDigest {
+    centroids: Vec<Centroid>,
+    unmerged: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    fn new() -> Self {
+        TDigest {
+            centroids: vec![],
+            unmerged: vec![],
+            count: 0.0,
+            min: f64::MAX,
+            max: f64::MIN,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+
+        self.unmerged.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        self.count += 1.0;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+
+        if self.unmerged.len() >= TDIGEST_BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0.0 {
+            return;
+        }
+
+        self.unmerged.extend_from_slice(&other.centroids);
+        self.unmerged.extend_from_slice(&other.unmerged);
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.compress();
+    }
+
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.append(&mut self.unmerged);
+        centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap_or(Ordering::Equal));
+
+        let mut merged = Vec::with_capacity(centroids.len());
+        let mut cumulative = 0.0;
+        let mut current = centroids[0];
+        for centroid in centroids.into_iter().skip(1) {
+            let weight = current.weight + centroid.weight;
+            let q = (cumulative + weight / 2.0) / self.count;
+            let limit = 4.0 * self.count * q * (1.0 - q) / TDIGEST_COMPRESSION;
+
+            if weight <= limit.max(1.0) {
+                current.mean += (centroid.mean - current.mean) * centroid.weight / weight;
+                current.weight = weight;
+            } else {
+                cumulative += current.weight;
+                merged.push(current);
+                current = centroid;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+
+    fn quantile(&mut self, level: f64) -> Option<f64> {
+        self.compress();
+        if self.centroids.is_empty() {
+            return None;
+        }
+
+        let target = level * self.count;
+        let mut cumulative = 0.0;
+        for (index, centroid) in self.centroids.iter().enumerate() {
+            let center = cumulative + centroid.weight / 2.0;
+            if target < center {
+                let (left_mean, left_center) = match index {
+                    0 => (self.min, 0.0),
+                    _ => {
+                        let prev = &self.centroids[index - 1];
+                        (prev.mean, cumulative - prev.weight / 2.0)
+                    }
+                };
+                let ratio = (target - left_center) / (center - left_center);
+                return Some(left_mean + (centroid.mean - left_mean) * ratio);
+            }
+            cumulative += centroid.weight;
+        }
+
+        let last = &self.centroids[self.centroids.len() - 1];
+        let center = self.count - last.weight / 2.0;
+        if self.count <= center {
+            return Some(last.mean);
+        }
+        let ratio = (target - center) / (self.count - center);
+        Some(last.mean + (self.max - last.mean) * ratio)
+    }
+
+    fn serialize(&mut self, writer: &mut BytesMut) -> Result<()> {
+        self.compress();
+        self.count.serialize_to_buf(writer)?;
+        self.min.serialize_to_buf(writer)?;
+        self.max.serialize_to_buf(writer)?;
+        writer.write_uvarint(self.centroids.len() as u64)?;
+        for centroid in self.centroids.iter() {
+            centroid.mean.serialize_to_buf(writer)?;
+            centroid.weight.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.count = f64::deserialize(reader)?;
+        self.min = f64::deserialize(reader)?;
+        self.max = f64::deserialize(reader)?;
+
+        let size = reader.read_uvarint()?;
+        self.unmerged.clear();
+        self.centroids = Vec::with_capacity(size as usize);
+        for _i in 0..size {
+            let mean = f64::deserialize(reader)?;
+            let weight = f64::deserialize(reader)?;
+            self.centroids.push(Centroid { mean, weight });
+        }
+        Ok(())
+    }
+}
+
+/// quantile(level)(x), median(x) and quantiles(level1, level2, ...)(x).
+#[derive(Clone)]
+pub struct AggregateQuantileFunction<T> {
+    display_name: String,
+    levels: Vec<f64>,
+    // quantiles returns an array even with a single level.
+    multiple: bool,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        match self.multiple {
+            true => Ok(DataType::List(Box::new(DataField::new(
+                "item",
+                DataType::Float64,
+                true,
+            )))),
+            false => Ok(DataType::Float64),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(TDigest::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<TDigest>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<TDigest>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+
+        if array.null_count() == 0 {
+            for value in array.into_no_null_iter() {
+                state.add(value.as_());
+            }
+        } else {
+            for value in array.iter().flatten() {
+                state.add(value.as_());
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                place.get::<TDigest>().add(value.as_());
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<TDigest>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<TDigest>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<TDigest>();
+        let rhs = rhs.get::<TDigest>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<TDigest>();
+        let mut values = self
+            .levels
+            .iter()
+            .map(|level| DataValue::Float64(state.quantile(*level)))
+            .collect::<Vec<_>>();
+
+        match self.multiple {
+            true => Ok(DataValue::List(Some(values), DataType::Float64)),
+            false => Ok(values.remove(0)),
+        }
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateQuantileFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(
+        display_name: &str,
+        levels: Vec<f64>,
+        multiple: bool,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            levels,
+            multiple,
+            t: PhantomData,
+        }))
+    }
+}
+
+fn try_create_quantile(
+    display_name: &str,
+    levels: Vec<f64>,
+    multiple: bool,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    if let Some(level) = levels.iter().find(|level| !(0.0..=1.0).contains(*level)) {
+        return Err(ErrorCode::BadArguments(format!(
+            "{} level must be in the range [0, 1], but got {}",
+            display_name, level
+        )));
+    }
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type!(data_type, |$T| {
+        AggregateQuantileFunction::<$T>::try_create(display_name, levels, multiple)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_quantile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    let level = match params.len() {
+        0 => 0.5,
+        1 => params[0].as_f64()?,
+        n => {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at most one parameter, but got {}",
+                display_name, n
+            )));
+        }
+    };
+    try_create_quantile(display_name, vec![level], false, arguments)
+}
+
+pub fn try_create_aggregate_median_function(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create_quantile(display_name, vec![0.5], false, arguments)
+}
+
+pub fn try_create_aggregate_quantiles_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    if params.is_empty() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at least one parameter",
+            display_name
+        )));
+    }
+
+    let levels = params
+        .iter()
+        .map(|v| v.as_f64())
+        .collect::<Result<Vec<_>>>()?;
+    try_create_quantile(display_name, levels, true, arguments)
+}
@@ -29,7 +29,7 @@ use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 use crate::with_match_primitive_type;
 
-struct AggregateStddevPopState {
+pub(crate) struct AggregateStddevPopState {
     pub sum: f64,
     pub count: u64,
     pub variance: f64,
@@ -37,7 +37,7 @@ struct AggregateStddevPopState {
 
 impl AggregateStddevPopState {
     #[inline(always)]
-    fn add(&mut self, value: f64) {
+    pub(crate) fn add(&mut self, value: f64) {
         self.sum += value;
         self.count += 1;
         if self.count > 1 {
@@ -47,7 +47,7 @@ impl AggregateStddevPopState {
     }
 
     #[inline(always)]
-    fn merge(&mut self, other: &Self) {
+    pub(crate) fn merge(&mut self, other: &Self) {
         if other.count == 0 {
             return;
         }
@@ -66,6 +66,19 @@ impl AggregateStddevPopState {
         self.count += other.count;
         self.sum += other.sum;
     }
+
+    pub(crate) fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        self.sum.serialize_to_buf(writer)?;
+        self.count.serialize_to_buf(writer)?;
+        self.variance.serialize_to_buf(writer)
+    }
+
+    pub(crate) fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.sum = f64::deserialize(reader)?;
+        self.count = u64::deserialize(reader)?;
+        self.variance = f64::deserialize(reader)?;
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
@@ -157,17 +170,12 @@ where T: DFPrimitiveType + AsPrimitive<f64>
 
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
         let state = place.get::<AggregateStddevPopState>();
-        state.sum.serialize_to_buf(writer)?;
-        state.count.serialize_to_buf(writer)?;
-        state.variance.serialize_to_buf(writer)
+        state.serialize(writer)
     }
 
     fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
         let state = place.get::<AggregateStddevPopState>();
-        state.sum = f64::deserialize(reader)?;
-        state.count = u64::deserialize(reader)?;
-        state.variance = f64::deserialize(reader)?;
-        Ok(())
+        state.deserialize(reader)
     }
 
     fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
@@ -28,7 +28,9 @@ use num::traits::AsPrimitive;
 use super::AggregateFunctionRef;
 use super::StateAddr;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateDecimalSumFunction;
 use crate::aggregates::AggregateFunction;
+use crate::aggregates::DecimalSumKind;
 use crate::with_match_primitive_type;
 
 struct AggregateSumState<T> {
@@ -200,6 +202,14 @@ pub fn try_create_aggregate_sum_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if let DataType::Decimal(_, _) = data_type {
+        return AggregateDecimalSumFunction::try_create(
+            DecimalSumKind::Sum,
+            display_name,
+            arguments,
+        );
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
              display_name,
@@ -0,0 +1,258 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataGroupValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use indexmap::IndexMap;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+const TOP_K_DEFAULT: u64 = 10;
+const TOP_K_MAX: u64 = 65536;
+// Space-Saving keeps more counters than requested to make the top entries reliable.
+const TOP_K_RESERVE: usize = 3;
+
+#[derive(Clone, Copy)]
+struct Counter {
+    count: u64,
+    error: u64,
+}
+
+/// The Space-Saving sketch: at most `capacity` counters, a new value evicts the
+/// smallest counter and inherits its count as the error bound.
+struct AggregateTopKState {
+    counters: IndexMap<DataGroupValue, Counter>,
+}
+
+impl AggregateTopKState {
+    #[inline(always)]
+    fn add(&mut self, value: DataValue, capacity: usize) -> Result<()> {
+        if value.is_null() {
+            return Ok(());
+        }
+
+        let value = DataGroupValue::try_from(&value)?;
+        if let Some(counter) = self.counters.get_mut(&value) {
+            counter.count += 1;
+            return Ok(());
+        }
+
+        if self.counters.len() < capacity {
+            self.counters.insert(value, Counter { count: 1, error: 0 });
+            return Ok(());
+        }
+
+        let min = self
+            .counters
+            .values()
+            .enumerate()
+            .min_by_key(|(_, counter)| counter.count)
+            .map(|(index, counter)| (index, counter.count));
+        if let Some((index, count)) = min {
+            self.counters.swap_remove_index(index);
+            self.counters.insert(value, Counter {
+                count: count + 1,
+                error: count,
+            });
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self, capacity: usize) {
+        for (value, counter) in other.counters.iter() {
+            match self.counters.get_mut(value) {
+                Some(current) => {
+                    current.count += counter.count;
+                    current.error += counter.error;
+                }
+                None => {
+                    self.counters.insert(value.clone(), *counter);
+                }
+            }
+        }
+
+        if self.counters.len() > capacity {
+            self.counters.sort_by(|_, a, _, b| b.count.cmp(&a.count));
+            self.counters.truncate(capacity);
+        }
+    }
+}
+
+/// topK(k)(x) returns an array of the approximately most frequent values.
+#[derive(Clone)]
+pub struct AggregateTopKFunction {
+    display_name: String,
+    arguments: Vec<DataField>,
+    k: usize,
+}
+
+impl AggregateTopKFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        let k = match params.len() {
+            0 => TOP_K_DEFAULT,
+            1 => params[0].as_u64()?,
+            n => {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "{} expect to have at most one parameter, but got {}",
+                    display_name, n
+                )));
+            }
+        };
+        if k == 0 || k > TOP_K_MAX {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} parameter k must be in the range [1, {}], but got {}",
+                display_name, TOP_K_MAX, k
+            )));
+        }
+
+        let data_type = arguments[0].data_type();
+        if !is_numeric(data_type) && !matches!(data_type, DataType::Boolean | DataType::String) {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "AggregateTopKFunction does not support type '{:?}'",
+                data_type
+            )));
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            arguments,
+            k: k as usize,
+        }))
+    }
+
+    fn capacity(&self) -> usize {
+        self.k * TOP_K_RESERVE
+    }
+}
+
+impl AggregateFunction for AggregateTopKFunction {
+    fn name(&self) -> &str {
+        "AggregateTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        let item = DataField::new("item", self.arguments[0].data_type().clone(), true);
+        Ok(DataType::List(Box::new(item)))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateTopKState {
+            counters: IndexMap::new(),
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateTopKState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        for row in 0..input_rows {
+            state.add(arrays[0].try_get(row)?, self.capacity())?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateTopKState>();
+            state.add(arrays[0].try_get(row)?, self.capacity())?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        writer.write_uvarint(state.counters.len() as u64)?;
+        for (value, counter) in state.counters.iter() {
+            DataValue::from(value).serialize_to_buf(writer)?;
+            counter.count.serialize_to_buf(writer)?;
+            counter.error.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        let size = reader.read_uvarint()?;
+        state.counters = IndexMap::with_capacity(size as usize);
+        for _i in 0..size {
+            let value = DataValue::deserialize(reader)?;
+            let count = u64::deserialize(reader)?;
+            let error = u64::deserialize(reader)?;
+            state
+                .counters
+                .insert(DataGroupValue::try_from(&value)?, Counter { count, error });
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        let rhs = rhs.get::<AggregateTopKState>();
+        state.merge(rhs, self.capacity());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateTopKState>();
+        let mut counters = state.counters.iter().collect::<Vec<_>>();
+        // Stable sort, ties keep the order values were first seen in.
+        counters.sort_by(|(_, a), (_, b)| b.count.cmp(&a.count));
+
+        let values = counters
+            .into_iter()
+            .take(self.k)
+            .map(|(value, _)| DataValue::from(value))
+            .collect();
+        Ok(DataValue::List(
+            Some(values),
+            self.arguments[0].data_type().clone(),
+        ))
+    }
+}
+
+impl fmt::Display for AggregateTopKFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
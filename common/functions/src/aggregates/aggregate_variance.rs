@@ -0,0 +1,179 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_stddev_pop::AggregateStddevPopState;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::with_match_primitive_type;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VarianceKind {
+    VarPop,
+    VarSamp,
+    StddevSamp,
+}
+
+/// var_pop, var_samp and stddev_samp, sharing the state of stddev_pop.
+#[derive(Clone)]
+pub struct AggregateVarianceFunction<T> {
+    display_name: String,
+    kind: VarianceKind,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateVarianceFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateVarianceFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateStddevPopState {
+            sum: 0.0,
+            count: 0,
+            variance: 0.0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateStddevPopState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateStddevPopState>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+
+        if array.null_count() == 0 {
+            for value in array.into_no_null_iter() {
+                state.add(value.as_());
+            }
+        } else {
+            for value in array.iter().flatten() {
+                state.add(value.as_());
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                place.get::<AggregateStddevPopState>().add(value.as_());
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateStddevPopState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateStddevPopState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateStddevPopState>();
+        let rhs = rhs.get::<AggregateStddevPopState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateStddevPopState>();
+        let variance = match self.kind {
+            VarianceKind::VarPop if state.count > 0 => state.variance / state.count as f64,
+            VarianceKind::VarSamp | VarianceKind::StddevSamp if state.count > 1 => {
+                state.variance / (state.count - 1) as f64
+            }
+            _ => return Ok(DataValue::Float64(None)),
+        };
+
+        match self.kind {
+            VarianceKind::StddevSamp => Ok(DataValue::Float64(Some(variance.sqrt()))),
+            _ => Ok(DataValue::Float64(Some(variance))),
+        }
+    }
+}
+
+impl<T> fmt::Display for AggregateVarianceFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateVarianceFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(display_name: &str, kind: VarianceKind) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            kind,
+            t: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_variance_function(
+    kind: VarianceKind,
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type!(data_type, |$T| {
+        AggregateVarianceFunction::<$T>::try_create(display_name, kind)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateVarianceFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
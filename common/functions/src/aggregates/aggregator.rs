@@ -17,14 +17,26 @@ use common_exception::Result;
 use super::aggregate_arg_min_max::try_create_aggregate_arg_minmax_function;
 use super::aggregate_avg::try_create_aggregate_avg_function;
 use super::aggregate_min_max::try_create_aggregate_minmax_function;
+use super::aggregate_quantile::try_create_aggregate_median_function;
+use super::aggregate_quantile::try_create_aggregate_quantile_function;
+use super::aggregate_quantile::try_create_aggregate_quantiles_function;
 use super::aggregate_stddev_pop::try_create_aggregate_stddev_pop_function;
 use super::aggregate_sum::try_create_aggregate_sum_function;
+use super::aggregate_variance::try_create_aggregate_variance_function;
 use super::aggregate_window_funnel::try_create_aggregate_window_funnel_function;
 use crate::aggregates::aggregate_function_factory::FactoryCombinatorFuncRef;
 use crate::aggregates::aggregate_function_factory::FactoryFuncRef;
+use crate::aggregates::AggregateAnyFunction;
+use crate::aggregates::AggregateApproxCountDistinctFunction;
 use crate::aggregates::AggregateCountFunction;
+use crate::aggregates::AggregateCovarianceFunction;
 use crate::aggregates::AggregateDistinctCombinator;
+use crate::aggregates::AggregateGroupArrayFunction;
+use crate::aggregates::AggregateGroupUniqArrayFunction;
 use crate::aggregates::AggregateIfCombinator;
+use crate::aggregates::AggregateTopKFunction;
+use crate::aggregates::CovarianceKind;
+use crate::aggregates::VarianceKind;
 
 pub struct Aggregators;
 
@@ -60,7 +72,72 @@ impl Aggregators {
             try_create_aggregate_window_funnel_function,
         );
 
+        map.insert("var_pop".into(), |display_name, params, arguments| {
+            try_create_aggregate_variance_function(
+                VarianceKind::VarPop,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("var_samp".into(), |display_name, params, arguments| {
+            try_create_aggregate_variance_function(
+                VarianceKind::VarSamp,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("stddev_samp".into(), |display_name, params, arguments| {
+            try_create_aggregate_variance_function(
+                VarianceKind::StddevSamp,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("covar_pop".into(), |display_name, params, arguments| {
+            AggregateCovarianceFunction::try_create(
+                CovarianceKind::CovarPop,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("covar_samp".into(), |display_name, params, arguments| {
+            AggregateCovarianceFunction::try_create(
+                CovarianceKind::CovarSamp,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("corr".into(), |display_name, params, arguments| {
+            AggregateCovarianceFunction::try_create(
+                CovarianceKind::Corr,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+
+        map.insert("quantile".into(), try_create_aggregate_quantile_function);
+        map.insert("median".into(), try_create_aggregate_median_function);
+        map.insert("quantiles".into(), try_create_aggregate_quantiles_function);
+
         map.insert("uniq".into(), AggregateDistinctCombinator::try_create_uniq);
+        map.insert(
+            "approx_count_distinct".into(),
+            AggregateApproxCountDistinctFunction::try_create,
+        );
+        map.insert("any".into(), AggregateAnyFunction::try_create_any);
+        map.insert("anyLast".into(), AggregateAnyFunction::try_create_any_last);
+        map.insert("groupArray".into(), AggregateGroupArrayFunction::try_create);
+        map.insert(
+            "groupUniqArray".into(),
+            AggregateGroupUniqArrayFunction::try_create,
+        );
+        map.insert("topK".into(), AggregateTopKFunction::try_create);
         Ok(())
     }
 
@@ -68,6 +68,9 @@ impl Function for ArithmeticFunction {
         if is_interval(&args[0]) || is_interval(&args[1]) {
             return interval_arithmetic_coercion(&self.op, &args[0], &args[1]);
         }
+        if is_decimal(&args[0]) || is_decimal(&args[1]) {
+            return decimal_arithmetic_coercion(&self.op, &args[0], &args[1]);
+        }
         if is_date_or_date_time(&args[0]) || is_date_or_date_time(&args[1]) {
             return datetime_arithmetic_coercion(&self.op, &args[0], &args[1]);
         }
@@ -83,6 +86,9 @@ impl Function for ArithmeticFunction {
             // Some logic type need DateType information, try arithmetic on column with field first.
             if let Some(f) = IntervalFunctionFactory::try_get_arithmetic_func(columns) {
                 f(&self.op, &columns[0], &columns[1])?
+            } else if columns.len() == 2 && columns.iter().any(|c| is_decimal(c.data_type())) {
+                // Decimal need the scale of both sides.
+                columns[0].decimal_arithmetic(self.op.clone(), &columns[1])?
             } else {
                 match columns.len() {
                     1 => columns[0].column().unary_arithmetic(self.op.clone()),
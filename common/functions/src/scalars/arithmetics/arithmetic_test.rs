@@ -456,3 +456,95 @@ fn test_arithmetic_date_interval() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_decimal() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        arg_names: Vec<&'static str>,
+        func: Box<dyn Function>,
+        expect_type: DataType,
+        expect: DataColumn,
+    }
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("price", DataType::Decimal(10, 2), false),
+        DataField::new("discount", DataType::Decimal(10, 1), false),
+        DataField::new("qty", DataType::Int32, false),
+        DataField::new("rate", DataType::Float64, false),
+    ]);
+
+    // price: [1.50, 2.25], discount: [0.3, 1.0], qty: [3, 2], rate: [0.5, 0.25]
+    let columns: Vec<DataColumn> = vec![
+        Series::new(vec![150i64, 225]).into(),
+        Series::new(vec![3i64, 10]).into(),
+        Series::new(vec![3i32, 2]).into(),
+        Series::new(vec![0.5f64, 0.25]).into(),
+    ];
+
+    let tests = vec![
+        Test {
+            name: "decimal-plus-decimal-passed",
+            arg_names: vec!["price", "discount"],
+            func: ArithmeticPlusFunction::try_create_func("")?,
+            expect_type: DataType::Decimal(12, 2),
+            expect: Series::new(vec![180i64, 325]).into(),
+        },
+        Test {
+            name: "decimal-minus-decimal-passed",
+            arg_names: vec!["price", "discount"],
+            func: ArithmeticMinusFunction::try_create_func("")?,
+            expect_type: DataType::Decimal(12, 2),
+            expect: Series::new(vec![120i64, 125]).into(),
+        },
+        Test {
+            name: "decimal-mul-int-passed",
+            arg_names: vec!["price", "qty"],
+            func: ArithmeticMulFunction::try_create_func("")?,
+            expect_type: DataType::Decimal(18, 2),
+            expect: Series::new(vec![450i64, 450]).into(),
+        },
+        Test {
+            name: "decimal-div-int-passed",
+            arg_names: vec!["price", "qty"],
+            func: ArithmeticDivFunction::try_create_func("")?,
+            expect_type: DataType::Decimal(18, 2),
+            expect: Series::new(vec![50i64, 113]).into(),
+        },
+        Test {
+            name: "decimal-plus-float-passed",
+            arg_names: vec!["price", "rate"],
+            func: ArithmeticPlusFunction::try_create_func("")?,
+            expect_type: DataType::Float64,
+            expect: Series::new(vec![2.0f64, 2.5]).into(),
+        },
+        Test {
+            name: "negate-decimal-passed",
+            arg_names: vec!["price"],
+            func: ArithmeticMinusFunction::try_create_func("")?,
+            expect_type: DataType::Decimal(10, 2),
+            expect: Series::new(vec![-150i64, -225]).into(),
+        },
+    ];
+
+    for t in tests {
+        let mut args = vec![];
+        let mut arg_columns = vec![];
+        for name in t.arg_names {
+            let field = schema.field_with_name(name)?;
+            let index = schema.index_of(name)?;
+            args.push(field.data_type().clone());
+            arg_columns.push(DataColumnWithField::new(
+                columns[index].clone(),
+                field.clone(),
+            ));
+        }
+
+        let func = t.func;
+        assert_eq!(t.expect_type, func.return_type(&args)?, "{}", t.name);
+
+        let v = &(func.eval(&arg_columns, 2)?);
+        assert_eq!(v, &t.expect, "{}", t.name);
+    }
+    Ok(())
+}
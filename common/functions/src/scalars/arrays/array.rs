@@ -0,0 +1,49 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::scalars::ArrayCreateFunction;
+use crate::scalars::ArrayElementFunction;
+use crate::scalars::ArrayHasFunction;
+use crate::scalars::ArrayJoinFunction;
+use crate::scalars::ArrayLengthFunction;
+use crate::scalars::ArrayMapFunction;
+use crate::scalars::FactoryFuncRef;
+use crate::scalars::LambdaFunction;
+use crate::scalars::MapCreateFunction;
+use crate::scalars::MapEntriesFunction;
+
+#[derive(Clone)]
+pub struct ArrayFunction;
+
+impl ArrayFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("array".into(), ArrayCreateFunction::try_create);
+        map.insert("arrayElement".into(), ArrayElementFunction::try_create);
+        map.insert("length".into(), ArrayLengthFunction::try_create);
+        map.insert("has".into(), ArrayHasFunction::try_create);
+        map.insert("arrayJoin".into(), ArrayJoinFunction::try_create);
+        map.insert("unnest".into(), ArrayJoinFunction::try_create);
+        map.insert("arrayMap".into(), ArrayMapFunction::try_create);
+        map.insert("arrayFilter".into(), ArrayMapFunction::try_create_filter);
+        map.insert("lambda".into(), LambdaFunction::try_create);
+        map.insert("map".into(), MapCreateFunction::try_create);
+        map.insert("mapKeys".into(), MapEntriesFunction::try_create_keys);
+        map.insert("mapValues".into(), MapEntriesFunction::try_create_values);
+
+        Ok(())
+    }
+}
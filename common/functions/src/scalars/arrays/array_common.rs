@@ -0,0 +1,73 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The type of the items in the lists of `data_type`.
+pub fn list_item_type(display_name: &str, data_type: &DataType) -> Result<DataType> {
+    match data_type {
+        DataType::List(field) => Ok(field.data_type().clone()),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Expected an array for {}, but got {:?}",
+            display_name, other
+        ))),
+    }
+}
+
+/// The list type whose items are nullable values of `data_type`.
+pub fn list_type(data_type: DataType) -> DataType {
+    DataType::List(Box::new(DataField::new("item", data_type, true)))
+}
+
+/// Whether the lists are valid, None if there are no null lists.
+pub fn list_validity(list: &DFListArray) -> Option<Vec<bool>> {
+    match list.null_count() {
+        0 => None,
+        _ => Some((0..list.len()).map(|row| !list.is_null(row)).collect()),
+    }
+}
+
+/// Interleaves the columns into the flattened items of the lists, the i-th list has the i-th
+/// values of all columns casted to `data_type`.
+pub fn interleave_columns(
+    columns: &[DataColumn],
+    data_type: &DataType,
+    input_rows: usize,
+) -> Result<(Series, Vec<i64>)> {
+    let size = columns.len();
+    let offsets = (0..=input_rows).map(|row| (row * size) as i64).collect();
+    if size == 0 {
+        return Ok((DataValue::Null.to_series_with_size(0)?, offsets));
+    }
+
+    let physical_type = data_type_physical(data_type.clone());
+    let columns = columns
+        .iter()
+        .map(|column| {
+            let column = column.resize_constant(input_rows);
+            match column.data_type() == physical_type {
+                true => Ok(column),
+                false => column.cast_with_type(data_type),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let values = DataColumnCommon::concat(&columns)?.to_array()?;
+    let indices = (0..input_rows)
+        .flat_map(|row| (0..size).map(move |i| i * input_rows + row))
+        .collect::<Vec<_>>();
+    let values = unsafe { values.take_iter_unchecked(&mut indices.into_iter())? };
+    Ok((values, offsets))
+}
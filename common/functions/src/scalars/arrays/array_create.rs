@@ -0,0 +1,81 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::interleave_columns;
+use crate::scalars::list_item_type;
+use crate::scalars::list_type;
+use crate::scalars::Function;
+
+/// Creates an array from the arguments, the type of the items is the common type of the
+/// arguments, `[a, b]` is the same as `array(a, b)`.
+#[derive(Clone)]
+pub struct ArrayCreateFunction {
+    display_name: String,
+}
+
+impl ArrayCreateFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayCreateFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayCreateFunction {
+    fn name(&self) -> &str {
+        "ArrayCreateFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match args.is_empty() {
+            true => Ok(list_type(DataType::Null)),
+            false => Ok(list_type(aggregate_types(args)?)),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let arg_types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let item_type = list_item_type(&self.display_name, &self.return_type(&arg_types)?)?;
+
+        let columns = columns
+            .iter()
+            .map(|c| c.column().clone())
+            .collect::<Vec<_>>();
+        let (values, offsets) = interleave_columns(&columns, &item_type, input_rows)?;
+        let array = DFListArray::from_flatten(&values, offsets, None);
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for ArrayCreateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
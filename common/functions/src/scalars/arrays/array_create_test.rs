@@ -0,0 +1,150 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::list_type;
+use crate::scalars::ArrayCreateFunction;
+use crate::scalars::ArrayElementFunction;
+use crate::scalars::ArrayHasFunction;
+use crate::scalars::ArrayJoinFunction;
+use crate::scalars::ArrayLengthFunction;
+
+fn int_list(values: &[i64]) -> DataValue {
+    let values = values.iter().map(|v| DataValue::Int64(Some(*v))).collect();
+    DataValue::List(Some(values), DataType::Int64)
+}
+
+#[test]
+fn test_array_create_function() -> Result<()> {
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec![1i64, 2, 3]).into(),
+            DataField::new("a", DataType::Int64, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int8(Some(9)), 3),
+            DataField::new("b", DataType::Int8, false),
+        ),
+    ];
+
+    let func = ArrayCreateFunction::try_create("array")?;
+    assert_eq!(func.to_string(), "array");
+    assert_eq!(
+        func.return_type(&[DataType::Int64, DataType::Int8])?,
+        list_type(DataType::Int64)
+    );
+    assert_eq!(func.return_type(&[])?, list_type(DataType::Null));
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.try_get(0)?, int_list(&[1, 9]));
+    assert_eq!(result.try_get(2)?, int_list(&[3, 9]));
+    Ok(())
+}
+
+#[test]
+fn test_array_element_length_has_function() -> Result<()> {
+    let lists = DataValue::try_into_data_array(
+        &[int_list(&[1, 2, 3]), int_list(&[]), DataValue::Null],
+        &list_type(DataType::Int64),
+    )?;
+    let list_column = DataColumnWithField::new(
+        lists.into(),
+        DataField::new("l", list_type(DataType::Int64), true),
+    );
+
+    let func = ArrayElementFunction::try_create("arrayElement")?;
+    assert_eq!(
+        func.return_type(&[list_type(DataType::Int64), DataType::Int32])?,
+        DataType::Int64
+    );
+    assert!(func
+        .return_type(&[list_type(DataType::Int64), DataType::String])
+        .is_err());
+    for (index, expect) in [
+        (1i32, vec![
+            DataValue::Int64(Some(1)),
+            DataValue::Int64(None),
+        ]),
+        (-1, vec![DataValue::Int64(Some(3)), DataValue::Int64(None)]),
+        (4, vec![DataValue::Int64(None), DataValue::Int64(None)]),
+    ] {
+        let columns = vec![
+            list_column.clone(),
+            DataColumnWithField::new(
+                DataColumn::Constant(DataValue::Int32(Some(index)), 3),
+                DataField::new("i", DataType::Int32, false),
+            ),
+        ];
+        let result = func.eval(&columns, 3)?.to_array()?;
+        assert_eq!(result.try_get(0)?, expect[0], "index: {}", index);
+        assert_eq!(result.try_get(1)?, expect[1], "index: {}", index);
+        assert!(result.is_null(2));
+    }
+
+    let func = ArrayLengthFunction::try_create("length")?;
+    let result = func.eval(&[list_column.clone()], 3)?.to_array()?;
+    assert_eq!(result.try_get(0)?, DataValue::UInt64(Some(3)));
+    assert_eq!(result.try_get(1)?, DataValue::UInt64(Some(0)));
+    assert!(result.is_null(2));
+
+    let func = ArrayHasFunction::try_create("has")?;
+    assert_eq!(
+        func.return_type(&[list_type(DataType::Int64), DataType::UInt8])?,
+        DataType::Boolean
+    );
+    let columns = vec![
+        list_column,
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::UInt8(Some(2)), 3),
+            DataField::new("v", DataType::UInt8, false),
+        ),
+    ];
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.try_get(0)?, DataValue::Boolean(Some(true)));
+    assert_eq!(result.try_get(1)?, DataValue::Boolean(Some(false)));
+    Ok(())
+}
+
+#[test]
+fn test_array_join_function() -> Result<()> {
+    let lists = DataValue::try_into_data_array(
+        &[
+            int_list(&[1, 2]),
+            int_list(&[]),
+            DataValue::Null,
+            int_list(&[3]),
+        ],
+        &list_type(DataType::Int64),
+    )?;
+    let list_column = DataColumnWithField::new(
+        lists.into(),
+        DataField::new("l", list_type(DataType::Int64), true),
+    );
+
+    let func = ArrayJoinFunction::try_create("arrayJoin")?;
+    // the number of rows changes, so a constant argument must not be folded
+    assert!(!func.is_deterministic());
+    assert_eq!(
+        func.return_type(&[list_type(DataType::Int64)])?,
+        DataType::Int64
+    );
+
+    let result = func.eval(&[list_column], 4)?.to_array()?;
+    assert_eq!(result.len(), 3);
+    assert_eq!(result.try_get(0)?, DataValue::Int64(Some(1)));
+    assert_eq!(result.try_get(2)?, DataValue::Int64(Some(3)));
+    Ok(())
+}
@@ -0,0 +1,128 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Gets the item of an array by the 1-based index, a negative index counts from the end,
+/// NULL is returned if the index is out of range. For a map it gets the value of the key,
+/// NULL is returned if the key does not exist. `arr[i]` is the same as `arrayElement(arr, i)`.
+#[derive(Clone)]
+pub struct ArrayElementFunction {
+    display_name: String,
+}
+
+impl ArrayElementFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayElementFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    fn take_list_items(&self, list: &DFListArray, index: &Series) -> Result<Series> {
+        let index = index.cast_with_type(&DataType::Int64)?;
+        let (values, offsets) = list.flatten();
+
+        let indices = index
+            .i64()?
+            .into_iter()
+            .enumerate()
+            .map(|(row, i)| {
+                let (start, end) = (offsets[row], offsets[row + 1]);
+                match i {
+                    _ if list.is_null(row) => None,
+                    Some(i) if *i > 0 && *i <= end - start => Some((start + i - 1) as usize),
+                    Some(i) if *i < 0 && -*i <= end - start => Some((end + i) as usize),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        unsafe { values.take_opt_iter_unchecked(&mut indices.into_iter()) }
+    }
+
+    fn take_map_values(
+        &self,
+        map: &DFListArray,
+        key_type: &DataType,
+        key: &Series,
+    ) -> Result<Series> {
+        let key = key.cast_with_type(key_type)?;
+        let (entries, offsets) = map.flatten();
+        let entries = DFStructArray::from_arrow_array(entries.get_array_ref().as_ref());
+        let keys = entries.inner().values()[0].clone().into_series();
+        let values = entries.inner().values()[1].clone().into_series();
+
+        let mut indices = Vec::with_capacity(map.len());
+        for row in 0..map.len() {
+            let mut index = None;
+            if !map.is_null(row) {
+                let key = key.try_get(row)?;
+                for i in offsets[row] as usize..offsets[row + 1] as usize {
+                    if keys.try_get(i)? == key {
+                        index = Some(i);
+                        break;
+                    }
+                }
+            }
+            indices.push(index);
+        }
+        unsafe { values.take_opt_iter_unchecked(&mut indices.into_iter()) }
+    }
+}
+
+impl Function for ArrayElementFunction {
+    fn name(&self) -> &str {
+        "ArrayElementFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match (&args[0], &args[1]) {
+            (DataType::List(field), index) if is_integer(index) => Ok(field.data_type().clone()),
+            (DataType::Map(_, value), _) => Ok(value.as_ref().clone()),
+            (arg, index) => Err(ErrorCode::IllegalDataType(format!(
+                "Expected an array and an integer index or a map and a key for {}, but got {:?} and {:?}",
+                self.display_name, arg, index
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let array = columns[0].column().to_array()?;
+        let key = columns[1].column().to_array()?;
+        let result = match columns[0].data_type() {
+            DataType::Map(key_type, _) => self.take_map_values(array.list()?, key_type, &key)?,
+            _ => self.take_list_items(array.list()?, &key)?,
+        };
+        Ok(result.into())
+    }
+}
+
+impl fmt::Display for ArrayElementFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Whether the array contains the value, a NULL array contains nothing.
+#[derive(Clone)]
+pub struct ArrayHasFunction {
+    display_name: String,
+}
+
+impl ArrayHasFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayHasFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayHasFunction {
+    fn name(&self) -> &str {
+        "ArrayHasFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(field) => {
+                equal_coercion(field.data_type(), &args[1])?;
+                Ok(DataType::Boolean)
+            }
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected an array for {}, but got {:?}",
+                self.display_name, other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let array = columns[0].column().to_array()?;
+        let list = array.list()?;
+        let (items, offsets) = list.flatten();
+
+        // compare the items and the values in their common type
+        let item_type = match columns[0].data_type() {
+            DataType::List(field) => field.data_type().clone(),
+            other => other.clone(),
+        };
+        let common_type = equal_coercion(&item_type, columns[1].data_type())?;
+        let physical_type = data_type_physical(common_type.clone());
+        let items = match items.data_type() == &physical_type {
+            true => items,
+            false => items.cast_with_type(&common_type)?,
+        };
+        let values = match columns[1].column().data_type() == physical_type {
+            true => columns[1].column().clone(),
+            false => columns[1].column().cast_with_type(&common_type)?,
+        };
+
+        let mut result = Vec::with_capacity(list.len());
+        for row in 0..list.len() {
+            let value = values.try_get(row)?;
+            let mut found = false;
+            for i in offsets[row] as usize..offsets[row + 1] as usize {
+                if items.try_get(i)? == value {
+                    found = true;
+                    break;
+                }
+            }
+            result.push(found);
+        }
+        Ok(DFBooleanArray::new_from_slice(&result).into_series().into())
+    }
+}
+
+impl fmt::Display for ArrayHasFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
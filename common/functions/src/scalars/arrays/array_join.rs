@@ -0,0 +1,102 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::list_item_type;
+use crate::scalars::Function;
+
+/// Unfolds the arrays into rows, a row with an empty or NULL array is removed. The function
+/// only returns the flattened items of the arrays, the other columns of the block are
+/// replicated by the expression executor according to the lengths of the arrays.
+#[derive(Clone)]
+pub struct ArrayJoinFunction {
+    display_name: String,
+}
+
+impl ArrayJoinFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayJoinFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    /// Whether the function is arrayJoin or its alias, which changes the number of rows.
+    pub fn is_array_join(name: &str) -> bool {
+        name.eq_ignore_ascii_case("arrayJoin") || name.eq_ignore_ascii_case("unnest")
+    }
+
+    /// The source row of each unfolded row.
+    pub fn unfolded_rows(list: &DFListArray) -> Vec<usize> {
+        let (_, offsets) = list.flatten();
+        let mut rows = Vec::with_capacity(offsets[offsets.len() - 1] as usize);
+        for row in 0..list.len() {
+            if !list.is_null(row) {
+                let length = (offsets[row + 1] - offsets[row]) as usize;
+                rows.extend(std::iter::repeat(row).take(length));
+            }
+        }
+        rows
+    }
+}
+
+impl Function for ArrayJoinFunction {
+    fn name(&self) -> &str {
+        "ArrayJoinFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        list_item_type(&self.display_name, &args[0])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let array = columns[0].column().to_array()?;
+        let list = array.list()?;
+        let (values, offsets) = list.flatten();
+        if list.null_count() == 0 {
+            return Ok(values.into());
+        }
+
+        // skip the items of the NULL arrays
+        let indices = (0..list.len())
+            .filter(|row| !list.is_null(*row))
+            .flat_map(|row| offsets[row] as usize..offsets[row + 1] as usize)
+            .collect::<Vec<_>>();
+        let values = unsafe { values.take_iter_unchecked(&mut indices.into_iter())? };
+        Ok(values.into())
+    }
+
+    // It changes the number of rows, so it must not be folded into a constant even if its
+    // argument is a constant.
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for ArrayJoinFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// The number of the items in an array or a map, or the number of bytes in a string.
+#[derive(Clone)]
+pub struct ArrayLengthFunction {
+    display_name: String,
+}
+
+impl ArrayLengthFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayLengthFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayLengthFunction {
+    fn name(&self) -> &str {
+        "ArrayLengthFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(_) | DataType::Map(_, _) | DataType::String | DataType::Null => {
+                Ok(DataType::UInt64)
+            }
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected an array, a map or a string for {}, but got {:?}",
+                self.display_name, other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let array = columns[0].column().to_minimal_array()?;
+        let lengths: DFUInt64Array = match columns[0].data_type() {
+            DataType::Null => DFUInt64Array::full_null(array.len()),
+            DataType::String => array
+                .string()?
+                .into_iter()
+                .map(|v| v.map(|v| v.len() as u64))
+                .collect(),
+            _ => {
+                let list = array.list()?;
+                let (_, offsets) = list.flatten();
+                (0..list.len())
+                    .map(|row| match list.is_null(row) {
+                        true => None,
+                        false => Some((offsets[row + 1] - offsets[row]) as u64),
+                    })
+                    .collect()
+            }
+        };
+        let column: DataColumn = lengths.into_series().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArrayLengthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
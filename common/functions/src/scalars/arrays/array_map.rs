@@ -0,0 +1,124 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::list_type;
+use crate::scalars::list_validity;
+use crate::scalars::Function;
+
+/// The higher-order functions `arrayMap(x -> expr, arr, ...)` and `arrayFilter(x -> cond, arr, ...)`.
+/// The lambda is evaluated by the expression executor over the flattened items of the arrays,
+/// so the first column passed to `eval` is the results of the lambda, which has one value for
+/// each item of the arrays rather than one for each row.
+#[derive(Clone)]
+pub struct ArrayMapFunction {
+    display_name: String,
+    filter: bool,
+}
+
+impl ArrayMapFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayMapFunction {
+            display_name: display_name.to_string(),
+            filter: false,
+        }))
+    }
+
+    pub fn try_create_filter(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayMapFunction {
+            display_name: display_name.to_string(),
+            filter: true,
+        }))
+    }
+}
+
+impl Function for ArrayMapFunction {
+    fn name(&self) -> &str {
+        "ArrayMapFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if let Some(arg) = args[1..]
+            .iter()
+            .find(|arg| !matches!(arg, DataType::List(_)))
+        {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected arrays for {}, but got {:?}",
+                self.display_name, arg
+            )));
+        }
+
+        match (self.filter, &args[0]) {
+            (false, result_type) => Ok(list_type(result_type.clone())),
+            (true, DataType::Boolean) => Ok(args[1].clone()),
+            (true, other) => Err(ErrorCode::IllegalDataType(format!(
+                "Expected a lambda which returns Boolean for {}, but got {:?}",
+                self.display_name, other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let results = columns[0].column().to_array()?;
+        let array = columns[1].column().to_array()?;
+        let list = array.list()?;
+        let (values, offsets) = list.flatten();
+        if results.len() != values.len() {
+            return Err(ErrorCode::LogicalError(format!(
+                "The lambda of {} must return one value for each item of the arrays",
+                self.display_name
+            )));
+        }
+
+        if !self.filter {
+            let array = DFListArray::from_flatten(&results, offsets, list_validity(list));
+            return Ok(array.into_series().into());
+        }
+
+        let predicate = results.bool()?;
+        let mut indices = Vec::with_capacity(values.len());
+        let mut filtered_offsets = Vec::with_capacity(offsets.len());
+        filtered_offsets.push(0);
+        for row in 0..list.len() {
+            for i in offsets[row] as usize..offsets[row + 1] as usize {
+                if predicate.inner().value(i) && predicate.inner().is_valid(i) {
+                    indices.push(i);
+                }
+            }
+            filtered_offsets.push(indices.len() as i64);
+        }
+        let values = unsafe { values.take_iter_unchecked(&mut indices.into_iter())? };
+        let array = DFListArray::from_flatten(&values, filtered_offsets, list_validity(list));
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for ArrayMapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::list_type;
+use crate::scalars::ArrayJoinFunction;
+use crate::scalars::ArrayMapFunction;
+
+fn int_list(values: &[i64]) -> DataValue {
+    let values = values.iter().map(|v| DataValue::Int64(Some(*v))).collect();
+    DataValue::List(Some(values), DataType::Int64)
+}
+
+#[test]
+fn test_array_map_filter_function() -> Result<()> {
+    let lists = DataValue::try_into_data_array(
+        &[int_list(&[1, 2, 3]), DataValue::Null, int_list(&[4])],
+        &list_type(DataType::Int64),
+    )?;
+    let list_column = DataColumnWithField::new(
+        lists.into(),
+        DataField::new("l", list_type(DataType::Int64), true),
+    );
+
+    // the results of `x -> x * 10` over the flattened items
+    let mapped = DataColumnWithField::new(
+        Series::new(vec![10i64, 20, 30, 40]).into(),
+        DataField::new("lambda", DataType::Int64, false),
+    );
+    let func = ArrayMapFunction::try_create("arrayMap")?;
+    assert_eq!(
+        func.return_type(&[DataType::Int64, list_type(DataType::Int64)])?,
+        list_type(DataType::Int64)
+    );
+    assert!(func
+        .return_type(&[DataType::Int64, DataType::Int64])
+        .is_err());
+    let result = func.eval(&[mapped, list_column.clone()], 3)?.to_array()?;
+    assert_eq!(result.try_get(0)?, int_list(&[10, 20, 30]));
+    assert!(result.is_null(1));
+    assert_eq!(result.try_get(2)?, int_list(&[40]));
+
+    // the results of `x -> x % 2 = 0` over the flattened items
+    let predicate = DataColumnWithField::new(
+        Series::new(vec![false, true, false, true]).into(),
+        DataField::new("lambda", DataType::Boolean, false),
+    );
+    let func = ArrayMapFunction::try_create_filter("arrayFilter")?;
+    assert_eq!(
+        func.return_type(&[DataType::Boolean, list_type(DataType::Int64)])?,
+        list_type(DataType::Int64)
+    );
+    assert!(func
+        .return_type(&[DataType::Int64, list_type(DataType::Int64)])
+        .is_err());
+    let result = func.eval(&[predicate, list_column], 3)?.to_array()?;
+    assert_eq!(result.try_get(0)?, int_list(&[2]));
+    assert!(result.is_null(1));
+    assert_eq!(result.try_get(2)?, int_list(&[4]));
+    Ok(())
+}
+
+#[test]
+fn test_array_join_function() -> Result<()> {
+    let lists = DataValue::try_into_data_array(
+        &[
+            int_list(&[1, 2]),
+            DataValue::Null,
+            int_list(&[]),
+            int_list(&[3]),
+        ],
+        &list_type(DataType::Int64),
+    )?;
+    assert_eq!(ArrayJoinFunction::unfolded_rows(lists.list()?), vec![
+        0, 0, 3
+    ]);
+    assert!(ArrayJoinFunction::is_array_join("UNNEST"));
+
+    let func = ArrayJoinFunction::try_create("arrayJoin")?;
+    assert_eq!(
+        func.return_type(&[list_type(DataType::Int64)])?,
+        DataType::Int64
+    );
+    let columns = vec![DataColumnWithField::new(
+        lists.into(),
+        DataField::new("l", list_type(DataType::Int64), true),
+    )];
+    let result = func.eval(&columns, 4)?.to_array()?;
+    assert_eq!(result.len(), 3);
+    assert_eq!(result.try_get(2)?, DataValue::Int64(Some(3)));
+    Ok(())
+}
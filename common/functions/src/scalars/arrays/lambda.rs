@@ -0,0 +1,72 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// The lambda `(x, y) -> expr`, parsed as `lambda('x', 'y', expr)`. It is only a marker for
+/// the planner: the argument of a higher-order function is evaluated by the expression executor,
+/// so the lambda itself can't be evaluated.
+#[derive(Clone)]
+pub struct LambdaFunction {
+    display_name: String,
+}
+
+impl LambdaFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LambdaFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    fn unsupported(&self) -> ErrorCode {
+        ErrorCode::SyntaxException(format!(
+            "The {} can only be used as the first argument of arrayMap or arrayFilter",
+            self.display_name
+        ))
+    }
+}
+
+impl Function for LambdaFunction {
+    fn name(&self) -> &str {
+        "LambdaFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Err(self.unsupported())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, _columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        Err(self.unsupported())
+    }
+}
+
+impl fmt::Display for LambdaFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
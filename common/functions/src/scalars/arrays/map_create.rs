@@ -0,0 +1,116 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::interleave_columns;
+use crate::scalars::Function;
+
+/// Creates a map from the key value pairs `map(k1, v1, k2, v2, ...)`, the type of the keys
+/// is the common type of k1, k2, ... and the type of the values is the common type of v1, v2, ...
+#[derive(Clone)]
+pub struct MapCreateFunction {
+    display_name: String,
+}
+
+impl MapCreateFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapCreateFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    fn key_value_types(&self, args: &[DataType]) -> Result<(DataType, DataType)> {
+        if args.len() % 2 != 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects key value pairs, but got {} arguments",
+                self.display_name,
+                args.len()
+            )));
+        }
+        if args.is_empty() {
+            return Ok((DataType::Null, DataType::Null));
+        }
+
+        let keys = args.iter().step_by(2).cloned().collect::<Vec<_>>();
+        let values = args.iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+        Ok((aggregate_types(&keys)?, aggregate_types(&values)?))
+    }
+}
+
+impl Function for MapCreateFunction {
+    fn name(&self) -> &str {
+        "MapCreateFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let (key_type, value_type) = self.key_value_types(args)?;
+        Ok(DataType::Map(Box::new(key_type), Box::new(value_type)))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let arg_types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let (key_type, value_type) = self.key_value_types(&arg_types)?;
+
+        let keys = columns
+            .iter()
+            .step_by(2)
+            .map(|c| c.column().clone())
+            .collect::<Vec<_>>();
+        let values = columns
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|c| c.column().clone())
+            .collect::<Vec<_>>();
+        let (keys, offsets) = interleave_columns(&keys, &key_type, input_rows)?;
+        let (values, _) = interleave_columns(&values, &value_type, input_rows)?;
+
+        let keys = keys.get_array_ref();
+        let values = values.get_array_ref();
+        let fields = vec![
+            ArrowField::new("key", keys.data_type().clone(), true),
+            ArrowField::new("value", values.data_type().clone(), true),
+        ];
+        let entries =
+            StructArray::from_data(ArrowDataType::Struct(fields), vec![keys, values], None);
+        let entries = DFStructArray::new(entries).into_series();
+        let array = DFListArray::from_flatten(&entries, offsets, None);
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for MapCreateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
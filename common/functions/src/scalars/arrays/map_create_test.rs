@@ -0,0 +1,99 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::ArrayElementFunction;
+use crate::scalars::MapCreateFunction;
+use crate::scalars::MapEntriesFunction;
+
+#[test]
+fn test_map_functions() -> Result<()> {
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec!["a", "c"]).into(),
+            DataField::new("k1", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![1u8, 3]).into(),
+            DataField::new("v1", DataType::UInt8, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 2),
+            DataField::new("k2", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![2i32, 4]).into(),
+            DataField::new("v2", DataType::Int32, false),
+        ),
+    ];
+    let map_type = DataType::Map(Box::new(DataType::String), Box::new(DataType::Int64));
+
+    let func = MapCreateFunction::try_create("map")?;
+    assert_eq!(func.to_string(), "map");
+    assert_eq!(
+        func.return_type(&[
+            DataType::String,
+            DataType::UInt8,
+            DataType::String,
+            DataType::Int32
+        ])?,
+        map_type
+    );
+    assert!(func.return_type(&[DataType::String]).is_err());
+    let map = func.eval(&columns, 2)?;
+    let map_column = DataColumnWithField::new(map, DataField::new("m", map_type.clone(), false));
+
+    let func = MapEntriesFunction::try_create_keys("mapKeys")?;
+    let result = func.eval(&[map_column.clone()], 2)?.to_array()?;
+    assert_eq!(
+        result.try_get(1)?,
+        DataValue::List(
+            Some(vec![
+                DataValue::String(Some(b"c".to_vec())),
+                DataValue::String(Some(b"b".to_vec()))
+            ]),
+            DataType::String
+        )
+    );
+
+    let func = MapEntriesFunction::try_create_values("mapValues")?;
+    let result = func.eval(&[map_column.clone()], 2)?.to_array()?;
+    assert_eq!(
+        result.try_get(0)?,
+        DataValue::List(
+            Some(vec![DataValue::Int64(Some(1)), DataValue::Int64(Some(2))]),
+            DataType::Int64
+        )
+    );
+
+    let func = ArrayElementFunction::try_create("arrayElement")?;
+    assert_eq!(
+        func.return_type(&[map_type, DataType::String])?,
+        DataType::Int64
+    );
+    let columns = vec![
+        map_column,
+        DataColumnWithField::new(
+            Series::new(vec!["b", "x"]).into(),
+            DataField::new("k", DataType::String, false),
+        ),
+    ];
+    let result = func.eval(&columns, 2)?.to_array()?;
+    assert_eq!(result.try_get(0)?, DataValue::Int64(Some(2)));
+    assert!(result.is_null(1));
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::list_type;
+use crate::scalars::list_validity;
+use crate::scalars::Function;
+
+/// `mapKeys(map)` and `mapValues(map)`, the keys or the values of a map as an array.
+#[derive(Clone)]
+pub struct MapEntriesFunction {
+    display_name: String,
+    values: bool,
+}
+
+impl MapEntriesFunction {
+    pub fn try_create_keys(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapEntriesFunction {
+            display_name: display_name.to_string(),
+            values: false,
+        }))
+    }
+
+    pub fn try_create_values(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapEntriesFunction {
+            display_name: display_name.to_string(),
+            values: true,
+        }))
+    }
+}
+
+impl Function for MapEntriesFunction {
+    fn name(&self) -> &str {
+        "MapEntriesFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::Map(_, value) if self.values => Ok(list_type(value.as_ref().clone())),
+            DataType::Map(key, _) => Ok(list_type(key.as_ref().clone())),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected a map for {}, but got {:?}",
+                self.display_name, other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let array = columns[0].column().to_array()?;
+        let map = array.list()?;
+        let (entries, offsets) = map.flatten();
+        let entries = DFStructArray::from_arrow_array(entries.get_array_ref().as_ref());
+        let index = if self.values { 1 } else { 0 };
+        let values = entries.inner().values()[index].clone().into_series();
+        let array = DFListArray::from_flatten(&values, offsets, list_validity(map));
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for MapEntriesFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -73,6 +73,21 @@ impl Function for ComparisonFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        // Variant is casted to the scalar type of the other side.
+        if columns.iter().any(|c| is_variant(c.data_type())) {
+            return columns[0].variant_compare(self.op.clone(), &columns[1]);
+        }
+
+        // Decimal need the scale of both sides.
+        if columns.iter().any(|c| is_decimal(c.data_type())) {
+            return columns[0].decimal_compare(self.op.clone(), &columns[1]);
+        }
+
+        // DateTime64 need the precision and the timezone of both sides.
+        if columns.iter().any(|c| is_datetime64(c.data_type())) {
+            return columns[0].datetime64_compare(self.op.clone(), &columns[1]);
+        }
+
         columns[0]
             .column()
             .compare(self.op.clone(), columns[1].column())
@@ -189,3 +189,52 @@ fn test_comparison_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_comparison_decimal() -> Result<()> {
+    let price = DataColumnWithField::new(
+        Series::new(vec![150i64, 225]).into(),
+        DataField::new("price", DataType::Decimal(10, 2), false),
+    );
+    let discount = DataColumnWithField::new(
+        Series::new(vec![15i64, 10]).into(),
+        DataField::new("discount", DataType::Decimal(10, 1), false),
+    );
+    let qty = DataColumnWithField::new(
+        Series::new(vec![1i32, 3]).into(),
+        DataField::new("qty", DataType::Int32, false),
+    );
+    let rate = DataColumnWithField::new(
+        Series::new(vec![1.5f64, 2.5]).into(),
+        DataField::new("rate", DataType::Float64, false),
+    );
+
+    let tests = vec![
+        (
+            "decimal-eq-decimal-passed",
+            ComparisonEqFunction::try_create_func("")?,
+            vec![price.clone(), discount],
+            vec![true, false],
+        ),
+        (
+            "decimal-gt-int-passed",
+            ComparisonGtFunction::try_create_func("")?,
+            vec![price.clone(), qty],
+            vec![true, false],
+        ),
+        (
+            "decimal-lt-float-passed",
+            ComparisonLtFunction::try_create_func("")?,
+            vec![price, rate],
+            vec![false, true],
+        ),
+    ];
+
+    for (name, func, columns, expect) in tests {
+        let v = func.eval(&columns, 2)?;
+        let actual = v.to_array()?.bool()?.collect_values();
+        let expect: Vec<Option<bool>> = expect.into_iter().map(Some).collect();
+        assert_eq!(actual, expect, "{}", name);
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// coalesce(a, b, ...) returns the first non-NULL argument, ifNull(a, b) is the
+/// two-argument form of it.
+#[derive(Clone)]
+pub struct CoalesceFunction {
+    display_name: String,
+    variadic: bool,
+}
+
+impl CoalesceFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(CoalesceFunction {
+            display_name: display_name.to_string(),
+            variadic: true,
+        }))
+    }
+
+    pub fn try_create_if_null(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(CoalesceFunction {
+            display_name: display_name.to_string(),
+            variadic: false,
+        }))
+    }
+}
+
+impl Function for CoalesceFunction {
+    fn name(&self) -> &str {
+        "CoalesceFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        match self.variadic {
+            true => Some((1, usize::MAX)),
+            false => None,
+        }
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        common_datavalues::aggregate_types(args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let mut result = columns[columns.len() - 1].column().clone();
+        for column in columns[..columns.len() - 1].iter().rev() {
+            let column = column.column();
+            result = column.is_not_null()?.if_then_else(column, &result)?;
+        }
+        Ok(result)
+    }
+}
+
+impl fmt::Display for CoalesceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
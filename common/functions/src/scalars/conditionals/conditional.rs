@@ -14,8 +14,12 @@
 
 use common_exception::Result;
 
+use crate::scalars::CoalesceFunction;
 use crate::scalars::FactoryFuncRef;
 use crate::scalars::IfFunction;
+use crate::scalars::InFunction;
+use crate::scalars::MultiIfFunction;
+use crate::scalars::NullIfFunction;
 
 #[derive(Clone)]
 pub struct ConditionalFunction;
@@ -25,6 +29,12 @@ impl ConditionalFunction {
         let mut map = map.write();
 
         map.insert("if".into(), IfFunction::try_create_func);
+        map.insert("multiif".into(), MultiIfFunction::try_create_func);
+        map.insert("coalesce".into(), CoalesceFunction::try_create_func);
+        map.insert("ifnull".into(), CoalesceFunction::try_create_if_null);
+        map.insert("nullif".into(), NullIfFunction::try_create_func);
+        map.insert("in".into(), InFunction::try_create_in);
+        map.insert("notin".into(), InFunction::try_create_not_in);
 
         Ok(())
     }
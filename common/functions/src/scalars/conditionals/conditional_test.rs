@@ -0,0 +1,180 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::*;
+
+fn column<T: DFPrimitiveType>(name: &str, values: Vec<Option<T>>) -> DataColumnWithField {
+    let values = DFPrimitiveArray::<T>::new_from_opt_iter(values.into_iter());
+    DataColumnWithField::new(
+        values.into_series().into(),
+        DataField::new(name, T::data_type(), true),
+    )
+}
+
+fn constant_column(name: &str, value: DataValue) -> DataColumnWithField {
+    let data_type = value.data_type();
+    DataColumnWithField::new(
+        DataColumn::Constant(value, 3),
+        DataField::new(name, data_type, true),
+    )
+}
+
+fn eval(func: Box<dyn Function>, columns: &[DataColumnWithField]) -> Result<Vec<DataValue>> {
+    let args = columns
+        .iter()
+        .map(|c| c.data_type().clone())
+        .collect::<Vec<_>>();
+    let data_type = func.return_type(&args)?;
+    let result = func.eval(columns, 3)?;
+    assert_eq!(result.data_type(), data_type, "{}", func);
+    (0..3).map(|row| result.try_get(row)).collect()
+}
+
+#[test]
+fn test_multi_if_function() -> Result<()> {
+    let a = column::<i32>("a", vec![Some(1), Some(2), None]);
+    let is_one = a.column().compare(
+        DataValueComparisonOperator::Eq,
+        &DataColumn::Constant(DataValue::Int32(Some(1)), 3),
+    )?;
+    let is_positive = a.column().compare(
+        DataValueComparisonOperator::Gt,
+        &DataColumn::Constant(DataValue::Int32(Some(0)), 3),
+    )?;
+    let is_one = DataColumnWithField::new(is_one, DataField::new("c1", DataType::Boolean, true));
+    let is_positive =
+        DataColumnWithField::new(is_positive, DataField::new("c2", DataType::Boolean, true));
+
+    let result = eval(MultiIfFunction::try_create_func("multiIf")?, &[
+        is_one.clone(),
+        constant_column("t1", DataValue::UInt8(Some(10))),
+        is_positive,
+        a.clone(),
+        constant_column("e", DataValue::Int64(Some(-1))),
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int64(Some(10)),
+        DataValue::Int64(Some(2)),
+        DataValue::Int64(Some(-1)),
+    ]);
+
+    // Without ELSE the result is NULL.
+    let result = eval(MultiIfFunction::try_create_func("multiIf")?, &[
+        is_one,
+        a.clone(),
+        constant_column("e", DataValue::Null),
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(1)),
+        DataValue::Int32(None),
+        DataValue::Int32(None),
+    ]);
+
+    let func = MultiIfFunction::try_create_func("multiIf")?;
+    let result = func.return_type(&[DataType::Boolean, DataType::Int32]);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Function multiIf expects an odd number of arguments, but got 2"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_coalesce_functions() -> Result<()> {
+    let a = column::<i32>("a", vec![None, Some(2), None]);
+    let b = column::<u8>("b", vec![Some(1), None, None]);
+    let c = constant_column("c", DataValue::Int64(Some(3)));
+
+    let result = eval(CoalesceFunction::try_create_func("coalesce")?, &[
+        a.clone(),
+        b.clone(),
+        c,
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int64(Some(1)),
+        DataValue::Int64(Some(2)),
+        DataValue::Int64(Some(3)),
+    ]);
+
+    let result = eval(CoalesceFunction::try_create_if_null("ifNull")?, &[a, b])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(1)),
+        DataValue::Int32(Some(2)),
+        DataValue::Int32(None),
+    ]);
+
+    let result = eval(NullIfFunction::try_create_func("nullif")?, &[
+        column::<i32>("a", vec![Some(1), Some(2), None]),
+        constant_column("b", DataValue::UInt8(Some(2))),
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(1)),
+        DataValue::Int32(None),
+        DataValue::Int32(None),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_in_function() -> Result<()> {
+    let a = column::<i64>("a", vec![Some(1), Some(5), None]);
+
+    let list = vec![
+        a.clone(),
+        constant_column("x", DataValue::UInt8(Some(1))),
+        constant_column("y", DataValue::Int32(Some(2))),
+    ];
+    let result = eval(InFunction::try_create_in("in")?, &list)?;
+    assert_eq!(result, vec![
+        DataValue::Boolean(Some(true)),
+        DataValue::Boolean(Some(false)),
+        DataValue::Boolean(None),
+    ]);
+    let result = eval(InFunction::try_create_not_in("notIn")?, &list)?;
+    assert_eq!(result, vec![
+        DataValue::Boolean(Some(false)),
+        DataValue::Boolean(Some(true)),
+        DataValue::Boolean(None),
+    ]);
+
+    // Large constant lists go through the hash set, a NULL item turns misses into NULL.
+    let mut list = vec![a.clone(), constant_column("n", DataValue::Null)];
+    list.extend((0..10).map(|v| constant_column("v", DataValue::UInt64(Some(v * 10 + 1)))));
+    let result = eval(InFunction::try_create_in("in")?, &list)?;
+    assert_eq!(result, vec![
+        DataValue::Boolean(Some(true)),
+        DataValue::Boolean(None),
+        DataValue::Boolean(None),
+    ]);
+
+    // Subquery result sets arrive as a constant list.
+    let subquery = constant_column(
+        "s",
+        DataValue::List(
+            Some(vec![DataValue::Int32(Some(5)), DataValue::Int32(Some(7))]),
+            DataType::Int32,
+        ),
+    );
+    let result = eval(InFunction::try_create_not_in("notIn")?, &[a, subquery])?;
+    assert_eq!(result, vec![
+        DataValue::Boolean(Some(true)),
+        DataValue::Boolean(Some(false)),
+        DataValue::Boolean(None),
+    ]);
+    Ok(())
+}
@@ -0,0 +1,180 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataGroupValue;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_datavalues::DataValueComparisonOperator;
+use common_datavalues::DataValueLogicOperator;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Constant lists with at least this many items are probed through a hash set
+/// instead of a chain of equality comparisons.
+const IN_SET_THRESHOLD: usize = 8;
+
+/// `x [NOT] IN (a, b, ...)` and `x [NOT] IN (SELECT ...)`.
+///
+/// The subquery form arrives as a single constant List column produced by
+/// the CreateSetsTransform.
+#[derive(Clone)]
+pub struct InFunction {
+    negated: bool,
+}
+
+impl InFunction {
+    pub fn try_create_in(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(InFunction { negated: false }))
+    }
+
+    pub fn try_create_not_in(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(InFunction { negated: true }))
+    }
+
+    fn subquery_values(columns: &DataColumnsWithField) -> Result<Option<Vec<DataValue>>> {
+        if columns.len() != 2 {
+            return Ok(None);
+        }
+
+        match columns[1].column() {
+            DataColumn::Constant(DataValue::List(Some(values), _), _) => Ok(Some(values.clone())),
+            DataColumn::Constant(DataValue::Struct(_), _) => Err(ErrorCode::BadArguments(
+                "Subquery of IN must return exactly one column",
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn eval_set(&self, value: &DataColumn, values: &[DataValue]) -> Result<DataColumn> {
+        let mut data_type = value.data_type();
+        for item in values.iter().filter(|v| !v.is_null()) {
+            data_type = match data_type {
+                DataType::Null => item.data_type(),
+                _ => equal_coercion(&data_type, &item.data_type())?,
+            };
+        }
+
+        let mut has_null = false;
+        let mut set = HashSet::with_capacity(values.len());
+        for item in values {
+            if item.is_null() {
+                has_null = true;
+                continue;
+            }
+
+            let item = match item.data_type() == data_type {
+                true => item.clone(),
+                false => item
+                    .to_series_with_size(1)?
+                    .cast_with_type(&data_type)?
+                    .try_get(0)?,
+            };
+            set.insert(DataGroupValue::try_from(&item)?);
+        }
+
+        let array = value.cast_with_type(&data_type)?.to_minimal_array()?;
+        let mut result = Vec::with_capacity(array.len());
+        for row in 0..array.len() {
+            let item = array.try_get(row)?;
+            result.push(match item.is_null() {
+                true => None,
+                false if set.contains(&DataGroupValue::try_from(&item)?) => Some(true),
+                false if has_null => None,
+                false => Some(false),
+            });
+        }
+
+        let result: DataColumn = result
+            .into_iter()
+            .collect::<DFBooleanArray>()
+            .into_series()
+            .into();
+        Ok(result.resize_constant(value.len()))
+    }
+
+    fn eval_compare(&self, value: &DataColumn, list: &[DataColumnWithField]) -> Result<DataColumn> {
+        let mut result = value.compare(DataValueComparisonOperator::Eq, list[0].column())?;
+        for item in &list[1..] {
+            let equal = value.compare(DataValueComparisonOperator::Eq, item.column())?;
+            result = result.logic(DataValueLogicOperator::Or, &[equal])?;
+        }
+        Ok(result)
+    }
+}
+
+impl Function for InFunction {
+    fn name(&self) -> &str {
+        "InFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args.len() == 2 && matches!(args[1], DataType::Struct(_)) {
+            return Err(ErrorCode::BadArguments(
+                "Subquery of IN must return exactly one column",
+            ));
+        }
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let value = columns[0].column();
+        let list = &columns[1..];
+
+        let result = match Self::subquery_values(columns)? {
+            Some(values) => self.eval_set(value, &values)?,
+            None if list.len() >= IN_SET_THRESHOLD
+                && list
+                    .iter()
+                    .all(|c| matches!(c.column(), DataColumn::Constant(_, _))) =>
+            {
+                let values = list
+                    .iter()
+                    .map(|c| c.column().try_get(0))
+                    .collect::<Result<Vec<_>>>()?;
+                self.eval_set(value, &values)?
+            }
+            None => self.eval_compare(value, list)?,
+        };
+
+        match self.negated {
+            true => result.logic(DataValueLogicOperator::Not, &[]),
+            false => Ok(result),
+        }
+    }
+}
+
+impl fmt::Display for InFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.negated {
+            true => write!(f, "NOT IN"),
+            false => write!(f, "IN"),
+        }
+    }
+}
@@ -12,10 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod coalesce;
 mod conditional;
 mod r#if;
+mod in_basic;
+mod multi_if;
+mod null_if;
+
+#[cfg(test)]
+mod conditional_test;
 #[cfg(test)]
 mod if_test;
 
+pub use coalesce::CoalesceFunction;
 pub use conditional::ConditionalFunction;
+pub use in_basic::InFunction;
+pub use multi_if::MultiIfFunction;
+pub use null_if::NullIfFunction;
 pub use r#if::IfFunction;
@@ -0,0 +1,87 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// multiIf(cond1, then1, cond2, then2, ..., else), the backend of `CASE WHEN`.
+#[derive(Clone)]
+pub struct MultiIfFunction {
+    _display_name: String,
+}
+
+impl MultiIfFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MultiIfFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for MultiIfFunction {
+    fn name(&self) -> &str {
+        "MultiIfFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args.len() % 2 == 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function multiIf expects an odd number of arguments, but got {}",
+                args.len()
+            )));
+        }
+
+        let results = args
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .chain(args.last())
+            .cloned()
+            .collect::<Vec<_>>();
+        common_datavalues::aggregate_types(&results)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        // Fold from the last branch so that the first matching condition wins.
+        let mut result = columns[columns.len() - 1].column().clone();
+        for branch in columns[..columns.len() - 1].chunks(2).rev() {
+            result = branch[0]
+                .column()
+                .if_then_else(branch[1].column(), &result)?;
+        }
+        Ok(result)
+    }
+}
+
+impl fmt::Display for MultiIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "multiIf")
+    }
+}
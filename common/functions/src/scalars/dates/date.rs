@@ -37,6 +37,34 @@ use crate::scalars::FactoryFuncRef;
 pub struct DateFunction {}
 
 impl DateFunction {
+    /// The functions evaluated in a timezone, the session `timezone` is appended as their
+    /// last argument by the planner.
+    pub fn is_timezone_aware(name: &str) -> bool {
+        matches!(
+            name.to_lowercase().as_str(),
+            "today"
+                | "yesterday"
+                | "tomorrow"
+                | "toyyyymm"
+                | "toyyyymmdd"
+                | "toyyyymmddhhmmss"
+                | "tostartofyear"
+                | "tostartofisoyear"
+                | "tostartofquarter"
+                | "tostartofweek"
+                | "tostartofmonth"
+                | "tomonth"
+                | "tostartofsecond"
+                | "tostartofminute"
+                | "tostartoffiveminutes"
+                | "tostartoftenminutes"
+                | "tostartoffifteenminutes"
+                | "timeslot"
+                | "tostartofhour"
+                | "tostartofday"
+        )
+    }
+
     pub fn register(map: FactoryFuncRef) -> Result<()> {
         let mut map = map.write();
         map.insert("today".into(), TodayFunction::try_create);
@@ -685,3 +685,89 @@ fn test_tomonth_function() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_toyyyymmdd_timezone_function() -> Result<()> {
+    // 2021-09-30 16:00:00 UTC is 2021-10-01 00:00:00 in Asia/Shanghai
+    let seconds = 1633017600u32;
+    let datetime = |data_type: DataType| -> DataColumnWithField {
+        let column: DataColumn = match data_type {
+            DataType::DateTime64(precision, _) => {
+                let ticks = seconds as i64 * 10_i64.pow(precision as u32) + 123;
+                Series::new(vec![ticks]).into()
+            }
+            _ => Series::new(vec![seconds]).into(),
+        };
+        DataColumnWithField::new(column, DataField::new("a", data_type, false))
+    };
+    let timezone = |tz: &str| -> DataColumnWithField {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(tz.as_bytes().to_vec())), 1),
+            DataField::new("tz", DataType::String, false),
+        )
+    };
+
+    let shanghai = Some("Asia/Shanghai".to_string());
+    let tests = vec![
+        (
+            "no-timezone",
+            vec![datetime(DataType::DateTime32(None))],
+            20210930u32,
+        ),
+        (
+            "session-timezone",
+            vec![
+                datetime(DataType::DateTime32(None)),
+                timezone("Asia/Shanghai"),
+            ],
+            20211001u32,
+        ),
+        (
+            "column-timezone-over-session-timezone",
+            vec![
+                datetime(DataType::DateTime32(shanghai.clone())),
+                timezone("UTC"),
+            ],
+            20211001u32,
+        ),
+        (
+            "explicit-timezone-over-column-timezone",
+            vec![
+                datetime(DataType::DateTime32(shanghai.clone())),
+                timezone("UTC"),
+                timezone("Asia/Shanghai"),
+            ],
+            20210930u32,
+        ),
+        (
+            "datetime64-column-timezone",
+            vec![datetime(DataType::DateTime64(3, shanghai)), timezone("UTC")],
+            20211001u32,
+        ),
+    ];
+
+    let func = ToYYYYMMDDFunction::try_create("a")?;
+    for (name, columns, expect) in tests {
+        let result = func.eval(&columns, 1)?;
+        let actual = result
+            .to_array()?
+            .u32()?
+            .inner()
+            .values()
+            .as_slice()
+            .to_vec();
+        assert_eq!(vec![expect], actual, "{}", name);
+    }
+
+    let columns = vec![
+        datetime(DataType::DateTime32(None)),
+        timezone("Mars/Olympus"),
+    ];
+    let result = func.eval(&columns, 1);
+    assert_eq!(
+        "Code: 6, displayText = Unknown timezone: Mars/Olympus.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
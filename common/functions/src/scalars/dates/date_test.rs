@@ -96,3 +96,33 @@ fn do_test(t: Test) -> Result<()> {
     assert_eq!(&expect, &v);
     Ok(())
 }
+
+#[test]
+fn test_round_timezone_function() -> Result<()> {
+    // 2021-09-30 18:30:00 UTC is 2021-10-01 02:30:00 in Asia/Shanghai, and the start of that
+    // day in Asia/Shanghai is 2021-09-30 16:00:00 UTC.
+    let to_start_of_day = RoundFunction::try_create("toStartOfDay", 24 * 3600)?;
+    let timezone = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::String(Some(b"Asia/Shanghai".to_vec())), 1),
+        DataField::new("tz", DataType::String, false),
+    );
+
+    let datetime32 = DataColumnWithField::new(
+        Series::new(vec![1633026600u32]).into(),
+        DataField::new("a", DataType::DateTime32(None), false),
+    );
+    let result = to_start_of_day.eval(&[datetime32, timezone.clone()], 1)?;
+    let expect: DataColumn = Series::new(vec![1633017600u32]).into();
+    assert_eq!(&expect, &result);
+
+    // DateTime64(6) keeps its precision
+    let datetime64 = DataColumnWithField::new(
+        Series::new(vec![1633026600_123456i64]).into(),
+        DataField::new("a", DataType::DateTime64(6, None), false),
+    );
+    let result = to_start_of_day.eval(&[datetime64, timezone], 1)?;
+    let expect: DataColumn = Series::new(vec![1633017600_000000i64]).into();
+    assert_eq!(&expect, &result);
+
+    Ok(())
+}
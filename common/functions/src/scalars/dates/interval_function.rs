@@ -20,9 +20,10 @@ use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::Duration;
 use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Timelike;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -191,9 +192,10 @@ impl IntervalFunctionFactory {
         let mut date_datetime_opt = None;
         columns.iter().for_each(|column| match column.data_type() {
             DataType::Interval(_) => interval_opt = Some(column),
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) => {
-                date_datetime_opt = Some(column)
-            }
+            DataType::Date16
+            | DataType::Date32
+            | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _) => date_datetime_opt = Some(column),
             _ => {}
         });
 
@@ -236,6 +238,8 @@ impl IntervalFunctionFactory {
     //   4. interval_month_plus_minus_date16 --------- Interval(YearMonth) +/-  Date16
     //   5. interval_month_plus_minus_date32 --------- Interval(YearMonth) +/-  Date32
     //   6. interval_month_plus_minus_datetime32 ----- Interval(YearMonth) +/-  DateTime32
+    //   7. interval_daytime_plus_minus_datetime64 --- Interval(DayTime)   +/-  DateTime64
+    //   8. interval_month_plus_minus_datetime64 ----- Interval(YearMonth) +/-  DateTime64
 
     fn get_interval_arithmetic_func(
         interval: &DataType,
@@ -246,12 +250,14 @@ impl IntervalFunctionFactory {
                 DataType::Date16 => Self::interval_month_plus_minus_date16,
                 DataType::Date32 => Self::interval_month_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_month_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_month_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             DataType::Interval(IntervalUnit::DayTime) => match date_datetime {
                 DataType::Date16 => Self::interval_daytime_plus_minus_date16,
                 DataType::Date32 => Self::interval_daytime_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_daytime_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_daytime_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -358,6 +364,23 @@ impl IntervalFunctionFactory {
         Self::month_i64_plus_minus_datetime32(op, interval, datetime, 1)
     }
 
+    fn interval_daytime_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        Self::milliseconds_plus_minus_datetime64(op, interval.column().to_array()?.i64()?, datetime)
+    }
+
+    fn interval_month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        Self::month_plus_minus_datetime64(op, a, b, 1)
+    }
+
     //  End of interval typed arithmetic functions.
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -404,6 +427,8 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::month_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            // all the integers are casted to Int64
+            DataType::DateTime64(_, _) => Self::month_plus_minus_datetime64,
             _ => unreachable!(),
         }
     }
@@ -437,6 +462,39 @@ impl IntervalFunctionFactory {
     crate::define_month_plus_minus_datetime32!(month_u16_plus_minus_datetime32, u16);
     crate::define_month_plus_minus_datetime32!(month_u8_plus_minus_datetime32, u8);
 
+    fn month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+        mul: i64,
+    ) -> Result<DataColumn> {
+        let (interval_months, datetime64) = Self::validate_input(op, a, b)?;
+        let (precision, tz) = Self::datetime64_precision_timezone(datetime64)?;
+        let months = interval_months
+            .column()
+            .to_array()?
+            .cast_with_type(&DataType::Int64)?;
+
+        let res = Self::interval_operation(
+            months.i64()?,
+            datetime64.column().to_array()?.i64()?,
+            |months: &i64, ticks: &i64| {
+                let dt = ticks_to_datetime(*ticks, precision, &tz)?;
+                let new_dt = match op {
+                    DataValueArithmeticOperator::Plus => {
+                        Self::datetime_plus_signed_months(&dt, *months * mul)?
+                    }
+                    DataValueArithmeticOperator::Minus => {
+                        Self::datetime_plus_signed_months(&dt, -*months * mul)?
+                    }
+                    _ => unreachable!(),
+                };
+                Ok(datetime_to_ticks(&new_dt, precision))
+            },
+        )?;
+        Ok(res.into())
+    }
+
     //  End of months integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -483,6 +541,8 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::time_secs_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            // all the integers are casted to Int64
+            DataType::DateTime64(_, _) => Self::time_secs_plus_minus_datetime64,
             _ => unreachable!(),
         }
     }
@@ -516,6 +576,21 @@ impl IntervalFunctionFactory {
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u16_plus_minus_datetime32, u16);
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u8_plus_minus_datetime32, u8);
 
+    fn time_secs_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+        mul: i64,
+    ) -> Result<DataColumn> {
+        let (interval_seconds, datetime64) = Self::validate_input(op, a, b)?;
+        let seconds = interval_seconds
+            .column()
+            .to_array()?
+            .cast_with_type(&DataType::Int64)?;
+        let milliseconds = seconds.i64()?.apply(|secs| secs * mul * 1000);
+        Self::milliseconds_plus_minus_datetime64(op, &milliseconds, datetime64)
+    }
+
     // End of seconds integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -542,6 +617,52 @@ impl IntervalFunctionFactory {
         }
     }
 
+    // A private helper function to get the precision and the timezone of datetime64
+    fn datetime64_precision_timezone(datetime64: &DataColumnWithField) -> Result<(u8, Tz)> {
+        match datetime64.data_type() {
+            DataType::DateTime64(precision, _) => Ok((
+                *precision,
+                timezone_of(datetime64.data_type())?.unwrap_or(Tz::UTC),
+            )),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected a DateTime64 type, but got {:?}",
+                other
+            ))),
+        }
+    }
+
+    // A private helper function to add/subtract milliseconds to/from the ticks of datetime64
+    fn milliseconds_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        milliseconds: &DFInt64Array,
+        datetime64: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (precision, _) = Self::datetime64_precision_timezone(datetime64)?;
+        let res = Self::interval_operation(
+            milliseconds,
+            datetime64.column().to_array()?.i64()?,
+            |ms: &i64, ticks: &i64| {
+                let delta = if precision >= 3 {
+                    ms.checked_mul(datetime64_tick_multiplier(precision - 3))
+                } else {
+                    Some(ms / datetime64_tick_multiplier(3 - precision))
+                };
+                let r = delta.and_then(|delta| match op {
+                    DataValueArithmeticOperator::Plus => ticks.checked_add(delta),
+                    DataValueArithmeticOperator::Minus => ticks.checked_sub(delta),
+                    _ => unreachable!(),
+                });
+                r.ok_or_else(|| {
+                    ErrorCode::Overflow(format!(
+                        "Overflow on datetime64 with ticks {} and milliseconds {}.",
+                        ticks, ms
+                    ))
+                })
+            },
+        )?;
+        Ok(res.into())
+    }
+
     // A private helper function to add/subtract month to/from days
     fn days_plus_signed_months(days: i64, months: i64) -> Result<u32> {
         let naive = NaiveDateTime::from_timestamp(0, 0).checked_add_signed(Duration::days(days));
@@ -551,14 +672,15 @@ impl IntervalFunctionFactory {
                 days,
             )));
         }
-        let dt = DateTime::<Utc>::from_utc(naive.unwrap(), Utc);
+        let dt = Tz::UTC.from_utc_datetime(&naive.unwrap());
         let dt = Self::datetime_plus_signed_months(&dt, months)?;
         let seconds_per_day = 24 * 3600;
         Ok((dt.timestamp() / seconds_per_day) as u32)
     }
 
-    // A private helper function to add/subtract month to/from chrono datetime object
-    fn datetime_plus_signed_months(dt: &DateTime<Utc>, months: i64) -> Result<DateTime<Utc>> {
+    // A private helper function to add/subtract month to/from chrono datetime object,
+    // the month is added on the local time of its timezone.
+    fn datetime_plus_signed_months(dt: &DateTime<Tz>, months: i64) -> Result<DateTime<Tz>> {
         let total_months = (dt.month0() as i64) + months;
         let mut new_year = dt.year() + (total_months / 12) as i32;
         let mut new_month0 = total_months % 12;
@@ -591,10 +713,19 @@ impl IntervalFunctionFactory {
                 new_day
             )));
         }
-        Ok(DateTime::<Utc>::from_utc(
-            new_date.unwrap().and_hms(h, m, s),
-            Utc,
-        ))
+        let new_dt = new_date
+            .unwrap()
+            .and_hms_nano(h, m, s, dt.timestamp_subsec_nanos());
+        dt.timezone()
+            .from_local_datetime(&new_dt)
+            .earliest()
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Invalid local time {} in timezone {}.",
+                    new_dt,
+                    dt.timezone()
+                ))
+            })
     }
 
     // Get the last day of the year month, could be 28(non leap Feb), 29(leap year Feb), 30 or 31
@@ -608,7 +739,7 @@ impl IntervalFunctionFactory {
     }
 
     // A private helper function to convert seconds (since Unix epoch) to chrono DateTime
-    fn seconds_to_datetime(seconds: i64) -> Result<DateTime<Utc>> {
+    fn seconds_to_datetime(seconds: i64, tz: &Tz) -> Result<DateTime<Tz>> {
         let naive = NaiveDateTime::from_timestamp_opt(seconds, 0);
         if naive.is_none() {
             return Err(ErrorCode::Overflow(format!(
@@ -616,7 +747,7 @@ impl IntervalFunctionFactory {
                 seconds
             )));
         }
-        Ok(DateTime::<Utc>::from_utc(naive.unwrap(), Utc))
+        Ok(tz.from_utc_datetime(&naive.unwrap()))
     }
 }
 
@@ -630,12 +761,13 @@ macro_rules! define_month_plus_minus_datetime32 {
             mul: i64,
         ) -> Result<DataColumn> {
             let (interval_months, datetime32) = Self::validate_input(op, a, b)?;
+            let tz = timezone_of(datetime32.data_type())?.unwrap_or(Tz::UTC);
 
             let res = Self::interval_operation(
                 interval_months.column().to_array()?.$type()?,
                 datetime32.column().to_array()?.u32()?,
                 |months: &$type, seconds: &u32| {
-                    let dt = Self::seconds_to_datetime(*seconds as i64)?;
+                    let dt = Self::seconds_to_datetime(*seconds as i64, &tz)?;
                     let new_dt = match op {
                         DataValueArithmeticOperator::Plus => {
                             Self::datetime_plus_signed_months(&dt, (*months as i64) * mul)?
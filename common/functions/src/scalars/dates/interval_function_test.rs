@@ -222,3 +222,54 @@ fn test_add_subtract_seconds() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_datetime64_interval_arithmetic() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new(
+            "datetime64",
+            DataType::DateTime64(6, Some("Asia/Shanghai".to_string())),
+            false,
+        ),
+        DataField::new("i32", DataType::Int32, false),
+    ]);
+
+    // 2021-01-31 23:30:00.123456 in Asia/Shanghai
+    let ticks = 1612107000_123456i64;
+    let blocks = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![ticks]),
+        Series::new(vec![1_i32]),
+    ]);
+
+    let column = |col_name: &str| -> DataColumnWithField {
+        DataColumnWithField::new(
+            blocks.try_column_by_name(col_name).unwrap().clone(),
+            schema.field_with_name(col_name).unwrap().clone(),
+        )
+    };
+
+    {
+        // The month is added on the local date, 2021-02-28 23:30:00.123456 in Asia/Shanghai
+        let add_months = MonthsArithmeticFunction::try_create(
+            "addMonths",
+            DataValueArithmeticOperator::Plus,
+            1,
+        )?;
+        let col = add_months.eval(&[column("datetime64"), column("i32")], 1)?;
+        let raw = col.to_array()?.i64()?.inner().values().as_slice().to_vec();
+        assert_eq!(raw, vec![1614526200_123456i64]);
+    }
+
+    {
+        let subtract_minutes = SecondsArithmeticFunction::try_create(
+            "subtractMinutes",
+            DataValueArithmeticOperator::Minus,
+            60,
+        )?;
+        let col = subtract_minutes.eval(&[column("datetime64"), column("i32")], 1)?;
+        let raw = col.to_array()?.i64()?.inner().values().as_slice().to_vec();
+        assert_eq!(raw, vec![ticks - 60_000_000]);
+    }
+
+    Ok(())
+}
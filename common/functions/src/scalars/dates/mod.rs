@@ -26,6 +26,7 @@ mod now;
 mod number_function;
 mod round_function;
 mod simple_date;
+mod timezone;
 mod week_date;
 
 pub use date::DateFunction;
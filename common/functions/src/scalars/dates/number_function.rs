@@ -17,13 +17,16 @@ use std::marker::PhantomData;
 
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Timelike;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::timezone::resolve_timezone;
 use crate::scalars::Function;
 
 #[derive(Clone, Debug)]
@@ -35,8 +38,8 @@ pub struct NumberFunction<T, R> {
 
 pub trait NumberResultFunction<R> {
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>) -> DataValue;
 }
 
 #[derive(Clone)]
@@ -46,11 +49,11 @@ impl NumberResultFunction<u32> for ToYYYYMM {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 100 + value.month()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -62,11 +65,11 @@ impl NumberResultFunction<u32> for ToYYYYMMDD {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 10000 + value.month() * 100 + value.day()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -79,7 +82,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
         Ok(DataType::UInt64)
     }
 
-    fn to_number(value: DateTime<Utc>) -> u64 {
+    fn to_number(value: DateTime<Tz>) -> u64 {
         value.year() as u64 * 10000000000
             + value.month() as u64 * 100000000
             + value.day() as u64 * 1000000
@@ -88,7 +91,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
             + value.second() as u64
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt64(Some(Self::to_number(value)))
     }
 }
@@ -100,12 +103,11 @@ impl NumberResultFunction<u16> for ToStartOfYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let end: DateTime<Utc> = Utc.ymd(value.year(), 1, 1).and_hms(0, 0, 0);
-        get_day(end) as u16
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        get_day(NaiveDate::from_ymd(value.year(), 1, 1)) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -117,17 +119,16 @@ impl NumberResultFunction<u16> for ToStartOfISOYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         let week_day = value.weekday().num_days_from_monday();
         let iso_week = value.iso_week();
         let iso_week_num = iso_week.week();
         let sub_days = (iso_week_num - 1) * 7 + week_day;
-        let result = value.timestamp_millis() - sub_days as i64 * 24 * 3600 * 1000;
-        let end: DateTime<Utc> = Utc.timestamp_millis(result);
+        let end = value.naive_local().date() - Duration::days(sub_days as i64);
         get_day(end) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -139,13 +140,12 @@ impl NumberResultFunction<u16> for ToStartOfQuarter {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         let new_month = value.month0() / 3 * 3 + 1;
-        let date = Utc.ymd(value.year(), new_month, 1).and_hms(0, 0, 0);
-        get_day(date) as u16
+        get_day(NaiveDate::from_ymd(value.year(), new_month, 1)) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -157,12 +157,11 @@ impl NumberResultFunction<u16> for ToStartOfMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let date = Utc.ymd(value.year(), value.month(), 1).and_hms(0, 0, 0);
-        get_day(date) as u16
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        get_day(NaiveDate::from_ymd(value.year(), value.month(), 1)) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -174,11 +173,11 @@ impl NumberResultFunction<u8> for ToMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.month() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 }
@@ -212,8 +211,9 @@ where
         T::return_type()
     }
 
-    fn num_arguments(&self) -> usize {
-        1
+    // The optional trailing arguments are the explicit and the session timezones
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 3))
     }
 
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
@@ -222,10 +222,11 @@ where
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let data_type = columns[0].data_type();
+        let tz = resolve_timezone(columns)?;
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -233,7 +234,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time)
                         }
                         );
@@ -242,7 +243,7 @@ where
             },
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()? * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_i64()? * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -250,7 +251,7 @@ where
                         .to_array()?
                         .i32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time)
                         }
                         );
@@ -259,7 +260,7 @@ where
             },
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64()? as i64, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -267,7 +268,26 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = tz.timestamp(v as i64, 0_u32);
+                            T::to_number(date_time)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            },
+            DataType::DateTime64(precision, _) => {
+                // the fraction of second doesn't affect the results
+                let multiplier = datetime64_tick_multiplier(*precision);
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = tz.timestamp(v.as_i64()?.div_euclid(multiplier), 0_u32);
+                    let constant_result = T::to_constant_value(date_time);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = tz.timestamp(v.div_euclid(multiplier), 0_u32);
                             T::to_number(date_time)
                         }
                         );
@@ -275,7 +295,7 @@ where
                 }
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -289,8 +309,8 @@ impl<T, R> fmt::Display for NumberFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
+fn get_day(date: NaiveDate) -> u32 {
+    let start = NaiveDate::from_ymd(1970, 1, 1);
     let duration = date.signed_duration_since(start);
     duration.num_days() as u32
 }
@@ -14,10 +14,15 @@
 
 use std::fmt;
 
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::timezone::resolve_timezone;
 use crate::scalars::Function;
 
 #[derive(Clone)]
@@ -36,12 +41,20 @@ impl RoundFunction {
         Ok(Box::new(s))
     }
 
-    // TODO: (sundy-li)
-    // Consider about the timezones/offsets
-    // Currently: assuming timezone offset is a multiple of round.
+    // Round the seconds on the local time of the timezone, so the rounders such as
+    // toStartOfHour and toStartOfDay respect the timezones with a non-hour offset or DST.
     #[inline]
-    fn execute(&self, time: u32) -> u32 {
-        time / self.round * self.round
+    fn execute(&self, time: i64, tz: &Tz) -> i64 {
+        let round = self.round as i64;
+        let offset = tz
+            .offset_from_utc_datetime(&NaiveDateTime::from_timestamp(time, 0))
+            .fix()
+            .local_minus_utc() as i64;
+        let local = (time + offset).div_euclid(round) * round;
+        tz.from_local_datetime(&NaiveDateTime::from_timestamp(local, 0))
+            .earliest()
+            .map(|v| v.timestamp())
+            .unwrap_or(local - offset)
     }
 }
 
@@ -52,7 +65,7 @@ impl Function for RoundFunction {
 
     fn return_type(&self, args: &[DataType]) -> Result<DataType> {
         match args[0] {
-            DataType::DateTime32(_) => Ok(DataType::DateTime32(None)),
+            DataType::DateTime32(_) | DataType::DateTime64(_, _) => Ok(args[0].clone()),
             _ => Err(ErrorCode::BadDataValueType(format!(
                 "Function {} must have a DateTime type as argument, but got {}",
                 self.display_name, args[0],
@@ -65,10 +78,33 @@ impl Function for RoundFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let tz = resolve_timezone(columns)?;
+        if let DataType::DateTime64(precision, _) = columns[0].data_type() {
+            let multiplier = datetime64_tick_multiplier(*precision);
+            let execute = |x: i64| self.execute(x.div_euclid(multiplier), &tz) * multiplier;
+            return match columns[0].column() {
+                DataColumn::Array(array) => {
+                    let array = array.i64()?;
+                    let arr = array.apply(execute);
+                    Ok(DataColumn::Array(arr.into_series()))
+                }
+                DataColumn::Constant(v, rows) => {
+                    if v.is_null() {
+                        return Ok(DataColumn::Constant(DataValue::Int64(None), *rows));
+                    }
+                    let value = v.as_i64()?;
+                    Ok(DataColumn::Constant(
+                        DataValue::Int64(Some(execute(value))),
+                        *rows,
+                    ))
+                }
+            };
+        }
+
         match columns[0].column() {
             DataColumn::Array(array) => {
                 let array = array.u32()?;
-                let arr = array.apply(|x| self.execute(x));
+                let arr = array.apply(|x| self.execute(x as i64, &tz) as u32);
                 Ok(DataColumn::Array(arr.into_series()))
             }
             DataColumn::Constant(v, rows) => {
@@ -77,15 +113,16 @@ impl Function for RoundFunction {
                 }
                 let value = v.as_u64()?;
                 Ok(DataColumn::Constant(
-                    DataValue::UInt32(Some(self.execute(value as u32))),
+                    DataValue::UInt32(Some(self.execute(value as i64, &tz) as u32)),
                     *rows,
                 ))
             }
         }
     }
 
-    fn num_arguments(&self) -> usize {
-        1
+    // The optional trailing arguments are the explicit and the session timezones
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 3))
     }
 }
 
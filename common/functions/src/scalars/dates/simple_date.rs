@@ -16,12 +16,13 @@ use std::fmt;
 use std::marker::PhantomData;
 use std::ops::Sub;
 
-use common_datavalues::chrono::Date;
 use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::Result;
 
+use super::timezone::resolve_timezone;
 use crate::scalars::Function;
 
 #[derive(Clone, Debug)]
@@ -31,18 +32,18 @@ pub struct SimpleFunction<T> {
 }
 
 pub trait NoArgDateFunction {
-    fn execute() -> u16;
+    fn execute(tz: &Tz) -> u16;
 }
 
 #[derive(Clone)]
 pub struct Today;
 
 impl NoArgDateFunction for Today {
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(tz: &Tz) -> u16 {
+        let today = Utc::now().with_timezone(tz).date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
-        let duration = utc.naive_utc().sub(epoch);
+        let duration = today.naive_local().sub(epoch);
         duration.num_days() as u16
     }
 }
@@ -51,11 +52,11 @@ impl NoArgDateFunction for Today {
 pub struct Yesterday;
 
 impl NoArgDateFunction for Yesterday {
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(tz: &Tz) -> u16 {
+        let today = Utc::now().with_timezone(tz).date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
-        let duration = utc.naive_utc().sub(epoch);
+        let duration = today.naive_local().sub(epoch);
         duration.num_days() as u16 - 1
     }
 }
@@ -64,11 +65,11 @@ impl NoArgDateFunction for Yesterday {
 pub struct Tomorrow;
 
 impl NoArgDateFunction for Tomorrow {
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(tz: &Tz) -> u16 {
+        let today = Utc::now().with_timezone(tz).date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
-        let duration = utc.naive_utc().sub(epoch);
+        let duration = today.naive_local().sub(epoch);
         duration.num_days() as u16 + 1
     }
 }
@@ -99,8 +100,14 @@ where T: NoArgDateFunction + Clone + Sync + Send + 'static
         Ok(false)
     }
 
-    fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let value = T::execute();
+    // The optional arguments are the explicit and the session timezones
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, 2))
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let tz = resolve_timezone(columns)?;
+        let value = T::execute(&tz);
         Ok(DataColumn::Constant(
             DataValue::UInt16(Some(value)),
             input_rows,
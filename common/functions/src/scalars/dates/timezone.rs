@@ -0,0 +1,61 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Resolves the timezone to evaluate a date function in. The planner always appends the
+/// session `timezone` as the last argument, so the trailing string arguments are
+/// `[explicit timezone,] session timezone`. The explicit timezone has the highest priority,
+/// then the timezone of the datetime argument, then the session timezone, otherwise UTC.
+pub fn resolve_timezone(columns: &DataColumnsWithField) -> Result<Tz> {
+    let timezones = columns
+        .iter()
+        .filter(|c| c.data_type() == &DataType::String)
+        .collect::<Vec<_>>();
+
+    if timezones.len() > 1 {
+        return timezone_argument(timezones[timezones.len() - 2]);
+    }
+    if let Some(tz) = columns
+        .first()
+        .map(|c| timezone_of(c.data_type()))
+        .transpose()?
+        .flatten()
+    {
+        return Ok(tz);
+    }
+    match timezones.last() {
+        Some(column) => timezone_argument(column),
+        None => Ok(Tz::UTC),
+    }
+}
+
+fn timezone_argument(column: &DataColumnWithField) -> Result<Tz> {
+    match column.column().try_get(0)? {
+        DataValue::String(Some(tz)) => {
+            let tz = std::str::from_utf8(&tz).map_err(|_| {
+                ErrorCode::BadArguments("The timezone argument must be a valid utf8 string")
+            })?;
+            parse_timezone(tz)
+        }
+        other => Err(ErrorCode::BadArguments(format!(
+            "The timezone argument must be a constant string, but got {:?}",
+            other
+        ))),
+    }
+}
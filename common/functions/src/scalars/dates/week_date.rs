@@ -19,12 +19,14 @@ use std::ops::Sub;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::TimeZone;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::timezone::resolve_timezone;
 use crate::scalars::Function;
 
 #[derive(Clone, Debug)]
@@ -36,8 +38,8 @@ pub struct WeekFunction<T, R> {
 
 pub trait WeekResultFunction<R> {
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>, mode: Option<u64>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>, mode: Option<u64>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>, mode: Option<u64>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>, mode: Option<u64>) -> DataValue;
 }
 
 #[derive(Clone)]
@@ -47,7 +49,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>, mode: Option<u64>) -> u32 {
+    fn to_number(value: DateTime<Tz>, mode: Option<u64>) -> u32 {
         let week_mode = mode.unwrap_or(0);
         let mut weekday = value.weekday().number_from_sunday();
         if week_mode & 1 == 1 {
@@ -55,11 +57,11 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
         }
         weekday -= 1;
         let duration = Duration::days(weekday as i64);
-        let result = value.sub(duration);
+        let result = value.naive_local().date().sub(duration);
         get_day(result)
     }
 
-    fn to_constant_value(value: DateTime<Utc>, mode: Option<u64>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>, mode: Option<u64>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value, mode) as u16))
     }
 }
@@ -93,8 +95,9 @@ where
         T::return_type()
     }
 
+    // The optional arguments are the week mode, the explicit and the session timezones
     fn variadic_arguments(&self) -> Option<(usize, usize)> {
-        Some((1, 2))
+        Some((1, 4))
     }
 
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
@@ -104,7 +107,10 @@ where
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let data_type = columns[0].data_type();
         let mut mode: Option<u64> = None;
-        if columns.len() == 2 && !columns[1].column().is_empty() {
+        if columns.len() >= 2
+            && columns[1].data_type() != &DataType::String
+            && !columns[1].column().is_empty()
+        {
             let week_mode = columns[1].column().to_values()?[0].clone().as_u64()?;
             if !(0..=9).contains(&week_mode) {
                 return Err(ErrorCode::BadArguments(format!(
@@ -114,10 +120,11 @@ where
             }
             mode = Some(week_mode);
         }
+        let tz = resolve_timezone(columns)?;
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -125,7 +132,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -134,7 +141,7 @@ where
             },
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?  * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_i64()?  * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -142,7 +149,7 @@ where
                         .to_array()?
                         .i32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -151,7 +158,7 @@ where
             },
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?, 0_u32);
+                    let date_time = tz.timestamp(v.as_i64()?, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -159,7 +166,25 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = tz.timestamp(v as i64, 0_u32);
+                            T::to_number(date_time, mode)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            },
+            DataType::DateTime64(precision, _) => {
+                let multiplier = datetime64_tick_multiplier(*precision);
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = tz.timestamp(v.as_i64()?.div_euclid(multiplier), 0_u32);
+                    let constant_result = T::to_constant_value(date_time, mode);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = tz.timestamp(v.div_euclid(multiplier), 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -167,7 +192,7 @@ where
                 }
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -181,8 +206,8 @@ impl<T, R> fmt::Display for WeekFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
+fn get_day(date: NaiveDate) -> u32 {
+    let start = NaiveDate::from_ymd(1970, 1, 1);
     let duration = date.signed_duration_since(start);
     duration.num_days() as u32
 }
@@ -15,7 +15,7 @@
 use std::fmt;
 
 use common_datavalues::columns::DataColumn;
-use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::prelude::*;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
 use common_exception::Result;
@@ -54,7 +54,29 @@ impl Function for CastFunction {
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let series = columns[0].column().clone().to_minimal_array()?;
-        let column: DataColumn = series.cast_with_type(&self.cast_type)?.into();
+        // Decimal is scaled, so it can't be casted as its physical type.
+        let series = match (columns[0].data_type(), &self.cast_type) {
+            // Variant is JSON text, the scalar is extracted from or formatted into it.
+            (DataType::Variant, _) => variant_cast_to(series.string()?, &self.cast_type)?,
+            (from_type, DataType::Variant) => variant_cast_from(&series, from_type)?.into_series(),
+            (DataType::Decimal(_, scale), _) => {
+                decimal_cast_to(series.i64()?, *scale, &self.cast_type)?
+            }
+            (_, DataType::Decimal(precision, scale)) => {
+                decimal_cast_from(&series, series.data_type(), *precision, *scale)?.into_series()
+            }
+            // DateTime64 is ticks in its precision and timezone.
+            (DataType::DateTime64(precision, tz), _) => {
+                let tz = parse_timezone(tz.as_deref().unwrap_or("UTC"))?;
+                datetime64_cast_to(series.i64()?, *precision, &tz, &self.cast_type)?
+            }
+            (from_type, DataType::DateTime64(precision, tz)) => {
+                let tz = parse_timezone(tz.as_deref().unwrap_or("UTC"))?;
+                datetime64_cast_from(&series, from_type, *precision, &tz)?.into_series()
+            }
+            _ => series.cast_with_type(&self.cast_type)?,
+        };
+        let column: DataColumn = series.into();
         Ok(column.resize_constant(input_rows))
     }
 
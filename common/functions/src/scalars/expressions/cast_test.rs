@@ -124,3 +124,44 @@ fn test_cast_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_cast_decimal_function() -> Result<()> {
+    let string_field = DataField::new("s", DataType::String, false);
+    let decimal_field = DataField::new("d", DataType::Decimal(10, 2), false);
+
+    // String to Decimal(10, 2), the extra digit is rounded.
+    let func = CastFunction::create("cast".to_string(), DataType::Decimal(10, 2))?;
+    let columns = vec![DataColumnWithField::new(
+        Series::new(vec!["1.005", "-2", "33.3"]).into(),
+        string_field,
+    )];
+    let v = func.eval(&columns, 3)?;
+    assert_eq!(v.to_array()?.i64()?.collect_values(), vec![
+        Some(101),
+        Some(-200),
+        Some(3330)
+    ]);
+
+    // Decimal(10, 2) to String.
+    let func = CastFunction::create("cast".to_string(), DataType::String)?;
+    let columns = vec![DataColumnWithField::new(
+        Series::new(vec![101i64, -200, 5]).into(),
+        decimal_field.clone(),
+    )];
+    let v = func.eval(&columns, 3)?;
+    assert_eq!(v.to_array()?.string()?.collect_values(), vec![
+        Some(b"1.01".to_vec()),
+        Some(b"-2.00".to_vec()),
+        Some(b"0.05".to_vec())
+    ]);
+
+    // Decimal(10, 2) to Decimal(3, 1) overflows.
+    let func = CastFunction::create("cast".to_string(), DataType::Decimal(3, 1))?;
+    let columns = vec![DataColumnWithField::new(
+        Series::new(vec![12345i64]).into(),
+        decimal_field,
+    )];
+    assert!(func.eval(&columns, 1).is_err());
+    Ok(())
+}
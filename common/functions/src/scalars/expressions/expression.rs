@@ -51,7 +51,8 @@ impl ToCastFunction {
                 Float64,
                 Date16,
                 Date32,
-                String
+                String,
+                Variant
             }
             // aliases
             map.insert("toDate".into(), |display_name| {
@@ -63,6 +64,11 @@ impl ToCastFunction {
             map.insert("toDateTime32".into(), |display_name| {
                 CastFunction::create(display_name.to_string(), DataType::DateTime32(None))
             });
+            // toDateTime64(expr, precision[, timezone]) is planned as a cast with the literal
+            // precision and timezone, DateTime64(3) is the default as ClickHouse.
+            map.insert("toDateTime64".into(), |display_name| {
+                CastFunction::create(display_name.to_string(), DataType::DateTime64(3, None))
+            });
         }
 
         Ok(())
@@ -22,17 +22,21 @@ use lazy_static::lazy_static;
 use unicase::UniCase;
 
 use crate::scalars::ArithmeticFunction;
+use crate::scalars::ArrayFunction;
 use crate::scalars::ComparisonFunction;
 use crate::scalars::ConditionalFunction;
 use crate::scalars::DateFunction;
 use crate::scalars::Function;
 use crate::scalars::HashesFunction;
 use crate::scalars::LogicFunction;
+use crate::scalars::MathFunction;
 use crate::scalars::NullableFunction;
 use crate::scalars::OtherFunction;
+use crate::scalars::RegexpFunction;
 use crate::scalars::StringFunction;
 use crate::scalars::ToCastFunction;
 use crate::scalars::UdfFunction;
+use crate::scalars::VariantFunction;
 
 pub struct FunctionFactory;
 pub type FactoryFunc = fn(name: &str) -> Result<Box<dyn Function>>;
@@ -54,6 +58,10 @@ lazy_static! {
         ConditionalFunction::register(map.clone()).unwrap();
         DateFunction::register(map.clone()).unwrap();
         OtherFunction::register(map.clone()).unwrap();
+        VariantFunction::register(map.clone()).unwrap();
+        ArrayFunction::register(map.clone()).unwrap();
+        RegexpFunction::register(map.clone()).unwrap();
+        MathFunction::register(map.clone()).unwrap();
 
         map
     };
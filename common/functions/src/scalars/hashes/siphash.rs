@@ -61,7 +61,9 @@ impl Function for SipHashFunction {
             | DataType::Date16
             | DataType::Date32
             | DataType::DateTime32(_)
-            | DataType::String => Ok(DataType::UInt64),
+            | DataType::DateTime64(_, _)
+            | DataType::String
+            | DataType::Variant => Ok(DataType::UInt64),
             _ => Result::Err(ErrorCode::BadArguments(format!(
                 "Function Error: {} does not support {} type parameters",
                 self.display_name, args[0]
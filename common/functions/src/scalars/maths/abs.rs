@@ -0,0 +1,79 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_primitive_column;
+use crate::scalars::FloatColumn;
+use crate::scalars::Function;
+use crate::scalars::PrimitiveColumn;
+
+/// `abs(x)` returns the absolute value, the signed integers become the unsigned ones.
+#[derive(Clone)]
+pub struct AbsFunction {
+    display_name: String,
+}
+
+impl AbsFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(AbsFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for AbsFunction {
+    fn name(&self) -> &str {
+        "AbsFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_abs_coercion(&args[0])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_type = columns[0].data_type();
+        let result = if is_floating(data_type) {
+            let values = FloatColumn::try_create(columns[0].column())?;
+            build_primitive_column(values.is_constant(), input_rows, |row| {
+                Ok(values.get(row).map(f64::abs))
+            })?
+        } else if is_signed_numeric(data_type) {
+            let values = PrimitiveColumn::<i64>::try_create(columns[0].column())?;
+            build_primitive_column(values.is_constant(), input_rows, |row| {
+                Ok(values.get(row).map(i64::unsigned_abs))
+            })?
+        } else {
+            columns[0].column().clone()
+        };
+        result.cast_with_type(&numerical_abs_coercion(data_type)?)
+    }
+}
+
+impl fmt::Display for AbsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
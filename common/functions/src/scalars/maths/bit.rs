@@ -0,0 +1,197 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::build_primitive_column;
+use crate::scalars::eval_binary_primitive;
+use crate::scalars::wrap_integer;
+use crate::scalars::Function;
+use crate::scalars::PrimitiveColumn;
+
+#[derive(Clone, Copy, PartialEq)]
+enum BitOperator {
+    And,
+    Or,
+    Xor,
+    Not,
+    ShiftLeft,
+    ShiftRight,
+    Count,
+}
+
+/// The bit functions of integers `bitAnd(a, b)`, `bitOr(a, b)`, `bitXor(a, b)`, `bitNot(a)`,
+/// `bitShiftLeft(a, n)`, `bitShiftRight(a, n)` and `bitCount(a)`. The results are computed
+/// in 64 bits and truncated to the bits of the result type.
+#[derive(Clone)]
+pub struct BitFunction {
+    display_name: String,
+    op: BitOperator,
+}
+
+impl BitFunction {
+    fn try_create(display_name: &str, op: BitOperator) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitFunction {
+            display_name: display_name.to_string(),
+            op,
+        }))
+    }
+
+    pub fn try_create_and(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, BitOperator::And)
+    }
+
+    pub fn try_create_or(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, BitOperator::Or)
+    }
+
+    pub fn try_create_xor(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, BitOperator::Xor)
+    }
+
+    pub fn try_create_not(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, BitOperator::Not)
+    }
+
+    pub fn try_create_shift_left(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, BitOperator::ShiftLeft)
+    }
+
+    pub fn try_create_shift_right(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, BitOperator::ShiftRight)
+    }
+
+    pub fn try_create_count(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, BitOperator::Count)
+    }
+
+    fn shift<T: num::PrimInt>(&self, value: T, amount: T) -> Result<Option<T>> {
+        let amount = match amount.to_usize() {
+            Some(amount) => amount,
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The shift amount of function {} must not be negative",
+                    self.display_name
+                )))
+            }
+        };
+        Ok(Some(match (self.op, amount < 64) {
+            (BitOperator::ShiftLeft, true) => value << amount,
+            (BitOperator::ShiftLeft, false) => T::zero(),
+            (_, true) => value >> amount,
+            (_, false) if value < T::zero() => !T::zero(),
+            (_, false) => T::zero(),
+        }))
+    }
+
+    fn eval_integers<T>(
+        &self,
+        columns: &DataColumnsWithField,
+        input_rows: usize,
+        bits: usize,
+    ) -> Result<DataColumn>
+    where
+        T: DFPrimitiveType + num::PrimInt,
+    {
+        let wrap = |value: T| -> Result<Option<T>> { Ok(Some(wrap_integer(value, bits))) };
+        match self.op {
+            BitOperator::And => {
+                eval_binary_primitive::<T, _>(columns, input_rows, |a, b| wrap(a & b))
+            }
+            BitOperator::Or => {
+                eval_binary_primitive::<T, _>(columns, input_rows, |a, b| wrap(a | b))
+            }
+            BitOperator::Xor => {
+                eval_binary_primitive::<T, _>(columns, input_rows, |a, b| wrap(a ^ b))
+            }
+            BitOperator::ShiftLeft | BitOperator::ShiftRight => {
+                eval_binary_primitive::<T, _>(columns, input_rows, |value, amount| {
+                    Ok(self.shift(value, amount)?.map(|v| wrap_integer(v, bits)))
+                })
+            }
+            BitOperator::Not => {
+                let values = PrimitiveColumn::<T>::try_create(columns[0].column())?;
+                build_primitive_column(values.is_constant(), input_rows, |row| {
+                    Ok(values.get(row).map(|v| wrap_integer(!v, bits)))
+                })
+            }
+            BitOperator::Count => {
+                let values = PrimitiveColumn::<T>::try_create(columns[0].column())?;
+                build_primitive_column(values.is_constant(), input_rows, |row| {
+                    // the bits above the type are shifted out before counting
+                    Ok(values
+                        .get(row)
+                        .map(|v| (v << (64 - bits)).count_ones() as u8))
+                })
+            }
+        }
+    }
+}
+
+impl Function for BitFunction {
+    fn name(&self) -> &str {
+        "BitFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        match self.op {
+            BitOperator::Not | BitOperator::Count => 1,
+            _ => 2,
+        }
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_args_check(&self.display_name, args, true)?;
+        match self.op {
+            BitOperator::And | BitOperator::Or | BitOperator::Xor => {
+                numerical_bitwise_coercion(&self.display_name, &args[0], &args[1])
+            }
+            BitOperator::Count => Ok(DataType::UInt8),
+            _ => Ok(args[0].clone()),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let data_type = self.return_type(&args)?;
+        // the type of the computed integers
+        let integer_type = match self.op {
+            BitOperator::Count => &args[0],
+            _ => &data_type,
+        };
+        let bits = numeric_byte_size(integer_type)? * 8;
+        let result = match is_signed_numeric(integer_type) {
+            true => self.eval_integers::<i64>(columns, input_rows, bits)?,
+            false => self.eval_integers::<u64>(columns, input_rows, bits)?,
+        };
+        result.cast_with_type(&data_type)
+    }
+}
+
+impl fmt::Display for BitFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::finish_column;
+use crate::scalars::Function;
+
+/// `greatest(a, b, ...)` and `least(a, b, ...)` return the largest or smallest argument,
+/// the arguments are casted to the common type and the result is NULL if any one is NULL.
+#[derive(Clone)]
+pub struct GreatestFunction {
+    display_name: String,
+    least: bool,
+}
+
+impl GreatestFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GreatestFunction {
+            display_name: display_name.to_string(),
+            least: false,
+        }))
+    }
+
+    pub fn try_create_least(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GreatestFunction {
+            display_name: display_name.to_string(),
+            least: true,
+        }))
+    }
+}
+
+impl Function for GreatestFunction {
+    fn name(&self) -> &str {
+        "GreatestFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        aggregate_types(args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let data_type = aggregate_types(&args)?;
+        let nulls = DataColumn::Constant(DataValue::Null, input_rows).cast_with_type(&data_type)?;
+        if args.contains(&DataType::Null) {
+            return Ok(nulls);
+        }
+
+        // the arguments are expanded to arrays of the same rows
+        let is_constant = columns
+            .iter()
+            .all(|c| matches!(c.column(), DataColumn::Constant(_, _)));
+        let rows = if is_constant { 1 } else { input_rows };
+        let expand = |column: &DataColumn| -> Result<DataColumn> {
+            let column = column.resize_constant(rows).cast_with_type(&data_type)?;
+            Ok(column.to_array()?.into())
+        };
+
+        let op = match self.least {
+            true => DataValueComparisonOperator::Lt,
+            false => DataValueComparisonOperator::Gt,
+        };
+        let mut result = expand(columns[0].column())?;
+        let mut has_null = result.is_null()?;
+        for column in &columns[1..] {
+            let column = expand(column.column())?;
+            let replaced = column.compare(op.clone(), &result)?;
+            result = replaced.if_then_else(&column, &result)?;
+            has_null = has_null.logic(DataValueLogicOperator::Or, &[column.is_null()?])?;
+        }
+        let nulls = expand(&nulls)?;
+        let result = has_null.if_then_else(&nulls, &result)?;
+        finish_column(result.to_array()?, is_constant, input_rows)
+    }
+}
+
+impl fmt::Display for GreatestFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::eval_binary_primitive;
+use crate::scalars::Function;
+
+/// `intDiv(a, b)` divides and rounds towards zero, the division by zero is an error.
+/// `intDivOrZero(a, b)` returns zero instead of the error.
+#[derive(Clone)]
+pub struct IntDivFunction {
+    display_name: String,
+    or_zero: bool,
+}
+
+impl IntDivFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(IntDivFunction {
+            display_name: display_name.to_string(),
+            or_zero: false,
+        }))
+    }
+
+    pub fn try_create_or_zero(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(IntDivFunction {
+            display_name: display_name.to_string(),
+            or_zero: true,
+        }))
+    }
+
+    fn divide<T: num::PrimInt>(&self, lhs: T, rhs: T) -> Result<Option<T>> {
+        match (lhs.checked_div(&rhs), self.or_zero) {
+            (Some(value), _) => Ok(Some(value)),
+            (None, true) => Ok(Some(T::zero())),
+            (None, false) if rhs.is_zero() => Err(ErrorCode::BadArguments("Division by zero")),
+            (None, false) => Err(ErrorCode::BadArguments(format!(
+                "Division overflow in function {}",
+                self.display_name
+            ))),
+        }
+    }
+}
+
+impl Function for IntDivFunction {
+    fn name(&self) -> &str {
+        "IntDivFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_int_div_coercion(&args[0], &args[1])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let lhs_type = columns[0].data_type();
+        let rhs_type = columns[1].data_type();
+        let result = if is_floating(lhs_type) || is_floating(rhs_type) {
+            eval_binary_primitive::<f64, _>(columns, input_rows, |lhs, rhs| {
+                match (rhs == 0.0, self.or_zero) {
+                    (false, _) => Ok(Some((lhs / rhs).trunc())),
+                    (true, true) => Ok(Some(0.0)),
+                    (true, false) => Err(ErrorCode::BadArguments("Division by zero")),
+                }
+            })?
+        } else if is_signed_numeric(lhs_type) || is_signed_numeric(rhs_type) {
+            eval_binary_primitive::<i64, _>(columns, input_rows, |lhs, rhs| self.divide(lhs, rhs))?
+        } else {
+            eval_binary_primitive::<u64, _>(columns, input_rows, |lhs, rhs| self.divide(lhs, rhs))?
+        };
+        result.cast_with_type(&numerical_int_div_coercion(lhs_type, rhs_type)?)
+    }
+}
+
+impl fmt::Display for IntDivFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::scalars::AbsFunction;
+use crate::scalars::AcosFunction;
+use crate::scalars::AsinFunction;
+use crate::scalars::Atan2Function;
+use crate::scalars::AtanFunction;
+use crate::scalars::BitFunction;
+use crate::scalars::CbrtFunction;
+use crate::scalars::CosFunction;
+use crate::scalars::DegreesFunction;
+use crate::scalars::Exp10Function;
+use crate::scalars::Exp2Function;
+use crate::scalars::ExpFunction;
+use crate::scalars::FactoryFuncRef;
+use crate::scalars::GreatestFunction;
+use crate::scalars::IntDivFunction;
+use crate::scalars::LnFunction;
+use crate::scalars::Log10Function;
+use crate::scalars::Log2Function;
+use crate::scalars::MathConstantFunction;
+use crate::scalars::MathRoundFunction;
+use crate::scalars::PowFunction;
+use crate::scalars::RadiansFunction;
+use crate::scalars::RandFunction;
+use crate::scalars::SignFunction;
+use crate::scalars::SinFunction;
+use crate::scalars::SqrtFunction;
+use crate::scalars::TanFunction;
+
+#[derive(Clone)]
+pub struct MathFunction;
+
+impl MathFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("abs".into(), AbsFunction::try_create);
+        map.insert("sign".into(), SignFunction::try_create);
+        map.insert("round".into(), MathRoundFunction::try_create_round);
+        map.insert("floor".into(), MathRoundFunction::try_create_floor);
+        map.insert("ceil".into(), MathRoundFunction::try_create_ceil);
+        map.insert("ceiling".into(), MathRoundFunction::try_create_ceil);
+        map.insert("truncate".into(), MathRoundFunction::try_create_truncate);
+        map.insert("trunc".into(), MathRoundFunction::try_create_truncate);
+        map.insert("sqrt".into(), SqrtFunction::try_create);
+        map.insert("cbrt".into(), CbrtFunction::try_create);
+        map.insert("pow".into(), PowFunction::try_create);
+        map.insert("power".into(), PowFunction::try_create);
+        map.insert("exp".into(), ExpFunction::try_create);
+        map.insert("exp2".into(), Exp2Function::try_create);
+        map.insert("exp10".into(), Exp10Function::try_create);
+        map.insert("ln".into(), LnFunction::try_create);
+        map.insert("log".into(), LnFunction::try_create);
+        map.insert("log2".into(), Log2Function::try_create);
+        map.insert("log10".into(), Log10Function::try_create);
+        map.insert("sin".into(), SinFunction::try_create);
+        map.insert("cos".into(), CosFunction::try_create);
+        map.insert("tan".into(), TanFunction::try_create);
+        map.insert("asin".into(), AsinFunction::try_create);
+        map.insert("acos".into(), AcosFunction::try_create);
+        map.insert("atan".into(), AtanFunction::try_create);
+        map.insert("atan2".into(), Atan2Function::try_create);
+        map.insert("degrees".into(), DegreesFunction::try_create);
+        map.insert("radians".into(), RadiansFunction::try_create);
+        map.insert("pi".into(), MathConstantFunction::try_create_pi);
+        map.insert("e".into(), MathConstantFunction::try_create_e);
+        map.insert("greatest".into(), GreatestFunction::try_create);
+        map.insert("least".into(), GreatestFunction::try_create_least);
+        map.insert("rand".into(), RandFunction::try_create);
+        map.insert("randConstant".into(), RandFunction::try_create_constant);
+        map.insert("intDiv".into(), IntDivFunction::try_create);
+        map.insert("intDivOrZero".into(), IntDivFunction::try_create_or_zero);
+        map.insert("bitAnd".into(), BitFunction::try_create_and);
+        map.insert("bitOr".into(), BitFunction::try_create_or);
+        map.insert("bitXor".into(), BitFunction::try_create_xor);
+        map.insert("bitNot".into(), BitFunction::try_create_not);
+        map.insert("bitShiftLeft".into(), BitFunction::try_create_shift_left);
+        map.insert("bitShiftRight".into(), BitFunction::try_create_shift_right);
+        map.insert("bitCount".into(), BitFunction::try_create_count);
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::eval_binary_primitive;
+use crate::scalars::Function;
+
+/// A math function of two numbers computed in Float64, such as `pow` and `atan2`.
+pub trait MathBinary: Clone + Send + Sync + 'static {
+    fn apply(lhs: f64, rhs: f64) -> f64;
+}
+
+#[derive(Clone)]
+pub struct MathBinaryFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T: MathBinary> MathBinaryFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MathBinaryFunction::<T> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+}
+
+impl<T: MathBinary> Function for MathBinaryFunction<T> {
+    fn name(&self) -> &str {
+        "MathBinaryFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_math_coercion(&self.display_name, args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        eval_binary_primitive::<f64, _>(columns, input_rows, |lhs, rhs| {
+            Ok(Some(T::apply(lhs, rhs)))
+        })
+    }
+}
+
+impl<T: MathBinary> fmt::Display for MathBinaryFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[derive(Clone)]
+pub struct Pow;
+
+impl MathBinary for Pow {
+    fn apply(lhs: f64, rhs: f64) -> f64 {
+        lhs.powf(rhs)
+    }
+}
+
+#[derive(Clone)]
+pub struct Atan2;
+
+impl MathBinary for Atan2 {
+    fn apply(lhs: f64, rhs: f64) -> f64 {
+        lhs.atan2(rhs)
+    }
+}
+
+pub type PowFunction = MathBinaryFunction<Pow>;
+pub type Atan2Function = MathBinaryFunction<Atan2>;
@@ -0,0 +1,115 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::finish_column;
+
+/// The primitive values of an argument casted to `T`, a constant argument is not expanded
+/// to the rows.
+pub enum PrimitiveColumn<T: DFPrimitiveType> {
+    Constant(Option<T>),
+    Array(DFPrimitiveArray<T>),
+}
+
+impl<T: DFPrimitiveType> PrimitiveColumn<T> {
+    pub fn try_create(column: &DataColumn) -> Result<Self> {
+        let data_type = T::data_type();
+        let column = match column.data_type() == data_type {
+            true => column.clone(),
+            false => column.cast_with_type(&data_type)?,
+        };
+        match column {
+            DataColumn::Constant(value, _) if value.is_null() => {
+                Ok(PrimitiveColumn::Constant(None))
+            }
+            DataColumn::Constant(value, _) => {
+                Ok(PrimitiveColumn::Constant(Some(<T as DFTryFrom<
+                    DataValue,
+                >>::try_from(
+                    value
+                )?)))
+            }
+            DataColumn::Array(series) => {
+                let array: &DFPrimitiveArray<T> = series.static_cast();
+                Ok(PrimitiveColumn::Array(array.clone()))
+            }
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        matches!(self, PrimitiveColumn::Constant(_))
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize) -> Option<T> {
+        match self {
+            PrimitiveColumn::Constant(value) => *value,
+            PrimitiveColumn::Array(array) if array.is_null(row) => None,
+            PrimitiveColumn::Array(array) => Some(array.inner().value(row)),
+        }
+    }
+}
+
+pub type FloatColumn = PrimitiveColumn<f64>;
+
+/// Builds the primitive results, `eval` returns the result of the row or None for NULL.
+/// The result is computed once if all arguments are constants.
+pub fn build_primitive_column<T, F>(
+    is_constant: bool,
+    input_rows: usize,
+    mut eval: F,
+) -> Result<DataColumn>
+where
+    T: DFPrimitiveType,
+    F: FnMut(usize) -> Result<Option<T>>,
+{
+    let rows = if is_constant { 1 } else { input_rows };
+    let values = (0..rows).map(&mut eval).collect::<Result<Vec<_>>>()?;
+    let array = DFPrimitiveArray::<T>::new_from_opt_iter(values.into_iter());
+    finish_column(array.into_series(), is_constant, input_rows)
+}
+
+/// Evaluates a binary function of the arguments casted to `T`, the result is NULL if any
+/// argument is NULL.
+pub fn eval_binary_primitive<T, F>(
+    columns: &DataColumnsWithField,
+    input_rows: usize,
+    eval: F,
+) -> Result<DataColumn>
+where
+    T: DFPrimitiveType,
+    F: Fn(T, T) -> Result<Option<T>>,
+{
+    let lhs = PrimitiveColumn::<T>::try_create(columns[0].column())?;
+    let rhs = PrimitiveColumn::<T>::try_create(columns[1].column())?;
+    let is_constant = lhs.is_constant() && rhs.is_constant();
+    build_primitive_column(is_constant, input_rows, |row| {
+        match (lhs.get(row), rhs.get(row)) {
+            (Some(lhs), Some(rhs)) => eval(lhs, rhs),
+            _ => Ok(None),
+        }
+    })
+}
+
+/// Truncates the 64 bits integer to the lowest `bits` bits, the result is sign-extended if
+/// `T` is signed, so that it fits in the integer type of `bits` bits.
+#[inline]
+pub fn wrap_integer<T: num::PrimInt>(value: T, bits: usize) -> T {
+    match bits >= 64 {
+        true => value,
+        false => (value << (64 - bits)) >> (64 - bits),
+    }
+}
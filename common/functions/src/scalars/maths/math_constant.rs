@@ -0,0 +1,70 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// The math constants `pi()` and `e()`.
+#[derive(Clone)]
+pub struct MathConstantFunction {
+    display_name: String,
+    value: f64,
+}
+
+impl MathConstantFunction {
+    pub fn try_create_pi(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MathConstantFunction {
+            display_name: display_name.to_string(),
+            value: std::f64::consts::PI,
+        }))
+    }
+
+    pub fn try_create_e(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MathConstantFunction {
+            display_name: display_name.to_string(),
+            value: std::f64::consts::E,
+        }))
+    }
+}
+
+impl Function for MathConstantFunction {
+    fn name(&self) -> &str {
+        "MathConstantFunction"
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        Ok(DataColumn::Constant(
+            DataValue::Float64(Some(self.value)),
+            input_rows,
+        ))
+    }
+}
+
+impl fmt::Display for MathConstantFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
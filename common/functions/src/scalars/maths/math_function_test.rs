@@ -0,0 +1,242 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::*;
+
+fn column<T: DFPrimitiveType>(name: &str, values: Vec<Option<T>>) -> DataColumnWithField {
+    let values = DFPrimitiveArray::<T>::new_from_opt_iter(values.into_iter());
+    DataColumnWithField::new(
+        values.into_series().into(),
+        DataField::new(name, T::data_type(), true),
+    )
+}
+
+fn constant_column(name: &str, value: DataValue) -> DataColumnWithField {
+    let data_type = value.data_type();
+    DataColumnWithField::new(
+        DataColumn::Constant(value, 2),
+        DataField::new(name, data_type, false),
+    )
+}
+
+fn eval(func: Box<dyn Function>, columns: &[DataColumnWithField]) -> Result<Vec<DataValue>> {
+    let args = columns
+        .iter()
+        .map(|c| c.data_type().clone())
+        .collect::<Vec<_>>();
+    let data_type = func.return_type(&args)?;
+    let result = func.eval(columns, 2)?;
+    assert_eq!(result.data_type(), data_type, "{}", func);
+    (0..2).map(|row| result.try_get(row)).collect()
+}
+
+#[test]
+fn test_math_functions() -> Result<()> {
+    let ints = column::<i8>("a", vec![Some(-128), None]);
+    let floats = column::<f64>("b", vec![Some(-2.5), Some(16.0)]);
+
+    let result = eval(AbsFunction::try_create("abs")?, &[ints.clone()])?;
+    assert_eq!(result, vec![
+        DataValue::UInt8(Some(128)),
+        DataValue::UInt8(None)
+    ]);
+    let result = eval(AbsFunction::try_create("abs")?, &[floats.clone()])?;
+    assert_eq!(result, vec![
+        DataValue::Float64(Some(2.5)),
+        DataValue::Float64(Some(16.0))
+    ]);
+
+    let result = eval(SignFunction::try_create("sign")?, &[floats.clone()])?;
+    assert_eq!(result, vec![
+        DataValue::Int8(Some(-1)),
+        DataValue::Int8(Some(1))
+    ]);
+
+    let result = eval(SqrtFunction::try_create("sqrt")?, &[floats.clone()])?;
+    assert!(matches!(result[0], DataValue::Float64(Some(v)) if v.is_nan()));
+    assert_eq!(result[1], DataValue::Float64(Some(4.0)));
+
+    let exponent = constant_column("e", DataValue::UInt8(Some(2)));
+    let result = eval(PowFunction::try_create("pow")?, &[floats.clone(), exponent])?;
+    assert_eq!(result, vec![
+        DataValue::Float64(Some(6.25)),
+        DataValue::Float64(Some(256.0))
+    ]);
+
+    let result = eval(Log2Function::try_create("log2")?, &[floats.clone()])?;
+    assert_eq!(result[1], DataValue::Float64(Some(4.0)));
+
+    let pi = MathConstantFunction::try_create_pi("pi")?.eval(&[], 2)?;
+    assert_eq!(
+        pi.try_get(1)?,
+        DataValue::Float64(Some(std::f64::consts::PI))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_round_functions() -> Result<()> {
+    let floats = column::<f64>("a", vec![Some(-2.55), Some(1234.5)]);
+    let ints = column::<i32>("b", vec![Some(1250), Some(-1251)]);
+    let precision = |p: i64| constant_column("p", DataValue::Int64(Some(p)));
+
+    let result = eval(MathRoundFunction::try_create_round("round")?, &[
+        floats.clone()
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Float64(Some(-3.0)),
+        DataValue::Float64(Some(1235.0))
+    ]);
+    let args = [floats.clone(), precision(1)];
+    let result = eval(MathRoundFunction::try_create_floor("floor")?, &args)?;
+    assert_eq!(result, vec![
+        DataValue::Float64(Some(-2.6)),
+        DataValue::Float64(Some(1234.5))
+    ]);
+    let args = [floats.clone(), precision(-2)];
+    let result = eval(MathRoundFunction::try_create_ceil("ceil")?, &args)?;
+    assert_eq!(result, vec![
+        DataValue::Float64(Some(-0.0)),
+        DataValue::Float64(Some(1300.0))
+    ]);
+
+    let args = [ints.clone(), precision(-2)];
+    let result = eval(MathRoundFunction::try_create_round("round")?, &args)?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(1300)),
+        DataValue::Int32(Some(-1300))
+    ]);
+    let args = [ints.clone(), precision(-2)];
+    let result = eval(MathRoundFunction::try_create_truncate("truncate")?, &args)?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(1200)),
+        DataValue::Int32(Some(-1200))
+    ]);
+    let args = [ints, precision(2)];
+    let result = eval(MathRoundFunction::try_create_round("round")?, &args)?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(1250)),
+        DataValue::Int32(Some(-1251))
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_integer_functions() -> Result<()> {
+    let lhs = column::<i32>("a", vec![Some(-7), Some(12)]);
+    let rhs = column::<u8>("b", vec![Some(2), Some(10)]);
+
+    let result = eval(IntDivFunction::try_create("intDiv")?, &[
+        lhs.clone(),
+        rhs.clone(),
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(-3)),
+        DataValue::Int32(Some(1))
+    ]);
+
+    let zero = constant_column("z", DataValue::UInt8(Some(0)));
+    let result = eval(IntDivFunction::try_create("intDiv")?, &[
+        lhs.clone(),
+        zero.clone(),
+    ]);
+    assert_eq!(result.unwrap_err().code(), 6);
+    let result = eval(IntDivFunction::try_create_or_zero("intDivOrZero")?, &[
+        lhs.clone(),
+        zero,
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(0)),
+        DataValue::Int32(Some(0))
+    ]);
+
+    let result = eval(BitFunction::try_create_and("bitAnd")?, &[
+        lhs.clone(),
+        rhs.clone(),
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(0)),
+        DataValue::Int32(Some(8))
+    ]);
+    let result = eval(BitFunction::try_create_xor("bitXor")?, &[
+        lhs.clone(),
+        rhs.clone(),
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(-5)),
+        DataValue::Int32(Some(6))
+    ]);
+
+    let bytes = column::<u8>("c", vec![Some(0b1010_0000), Some(1)]);
+    let result = eval(BitFunction::try_create_not("bitNot")?, &[bytes.clone()])?;
+    assert_eq!(result, vec![
+        DataValue::UInt8(Some(0b0101_1111)),
+        DataValue::UInt8(Some(254))
+    ]);
+    let shift = constant_column("s", DataValue::UInt8(Some(1)));
+    let result = eval(BitFunction::try_create_shift_left("bitShiftLeft")?, &[
+        bytes.clone(),
+        shift.clone(),
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::UInt8(Some(0b0100_0000)),
+        DataValue::UInt8(Some(2))
+    ]);
+    let result = eval(BitFunction::try_create_shift_right("bitShiftRight")?, &[
+        lhs.clone(),
+        shift,
+    ])?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(-4)),
+        DataValue::Int32(Some(6))
+    ]);
+
+    let result = eval(BitFunction::try_create_count("bitCount")?, &[lhs])?;
+    assert_eq!(result, vec![
+        DataValue::UInt8(Some(30)),
+        DataValue::UInt8(Some(2))
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_greatest_and_rand_functions() -> Result<()> {
+    let a = column::<i32>("a", vec![Some(3), None]);
+    let b = column::<u8>("b", vec![Some(5), Some(1)]);
+    let c = constant_column("c", DataValue::Int8(Some(4)));
+
+    let args = [a.clone(), b.clone(), c.clone()];
+    let result = eval(GreatestFunction::try_create("greatest")?, &args)?;
+    assert_eq!(result, vec![
+        DataValue::Int32(Some(5)),
+        DataValue::Int32(None)
+    ]);
+    let result = eval(GreatestFunction::try_create_least("least")?, &args[1..])?;
+    assert_eq!(result, vec![
+        DataValue::Int16(Some(4)),
+        DataValue::Int16(Some(1))
+    ]);
+
+    let func = RandFunction::try_create("rand")?;
+    assert!(!func.is_deterministic());
+    assert!(matches!(func.eval(&[], 2)?, DataColumn::Array(_)));
+    let func = RandFunction::try_create_constant("randConstant")?;
+    assert!(!func.is_deterministic());
+    assert!(matches!(func.eval(&[], 2)?, DataColumn::Constant(_, 2)));
+    Ok(())
+}
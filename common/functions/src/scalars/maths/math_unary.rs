@@ -0,0 +1,111 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_primitive_column;
+use crate::scalars::FloatColumn;
+use crate::scalars::Function;
+
+/// A math function of a number computed in Float64, such as `sqrt` and `sin`.
+pub trait MathUnary: Clone + Send + Sync + 'static {
+    fn apply(value: f64) -> f64;
+}
+
+#[derive(Clone)]
+pub struct MathUnaryFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T: MathUnary> MathUnaryFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MathUnaryFunction::<T> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+}
+
+impl<T: MathUnary> Function for MathUnaryFunction<T> {
+    fn name(&self) -> &str {
+        "MathUnaryFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_math_coercion(&self.display_name, args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = FloatColumn::try_create(columns[0].column())?;
+        build_primitive_column(values.is_constant(), input_rows, |row| {
+            Ok(values.get(row).map(T::apply))
+        })
+    }
+}
+
+impl<T: MathUnary> fmt::Display for MathUnaryFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn exp10(value: f64) -> f64 {
+    10f64.powf(value)
+}
+
+macro_rules! math_unary {
+    ($op: ident, $function: ident, $apply: path) => {
+        #[derive(Clone)]
+        pub struct $op;
+
+        impl MathUnary for $op {
+            #[inline]
+            fn apply(value: f64) -> f64 {
+                $apply(value)
+            }
+        }
+
+        pub type $function = MathUnaryFunction<$op>;
+    };
+}
+
+math_unary!(Sqrt, SqrtFunction, f64::sqrt);
+math_unary!(Cbrt, CbrtFunction, f64::cbrt);
+math_unary!(Exp, ExpFunction, f64::exp);
+math_unary!(Exp2, Exp2Function, f64::exp2);
+math_unary!(Exp10, Exp10Function, exp10);
+math_unary!(Ln, LnFunction, f64::ln);
+math_unary!(Log2, Log2Function, f64::log2);
+math_unary!(Log10, Log10Function, f64::log10);
+math_unary!(Sin, SinFunction, f64::sin);
+math_unary!(Cos, CosFunction, f64::cos);
+math_unary!(Tan, TanFunction, f64::tan);
+math_unary!(Asin, AsinFunction, f64::asin);
+math_unary!(Acos, AcosFunction, f64::acos);
+math_unary!(Atan, AtanFunction, f64::atan);
+math_unary!(Degrees, DegreesFunction, f64::to_degrees);
+math_unary!(Radians, RadiansFunction, f64::to_radians);
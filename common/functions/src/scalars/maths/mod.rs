@@ -0,0 +1,42 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(test)]
+mod math_function_test;
+
+mod abs;
+mod bit;
+mod greatest;
+mod int_div;
+mod math;
+mod math_binary;
+mod math_common;
+mod math_constant;
+mod math_unary;
+mod random;
+mod round;
+mod sign;
+
+pub use abs::AbsFunction;
+pub use bit::BitFunction;
+pub use greatest::GreatestFunction;
+pub use int_div::IntDivFunction;
+pub use math::MathFunction;
+pub use math_binary::*;
+pub use math_common::*;
+pub use math_constant::MathConstantFunction;
+pub use math_unary::*;
+pub use random::RandFunction;
+pub use round::MathRoundFunction;
+pub use sign::SignFunction;
@@ -0,0 +1,88 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use rand::Rng;
+
+use crate::scalars::Function;
+
+/// `rand([x])` returns a random UInt32 for each row, and `randConstant([x])` returns the same
+/// random UInt32 for all rows of a block. The optional argument is ignored, it makes the
+/// calls distinct expressions.
+#[derive(Clone)]
+pub struct RandFunction {
+    display_name: String,
+    constant: bool,
+}
+
+impl RandFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RandFunction {
+            display_name: display_name.to_string(),
+            constant: false,
+        }))
+    }
+
+    pub fn try_create_constant(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RandFunction {
+            display_name: display_name.to_string(),
+            constant: true,
+        }))
+    }
+}
+
+impl Function for RandFunction {
+    fn name(&self) -> &str {
+        "RandFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, 1))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt32)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let mut rng = rand::thread_rng();
+        match self.constant {
+            true => Ok(DataColumn::Constant(
+                DataValue::UInt32(Some(rng.gen())),
+                input_rows,
+            )),
+            false => {
+                let values = (0..input_rows).map(|_| rng.gen()).collect::<Vec<u32>>();
+                Ok(DFUInt32Array::new_from_slice(&values).into_series().into())
+            }
+        }
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for RandFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_primitive_column;
+use crate::scalars::FloatColumn;
+use crate::scalars::Function;
+use crate::scalars::IntColumn;
+
+#[derive(Clone, Copy)]
+enum RoundMode {
+    Round,
+    Floor,
+    Ceil,
+    Truncate,
+}
+
+/// `round(x[, d])`, `floor(x[, d])`, `ceil(x[, d])` and `truncate(x[, d])` round the number
+/// to `d` decimal places, `d` defaults to 0 and rounds to the tens, hundreds... if it's
+/// negative. `round` rounds half away from zero. The result has the type of `x`.
+#[derive(Clone)]
+pub struct MathRoundFunction {
+    display_name: String,
+    mode: RoundMode,
+}
+
+impl MathRoundFunction {
+    fn try_create(display_name: &str, mode: RoundMode) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MathRoundFunction {
+            display_name: display_name.to_string(),
+            mode,
+        }))
+    }
+
+    pub fn try_create_round(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, RoundMode::Round)
+    }
+
+    pub fn try_create_floor(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, RoundMode::Floor)
+    }
+
+    pub fn try_create_ceil(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, RoundMode::Ceil)
+    }
+
+    pub fn try_create_truncate(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::try_create(display_name, RoundMode::Truncate)
+    }
+
+    #[inline]
+    fn apply(&self, value: f64) -> f64 {
+        match self.mode {
+            RoundMode::Round => value.round(),
+            RoundMode::Floor => value.floor(),
+            RoundMode::Ceil => value.ceil(),
+            RoundMode::Truncate => value.trunc(),
+        }
+    }
+
+    fn round(&self, value: f64, precision: i64) -> f64 {
+        let exponent = precision.clamp(-308, 308) as i32;
+        match exponent {
+            0 => self.apply(value),
+            e if e > 0 => {
+                let scale = 10f64.powi(e);
+                let scaled = value * scale;
+                match scaled.is_finite() {
+                    true => self.apply(scaled) / scale,
+                    false => value,
+                }
+            }
+            e => {
+                let scale = 10f64.powi(-e);
+                self.apply(value / scale) * scale
+            }
+        }
+    }
+}
+
+impl Function for MathRoundFunction {
+    fn name(&self) -> &str {
+        "MathRoundFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_round_coercion(&self.display_name, args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_type = columns[0].data_type();
+        let precisions = match columns.len() {
+            2 => IntColumn::try_create(columns[1].column())?,
+            _ => IntColumn::Constant(Some(0)),
+        };
+        // the integers don't have decimal places
+        if is_integer(data_type) && matches!(precisions, IntColumn::Constant(Some(p)) if p >= 0) {
+            return Ok(columns[0].column().clone());
+        }
+
+        let values = FloatColumn::try_create(columns[0].column())?;
+        let is_constant = values.is_constant() && precisions.is_constant();
+        let result = build_primitive_column(is_constant, input_rows, |row| {
+            match (values.get(row), precisions.get(row)) {
+                (Some(value), Some(precision)) => Ok(Some(self.round(value, precision))),
+                _ => Ok(None),
+            }
+        })?;
+        result.cast_with_type(data_type)
+    }
+}
+
+impl fmt::Display for MathRoundFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
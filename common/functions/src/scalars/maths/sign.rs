@@ -0,0 +1,76 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_primitive_column;
+use crate::scalars::FloatColumn;
+use crate::scalars::Function;
+
+/// `sign(x)` returns -1, 0 or 1 for the negative numbers, zero and the positive numbers.
+#[derive(Clone)]
+pub struct SignFunction {
+    display_name: String,
+}
+
+impl SignFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SignFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for SignFunction {
+    fn name(&self) -> &str {
+        "SignFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_args_check(&self.display_name, args, false)?;
+        Ok(DataType::Int8)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = FloatColumn::try_create(columns[0].column())?;
+        build_primitive_column(values.is_constant(), input_rows, |row| {
+            Ok(values.get(row).map(|value| {
+                if value > 0.0 {
+                    1i8
+                } else if value < 0.0 {
+                    -1i8
+                } else {
+                    0i8
+                }
+            }))
+        })
+    }
+}
+
+impl fmt::Display for SignFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
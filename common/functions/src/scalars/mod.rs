@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -26,12 +27,16 @@ mod function_factory;
 mod function_literal;
 mod hashes;
 mod logics;
+mod maths;
 mod nullables;
 mod others;
+mod regexps;
 mod strings;
 mod udfs;
+mod variants;
 
 pub use arithmetics::*;
+pub use arrays::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
@@ -44,7 +49,10 @@ pub use function_factory::FunctionFactory;
 pub use function_literal::LiteralFunction;
 pub use hashes::*;
 pub use logics::*;
+pub use maths::*;
 pub use nullables::*;
 pub use others::*;
+pub use regexps::*;
 pub use strings::*;
 pub use udfs::*;
+pub use variants::*;
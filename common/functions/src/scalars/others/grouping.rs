@@ -0,0 +1,104 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// grouping(_grouping_id, index1, index2, ...), the backend of `grouping(a, b, ...)` after
+/// the arguments are rebased to their indexes in the group by expressions. The result has
+/// one bit per argument, the leftmost argument is the most significant bit, and a bit is
+/// set when the argument is rolled up in the grouping set of the row.
+#[derive(Clone)]
+pub struct GroupingFunction {
+    _display_name: String,
+}
+
+impl GroupingFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GroupingFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for GroupingFunction {
+    fn name(&self) -> &str {
+        "GroupingFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 33))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] != DataType::UInt32 {
+            return Err(ErrorCode::IllegalDataType(
+                "Function grouping must be used with GROUP BY ROLLUP, CUBE or GROUPING SETS",
+            ));
+        }
+        Ok(DataType::UInt32)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let indexes = columns[1..]
+            .iter()
+            .map(|c| match c.column() {
+                DataColumn::Constant(value, _) => Ok(value.as_u64()?),
+                _ => Err(ErrorCode::BadArguments(
+                    "Function grouping expects constant key indexes",
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let grouping = |id: u32| {
+            indexes
+                .iter()
+                .fold(0u32, |result, index| (result << 1) | ((id >> *index) & 1))
+        };
+
+        match columns[0].column() {
+            DataColumn::Constant(value, _) => {
+                let id = value.as_u64()? as u32;
+                Ok(DataColumn::Constant(
+                    DataValue::UInt32(Some(grouping(id))),
+                    input_rows,
+                ))
+            }
+            column => {
+                let series = column.to_array()?;
+                let ids = series.u32()?;
+                let result =
+                    DFUInt32Array::new_from_iter(ids.into_no_null_iter().map(|id| grouping(*id)));
+                Ok(result.into_series().into())
+            }
+        }
+    }
+}
+
+impl fmt::Display for GroupingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "grouping")
+    }
+}
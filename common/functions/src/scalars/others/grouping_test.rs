@@ -0,0 +1,77 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::*;
+
+#[test]
+fn test_grouping_function() -> Result<()> {
+    // The grouping ids of the sets (a, b), (a) and () over the keys [a, b].
+    let ids = DFUInt32Array::new_from_slice(&[0, 2, 3]);
+    let ids = DataColumnWithField::new(
+        ids.into_series().into(),
+        DataField::new("_grouping_id", DataType::UInt32, false),
+    );
+    let index = |i: u32| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::UInt32(Some(i)), 3),
+            DataField::new(&format!("{}", i), DataType::UInt32, false),
+        )
+    };
+
+    let tests = vec![
+        ("grouping(a)", vec![ids.clone(), index(0)], vec![0u32, 0, 1]),
+        ("grouping(b)", vec![ids.clone(), index(1)], vec![0, 1, 1]),
+        (
+            "grouping(a, b)",
+            vec![ids.clone(), index(0), index(1)],
+            vec![0, 1, 3],
+        ),
+        (
+            "grouping(b, a)",
+            vec![ids.clone(), index(1), index(0)],
+            vec![0, 2, 3],
+        ),
+    ];
+
+    for (name, columns, expect) in tests {
+        let func = GroupingFunction::try_create("grouping")?;
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(func.return_type(&args)?, DataType::UInt32, "{}", name);
+
+        let result = func.eval(&columns, 3)?;
+        let actual = (0..3)
+            .map(|row| result.try_get(row))
+            .collect::<Result<Vec<_>>>()?;
+        let expect = expect
+            .into_iter()
+            .map(|v| DataValue::UInt32(Some(v)))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expect, "{}", name);
+    }
+
+    // The grouping id is only produced by grouping sets.
+    let func = GroupingFunction::try_create("grouping")?;
+    let result = func.return_type(&[DataType::UInt64, DataType::UInt32]);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Function grouping must be used with GROUP BY ROLLUP, CUBE or GROUPING SETS"
+    );
+    Ok(())
+}
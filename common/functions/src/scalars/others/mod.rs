@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 #[cfg(test)]
+mod grouping_test;
+#[cfg(test)]
 mod running_difference_function_test;
 
+mod grouping;
 mod other;
 mod running_difference_function;
+pub use grouping::GroupingFunction;
 pub use other::OtherFunction;
 pub use running_difference_function::RunningDifferenceFunction;
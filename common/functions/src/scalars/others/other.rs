@@ -14,6 +14,7 @@
 
 use common_exception::Result;
 
+use super::grouping::GroupingFunction;
 use super::running_difference_function::RunningDifferenceFunction;
 use crate::scalars::FactoryFuncRef;
 
@@ -27,6 +28,7 @@ impl OtherFunction {
             "runningDifference".into(),
             RunningDifferenceFunction::try_create,
         );
+        map.insert("grouping".into(), GroupingFunction::try_create);
         Ok(())
     }
 }
@@ -0,0 +1,99 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::default_group;
+use crate::scalars::finish_column;
+use crate::scalars::list_type;
+use crate::scalars::Function;
+use crate::scalars::RegexpCache;
+use crate::scalars::RegexpColumn;
+use crate::scalars::StringColumn;
+
+/// `extractAll(s, pattern)` returns an array of all the non-overlapping matches, a match
+/// is the first capturing group if the pattern has one, otherwise the whole match.
+#[derive(Clone)]
+pub struct ExtractAllFunction {
+    display_name: String,
+    cache: RegexpCache,
+}
+
+impl ExtractAllFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ExtractAllFunction {
+            display_name: display_name.to_string(),
+            cache: RegexpCache::default(),
+        }))
+    }
+}
+
+impl Function for ExtractAllFunction {
+    fn name(&self) -> &str {
+        "ExtractAllFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(list_type(DataType::String))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let mut patterns = RegexpColumn::try_create(columns[1].column(), &self.cache)?;
+        let is_constant = values.is_constant() && patterns.is_constant();
+
+        let rows = if is_constant { 1 } else { input_rows };
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        let mut offsets = Vec::with_capacity(rows + 1);
+        let mut validity = Vec::with_capacity(rows);
+        let mut items = 0;
+        offsets.push(0);
+        for row in 0..rows {
+            match (values.get(row), patterns.get(row)?) {
+                (Some(value), Some(regex)) => {
+                    let group = default_group(regex);
+                    for captures in regex.captures_iter(value) {
+                        let matched = captures.get(group).map_or(&[][..], |m| m.as_bytes());
+                        builder.append_value(matched);
+                        items += 1;
+                    }
+                    validity.push(true);
+                }
+                _ => validity.push(false),
+            }
+            offsets.push(items as i64);
+        }
+
+        let items = builder.finish().into_series();
+        let array = DFListArray::from_flatten(&items, offsets, Some(validity));
+        finish_column(array.into_series(), is_constant, input_rows)
+    }
+}
+
+impl fmt::Display for ExtractAllFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
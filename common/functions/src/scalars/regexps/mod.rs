@@ -0,0 +1,32 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(test)]
+mod regexp_function_test;
+
+mod extract_all;
+mod regexp;
+mod regexp_common;
+mod regexp_count;
+mod regexp_extract;
+mod regexp_like;
+mod regexp_replace;
+
+pub use extract_all::ExtractAllFunction;
+pub use regexp::RegexpFunction;
+pub use regexp_common::*;
+pub use regexp_count::RegexpCountFunction;
+pub use regexp_extract::RegexpExtractFunction;
+pub use regexp_like::RegexpLikeFunction;
+pub use regexp_replace::RegexpReplaceFunction;
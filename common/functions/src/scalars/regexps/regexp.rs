@@ -0,0 +1,37 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::scalars::ExtractAllFunction;
+use crate::scalars::FactoryFuncRef;
+use crate::scalars::RegexpCountFunction;
+use crate::scalars::RegexpExtractFunction;
+use crate::scalars::RegexpLikeFunction;
+use crate::scalars::RegexpReplaceFunction;
+
+#[derive(Clone)]
+pub struct RegexpFunction;
+
+impl RegexpFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("regexp_like".into(), RegexpLikeFunction::try_create);
+        map.insert("regexp_extract".into(), RegexpExtractFunction::try_create);
+        map.insert("regexp_replace".into(), RegexpReplaceFunction::try_create);
+        map.insert("regexp_count".into(), RegexpCountFunction::try_create);
+        map.insert("extractAll".into(), ExtractAllFunction::try_create);
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use regex::bytes::Regex;
+
+use crate::scalars::StringColumn;
+
+/// Compiles the pattern, which must be a valid UTF-8 regular expression.
+pub fn compile_regexp(pattern: &[u8]) -> Result<Regex> {
+    let pattern = std::str::from_utf8(pattern).map_err(|_| {
+        ErrorCode::BadArguments("The regular expression pattern must be a valid UTF-8 string")
+    })?;
+    Regex::new(pattern).map_err(|e| {
+        ErrorCode::BadArguments(format!(
+            "Invalid regular expression pattern '{}': {}",
+            pattern, e
+        ))
+    })
+}
+
+/// The group returned by default, which is the first capturing group if the pattern has
+/// one, otherwise the whole match.
+#[inline]
+pub fn default_group(regex: &Regex) -> usize {
+    match regex.captures_len() > 1 {
+        true => 1,
+        false => 0,
+    }
+}
+
+/// The last compiled constant pattern of a function, it is shared by the clones of the
+/// function so a constant pattern is compiled once instead of once per block.
+#[derive(Clone, Default)]
+pub struct RegexpCache {
+    cached: Arc<Mutex<Option<(Vec<u8>, Regex)>>>,
+}
+
+impl RegexpCache {
+    pub fn get_or_compile(&self, pattern: &[u8]) -> Result<Regex> {
+        let mut cached = self.cached.lock();
+        match cached.as_ref() {
+            Some((cached_pattern, regex)) if cached_pattern == pattern => Ok(regex.clone()),
+            _ => {
+                let regex = compile_regexp(pattern)?;
+                *cached = Some((pattern.to_vec(), regex.clone()));
+                Ok(regex)
+            }
+        }
+    }
+}
+
+/// The compiled patterns of an argument. A constant pattern comes from the cache, the
+/// patterns of an array are compiled by rows and the consecutive duplicates are reused.
+pub struct RegexpColumn {
+    patterns: StringColumn,
+    last: Option<(Vec<u8>, Regex)>,
+}
+
+impl RegexpColumn {
+    pub fn try_create(column: &DataColumn, cache: &RegexpCache) -> Result<Self> {
+        let patterns = StringColumn::try_create(column)?;
+        let last = match &patterns {
+            StringColumn::Constant(Some(pattern)) => {
+                Some((pattern.clone(), cache.get_or_compile(pattern)?))
+            }
+            _ => None,
+        };
+        Ok(RegexpColumn { patterns, last })
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.patterns.is_constant()
+    }
+
+    pub fn get(&mut self, row: usize) -> Result<Option<&Regex>> {
+        let pattern = match self.patterns.get(row) {
+            Some(pattern) => pattern,
+            None => return Ok(None),
+        };
+        let reusable = matches!(&self.last, Some((last, _)) if last.as_slice() == pattern);
+        if !reusable {
+            self.last = Some((pattern.to_vec(), compile_regexp(pattern)?));
+        }
+        Ok(self.last.as_ref().map(|(_, regex)| regex))
+    }
+}
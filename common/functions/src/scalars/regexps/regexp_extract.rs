@@ -0,0 +1,113 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use crate::scalars::build_string_column;
+use crate::scalars::default_group;
+use crate::scalars::Function;
+use crate::scalars::IntColumn;
+use crate::scalars::RegexpCache;
+use crate::scalars::RegexpColumn;
+use crate::scalars::StringColumn;
+
+/// `regexp_extract(s, pattern[, group])` returns the group of the first match, which is
+/// the first capturing group if the pattern has one, otherwise the whole match. An empty
+/// string is returned if there is no match or the group does not participate.
+#[derive(Clone)]
+pub struct RegexpExtractFunction {
+    display_name: String,
+    cache: RegexpCache,
+}
+
+impl RegexpExtractFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpExtractFunction {
+            display_name: display_name.to_string(),
+            cache: RegexpCache::default(),
+        }))
+    }
+}
+
+/// Checks the group index is in the range of the groups of the pattern.
+fn check_group(regex: &Regex, group: i64) -> Result<usize> {
+    match group >= 0 && (group as usize) < regex.captures_len() {
+        true => Ok(group as usize),
+        false => Err(ErrorCode::BadArguments(format!(
+            "The group index {} is out of range, the pattern '{}' has {} groups",
+            group,
+            regex.as_str(),
+            regex.captures_len() - 1
+        ))),
+    }
+}
+
+impl Function for RegexpExtractFunction {
+    fn name(&self) -> &str {
+        "RegexpExtractFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let mut patterns = RegexpColumn::try_create(columns[1].column(), &self.cache)?;
+        let groups = match columns.len() {
+            3 => Some(IntColumn::try_create(columns[2].column())?),
+            _ => None,
+        };
+        let is_constant = values.is_constant()
+            && patterns.is_constant()
+            && groups.as_ref().map_or(true, |groups| groups.is_constant());
+
+        build_string_column(is_constant, input_rows, |row, buffer| {
+            let (value, regex) = match (values.get(row), patterns.get(row)?) {
+                (Some(value), Some(regex)) => (value, regex),
+                _ => return Ok(false),
+            };
+            let group = match &groups {
+                None => default_group(regex),
+                Some(groups) => match groups.get(row) {
+                    Some(group) => check_group(regex, group)?,
+                    None => return Ok(false),
+                },
+            };
+            if let Some(matched) = regex.captures(value).and_then(|c| c.get(group)) {
+                buffer.extend_from_slice(matched.as_bytes());
+            }
+            Ok(true)
+        })
+    }
+}
+
+impl fmt::Display for RegexpExtractFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::*;
+
+fn string_column(name: &str, values: Vec<Option<&str>>) -> DataColumnWithField {
+    let values: DFStringArray = values.iter().map(|v| v.map(|v| v.as_bytes())).collect();
+    DataColumnWithField::new(
+        values.into_series().into(),
+        DataField::new(name, DataType::String, true),
+    )
+}
+
+fn constant_column(name: &str, value: DataValue) -> DataColumnWithField {
+    let data_type = value.data_type();
+    DataColumnWithField::new(
+        DataColumn::Constant(value, 2),
+        DataField::new(name, data_type, false),
+    )
+}
+
+fn pattern_column(pattern: &str) -> DataColumnWithField {
+    constant_column("p", DataValue::String(Some(pattern.as_bytes().to_vec())))
+}
+
+fn eval_values(func: &dyn Function, columns: &[DataColumnWithField]) -> Result<Vec<DataValue>> {
+    let result = func.eval(columns, 2)?;
+    (0..2).map(|row| result.try_get(row)).collect()
+}
+
+fn strings(values: Vec<Option<&str>>) -> Vec<DataValue> {
+    values
+        .iter()
+        .map(|v| DataValue::String(v.map(|v| v.as_bytes().to_vec())))
+        .collect()
+}
+
+#[test]
+fn test_regexp_functions() -> Result<()> {
+    let values = string_column("a", vec![Some("k1=v1, k2=v2"), None]);
+
+    let func = RegexpLikeFunction::try_create("regexp_like")?;
+    let result = eval_values(func.as_ref(), &[values.clone(), pattern_column(r"^k\d=")])?;
+    assert_eq!(result, vec![
+        DataValue::Boolean(Some(true)),
+        DataValue::Boolean(None)
+    ]);
+
+    let func = RegexpExtractFunction::try_create("regexp_extract")?;
+    let result = eval_values(func.as_ref(), &[
+        values.clone(),
+        pattern_column(r"k\d=(\w+)"),
+    ])?;
+    assert_eq!(result, strings(vec![Some("v1"), None]));
+    let group = constant_column("g", DataValue::UInt8(Some(0)));
+    let args = [values.clone(), pattern_column(r"k\d=(\w+)"), group];
+    let result = eval_values(func.as_ref(), &args)?;
+    assert_eq!(result, strings(vec![Some("k1=v1"), None]));
+    let result = eval_values(func.as_ref(), &[values.clone(), pattern_column("x")])?;
+    assert_eq!(result, strings(vec![Some(""), None]));
+
+    let func = RegexpReplaceFunction::try_create("regexp_replace")?;
+    let replacement = constant_column("r", DataValue::String(Some(b"$2:$1".to_vec())));
+    let args = [values.clone(), pattern_column(r"(\w+)=(\w+)"), replacement];
+    let result = eval_values(func.as_ref(), &args)?;
+    assert_eq!(result, strings(vec![Some("v1:k1, v2:k2"), None]));
+
+    let func = RegexpCountFunction::try_create("regexp_count")?;
+    let result = eval_values(func.as_ref(), &[values.clone(), pattern_column(r"\d")])?;
+    assert_eq!(result, vec![
+        DataValue::UInt64(Some(4)),
+        DataValue::UInt64(None)
+    ]);
+
+    let func = ExtractAllFunction::try_create("extractAll")?;
+    let result = eval_values(func.as_ref(), &[values.clone(), pattern_column(r"(\w+)=")])?;
+    assert_eq!(
+        result[0],
+        DataValue::List(
+            Some(strings(vec![Some("k1"), Some("k2")])),
+            DataType::String
+        )
+    );
+    assert!(result[1].is_null());
+    Ok(())
+}
+
+#[test]
+fn test_regexp_patterns() -> Result<()> {
+    // the patterns differ by rows
+    let values = string_column("a", vec![Some("abc"), Some("abc")]);
+    let patterns = string_column("p", vec![Some("^a"), Some("^b")]);
+    let func = RegexpLikeFunction::try_create("regexp_like")?;
+    let result = eval_values(func.as_ref(), &[values.clone(), patterns])?;
+    assert_eq!(result, vec![
+        DataValue::Boolean(Some(true)),
+        DataValue::Boolean(Some(false))
+    ]);
+
+    // the constant pattern is cached by the function and the clones of it
+    let cloned = dyn_clone::clone_box(func.as_ref());
+    for pattern in ["c$", "c$", "^x"] {
+        let result = eval_values(cloned.as_ref(), &[values.clone(), pattern_column(pattern)])?;
+        assert_eq!(result[0], DataValue::Boolean(Some(pattern == "c$")));
+    }
+
+    let result = eval_values(func.as_ref(), &[values.clone(), pattern_column("(a")]);
+    assert_eq!(result.unwrap_err().code(), 6);
+
+    let func = RegexpExtractFunction::try_create("regexp_extract")?;
+    let group = constant_column("g", DataValue::UInt8(Some(2)));
+    let result = eval_values(func.as_ref(), &[values, pattern_column("(a)"), group]);
+    assert_eq!(result.unwrap_err().code(), 6);
+    Ok(())
+}
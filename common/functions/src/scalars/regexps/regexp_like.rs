@@ -0,0 +1,82 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::finish_column;
+use crate::scalars::Function;
+use crate::scalars::RegexpCache;
+use crate::scalars::RegexpColumn;
+use crate::scalars::StringColumn;
+
+/// `regexp_like(s, pattern)` returns whether `s` matches the pattern anywhere, the operators
+/// `s REGEXP pattern` and `s RLIKE pattern` are rewritten to it by the parser.
+#[derive(Clone)]
+pub struct RegexpLikeFunction {
+    display_name: String,
+    cache: RegexpCache,
+}
+
+impl RegexpLikeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpLikeFunction {
+            display_name: display_name.to_string(),
+            cache: RegexpCache::default(),
+        }))
+    }
+}
+
+impl Function for RegexpLikeFunction {
+    fn name(&self) -> &str {
+        "RegexpLikeFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let mut patterns = RegexpColumn::try_create(columns[1].column(), &self.cache)?;
+        let is_constant = values.is_constant() && patterns.is_constant();
+
+        let rows = if is_constant { 1 } else { input_rows };
+        let mut result = Vec::with_capacity(rows);
+        for row in 0..rows {
+            match (values.get(row), patterns.get(row)?) {
+                (Some(value), Some(regex)) => result.push(Some(regex.is_match(value))),
+                _ => result.push(None),
+            }
+        }
+        let result = DFBooleanArray::new_from_opt_iter(result.into_iter());
+        finish_column(result.into_series(), is_constant, input_rows)
+    }
+}
+
+impl fmt::Display for RegexpLikeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
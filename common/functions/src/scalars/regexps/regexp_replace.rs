@@ -0,0 +1,83 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_string_column;
+use crate::scalars::Function;
+use crate::scalars::RegexpCache;
+use crate::scalars::RegexpColumn;
+use crate::scalars::StringColumn;
+
+/// `regexp_replace(s, pattern, replacement)` replaces all matches of the pattern in `s`,
+/// the replacement refers to the groups by `$1` or `${name}`, and `$$` is a literal `$`.
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+    cache: RegexpCache,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpReplaceFunction {
+            display_name: display_name.to_string(),
+            cache: RegexpCache::default(),
+        }))
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        "RegexpReplaceFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let mut patterns = RegexpColumn::try_create(columns[1].column(), &self.cache)?;
+        let replacements = StringColumn::try_create(columns[2].column())?;
+        let is_constant =
+            values.is_constant() && patterns.is_constant() && replacements.is_constant();
+
+        build_string_column(is_constant, input_rows, |row, buffer| {
+            match (values.get(row), patterns.get(row)?, replacements.get(row)) {
+                (Some(value), Some(regex), Some(replacement)) => {
+                    buffer.extend_from_slice(&regex.replace_all(value, replacement));
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        })
+    }
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
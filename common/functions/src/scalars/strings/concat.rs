@@ -0,0 +1,110 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_string_column;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// `concat(s1, s2, ...)` concatenates the strings, NULL if any argument is NULL.
+/// `concat_ws(sep, s1, s2, ...)` concatenates the strings with the separator, the NULL strings
+/// are skipped as MySQL.
+#[derive(Clone)]
+pub struct ConcatFunction {
+    display_name: String,
+    with_separator: bool,
+}
+
+impl ConcatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ConcatFunction {
+            display_name: display_name.to_string(),
+            with_separator: false,
+        }))
+    }
+
+    pub fn try_create_with_separator(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ConcatFunction {
+            display_name: display_name.to_string(),
+            with_separator: true,
+        }))
+    }
+}
+
+impl Function for ConcatFunction {
+    fn name(&self) -> &str {
+        "ConcatFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        match self.with_separator {
+            true => Some((2, usize::MAX)),
+            false => Some((1, usize::MAX)),
+        }
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = columns
+            .iter()
+            .map(|c| StringColumn::try_create(c.column()))
+            .collect::<Result<Vec<_>>>()?;
+        let is_constant = values.iter().all(|v| v.is_constant());
+
+        if !self.with_separator {
+            return build_string_column(is_constant, input_rows, |row, buffer| {
+                for value in &values {
+                    match value.get(row) {
+                        Some(value) => buffer.extend_from_slice(value),
+                        None => return Ok(false),
+                    }
+                }
+                Ok(true)
+            });
+        }
+
+        build_string_column(is_constant, input_rows, |row, buffer| {
+            let separator = match values[0].get(row) {
+                Some(separator) => separator,
+                None => return Ok(false),
+            };
+            let mut first = true;
+            for value in values[1..].iter().filter_map(|v| v.get(row)) {
+                if !first {
+                    buffer.extend_from_slice(separator);
+                }
+                buffer.extend_from_slice(value);
+                first = false;
+            }
+            Ok(true)
+        })
+    }
+}
+
+impl fmt::Display for ConcatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
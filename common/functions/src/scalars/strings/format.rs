@@ -0,0 +1,161 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::build_string_column;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// `format(pattern, s1, s2, ...)` formats the arguments with the pattern like
+/// `'{} and {}'` or `'{1} and {0}'`, `{{` and `}}` are the literal braces.
+#[derive(Clone)]
+pub struct FormatFunction {
+    display_name: String,
+}
+
+/// The parts of a format pattern.
+#[derive(Debug, PartialEq)]
+pub enum FormatPart {
+    Literal(Vec<u8>),
+    Argument(usize),
+}
+
+impl FormatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    /// Parses the pattern, the arguments are indexed from 0.
+    pub fn parse_pattern(pattern: &[u8], arguments: usize) -> Result<Vec<FormatPart>> {
+        let illegal = |message: &str| {
+            ErrorCode::BadArguments(format!(
+                "Illegal format pattern '{}': {}",
+                String::from_utf8_lossy(pattern),
+                message
+            ))
+        };
+
+        let mut parts = vec![];
+        let mut literal = vec![];
+        let mut next_index = 0;
+        let mut pos = 0;
+        while pos < pattern.len() {
+            match (pattern[pos], pattern.get(pos + 1)) {
+                (b'{', Some(b'{')) | (b'}', Some(b'}')) => {
+                    literal.push(pattern[pos]);
+                    pos += 2;
+                }
+                (b'{', _) => {
+                    let end = pattern[pos..]
+                        .iter()
+                        .position(|c| *c == b'}')
+                        .ok_or_else(|| illegal("unclosed '{'"))?;
+                    let index = &pattern[pos + 1..pos + end];
+                    let index = match index.is_empty() {
+                        true => {
+                            next_index += 1;
+                            next_index - 1
+                        }
+                        false => std::str::from_utf8(index)
+                            .ok()
+                            .and_then(|i| i.parse::<usize>().ok())
+                            .ok_or_else(|| illegal("the argument index is not a number"))?,
+                    };
+                    if index >= arguments {
+                        return Err(illegal("not enough arguments"));
+                    }
+                    if !literal.is_empty() {
+                        parts.push(FormatPart::Literal(literal.split_off(0)));
+                    }
+                    parts.push(FormatPart::Argument(index));
+                    pos += end + 1;
+                }
+                (b'}', _) => return Err(illegal("unmatched '}'")),
+                (c, _) => {
+                    literal.push(c);
+                    pos += 1;
+                }
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(FormatPart::Literal(literal));
+        }
+        Ok(parts)
+    }
+}
+
+impl Function for FormatFunction {
+    fn name(&self) -> &str {
+        "FormatFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let patterns = StringColumn::try_create(columns[0].column())?;
+        let arguments = columns[1..]
+            .iter()
+            .map(|c| StringColumn::try_create(c.column()))
+            .collect::<Result<Vec<_>>>()?;
+        let is_constant = patterns.is_constant() && arguments.iter().all(|a| a.is_constant());
+
+        // the pattern is usually a constant, which is parsed once
+        let mut parsed: Option<(Vec<u8>, Vec<FormatPart>)> = None;
+        build_string_column(is_constant, input_rows, |row, buffer| {
+            let pattern = match patterns.get(row) {
+                Some(pattern) => pattern,
+                None => return Ok(false),
+            };
+            if !matches!(&parsed, Some((p, _)) if p.as_slice() == pattern) {
+                let parts = Self::parse_pattern(pattern, arguments.len())?;
+                parsed = Some((pattern.to_vec(), parts));
+            }
+
+            let (_, parts) = parsed.as_ref().unwrap();
+            for part in parts {
+                match part {
+                    FormatPart::Literal(literal) => buffer.extend_from_slice(literal),
+                    FormatPart::Argument(index) => match arguments[*index].get(row) {
+                        Some(value) => buffer.extend_from_slice(value),
+                        None => return Ok(false),
+                    },
+                }
+            }
+            Ok(true)
+        })
+    }
+}
+
+impl fmt::Display for FormatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
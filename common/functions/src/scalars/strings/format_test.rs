@@ -0,0 +1,69 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::FormatFunction;
+use crate::scalars::FormatPart;
+
+#[test]
+fn test_format_pattern() -> Result<()> {
+    let parts = FormatFunction::parse_pattern(b"{} and {{{0}}}", 1)?;
+    assert_eq!(parts, vec![
+        FormatPart::Argument(0),
+        FormatPart::Literal(b" and {".to_vec()),
+        FormatPart::Argument(0),
+        FormatPart::Literal(b"}".to_vec()),
+    ]);
+
+    for (pattern, error) in [
+        ("{} {}", "not enough arguments"),
+        ("{a}", "the argument index is not a number"),
+        ("{", "unclosed '{'"),
+        ("}", "unmatched '}'"),
+    ] {
+        let result = FormatFunction::parse_pattern(pattern.as_bytes(), 1);
+        assert!(result.unwrap_err().message().contains(error), "{}", pattern);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_format_function() -> Result<()> {
+    let columns = vec![
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"{1}-{0}".to_vec())), 2),
+            DataField::new("pattern", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![1i32, 2]).into(),
+            DataField::new("a", DataType::Int32, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec!["x", "y"]).into(),
+            DataField::new("b", DataType::String, false),
+        ),
+    ];
+
+    let func = FormatFunction::try_create("format")?;
+    assert_eq!(func.to_string(), "format");
+    let result = func.eval(&columns, 2)?.to_array()?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"x-1".to_vec()),
+        Some(b"y-2".to_vec())
+    ]);
+    Ok(())
+}
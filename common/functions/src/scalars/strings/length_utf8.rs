@@ -0,0 +1,70 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::finish_column;
+use crate::scalars::utf8_char_count;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// The number of the UTF-8 characters in a string, `length` is the number of bytes.
+#[derive(Clone)]
+pub struct LengthUTF8Function {
+    display_name: String,
+}
+
+impl LengthUTF8Function {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LengthUTF8Function {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for LengthUTF8Function {
+    fn name(&self) -> &str {
+        "LengthUTF8Function"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let rows = if values.is_constant() { 1 } else { input_rows };
+        let result: DFUInt64Array = (0..rows)
+            .map(|row| values.get(row).map(utf8_char_count))
+            .collect();
+        finish_column(result.into_series(), values.is_constant(), input_rows)
+    }
+}
+
+impl fmt::Display for LengthUTF8Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -12,11 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod format_test;
+#[cfg(test)]
+mod string_function_test;
 #[cfg(test)]
 mod substring_test;
 
+mod concat;
+mod format;
+mod length_utf8;
+mod pad;
+mod position;
+mod repeat;
+mod replace;
+mod split;
+mod starts_with;
 mod string;
+mod string_common;
+mod string_transform;
 mod substring;
 
+pub use concat::ConcatFunction;
+pub use format::FormatFunction;
+pub use format::FormatPart;
+pub use length_utf8::LengthUTF8Function;
+pub use pad::PadFunction;
+pub use position::PositionFunction;
+pub use repeat::RepeatFunction;
+pub use replace::ReplaceFunction;
+pub use split::SplitFunction;
+pub use starts_with::StartsWithFunction;
 pub use string::StringFunction;
+pub use string_common::*;
+pub use string_transform::*;
 pub use substring::SubstringFunction;
@@ -0,0 +1,158 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::repeat::MAX_REPEAT_BYTES;
+use crate::scalars::build_string_column;
+use crate::scalars::utf8_chars;
+use crate::scalars::Function;
+use crate::scalars::IntColumn;
+use crate::scalars::StringColumn;
+
+/// `lpad(s, length[, pad])` and `rpad(s, length[, pad])` pad the string on the left or the
+/// right with `pad` (space by default) to `length` bytes, the string is truncated to `length`
+/// if it's longer. `lpadUTF8` and `rpadUTF8` count the length in characters.
+/// The result is NULL if `length` is negative, and is limited to the same size as `repeat`.
+#[derive(Clone)]
+pub struct PadFunction {
+    display_name: String,
+    left: bool,
+    utf8: bool,
+}
+
+impl PadFunction {
+    pub fn try_create_left(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::create(display_name, true, false)
+    }
+
+    pub fn try_create_right(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::create(display_name, false, false)
+    }
+
+    pub fn try_create_left_utf8(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::create(display_name, true, true)
+    }
+
+    pub fn try_create_right_utf8(display_name: &str) -> Result<Box<dyn Function>> {
+        Self::create(display_name, false, true)
+    }
+
+    fn create(display_name: &str, left: bool, utf8: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(PadFunction {
+            display_name: display_name.to_string(),
+            left,
+            utf8,
+        }))
+    }
+
+    fn units<'a>(&self, value: &'a [u8]) -> Vec<&'a [u8]> {
+        match self.utf8 {
+            true => utf8_chars(value),
+            false => value.chunks(1).collect(),
+        }
+    }
+
+    fn pad(&self, value: &[u8], length: usize, pad: &[u8], buffer: &mut Vec<u8>) -> Result<()> {
+        let value = self.units(value);
+        if value.len() >= length || pad.is_empty() {
+            value
+                .iter()
+                .take(length)
+                .for_each(|u| buffer.extend_from_slice(u));
+            return Ok(());
+        }
+
+        let pad = self.units(pad);
+        let padding_units = length - value.len();
+        let pad_bytes = pad.iter().map(|u| u.len()).sum::<usize>();
+        let padding_bytes = (padding_units / pad.len())
+            .saturating_mul(pad_bytes)
+            .saturating_add(
+                pad[..padding_units % pad.len()]
+                    .iter()
+                    .map(|u| u.len())
+                    .sum(),
+            );
+        let value_bytes = value.iter().map(|u| u.len()).sum::<usize>();
+        if value_bytes.saturating_add(padding_bytes) > MAX_REPEAT_BYTES {
+            return Err(ErrorCode::BadArguments(format!(
+                "The result of {} is too large, the max size is {} bytes",
+                self.display_name, MAX_REPEAT_BYTES
+            )));
+        }
+
+        let padding = pad.iter().cycle().take(padding_units);
+        if self.left {
+            padding
+                .chain(value.iter())
+                .for_each(|u| buffer.extend_from_slice(u));
+        } else {
+            value
+                .iter()
+                .chain(padding)
+                .for_each(|u| buffer.extend_from_slice(u));
+        }
+        Ok(())
+    }
+}
+
+impl Function for PadFunction {
+    fn name(&self) -> &str {
+        "PadFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    // NULL is returned for a negative length
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let lengths = IntColumn::try_create(columns[1].column())?;
+        let pads = match columns.get(2) {
+            Some(pad) => StringColumn::try_create(pad.column())?,
+            None => StringColumn::Constant(Some(b" ".to_vec())),
+        };
+        let is_constant = values.is_constant() && lengths.is_constant() && pads.is_constant();
+
+        build_string_column(is_constant, input_rows, |row, buffer| {
+            match (values.get(row), lengths.get(row), pads.get(row)) {
+                (Some(value), Some(length), Some(pad)) if length >= 0 => {
+                    self.pad(value, length as usize, pad, buffer)?;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        })
+    }
+}
+
+impl fmt::Display for PadFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::finish_column;
+use crate::scalars::utf8_char_count;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// `position(haystack, needle)` is the 1-based position in bytes of the first occurrence of
+/// `needle` in `haystack`, 0 if it's not found. `positionUTF8` counts the position in characters.
+#[derive(Clone)]
+pub struct PositionFunction {
+    display_name: String,
+    utf8: bool,
+}
+
+impl PositionFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(PositionFunction {
+            display_name: display_name.to_string(),
+            utf8: false,
+        }))
+    }
+
+    pub fn try_create_utf8(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(PositionFunction {
+            display_name: display_name.to_string(),
+            utf8: true,
+        }))
+    }
+
+    fn position(&self, haystack: &[u8], needle: &[u8]) -> u64 {
+        if needle.is_empty() {
+            return 1;
+        }
+        match haystack
+            .windows(needle.len())
+            .position(|window| window == needle)
+        {
+            Some(pos) if self.utf8 => utf8_char_count(&haystack[..pos]) + 1,
+            Some(pos) => pos as u64 + 1,
+            None => 0,
+        }
+    }
+}
+
+impl Function for PositionFunction {
+    fn name(&self) -> &str {
+        "PositionFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let haystacks = StringColumn::try_create(columns[0].column())?;
+        let needles = StringColumn::try_create(columns[1].column())?;
+        let is_constant = haystacks.is_constant() && needles.is_constant();
+
+        let rows = if is_constant { 1 } else { input_rows };
+        let result: DFUInt64Array = (0..rows)
+            .map(|row| match (haystacks.get(row), needles.get(row)) {
+                (Some(haystack), Some(needle)) => Some(self.position(haystack, needle)),
+                _ => None,
+            })
+            .collect();
+        finish_column(result.into_series(), is_constant, input_rows)
+    }
+}
+
+impl fmt::Display for PositionFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
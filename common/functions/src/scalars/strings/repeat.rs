@@ -0,0 +1,88 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::build_string_column;
+use crate::scalars::Function;
+use crate::scalars::IntColumn;
+use crate::scalars::StringColumn;
+
+/// The max size of the result of `repeat` and `lpad`/`rpad`, to avoid the unexpected huge memory usage.
+pub(super) const MAX_REPEAT_BYTES: usize = 1024 * 1024 * 1024;
+
+/// `repeat(s, n)` repeats the string `n` times, an empty string if `n` is not positive.
+#[derive(Clone)]
+pub struct RepeatFunction {
+    display_name: String,
+}
+
+impl RepeatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RepeatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for RepeatFunction {
+    fn name(&self) -> &str {
+        "RepeatFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let times = IntColumn::try_create(columns[1].column())?;
+        let is_constant = values.is_constant() && times.is_constant();
+
+        build_string_column(is_constant, input_rows, |row, buffer| {
+            match (values.get(row), times.get(row)) {
+                (Some(value), Some(times)) => {
+                    let times = times.max(0) as usize;
+                    if value.len().saturating_mul(times) > MAX_REPEAT_BYTES {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "The result of {} is too large, the max size is {} bytes",
+                            self.display_name, MAX_REPEAT_BYTES
+                        )));
+                    }
+                    buffer.extend_from_slice(&value.repeat(times));
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        })
+    }
+}
+
+impl fmt::Display for RepeatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_string_column;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// `replace(s, from, to)` replaces all occurrences of `from` in `s` with `to`, `s` is
+/// returned as is if `from` is empty.
+#[derive(Clone)]
+pub struct ReplaceFunction {
+    display_name: String,
+}
+
+impl ReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ReplaceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+/// Writes `value` with all occurrences of `from` replaced by `to` into the buffer.
+fn replace_all(value: &[u8], from: &[u8], to: &[u8], buffer: &mut Vec<u8>) {
+    if from.is_empty() {
+        buffer.extend_from_slice(value);
+        return;
+    }
+
+    let mut pos = 0;
+    while pos < value.len() {
+        if value[pos..].starts_with(from) {
+            buffer.extend_from_slice(to);
+            pos += from.len();
+        } else {
+            buffer.push(value[pos]);
+            pos += 1;
+        }
+    }
+}
+
+impl Function for ReplaceFunction {
+    fn name(&self) -> &str {
+        "ReplaceFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let froms = StringColumn::try_create(columns[1].column())?;
+        let tos = StringColumn::try_create(columns[2].column())?;
+        let is_constant = values.is_constant() && froms.is_constant() && tos.is_constant();
+
+        build_string_column(is_constant, input_rows, |row, buffer| {
+            match (values.get(row), froms.get(row), tos.get(row)) {
+                (Some(value), Some(from), Some(to)) => {
+                    replace_all(value, from, to, buffer);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        })
+    }
+}
+
+impl fmt::Display for ReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::finish_column;
+use crate::scalars::list_type;
+use crate::scalars::utf8_chars;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// `split(s, separator)` splits the string into an array of strings, the string is split
+/// into the UTF-8 characters if the separator is empty.
+#[derive(Clone)]
+pub struct SplitFunction {
+    display_name: String,
+}
+
+impl SplitFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SplitFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+/// Appends the parts of `value` split by `separator` to the builder, returns the number of parts.
+fn split_into(value: &[u8], separator: &[u8], builder: &mut StringArrayBuilder) -> usize {
+    if separator.is_empty() {
+        let chars = utf8_chars(value);
+        chars.iter().for_each(|c| builder.append_value(c));
+        return chars.len();
+    }
+
+    let mut parts = 0;
+    let mut start = 0;
+    let mut pos = 0;
+    while pos + separator.len() <= value.len() {
+        if value[pos..].starts_with(separator) {
+            builder.append_value(&value[start..pos]);
+            parts += 1;
+            pos += separator.len();
+            start = pos;
+        } else {
+            pos += 1;
+        }
+    }
+    builder.append_value(&value[start..]);
+    parts + 1
+}
+
+impl Function for SplitFunction {
+    fn name(&self) -> &str {
+        "SplitFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(list_type(DataType::String))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let separators = StringColumn::try_create(columns[1].column())?;
+        let is_constant = values.is_constant() && separators.is_constant();
+
+        let rows = if is_constant { 1 } else { input_rows };
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        let mut offsets = Vec::with_capacity(rows + 1);
+        let mut validity = Vec::with_capacity(rows);
+        let mut items = 0;
+        offsets.push(0);
+        for row in 0..rows {
+            match (values.get(row), separators.get(row)) {
+                (Some(value), Some(separator)) => {
+                    items += split_into(value, separator, &mut builder);
+                    validity.push(true);
+                }
+                _ => validity.push(false),
+            }
+            offsets.push(items as i64);
+        }
+
+        let items = builder.finish().into_series();
+        let array = DFListArray::from_flatten(&items, offsets, Some(validity));
+        finish_column(array.into_series(), is_constant, input_rows)
+    }
+}
+
+impl fmt::Display for SplitFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::finish_column;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// `startsWith(s, prefix)` and `endsWith(s, suffix)`.
+#[derive(Clone)]
+pub struct StartsWithFunction {
+    display_name: String,
+    ends: bool,
+}
+
+impl StartsWithFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StartsWithFunction {
+            display_name: display_name.to_string(),
+            ends: false,
+        }))
+    }
+
+    pub fn try_create_ends(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StartsWithFunction {
+            display_name: display_name.to_string(),
+            ends: true,
+        }))
+    }
+}
+
+impl Function for StartsWithFunction {
+    fn name(&self) -> &str {
+        "StartsWithFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        let patterns = StringColumn::try_create(columns[1].column())?;
+        let is_constant = values.is_constant() && patterns.is_constant();
+
+        let rows = if is_constant { 1 } else { input_rows };
+        let result: DFBooleanArray = (0..rows)
+            .map(|row| match (values.get(row), patterns.get(row)) {
+                (Some(value), Some(pattern)) if self.ends => Some(value.ends_with(pattern)),
+                (Some(value), Some(pattern)) => Some(value.starts_with(pattern)),
+                _ => None,
+            })
+            .collect();
+        finish_column(result.into_series(), is_constant, input_rows)
+    }
+}
+
+impl fmt::Display for StartsWithFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
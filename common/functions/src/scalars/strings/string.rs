@@ -14,8 +14,30 @@
 
 use common_exception::Result;
 
+use crate::scalars::Base64DecodeFunction;
+use crate::scalars::Base64EncodeFunction;
+use crate::scalars::ConcatFunction;
 use crate::scalars::FactoryFuncRef;
+use crate::scalars::FormatFunction;
+use crate::scalars::HexFunction;
+use crate::scalars::LTrimFunction;
+use crate::scalars::LengthUTF8Function;
+use crate::scalars::LowerFunction;
+use crate::scalars::LowerUTF8Function;
+use crate::scalars::PadFunction;
+use crate::scalars::PositionFunction;
+use crate::scalars::RTrimFunction;
+use crate::scalars::RepeatFunction;
+use crate::scalars::ReplaceFunction;
+use crate::scalars::ReverseFunction;
+use crate::scalars::ReverseUTF8Function;
+use crate::scalars::SplitFunction;
+use crate::scalars::StartsWithFunction;
 use crate::scalars::SubstringFunction;
+use crate::scalars::TrimFunction;
+use crate::scalars::UnhexFunction;
+use crate::scalars::UpperFunction;
+use crate::scalars::UpperUTF8Function;
 
 #[derive(Clone)]
 pub struct StringFunction;
@@ -24,6 +46,46 @@ impl StringFunction {
     pub fn register(map: FactoryFuncRef) -> Result<()> {
         let mut map = map.write();
         map.insert("substring".into(), SubstringFunction::try_create);
+        map.insert("lengthUTF8".into(), LengthUTF8Function::try_create);
+        map.insert("char_length".into(), LengthUTF8Function::try_create);
+        map.insert("character_length".into(), LengthUTF8Function::try_create);
+        map.insert("lower".into(), LowerFunction::try_create);
+        map.insert("lcase".into(), LowerFunction::try_create);
+        map.insert("upper".into(), UpperFunction::try_create);
+        map.insert("ucase".into(), UpperFunction::try_create);
+        map.insert("lowerUTF8".into(), LowerUTF8Function::try_create);
+        map.insert("upperUTF8".into(), UpperUTF8Function::try_create);
+        map.insert("reverse".into(), ReverseFunction::try_create);
+        map.insert("reverseUTF8".into(), ReverseUTF8Function::try_create);
+        map.insert("trim".into(), TrimFunction::try_create);
+        map.insert("ltrim".into(), LTrimFunction::try_create);
+        map.insert("rtrim".into(), RTrimFunction::try_create);
+        map.insert("trimBoth".into(), TrimFunction::try_create);
+        map.insert("trimLeft".into(), LTrimFunction::try_create);
+        map.insert("trimRight".into(), RTrimFunction::try_create);
+        map.insert("base64Encode".into(), Base64EncodeFunction::try_create);
+        map.insert("to_base64".into(), Base64EncodeFunction::try_create);
+        map.insert("base64Decode".into(), Base64DecodeFunction::try_create);
+        map.insert("from_base64".into(), Base64DecodeFunction::try_create);
+        map.insert("hex".into(), HexFunction::try_create);
+        map.insert("unhex".into(), UnhexFunction::try_create);
+        map.insert("concat".into(), ConcatFunction::try_create);
+        map.insert(
+            "concat_ws".into(),
+            ConcatFunction::try_create_with_separator,
+        );
+        map.insert("replace".into(), ReplaceFunction::try_create);
+        map.insert("position".into(), PositionFunction::try_create);
+        map.insert("positionUTF8".into(), PositionFunction::try_create_utf8);
+        map.insert("split".into(), SplitFunction::try_create);
+        map.insert("lpad".into(), PadFunction::try_create_left);
+        map.insert("rpad".into(), PadFunction::try_create_right);
+        map.insert("lpadUTF8".into(), PadFunction::try_create_left_utf8);
+        map.insert("rpadUTF8".into(), PadFunction::try_create_right_utf8);
+        map.insert("repeat".into(), RepeatFunction::try_create);
+        map.insert("startsWith".into(), StartsWithFunction::try_create);
+        map.insert("endsWith".into(), StartsWithFunction::try_create_ends);
+        map.insert("format".into(), FormatFunction::try_create);
 
         Ok(())
     }
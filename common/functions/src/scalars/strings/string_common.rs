@@ -0,0 +1,133 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+/// The string values of an argument, a constant argument is not expanded to the rows.
+pub enum StringColumn {
+    Constant(Option<Vec<u8>>),
+    Array(DFStringArray),
+}
+
+impl StringColumn {
+    pub fn try_create(column: &DataColumn) -> Result<Self> {
+        let column = match column.data_type() {
+            DataType::String => column.clone(),
+            _ => column.cast_with_type(&DataType::String)?,
+        };
+        match column {
+            DataColumn::Constant(DataValue::String(value), _) => Ok(StringColumn::Constant(value)),
+            DataColumn::Constant(_, _) => Ok(StringColumn::Constant(None)),
+            DataColumn::Array(series) => Ok(StringColumn::Array(series.string()?.clone())),
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        matches!(self, StringColumn::Constant(_))
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize) -> Option<&[u8]> {
+        match self {
+            StringColumn::Constant(value) => value.as_deref(),
+            StringColumn::Array(array) if array.is_null(row) => None,
+            StringColumn::Array(array) => Some(array.inner().value(row)),
+        }
+    }
+}
+
+/// The integer values of an argument, a constant argument is not expanded to the rows.
+pub enum IntColumn {
+    Constant(Option<i64>),
+    Array(DFInt64Array),
+}
+
+impl IntColumn {
+    pub fn try_create(column: &DataColumn) -> Result<Self> {
+        let column = match column.data_type() {
+            DataType::Int64 => column.clone(),
+            _ => column.cast_with_type(&DataType::Int64)?,
+        };
+        match column {
+            DataColumn::Constant(DataValue::Int64(value), _) => Ok(IntColumn::Constant(value)),
+            DataColumn::Constant(_, _) => Ok(IntColumn::Constant(None)),
+            DataColumn::Array(series) => Ok(IntColumn::Array(series.i64()?.clone())),
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        matches!(self, IntColumn::Constant(_))
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize) -> Option<i64> {
+        match self {
+            IntColumn::Constant(value) => *value,
+            IntColumn::Array(array) if array.is_null(row) => None,
+            IntColumn::Array(array) => Some(array.inner().value(row)),
+        }
+    }
+}
+
+/// Builds the string results, `eval` writes the result of the row into the buffer and
+/// returns false for NULL. The result is computed once if all arguments are constants.
+pub fn build_string_column<F>(
+    is_constant: bool,
+    input_rows: usize,
+    mut eval: F,
+) -> Result<DataColumn>
+where
+    F: FnMut(usize, &mut Vec<u8>) -> Result<bool>,
+{
+    let rows = if is_constant { 1 } else { input_rows };
+    let mut builder = StringArrayBuilder::with_capacity(rows);
+    let mut buffer = Vec::new();
+    for row in 0..rows {
+        buffer.clear();
+        match eval(row, &mut buffer)? {
+            true => builder.append_value(&buffer),
+            false => builder.append_null(),
+        }
+    }
+    finish_column(builder.finish().into_series(), is_constant, input_rows)
+}
+
+/// The column of the results, which is a constant if all arguments are constants.
+pub fn finish_column(series: Series, is_constant: bool, input_rows: usize) -> Result<DataColumn> {
+    match is_constant {
+        true => Ok(DataColumn::Constant(series.try_get(0)?, input_rows)),
+        false => Ok(series.into()),
+    }
+}
+
+/// The number of the UTF-8 characters, which is the number of the bytes except the
+/// continuation bytes `0b10xxxxxx`.
+#[inline]
+pub fn utf8_char_count(value: &[u8]) -> u64 {
+    value.iter().filter(|c| (**c & 0xC0) != 0x80).count() as u64
+}
+
+/// Splits the string into the UTF-8 characters, an invalid byte is a character itself.
+pub fn utf8_chars(value: &[u8]) -> Vec<&[u8]> {
+    let mut chars = Vec::with_capacity(value.len());
+    let mut start = 0;
+    for pos in 1..=value.len() {
+        if pos == value.len() || (value[pos] & 0xC0) != 0x80 {
+            chars.push(&value[start..pos]);
+            start = pos;
+        }
+    }
+    chars
+}
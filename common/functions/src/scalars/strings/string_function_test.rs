@@ -0,0 +1,218 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::*;
+
+fn string_column(name: &str, values: Vec<Option<&str>>) -> DataColumnWithField {
+    let values: DFStringArray = values.iter().map(|v| v.map(|v| v.as_bytes())).collect();
+    DataColumnWithField::new(
+        values.into_series().into(),
+        DataField::new(name, DataType::String, true),
+    )
+}
+
+fn constant_column(name: &str, value: DataValue) -> DataColumnWithField {
+    let data_type = value.data_type();
+    DataColumnWithField::new(
+        DataColumn::Constant(value, 2),
+        DataField::new(name, data_type, false),
+    )
+}
+
+fn eval_values(func: Box<dyn Function>, columns: &[DataColumnWithField]) -> Result<Vec<DataValue>> {
+    let result = func.eval(columns, 2)?;
+    (0..2).map(|row| result.try_get(row)).collect()
+}
+
+fn strings(values: Vec<Option<&str>>) -> Vec<DataValue> {
+    values
+        .iter()
+        .map(|v| DataValue::String(v.map(|v| v.as_bytes().to_vec())))
+        .collect()
+}
+
+#[test]
+fn test_string_transform_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        input: Vec<Option<&'static str>>,
+        expect: Vec<Option<&'static str>>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "lower",
+            func: LowerFunction::try_create("lower")?,
+            input: vec![Some("AbC"), None],
+            expect: vec![Some("abc"), None],
+        },
+        Test {
+            name: "upperUTF8",
+            func: UpperUTF8Function::try_create("upperUTF8")?,
+            input: vec![Some("straße"), Some("ä")],
+            expect: vec![Some("STRASSE"), Some("Ä")],
+        },
+        Test {
+            name: "reverseUTF8",
+            func: ReverseUTF8Function::try_create("reverseUTF8")?,
+            input: vec![Some("abç"), Some("")],
+            expect: vec![Some("çba"), Some("")],
+        },
+        Test {
+            name: "trim",
+            func: TrimFunction::try_create("trim")?,
+            input: vec![Some("  a b  "), Some("   ")],
+            expect: vec![Some("a b"), Some("")],
+        },
+        Test {
+            name: "ltrim",
+            func: LTrimFunction::try_create("ltrim")?,
+            input: vec![Some("  a  "), Some("a")],
+            expect: vec![Some("a  "), Some("a")],
+        },
+        Test {
+            name: "base64Decode",
+            func: Base64DecodeFunction::try_create("base64Decode")?,
+            input: vec![Some("YWJj"), Some("!")],
+            expect: vec![Some("abc"), None],
+        },
+        Test {
+            name: "hex",
+            func: HexFunction::try_create("hex")?,
+            input: vec![Some("abc"), None],
+            expect: vec![Some("616263"), None],
+        },
+        Test {
+            name: "unhex",
+            func: UnhexFunction::try_create("unhex")?,
+            input: vec![Some("616263"), Some("6")],
+            expect: vec![Some("abc"), None],
+        },
+    ];
+
+    for t in tests {
+        assert_eq!(t.func.return_type(&[DataType::String])?, DataType::String);
+        let result = eval_values(t.func, &[string_column("a", t.input)])?;
+        assert_eq!(result, strings(t.expect), "{}", t.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_string_functions() -> Result<()> {
+    let columns = [
+        string_column("a", vec![Some("abcabc"), None]),
+        constant_column("b", DataValue::String(Some(b"bc".to_vec()))),
+        constant_column("c", DataValue::String(Some(b"-".to_vec()))),
+    ];
+
+    let func = ConcatFunction::try_create("concat")?;
+    let result = eval_values(func, &columns)?;
+    assert_eq!(result, strings(vec![Some("abcabcbc-"), None]));
+
+    let func = ConcatFunction::try_create_with_separator("concat_ws")?;
+    let args = [columns[2].clone(), columns[0].clone(), columns[1].clone()];
+    let result = eval_values(func, &args)?;
+    assert_eq!(result, strings(vec![Some("abcabc-bc"), Some("bc")]));
+
+    let func = ReplaceFunction::try_create("replace")?;
+    let result = eval_values(func, &columns)?;
+    assert_eq!(result, strings(vec![Some("a-a-"), None]));
+
+    let func = PositionFunction::try_create("position")?;
+    let result = eval_values(func, &columns[0..2])?;
+    assert_eq!(result, vec![
+        DataValue::UInt64(Some(2)),
+        DataValue::UInt64(None)
+    ]);
+
+    let func = StartsWithFunction::try_create_ends("endsWith")?;
+    let result = eval_values(func, &columns[0..2])?;
+    assert_eq!(result, vec![
+        DataValue::Boolean(Some(true)),
+        DataValue::Boolean(None)
+    ]);
+
+    let func = SplitFunction::try_create("split")?;
+    let args = [columns[0].clone(), columns[1].clone()];
+    let result = eval_values(func, &args)?;
+    assert_eq!(
+        result[0],
+        DataValue::List(
+            Some(strings(vec![Some("a"), Some("a"), Some("")])),
+            DataType::String
+        )
+    );
+    assert!(result[1].is_null());
+
+    // all arguments are constants
+    let args = [
+        constant_column("a", DataValue::String(Some("ü".as_bytes().to_vec()))),
+        constant_column("b", DataValue::UInt8(Some(3))),
+        constant_column("c", DataValue::String(Some(b"xy".to_vec()))),
+    ];
+    let result = PadFunction::try_create_left("lpad")?.eval(&args, 2)?;
+    assert!(matches!(result, DataColumn::Constant(_, 2)));
+    assert_eq!(result.try_get(1)?, strings(vec![Some("xü")])[0]);
+    let result = PadFunction::try_create_right_utf8("rpadUTF8")?.eval(&args, 2)?;
+    assert_eq!(result.try_get(0)?, strings(vec![Some("üxy")])[0]);
+    let result = PadFunction::try_create_left("lpad")?.eval(&args[0..2], 2)?;
+    assert_eq!(result.try_get(0)?, strings(vec![Some(" ü")])[0]);
+
+    // the result is limited like repeat
+    let args = [
+        constant_column("a", DataValue::String(Some(b"a".to_vec()))),
+        constant_column("b", DataValue::UInt64(Some(1 << 40))),
+        constant_column("c", DataValue::String(Some("ü".as_bytes().to_vec()))),
+    ];
+    let result = PadFunction::try_create_right_utf8("rpadUTF8")?.eval(&args, 1);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "The result of rpadUTF8 is too large, the max size is 1073741824 bytes"
+    );
+    let result = PadFunction::try_create_left("lpad")?.eval(&args[0..2], 1);
+    assert!(result.is_err());
+
+    // a negative length returns NULL, so the pad functions are nullable
+    let args = [
+        constant_column("a", DataValue::String(Some(b"a".to_vec()))),
+        constant_column("b", DataValue::Int64(Some(-1))),
+    ];
+    let schema = DataSchemaRefExt::create(vec![args[0].field().clone(), args[1].field().clone()]);
+    for func in [
+        PadFunction::try_create_left("lpad")?,
+        PadFunction::try_create_right_utf8("rpadUTF8")?,
+    ] {
+        assert!(func.nullable(&schema)?, "{}", func);
+        assert!(eval_values(func, &args)?[0].is_null());
+    }
+
+    let args = [
+        constant_column("a", DataValue::String(Some("ü".as_bytes().to_vec()))),
+        constant_column("b", DataValue::UInt64(Some(3))),
+    ];
+    let func = LengthUTF8Function::try_create("lengthUTF8")?;
+    let result = eval_values(func, &args[0..1])?;
+    assert_eq!(result[0], DataValue::UInt64(Some(1)));
+
+    let func = RepeatFunction::try_create("repeat")?;
+    let result = eval_values(func, &args[0..2])?;
+    assert_eq!(result[0], strings(vec![Some("üüü")])[0]);
+    Ok(())
+}
@@ -0,0 +1,247 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::build_string_column;
+use crate::scalars::Function;
+use crate::scalars::StringColumn;
+
+/// Transforms a string into another string, such as `lower` and `base64Encode`.
+pub trait StringTransform: Clone + Send + Sync + 'static {
+    /// Whether the transform returns NULL for the invalid strings.
+    const MAYBE_NULL: bool = false;
+
+    /// Writes the transformed `value` into the buffer, returns false if `value` is invalid.
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool;
+}
+
+#[derive(Clone)]
+pub struct StringTransformFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T: StringTransform> StringTransformFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StringTransformFunction::<T> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+}
+
+impl<T: StringTransform> Function for StringTransformFunction<T> {
+    fn name(&self) -> &str {
+        "StringTransformFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(T::MAYBE_NULL)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringColumn::try_create(columns[0].column())?;
+        build_string_column(values.is_constant(), input_rows, |row, buffer| {
+            Ok(match values.get(row) {
+                Some(value) => T::transform(value, buffer),
+                None => false,
+            })
+        })
+    }
+}
+
+impl<T: StringTransform> fmt::Display for StringTransformFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[derive(Clone)]
+pub struct Lower;
+
+impl StringTransform for Lower {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend(value.iter().map(|c| c.to_ascii_lowercase()));
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Upper;
+
+impl StringTransform for Upper {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend(value.iter().map(|c| c.to_ascii_uppercase()));
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct LowerUTF8;
+
+impl StringTransform for LowerUTF8 {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend_from_slice(String::from_utf8_lossy(value).to_lowercase().as_bytes());
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct UpperUTF8;
+
+impl StringTransform for UpperUTF8 {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend_from_slice(String::from_utf8_lossy(value).to_uppercase().as_bytes());
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Reverse;
+
+impl StringTransform for Reverse {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend(value.iter().rev());
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct ReverseUTF8;
+
+impl StringTransform for ReverseUTF8 {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        let value = String::from_utf8_lossy(value);
+        buffer.extend_from_slice(value.chars().rev().collect::<String>().as_bytes());
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Trim;
+
+impl StringTransform for Trim {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        let start = value.iter().position(|c| *c != b' ').unwrap_or(value.len());
+        let end = value
+            .iter()
+            .rposition(|c| *c != b' ')
+            .map_or(start, |i| i + 1);
+        buffer.extend_from_slice(&value[start..end]);
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct LTrim;
+
+impl StringTransform for LTrim {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        let start = value.iter().position(|c| *c != b' ').unwrap_or(value.len());
+        buffer.extend_from_slice(&value[start..]);
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct RTrim;
+
+impl StringTransform for RTrim {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        let end = value.iter().rposition(|c| *c != b' ').map_or(0, |i| i + 1);
+        buffer.extend_from_slice(&value[..end]);
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Base64Encode;
+
+impl StringTransform for Base64Encode {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend_from_slice(base64::encode(value).as_bytes());
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Base64Decode;
+
+impl StringTransform for Base64Decode {
+    const MAYBE_NULL: bool = true;
+
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        match base64::decode(value) {
+            Ok(decoded) => {
+                buffer.extend_from_slice(&decoded);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Hex;
+
+impl StringTransform for Hex {
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend_from_slice(hex::encode_upper(value).as_bytes());
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Unhex;
+
+impl StringTransform for Unhex {
+    const MAYBE_NULL: bool = true;
+
+    fn transform(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        match hex::decode(value) {
+            Ok(decoded) => {
+                buffer.extend_from_slice(&decoded);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+pub type LowerFunction = StringTransformFunction<Lower>;
+pub type UpperFunction = StringTransformFunction<Upper>;
+pub type LowerUTF8Function = StringTransformFunction<LowerUTF8>;
+pub type UpperUTF8Function = StringTransformFunction<UpperUTF8>;
+pub type ReverseFunction = StringTransformFunction<Reverse>;
+pub type ReverseUTF8Function = StringTransformFunction<ReverseUTF8>;
+pub type TrimFunction = StringTransformFunction<Trim>;
+pub type LTrimFunction = StringTransformFunction<LTrim>;
+pub type RTrimFunction = StringTransformFunction<RTrim>;
+pub type Base64EncodeFunction = StringTransformFunction<Base64Encode>;
+pub type Base64DecodeFunction = StringTransformFunction<Base64Decode>;
+pub type HexFunction = StringTransformFunction<Hex>;
+pub type UnhexFunction = StringTransformFunction<Unhex>;
@@ -0,0 +1,156 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Extracts the value at the path like `a.b[0]` from a variant or JSON text,
+/// NULL is returned if the path does not exist.
+/// get/get_path returns a variant, json_extract_path_text returns the text of the value.
+#[derive(Clone)]
+pub struct GetFunction {
+    display_name: String,
+    text: bool,
+}
+
+impl GetFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetFunction {
+            display_name: display_name.to_string(),
+            text: false,
+        }))
+    }
+
+    pub fn try_create_text(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetFunction {
+            display_name: display_name.to_string(),
+            text: true,
+        }))
+    }
+}
+
+impl GetFunction {
+    fn append_value(
+        &self,
+        builder: &mut StringArrayBuilder,
+        variant: Option<&[u8]>,
+        path: &[VariantPathElement],
+    ) -> Result<()> {
+        let value = variant.map(parse_variant).transpose()?;
+        let value = value.as_ref().and_then(|v| variant_get_path(v, path));
+        match value {
+            // the text of JSON null is NULL
+            Some(value) if self.text && !value.is_null() => {
+                builder.append_value(variant_to_text(value))
+            }
+            Some(value) if !self.text => builder.append_value(format_variant(value)),
+            _ => builder.append_null(),
+        }
+        Ok(())
+    }
+}
+
+impl Function for GetFunction {
+    fn name(&self) -> &str {
+        "GetFunction"
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match (&args[0], &args[1]) {
+            (DataType::String | DataType::Variant | DataType::Null, DataType::String) => {
+                if self.text {
+                    Ok(DataType::String)
+                } else {
+                    Ok(DataType::Variant)
+                }
+            }
+            (value, path) => Err(ErrorCode::IllegalDataType(format!(
+                "Expected a variant and a string path for {}, but got {:?} and {:?}",
+                self.display_name, value, path
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        // A constant path is parsed once, otherwise the path of each row is parsed.
+        if let DataColumn::Constant(path, _) = columns[1].column() {
+            let path = match path {
+                DataValue::String(Some(path)) => {
+                    parse_variant_path(&String::from_utf8_lossy(path))?
+                }
+                DataValue::String(None) | DataValue::Null => {
+                    return Ok(DataColumn::Constant(DataValue::String(None), input_rows));
+                }
+                other => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Expected a string path for {}, but got {:?}",
+                        self.display_name, other
+                    )))
+                }
+            };
+
+            let series = columns[0].column().to_minimal_array()?;
+            let variants = variant_cast_from(&series, columns[0].data_type())?;
+            let mut builder = StringArrayBuilder::with_capacity(variants.len());
+            for v in variants.into_iter() {
+                self.append_value(&mut builder, v, &path)?;
+            }
+            let column: DataColumn = builder.finish().into_series().into();
+            return Ok(column.resize_constant(input_rows));
+        }
+
+        let series = columns[0].column().to_array()?;
+        let variants = variant_cast_from(&series, columns[0].data_type())?;
+        let paths = columns[1].column().to_array()?;
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        let mut last_path: Option<(&[u8], Vec<VariantPathElement>)> = None;
+        for (v, path) in variants.into_iter().zip(paths.string()?.into_iter()) {
+            let path = match path {
+                None => {
+                    builder.append_null();
+                    continue;
+                }
+                Some(path) => path,
+            };
+            // the rows often share the same path
+            if !matches!(&last_path, Some((last, _)) if *last == path) {
+                let elements = parse_variant_path(&String::from_utf8_lossy(path))?;
+                last_path = Some((path, elements));
+            }
+            if let Some((_, elements)) = &last_path {
+                self.append_value(&mut builder, v, elements)?;
+            }
+        }
+        Ok(builder.finish().into_series().into())
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+}
+
+impl fmt::Display for GetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::GetFunction;
+
+#[test]
+fn test_get_function() -> Result<()> {
+    let values = Series::new(vec![
+        r#"{"a":{"b":[1,"x"]}}"#,
+        r#"{"a":{"b":[null]}}"#,
+        r#"{"c":1}"#,
+    ]);
+    let columns = vec![
+        DataColumnWithField::new(values.into(), DataField::new("v", DataType::Variant, false)),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"a.b[1]".to_vec())), 3),
+            DataField::new("path", DataType::String, false),
+        ),
+    ];
+
+    let func = GetFunction::try_create("get")?;
+    assert_eq!(func.to_string(), "get");
+    assert_eq!(
+        func.return_type(&[DataType::Variant, DataType::String])?,
+        DataType::Variant
+    );
+    assert!(func
+        .return_type(&[DataType::Int64, DataType::String])
+        .is_err());
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"\"x\"".to_vec()),
+        None,
+        None
+    ]);
+
+    let func = GetFunction::try_create_text("json_extract_path_text")?;
+    assert_eq!(
+        func.return_type(&[DataType::String, DataType::String])?,
+        DataType::String
+    );
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"x".to_vec()),
+        None,
+        None
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_get_function_with_column_path() -> Result<()> {
+    let values = Series::new(vec![
+        r#"{"a":{"b":[1,"x"]}}"#,
+        r#"{"a":{"b":[null]}}"#,
+        r#"{"c":1}"#,
+    ]);
+    let paths = DFStringArray::new_from_opt_slice(&[Some("a.b[0]"), Some("a.b[0]"), None]);
+    let columns = vec![
+        DataColumnWithField::new(values.into(), DataField::new("v", DataType::Variant, false)),
+        DataColumnWithField::new(
+            paths.into_series().into(),
+            DataField::new("path", DataType::String, true),
+        ),
+    ];
+
+    let func = GetFunction::try_create("get")?;
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"1".to_vec()),
+        Some(b"null".to_vec()),
+        None
+    ]);
+
+    let func = GetFunction::try_create_text("json_extract_path_text")?;
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"1".to_vec()),
+        None,
+        None
+    ]);
+    Ok(())
+}
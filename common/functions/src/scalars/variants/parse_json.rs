@@ -0,0 +1,92 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Parses the JSON text into a variant, try_parse_json returns NULL for the invalid text
+/// rather than an error.
+#[derive(Clone)]
+pub struct ParseJsonFunction {
+    display_name: String,
+    suppress_error: bool,
+}
+
+impl ParseJsonFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseJsonFunction {
+            display_name: display_name.to_string(),
+            suppress_error: false,
+        }))
+    }
+
+    pub fn try_create_try(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseJsonFunction {
+            display_name: display_name.to_string(),
+            suppress_error: true,
+        }))
+    }
+}
+
+impl Function for ParseJsonFunction {
+    fn name(&self) -> &str {
+        "ParseJsonFunction"
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::String | DataType::Variant | DataType::Null => Ok(DataType::Variant),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected a string argument for {}, but got {:?}",
+                self.display_name, other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.suppress_error)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = columns[0].column().to_minimal_array()?;
+        let array = match columns[0].data_type() {
+            DataType::String if self.suppress_error => {
+                let mut builder = StringArrayBuilder::with_capacity(series.len());
+                for v in series.string()?.into_iter() {
+                    let value = v.and_then(|v| parse_variant(v).ok());
+                    builder.append_option(value.as_ref().map(format_variant));
+                }
+                builder.finish()
+            }
+            data_type => variant_cast_from(&series, data_type)?,
+        };
+        let column: DataColumn = array.into_series().into();
+        Ok(column.resize_constant(input_rows))
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+}
+
+impl fmt::Display for ParseJsonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
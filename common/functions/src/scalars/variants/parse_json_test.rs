@@ -0,0 +1,43 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::ParseJsonFunction;
+
+#[test]
+fn test_parse_json_function() -> Result<()> {
+    let texts = Series::new(vec![r#"{ "a" : [1, 2] }"#, "null", "{"]);
+    let columns = vec![DataColumnWithField::new(
+        texts.into(),
+        DataField::new("s", DataType::String, false),
+    )];
+
+    let func = ParseJsonFunction::try_create("parse_json")?;
+    assert_eq!(func.return_type(&[DataType::String])?, DataType::Variant);
+    assert!(!func.nullable(&DataSchema::empty())?);
+    assert!(func.eval(&columns, 3).is_err());
+
+    let func = ParseJsonFunction::try_create_try("try_parse_json")?;
+    assert!(func.nullable(&DataSchema::empty())?);
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.string()?.collect_values(), vec![
+        Some(b"{\"a\":[1,2]}".to_vec()),
+        Some(b"null".to_vec()),
+        None
+    ]);
+    Ok(())
+}
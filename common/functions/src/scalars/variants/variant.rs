@@ -0,0 +1,38 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::scalars::FactoryFuncRef;
+use crate::scalars::GetFunction;
+use crate::scalars::ParseJsonFunction;
+
+#[derive(Clone)]
+pub struct VariantFunction;
+
+impl VariantFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("parse_json".into(), ParseJsonFunction::try_create);
+        map.insert("try_parse_json".into(), ParseJsonFunction::try_create_try);
+        map.insert("get".into(), GetFunction::try_create);
+        map.insert("get_path".into(), GetFunction::try_create);
+        map.insert(
+            "json_extract_path_text".into(),
+            GetFunction::try_create_text,
+        );
+
+        Ok(())
+    }
+}
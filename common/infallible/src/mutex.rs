@@ -16,7 +16,7 @@ use parking_lot::Mutex as ParkingMutex;
 use parking_lot::MutexGuard;
 
 /// A simple wrapper around the lock() function of a std::sync::Mutex
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Mutex<T>(ParkingMutex<T>);
 
 unsafe impl<T> Send for Mutex<T> where ParkingMutex<T>: Send {}
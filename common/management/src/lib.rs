@@ -14,12 +14,34 @@
 //
 
 mod namespace;
+mod quota;
+mod resource_group;
+mod role;
+mod setting;
 mod user;
 
 pub use namespace::NamespaceApi;
 pub use namespace::NamespaceMgr;
 pub use namespace::NodeInfo;
+pub use quota::quota_api::QuotaMgrApi;
+pub use quota::quota_api::QuotaUsage;
+pub use quota::quota_api::UserQuota;
+pub use quota::quota_mgr::QuotaMgr;
+pub use resource_group::resource_group_api::ResourceGroupInfo;
+pub use resource_group::resource_group_api::ResourceGroupMgrApi;
+pub use resource_group::resource_group_mgr::ResourceGroupMgr;
+pub use role::role_api::RoleInfo;
+pub use role::role_api::RoleMgrApi;
+pub use role::role_mgr::RoleMgr;
+pub use setting::setting_api::SettingMgrApi;
+pub use setting::setting_api::UserSetting;
+pub use setting::setting_mgr::SettingMgr;
 pub use user::user_api::AuthType;
 pub use user::user_api::UserInfo;
 pub use user::user_api::UserMgrApi;
+pub use user::user_grant::GrantEntry;
+pub use user::user_grant::GrantObject;
+pub use user::user_grant::UserGrantSet;
 pub use user::user_mgr::UserMgr;
+pub use user::user_privilege::UserPrivilegeSet;
+pub use user::user_privilege::UserPrivilegeType;
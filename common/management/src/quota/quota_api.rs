@@ -0,0 +1,124 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::Result;
+
+/// The limits of the queries of a user in every interval, zero means unlimited.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct UserQuota {
+    /// The length of the interval in seconds, zero means the usage is never reset.
+    pub interval_secs: u64,
+    pub max_queries: u64,
+    pub max_read_rows: u64,
+    pub max_read_bytes: u64,
+    pub max_execution_time_ms: u64,
+}
+
+impl UserQuota {
+    pub fn new(interval_secs: u64) -> Self {
+        UserQuota {
+            interval_secs,
+            ..Default::default()
+        }
+    }
+
+    /// The start of the interval containing `now`, both in seconds since the unix epoch.
+    pub fn interval_start(&self, now: u64) -> u64 {
+        match self.interval_secs {
+            0 => 0,
+            interval_secs => now - now % interval_secs,
+        }
+    }
+}
+
+impl Display for UserQuota {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "interval = {}, max_queries = {}, max_read_rows = {}, max_read_bytes = {}, max_execution_time = {}",
+            self.interval_secs,
+            self.max_queries,
+            self.max_read_rows,
+            self.max_read_bytes,
+            self.max_execution_time_ms / 1000
+        )
+    }
+}
+
+/// The usage of a user in one interval of a quota.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct QuotaUsage {
+    /// Seconds since the unix epoch.
+    pub interval_start: u64,
+    pub queries: u64,
+    pub read_rows: u64,
+    pub read_bytes: u64,
+    pub execution_time_ms: u64,
+}
+
+impl QuotaUsage {
+    /// The usage of the interval starting at `interval_start`, the usage of an earlier interval is reset.
+    pub fn in_interval(self, interval_start: u64) -> QuotaUsage {
+        match self.interval_start == interval_start {
+            true => self,
+            false => QuotaUsage {
+                interval_start,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn add(&mut self, other: &QuotaUsage) {
+        self.queries += other.queries;
+        self.read_rows += other.read_rows;
+        self.read_bytes += other.read_bytes;
+        self.execution_time_ms += other.execution_time_ms;
+    }
+
+    /// The first limit of the quota the usage reaches, None if a new query is allowed.
+    pub fn exceeded(&self, quota: &UserQuota) -> Option<String> {
+        let limits = [
+            ("queries", self.queries, quota.max_queries),
+            ("read rows", self.read_rows, quota.max_read_rows),
+            ("read bytes", self.read_bytes, quota.max_read_bytes),
+            (
+                "execution time ms",
+                self.execution_time_ms,
+                quota.max_execution_time_ms,
+            ),
+        ];
+
+        limits
+            .iter()
+            .find(|(_, used, max)| *max != 0 && used >= max)
+            .map(|(name, used, max)| format!("{} {}/{}", name, used, max))
+    }
+}
+
+pub trait QuotaMgrApi: Sync + Send {
+    /// The usage of the user in the interval of the quota containing `now`.
+    fn get_usage(&self, user: String, quota: &UserQuota, now: u64) -> Result<QuotaUsage>;
+
+    /// Add the usage of a finished query into the interval of the quota containing `now`.
+    fn add_usage(
+        &self,
+        user: String,
+        quota: &UserQuota,
+        now: u64,
+        usage: &QuotaUsage,
+    ) -> Result<QuotaUsage>;
+}
@@ -0,0 +1,91 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_kv_api::KVApi;
+use common_kv_api::SyncKVApi;
+use common_metatypes::MatchSeq;
+
+use crate::quota::quota_api::QuotaMgrApi;
+use crate::quota::quota_api::QuotaUsage;
+use crate::quota::quota_api::UserQuota;
+
+pub static QUOTA_USAGE_API_KEY_PREFIX: &str = "__fd_quota_usage";
+
+// The usage is written by every query node, retry if it's changed concurrently.
+const MAX_ADD_USAGE_RETRIES: usize = 16;
+
+pub struct QuotaMgr {
+    kv_api: Arc<dyn KVApi>,
+    usage_prefix: String,
+}
+
+impl QuotaMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        QuotaMgr {
+            kv_api,
+            usage_prefix: format!("{}/{}", QUOTA_USAGE_API_KEY_PREFIX, tenant),
+        }
+    }
+
+    // The usage is kept for every interval length, the user may have quotas of different intervals.
+    fn usage_key(&self, user: &str, quota: &UserQuota) -> String {
+        format!("{}/{}/{}", self.usage_prefix, user, quota.interval_secs)
+    }
+
+    fn read_usage(&self, key: &str) -> Result<(u64, QuotaUsage)> {
+        match self.kv_api.sync_get_kv(key)?.result {
+            None => Ok((0, QuotaUsage::default())),
+            Some((seq, value)) => Ok((seq, serde_json::from_slice(&value.value)?)),
+        }
+    }
+}
+
+impl QuotaMgrApi for QuotaMgr {
+    fn get_usage(&self, user: String, quota: &UserQuota, now: u64) -> Result<QuotaUsage> {
+        let (_, usage) = self.read_usage(&self.usage_key(&user, quota))?;
+        Ok(usage.in_interval(quota.interval_start(now)))
+    }
+
+    fn add_usage(
+        &self,
+        user: String,
+        quota: &UserQuota,
+        now: u64,
+        usage: &QuotaUsage,
+    ) -> Result<QuotaUsage> {
+        let key = self.usage_key(&user, quota);
+        for _ in 0..MAX_ADD_USAGE_RETRIES {
+            let (seq, current) = self.read_usage(&key)?;
+            let mut new_usage = current.in_interval(quota.interval_start(now));
+            new_usage.add(usage);
+
+            let value = serde_json::to_vec(&new_usage)?;
+            let res = self
+                .kv_api
+                .sync_upsert_kv(&key, MatchSeq::Exact(seq), Some(value), None)?;
+            if res.result.is_some() {
+                return Ok(new_usage);
+            }
+        }
+
+        Err(ErrorCode::UnexpectedError(format!(
+            "Cannot add the quota usage of user {}, it's changed concurrently",
+            user
+        )))
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_kv::KV;
+
+use crate::quota::quota_api::QuotaMgrApi;
+use crate::quota::quota_api::QuotaUsage;
+use crate::quota::quota_api::UserQuota;
+use crate::quota::quota_mgr::QuotaMgr;
+
+fn query_usage(read_rows: u64) -> QuotaUsage {
+    QuotaUsage {
+        interval_start: 0,
+        queries: 1,
+        read_rows,
+        read_bytes: read_rows * 8,
+        execution_time_ms: 10,
+    }
+}
+
+#[test]
+fn test_quota_usage_exceeded() -> Result<()> {
+    let quota = UserQuota {
+        interval_secs: 60,
+        max_queries: 2,
+        max_read_rows: 0,
+        max_read_bytes: 1000,
+        max_execution_time_ms: 0,
+    };
+    assert_eq!(quota.interval_start(125), 120);
+    assert_eq!(UserQuota::new(0).interval_start(125), 0);
+
+    let mut usage = QuotaUsage::default();
+    assert_eq!(usage.exceeded(&quota), None);
+
+    usage.add(&query_usage(100));
+    assert_eq!(usage.exceeded(&quota), None);
+
+    usage.add(&query_usage(100));
+    assert_eq!(usage.exceeded(&quota), Some("queries 2/2".to_string()));
+
+    // Unlimited.
+    assert_eq!(usage.exceeded(&UserQuota::new(60)), None);
+
+    // Reset in the next interval.
+    let usage = usage.in_interval(60);
+    assert_eq!(usage, QuotaUsage {
+        interval_start: 60,
+        ..Default::default()
+    });
+    Ok(())
+}
+
+#[test]
+fn test_add_quota_usage() -> Result<()> {
+    let kv_api = Arc::new(KV::sync_new_temp()?);
+    let quota_api = QuotaMgr::new(kv_api, "tenant1");
+
+    let hourly = UserQuota::new(3600);
+    let forever = UserQuota::new(0);
+
+    let usage = quota_api.get_usage("user1".to_string(), &hourly, 3700)?;
+    assert_eq!(usage.queries, 0);
+
+    quota_api.add_usage("user1".to_string(), &hourly, 3700, &query_usage(10))?;
+    quota_api.add_usage("user1".to_string(), &hourly, 3800, &query_usage(10))?;
+    quota_api.add_usage("user1".to_string(), &forever, 3800, &query_usage(10))?;
+
+    let usage = quota_api.get_usage("user1".to_string(), &hourly, 3900)?;
+    assert_eq!(usage.interval_start, 3600);
+    assert_eq!(usage.queries, 2);
+    assert_eq!(usage.read_rows, 20);
+
+    // The other users and intervals are tracked separately.
+    let usage = quota_api.get_usage("user2".to_string(), &hourly, 3900)?;
+    assert_eq!(usage.queries, 0);
+    let usage = quota_api.get_usage("user1".to_string(), &forever, 3900)?;
+    assert_eq!(usage.queries, 1);
+
+    // The next interval.
+    let usage = quota_api.get_usage("user1".to_string(), &hourly, 7200)?;
+    assert_eq!(usage.queries, 0);
+    let usage = quota_api.add_usage("user1".to_string(), &hourly, 7300, &query_usage(5))?;
+    assert_eq!(usage.interval_start, 7200);
+    assert_eq!(usage.read_rows, 5);
+    Ok(())
+}
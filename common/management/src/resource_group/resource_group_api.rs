@@ -0,0 +1,66 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metatypes::SeqValue;
+
+/// A named group of queries, at most `max_concurrency` of them run at the same time on a
+/// query node and at most `max_queued` of them wait for a free slot.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ResourceGroupInfo {
+    pub name: String,
+    pub max_concurrency: u64,
+    pub max_queued: u64,
+}
+
+impl ResourceGroupInfo {
+    pub fn new(name: String, max_concurrency: u64, max_queued: u64) -> Self {
+        ResourceGroupInfo {
+            name,
+            max_concurrency,
+            max_queued,
+        }
+    }
+}
+
+pub trait ResourceGroupMgrApi: Sync + Send {
+    fn add_resource_group(&self, resource_group: ResourceGroupInfo) -> Result<u64>;
+
+    fn get_resource_group(
+        &self,
+        name: String,
+        seq: Option<u64>,
+    ) -> Result<SeqValue<ResourceGroupInfo>>;
+
+    fn get_resource_groups(&self) -> Result<Vec<SeqValue<ResourceGroupInfo>>>;
+
+    fn drop_resource_group(&self, name: String, seq: Option<u64>) -> Result<()>;
+}
+
+impl TryFrom<Vec<u8>> for ResourceGroupInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(resource_group) => Ok(resource_group),
+            Err(serialize_error) => Err(ErrorCode::IllegalResourceGroupInfoFormat(format!(
+                "Cannot deserialize resource group info from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_kv_api::KVApi;
+use common_kv_api::SyncKVApi;
+use common_kv_api_vo::UpsertKVActionResult;
+use common_metatypes::MatchSeq;
+use common_metatypes::MatchSeqExt;
+use common_metatypes::SeqValue;
+
+use crate::resource_group::resource_group_api::ResourceGroupInfo;
+use crate::resource_group::resource_group_api::ResourceGroupMgrApi;
+
+pub static RESOURCE_GROUP_API_KEY_PREFIX: &str = "__fd_resource_groups";
+
+pub struct ResourceGroupMgr {
+    kv_api: Arc<dyn KVApi>,
+    resource_group_prefix: String,
+}
+
+impl ResourceGroupMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        ResourceGroupMgr {
+            kv_api,
+            resource_group_prefix: format!("{}/{}", RESOURCE_GROUP_API_KEY_PREFIX, tenant),
+        }
+    }
+}
+
+impl ResourceGroupMgrApi for ResourceGroupMgr {
+    fn add_resource_group(&self, resource_group: ResourceGroupInfo) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = format!("{}/{}", self.resource_group_prefix, resource_group.name);
+        let value = serde_json::to_vec(&resource_group)?;
+
+        let res = self
+            .kv_api
+            .sync_upsert_kv(&key, match_seq, Some(value), None)?;
+
+        match res {
+            UpsertKVActionResult {
+                prev: None,
+                result: Some((s, _)),
+            } => Ok(s),
+            UpsertKVActionResult {
+                prev: Some((s, _)),
+                result: _,
+            } => Err(ErrorCode::ResourceGroupAlreadyExists(format!(
+                "Resource group already exists, seq [{}]",
+                s
+            ))),
+            catch_result @ UpsertKVActionResult { .. } => {
+                Err(ErrorCode::UnknownException(format!(
+                    "upsert result not expected (using version 0, got {:?})",
+                    catch_result
+                )))
+            }
+        }
+    }
+
+    fn get_resource_group(
+        &self,
+        name: String,
+        seq: Option<u64>,
+    ) -> Result<SeqValue<ResourceGroupInfo>> {
+        let key = format!("{}/{}", self.resource_group_prefix, name);
+        let res = self.kv_api.sync_get_kv(&key)?;
+
+        let seq_value = res.result.ok_or_else(|| {
+            ErrorCode::UnknownResourceGroup(format!("unknown resource group {}", name))
+        })?;
+
+        match MatchSeq::from(seq).match_seq(&seq_value) {
+            Ok(_) => Ok((seq_value.0, seq_value.1.value.try_into()?)),
+            Err(_) => Err(ErrorCode::UnknownResourceGroup(format!(
+                "resource group: {}",
+                name
+            ))),
+        }
+    }
+
+    fn get_resource_groups(&self) -> Result<Vec<SeqValue<ResourceGroupInfo>>> {
+        let values = self
+            .kv_api
+            .sync_prefix_list_kv(self.resource_group_prefix.as_str())?;
+        let mut r = vec![];
+        for (_key, (s, val)) in values {
+            r.push((s, val.value.try_into()?));
+        }
+
+        Ok(r)
+    }
+
+    fn drop_resource_group(&self, name: String, seq: Option<u64>) -> Result<()> {
+        let key = format!("{}/{}", self.resource_group_prefix, name);
+        let res = self.kv_api.sync_upsert_kv(&key, seq.into(), None, None)?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownResourceGroup(format!(
+                "unknown resource group {}",
+                name
+            )))
+        }
+    }
+}
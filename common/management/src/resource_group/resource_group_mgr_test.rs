@@ -0,0 +1,57 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_kv::KV;
+
+use crate::resource_group::resource_group_api::ResourceGroupInfo;
+use crate::resource_group::resource_group_api::ResourceGroupMgrApi;
+use crate::resource_group::resource_group_mgr::ResourceGroupMgr;
+
+#[test]
+fn test_resource_group_mgr() -> Result<()> {
+    let kv_api = Arc::new(KV::sync_new_temp()?);
+    let resource_group_api = ResourceGroupMgr::new(kv_api, "tenant1");
+
+    let etl = ResourceGroupInfo::new("etl".to_string(), 2, 10);
+    resource_group_api.add_resource_group(etl.clone())?;
+    resource_group_api.add_resource_group(ResourceGroupInfo::new("dashboard".to_string(), 8, 0))?;
+
+    let (_, resource_group) = resource_group_api.get_resource_group("etl".to_string(), None)?;
+    assert_eq!(resource_group, etl);
+    assert_eq!(resource_group_api.get_resource_groups()?.len(), 2);
+
+    match resource_group_api.add_resource_group(etl) {
+        Ok(_) => assert!(
+            false,
+            "Already exists add resource group must be return Err."
+        ),
+        Err(cause) => assert_eq!(cause.code(), 3007),
+    }
+
+    resource_group_api.drop_resource_group("etl".to_string(), None)?;
+    match resource_group_api.get_resource_group("etl".to_string(), None) {
+        Ok(_) => assert!(false, "Dropped resource group must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 3006),
+    }
+
+    match resource_group_api.drop_resource_group("etl".to_string(), None) {
+        Ok(_) => assert!(false, "Drop unknown resource group must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 3006),
+    }
+    assert_eq!(resource_group_api.get_resource_groups()?.len(), 1);
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metatypes::SeqValue;
+
+use crate::quota::quota_api::UserQuota;
+use crate::user::user_grant::GrantObject;
+use crate::user::user_grant::UserGrantSet;
+use crate::user::user_privilege::UserPrivilegeSet;
+
+/// A named set of privileges, the users granted the role have its privileges.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RoleInfo {
+    pub name: String,
+    #[serde(default)]
+    pub grants: UserGrantSet,
+    /// The limits of the queries of every user granted the role.
+    #[serde(default)]
+    pub quota: Option<UserQuota>,
+    /// The resource group queueing the queries of every user granted the role.
+    #[serde(default)]
+    pub resource_group: Option<String>,
+}
+
+impl RoleInfo {
+    pub fn new(name: String) -> Self {
+        RoleInfo {
+            name,
+            grants: UserGrantSet::empty(),
+            quota: None,
+            resource_group: None,
+        }
+    }
+}
+
+pub trait RoleMgrApi: Sync + Send {
+    fn add_role(&self, role_info: RoleInfo) -> Result<u64>;
+
+    fn get_role(&self, role: String, seq: Option<u64>) -> Result<SeqValue<RoleInfo>>;
+
+    fn get_roles(&self) -> Result<Vec<SeqValue<RoleInfo>>>;
+
+    fn grant_role_privileges(
+        &self,
+        role: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn revoke_role_privileges(
+        &self,
+        role: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn set_role_quota(
+        &self,
+        role: String,
+        quota: Option<UserQuota>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn set_role_resource_group(
+        &self,
+        role: String,
+        resource_group: Option<String>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn drop_role(&self, role: String, seq: Option<u64>) -> Result<()>;
+}
+
+impl TryFrom<Vec<u8>> for RoleInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(role_info) => Ok(role_info),
+            Err(serialize_error) => Err(ErrorCode::IllegalRoleInfoFormat(format!(
+                "Cannot deserialize role info from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_kv_api::KVApi;
+use common_kv_api::SyncKVApi;
+use common_kv_api_vo::UpsertKVActionResult;
+use common_metatypes::MatchSeq;
+use common_metatypes::MatchSeqExt;
+use common_metatypes::SeqValue;
+
+use crate::quota::quota_api::UserQuota;
+use crate::role::role_api::RoleInfo;
+use crate::role::role_api::RoleMgrApi;
+use crate::user::user_grant::GrantObject;
+use crate::user::user_privilege::UserPrivilegeSet;
+
+pub static ROLE_API_KEY_PREFIX: &str = "__fd_roles";
+
+pub struct RoleMgr {
+    kv_api: Arc<dyn KVApi>,
+    role_prefix: String,
+}
+
+impl RoleMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        RoleMgr {
+            kv_api,
+            role_prefix: format!("{}/{}", ROLE_API_KEY_PREFIX, tenant),
+        }
+    }
+
+    /// Reads the role, applies `update` and writes it back
+    /// only if the role is not changed in the meantime.
+    fn update_role_info<F>(
+        &self,
+        role: String,
+        seq: Option<u64>,
+        update: F,
+    ) -> Result<Option<u64>>
+    where
+        F: FnOnce(&mut RoleInfo),
+    {
+        let (role_seq, mut role_info) = self.get_role(role.clone(), seq)?;
+        update(&mut role_info);
+
+        let key = format!("{}/{}", self.role_prefix, role_info.name);
+        let value = serde_json::to_vec(&role_info)?;
+        let res = self
+            .kv_api
+            .sync_upsert_kv(&key, MatchSeq::Exact(role_seq), Some(value), None)?;
+        match res.result {
+            Some((s, _)) => Ok(Some(s)),
+            None => Err(ErrorCode::UnknownRole(format!(
+                "unknown role, or role is changed concurrently {}",
+                role
+            ))),
+        }
+    }
+}
+
+impl RoleMgrApi for RoleMgr {
+    fn add_role(&self, role_info: RoleInfo) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = format!("{}/{}", self.role_prefix, role_info.name);
+        let value = serde_json::to_vec(&role_info)?;
+
+        let res = self
+            .kv_api
+            .sync_upsert_kv(&key, match_seq, Some(value), None)?;
+
+        match res {
+            UpsertKVActionResult {
+                prev: None,
+                result: Some((s, _)),
+            } => Ok(s),
+            UpsertKVActionResult {
+                prev: Some((s, _)),
+                result: _,
+            } => Err(ErrorCode::RoleAlreadyExists(format!(
+                "Role already exists, seq [{}]",
+                s
+            ))),
+            catch_result @ UpsertKVActionResult { .. } => {
+                Err(ErrorCode::UnknownException(format!(
+                    "upsert result not expected (using version 0, got {:?})",
+                    catch_result
+                )))
+            }
+        }
+    }
+
+    fn get_role(&self, role: String, seq: Option<u64>) -> Result<SeqValue<RoleInfo>> {
+        let key = format!("{}/{}", self.role_prefix, role);
+        let res = self.kv_api.sync_get_kv(&key)?;
+
+        let seq_value = res
+            .result
+            .ok_or_else(|| ErrorCode::UnknownRole(format!("unknown role {}", role)))?;
+
+        match MatchSeq::from(seq).match_seq(&seq_value) {
+            Ok(_) => Ok((seq_value.0, seq_value.1.value.try_into()?)),
+            Err(_) => Err(ErrorCode::UnknownRole(format!("role: {}", role))),
+        }
+    }
+
+    fn get_roles(&self) -> Result<Vec<SeqValue<RoleInfo>>> {
+        let values = self.kv_api.sync_prefix_list_kv(self.role_prefix.as_str())?;
+        let mut r = vec![];
+        for (_key, (s, val)) in values {
+            r.push((s, val.value.try_into()?));
+        }
+
+        Ok(r)
+    }
+
+    fn grant_role_privileges(
+        &self,
+        role: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_role_info(role, seq, |role_info| {
+            role_info.grants.grant_privileges(&object, privileges)
+        })
+    }
+
+    fn revoke_role_privileges(
+        &self,
+        role: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_role_info(role, seq, |role_info| {
+            role_info.grants.revoke_privileges(&object, privileges)
+        })
+    }
+
+    fn set_role_quota(
+        &self,
+        role: String,
+        quota: Option<UserQuota>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_role_info(role, seq, |role_info| role_info.quota = quota)
+    }
+
+    fn set_role_resource_group(
+        &self,
+        role: String,
+        resource_group: Option<String>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_role_info(role, seq, |role_info| {
+            role_info.resource_group = resource_group
+        })
+    }
+
+    fn drop_role(&self, role: String, seq: Option<u64>) -> Result<()> {
+        let key = format!("{}/{}", self.role_prefix, role);
+        let res = self.kv_api.sync_upsert_kv(&key, seq.into(), None, None)?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownRole(format!("unknown role {}", role)))
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_kv::KV;
+
+use crate::role::role_api::RoleInfo;
+use crate::role::role_api::RoleMgrApi;
+use crate::role::role_mgr::RoleMgr;
+use crate::user::user_grant::GrantObject;
+use crate::user::user_privilege::UserPrivilegeSet;
+use crate::user::user_privilege::UserPrivilegeType;
+
+fn new_role_api() -> Result<RoleMgr> {
+    let kv_api = Arc::new(KV::sync_new_temp()?);
+    Ok(RoleMgr::new(kv_api, "tenant1"))
+}
+
+#[test]
+fn test_add_role() -> Result<()> {
+    let role_api = new_role_api()?;
+
+    let seq = role_api.add_role(RoleInfo::new("role1".to_string()))?;
+    assert_eq!(seq, 1);
+
+    let (_, role) = role_api.get_role("role1".to_string(), None)?;
+    assert_eq!(role, RoleInfo::new("role1".to_string()));
+
+    match role_api.add_role(RoleInfo::new("role1".to_string())) {
+        Ok(_) => assert!(false, "Already exists add role must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 3004),
+    }
+
+    role_api.add_role(RoleInfo::new("role2".to_string()))?;
+    let roles = role_api.get_roles()?;
+    assert_eq!(roles.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_grant_and_revoke_role_privileges() -> Result<()> {
+    let role_api = new_role_api()?;
+    role_api.add_role(RoleInfo::new("role1".to_string()))?;
+
+    let db = GrantObject::Database("db".to_string());
+    let table = GrantObject::Table("db".to_string(), "t".to_string());
+    role_api.grant_role_privileges(
+        "role1".to_string(),
+        db.clone(),
+        UserPrivilegeSet::all_privileges(),
+        None,
+    )?;
+
+    let (_, role) = role_api.get_role("role1".to_string(), None)?;
+    assert!(role
+        .grants
+        .verify_privilege(&table, UserPrivilegeType::Insert));
+
+    role_api.revoke_role_privileges(
+        "role1".to_string(),
+        db,
+        UserPrivilegeType::Insert.into(),
+        None,
+    )?;
+
+    let (_, role) = role_api.get_role("role1".to_string(), None)?;
+    assert!(!role
+        .grants
+        .verify_privilege(&table, UserPrivilegeType::Insert));
+    assert!(role
+        .grants
+        .verify_privilege(&table, UserPrivilegeType::Select));
+
+    match role_api.grant_role_privileges(
+        "unknown".to_string(),
+        GrantObject::Global,
+        UserPrivilegeType::Select.into(),
+        None,
+    ) {
+        Ok(_) => assert!(false, "Grant to unknown role must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 3003),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_drop_role() -> Result<()> {
+    let role_api = new_role_api()?;
+    role_api.add_role(RoleInfo::new("role1".to_string()))?;
+
+    role_api.drop_role("role1".to_string(), None)?;
+    assert_eq!(
+        role_api
+            .get_role("role1".to_string(), None)
+            .unwrap_err()
+            .code(),
+        3003
+    );
+
+    match role_api.drop_role("role1".to_string(), None) {
+        Ok(_) => assert!(false, "Unknown role drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 3003),
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metatypes::SeqValue;
+
+/// A setting value applied to the new sessions of the tenant or of a user. The value is kept
+/// as it's written in SET, the query node checks it against the type of the setting.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UserSetting {
+    pub name: String,
+    pub value: String,
+}
+
+impl UserSetting {
+    pub fn new(name: &str, value: &str) -> Self {
+        UserSetting {
+            name: name.to_lowercase(),
+            value: value.to_string(),
+        }
+    }
+}
+
+pub trait SettingMgrApi: Sync + Send {
+    /// Set the global value of the setting, the previous value is replaced.
+    fn set_setting(&self, setting: UserSetting) -> Result<u64>;
+
+    fn get_settings(&self) -> Result<Vec<SeqValue<UserSetting>>>;
+
+    fn drop_setting(&self, name: String, seq: Option<u64>) -> Result<()>;
+}
+
+impl TryFrom<Vec<u8>> for UserSetting {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(setting) => Ok(setting),
+            Err(serialize_error) => Err(ErrorCode::IllegalUserSettingFormat(format!(
+                "Cannot deserialize setting from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_kv_api::KVApi;
+use common_kv_api::SyncKVApi;
+use common_metatypes::MatchSeq;
+use common_metatypes::SeqValue;
+
+use crate::setting::setting_api::SettingMgrApi;
+use crate::setting::setting_api::UserSetting;
+
+pub static SETTING_API_KEY_PREFIX: &str = "__fd_settings";
+
+pub struct SettingMgr {
+    kv_api: Arc<dyn KVApi>,
+    setting_prefix: String,
+}
+
+impl SettingMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        SettingMgr {
+            kv_api,
+            setting_prefix: format!("{}/{}", SETTING_API_KEY_PREFIX, tenant),
+        }
+    }
+}
+
+impl SettingMgrApi for SettingMgr {
+    fn set_setting(&self, setting: UserSetting) -> Result<u64> {
+        let key = format!("{}/{}", self.setting_prefix, setting.name);
+        let value = serde_json::to_vec(&setting)?;
+
+        let res = self
+            .kv_api
+            .sync_upsert_kv(&key, MatchSeq::Any, Some(value), None)?;
+
+        match res.result {
+            Some((s, _)) => Ok(s),
+            None => Err(ErrorCode::UnknownException(format!(
+                "Cannot set the global setting {}",
+                setting.name
+            ))),
+        }
+    }
+
+    fn get_settings(&self) -> Result<Vec<SeqValue<UserSetting>>> {
+        let values = self
+            .kv_api
+            .sync_prefix_list_kv(self.setting_prefix.as_str())?;
+        let mut r = vec![];
+        for (_key, (s, val)) in values {
+            r.push((s, val.value.try_into()?));
+        }
+
+        Ok(r)
+    }
+
+    fn drop_setting(&self, name: String, seq: Option<u64>) -> Result<()> {
+        let key = format!("{}/{}", self.setting_prefix, name);
+        let res = self.kv_api.sync_upsert_kv(&key, seq.into(), None, None)?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownVariable(format!(
+                "Unknown global setting {}",
+                name
+            )))
+        }
+    }
+}
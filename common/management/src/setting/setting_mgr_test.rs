@@ -0,0 +1,57 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_kv::KV;
+
+use crate::setting::setting_api::SettingMgrApi;
+use crate::setting::setting_api::UserSetting;
+use crate::setting::setting_mgr::SettingMgr;
+
+#[test]
+fn test_setting_mgr() -> Result<()> {
+    let kv_api = Arc::new(KV::sync_new_temp()?);
+    let setting_api = SettingMgr::new(kv_api.clone(), "tenant1");
+
+    setting_api.set_setting(UserSetting::new("max_threads", "4"))?;
+    setting_api.set_setting(UserSetting::new("Timezone", "Asia/Shanghai"))?;
+
+    // The later value replaces the previous one.
+    setting_api.set_setting(UserSetting::new("max_threads", "8"))?;
+
+    let mut settings = setting_api
+        .get_settings()?
+        .into_iter()
+        .map(|(_, setting)| setting)
+        .collect::<Vec<_>>();
+    settings.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(settings, vec![
+        UserSetting::new("max_threads", "8"),
+        UserSetting::new("timezone", "Asia/Shanghai"),
+    ]);
+
+    // The settings of the other tenants are not visible.
+    let other_tenant_api = SettingMgr::new(kv_api, "tenant2");
+    assert_eq!(other_tenant_api.get_settings()?.len(), 0);
+
+    setting_api.drop_setting("max_threads".to_string(), None)?;
+    match setting_api.drop_setting("max_threads".to_string(), None) {
+        Ok(_) => assert!(false, "Drop unknown setting must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 20),
+    }
+    assert_eq!(setting_api.get_settings()?.len(), 1);
+    Ok(())
+}
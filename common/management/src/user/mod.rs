@@ -14,7 +14,11 @@
 //
 
 pub(crate) mod user_api;
+pub(crate) mod user_grant;
 pub(crate) mod user_mgr;
+pub(crate) mod user_privilege;
 
+#[cfg(test)]
+mod user_grant_test;
 #[cfg(test)]
 mod user_mgr_test;
@@ -19,6 +19,12 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_metatypes::SeqValue;
 
+use crate::quota::quota_api::UserQuota;
+use crate::setting::setting_api::UserSetting;
+use crate::user::user_grant::GrantObject;
+use crate::user::user_grant::UserGrantSet;
+use crate::user::user_privilege::UserPrivilegeSet;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum AuthType {
     None = 0,
@@ -27,19 +33,39 @@ pub enum AuthType {
     Sha256 = 3,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct UserInfo {
     pub name: String,
     pub password: Vec<u8>,
     pub auth_type: AuthType,
+    /// The privileges granted to the user itself.
+    #[serde(default)]
+    pub grants: UserGrantSet,
+    /// The roles granted to the user, the user has their privileges too.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// The limits of the queries of the user in every interval.
+    #[serde(default)]
+    pub quota: Option<UserQuota>,
+    /// The resource group queueing the queries of the user.
+    #[serde(default)]
+    pub resource_group: Option<String>,
+    /// The default settings of the sessions of the user, they override the global settings.
+    #[serde(default)]
+    pub settings: Vec<UserSetting>,
 }
 
 impl UserInfo {
-    pub(crate) fn new(name: String, password: Vec<u8>, auth_type: AuthType) -> Self {
+    pub fn new(name: String, password: Vec<u8>, auth_type: AuthType) -> Self {
         UserInfo {
             name,
             password,
             auth_type,
+            grants: UserGrantSet::empty(),
+            roles: vec![],
+            quota: None,
+            resource_group: None,
+            settings: vec![],
         }
     }
 }
@@ -59,6 +85,58 @@ pub trait UserMgrApi: Sync + Send {
         seq: Option<u64>,
     ) -> Result<Option<u64>>;
 
+    fn grant_user_privileges(
+        &self,
+        username: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn revoke_user_privileges(
+        &self,
+        username: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn grant_user_role(
+        &self,
+        username: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn revoke_user_role(
+        &self,
+        username: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn set_user_quota(
+        &self,
+        username: String,
+        quota: Option<UserQuota>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    fn set_user_resource_group(
+        &self,
+        username: String,
+        resource_group: Option<String>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    /// Set the default settings of the user, the previous settings are replaced.
+    fn set_user_settings(
+        &self,
+        username: String,
+        settings: Vec<UserSetting>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
     fn drop_user(&self, username: String, seq: Option<u64>) -> Result<()>;
 }
 
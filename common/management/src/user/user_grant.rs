@@ -0,0 +1,105 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use crate::user::user_privilege::UserPrivilegeSet;
+use crate::user::user_privilege::UserPrivilegeType;
+
+/// The object a privilege is granted on: `*.*`, `db.*` or `db.table`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum GrantObject {
+    Global,
+    Database(String),
+    Table(String, String),
+}
+
+impl GrantObject {
+    /// Whether the privileges granted on this object apply to the `object`,
+    /// e.g. the grants on `db.*` apply to all the tables of `db`.
+    pub fn contains(&self, object: &GrantObject) -> bool {
+        match (self, object) {
+            (GrantObject::Global, _) => true,
+            (GrantObject::Database(lhs), GrantObject::Database(rhs)) => lhs == rhs,
+            (GrantObject::Database(lhs), GrantObject::Table(rhs, _)) => lhs == rhs,
+            (GrantObject::Table(lhs_db, lhs_table), GrantObject::Table(rhs_db, rhs_table)) => {
+                lhs_db == rhs_db && lhs_table == rhs_table
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for GrantObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GrantObject::Global => write!(f, "*.*"),
+            GrantObject::Database(db) => write!(f, "'{}'.*", db),
+            GrantObject::Table(db, table) => write!(f, "'{}'.'{}'", db, table),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GrantEntry {
+    pub object: GrantObject,
+    pub privileges: UserPrivilegeSet,
+}
+
+impl fmt::Display for GrantEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GRANT {} ON {}", self.privileges, self.object)
+    }
+}
+
+/// The privileges granted to a user or a role, one entry per object.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct UserGrantSet {
+    entries: Vec<GrantEntry>,
+}
+
+impl UserGrantSet {
+    pub fn empty() -> Self {
+        UserGrantSet { entries: vec![] }
+    }
+
+    pub fn entries(&self) -> &[GrantEntry] {
+        &self.entries
+    }
+
+    pub fn grant_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
+        match self.entries.iter_mut().find(|e| &e.object == object) {
+            Some(entry) => entry.privileges = entry.privileges.union(&privileges),
+            None => self.entries.push(GrantEntry {
+                object: object.clone(),
+                privileges,
+            }),
+        }
+    }
+
+    /// Revokes the privileges granted on exactly this object,
+    /// the privileges granted on the enclosing objects are kept.
+    pub fn revoke_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
+        for entry in self.entries.iter_mut().filter(|e| &e.object == object) {
+            entry.privileges = entry.privileges.difference(&privileges);
+        }
+        self.entries.retain(|e| !e.privileges.is_empty());
+    }
+
+    pub fn verify_privilege(&self, object: &GrantObject, privilege: UserPrivilegeType) -> bool {
+        self.entries
+            .iter()
+            .any(|e| e.object.contains(object) && e.privileges.has_privilege(privilege))
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::user::user_grant::GrantObject;
+use crate::user::user_grant::UserGrantSet;
+use crate::user::user_privilege::UserPrivilegeSet;
+use crate::user::user_privilege::UserPrivilegeType;
+
+#[test]
+fn test_grant_object_contains() -> Result<()> {
+    let db = GrantObject::Database("db".to_string());
+    let table = GrantObject::Table("db".to_string(), "t".to_string());
+    let other_table = GrantObject::Table("other".to_string(), "t".to_string());
+
+    assert!(GrantObject::Global.contains(&db));
+    assert!(GrantObject::Global.contains(&table));
+    assert!(db.contains(&table));
+    assert!(!db.contains(&other_table));
+    assert!(!table.contains(&db));
+    assert!(!db.contains(&GrantObject::Global));
+    Ok(())
+}
+
+#[test]
+fn test_user_privilege_set() -> Result<()> {
+    let mut privileges = UserPrivilegeSet::empty();
+    assert!(privileges.is_empty());
+
+    privileges.set_privilege(UserPrivilegeType::Select);
+    privileges.set_privilege(UserPrivilegeType::Insert);
+    assert!(privileges.has_privilege(UserPrivilegeType::Select));
+    assert!(!privileges.has_privilege(UserPrivilegeType::Drop));
+    assert_eq!(privileges.to_string(), "SELECT,INSERT");
+
+    let all = UserPrivilegeSet::all_privileges();
+    assert!(all.is_all_privileges());
+    assert_eq!(all.to_string(), "ALL");
+
+    let rest = all.difference(&privileges);
+    assert!(!rest.has_privilege(UserPrivilegeType::Select));
+    assert!(rest.has_privilege(UserPrivilegeType::Create));
+    assert_eq!(rest.union(&privileges), all);
+
+    assert!(UserPrivilegeType::try_from_name("select").is_ok());
+    assert_eq!(
+        UserPrivilegeType::try_from_name("update")
+            .unwrap_err()
+            .code(),
+        5
+    );
+    Ok(())
+}
+
+#[test]
+fn test_user_grant_set() -> Result<()> {
+    let db = GrantObject::Database("db".to_string());
+    let table = GrantObject::Table("db".to_string(), "t".to_string());
+
+    let mut grants = UserGrantSet::empty();
+    assert!(!grants.verify_privilege(&table, UserPrivilegeType::Select));
+
+    grants.grant_privileges(&db, UserPrivilegeType::Select.into());
+    grants.grant_privileges(&table, UserPrivilegeType::Insert.into());
+    grants.grant_privileges(&table, UserPrivilegeType::Drop.into());
+    assert_eq!(grants.entries().len(), 2);
+    assert_eq!(
+        grants.entries()[1].to_string(),
+        "GRANT INSERT,DROP ON 'db'.'t'"
+    );
+
+    assert!(grants.verify_privilege(&table, UserPrivilegeType::Select));
+    assert!(grants.verify_privilege(&table, UserPrivilegeType::Insert));
+    assert!(!grants.verify_privilege(&db, UserPrivilegeType::Insert));
+    assert!(!grants.verify_privilege(&GrantObject::Global, UserPrivilegeType::Select));
+
+    // Revoking on the table keeps the privileges granted on the database.
+    grants.revoke_privileges(&table, UserPrivilegeSet::all_privileges());
+    assert_eq!(grants.entries().len(), 1);
+    assert!(grants.verify_privilege(&table, UserPrivilegeType::Select));
+    assert!(!grants.verify_privilege(&table, UserPrivilegeType::Insert));
+
+    grants.revoke_privileges(&db, UserPrivilegeType::Select.into());
+    assert!(grants.entries().is_empty());
+
+    grants.grant_privileges(&GrantObject::Global, UserPrivilegeSet::all_privileges());
+    assert!(grants.verify_privilege(&table, UserPrivilegeType::Drop));
+    assert_eq!(grants.entries()[0].to_string(), "GRANT ALL ON *.*");
+    Ok(())
+}
@@ -27,8 +27,12 @@ use common_metatypes::MatchSeqExt;
 use common_metatypes::SeqValue;
 
 use super::user_api::AuthType;
+use crate::quota::quota_api::UserQuota;
+use crate::setting::setting_api::UserSetting;
 use crate::user::user_api::UserInfo;
 use crate::user::user_api::UserMgrApi;
+use crate::user::user_grant::GrantObject;
+use crate::user::user_privilege::UserPrivilegeSet;
 
 pub static USER_API_KEY_PREFIX: &str = "__fd_users";
 
@@ -44,6 +48,34 @@ impl UserMgr {
             user_prefix: format!("{}/{}", USER_API_KEY_PREFIX, tenant),
         }
     }
+
+    /// Reads the user, applies `update` and writes it back
+    /// only if the user is not changed in the meantime.
+    fn update_user_info<F>(
+        &self,
+        username: String,
+        seq: Option<u64>,
+        update: F,
+    ) -> Result<Option<u64>>
+    where
+        F: FnOnce(&mut UserInfo),
+    {
+        let (user_seq, mut user_info) = self.get_user(username.clone(), seq)?;
+        update(&mut user_info);
+
+        let key = format!("{}/{}", self.user_prefix, user_info.name);
+        let value = serde_json::to_vec(&user_info)?;
+        let res = self
+            .kv_api
+            .sync_upsert_kv(&key, MatchSeq::Exact(user_seq), Some(value), None)?;
+        match res.result {
+            Some((s, _)) => Ok(Some(s)),
+            None => Err(ErrorCode::UnknownUser(format!(
+                "unknown user, or user is changed concurrently {}",
+                username
+            ))),
+        }
+    }
 }
 
 impl UserMgrApi for UserMgr {
@@ -114,18 +146,16 @@ impl UserMgrApi for UserMgr {
         if new_password.is_none() && new_auth.is_none() {
             return Ok(seq);
         }
-        let partial_update = new_auth.is_none() || new_password.is_none();
-        let user_info = if partial_update {
-            let user_val_seq = self.get_user(username.clone(), seq)?;
-            let user_info = user_val_seq.1;
-            UserInfo::new(
-                username.clone(),
-                new_password.map_or(user_info.password, |v| v.to_vec()),
-                new_auth.map_or(user_info.auth_type, |v| v),
-            )
-        } else {
-            UserInfo::new(username.clone(), new_password.unwrap(), new_auth.unwrap())
-        };
+
+        // The stored user is always read, to keep the grants and roles of the user.
+        let user_val_seq = self.get_user(username.clone(), seq)?;
+        let mut user_info = user_val_seq.1;
+        if let Some(password) = new_password {
+            user_info.password = password;
+        }
+        if let Some(auth_type) = new_auth {
+            user_info.auth_type = auth_type;
+        }
 
         let key = format!("{}/{}", self.user_prefix, user_info.name);
         let value = serde_json::to_vec(&user_info)?;
@@ -146,6 +176,83 @@ impl UserMgrApi for UserMgr {
         }
     }
 
+    fn grant_user_privileges(
+        &self,
+        username: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_user_info(username, seq, |user_info| {
+            user_info.grants.grant_privileges(&object, privileges)
+        })
+    }
+
+    fn revoke_user_privileges(
+        &self,
+        username: String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_user_info(username, seq, |user_info| {
+            user_info.grants.revoke_privileges(&object, privileges)
+        })
+    }
+
+    fn grant_user_role(
+        &self,
+        username: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_user_info(username, seq, |user_info| {
+            if !user_info.roles.contains(&role) {
+                user_info.roles.push(role);
+            }
+        })
+    }
+
+    fn revoke_user_role(
+        &self,
+        username: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_user_info(username, seq, |user_info| {
+            user_info.roles.retain(|r| r != &role)
+        })
+    }
+
+    fn set_user_quota(
+        &self,
+        username: String,
+        quota: Option<UserQuota>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_user_info(username, seq, |user_info| user_info.quota = quota)
+    }
+
+    fn set_user_resource_group(
+        &self,
+        username: String,
+        resource_group: Option<String>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_user_info(username, seq, |user_info| {
+            user_info.resource_group = resource_group
+        })
+    }
+
+    fn set_user_settings(
+        &self,
+        username: String,
+        settings: Vec<UserSetting>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        self.update_user_info(username, seq, |user_info| user_info.settings = settings)
+    }
+
     fn drop_user(&self, username: String, seq: Option<u64>) -> Result<()> {
         let key = format!("{}/{}", self.user_prefix, username);
         let res = self.kv_api.sync_upsert_kv(&key, seq.into(), None, None)?;
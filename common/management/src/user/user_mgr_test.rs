@@ -558,9 +558,16 @@ mod update {
         let test_key = format!("__fd_users/tenant1/{}", test_user_name);
         let test_seq = None;
 
-        // - get_kv should NOT be called
+        // - get_kv should be called, to keep the grants and roles of the user
         // - update_kv should be called
 
+        let old_user_info = UserInfo::new(
+            test_user_name.to_string(),
+            Vec::from("old_pass"),
+            AuthType::DoubleSha1,
+        );
+        let prev_value = serde_json::to_vec(&old_user_info)?;
+
         let new_pass = "new_pass";
         let new_auth_type = AuthType::Sha256;
 
@@ -572,6 +579,20 @@ mod update {
         let new_value = serde_json::to_vec(&new_user_info)?;
 
         let mut kv = MockKV::new();
+        {
+            let test_key = test_key.clone();
+            kv.expect_get_kv()
+                .with(predicate::function(move |v| v == test_key.as_str()))
+                .times(1)
+                .return_once(move |_k| {
+                    Ok(GetKVActionResult {
+                        result: Some((1, KVValue {
+                            meta: None,
+                            value: prev_value,
+                        })),
+                    })
+                });
+        }
         kv.expect_upsert_kv()
             .with(
                 predicate::function(move |v| v == test_key.as_str()),
@@ -652,25 +673,13 @@ mod update {
         let test_key = format!("__fd_users/tenant1/{}", test_user_name);
         let test_seq = None;
 
-        // get_kv should not be called
+        // get_kv returns None, update_kv should NOT be called
         let mut kv = MockKV::new();
         let test_key = test_key.clone();
-
-        // upsert should be called
-        kv.expect_upsert_kv()
-            .with(
-                predicate::function(move |v| v == test_key.as_str()),
-                predicate::eq(MatchSeq::GE(1)),
-                predicate::always(), // a little bit relax here, as we've covered it before
-                predicate::eq(None),
-            )
+        kv.expect_get_kv()
+            .with(predicate::function(move |v| v == test_key.as_str()))
             .times(1)
-            .returning(|_u, _s, _salt, _meta| {
-                Ok(UpsertKVActionResult {
-                    prev: None,
-                    result: None,
-                })
-            });
+            .return_once(move |_k| Ok(GetKVActionResult { result: None }));
 
         let kv = Arc::new(kv);
         let user_mgr = UserMgr::new(kv, "tenant1");
@@ -0,0 +1,138 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UserPrivilegeType {
+    // Read the rows of the tables.
+    Select = 1 << 0,
+    // Insert rows into the tables.
+    Insert = 1 << 1,
+    // Create databases and tables.
+    Create = 1 << 2,
+    // Drop or truncate databases and tables.
+    Drop = 1 << 3,
+}
+
+const ALL_PRIVILEGES: [UserPrivilegeType; 4] = [
+    UserPrivilegeType::Select,
+    UserPrivilegeType::Insert,
+    UserPrivilegeType::Create,
+    UserPrivilegeType::Drop,
+];
+
+impl UserPrivilegeType {
+    pub fn try_from_name(name: &str) -> Result<UserPrivilegeType> {
+        match name.to_uppercase().as_str() {
+            "SELECT" => Ok(UserPrivilegeType::Select),
+            "INSERT" => Ok(UserPrivilegeType::Insert),
+            "CREATE" => Ok(UserPrivilegeType::Create),
+            "DROP" => Ok(UserPrivilegeType::Drop),
+            _ => Err(ErrorCode::SyntaxException(format!(
+                "Unknown privilege {}, expect SELECT, INSERT, CREATE, DROP or ALL",
+                name
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for UserPrivilegeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            UserPrivilegeType::Select => "SELECT",
+            UserPrivilegeType::Insert => "INSERT",
+            UserPrivilegeType::Create => "CREATE",
+            UserPrivilegeType::Drop => "DROP",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A set of privileges, stored as the bits of `UserPrivilegeType`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct UserPrivilegeSet {
+    privileges: u64,
+}
+
+impl UserPrivilegeSet {
+    pub fn empty() -> Self {
+        UserPrivilegeSet { privileges: 0 }
+    }
+
+    pub fn all_privileges() -> Self {
+        let mut privileges = Self::empty();
+        for privilege in ALL_PRIVILEGES {
+            privileges.set_privilege(privilege);
+        }
+        privileges
+    }
+
+    pub fn set_privilege(&mut self, privilege: UserPrivilegeType) {
+        self.privileges |= privilege as u64;
+    }
+
+    pub fn has_privilege(&self, privilege: UserPrivilegeType) -> bool {
+        self.privileges & (privilege as u64) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.privileges == 0
+    }
+
+    pub fn is_all_privileges(&self) -> bool {
+        *self == Self::all_privileges()
+    }
+
+    pub fn union(&self, other: &UserPrivilegeSet) -> UserPrivilegeSet {
+        UserPrivilegeSet {
+            privileges: self.privileges | other.privileges,
+        }
+    }
+
+    pub fn difference(&self, other: &UserPrivilegeSet) -> UserPrivilegeSet {
+        UserPrivilegeSet {
+            privileges: self.privileges & !other.privileges,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = UserPrivilegeType> + '_ {
+        ALL_PRIVILEGES
+            .iter()
+            .copied()
+            .filter(move |privilege| self.has_privilege(*privilege))
+    }
+}
+
+impl From<UserPrivilegeType> for UserPrivilegeSet {
+    fn from(privilege: UserPrivilegeType) -> Self {
+        let mut privileges = UserPrivilegeSet::empty();
+        privileges.set_privilege(privilege);
+        privileges
+    }
+}
+
+impl fmt::Display for UserPrivilegeSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_all_privileges() {
+            return write!(f, "ALL");
+        }
+
+        let names = self.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", names.join(","))
+    }
+}
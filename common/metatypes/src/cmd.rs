@@ -41,6 +41,9 @@ pub enum Cmd {
     /// Add node if absent
     AddNode { node_id: NodeId, node: Node },
 
+    /// Remove node if present
+    RemoveNode { node_id: NodeId },
+
     /// Add a database if absent
     CreateDatabase {
         // TODO(ariesdevil): add `seq` for distinguish between the results of the execution of
@@ -109,6 +112,9 @@ impl fmt::Display for Cmd {
             Cmd::AddNode { node_id, node } => {
                 write!(f, "add_node:{}={}", node_id, node)
             }
+            Cmd::RemoveNode { node_id } => {
+                write!(f, "remove_node:{}", node_id)
+            }
             Cmd::CreateDatabase {
                 name,
                 if_not_exists,
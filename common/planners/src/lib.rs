@@ -29,6 +29,8 @@ mod plan_extras_test;
 #[cfg(test)]
 mod plan_filter_test;
 #[cfg(test)]
+mod plan_grouping_sets_test;
+#[cfg(test)]
 mod plan_having_test;
 #[cfg(test)]
 mod plan_limit_test;
@@ -48,6 +50,7 @@ mod plan_aggregator_partial;
 mod plan_broadcast;
 mod plan_builder;
 mod plan_builder_scan;
+mod plan_copy_into;
 mod plan_database_create;
 mod plan_database_drop;
 mod plan_describe_table;
@@ -61,6 +64,7 @@ mod plan_expression_chain;
 mod plan_expression_column;
 mod plan_expression_common;
 mod plan_expression_function;
+mod plan_expression_lambda;
 mod plan_expression_literal;
 mod plan_expression_rewriter;
 mod plan_expression_sort;
@@ -68,6 +72,7 @@ mod plan_expression_validator;
 mod plan_expression_visitor;
 mod plan_extras;
 mod plan_filter;
+mod plan_grouping_sets;
 mod plan_having;
 mod plan_insert_into;
 mod plan_kill;
@@ -75,10 +80,21 @@ mod plan_limit;
 mod plan_limit_by;
 mod plan_node;
 mod plan_partition;
+mod plan_privilege_grant;
+mod plan_privilege_revoke;
 mod plan_projection;
+mod plan_quota_create;
+mod plan_quota_drop;
 mod plan_read_datasource;
 mod plan_remote;
+mod plan_resource_group_create;
+mod plan_resource_group_drop;
+mod plan_resource_group_grant;
 mod plan_rewriter;
+mod plan_role_create;
+mod plan_role_drop;
+mod plan_role_grant;
+mod plan_role_revoke;
 mod plan_scan;
 mod plan_select;
 mod plan_setting;
@@ -91,6 +107,9 @@ mod plan_table_create;
 mod plan_table_drop;
 mod plan_truncate_table;
 mod plan_use_database;
+mod plan_user_alter;
+mod plan_user_create;
+mod plan_user_drop;
 mod plan_visitor;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
@@ -98,6 +117,8 @@ pub use plan_aggregator_partial::AggregatorPartialPlan;
 pub use plan_broadcast::BroadcastPlan;
 pub use plan_builder::PlanBuilder;
 pub use plan_builder_scan::TableScanInfo;
+pub use plan_copy_into::CopyFormatOptions;
+pub use plan_copy_into::CopyIntoPlan;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
@@ -119,6 +140,7 @@ pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
+pub use plan_expression_common::rebase_grouping_exprs;
 pub use plan_expression_common::resolve_aliases_to_exprs;
 pub use plan_expression_common::sort_to_inner_expr;
 pub use plan_expression_common::unwrap_alias_exprs;
@@ -135,6 +157,13 @@ pub use plan_expression_visitor::ExpressionVisitor;
 pub use plan_expression_visitor::Recursion;
 pub use plan_extras::Extras;
 pub use plan_filter::FilterPlan;
+pub use plan_grouping_sets::expand_grouping_sets;
+pub use plan_grouping_sets::grouping_key_column_name;
+pub use plan_grouping_sets::grouping_set_id;
+pub use plan_grouping_sets::grouping_sets_key_fields;
+pub use plan_grouping_sets::GROUPING_ID_COLUMN_NAME;
+pub use plan_grouping_sets::MAX_GROUPING_SETS;
+pub use plan_grouping_sets::MAX_GROUPING_SET_KEYS;
 pub use plan_having::HavingPlan;
 pub use plan_insert_into::InsertIntoPlan;
 pub use plan_kill::KillPlan;
@@ -143,14 +172,27 @@ pub use plan_limit_by::LimitByPlan;
 pub use plan_node::PlanNode;
 pub use plan_partition::Part;
 pub use plan_partition::Partitions;
+pub use plan_privilege_grant::GrantPrivilegePlan;
+pub use plan_privilege_grant::PrincipalIdentity;
+pub use plan_privilege_revoke::RevokePrivilegePlan;
 pub use plan_projection::ProjectionPlan;
+pub use plan_quota_create::CreateQuotaPlan;
+pub use plan_quota_drop::DropQuotaPlan;
 pub use plan_read_datasource::ReadDataSourcePlan;
 pub use plan_remote::RemotePlan;
+pub use plan_resource_group_create::CreateResourceGroupPlan;
+pub use plan_resource_group_drop::DropResourceGroupPlan;
+pub use plan_resource_group_grant::GrantResourceGroupPlan;
 pub use plan_rewriter::PlanRewriter;
 pub use plan_rewriter::RewriteHelper;
+pub use plan_role_create::CreateRolePlan;
+pub use plan_role_drop::DropRolePlan;
+pub use plan_role_grant::GrantRolePlan;
+pub use plan_role_revoke::RevokeRolePlan;
 pub use plan_scan::ScanPlan;
 pub use plan_select::SelectPlan;
 pub use plan_setting::SettingPlan;
+pub use plan_setting::SettingScope;
 pub use plan_setting::VarValue;
 pub use plan_show_table_create::ShowCreateTablePlan;
 pub use plan_sort::SortPlan;
@@ -163,4 +205,7 @@ pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
 pub use plan_truncate_table::TruncateTablePlan;
 pub use plan_use_database::UseDatabasePlan;
+pub use plan_user_alter::AlterUserPlan;
+pub use plan_user_create::CreateUserPlan;
+pub use plan_user_drop::DropUserPlan;
 pub use plan_visitor::PlanVisitor;
@@ -23,6 +23,8 @@ use crate::PlanNode;
 pub struct AggregatorFinalPlan {
     pub aggr_expr: Vec<Expression>,
     pub group_expr: Vec<Expression>,
+    // Indexes into group_expr of each grouping set, empty for a plain group by.
+    pub grouping_sets: Vec<Vec<usize>>,
     pub schema: DataSchemaRef,
     pub schema_before_group_by: DataSchemaRef,
     pub input: Arc<PlanNode>,
@@ -23,6 +23,7 @@ use common_datavalues::DataType;
 use common_exception::Result;
 
 use crate::col;
+use crate::grouping_sets_key_fields;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::validate_expression;
 use crate::AggregatorFinalPlan;
@@ -41,6 +42,7 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::GROUPING_ID_COLUMN_NAME;
 
 pub enum AggregateMode {
     Partial,
@@ -123,6 +125,7 @@ impl PlanBuilder {
         schema_before_groupby: DataSchemaRef,
         aggr_expr: &[Expression],
         group_expr: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<Self> {
         Ok(match mode {
             AggregateMode::Partial => {
@@ -133,7 +136,17 @@ impl PlanBuilder {
                     .map(|f| DataField::new(f.name(), DataType::String, false))
                     .collect::<Vec<_>>();
 
-                if !group_expr.is_empty() {
+                if !grouping_sets.is_empty() {
+                    // Fields. [aggrs,  key]
+                    // key: the grouping id and the group by keys of the grouping set
+                    let key_fields = grouping_sets_key_fields(&schema_before_groupby, group_expr)?;
+                    let group_cols: Vec<String> =
+                        key_fields.iter().map(|f| f.name().clone()).collect();
+                    let sample_block =
+                        DataBlock::empty_with_schema(DataSchemaRefExt::create(key_fields));
+                    let method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
+                    partial_fields.push(DataField::new("_group_by_key", method.data_type(), false));
+                } else if !group_expr.is_empty() {
                     // Fields. [aggrs,  key]
                     // aggrs: aggr_len aggregate states
                     // key: Varint by hash method
@@ -149,19 +162,37 @@ impl PlanBuilder {
                     input: Arc::new(self.plan.clone()),
                     aggr_expr: aggr_expr.to_vec(),
                     group_expr: group_expr.to_vec(),
+                    grouping_sets: grouping_sets.to_vec(),
                     schema: DataSchemaRefExt::create(partial_fields),
                 }))
             }
             AggregateMode::Final => {
-                let mut final_exprs = aggr_expr.to_owned();
-                final_exprs.extend_from_slice(group_expr);
-                let final_fields =
-                    RewriteHelper::exprs_to_fields(&final_exprs, &schema_before_groupby)?;
+                let mut final_fields =
+                    RewriteHelper::exprs_to_fields(aggr_expr, &schema_before_groupby)?;
+                let group_fields =
+                    RewriteHelper::exprs_to_fields(group_expr, &schema_before_groupby)?;
+
+                if grouping_sets.is_empty() {
+                    final_fields.extend(group_fields);
+                } else {
+                    // The keys which are rolled up in a grouping set are NULL.
+                    final_fields.extend(
+                        group_fields
+                            .iter()
+                            .map(|f| DataField::new(f.name(), f.data_type().clone(), true)),
+                    );
+                    final_fields.push(DataField::new(
+                        GROUPING_ID_COLUMN_NAME,
+                        DataType::UInt32,
+                        false,
+                    ));
+                }
 
                 Self::from(&PlanNode::AggregatorFinal(AggregatorFinalPlan {
                     input: Arc::new(self.plan.clone()),
                     aggr_expr: aggr_expr.to_vec(),
                     group_expr: group_expr.to_vec(),
+                    grouping_sets: grouping_sets.to_vec(),
                     schema: DataSchemaRefExt::create(final_fields),
                     schema_before_group_by: schema_before_groupby,
                 }))
@@ -174,27 +205,51 @@ impl PlanBuilder {
         &self,
         aggr_expr: &[Expression],
         group_expr: &[Expression],
+    ) -> Result<Self> {
+        self.aggregate_grouping_sets_partial(aggr_expr, group_expr, &[])
+    }
+
+    /// Apply a final aggregator plan.
+    pub fn aggregate_final(
+        &self,
+        schema_before_group_by: DataSchemaRef,
+        aggr_expr: &[Expression],
+        group_expr: &[Expression],
+    ) -> Result<Self> {
+        self.aggregate_grouping_sets_final(schema_before_group_by, aggr_expr, group_expr, &[])
+    }
+
+    /// Apply a partial aggregator plan which groups by each of the grouping sets,
+    /// a grouping set is a list of indexes into the group by expressions.
+    pub fn aggregate_grouping_sets_partial(
+        &self,
+        aggr_expr: &[Expression],
+        group_expr: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<Self> {
         self.aggregate(
             AggregateMode::Partial,
             self.plan.schema(),
             aggr_expr,
             group_expr,
+            grouping_sets,
         )
     }
 
-    /// Apply a final aggregator plan.
-    pub fn aggregate_final(
+    /// Apply a final aggregator plan which groups by each of the grouping sets.
+    pub fn aggregate_grouping_sets_final(
         &self,
         schema_before_group_by: DataSchemaRef,
         aggr_expr: &[Expression],
         group_expr: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<Self> {
         self.aggregate(
             AggregateMode::Final,
             schema_before_group_by,
             aggr_expr,
             group_expr,
+            grouping_sets,
         )
     }
 
@@ -0,0 +1,55 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+
+use crate::PlanNode;
+
+pub type CopyFormatOptions = HashMap<String, String>;
+
+/// Unload the result of a query into files under a storage location, e.g.
+/// COPY INTO 's3://bucket/out/' FROM (SELECT ...) FILE_FORMAT = (TYPE = PARQUET) MAX_FILE_SIZE = 16777216
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct CopyIntoPlan {
+    /// The target location, such as `s3://bucket/path/` or `file:///tmp/path/`
+    pub location: String,
+    /// The file type of the written files, `PARQUET` or `CSV`
+    pub file_format: String,
+    /// Format specific options, such as `field_delimiter` for CSV
+    pub format_options: CopyFormatOptions,
+    /// Upper bound (in bytes of in-memory data) of each written file
+    pub max_file_size: u64,
+    pub input: Arc<PlanNode>,
+}
+
+impl CopyIntoPlan {
+    /// One row per written file.
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String, false),
+            DataField::new("rows", DataType::UInt64, false),
+            DataField::new("bytes", DataType::UInt64, false),
+        ])
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
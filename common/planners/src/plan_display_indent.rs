@@ -18,16 +18,31 @@ use std::fmt::Formatter;
 use crate::plan_broadcast::BroadcastPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterUserPlan;
+use crate::CopyIntoPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateQuotaPlan;
+use crate::CreateResourceGroupPlan;
+use crate::CreateRolePlan;
 use crate::CreateTablePlan;
+use crate::CreateUserPlan;
 use crate::DropDatabasePlan;
+use crate::DropQuotaPlan;
+use crate::DropResourceGroupPlan;
+use crate::DropRolePlan;
 use crate::DropTablePlan;
+use crate::DropUserPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::GrantPrivilegePlan;
+use crate::GrantResourceGroupPlan;
+use crate::GrantRolePlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
+use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
@@ -71,6 +86,21 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
             PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
             PlanNode::DropTable(plan) => Self::format_drop_table(f, plan),
+            PlanNode::CopyInto(plan) => Self::format_copy_into(f, plan),
+            PlanNode::CreateUser(plan) => Self::format_create_user(f, plan),
+            PlanNode::AlterUser(plan) => Self::format_alter_user(f, plan),
+            PlanNode::DropUser(plan) => Self::format_drop_user(f, plan),
+            PlanNode::CreateRole(plan) => Self::format_create_role(f, plan),
+            PlanNode::DropRole(plan) => Self::format_drop_role(f, plan),
+            PlanNode::GrantPrivilege(plan) => Self::format_grant_privilege(f, plan),
+            PlanNode::RevokePrivilege(plan) => Self::format_revoke_privilege(f, plan),
+            PlanNode::GrantRole(plan) => Self::format_grant_role(f, plan),
+            PlanNode::RevokeRole(plan) => Self::format_revoke_role(f, plan),
+            PlanNode::CreateQuota(plan) => Self::format_create_quota(f, plan),
+            PlanNode::DropQuota(plan) => Self::format_drop_quota(f, plan),
+            PlanNode::CreateResourceGroup(plan) => Self::format_create_resource_group(f, plan),
+            PlanNode::DropResourceGroup(plan) => Self::format_drop_resource_group(f, plan),
+            PlanNode::GrantResourceGroup(plan) => Self::format_grant_resource_group(f, plan),
             _ => {
                 let mut printed = true;
 
@@ -154,7 +184,11 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             f,
             "AggregatorPartial: groupBy=[{:?}], aggr=[{:?}]",
             plan.group_expr, plan.aggr_expr
-        )
+        )?;
+        if !plan.grouping_sets.is_empty() {
+            write!(f, ", groupingSets={:?}", plan.grouping_sets)?;
+        }
+        Ok(())
     }
 
     fn format_aggregator_final(f: &mut Formatter, plan: &AggregatorFinalPlan) -> fmt::Result {
@@ -162,7 +196,11 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             f,
             "AggregatorFinal: groupBy=[{:?}], aggr=[{:?}]",
             plan.group_expr, plan.aggr_expr
-        )
+        )?;
+        if !plan.grouping_sets.is_empty() {
+            write!(f, ", groupingSets={:?}", plan.grouping_sets)?;
+        }
+        Ok(())
     }
 
     fn format_sort(f: &mut Formatter, plan: &SortPlan) -> fmt::Result {
@@ -239,4 +277,97 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, "Drop table {:}.{:},", plan.db, plan.table)?;
         write!(f, " if_exists:{:}", plan.if_exists)
     }
+
+    fn format_copy_into(f: &mut Formatter, plan: &CopyIntoPlan) -> fmt::Result {
+        write!(f, "Copy into {:},", plan.location)?;
+        write!(f, " file_format: {:},", plan.file_format)?;
+        write!(f, " max_file_size: {:},", plan.max_file_size)?;
+        write!(f, " option: {:?}", plan.format_options)
+    }
+    fn format_create_user(f: &mut Formatter, plan: &CreateUserPlan) -> fmt::Result {
+        write!(f, "Create user {:},", plan.name)?;
+        write!(f, " auth_type: {:?},", plan.auth_type)?;
+        write!(f, " if_not_exists:{:}", plan.if_not_exists)
+    }
+
+    fn format_alter_user(f: &mut Formatter, plan: &AlterUserPlan) -> fmt::Result {
+        write!(f, "Alter user {:},", plan.name)?;
+        write!(f, " auth_type: {:?}", plan.auth_type)
+    }
+
+    fn format_drop_user(f: &mut Formatter, plan: &DropUserPlan) -> fmt::Result {
+        write!(f, "Drop user {:},", plan.name)?;
+        write!(f, " if_exists:{:}", plan.if_exists)
+    }
+
+    fn format_create_role(f: &mut Formatter, plan: &CreateRolePlan) -> fmt::Result {
+        write!(f, "Create role {:},", plan.name)?;
+        write!(f, " if_not_exists:{:}", plan.if_not_exists)
+    }
+
+    fn format_drop_role(f: &mut Formatter, plan: &DropRolePlan) -> fmt::Result {
+        write!(f, "Drop role {:},", plan.name)?;
+        write!(f, " if_exists:{:}", plan.if_exists)
+    }
+
+    fn format_grant_privilege(f: &mut Formatter, plan: &GrantPrivilegePlan) -> fmt::Result {
+        write!(
+            f,
+            "Grant {:} on {:} to {:}",
+            plan.privileges, plan.on, plan.principal
+        )
+    }
+
+    fn format_revoke_privilege(f: &mut Formatter, plan: &RevokePrivilegePlan) -> fmt::Result {
+        write!(
+            f,
+            "Revoke {:} on {:} from {:}",
+            plan.privileges, plan.on, plan.principal
+        )
+    }
+
+    fn format_grant_role(f: &mut Formatter, plan: &GrantRolePlan) -> fmt::Result {
+        write!(f, "Grant role {:} to user {:}", plan.role, plan.user)
+    }
+
+    fn format_revoke_role(f: &mut Formatter, plan: &RevokeRolePlan) -> fmt::Result {
+        write!(f, "Revoke role {:} from user {:}", plan.role, plan.user)
+    }
+
+    fn format_create_quota(f: &mut Formatter, plan: &CreateQuotaPlan) -> fmt::Result {
+        write!(
+            f,
+            "Create quota for {:} with {:}",
+            plan.principal, plan.quota
+        )
+    }
+
+    fn format_drop_quota(f: &mut Formatter, plan: &DropQuotaPlan) -> fmt::Result {
+        write!(f, "Drop quota for {:}", plan.principal)
+    }
+
+    fn format_create_resource_group(
+        f: &mut Formatter,
+        plan: &CreateResourceGroupPlan,
+    ) -> fmt::Result {
+        write!(f, "Create resource group {:},", plan.name)?;
+        write!(f, " max_concurrency:{:},", plan.max_concurrency)?;
+        write!(f, " max_queued:{:},", plan.max_queued)?;
+        write!(f, " if_not_exists:{:}", plan.if_not_exists)
+    }
+
+    fn format_drop_resource_group(f: &mut Formatter, plan: &DropResourceGroupPlan) -> fmt::Result {
+        write!(f, "Drop resource group {:},", plan.name)?;
+        write!(f, " if_exists:{:}", plan.if_exists)
+    }
+
+    fn format_grant_resource_group(
+        f: &mut Formatter,
+        plan: &GrantResourceGroupPlan,
+    ) -> fmt::Result {
+        match &plan.name {
+            Some(name) => write!(f, "Grant resource group {:} to {:}", name, plan.principal),
+            None => write!(f, "Revoke resource group from {:}", plan.principal),
+        }
+    }
 }
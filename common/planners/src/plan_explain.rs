@@ -26,6 +26,8 @@ pub enum ExplainType {
     Syntax,
     Graph,
     Pipeline,
+    // Executes the query and reports the runtime statistics of the pipeline.
+    Analyze,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -136,6 +136,17 @@ impl Expression {
         }
     }
 
+    /// A literal argument appended by the planner, such as the session timezone.
+    /// Its column name is empty, so it is not shown in the column name of the function.
+    pub fn create_hidden_literal(value: DataValue) -> Expression {
+        let data_type = value.data_type();
+        Expression::Literal {
+            value,
+            column_name: Some("".to_string()),
+            data_type,
+        }
+    }
+
     pub fn column_name(&self) -> String {
         match self {
             Expression::Alias(name, _expr) => name.clone(),
@@ -165,8 +176,11 @@ impl Expression {
                 match OP_SET.get(&op.to_lowercase().as_ref()) {
                     Some(_) => format!("{}()", op),
                     None => {
-                        let args_column_name =
-                            args.iter().map(Expression::column_name).collect::<Vec<_>>();
+                        let args_column_name = args
+                            .iter()
+                            .map(Expression::column_name)
+                            .filter(|name| !name.is_empty())
+                            .collect::<Vec<_>>();
 
                         format!("{}({})", op, args_column_name.join(", "))
                     }
@@ -274,10 +288,7 @@ impl Expression {
             }
 
             Expression::ScalarFunction { op, args } => {
-                let mut arg_types = Vec::with_capacity(args.len());
-                for arg in args {
-                    arg_types.push(arg.to_data_type(input_schema)?);
-                }
+                let arg_types = Self::to_function_arg_types(args, input_schema)?;
                 let func = FunctionFactory::get(op)?;
                 func.return_type(&arg_types)
             }
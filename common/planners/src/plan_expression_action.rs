@@ -23,6 +23,8 @@ use common_functions::scalars::CastFunction;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionFactory;
 
+use crate::Expression;
+
 #[derive(Debug, Clone)]
 pub enum ExpressionAction {
     /// Column which must be in input.
@@ -31,6 +33,8 @@ pub enum ExpressionAction {
     Constant(ActionConstant),
     Alias(ActionAlias),
     Function(ActionFunction),
+    /// Lambda argument of a higher-order function, its body is evaluated over the array items.
+    Lambda(ActionLambda),
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +57,14 @@ pub struct ActionAlias {
     pub arg_type: DataType,
 }
 
+#[derive(Debug, Clone)]
+pub struct ActionLambda {
+    pub name: String,
+    pub params: Vec<DataField>,
+    pub body: Expression,
+    pub return_type: DataType,
+}
+
 #[derive(Debug, Clone)]
 pub struct ActionFunction {
     pub name: String,
@@ -77,6 +89,7 @@ impl ExpressionAction {
             ExpressionAction::Constant(c) => &c.name,
             ExpressionAction::Alias(a) => &a.name,
             ExpressionAction::Function(f) => &f.name,
+            ExpressionAction::Lambda(l) => &l.name,
         }
     }
 }
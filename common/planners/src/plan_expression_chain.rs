@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::Result;
 use common_functions::scalars::FunctionFactory;
 
@@ -20,6 +21,7 @@ use crate::ActionAlias;
 use crate::ActionConstant;
 use crate::ActionFunction;
 use crate::ActionInput;
+use crate::ActionLambda;
 use crate::Expression;
 use crate::ExpressionAction;
 
@@ -142,14 +144,14 @@ impl ExpressionChain {
 
             Expression::ScalarFunction { op, args } => {
                 for expr in args.iter() {
-                    self.add_expr(expr)?;
+                    match expr.is_lambda() {
+                        true => self.add_lambda(expr, &args[1..])?,
+                        false => self.add_expr(expr)?,
+                    }
                 }
 
                 let func = FunctionFactory::get(op)?;
-                let arg_types = args
-                    .iter()
-                    .map(|action| action.to_data_type(&self.schema))
-                    .collect::<Result<Vec<_>>>()?;
+                let arg_types = Expression::to_function_arg_types(args, &self.schema)?;
 
                 let function = ActionFunction {
                     name: expr.column_name(),
@@ -216,4 +218,24 @@ impl ExpressionChain {
         }
         Ok(())
     }
+
+    /// The body of a lambda is not a part of the chain, it is evaluated over the items of
+    /// the arrays `lists` by the higher-order function.
+    fn add_lambda(&mut self, lambda: &Expression, lists: &[Expression]) -> Result<()> {
+        let list_types = lists
+            .iter()
+            .map(|list| list.to_data_type(&self.schema))
+            .collect::<Result<Vec<_>>>()?;
+        let (_, body) = lambda.to_lambda_parts()?;
+        let params = lambda.to_lambda_param_fields(&list_types)?;
+        let return_type = body.to_data_type(&DataSchemaRefExt::create(params.clone()))?;
+
+        self.actions.push(ExpressionAction::Lambda(ActionLambda {
+            name: lambda.column_name(),
+            params,
+            body: body.clone(),
+            return_type,
+        }));
+        Ok(())
+    }
 }
@@ -15,12 +15,14 @@
 use std::collections::HashMap;
 
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::Expression;
 use crate::ExpressionVisitor;
 use crate::Recursion;
+use crate::GROUPING_ID_COLUMN_NAME;
 
 /// Resolves an `Expression::Wildcard` to a collection of `Expression::Column`'s.
 pub fn expand_wildcard(expr: &Expression, schema: &DataSchemaRef) -> Vec<Expression> {
@@ -123,6 +125,11 @@ where F: Fn(&Expression) -> bool
             return Ok(Recursion::Stop(self));
         }
 
+        // the columns in a lambda body are the lambda parameters
+        if expr.is_lambda() {
+            return Ok(Recursion::Stop(self));
+        }
+
         Ok(Recursion::Continue(self))
     }
 }
@@ -172,6 +179,52 @@ pub fn rebase_expr(expr: &Expression, base_exprs: &[Expression]) -> Result<Expre
     })
 }
 
+/// Rebuilds an `expr` with `grouping(a, b)` replaced by `grouping(_grouping_id, i, j)`, where
+/// `i` and `j` are the indexes of `a` and `b` in the group by expressions. A plain GROUP BY
+/// never rolls up a key, so there `grouping` is the constant 0.
+pub fn rebase_grouping_exprs(
+    expr: &Expression,
+    group_exprs: &[Expression],
+    aliases: &HashMap<String, Expression>,
+    has_grouping_sets: bool,
+) -> Result<Expression> {
+    clone_with_replacement(expr, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("grouping") => {
+            if args.is_empty() {
+                return Err(ErrorCode::NumberArgumentsNotMatch(
+                    "Function grouping expect at least 1 argument, but got 0",
+                ));
+            }
+
+            let mut rebased_args = Vec::with_capacity(args.len() + 1);
+            rebased_args.push(Expression::Column(GROUPING_ID_COLUMN_NAME.to_string()));
+            for arg in args {
+                let arg = resolve_aliases_to_exprs(arg, aliases)?;
+                match group_exprs.iter().position(|e| e == &arg) {
+                    Some(index) => rebased_args.push(Expression::create_literal(
+                        DataValue::UInt32(Some(index as u32)),
+                    )),
+                    None => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Argument of grouping must be a GROUP BY expression, but got {:?}",
+                            arg
+                        )))
+                    }
+                }
+            }
+
+            match has_grouping_sets {
+                true => Ok(Some(Expression::ScalarFunction {
+                    op: "grouping".to_string(),
+                    args: rebased_args,
+                })),
+                false => Ok(Some(Expression::create_literal(DataValue::UInt32(Some(0))))),
+            }
+        }
+        _ => Ok(None),
+    })
+}
+
 // Rebuilds an `expr` to ColumnExpr when some expressions already processed in upstream
 // Skip Sort, Alias because we can go into the inner nest_exprs
 pub fn rebase_expr_from_input(expr: &Expression, schema: &DataSchemaRef) -> Result<Expression> {
@@ -0,0 +1,118 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::plan_expression_common::find_column_exprs;
+use crate::Expression;
+
+impl Expression {
+    /// Whether the expression is a lambda `(x, y) -> body`, which is parsed as
+    /// `lambda('x', 'y', body)`.
+    pub fn is_lambda(&self) -> bool {
+        matches!(self, Expression::ScalarFunction { op, args }
+            if op.eq_ignore_ascii_case("lambda") && args.len() >= 2)
+    }
+
+    /// The parameter names and the body of a lambda.
+    pub fn to_lambda_parts(&self) -> Result<(Vec<String>, &Expression)> {
+        match self {
+            Expression::ScalarFunction { args, .. } if self.is_lambda() => {
+                let (body, params) = args.split_last().unwrap();
+                let params = params
+                    .iter()
+                    .map(|param| match param {
+                        Expression::Literal {
+                            value: DataValue::String(Some(name)),
+                            ..
+                        } => Ok(String::from_utf8_lossy(name).to_string()),
+                        other => Err(ErrorCode::SyntaxException(format!(
+                            "Expected an identifier as the lambda parameter, but got {:?}",
+                            other
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok((params, body))
+            }
+            other => Err(ErrorCode::LogicalError(format!(
+                "Expected a lambda, but got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// The fields of the lambda parameters, the i-th parameter is bound to the items of
+    /// the i-th array argument of the higher-order function.
+    pub fn to_lambda_param_fields(&self, list_types: &[DataType]) -> Result<Vec<DataField>> {
+        let (params, body) = self.to_lambda_parts()?;
+
+        // The body is evaluated over the array items only, the outer columns are not there.
+        for column in find_column_exprs(&[body.clone()]) {
+            if let Expression::Column(name) = column {
+                if !params.contains(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The lambda can only reference its parameters, but references column {}",
+                        name
+                    )));
+                }
+            }
+        }
+
+        if params.len() != list_types.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "The lambda expects {} arrays, but got {}",
+                params.len(),
+                list_types.len()
+            )));
+        }
+
+        params
+            .iter()
+            .zip(list_types.iter())
+            .map(|(param, list_type)| match list_type {
+                DataType::List(field) => Ok(DataField::new(param, field.data_type().clone(), true)),
+                other => Err(ErrorCode::IllegalDataType(format!(
+                    "Expected an array for the lambda parameter {}, but got {:?}",
+                    param, other
+                ))),
+            })
+            .collect()
+    }
+
+    /// The argument types of a function, the type of a lambda argument is the type of its body.
+    pub fn to_function_arg_types(
+        args: &[Expression],
+        input_schema: &DataSchemaRef,
+    ) -> Result<Vec<DataType>> {
+        match args.split_first() {
+            Some((lambda, lists)) if lambda.is_lambda() => {
+                let mut arg_types = Vec::with_capacity(args.len());
+                for arg in lists {
+                    arg_types.push(arg.to_data_type(input_schema)?);
+                }
+                let (_, body) = lambda.to_lambda_parts()?;
+                let fields = lambda.to_lambda_param_fields(&arg_types)?;
+                let body_type = body.to_data_type(&DataSchemaRefExt::create(fields))?;
+                arg_types.insert(0, body_type);
+                Ok(arg_types)
+            }
+            _ => args
+                .iter()
+                .map(|arg| arg.to_data_type(input_schema))
+                .collect(),
+        }
+    }
+}
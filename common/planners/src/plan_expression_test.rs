@@ -121,3 +121,62 @@ fn test_expression_validate() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_expression_lambda() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let list_type = DataType::List(Box::new(DataField::new("item", DataType::Int64, true)));
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", list_type.clone(), false)]);
+
+    // arrayMap(x -> x + 1, a)
+    let lambda = Expression::ScalarFunction {
+        op: "lambda".to_string(),
+        args: vec![lit("x".as_bytes()), add(col("x"), lit(1i64))],
+    };
+    assert!(lambda.is_lambda());
+    let (params, body) = lambda.to_lambda_parts()?;
+    assert_eq!(params, vec!["x".to_string()]);
+    assert_eq!(body, &add(col("x"), lit(1i64)));
+
+    let expr = Expression::ScalarFunction {
+        op: "arrayMap".to_string(),
+        args: vec![lambda.clone(), col("a")],
+    };
+    assert_eq!(expr.to_data_type(&schema)?, list_type);
+
+    let chain = ExpressionChain::try_create(schema.clone(), &[expr])?;
+    let lambdas = chain
+        .actions
+        .iter()
+        .filter(|action| matches!(action, ExpressionAction::Lambda(_)))
+        .count();
+    assert_eq!(lambdas, 1);
+
+    // the lambda parameters are not the input columns
+    let expr = Expression::ScalarFunction {
+        op: "arrayMap".to_string(),
+        args: vec![lambda.clone(), col("a")],
+    };
+    assert_eq!(
+        crate::plan_expression_common::find_column_exprs(&[expr]),
+        vec![col("a")]
+    );
+
+    // a lambda can't be evaluated as a function
+    assert!(lambda.to_data_type(&schema).is_err());
+
+    // the lambda body can't reference the outer columns
+    let lambda = Expression::ScalarFunction {
+        op: "lambda".to_string(),
+        args: vec![lit("x".as_bytes()), add(col("x"), col("b"))],
+    };
+    let expr = Expression::ScalarFunction {
+        op: "arrayMap".to_string(),
+        args: vec![lambda, col("a")],
+    };
+    let error = expr.to_data_type(&schema).unwrap_err();
+    assert_eq!(error.code(), 6);
+    assert!(error.message().contains("column b"));
+    Ok(())
+}
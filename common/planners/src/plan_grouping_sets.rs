@@ -0,0 +1,216 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::Expression;
+
+/// The hidden key column which tells the grouping sets apart, the bit `i` is set when the
+/// i-th group by expression is rolled up in the grouping set of the row.
+pub const GROUPING_ID_COLUMN_NAME: &str = "_grouping_id";
+
+/// At most 32 group by expressions fit in the `UInt32` grouping id.
+pub const MAX_GROUPING_SET_KEYS: usize = 32;
+
+/// Every grouping set aggregates each input row once more, so bound the `CUBE` explosion.
+pub const MAX_GROUPING_SETS: usize = 4096;
+
+/// The name of the hidden copy of the index-th group by expression.
+pub fn grouping_key_column_name(index: usize) -> String {
+    format!("_grouping_key_{}", index)
+}
+
+/// The grouping id of a grouping set which is a list of group by expression indexes.
+pub fn grouping_set_id(grouping_set: &[usize], keys: usize) -> u32 {
+    (0..keys)
+        .filter(|index| !grouping_set.contains(index))
+        .fold(0, |id, index| id | (1 << index))
+}
+
+/// The key fields the group by transforms hash on when there are grouping sets:
+/// the grouping id first, then a hidden copy of every group by expression.
+pub fn grouping_sets_key_fields(
+    schema_before_group_by: &DataSchemaRef,
+    group_expr: &[Expression],
+) -> Result<Vec<DataField>> {
+    if group_expr.len() > MAX_GROUPING_SET_KEYS {
+        return Err(ErrorCode::BadArguments(format!(
+            "Grouping sets support at most {} group by expressions, but got {}",
+            MAX_GROUPING_SET_KEYS,
+            group_expr.len()
+        )));
+    }
+
+    let mut fields = Vec::with_capacity(group_expr.len() + 1);
+    fields.push(DataField::new(
+        GROUPING_ID_COLUMN_NAME,
+        DataType::UInt32,
+        false,
+    ));
+    for (index, expr) in group_expr.iter().enumerate() {
+        let data_type = expr.to_data_type(schema_before_group_by)?;
+        fields.push(DataField::new(
+            &grouping_key_column_name(index),
+            data_type,
+            true,
+        ));
+    }
+    Ok(fields)
+}
+
+fn is_grouping_sets_function(expr: &Expression) -> bool {
+    match expr {
+        Expression::ScalarFunction { op, .. } => ["rollup", "cube", "groupingSets", "groupingSet"]
+            .iter()
+            .any(|name| op.eq_ignore_ascii_case(name)),
+        _ => false,
+    }
+}
+
+fn grouping_key_index(expr: &Expression, group_exprs: &mut Vec<Expression>) -> Result<usize> {
+    if is_grouping_sets_function(expr) {
+        return Err(ErrorCode::SyntaxException(format!(
+            "Unsupported nested grouping sets: {:?}",
+            expr
+        )));
+    }
+
+    match group_exprs.iter().position(|e| e == expr) {
+        Some(index) => Ok(index),
+        None => {
+            group_exprs.push(expr.clone());
+            Ok(group_exprs.len() - 1)
+        }
+    }
+}
+
+/// The keys of an element of `ROLLUP`, `CUBE` or `GROUPING SETS`, which is an expression or
+/// a parenthesized list of expressions.
+fn grouping_element_keys(
+    expr: &Expression,
+    group_exprs: &mut Vec<Expression>,
+) -> Result<Vec<usize>> {
+    match expr {
+        Expression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("groupingSet") => args
+            .iter()
+            .map(|arg| grouping_key_index(arg, group_exprs))
+            .collect(),
+        _ => Ok(vec![grouping_key_index(expr, group_exprs)?]),
+    }
+}
+
+fn group_by_item_sets(
+    item: &Expression,
+    group_exprs: &mut Vec<Expression>,
+) -> Result<Vec<Vec<usize>>> {
+    let (op, args) = match item {
+        Expression::ScalarFunction { op, args } => (op.to_lowercase(), args),
+        _ => return Ok(vec![grouping_element_keys(item, group_exprs)?]),
+    };
+
+    match op.as_str() {
+        "rollup" => {
+            let elements = args
+                .iter()
+                .map(|arg| grouping_element_keys(arg, group_exprs))
+                .collect::<Result<Vec<_>>>()?;
+            Ok((0..=elements.len())
+                .rev()
+                .map(|len| elements[..len].concat())
+                .collect())
+        }
+        "cube" => {
+            let elements = args
+                .iter()
+                .map(|arg| grouping_element_keys(arg, group_exprs))
+                .collect::<Result<Vec<_>>>()?;
+            let sets = 1usize
+                .checked_shl(elements.len() as u32)
+                .unwrap_or(usize::MAX);
+            if sets > MAX_GROUPING_SETS {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Too many grouping sets, at most {} are supported",
+                    MAX_GROUPING_SETS
+                )));
+            }
+            Ok((0..sets)
+                .rev()
+                .map(|mask| {
+                    elements
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, _)| mask & (1 << (elements.len() - 1 - index)) != 0)
+                        .flat_map(|(_, keys)| keys.iter().cloned())
+                        .collect()
+                })
+                .collect())
+        }
+        "groupingsets" => {
+            let mut sets = vec![];
+            for arg in args {
+                match arg {
+                    Expression::ScalarFunction { op, .. }
+                        if op.eq_ignore_ascii_case("rollup") || op.eq_ignore_ascii_case("cube") =>
+                    {
+                        sets.extend(group_by_item_sets(arg, group_exprs)?)
+                    }
+                    _ => sets.push(grouping_element_keys(arg, group_exprs)?),
+                }
+            }
+            Ok(sets)
+        }
+        _ => Ok(vec![grouping_element_keys(item, group_exprs)?]),
+    }
+}
+
+/// Splits the GROUP BY list into the distinct group by expressions and the grouping sets
+/// over them, `ROLLUP(...)`, `CUBE(...)` and `GROUPING SETS (...)` arrive as the functions
+/// `rollup`, `cube` and `groupingSets` whose parenthesized elements are `groupingSet`.
+/// The grouping sets of the items are crossed, so `GROUP BY a, ROLLUP(b, c)` has the sets
+/// (a, b, c), (a, b) and (a). A plain GROUP BY list is returned as is with no grouping sets.
+pub fn expand_grouping_sets(group_by: &[Expression]) -> Result<(Vec<Expression>, Vec<Vec<usize>>)> {
+    if !group_by.iter().any(is_grouping_sets_function) {
+        return Ok((group_by.to_vec(), vec![]));
+    }
+
+    let mut group_exprs = vec![];
+    let mut grouping_sets: Vec<Vec<usize>> = vec![vec![]];
+    for item in group_by {
+        let item_sets = group_by_item_sets(item, &mut group_exprs)?;
+        if grouping_sets.len() * item_sets.len() > MAX_GROUPING_SETS {
+            return Err(ErrorCode::BadArguments(format!(
+                "Too many grouping sets, at most {} are supported",
+                MAX_GROUPING_SETS
+            )));
+        }
+
+        let mut crossed = Vec::with_capacity(grouping_sets.len() * item_sets.len());
+        for set in &grouping_sets {
+            for item_set in &item_sets {
+                let mut keys = set.iter().chain(item_set).cloned().collect::<Vec<_>>();
+                keys.sort_unstable();
+                keys.dedup();
+                // The same grouping set is computed once.
+                if !crossed.contains(&keys) {
+                    crossed.push(keys);
+                }
+            }
+        }
+        grouping_sets = crossed;
+    }
+    Ok((group_exprs, grouping_sets))
+}
@@ -0,0 +1,114 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::*;
+
+fn function(op: &str, args: Vec<Expression>) -> Expression {
+    Expression::ScalarFunction {
+        op: op.to_string(),
+        args,
+    }
+}
+
+#[test]
+fn test_expand_grouping_sets() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        group_by: Vec<Expression>,
+        expect_exprs: Vec<Expression>,
+        expect_sets: Vec<Vec<usize>>,
+    }
+
+    let (a, b, c) = (col("a"), col("b"), col("c"));
+    let tests = vec![
+        Test {
+            name: "plain",
+            group_by: vec![a.clone(), b.clone(), a.clone()],
+            expect_exprs: vec![a.clone(), b.clone(), a.clone()],
+            expect_sets: vec![],
+        },
+        Test {
+            name: "rollup",
+            group_by: vec![function("rollup", vec![a.clone(), b.clone()])],
+            expect_exprs: vec![a.clone(), b.clone()],
+            expect_sets: vec![vec![0, 1], vec![0], vec![]],
+        },
+        Test {
+            name: "cube",
+            group_by: vec![function("CUBE", vec![a.clone(), b.clone()])],
+            expect_exprs: vec![a.clone(), b.clone()],
+            expect_sets: vec![vec![0, 1], vec![0], vec![1], vec![]],
+        },
+        Test {
+            name: "grouping-sets",
+            group_by: vec![function("groupingSets", vec![
+                function("groupingSet", vec![a.clone(), b.clone()]),
+                c.clone(),
+                function("groupingSet", vec![]),
+            ])],
+            expect_exprs: vec![a.clone(), b.clone(), c.clone()],
+            expect_sets: vec![vec![0, 1], vec![2], vec![]],
+        },
+        Test {
+            name: "rollup-composite",
+            group_by: vec![function("rollup", vec![
+                function("groupingSet", vec![a.clone(), b.clone()]),
+                c.clone(),
+            ])],
+            expect_exprs: vec![a.clone(), b.clone(), c.clone()],
+            expect_sets: vec![vec![0, 1, 2], vec![0, 1], vec![]],
+        },
+        Test {
+            name: "mixed",
+            group_by: vec![a.clone(), function("rollup", vec![b.clone(), a.clone()])],
+            expect_exprs: vec![a.clone(), b.clone()],
+            expect_sets: vec![vec![0, 1], vec![0]],
+        },
+        Test {
+            name: "crossed",
+            group_by: vec![
+                function("rollup", vec![a.clone()]),
+                function("rollup", vec![b.clone()]),
+            ],
+            expect_exprs: vec![a.clone(), b.clone()],
+            expect_sets: vec![vec![0, 1], vec![0], vec![1], vec![]],
+        },
+    ];
+
+    for t in tests {
+        let (exprs, sets) = expand_grouping_sets(&t.group_by)?;
+        assert_eq!(exprs, t.expect_exprs, "{}", t.name);
+        assert_eq!(sets, t.expect_sets, "{}", t.name);
+    }
+
+    // Nested grouping sets functions.
+    let group_by = vec![function("rollup", vec![function("cube", vec![a.clone()])])];
+    let actual = expand_grouping_sets(&group_by);
+    assert_eq!(actual.unwrap_err().code(), 5);
+
+    // The grouping sets of a large cube are bounded.
+    let keys = (0..13).map(|i| col(&format!("c{}", i))).collect::<Vec<_>>();
+    let actual = expand_grouping_sets(&[function("cube", keys)]);
+    assert_eq!(
+        actual.unwrap_err().message(),
+        "Too many grouping sets, at most 4096 are supported"
+    );
+
+    assert_eq!(grouping_set_id(&[0, 1], 2), 0);
+    assert_eq!(grouping_set_id(&[1], 2), 1);
+    assert_eq!(grouping_set_id(&[], 3), 7);
+    Ok(())
+}
@@ -22,15 +22,28 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterUserPlan;
+use crate::CopyIntoPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateQuotaPlan;
+use crate::CreateResourceGroupPlan;
+use crate::CreateRolePlan;
 use crate::CreateTablePlan;
+use crate::CreateUserPlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropQuotaPlan;
+use crate::DropResourceGroupPlan;
+use crate::DropRolePlan;
 use crate::DropTablePlan;
+use crate::DropUserPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
+use crate::GrantPrivilegePlan;
+use crate::GrantResourceGroupPlan;
+use crate::GrantRolePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
 use crate::KillPlan;
@@ -39,6 +52,8 @@ use crate::LimitPlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
 use crate::ScanPlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -76,9 +91,24 @@ pub enum PlanNode {
     UseDatabase(UseDatabasePlan),
     SetVariable(SettingPlan),
     InsertInto(InsertIntoPlan),
+    CopyInto(CopyIntoPlan),
     ShowCreateTable(ShowCreateTablePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Kill(KillPlan),
+    CreateUser(CreateUserPlan),
+    AlterUser(AlterUserPlan),
+    DropUser(DropUserPlan),
+    CreateRole(CreateRolePlan),
+    DropRole(DropRolePlan),
+    GrantPrivilege(GrantPrivilegePlan),
+    RevokePrivilege(RevokePrivilegePlan),
+    GrantRole(GrantRolePlan),
+    RevokeRole(RevokeRolePlan),
+    CreateQuota(CreateQuotaPlan),
+    DropQuota(DropQuotaPlan),
+    CreateResourceGroup(CreateResourceGroupPlan),
+    DropResourceGroup(DropResourceGroupPlan),
+    GrantResourceGroup(GrantResourceGroupPlan),
 }
 
 impl PlanNode {
@@ -111,9 +141,24 @@ impl PlanNode {
             PlanNode::Sort(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
             PlanNode::InsertInto(v) => v.schema(),
+            PlanNode::CopyInto(v) => v.schema(),
             PlanNode::ShowCreateTable(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
             PlanNode::Kill(v) => v.schema(),
+            PlanNode::CreateUser(v) => v.schema(),
+            PlanNode::AlterUser(v) => v.schema(),
+            PlanNode::DropUser(v) => v.schema(),
+            PlanNode::CreateRole(v) => v.schema(),
+            PlanNode::DropRole(v) => v.schema(),
+            PlanNode::GrantPrivilege(v) => v.schema(),
+            PlanNode::RevokePrivilege(v) => v.schema(),
+            PlanNode::GrantRole(v) => v.schema(),
+            PlanNode::RevokeRole(v) => v.schema(),
+            PlanNode::CreateQuota(v) => v.schema(),
+            PlanNode::DropQuota(v) => v.schema(),
+            PlanNode::CreateResourceGroup(v) => v.schema(),
+            PlanNode::DropResourceGroup(v) => v.schema(),
+            PlanNode::GrantResourceGroup(v) => v.schema(),
         }
     }
 
@@ -145,9 +190,24 @@ impl PlanNode {
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
             PlanNode::InsertInto(_) => "InsertIntoPlan",
+            PlanNode::CopyInto(_) => "CopyIntoPlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
             PlanNode::Kill(_) => "KillQuery",
+            PlanNode::CreateUser(_) => "CreateUserPlan",
+            PlanNode::AlterUser(_) => "AlterUserPlan",
+            PlanNode::DropUser(_) => "DropUserPlan",
+            PlanNode::CreateRole(_) => "CreateRolePlan",
+            PlanNode::DropRole(_) => "DropRolePlan",
+            PlanNode::GrantPrivilege(_) => "GrantPrivilegePlan",
+            PlanNode::RevokePrivilege(_) => "RevokePrivilegePlan",
+            PlanNode::GrantRole(_) => "GrantRolePlan",
+            PlanNode::RevokeRole(_) => "RevokeRolePlan",
+            PlanNode::CreateQuota(_) => "CreateQuotaPlan",
+            PlanNode::DropQuota(_) => "DropQuotaPlan",
+            PlanNode::CreateResourceGroup(_) => "CreateResourceGroupPlan",
+            PlanNode::DropResourceGroup(_) => "DropResourceGroupPlan",
+            PlanNode::GrantResourceGroup(_) => "GrantResourceGroupPlan",
         }
     }
 
@@ -165,6 +225,7 @@ impl PlanNode {
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
+            PlanNode::CopyInto(v) => vec![v.input.clone()],
             PlanNode::SubQueryExpression(v) => v.get_inputs(),
 
             _ => vec![],
@@ -193,6 +254,7 @@ impl PlanNode {
             PlanNode::Explain(v) => v.set_input(inputs[0]),
             PlanNode::Select(v) => v.set_input(inputs[0]),
             PlanNode::Sort(v) => v.set_input(inputs[0]),
+            PlanNode::CopyInto(v) => v.set_input(inputs[0]),
             PlanNode::SubQueryExpression(v) => v.set_inputs(inputs),
             _ => {
                 return Err(ErrorCode::UnImplement(format!(
@@ -0,0 +1,50 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_management::GrantObject;
+use common_management::UserPrivilegeSet;
+
+/// The user or the role which the privileges are granted to.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum PrincipalIdentity {
+    User(String),
+    Role(String),
+}
+
+impl fmt::Display for PrincipalIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PrincipalIdentity::User(name) => write!(f, "USER '{}'", name),
+            PrincipalIdentity::Role(name) => write!(f, "ROLE '{}'", name),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct GrantPrivilegePlan {
+    pub principal: PrincipalIdentity,
+    pub on: GrantObject,
+    pub privileges: UserPrivilegeSet,
+}
+
+impl GrantPrivilegePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -25,17 +25,30 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterUserPlan;
+use crate::CopyIntoPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateQuotaPlan;
+use crate::CreateResourceGroupPlan;
+use crate::CreateRolePlan;
 use crate::CreateTablePlan;
+use crate::CreateUserPlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropQuotaPlan;
+use crate::DropResourceGroupPlan;
+use crate::DropRolePlan;
 use crate::DropTablePlan;
+use crate::DropUserPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::Expressions;
 use crate::FilterPlan;
+use crate::GrantPrivilegePlan;
+use crate::GrantResourceGroupPlan;
+use crate::GrantRolePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
 use crate::KillPlan;
@@ -46,6 +59,8 @@ use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
 use crate::ScanPlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -102,10 +117,25 @@ pub trait PlanRewriter {
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
             PlanNode::DropDatabase(plan) => self.rewrite_drop_database(plan),
             PlanNode::InsertInto(plan) => self.rewrite_insert_into(plan),
+            PlanNode::CopyInto(plan) => self.rewrite_copy_into(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
+            PlanNode::CreateUser(plan) => self.rewrite_create_user(plan),
+            PlanNode::AlterUser(plan) => self.rewrite_alter_user(plan),
+            PlanNode::DropUser(plan) => self.rewrite_drop_user(plan),
+            PlanNode::CreateRole(plan) => self.rewrite_create_role(plan),
+            PlanNode::DropRole(plan) => self.rewrite_drop_role(plan),
+            PlanNode::GrantPrivilege(plan) => self.rewrite_grant_privilege(plan),
+            PlanNode::RevokePrivilege(plan) => self.rewrite_revoke_privilege(plan),
+            PlanNode::GrantRole(plan) => self.rewrite_grant_role(plan),
+            PlanNode::RevokeRole(plan) => self.rewrite_revoke_role(plan),
+            PlanNode::CreateQuota(plan) => self.rewrite_create_quota(plan),
+            PlanNode::DropQuota(plan) => self.rewrite_drop_quota(plan),
+            PlanNode::CreateResourceGroup(plan) => self.rewrite_create_resource_group(plan),
+            PlanNode::DropResourceGroup(plan) => self.rewrite_drop_resource_group(plan),
+            PlanNode::GrantResourceGroup(plan) => self.rewrite_grant_resource_group(plan),
         }
     }
 
@@ -340,6 +370,16 @@ pub trait PlanRewriter {
         Ok(PlanNode::InsertInto(plan.clone()))
     }
 
+    fn rewrite_copy_into(&mut self, plan: &CopyIntoPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CopyInto(CopyIntoPlan {
+            location: plan.location.clone(),
+            file_format: plan.file_format.clone(),
+            format_options: plan.format_options.clone(),
+            max_file_size: plan.max_file_size,
+            input: Arc::new(self.rewrite_plan_node(plan.input.as_ref())?),
+        }))
+    }
+
     fn rewrite_show_create_table(&mut self, plan: &ShowCreateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::ShowCreateTable(plan.clone()))
     }
@@ -351,6 +391,65 @@ pub trait PlanRewriter {
     fn rewrite_kill(&mut self, plan: &KillPlan) -> Result<PlanNode> {
         Ok(PlanNode::Kill(plan.clone()))
     }
+
+    fn rewrite_create_user(&mut self, plan: &CreateUserPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateUser(plan.clone()))
+    }
+
+    fn rewrite_alter_user(&mut self, plan: &AlterUserPlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterUser(plan.clone()))
+    }
+
+    fn rewrite_drop_user(&mut self, plan: &DropUserPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropUser(plan.clone()))
+    }
+
+    fn rewrite_create_role(&mut self, plan: &CreateRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateRole(plan.clone()))
+    }
+
+    fn rewrite_drop_role(&mut self, plan: &DropRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropRole(plan.clone()))
+    }
+
+    fn rewrite_grant_privilege(&mut self, plan: &GrantPrivilegePlan) -> Result<PlanNode> {
+        Ok(PlanNode::GrantPrivilege(plan.clone()))
+    }
+
+    fn rewrite_revoke_privilege(&mut self, plan: &RevokePrivilegePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RevokePrivilege(plan.clone()))
+    }
+
+    fn rewrite_grant_role(&mut self, plan: &GrantRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::GrantRole(plan.clone()))
+    }
+
+    fn rewrite_revoke_role(&mut self, plan: &RevokeRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RevokeRole(plan.clone()))
+    }
+
+    fn rewrite_create_quota(&mut self, plan: &CreateQuotaPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateQuota(plan.clone()))
+    }
+
+    fn rewrite_drop_quota(&mut self, plan: &DropQuotaPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropQuota(plan.clone()))
+    }
+
+    fn rewrite_create_resource_group(
+        &mut self,
+        plan: &CreateResourceGroupPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateResourceGroup(plan.clone()))
+    }
+
+    fn rewrite_drop_resource_group(&mut self, plan: &DropResourceGroupPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropResourceGroup(plan.clone()))
+    }
+
+    fn rewrite_grant_resource_group(&mut self, plan: &GrantResourceGroupPlan) -> Result<PlanNode> {
+        Ok(PlanNode::GrantResourceGroup(plan.clone()))
+    }
 }
 
 pub struct RewriteHelper {}
@@ -17,14 +17,45 @@ use std::sync::Arc;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct VarValue {
     pub variable: String,
     pub value: String,
+    /// Set to DEFAULT, the value is ignored.
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl VarValue {
+    pub fn create(variable: &str, value: &str) -> VarValue {
+        VarValue {
+            variable: variable.to_string(),
+            value: value.to_string(),
+            is_default: false,
+        }
+    }
+
+    pub fn create_default(variable: &str) -> VarValue {
+        VarValue {
+            variable: variable.to_string(),
+            value: String::new(),
+            is_default: true,
+        }
+    }
+}
+
+/// Where the values are set: the current session (SET), the new sessions of the tenant
+/// (SET GLOBAL), or the new sessions of a user (ALTER USER ... SETTINGS).
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum SettingScope {
+    Session,
+    Global,
+    User(String),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct SettingPlan {
+    pub scope: SettingScope,
     pub vars: Vec<VarValue>,
 }
 
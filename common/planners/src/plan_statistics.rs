@@ -20,6 +20,8 @@ pub struct Statistics {
     pub read_bytes: usize,
     /// Is the statistics exact.
     pub is_exact: bool,
+    /// Total partitions of the table before pruning, 0 if the table does not prune.
+    pub partitions_total: usize,
 }
 
 impl Statistics {
@@ -28,6 +30,7 @@ impl Statistics {
             read_rows,
             read_bytes,
             is_exact: false,
+            partitions_total: 0,
         }
     }
 
@@ -36,6 +39,7 @@ impl Statistics {
             read_rows,
             read_bytes,
             is_exact: true,
+            partitions_total: 0,
         }
     }
 
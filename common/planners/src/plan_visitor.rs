@@ -18,16 +18,29 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterUserPlan;
+use crate::CopyIntoPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateQuotaPlan;
+use crate::CreateResourceGroupPlan;
+use crate::CreateRolePlan;
 use crate::CreateTablePlan;
+use crate::CreateUserPlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropQuotaPlan;
+use crate::DropResourceGroupPlan;
+use crate::DropRolePlan;
 use crate::DropTablePlan;
+use crate::DropUserPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
+use crate::GrantPrivilegePlan;
+use crate::GrantResourceGroupPlan;
+use crate::GrantRolePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
 use crate::KillPlan;
@@ -37,6 +50,8 @@ use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
 use crate::ScanPlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -116,9 +131,24 @@ pub trait PlanVisitor {
             PlanNode::Having(plan) => self.visit_having(plan),
             PlanNode::Expression(plan) => self.visit_expression(plan),
             PlanNode::InsertInto(plan) => self.visit_insert_into(plan),
+            PlanNode::CopyInto(plan) => self.visit_copy_into(plan),
             PlanNode::ShowCreateTable(plan) => self.visit_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Kill(plan) => self.visit_kill_query(plan),
+            PlanNode::CreateUser(plan) => self.visit_create_user(plan),
+            PlanNode::AlterUser(plan) => self.visit_alter_user(plan),
+            PlanNode::DropUser(plan) => self.visit_drop_user(plan),
+            PlanNode::CreateRole(plan) => self.visit_create_role(plan),
+            PlanNode::DropRole(plan) => self.visit_drop_role(plan),
+            PlanNode::GrantPrivilege(plan) => self.visit_grant_privilege(plan),
+            PlanNode::RevokePrivilege(plan) => self.visit_revoke_privilege(plan),
+            PlanNode::GrantRole(plan) => self.visit_grant_role(plan),
+            PlanNode::RevokeRole(plan) => self.visit_revoke_role(plan),
+            PlanNode::CreateQuota(plan) => self.visit_create_quota(plan),
+            PlanNode::DropQuota(plan) => self.visit_drop_quota(plan),
+            PlanNode::CreateResourceGroup(plan) => self.visit_create_resource_group(plan),
+            PlanNode::DropResourceGroup(plan) => self.visit_drop_resource_group(plan),
+            PlanNode::GrantResourceGroup(plan) => self.visit_grant_resource_group(plan),
         }
     }
 
@@ -262,6 +292,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_copy_into(&mut self, plan: &CopyIntoPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())
+    }
+
     fn visit_show_create_table(&mut self, _: &ShowCreateTablePlan) -> Result<()> {
         Ok(())
     }
@@ -273,4 +307,60 @@ pub trait PlanVisitor {
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
+
+    fn visit_create_user(&mut self, _: &CreateUserPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_user(&mut self, _: &AlterUserPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_user(&mut self, _: &DropUserPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_role(&mut self, _: &CreateRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_role(&mut self, _: &DropRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_grant_privilege(&mut self, _: &GrantPrivilegePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_revoke_privilege(&mut self, _: &RevokePrivilegePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_grant_role(&mut self, _: &GrantRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_revoke_role(&mut self, _: &RevokeRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_quota(&mut self, _: &CreateQuotaPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_quota(&mut self, _: &DropQuotaPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_resource_group(&mut self, _: &CreateResourceGroupPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_resource_group(&mut self, _: &DropResourceGroupPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_grant_resource_group(&mut self, _: &GrantResourceGroupPlan) -> Result<()> {
+        Ok(())
+    }
 }
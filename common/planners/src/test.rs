@@ -41,6 +41,7 @@ impl Test {
             read_rows: total,
             read_bytes: total * 8,
             is_exact: true,
+            partitions_total: 0,
         };
 
         Ok(PlanNode::ReadSource(ReadDataSourcePlan {
@@ -425,6 +425,14 @@ impl StateMachine {
                 }
             }
 
+            Cmd::RemoveNode { ref node_id } => {
+                let sm_nodes = self.nodes();
+
+                let prev = sm_nodes.remove(node_id, true).await?;
+                tracing::info!("applied RemoveNode: {}={:?}", node_id, prev);
+                Ok((prev, None).into())
+            }
+
             Cmd::CreateDatabase {
                 ref name, ref db, ..
             } => {
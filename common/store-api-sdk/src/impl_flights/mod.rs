@@ -15,6 +15,7 @@
 
 pub mod kv_api_impl;
 pub mod meta_api_impl;
+pub mod replication_impl;
 pub mod storage_api_impl;
 pub mod storage_api_impl_utils;
 #[cfg(test)]
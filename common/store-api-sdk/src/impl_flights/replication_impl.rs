@@ -0,0 +1,87 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transfers copies of files between dfs servers, which is only used internally.
+
+use common_arrow::arrow_flight::FlightData;
+use common_arrow::arrow_flight::Ticket;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use futures::StreamExt;
+use tonic::Request;
+
+use crate::action_declare;
+use crate::impl_flights::storage_api_impl_utils;
+use crate::store_do_get::PullAction;
+use crate::RequestFor;
+use crate::StoreClient;
+use crate::StoreDoAction;
+use crate::StoreDoGet;
+
+impl StoreClient {
+    /// Stores a copy of file `key` on the connected dfs server.
+    #[tracing::instrument(level = "debug", skip(self, data))]
+    pub async fn push_file(&self, key: &str, data: Vec<u8>) -> Result<()> {
+        let flight_data = FlightData {
+            data_body: data,
+            ..Default::default()
+        };
+        let req = Request::new(futures::stream::iter(vec![flight_data]));
+        let mut req = common_tracing::inject_span_to_tonic_request(req);
+        storage_api_impl_utils::put_replica_meta(req.metadata_mut(), key);
+        req.set_timeout(self.timeout);
+
+        let res = self.client.clone().do_put(req).await?;
+        match res.into_inner().message().await? {
+            Some(_) => Ok(()),
+            None => Err(ErrorCode::UnknownException("Push file result is empty")),
+        }
+    }
+
+    /// Reads the copy of file `key` stored on the connected dfs server.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn pull_file(&self, key: &str) -> Result<Vec<u8>> {
+        let cmd = StoreDoGet::Pull(PullAction {
+            key: key.to_string(),
+        });
+        let req = Request::<Ticket>::from(&cmd);
+        let mut req = common_tracing::inject_span_to_tonic_request(req);
+        req.set_timeout(self.timeout);
+
+        let mut stream = self.client.clone().do_get(req).await?.into_inner();
+        let mut data = vec![];
+        while let Some(flight_data) = stream.next().await {
+            data.extend_from_slice(&flight_data?.data_body);
+        }
+        Ok(data)
+    }
+
+    /// Removes the copy of file `key` stored on the connected dfs server,
+    /// which is used to roll back the copies of a write that did not reach a quorum.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn remove_replica(&self, key: &str) -> Result<()> {
+        self.do_action(RemoveReplicaAction {
+            key: key.to_string(),
+        })
+        .await
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct RemoveReplicaAction {
+    pub key: String,
+}
+
+action_declare!(RemoveReplicaAction, (), StoreDoAction::RemoveReplica);
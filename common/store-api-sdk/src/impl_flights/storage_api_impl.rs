@@ -23,6 +23,7 @@ use common_arrow::arrow::record_batch::RecordBatch;
 use common_arrow::arrow_flight::utils::flight_data_from_arrow_batch;
 use common_arrow::arrow_flight::utils::flight_data_from_arrow_schema;
 use common_arrow::arrow_flight::utils::flight_data_to_arrow_batch;
+use common_arrow::arrow_flight::FlightData;
 use common_arrow::arrow_flight::Ticket;
 use common_base::tokio;
 use common_datablocks::DataBlock;
@@ -31,28 +32,38 @@ pub use common_dfs_api::StorageApi;
 pub use common_dfs_api_vo::AppendResult;
 pub use common_dfs_api_vo::BlockStream;
 pub use common_dfs_api_vo::DataPartInfo;
+pub use common_dfs_api_vo::FileStream;
 pub use common_dfs_api_vo::ReadAction;
 use common_exception::ErrorCode;
 use common_planners::PlanNode;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
 use futures::SinkExt;
 use futures::StreamExt;
 use tonic::Request;
 
+use crate::action_declare;
 use crate::impl_flights::storage_api_impl_utils;
+pub use crate::impl_flights::storage_api_impl_utils::get_file_meta;
 pub use crate::impl_flights::storage_api_impl_utils::get_meta;
+pub use crate::impl_flights::storage_api_impl_utils::get_replica_meta;
+pub use crate::store_do_get::ReadFileAction;
+use crate::RequestFor;
 use crate::StoreClient;
+use crate::StoreDoAction;
 use crate::StoreDoGet;
 
 #[async_trait::async_trait]
 impl StorageApi for StoreClient {
+    #[tracing::instrument(level = "debug", skip(self, schema, read_action))]
     async fn read_partition(
         &self,
         schema: DataSchemaRef,
         read_action: &ReadAction,
     ) -> common_exception::Result<SendableDataBlockStream> {
         let cmd = StoreDoGet::Read(read_action.clone());
-        let mut req = tonic::Request::<Ticket>::from(&cmd);
+        let req = tonic::Request::<Ticket>::from(&cmd);
+        let mut req = common_tracing::inject_span_to_tonic_request(req);
         req.set_timeout(self.timeout);
         let res = self.client.clone().do_get(req).await?.into_inner();
         let mut arrow_schema: ArrowSchemaRef = Arc::new(schema.to_arrow());
@@ -74,6 +85,7 @@ impl StorageApi for StoreClient {
         Ok(Box::pin(res_stream))
     }
 
+    #[tracing::instrument(level = "debug", skip(self, scheme_ref, block_stream))]
     async fn append_data(
         &self,
         db_name: String,
@@ -114,7 +126,7 @@ impl StorageApi for StoreClient {
             }
         });
 
-        let mut req = Request::new(flight_stream);
+        let mut req = common_tracing::inject_span_to_tonic_request(Request::new(flight_stream));
         let meta = req.metadata_mut();
         storage_api_impl_utils::put_meta(meta, &db_name, &tbl_name);
 
@@ -125,4 +137,60 @@ impl StorageApi for StoreClient {
             None => Err(ErrorCode::UnknownException("Put result is empty")),
         }
     }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn read_file(
+        &self,
+        key: &str,
+        offset: u64,
+        len: Option<u64>,
+    ) -> common_exception::Result<FileStream> {
+        let cmd = StoreDoGet::ReadFile(ReadFileAction {
+            key: key.to_string(),
+            offset,
+            len,
+        });
+        let req = tonic::Request::<Ticket>::from(&cmd);
+        let mut req = common_tracing::inject_span_to_tonic_request(req);
+        req.set_timeout(self.timeout);
+        let res = self.client.clone().do_get(req).await?.into_inner();
+
+        let res_stream = res.map(|item| {
+            item.map(|flight_data| flight_data.data_body)
+                .map_err(|status| ErrorCode::TokioError(status.to_string()))
+        });
+        Ok(Box::pin(res_stream))
+    }
+
+    async fn file_size(&self, key: &str) -> common_exception::Result<u64> {
+        self.do_action(FileSizeAction {
+            key: key.to_string(),
+        })
+        .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, data))]
+    async fn add_file(&self, key: &str, data: Vec<u8>) -> common_exception::Result<()> {
+        let flight_data = FlightData {
+            data_body: data,
+            ..Default::default()
+        };
+        let req = Request::new(futures::stream::iter(vec![flight_data]));
+        let mut req = common_tracing::inject_span_to_tonic_request(req);
+        storage_api_impl_utils::put_file_meta(req.metadata_mut(), key);
+        req.set_timeout(self.timeout);
+
+        let res = self.client.clone().do_put(req).await?;
+        match res.into_inner().message().await? {
+            Some(_) => Ok(()),
+            None => Err(ErrorCode::UnknownException("Add file result is empty")),
+        }
+    }
 }
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct FileSizeAction {
+    pub key: String,
+}
+
+action_declare!(FileSizeAction, u64, StoreDoAction::FileSize);
@@ -21,6 +21,8 @@ use tonic::metadata::MetadataValue;
 
 pub const META_KEY_DB_NAME: &str = "fq-db-name-bin";
 pub const META_KEY_TBL_NAME: &str = "fq-tbl-name-bin";
+pub const META_KEY_REPLICA_KEY: &str = "fq-replica-key-bin";
+pub const META_KEY_FILE_KEY: &str = "fq-file-key-bin";
 
 pub fn put_meta(meta: &mut MetadataMap, db_name: &str, tbl_name: &str) {
     meta.insert_bin(
@@ -55,3 +57,44 @@ pub fn get_meta(meta: &MetadataMap) -> Result<(String, String)> {
     let tbl_name = fetch_string(meta, META_KEY_TBL_NAME, "invalid tbl_name meta data")?;
     Ok((db_name, tbl_name))
 }
+
+/// Marks a do_put request as pushing a copy of file `key` to a dfs node.
+pub fn put_replica_meta(meta: &mut MetadataMap, key: &str) {
+    meta.insert_bin(
+        META_KEY_REPLICA_KEY,
+        MetadataValue::from_bytes(key.as_bytes()),
+    );
+}
+
+/// Returns the file key if the do_put request is pushing a copy of a file.
+pub fn get_replica_meta(meta: &MetadataMap) -> Result<Option<String>> {
+    match meta.get_bin(META_KEY_REPLICA_KEY) {
+        None => Ok(None),
+        Some(value) => match value.to_bytes() {
+            Ok(bytes) => Ok(Some(String::from_utf8(bytes.to_vec())?)),
+            Err(error) => Err(ErrorCode::InvalidMetaBinaryFormat(format!(
+                "invalid replica key meta data, cause {}",
+                error
+            ))),
+        },
+    }
+}
+
+/// Marks a do_put request as adding file `key` to dfs.
+pub fn put_file_meta(meta: &mut MetadataMap, key: &str) {
+    meta.insert_bin(META_KEY_FILE_KEY, MetadataValue::from_bytes(key.as_bytes()));
+}
+
+/// Returns the file key if the do_put request is adding a file.
+pub fn get_file_meta(meta: &MetadataMap) -> Result<Option<String>> {
+    match meta.get_bin(META_KEY_FILE_KEY) {
+        None => Ok(None),
+        Some(value) => match value.to_bytes() {
+            Ok(bytes) => Ok(Some(String::from_utf8(bytes.to_vec())?)),
+            Err(error) => Err(ErrorCode::InvalidMetaBinaryFormat(format!(
+                "invalid file key meta data, cause {}",
+                error
+            ))),
+        },
+    }
+}
@@ -17,8 +17,12 @@
 mod test {
     use tonic::metadata::MetadataMap;
 
+    use crate::impl_flights::storage_api_impl_utils::get_file_meta;
     use crate::impl_flights::storage_api_impl_utils::get_meta;
+    use crate::impl_flights::storage_api_impl_utils::get_replica_meta;
+    use crate::impl_flights::storage_api_impl_utils::put_file_meta;
     use crate::impl_flights::storage_api_impl_utils::put_meta;
+    use crate::impl_flights::storage_api_impl_utils::put_replica_meta;
 
     #[test]
     fn test_get_set_meta() {
@@ -30,4 +34,29 @@ mod test {
         assert_eq!(test_db, db);
         assert_eq!(test_tbl, tbl);
     }
+
+    #[test]
+    fn test_get_set_replica_meta() {
+        let mut meta = MetadataMap::new();
+        assert_eq!(None, get_replica_meta(&meta).unwrap());
+
+        put_replica_meta(&mut meta, "db/tbl/part");
+        assert_eq!(
+            Some("db/tbl/part".to_string()),
+            get_replica_meta(&meta).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_set_file_meta() {
+        let mut meta = MetadataMap::new();
+        assert_eq!(None, get_file_meta(&meta).unwrap());
+
+        put_file_meta(&mut meta, "db/tbl/_b/part");
+        assert_eq!(
+            Some("db/tbl/_b/part".to_string()),
+            get_file_meta(&meta).unwrap()
+        );
+        assert_eq!(None, get_replica_meta(&meta).unwrap());
+    }
 }
@@ -19,6 +19,7 @@ pub use flight_token::FlightClaim;
 pub use flight_token::FlightToken;
 pub use impl_flights::kv_api_impl;
 pub use impl_flights::meta_api_impl;
+pub use impl_flights::replication_impl;
 pub use impl_flights::storage_api_impl;
 pub use store_client::StoreClient;
 pub use store_client_conf::ClientConf;
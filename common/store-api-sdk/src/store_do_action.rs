@@ -34,6 +34,8 @@ use crate::impl_flights::meta_api_impl::GetDatabasesAction;
 use crate::impl_flights::meta_api_impl::GetTableAction;
 use crate::impl_flights::meta_api_impl::GetTableExtReq;
 use crate::impl_flights::meta_api_impl::GetTablesAction;
+use crate::impl_flights::replication_impl::RemoveReplicaAction;
+use crate::impl_flights::storage_api_impl::FileSizeAction;
 use crate::protobuf::FlightStoreRequest;
 
 pub trait RequestFor {
@@ -75,6 +77,10 @@ pub enum StoreDoAction {
     GetKV(GetKVAction),
     MGetKV(MGetKVAction),
     PrefixListKV(PrefixListReq),
+
+    // dfs file
+    FileSize(FileSizeAction),
+    RemoveReplica(RemoveReplicaAction),
 }
 
 /// Try convert tonic::Request<Action> to DoActionAction.
@@ -30,11 +30,21 @@ pub struct PullAction {
     pub key: String,
 }
 
+/// Read a range of a file, the content is streamed back in chunks.
+/// If `len` is None, it reads till the end of the file.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ReadFileAction {
+    pub key: String,
+    pub offset: u64,
+    pub len: Option<u64>,
+}
+
 // Action wrapper for do_get.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub enum StoreDoGet {
     Read(ReadAction),
     Pull(PullAction),
+    ReadFile(ReadFileAction),
 }
 
 /// Try convert tonic::Request<Ticket> to StoreDoGet.
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod stream_cancellable_test;
+
 #[cfg(test)]
 mod stream_datablock_test;
 
@@ -26,7 +29,7 @@ mod stream_limit_by_test;
 
 mod sources;
 mod stream;
-mod stream_abort;
+mod stream_cancellable;
 mod stream_correct_with_schema;
 mod stream_datablock;
 mod stream_limit_by;
@@ -40,7 +43,7 @@ mod stream_take;
 
 pub use sources::*;
 pub use stream::SendableDataBlockStream;
-pub use stream_abort::AbortStream;
+pub use stream_cancellable::CancellableStream;
 pub use stream_correct_with_schema::CorrectWithSchemaStream;
 pub use stream_datablock::DataBlockStream;
 pub use stream_limit_by::LimitByStream;
@@ -15,8 +15,8 @@
 use std::task::Context;
 use std::task::Poll;
 
+use common_base::CancellationToken;
 use common_datablocks::DataBlock;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::stream::AbortHandle;
 use futures::stream::Abortable;
@@ -26,22 +26,29 @@ use pin_project_lite::pin_project;
 use crate::SendableDataBlockStream;
 
 pin_project! {
-    pub struct AbortStream {
+    /// Stops the input with the cancelled reason once the token is cancelled,
+    /// it checks the token between the blocks and wakes up when waiting for the input.
+    pub struct CancellableStream {
         #[pin]
-        input: Abortable<SendableDataBlockStream>
+        input: Abortable<SendableDataBlockStream>,
+        token: CancellationToken,
+        finished: bool,
     }
 }
 
-impl AbortStream {
-    pub fn try_create(input: SendableDataBlockStream) -> Result<(AbortHandle, Self)> {
+impl CancellableStream {
+    pub fn try_create(input: SendableDataBlockStream, token: CancellationToken) -> Result<Self> {
         let (handle, reg) = AbortHandle::new_pair();
-        Ok((handle, Self {
+        token.register_abort_handle(handle);
+        Ok(Self {
             input: Abortable::new(input, reg),
-        }))
+            token,
+            finished: false,
+        })
     }
 }
 
-impl Stream for AbortStream {
+impl Stream for CancellableStream {
     type Item = Result<DataBlock>;
 
     fn poll_next(
@@ -49,15 +56,24 @@ impl Stream for AbortStream {
         ctx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
         let this = self.project();
-        let is_aborted = this.input.is_aborted();
+
+        if *this.finished {
+            return Poll::Ready(None);
+        }
+
+        if let Err(cause) = this.token.check() {
+            *this.finished = true;
+            return Poll::Ready(Some(Err(cause)));
+        }
 
         match this.input.poll_next(ctx) {
-            Poll::Ready(None) => match is_aborted {
-                false => Poll::Ready(None),
-                true => Poll::Ready(Some(Err(ErrorCode::AbortedQuery(
-                    "Aborted query, because the server is shutting down or the query was killed",
-                )))),
-            },
+            Poll::Ready(None) => {
+                *this.finished = true;
+                match this.token.check() {
+                    Ok(_) => Poll::Ready(None),
+                    Err(cause) => Poll::Ready(Some(Err(cause))),
+                }
+            }
             other => other,
         }
     }
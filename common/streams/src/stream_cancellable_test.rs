@@ -0,0 +1,72 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_base::CancellationToken;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use futures::stream::StreamExt;
+
+use crate::*;
+
+#[tokio::test]
+async fn test_cancellable_stream() {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i32, 2, 3])]);
+    let blocks = vec![block.clone(), block.clone(), block];
+
+    // Not cancelled, all the blocks are read.
+    {
+        let token = CancellationToken::create();
+        let input = DataBlockStream::create(schema.clone(), None, blocks.clone());
+        let stream = CancellableStream::try_create(Box::pin(input), token).unwrap();
+        let result = stream.collect::<Vec<_>>().await;
+        assert_eq!(result.len(), 3);
+        assert!(result.iter().all(|block| block.is_ok()));
+    }
+
+    // Cancelled between the blocks, the stream stops with the reason.
+    {
+        let token = CancellationToken::create();
+        let input = DataBlockStream::create(schema.clone(), None, blocks.clone());
+        let mut stream = CancellableStream::try_create(Box::pin(input), token.clone()).unwrap();
+
+        assert!(stream.next().await.unwrap().is_ok());
+        token.cancel(ErrorCode::Timeout("Query exceeded max_execution_time"));
+
+        match stream.next().await {
+            Some(Err(cause)) => assert_eq!(cause.code(), ErrorCode::Timeout("").code()),
+            _ => panic!("the stream must be cancelled"),
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    // Cancelled while waiting for the input.
+    {
+        let token = CancellationToken::create();
+        let input = futures::stream::pending::<common_exception::Result<DataBlock>>();
+        let mut stream = CancellableStream::try_create(Box::pin(input), token.clone()).unwrap();
+
+        let cancel_token = token.clone();
+        tokio::spawn(async move {
+            cancel_token.cancel(ErrorCode::AbortedQuery("killed"));
+        });
+
+        match stream.next().await {
+            Some(Err(cause)) => assert_eq!(cause.code(), ErrorCode::AbortedQuery("").code()),
+            _ => panic!("the stream must be cancelled"),
+        }
+    }
+}
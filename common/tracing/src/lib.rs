@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod tracing_to_jaeger_test;
+
 mod logging;
 mod panic_hook;
 mod tracing_to_jaeger;
@@ -72,7 +72,8 @@ lazy_static! {
 /// To adjust batch sending delay, use `OTEL_BSP_SCHEDULE_DELAY`:
 ///   DATABEND_JAEGER=on RUST_LOG=trace OTEL_BSP_SCHEDULE_DELAY=1 cargo test
 ///
-// TODO(xp): use DATABEND_JAEGER to assign jaeger server address.
+/// To report to a jaeger agent other than `127.0.0.1:6831`, use `DATABEND_JAEGER_AGENT_ENDPOINT`:
+///   DATABEND_JAEGER=on DATABEND_JAEGER_AGENT_ENDPOINT=jaeger:6831 databend-query
 fn init_tracing_stdout() {
     let fmt_layer = Layer::default()
         .with_thread_ids(true)
@@ -84,7 +85,7 @@ fn init_tracing_stdout() {
     let subscriber = Registry::default()
         .with(EnvFilter::from_default_env())
         .with(fmt_layer)
-        .with(jaeger_layer());
+        .with(jaeger_layer("databend"));
 
     tracing::subscriber::set_global_default(subscriber)
         .expect("error setting global tracing subscriber");
@@ -92,14 +93,20 @@ fn init_tracing_stdout() {
 
 fn jaeger_layer<
     S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
->() -> Option<impl tracing_subscriber::layer::Layer<S>> {
+>(
+    service_name: &str,
+) -> Option<impl tracing_subscriber::layer::Layer<S>> {
     let fuse_jaeger = env::var("DATABEND_JAEGER").unwrap_or_else(|_| "".to_string());
 
     if !fuse_jaeger.is_empty() {
         global::set_text_map_propagator(TraceContextPropagator::new());
 
-        let tracer = opentelemetry_jaeger::new_pipeline()
-            .with_service_name("databend-dfs")
+        let mut pipeline = opentelemetry_jaeger::new_pipeline().with_service_name(service_name);
+        if let Ok(endpoint) = env::var("DATABEND_JAEGER_AGENT_ENDPOINT") {
+            pipeline = pipeline.with_agent_endpoint(endpoint);
+        }
+
+        let tracer = pipeline
             .install_batch(opentelemetry::runtime::Tokio)
             .expect("install");
 
@@ -128,7 +135,7 @@ pub fn init_tracing_with_file(app_name: &str, dir: &str, level: &str) -> Vec<Wor
         .with(stdout_logging_layer)
         .with(JsonStorageLayer)
         .with(file_logging_layer)
-        .with(jaeger_layer());
+        .with(jaeger_layer(app_name));
 
     tracing::subscriber::set_global_default(subscriber)
         .expect("error setting global tracing subscriber");
@@ -194,7 +201,7 @@ pub fn init_file_subscriber(app_name: &str, dir: &str) -> (WorkerGuard, impl Sub
     let subscriber = Registry::default()
         .with(EnvFilter::from_default_env())
         .with(f_layer)
-        .with(jaeger_layer());
+        .with(jaeger_layer(app_name));
 
     (writer_guard, subscriber)
 }
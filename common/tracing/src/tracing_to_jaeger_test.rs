@@ -0,0 +1,86 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use opentelemetry::global;
+use opentelemetry::sdk::export::trace::ExportResult;
+use opentelemetry::sdk::export::trace::SpanData;
+use opentelemetry::sdk::export::trace::SpanExporter;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace::TracerProvider;
+use opentelemetry::trace::TracerProvider as _;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::Registry;
+
+use crate::extract_remote_span_as_parent;
+use crate::inject_span_to_tonic_request;
+
+/// An in-process collector keeping every exported span.
+#[derive(Clone, Debug, Default)]
+struct MemoryExporter {
+    spans: Arc<Mutex<Vec<SpanData>>>,
+}
+
+#[async_trait::async_trait]
+impl SpanExporter for MemoryExporter {
+    async fn export(&mut self, batch: Vec<SpanData>) -> ExportResult {
+        self.spans.lock().unwrap().extend(batch);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_span_propagation_through_tonic_request() {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let exporter = MemoryExporter::default();
+    let provider = TracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let tracer = provider.get_tracer("databend-test", None);
+    let subscriber = Registry::default().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+    tracing::subscriber::with_default(subscriber, || {
+        let client = tracing::info_span!("client");
+        let request = client.in_scope(|| inject_span_to_tonic_request(tonic::Request::new(())));
+        assert!(request.metadata().get("traceparent").is_some());
+
+        // The server span is not a child of the client span in this process,
+        // it is only chained by the context carried in the request meta.
+        let server = tracing::info_span!(parent: None, "server");
+        server.in_scope(|| extract_remote_span_as_parent(&request));
+    });
+
+    // Shutting down the provider waits for the exporter to receive all the ended spans.
+    drop(provider);
+
+    let spans = exporter.spans.lock().unwrap();
+    let find = |name: &str| {
+        spans
+            .iter()
+            .find(|span| span.name == name)
+            .cloned()
+            .unwrap_or_else(|| panic!("span {} is not exported", name))
+    };
+
+    let client = find("client");
+    let server = find("server");
+    assert_eq!(
+        server.span_context.trace_id(),
+        client.span_context.trace_id()
+    );
+    assert_eq!(server.parent_span_id, client.span_context.span_id());
+}
@@ -57,19 +57,22 @@ use crate::configs::Config;
 use crate::executor::ActionHandler;
 use crate::executor::ReplySerializer;
 use crate::fs::FileSystem;
+use crate::localfs::check_path;
 
 pub type FlightStream<T> =
     Pin<Box<dyn Stream<Item = Result<T, tonic::Status>> + Send + Sync + 'static>>;
 
 /// StoreFlightImpl provides data access API-s for DatabendQuery, in arrow-flight protocol.
 pub struct StoreFlightImpl {
+    conf: Config,
     token: FlightToken,
     action_handler: ActionHandler,
 }
 
 impl StoreFlightImpl {
-    pub fn create(_conf: Config, fs: Arc<dyn FileSystem>, meta_node: Arc<MetaNode>) -> Self {
+    pub fn create(conf: Config, fs: Arc<dyn FileSystem>, meta_node: Arc<MetaNode>) -> Self {
         Self {
+            conf,
             token: FlightToken::create(),
             // TODO pass in action handler
             action_handler: ActionHandler::create(fs, meta_node),
@@ -108,10 +111,13 @@ impl FlightService for StoreFlightImpl {
         let auth = BasicAuth::decode(&*payload).map_err(|e| Status::internal(e.to_string()))?;
 
         // Check auth and create token.
-        let user = "root";
-        if auth.username == user {
+        // The other dfs nodes authenticate as the configured replication user.
+        let is_replication = !self.conf.replication_username.is_empty()
+            && auth.username == self.conf.replication_username
+            && auth.password == self.conf.replication_password;
+        if auth.username == "root" || is_replication {
             let claim = FlightClaim {
-                username: user.to_string(),
+                username: auth.username.clone(),
             };
             let token = self
                 .token
@@ -156,6 +162,7 @@ impl FlightService for StoreFlightImpl {
 
     type DoGetStream =
         Pin<Box<dyn Stream<Item = Result<FlightData, tonic::Status>> + Send + Sync + 'static>>;
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_get(
         &self,
         request: Request<Ticket>,
@@ -163,6 +170,8 @@ impl FlightService for StoreFlightImpl {
         // Check token.
         let _claim = self.check_token(request.metadata())?;
 
+        common_tracing::extract_remote_span_as_parent(&request);
+
         // Action.
         let action: StoreDoGet = request.try_into()?;
         match action {
@@ -183,6 +192,18 @@ impl FlightService for StoreFlightImpl {
 
                 self.action_handler.do_pull_file(key, tx).await?;
 
+                Ok(Response::new(
+                    Box::pin(ReceiverStream::new(rx)) as Self::DoGetStream
+                ))
+            }
+            StoreDoGet::ReadFile(act) => {
+                let (tx, rx): (
+                    Sender<Result<FlightData, tonic::Status>>,
+                    Receiver<Result<FlightData, tonic::Status>>,
+                ) = tokio::sync::mpsc::channel(2);
+
+                self.action_handler.do_read_file(act, tx);
+
                 Ok(Response::new(
                     Box::pin(ReceiverStream::new(rx)) as Self::DoGetStream
                 ))
@@ -191,13 +212,44 @@ impl FlightService for StoreFlightImpl {
     }
 
     type DoPutStream = FlightStream<PutResult>;
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_put(
         &self,
         request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoPutStream>, Status> {
         let _claim = self.check_token(request.metadata())?;
+
+        common_tracing::extract_remote_span_as_parent(&request);
         let meta = request.metadata();
 
+        let replica_key = storage_api_impl::get_replica_meta(meta)
+            .map_err(|e| Status::internal(e.to_string()))?;
+        if let Some(key) = replica_key {
+            check_path(&key).map_err(|e| Status::invalid_argument(e.to_string()))?;
+            self.action_handler
+                .do_push_file(key, request.into_inner())
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+
+            return Ok(Response::new(Box::pin(futures::stream::once(async {
+                Ok(PutResult::default())
+            }))));
+        }
+
+        let file_key =
+            storage_api_impl::get_file_meta(meta).map_err(|e| Status::internal(e.to_string()))?;
+        if let Some(key) = file_key {
+            check_path(&key).map_err(|e| Status::invalid_argument(e.to_string()))?;
+            self.action_handler
+                .do_add_file(key, request.into_inner())
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+
+            return Ok(Response::new(Box::pin(futures::stream::once(async {
+                Ok(PutResult::default())
+            }))));
+        }
+
         let (db_name, tbl_name) =
             storage_api_impl::get_meta(meta).map_err(|e| Status::internal(e.to_string()))?;
 
@@ -233,13 +285,23 @@ impl FlightService for StoreFlightImpl {
         request: Request<Action>,
     ) -> Result<Response<Self::DoActionStream>, Status> {
         // Check token.
-        let _claim = self.check_token(request.metadata())?;
+        let claim = self.check_token(request.metadata())?;
 
         common_tracing::extract_remote_span_as_parent(&request);
 
         let action: StoreDoAction = request.try_into()?;
         info!("Receive do_action: {:?}", action);
 
+        // Removing a copy is a replication action between dfs nodes.
+        if let StoreDoAction::RemoveReplica(_) = &action {
+            if claim.username != self.conf.replication_username {
+                return Err(Status::permission_denied(format!(
+                    "user {} is not allowed to remove replicas",
+                    claim.username
+                )));
+            }
+        }
+
         let s = JsonSer;
         let body = self.action_handler.execute(action, s).await?;
         let arrow = arrow_flight::Result { body };
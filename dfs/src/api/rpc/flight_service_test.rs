@@ -527,6 +527,89 @@ async fn test_do_append() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_illegal_file_key() -> anyhow::Result<()> {
+    // - The file keys escaping the local fs dir are rejected.
+
+    let (_log_guards, ut_span) = init_store_ut!();
+    let _ent = ut_span.enter();
+
+    let (tc, addr) = crate::tests::start_store_server().await?;
+    let client = StoreClient::try_create(addr.as_str(), "root", "xxx").await?;
+    let replication_client = StoreClient::try_create(
+        addr.as_str(),
+        &tc.config.replication_username,
+        &tc.config.replication_password,
+    )
+    .await?;
+
+    for key in ["../escaped", "a/../../escaped", "/tmp/escaped"] {
+        let res = client.push_file(key, b"123".to_vec()).await;
+        assert!(
+            res.unwrap_err().to_string().contains("illegal file key"),
+            "push {}",
+            key
+        );
+
+        let res = client.add_file(key, b"123".to_vec()).await;
+        assert!(
+            res.unwrap_err().to_string().contains("illegal file key"),
+            "add {}",
+            key
+        );
+
+        let res = replication_client.remove_replica(key).await;
+        assert!(
+            res.unwrap_err().to_string().contains("illegal file key"),
+            "remove {}",
+            key
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_remove_replica_permission() -> anyhow::Result<()> {
+    // - Only the replication user is allowed to remove a copy.
+    // - A committed file can not be removed.
+
+    let (_log_guards, ut_span) = init_store_ut!();
+    let _ent = ut_span.enter();
+
+    let (tc, addr) = crate::tests::start_store_server().await?;
+    let client = StoreClient::try_create(addr.as_str(), "root", "xxx").await?;
+    let replication_client = StoreClient::try_create(
+        addr.as_str(),
+        &tc.config.replication_username,
+        &tc.config.replication_password,
+    )
+    .await?;
+
+    client.push_file("pushed", b"123".to_vec()).await?;
+    client.add_file("added", b"123".to_vec()).await?;
+
+    let res = client.remove_replica("pushed").await;
+    assert!(
+        res.unwrap_err()
+            .to_string()
+            .contains("not allowed to remove replicas"),
+        "root can not remove replicas"
+    );
+
+    let res = replication_client.remove_replica("added").await;
+    assert!(
+        res.unwrap_err()
+            .to_string()
+            .contains("can not remove the copy of the committed file"),
+        "committed file can not be removed"
+    );
+
+    replication_client.remove_replica("pushed").await?;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_flight_generic_kv_mget() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_store_ut!();
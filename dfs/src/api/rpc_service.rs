@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_arrow::arrow_flight::flight_service_server::FlightServiceServer;
 use common_base::tokio;
@@ -31,6 +32,7 @@ use transport::ServerTlsConfig;
 
 use crate::api::rpc::StoreFlightImpl;
 use crate::configs::Config;
+use crate::dfs::register_flight_address;
 use crate::dfs::Dfs;
 use crate::localfs::LocalFS;
 
@@ -111,9 +113,15 @@ impl StoreServer {
         };
         tracing::info!("Done starting MetaNode: {:?}", self.conf);
 
-        let dfs = Dfs::create(fs, mn.clone());
+        register_flight_address(&mn, &self.conf.flight_api_address).await?;
 
-        let flight_impl = StoreFlightImpl::create(self.conf.clone(), Arc::new(dfs), mn.clone());
+        let dfs = Arc::new(Dfs::create(fs, mn.clone(), &self.conf)?);
+        Dfs::start_repairer(
+            dfs.clone(),
+            Duration::from_secs(self.conf.replica_repair_interval_secs),
+        );
+
+        let flight_impl = StoreFlightImpl::create(self.conf.clone(), dfs, mn.clone());
         let flight_srv = FlightServiceServer::new(flight_impl);
 
         let builder = Server::builder();
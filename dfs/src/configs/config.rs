@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
 use common_raft_store::config::RaftConfig;
 use lazy_static::lazy_static;
 use structopt::StructOpt;
@@ -92,6 +93,30 @@ pub struct Config {
         default_value = "./_local_fs"
     )]
     pub local_fs_dir: String,
+
+    #[structopt(
+        long,
+        env = "STORE_REPLICA_REPAIR_INTERVAL_SECS",
+        help = "Interval in seconds to check and repair under-replicated files",
+        default_value = "60"
+    )]
+    pub replica_repair_interval_secs: u64,
+
+    #[structopt(
+        long,
+        env = "STORE_REPLICATION_USERNAME",
+        help = "User to authenticate as when transferring copies to other dfs nodes, it must be configured",
+        default_value = ""
+    )]
+    pub replication_username: String,
+
+    #[structopt(
+        long,
+        env = "STORE_REPLICATION_PASSWORD",
+        help = "Password of the replication user, it must be configured",
+        default_value = ""
+    )]
+    pub replication_password: String,
 }
 
 impl Config {
@@ -108,6 +133,21 @@ impl Config {
         self.meta_config.check()
     }
 
+    /// The replication user is a dedicated identity of the dfs nodes, it can not be `root`.
+    pub fn check_replication(&self) -> common_exception::Result<()> {
+        if self.replication_username.is_empty() || self.replication_password.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "replication_username and replication_password must be configured",
+            ));
+        }
+        if self.replication_username == "root" {
+            return Err(ErrorCode::InvalidConfig(
+                "replication_username can not be root",
+            ));
+        }
+        Ok(())
+    }
+
     pub fn tls_rpc_server_enabled(&self) -> bool {
         !self.rpc_tls_server_key.is_empty() && !self.rpc_tls_server_cert.is_empty()
     }
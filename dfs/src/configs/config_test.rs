@@ -31,3 +31,18 @@ fn test_tls_rpc_enabled() -> anyhow::Result<()> {
     assert_eq!(true, conf.tls_rpc_server_enabled());
     Ok(())
 }
+
+#[test]
+fn test_check_replication() -> anyhow::Result<()> {
+    let mut conf = Config::empty();
+    assert!(conf.check_replication().is_err());
+    conf.replication_username = "root".to_owned();
+    conf.replication_password = "test".to_owned();
+    assert!(conf.check_replication().is_err());
+    conf.replication_username = "replication".to_owned();
+    conf.replication_password = "".to_owned();
+    assert!(conf.check_replication().is_err());
+    conf.replication_password = "test".to_owned();
+    assert!(conf.check_replication().is_ok());
+    Ok(())
+}
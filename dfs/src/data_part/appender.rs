@@ -15,16 +15,14 @@
 
 use std::convert::TryFrom;
 use std::io::Cursor;
-use std::iter::repeat;
 use std::sync::Arc;
 
 use anyhow::Result;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
-use common_arrow::arrow::io::parquet::write::*;
-use common_arrow::arrow::record_batch::RecordBatch;
 use common_arrow::arrow_flight::utils::flight_data_to_arrow_batch;
 use common_arrow::arrow_flight::FlightData;
 use common_datablocks::DataBlock;
+use common_datablocks::ParquetWriteOptions;
 use common_store_api_sdk::storage_api_impl::AppendResult;
 use futures::StreamExt;
 use uuid::Uuid;
@@ -45,7 +43,12 @@ impl Appender {
     /// Assumes
     /// - upstream caller has properly batched data
     /// - first element of the incoming stream is a properly serialized schema
-    pub async fn append_data(&self, path: String, mut stream: InputData) -> Result<AppendResult> {
+    pub async fn append_data(
+        &self,
+        path: String,
+        mut stream: InputData,
+        options: &ParquetWriteOptions,
+    ) -> Result<AppendResult> {
         if let Some(flight_data) = stream.next().await {
             let arrow_schema = ArrowSchema::try_from(&flight_data)?;
             let arrow_schema_ref = Arc::new(arrow_schema);
@@ -59,7 +62,7 @@ impl Appender {
                     (block.num_rows(), block.num_columns(), block.memory_size());
                 let part_uuid = Uuid::new_v4().to_simple().to_string() + ".parquet";
                 let location = format!("{}/{}", path, part_uuid);
-                let buffer = write_in_memory(block)?;
+                let buffer = write_in_memory(block, options)?;
 
                 result.append_part(&location, rows, cols, wire_bytes, buffer.len());
 
@@ -72,35 +75,9 @@ impl Appender {
     }
 }
 
-pub(crate) fn write_in_memory(block: DataBlock) -> Result<Vec<u8>> {
-    let arrow_schema = block.schema().to_arrow();
-    let options = WriteOptions {
-        write_statistics: true,
-        compression: Compression::Uncompressed,
-        version: Version::V2,
-    };
-    let encodings: Vec<_> = repeat(Encoding::Plain).take(block.num_columns()).collect();
-    let memory_size = block.memory_size();
-    let batch = RecordBatch::try_from(block)?;
-
-    let iter = vec![Ok(batch)];
-
-    let row_groups =
-        RowGroupIterator::try_new(iter.into_iter(), &arrow_schema, options, encodings)?;
-
-    // Create a new empty file
-    let writer = Vec::with_capacity(memory_size);
-    let mut cursor = Cursor::new(writer);
+pub(crate) fn write_in_memory(block: DataBlock, options: &ParquetWriteOptions) -> Result<Vec<u8>> {
+    let mut cursor = Cursor::new(Vec::with_capacity(block.memory_size()));
     // Write the file. Note that, at present, any error results in a corrupted file.
-    let parquet_schema = row_groups.parquet_schema().clone();
-    write_file(
-        &mut cursor,
-        row_groups,
-        &arrow_schema,
-        parquet_schema,
-        options,
-        None,
-    )?;
-
+    DataBlock::write_parquet(block, &mut cursor, options)?;
     Ok(cursor.into_inner())
 }
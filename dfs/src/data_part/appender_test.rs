@@ -15,6 +15,7 @@
 
 #[cfg(test)]
 mod test {
+    use std::convert::TryFrom;
     use std::io::Cursor;
     use std::sync::Arc;
 
@@ -28,6 +29,8 @@ mod test {
     use common_arrow::arrow_flight::utils::flight_data_from_arrow_schema;
     use common_base::tokio;
     use common_datablocks::DataBlock;
+    use common_datablocks::ParquetCompression;
+    use common_datablocks::ParquetWriteOptions;
     use common_datavalues::prelude::*;
 
     use crate::data_part::appender::*;
@@ -44,7 +47,7 @@ mod test {
         let col1 = Series::new(vec!["str1", "str2", "str3"]);
         let block = DataBlock::create_by_array(schema.clone(), vec![col0.clone(), col1.clone()]);
 
-        let buffer = write_in_memory(block)?;
+        let buffer = write_in_memory(block, &ParquetWriteOptions::default())?;
         let cursor = Cursor::new(buffer);
         let mut reader = read::RecordReader::try_new(cursor, None, None, None, None)?;
         let arrow_schema = schema.to_arrow();
@@ -60,6 +63,41 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_in_memory_write_with_options() -> anyhow::Result<()> {
+        let schema = Arc::new(DataSchema::new(vec![
+            DataField::new("col_i", DataType::Int64, false),
+            DataField::new("col_s", DataType::String, false),
+        ]));
+
+        let col0 = Series::new(vec![0_i64, 1, 2, 3]);
+        let col1 = Series::new(vec!["str1", "str2", "str1", "str1"]);
+        let block = DataBlock::create_by_array(schema.clone(), vec![col0, col1]);
+
+        let options = ParquetWriteOptions {
+            compression: ParquetCompression::Zstd,
+            dictionary_encoding: true,
+            row_group_size: 3,
+        };
+        let buffer = write_in_memory(block.clone(), &options)?;
+        let mut cursor = Cursor::new(buffer);
+        let metadata = read::read_metadata(&mut cursor)?;
+        assert_eq!(metadata.row_groups.len(), 2);
+
+        let reader = read::RecordReader::try_new(cursor, None, None, None, None)?;
+        let mut blocks = vec![];
+        for batch in reader {
+            blocks.push(DataBlock::try_from(batch?)?);
+        }
+        let expected = DataBlock::split_block_by_size(&block, 3)?;
+        assert_eq!(blocks.len(), expected.len());
+        for (actual, expected) in blocks.iter().zip(expected.iter()) {
+            assert_eq!(actual.schema(), expected.schema());
+            assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+        }
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
     async fn test_append() -> anyhow::Result<()> {
         let col0: ArrayRef = Arc::new(Int64Array::from_values(vec![0, 1, 2]));
@@ -83,7 +121,11 @@ mod test {
             flight_data_from_arrow_batch(&batch, &default_ipc_write_opt).1, // ignore dict
         ]);
         let r = appender
-            .append_data("test_tbl".to_string(), Box::pin(req))
+            .append_data(
+                "test_tbl".to_string(),
+                Box::pin(req),
+                &ParquetWriteOptions::default(),
+            )
             .await;
         assert!(r.is_ok());
         Ok(())
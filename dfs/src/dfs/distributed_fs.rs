@@ -13,52 +13,326 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
+use common_base::tokio;
+use common_base::tokio::task::JoinHandle;
 use common_exception::exception;
 use common_exception::ErrorCode;
 use common_metatypes::Cmd;
 use common_metatypes::LogEntry;
+use common_metatypes::MatchSeq;
+use common_metatypes::NodeId;
+use common_metatypes::Operation;
+use common_raft_store::state_machine::Placement;
 use common_tracing::tracing;
 use kvsrv::meta_service::MetaNode;
 
+use crate::configs::Config;
+use crate::dfs::FlightReplicator;
+use crate::dfs::Replicator;
 use crate::fs::FileSystem;
 use crate::fs::ListResult;
 use crate::localfs::LocalFS;
 
 /// DFS is a distributed file system impl.
-/// When a file is added, it stores it locally, commit the this action into distributed meta data(something like a raft group).
+/// When a file is added, it is written synchronously to the nodes the file is placed on,
+/// by the slot placement in the meta state machine.
+/// Once a quorum of the copies are written, it commits this action into distributed meta data(something like a raft group).
 /// Then notifies client Ok.
-/// The replication is done by 2 other nodes, by subscribing meta data changes, and pulling the file.
-/// TODO: There is a chance the node receiving the upload fails before replication is done, which results in a data loss.
-///       A synchronous quorum write is required to solve this.
+///
+/// A copy that failed to be written, e.g., a node is down, is recorded as pending in meta kv
+/// and repaired in background: every node periodically pulls its pending files from the surviving replicas.
+/// A node that starts with an empty local fs, e.g. it is replaced, checks every file once.
 pub struct Dfs {
     /// The local fs to store data copies.
     /// The distributed fs is a cluster of local-fs organized with a meta data service.
     pub local_fs: LocalFS,
     pub meta_node: Arc<MetaNode>,
+    /// Transfers data copies between dfs nodes.
+    pub replicator: Arc<dyn Replicator>,
+}
+
+/// The key prefix in meta kv of the copies that were not written when their files were added,
+/// i.e. `<prefix>/<node_id>/<file key>`.
+const DFS_PENDING_REPLICA_PREFIX: &str = "__fd_dfs_pending_replica";
+
+fn pending_replica_prefix(node_id: NodeId) -> String {
+    format!("{}/{}/", DFS_PENDING_REPLICA_PREFIX, node_id)
 }
 
 impl Dfs {
-    pub fn create(local_fs: LocalFS, meta_node: Arc<MetaNode>) -> Dfs {
+    /// The replication credentials must be configured, other nodes only accept
+    /// the replication actions, e.g. removing a copy, from this identity.
+    pub fn create(
+        local_fs: LocalFS,
+        meta_node: Arc<MetaNode>,
+        conf: &Config,
+    ) -> exception::Result<Dfs> {
+        conf.check_replication()?;
+
+        let replicator = Arc::new(FlightReplicator::create(
+            meta_node.clone(),
+            &conf.replication_username,
+            &conf.replication_password,
+        ));
+        Ok(Dfs::with_replicator(local_fs, meta_node, replicator))
+    }
+
+    pub fn with_replicator(
+        local_fs: LocalFS,
+        meta_node: Arc<MetaNode>,
+        replicator: Arc<dyn Replicator>,
+    ) -> Dfs {
         Dfs {
             local_fs,
             meta_node,
+            replicator,
         }
     }
 }
 
-impl Dfs {}
+impl Dfs {
+    fn node_id(&self) -> NodeId {
+        self.meta_node.sto.id
+    }
+
+    /// Returns ids of the nodes that should store a copy of the file.
+    /// If slots are not assigned yet, the file is only stored on this node.
+    pub async fn replicas_of(&self, key: &str) -> Vec<NodeId> {
+        let sm = self.meta_node.sto.state_machine.read().await;
+        let node_ids = if sm.get_slots().is_empty() {
+            vec![]
+        } else {
+            sm.get_slot(sm.slot_index_for_key(key)).node_ids.clone()
+        };
+
+        if node_ids.is_empty() {
+            vec![self.node_id()]
+        } else {
+            node_ids
+        }
+    }
+
+    async fn write_copy(&self, node_id: NodeId, key: &str, data: &[u8]) -> exception::Result<()> {
+        if node_id == self.node_id() {
+            self.local_fs.add(key, data).await
+        } else {
+            self.replicator.push(node_id, key, data).await
+        }
+    }
+
+    async fn remove_copy(&self, node_id: NodeId, key: &str) -> exception::Result<()> {
+        if node_id == self.node_id() {
+            self.local_fs.remove_replica(key).await
+        } else {
+            self.replicator.remove(node_id, key).await
+        }
+    }
+
+    /// Removes the copies written by an `add` that did not reach a quorum,
+    /// unless the file is committed, e.g. by an earlier `add` of the same key.
+    /// A copy failed to be removed is left as an orphan and only logged.
+    async fn rollback_copies(&self, key: &str, node_ids: &[NodeId]) -> exception::Result<()> {
+        if self.meta_node.get_file(key).await?.is_some() {
+            return Ok(());
+        }
+
+        let removes = node_ids
+            .iter()
+            .map(|node_id| self.remove_copy(*node_id, key));
+        let results = futures::future::join_all(removes).await;
+        for (node_id, res) in node_ids.iter().zip(results) {
+            if let Err(e) = res {
+                tracing::warn!("dfs: orphan copy of {} on node {}: {}", key, node_id, e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a copy of the file from the first replica(except this node) that responds.
+    /// If `range` is `Some((offset, len))`, only the bytes in the range are read.
+    async fn pull_copy(
+        &self,
+        key: &str,
+        replicas: &[NodeId],
+        range: Option<(u64, u64)>,
+    ) -> exception::Result<Vec<u8>> {
+        let mut last_err = None;
+        for node_id in replicas.iter().filter(|x| **x != self.node_id()) {
+            let res = match range {
+                None => self.replicator.pull(*node_id, key).await,
+                Some((offset, len)) => self.replicator.pull_range(*node_id, key, offset, len).await,
+            };
+            match res {
+                Ok(data) => return Ok(data),
+                Err(e) => {
+                    tracing::warn!("dfs: fail to pull {} from node {}: {}", key, node_id, e);
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| {
+            ErrorCode::FileDamaged(format!("dfs: no replica to read: {:?}", key))
+        }))
+    }
+
+    /// Returns the file meta, or error if the file is not found in meta.
+    async fn file_meta(&self, key: &str) -> exception::Result<String> {
+        // TODO(xp): week consistency, meta may not have been replicated to this node.
+        self.meta_node.get_file(key).await?.ok_or_else(|| {
+            ErrorCode::FileMetaNotFound(format!("dfs/meta: key not found: {:?}", key))
+        })
+    }
+
+    /// Records that the copy of `key` on `node_id` is missing, or is repaired if `pending` is false.
+    pub(crate) async fn set_pending(
+        &self,
+        node_id: NodeId,
+        key: &str,
+        pending: bool,
+    ) -> exception::Result<()> {
+        let value = match pending {
+            true => Operation::Update(vec![]),
+            false => Operation::Delete,
+        };
+        let req = LogEntry {
+            txid: None,
+            cmd: Cmd::UpsertKV {
+                key: format!("{}{}", pending_replica_prefix(node_id), key),
+                seq: MatchSeq::Any,
+                value,
+                value_meta: None,
+            },
+        };
+        let _resp = self.meta_node.write(req).await?;
+        Ok(())
+    }
+
+    /// Copies the pending files of this node from other replicas.
+    /// Returns the number of files repaired.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn repair(&self) -> exception::Result<usize> {
+        let prefix = pending_replica_prefix(self.node_id());
+        let pending = self.meta_node.prefix_list_kv(&prefix).await?;
+
+        let mut repaired = 0;
+        for (pending_key, _) in pending.iter() {
+            let key = &pending_key[prefix.len()..];
+            if self.repair_file(key).await? {
+                repaired += 1;
+            }
+        }
+        Ok(repaired)
+    }
+
+    /// Copies every file that this node should store but is absent in local fs, from other replicas.
+    /// It scans all the files in meta, thus it is only run when this node starts with an empty local fs.
+    /// Returns the number of files repaired.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn repair_all(&self) -> exception::Result<usize> {
+        let keys = self.meta_node.list_files("").await?;
+
+        let mut repaired = 0;
+        for key in keys.iter() {
+            if self.repair_file(key).await? {
+                repaired += 1;
+            }
+        }
+        Ok(repaired)
+    }
+
+    /// Pulls the file if this node should store it but does not have it.
+    /// The pending record is cleared unless pulling fails.
+    /// Returns true if the file is pulled.
+    async fn repair_file(&self, key: &str) -> exception::Result<bool> {
+        let replicas = self.replicas_of(key).await;
+        let committed = self.meta_node.get_file(key).await?.is_some();
+        if !committed || !replicas.contains(&self.node_id()) || self.local_fs.exists(key) {
+            self.set_pending(self.node_id(), key, false).await?;
+            return Ok(false);
+        }
+
+        match self.pull_copy(key, &replicas, None).await {
+            Ok(data) => {
+                self.add_replica(key, &data).await?;
+                self.set_pending(self.node_id(), key, false).await?;
+                tracing::info!("dfs: repaired under-replicated file: {}", key);
+                Ok(true)
+            }
+            Err(e) => {
+                tracing::warn!("dfs: fail to repair {}: {}", key, e);
+                Ok(false)
+            }
+        }
+    }
+
+    /// Spawns a task to run `repair()` every `interval`, until the meta node is stopped.
+    pub fn start_repairer(dfs: Arc<Dfs>, interval: Duration) -> JoinHandle<()> {
+        let mut running_rx = dfs.meta_node.running_rx.clone();
+
+        tokio::spawn(async move {
+            if dfs.local_fs.is_empty() {
+                if let Err(e) = dfs.repair_all().await {
+                    tracing::warn!("dfs: fail to repair all files: {}", e);
+                }
+            }
+
+            loop {
+                tokio::select! {
+                    _ = running_rx.changed() => {
+                        tracing::info!("dfs: repairer is stopped");
+                        return;
+                    }
+                    _ = tokio::time::sleep(interval) => {
+                        if let Err(e) = dfs.repair().await {
+                            tracing::warn!("dfs: fail to repair: {}", e);
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
 
 #[async_trait]
 impl FileSystem for Dfs {
     #[tracing::instrument(level = "debug", skip(self, data))]
     async fn add(&self, path: &str, data: &[u8]) -> common_exception::Result<()> {
-        // add the file to local fs
+        // write copies to all replicas, and wait for a quorum of them to finish.
+
+        let replicas = self.replicas_of(path).await;
+        let quorum = replicas.len() / 2 + 1;
+
+        let writes = replicas
+            .iter()
+            .map(|node_id| self.write_copy(*node_id, path, data));
+        let results = futures::future::join_all(writes).await;
+
+        let mut written = vec![];
+        for (node_id, res) in replicas.iter().zip(results) {
+            match res {
+                Ok(_) => written.push(*node_id),
+                Err(e) => {
+                    tracing::warn!("dfs: fail to write {} to node {}: {}", path, node_id, e)
+                }
+            }
+        }
 
-        self.local_fs.add(path, data).await?;
+        if written.len() < quorum {
+            self.rollback_copies(path, &written).await?;
+            return Err(ErrorCode::ReplicationQuorumNotReached(format!(
+                "dfs: {} of {} copies of {:?} are written, quorum is {}",
+                written.len(),
+                replicas.len(),
+                path,
+                quorum
+            )));
+        }
 
-        // update meta, other dfs nodes will be informed about this change and then pull the data to complete replication.
+        // update meta, the missing copies will be repaired by the replicas themselves.
 
         let req = LogEntry {
             txid: None,
@@ -68,20 +342,86 @@ impl FileSystem for Dfs {
             },
         };
         let _resp = self.meta_node.write(req).await?;
+
+        for node_id in replicas.iter().filter(|x| !written.contains(x)) {
+            if let Err(e) = self.set_pending(*node_id, path, true).await {
+                tracing::warn!(
+                    "dfs: fail to record the missing copy of {} on node {}: {}",
+                    path,
+                    node_id,
+                    e
+                );
+            }
+        }
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip(self, data))]
+    async fn add_replica(&self, path: &str, data: &[u8]) -> common_exception::Result<()> {
+        if self.local_fs.exists(path) {
+            return Ok(());
+        }
+        self.local_fs.add(path, data).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
-    async fn read_all(&self, key: &str) -> exception::Result<Vec<u8>> {
-        // TODO read from remote if file is not in local fs
-        // TODO(xp): week consistency, meta may not have been replicated to this node.
+    async fn remove_replica(&self, path: &str) -> common_exception::Result<()> {
+        // Only the copies of a failed `add` are removed, never a committed file.
+        if self.meta_node.get_file(path).await?.is_some() {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "dfs: can not remove the copy of the committed file {:?}",
+                path
+            )));
+        }
+        self.local_fs.remove_replica(path).await
+    }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn read_all(&self, key: &str) -> exception::Result<Vec<u8>> {
         // meanwhile, file meta is empty string
-        let _file_meta = self.meta_node.get_file(key).await?.ok_or_else(|| {
-            ErrorCode::FileMetaNotFound(format!("dfs/meta: key not found: {:?}", key))
-        })?;
+        let _file_meta = self.file_meta(key).await?;
+
+        if self.local_fs.exists(key) {
+            return self.local_fs.read_all(key).await;
+        }
+
+        // the file is not stored on this node, or this copy has not been repaired yet.
+        let replicas = self.replicas_of(key).await;
+        self.pull_copy(key, &replicas, None).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn read_range(&self, key: &str, offset: u64, len: u64) -> exception::Result<Vec<u8>> {
+        let _file_meta = self.file_meta(key).await?;
+
+        if self.local_fs.exists(key) {
+            return self.local_fs.read_range(key, offset, len).await;
+        }
+
+        let replicas = self.replicas_of(key).await;
+        self.pull_copy(key, &replicas, Some((offset, len))).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn file_size(&self, key: &str) -> exception::Result<u64> {
+        let _file_meta = self.file_meta(key).await?;
+
+        if self.local_fs.exists(key) {
+            return self.local_fs.file_size(key).await;
+        }
+
+        let replicas = self.replicas_of(key).await;
+        let mut last_err = None;
+        for node_id in replicas.iter().filter(|x| **x != self.node_id()) {
+            match self.replicator.file_size(*node_id, key).await {
+                Ok(size) => return Ok(size),
+                Err(e) => last_err = Some(e),
+            }
+        }
 
-        self.local_fs.read_all(key).await
+        Err(last_err.unwrap_or_else(|| {
+            ErrorCode::FileDamaged(format!("dfs: no replica to stat: {:?}", key))
+        }))
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
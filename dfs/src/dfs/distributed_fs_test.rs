@@ -13,9 +13,17 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
 
+use async_trait::async_trait;
 use common_base::tokio;
+use common_exception::ErrorCode;
+use common_infallible::Mutex;
+use common_metatypes::NodeId;
+use common_metatypes::Slot;
 use common_tracing::tracing;
+use futures::TryStreamExt;
 use kvsrv::meta_service::GetReq;
 use kvsrv::meta_service::MetaNode;
 use kvsrv::meta_service::MetaServiceClient;
@@ -25,6 +33,7 @@ use tempfile::tempdir;
 use tempfile::TempDir;
 
 use crate::dfs::Dfs;
+use crate::dfs::Replicator;
 use crate::fs::FileSystem;
 use crate::localfs::LocalFS;
 use crate::tests::assert_meta_connection;
@@ -119,6 +128,278 @@ async fn test_distributed_fs_single_node_list() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_distributed_fs_quorum_write() -> anyhow::Result<()> {
+    // - Brings a single node dfs online, with 2 other nodes faked by a replicator.
+    // - Write with one replica down: a quorum is reached.
+    // - Write with two replicas down: no quorum, the file meta is not committed,
+    //   and the written copy is removed.
+    // - Re-write a committed file without quorum: the committed copies are kept.
+
+    let dir = tempdir()?;
+    let replicator = Arc::new(FakeReplicator::default());
+    let (_tc, dfs) = bring_up_replicated_dfs(&dir, replicator.clone()).await?;
+
+    replicator.set_down(&[2]);
+    dfs.add("foo", b"bar").await?;
+    assert_eq!(Some(b"bar".to_vec()), replicator.get(1, "foo"));
+    assert_eq!(None, replicator.get(2, "foo"));
+    assert_eq!(b"bar".to_vec(), dfs.read_all("foo").await?);
+
+    replicator.set_down(&[1, 2]);
+    let got = dfs.add("ping", b"pong").await;
+    let err = got.unwrap_err();
+    assert_eq!(
+        ErrorCode::ReplicationQuorumNotReached("").code(),
+        err.code()
+    );
+    assert_eq!(
+        "dfs: 1 of 3 copies of \"ping\" are written, quorum is 2",
+        err.message()
+    );
+    assert!(dfs.meta_node.get_file("ping").await?.is_none());
+    assert!(!dfs.local_fs.exists("ping"));
+
+    // The local copy fails to be written since it exists, the committed copies are kept.
+    replicator.set_down(&[2]);
+    let got = dfs.add("foo", b"bar").await;
+    assert_eq!(
+        ErrorCode::ReplicationQuorumNotReached("").code(),
+        got.unwrap_err().code()
+    );
+    assert!(dfs.local_fs.exists("foo"));
+    assert_eq!(Some(b"bar".to_vec()), replicator.get(1, "foo"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_distributed_fs_repair() -> anyhow::Result<()> {
+    // - Brings a single node dfs online, with 2 other nodes faked by a replicator.
+    // - Write a file then remove the local copy, as if the node is replaced with an empty one.
+    // - read_all() reads from other replicas.
+    // - repair() does nothing since the copy is not recorded as pending.
+    // - repair_all() copies the file back from other replicas.
+
+    let dir = tempdir()?;
+    let replicator = Arc::new(FakeReplicator::default());
+    let (_tc, dfs) = bring_up_replicated_dfs(&dir, replicator.clone()).await?;
+
+    dfs.add("who/is/hiding", b"jerry").await?;
+    std::fs::remove_file(dir.path().join("who/is/hiding"))?;
+    assert!(!dfs.local_fs.exists("who/is/hiding"));
+
+    replicator.set_down(&[1]);
+    assert_eq!(b"jerry".to_vec(), dfs.read_all("who/is/hiding").await?);
+
+    assert_eq!(0, dfs.repair().await?);
+    assert!(!dfs.local_fs.exists("who/is/hiding"));
+
+    assert_eq!(1, dfs.repair_all().await?);
+    assert!(dfs.local_fs.exists("who/is/hiding"));
+    assert_eq!(0, dfs.repair_all().await?);
+
+    replicator.set_down(&[1, 2]);
+    assert_eq!(b"jerry".to_vec(), dfs.read_all("who/is/hiding").await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_distributed_fs_repair_pending() -> anyhow::Result<()> {
+    // - Brings a single node dfs online, with 2 other nodes faked by a replicator.
+    // - Write with one replica down: the missing copy is recorded as pending.
+    // - A pending local copy is pulled by repair(), and the pending record is cleared.
+    // - A pending record of a file that is not committed is just cleared.
+
+    let dir = tempdir()?;
+    let replicator = Arc::new(FakeReplicator::default());
+    let (_tc, dfs) = bring_up_replicated_dfs(&dir, replicator.clone()).await?;
+
+    replicator.set_down(&[2]);
+    dfs.add("foo", b"bar").await?;
+    let pending = dfs
+        .meta_node
+        .prefix_list_kv("__fd_dfs_pending_replica/")
+        .await?;
+    let keys = pending.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+    assert_eq!(vec!["__fd_dfs_pending_replica/2/foo"], keys);
+
+    replicator.set_down(&[]);
+    std::fs::remove_file(dir.path().join("foo"))?;
+    dfs.set_pending(0, "foo", true).await?;
+    dfs.set_pending(0, "not-committed", true).await?;
+
+    assert_eq!(1, dfs.repair().await?);
+    assert!(dfs.local_fs.exists("foo"));
+    assert!(dfs
+        .meta_node
+        .prefix_list_kv("__fd_dfs_pending_replica/0/")
+        .await?
+        .is_empty());
+    assert_eq!(0, dfs.repair().await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_distributed_fs_remove_committed_replica() -> anyhow::Result<()> {
+    // - A copy of a committed file can not be removed.
+
+    let dir = tempdir()?;
+    let replicator = Arc::new(FakeReplicator::default());
+    let (_tc, dfs) = bring_up_replicated_dfs(&dir, replicator.clone()).await?;
+
+    dfs.add("foo", b"bar").await?;
+    let got = dfs.remove_replica("foo").await;
+    assert_eq!(
+        ErrorCode::PermissionDenied("").code(),
+        got.unwrap_err().code()
+    );
+    assert!(dfs.local_fs.exists("foo"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_distributed_fs_read_range() -> anyhow::Result<()> {
+    // - Brings a single node dfs online, with 2 other nodes faked by a replicator.
+    // - Read ranges and stream from the local copy.
+    // - Remove the local copy, read ranges and stream from other replicas.
+
+    let dir = tempdir()?;
+    let replicator = Arc::new(FakeReplicator::default());
+    let (_tc, dfs) = bring_up_replicated_dfs(&dir, replicator.clone()).await?;
+
+    dfs.add("foo", b"0123456789").await?;
+
+    for local in [true, false] {
+        if !local {
+            std::fs::remove_file(dir.path().join("foo"))?;
+            replicator.set_down(&[1]);
+        }
+
+        assert_eq!(10, dfs.file_size("foo").await?, "local: {}", local);
+        assert_eq!(b"234".to_vec(), dfs.read_range("foo", 2, 3).await?);
+        assert_eq!(b"89".to_vec(), dfs.read_range("foo", 8, 5).await?);
+        assert!(dfs.read_range("foo", 20, 5).await?.is_empty());
+
+        let chunks: Vec<Vec<u8>> = dfs.read_stream("foo", 1, Some(8), 3).try_collect().await?;
+        assert_eq!(
+            vec![b"123".to_vec(), b"456".to_vec(), b"78".to_vec()],
+            chunks,
+            "local: {}",
+            local
+        );
+
+        let chunks: Vec<Vec<u8>> = dfs.read_stream("foo", 6, None, 3).try_collect().await?;
+        assert_eq!(vec![b"678".to_vec(), b"9".to_vec()], chunks);
+    }
+
+    let got = dfs.read_range("absent", 0, 1).await;
+    assert_eq!(
+        "dfs/meta: key not found: \"absent\"",
+        got.unwrap_err().message()
+    );
+
+    Ok(())
+}
+
+/// Fakes copies stored on other nodes in memory.
+#[derive(Default)]
+struct FakeReplicator {
+    copies: Mutex<HashMap<(NodeId, String), Vec<u8>>>,
+    down: Mutex<HashSet<NodeId>>,
+}
+
+impl FakeReplicator {
+    fn set_down(&self, node_ids: &[NodeId]) {
+        *self.down.lock() = node_ids.iter().cloned().collect();
+    }
+
+    fn get(&self, node_id: NodeId, key: &str) -> Option<Vec<u8>> {
+        self.copies.lock().get(&(node_id, key.to_string())).cloned()
+    }
+
+    fn check_up(&self, node_id: NodeId) -> common_exception::Result<()> {
+        if self.down.lock().contains(&node_id) {
+            return Err(ErrorCode::CannotConnectNode(format!(
+                "node {} is down",
+                node_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Replicator for FakeReplicator {
+    async fn push(&self, node_id: NodeId, key: &str, data: &[u8]) -> common_exception::Result<()> {
+        self.check_up(node_id)?;
+        self.copies
+            .lock()
+            .insert((node_id, key.to_string()), data.to_vec());
+        Ok(())
+    }
+
+    async fn pull(&self, node_id: NodeId, key: &str) -> common_exception::Result<Vec<u8>> {
+        self.check_up(node_id)?;
+        self.get(node_id, key)
+            .ok_or_else(|| ErrorCode::FileDamaged(format!("{} not found on {}", key, node_id)))
+    }
+
+    async fn pull_range(
+        &self,
+        node_id: NodeId,
+        key: &str,
+        offset: u64,
+        len: u64,
+    ) -> common_exception::Result<Vec<u8>> {
+        let data = self.pull(node_id, key).await?;
+        let start = std::cmp::min(offset as usize, data.len());
+        let end = std::cmp::min(start + len as usize, data.len());
+        Ok(data[start..end].to_vec())
+    }
+
+    async fn file_size(&self, node_id: NodeId, key: &str) -> common_exception::Result<u64> {
+        let data = self.pull(node_id, key).await?;
+        Ok(data.len() as u64)
+    }
+
+    async fn remove(&self, node_id: NodeId, key: &str) -> common_exception::Result<()> {
+        self.check_up(node_id)?;
+        self.copies.lock().remove(&(node_id, key.to_string()));
+        Ok(())
+    }
+}
+
+// Start an dfs whose files are placed on node 0(this node), 1 and 2.
+async fn bring_up_replicated_dfs(
+    root: &TempDir,
+    replicator: Arc<FakeReplicator>,
+) -> anyhow::Result<(StoreTestContext, Dfs)> {
+    let root = root.path().to_str().unwrap().to_string();
+    let fs = LocalFS::try_create(root)?;
+
+    let mut tc = new_test_context();
+    let meta_addr = tc.config.meta_config.raft_api_addr();
+
+    let mn = MetaNode::boot(0, &tc.config.meta_config).await?;
+    tc.meta_nodes.push(mn.clone());
+
+    assert_meta_connection(&meta_addr).await?;
+
+    {
+        let mut sm = mn.sto.state_machine.write().await;
+        sm.slots = vec![Slot {
+            node_ids: vec![0, 1, 2],
+        }];
+    }
+
+    let dfs = Dfs::with_replicator(fs, mn, replicator);
+    Ok((tc, dfs))
+}
+
 // Start an dfs.
 // And feed files into dfs.
 async fn bring_up_dfs(
@@ -136,7 +417,7 @@ async fn bring_up_dfs(
 
     assert_meta_connection(&meta_addr).await?;
 
-    let dfs = Dfs::create(fs, mn);
+    let dfs = Dfs::create(fs, mn, &tc.config)?;
     for (key, content) in files.iter() {
         dfs.add(*key, (*content).as_bytes()).await?;
         tracing::debug!("dfs added file: {} {:?}", *key, *content);
@@ -12,8 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 pub mod distributed_fs;
+pub mod replicator;
 
 pub use distributed_fs::Dfs;
+pub use replicator::flight_address_key;
+pub use replicator::register_flight_address;
+pub use replicator::FlightReplicator;
+pub use replicator::Replicator;
 
 #[cfg(test)]
 mod distributed_fs_test;
@@ -0,0 +1,150 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_exception::ErrorCode;
+use common_metatypes::Cmd;
+use common_metatypes::LogEntry;
+use common_metatypes::MatchSeq;
+use common_metatypes::NodeId;
+use common_metatypes::Operation;
+use common_store_api_sdk::storage_api_impl::StorageApi;
+use common_store_api_sdk::StoreClient;
+use futures::TryStreamExt;
+use kvsrv::meta_service::MetaNode;
+
+/// The key prefix in meta kv to store the flight address of every dfs node.
+const DFS_FLIGHT_ADDRESS_PREFIX: &str = "__fd_dfs_flight_address";
+
+pub fn flight_address_key(node_id: NodeId) -> String {
+    format!("{}/{}", DFS_FLIGHT_ADDRESS_PREFIX, node_id)
+}
+
+/// Registers the flight address of this node, through which other nodes push or pull data copies.
+pub async fn register_flight_address(
+    meta_node: &MetaNode,
+    address: &str,
+) -> common_exception::Result<()> {
+    let req = LogEntry {
+        txid: None,
+        cmd: Cmd::UpsertKV {
+            key: flight_address_key(meta_node.sto.id),
+            seq: MatchSeq::Any,
+            value: Operation::Update(address.as_bytes().to_vec()),
+            value_meta: None,
+        },
+    };
+    let _resp = meta_node.write(req).await?;
+    Ok(())
+}
+
+/// Replicator transfers copies of files between dfs nodes.
+/// It does not touch the file meta data, which is maintained by Dfs.
+#[async_trait]
+pub trait Replicator
+where Self: Sync + Send
+{
+    /// Stores a copy of a file on node `node_id`.
+    async fn push(&self, node_id: NodeId, key: &str, data: &[u8]) -> common_exception::Result<()>;
+
+    /// Reads the copy of a file stored on node `node_id`.
+    async fn pull(&self, node_id: NodeId, key: &str) -> common_exception::Result<Vec<u8>>;
+
+    /// Reads at most `len` bytes from `offset` of the copy of a file stored on node `node_id`.
+    async fn pull_range(
+        &self,
+        node_id: NodeId,
+        key: &str,
+        offset: u64,
+        len: u64,
+    ) -> common_exception::Result<Vec<u8>>;
+
+    /// Returns the size of the copy of a file stored on node `node_id`.
+    async fn file_size(&self, node_id: NodeId, key: &str) -> common_exception::Result<u64>;
+
+    /// Removes the copy of a file stored on node `node_id`, which is not committed to meta.
+    async fn remove(&self, node_id: NodeId, key: &str) -> common_exception::Result<()>;
+}
+
+/// A Replicator talks to other dfs nodes with arrow-flight.
+/// The flight address of a node is registered in meta kv when the node starts up.
+pub struct FlightReplicator {
+    meta_node: Arc<MetaNode>,
+    username: String,
+    password: String,
+}
+
+impl FlightReplicator {
+    /// Creates a replicator that authenticates to other nodes as `username`,
+    /// i.e. `replication_username` and `replication_password` in config.
+    pub fn create(meta_node: Arc<MetaNode>, username: &str, password: &str) -> Self {
+        FlightReplicator {
+            meta_node,
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    async fn client(&self, node_id: NodeId) -> common_exception::Result<StoreClient> {
+        let key = flight_address_key(node_id);
+        let (_seq, value) = self.meta_node.get_kv(&key).await?.ok_or_else(|| {
+            ErrorCode::UnknownNode(format!("flight address of dfs node {} not found", node_id))
+        })?;
+        let addr = String::from_utf8(value.value)?;
+
+        // TODO(xp): internal rpc with tls
+        StoreClient::try_create(&addr, &self.username, &self.password).await
+    }
+}
+
+#[async_trait]
+impl Replicator for FlightReplicator {
+    async fn push(&self, node_id: NodeId, key: &str, data: &[u8]) -> common_exception::Result<()> {
+        let client = self.client(node_id).await?;
+        client.push_file(key, data.to_vec()).await
+    }
+
+    async fn pull(&self, node_id: NodeId, key: &str) -> common_exception::Result<Vec<u8>> {
+        let client = self.client(node_id).await?;
+        client.pull_file(key).await
+    }
+
+    async fn pull_range(
+        &self,
+        node_id: NodeId,
+        key: &str,
+        offset: u64,
+        len: u64,
+    ) -> common_exception::Result<Vec<u8>> {
+        let client = self.client(node_id).await?;
+        let chunks: Vec<Vec<u8>> = client
+            .read_file(key, offset, Some(len))
+            .await?
+            .try_collect()
+            .await?;
+        Ok(chunks.concat())
+    }
+
+    async fn file_size(&self, node_id: NodeId, key: &str) -> common_exception::Result<u64> {
+        let client = self.client(node_id).await?;
+        client.file_size(key).await
+    }
+
+    async fn remove(&self, node_id: NodeId, key: &str) -> common_exception::Result<()> {
+        let client = self.client(node_id).await?;
+        client.remove_replica(key).await
+    }
+}
@@ -20,11 +20,16 @@ use common_arrow::arrow::io::ipc::write::common::IpcWriteOptions;
 use common_arrow::arrow::io::parquet::read;
 use common_arrow::arrow_flight::utils::flight_data_from_arrow_batch;
 use common_arrow::arrow_flight::FlightData;
+use common_base::tokio;
 use common_base::tokio::sync::mpsc::Sender;
+use common_datablocks::ParquetWriteOptions;
 use common_exception::ErrorCode;
 use common_planners::PlanNode;
+use common_store_api_sdk::replication_impl::RemoveReplicaAction;
 use common_store_api_sdk::storage_api_impl::AppendResult;
+use common_store_api_sdk::storage_api_impl::FileSizeAction;
 use common_store_api_sdk::storage_api_impl::ReadAction;
+use common_store_api_sdk::storage_api_impl::ReadFileAction;
 use common_store_api_sdk::RequestFor;
 use common_store_api_sdk::StoreDoAction;
 use futures::Stream;
@@ -51,6 +56,9 @@ pub struct ActionHandler {
     fs: Arc<dyn FileSystem>,
 }
 
+/// The max size of a chunk when streaming a file back to client.
+const READ_FILE_CHUNK_SIZE: u64 = 1024 * 1024;
+
 // TODO did this already defined somewhere?
 type DoGetStream =
     Pin<Box<dyn Stream<Item = Result<FlightData, tonic::Status>> + Send + Sync + 'static>>;
@@ -89,6 +97,59 @@ impl ActionHandler {
         .map_err(|e| Status::internal(format!("{:?}", e)))
     }
 
+    /// Handle push-file request, which is used internally for replicating data copies.
+    /// The pushed copy is stored on this node without updating meta data.
+    pub async fn do_push_file(
+        &self,
+        key: String,
+        mut parts: Streaming<FlightData>,
+    ) -> common_exception::Result<()> {
+        let mut data = vec![];
+        while let Some(flight_data) = parts.next().await {
+            data.extend_from_slice(&flight_data?.data_body);
+        }
+
+        self.fs.add_replica(&key, &data).await
+    }
+
+    /// Handle read-file request, the requested range of the file is streamed back in chunks.
+    /// Reading is done in a spawned task, the chunks are sent through `tx`.
+    pub fn do_read_file(&self, act: ReadFileAction, tx: Sender<Result<FlightData, tonic::Status>>) {
+        let fs = self.fs.clone();
+
+        tokio::spawn(async move {
+            let mut chunks = fs.read_stream(&act.key, act.offset, act.len, READ_FILE_CHUNK_SIZE);
+
+            while let Some(chunk) = chunks.next().await {
+                let item = chunk
+                    .map(|data| FlightData {
+                        data_body: data,
+                        ..Default::default()
+                    })
+                    .map_err(|e| Status::internal(e.to_string()));
+
+                let failed = item.is_err();
+                if tx.send(item).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Handle add-file request, the file is added to fs as a whole.
+    pub async fn do_add_file(
+        &self,
+        key: String,
+        mut parts: Streaming<FlightData>,
+    ) -> common_exception::Result<()> {
+        let mut data = vec![];
+        while let Some(flight_data) = parts.next().await {
+            data.extend_from_slice(&flight_data?.data_body);
+        }
+
+        self.fs.add(&key, &data).await
+    }
+
     pub async fn execute<S, R>(&self, action: StoreDoAction, s: S) -> common_exception::Result<R>
     where S: ReplySerializer<Output = R> {
         // To keep the code IDE-friendly, we manually expand the enum variants and dispatch them one by one
@@ -113,6 +174,10 @@ impl ActionHandler {
             StoreDoAction::GetKV(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::MGetKV(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::PrefixListKV(a) => s.serialize(self.handle(a).await?),
+
+            // dfs file
+            StoreDoAction::FileSize(a) => s.serialize(self.handle(a).await?),
+            StoreDoAction::RemoveReplica(a) => s.serialize(self.handle(a).await?),
         }
     }
 
@@ -128,18 +193,44 @@ impl ActionHandler {
             // table's current schema (or following the evolution rules of table schema)
         }
 
+        let options = self.parquet_write_options(&db_name, &table_name).await?;
+
         let appender = Appender::new(self.fs.clone());
         let parts = parts
             .take_while(|item| item.is_ok())
             .map(|item| item.unwrap());
 
         let res = appender
-            .append_data(format!("{}/{}", &db_name, &table_name), Box::pin(parts))
+            .append_data(
+                format!("{}/{}", &db_name, &table_name),
+                Box::pin(parts),
+                &options,
+            )
             .await?;
 
         Ok(res)
     }
 
+    /// The parquet options of the parts are specified by the options of the table.
+    async fn parquet_write_options(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> common_exception::Result<ParquetWriteOptions> {
+        let db = self.meta_node.get_database(db_name).await.ok_or_else(|| {
+            ErrorCode::UnknownDatabase(format!("append data: database not found {:}", db_name))
+        })?;
+        let table_id = db
+            .tables
+            .get(table_name)
+            .ok_or_else(|| ErrorCode::UnknownTable(format!("table not found: {:}", table_name)))?;
+        let table =
+            self.meta_node.get_table(table_id).await.ok_or_else(|| {
+                ErrorCode::UnknownTable(format!("table not found: {:}", table_name))
+            })?;
+        ParquetWriteOptions::from_table_options(&table.table_options)
+    }
+
     pub async fn read_partition(
         &self,
         action: ReadAction,
@@ -180,3 +271,17 @@ impl ActionHandler {
         Ok(Box::pin(stream))
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<FileSizeAction> for ActionHandler {
+    async fn handle(&self, act: FileSizeAction) -> common_exception::Result<u64> {
+        self.fs.file_size(&act.key).await
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<RemoveReplicaAction> for ActionHandler {
+    async fn handle(&self, act: RemoveReplicaAction) -> common_exception::Result<()> {
+        self.fs.remove_replica(&act.key).await
+    }
+}
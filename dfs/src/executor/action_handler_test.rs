@@ -34,6 +34,8 @@ use common_store_api_sdk::meta_api_impl::DropDatabaseAction;
 use common_store_api_sdk::meta_api_impl::DropTableAction;
 use common_store_api_sdk::meta_api_impl::GetDatabaseAction;
 use common_store_api_sdk::meta_api_impl::GetTableAction;
+use common_store_api_sdk::storage_api_impl::FileSizeAction;
+use common_store_api_sdk::storage_api_impl::ReadFileAction;
 use common_tracing::tracing;
 use kvsrv::meta_service::MetaNode;
 use maplit::hashmap;
@@ -79,6 +81,72 @@ async fn test_action_handler_do_pull_file() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_action_handler_do_read_file() -> anyhow::Result<()> {
+    // - Bring up an ActionHandler backed with a Dfs
+    // - Assert reading a range of file and getting file size works fine.
+
+    let (_log_guards, ut_span) = init_store_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, hdlr) = bring_up_dfs_action_handler(hashmap! {
+        "foo" => "0123456789",
+    })
+    .await?;
+
+    {
+        // read a range of file
+        let (tx, mut rx): (
+            Sender<Result<FlightData, tonic::Status>>,
+            Receiver<Result<FlightData, tonic::Status>>,
+        ) = tokio::sync::mpsc::channel(2);
+
+        hdlr.do_read_file(
+            ReadFileAction {
+                key: "foo".into(),
+                offset: 3,
+                len: Some(4),
+            },
+            tx,
+        );
+
+        let mut body = vec![];
+        while let Some(rst) = rx.recv().await {
+            body.extend_from_slice(&rst?.data_body);
+        }
+        assert_eq!("3456", std::str::from_utf8(&body)?);
+    }
+    {
+        // read absent file
+        let (tx, mut rx): (
+            Sender<Result<FlightData, tonic::Status>>,
+            Receiver<Result<FlightData, tonic::Status>>,
+        ) = tokio::sync::mpsc::channel(2);
+
+        hdlr.do_read_file(
+            ReadFileAction {
+                key: "absent".into(),
+                offset: 0,
+                len: None,
+            },
+            tx,
+        );
+
+        let rst = rx
+            .recv()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("should not be None"))?;
+        assert!(rst.is_err());
+        assert!(rx.recv().await.is_none());
+    }
+    {
+        // file size
+        let size = hdlr.handle(FileSizeAction { key: "foo".into() }).await?;
+        assert_eq!(10, size);
+    }
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_action_handler_add_database() -> anyhow::Result<()> {
     // - Bring up an ActionHandler backed with a Dfs
@@ -657,7 +725,7 @@ async fn bring_up_dfs_action_handler(
     let mn = MetaNode::boot(0, &tc.config.meta_config).await?;
     tc.meta_nodes.push(mn.clone());
 
-    let dfs = Dfs::create(fs, mn.clone());
+    let dfs = Dfs::create(fs, mn.clone(), &tc.config)?;
 
     for (key, content) in files.iter() {
         dfs.add(*key, (*content).as_bytes()).await?;
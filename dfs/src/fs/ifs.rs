@@ -14,9 +14,15 @@
 
 use async_trait::async_trait;
 use common_exception::exception;
+use common_exception::ErrorCode;
+use futures::stream::BoxStream;
+use futures::StreamExt;
 
 use crate::fs::ListResult;
 
+/// A stream of consecutive chunks of a file.
+pub type FileChunkStream<'a> = BoxStream<'a, common_exception::Result<Vec<u8>>>;
+
 /// Abstract storage layer API.
 #[async_trait]
 pub trait FileSystem
@@ -26,16 +32,61 @@ where Self: Sync + Send
     /// AKA put_if_absent
     async fn add(&self, path: &str, data: &[u8]) -> common_exception::Result<()>;
 
+    /// Add a copy of a file that is replicated from another node.
+    /// Unlike `add`, it neither updates the meta data nor replicates the file any further.
+    async fn add_replica(&self, path: &str, data: &[u8]) -> common_exception::Result<()> {
+        self.add(path, data).await
+    }
+
+    /// Remove a copy of a file which is not committed to the meta data,
+    /// e.g. the copies written by an `add` that failed. Removing an absent copy is ok.
+    async fn remove_replica(&self, path: &str) -> common_exception::Result<()>;
+
     /// read all bytes from a file
     async fn read_all(&self, path: &str) -> exception::Result<Vec<u8>>;
 
+    /// Read at most `len` bytes from a file, starting at `offset`.
+    /// Fewer bytes are returned if the end of file is reached.
+    async fn read_range(
+        &self,
+        path: &str,
+        offset: u64,
+        len: u64,
+    ) -> common_exception::Result<Vec<u8>>;
+
+    /// Returns the size of a file in bytes.
+    async fn file_size(&self, path: &str) -> common_exception::Result<u64>;
+
+    /// Read a file from `offset` as a stream of chunks of at most `chunk_size` bytes.
+    /// It reads `len` bytes, or till the end of file if `len` is None.
+    fn read_stream<'a>(
+        &'a self,
+        path: &'a str,
+        offset: u64,
+        len: Option<u64>,
+        chunk_size: u64,
+    ) -> FileChunkStream<'a> {
+        let end = len.map(|l| offset.saturating_add(l));
+
+        futures::stream::try_unfold(offset, move |pos| async move {
+            let n = match end {
+                Some(end) => std::cmp::min(chunk_size, end.saturating_sub(pos)),
+                None => chunk_size,
+            };
+            if n == 0 {
+                return Ok::<_, ErrorCode>(None);
+            }
+
+            let chunk = self.read_range(path, pos, n).await?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            let next = pos + chunk.len() as u64;
+            Ok(Some((chunk, next)))
+        })
+        .boxed()
+    }
+
     /// List dir and returns directories and files.
     async fn list(&self, prefix: &str) -> common_exception::Result<ListResult>;
-
-    // async fn read(
-    //     path: &str,
-    //     offset: usize,
-    //     length: usize,
-    //     buf: &mut [u8],
-    // ) -> common_exception::Result<usize>;
 }
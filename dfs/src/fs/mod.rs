@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use ifs::FileChunkStream;
 pub use ifs::FileSystem;
 pub use list_result::ListResult;
 
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use std::fs::OpenOptions;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
 use std::io::Write;
+use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -26,6 +30,22 @@ use common_tracing::tracing;
 use crate::fs::FileSystem;
 use crate::fs::ListResult;
 
+/// Checks that a file key is a relative path without `..`, so that it can not escape the root dir.
+/// The keys come from the network, e.g., the replica key of a push.
+pub fn check_path(path: &str) -> common_exception::Result<()> {
+    let valid = !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if !valid {
+        return Err(ErrorCode::IllegalFileName(format!(
+            "LocalFS: illegal file key {:?}, it must be a relative path without \"..\"",
+            path
+        )));
+    }
+    Ok(())
+}
+
 pub struct LocalFS {
     root: PathBuf,
 }
@@ -38,6 +58,27 @@ impl LocalFS {
         };
         Ok(f)
     }
+
+    /// Returns true if the file is stored in this local fs.
+    pub fn exists(&self, path: &str) -> bool {
+        match self.local_path(path) {
+            Ok(p) => p.is_file(),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns true if nothing is stored in this local fs, e.g. the node is new or replaced.
+    pub fn is_empty(&self) -> bool {
+        match std::fs::read_dir(&self.root) {
+            Ok(mut entries) => entries.next().is_none(),
+            Err(_) => true,
+        }
+    }
+
+    fn local_path(&self, path: &str) -> common_exception::Result<PathBuf> {
+        check_path(path)?;
+        Ok(self.root.join(path))
+    }
 }
 
 #[async_trait]
@@ -45,7 +86,7 @@ impl FileSystem for LocalFS {
     #[tracing::instrument(level = "debug", skip(self, data))]
     async fn add(&self, path: &str, data: &[u8]) -> common_exception::Result<()> {
         // TODO: test atomicity: write temp file and rename it
-        let p = Path::new(self.root.as_path()).join(path);
+        let p = self.local_path(path)?;
         let mut an = p.ancestors();
         let _tail = an.next();
         let base = an.next();
@@ -69,9 +110,21 @@ impl FileSystem for LocalFS {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn remove_replica(&self, path: &str) -> common_exception::Result<()> {
+        let p = self.local_path(path)?;
+        match std::fs::remove_file(p.as_path()) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            res => {
+                res.with_context(|| format!("LocalFS: fail to remove {}", path))?;
+                Ok(())
+            }
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn read_all(&self, path: &str) -> exception::Result<Vec<u8>> {
-        let p = Path::new(self.root.as_path()).join(path);
+        let p = self.local_path(path)?;
         tracing::info!("read: {}", p.as_path().display());
 
         let data = std::fs::read(p.as_path()).map_err_to_code(ErrorCode::FileDamaged, || {
@@ -80,9 +133,50 @@ impl FileSystem for LocalFS {
         Ok(data)
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn read_range(
+        &self,
+        path: &str,
+        offset: u64,
+        len: u64,
+    ) -> common_exception::Result<Vec<u8>> {
+        let p = self.local_path(path)?;
+
+        let mut f = std::fs::File::open(p.as_path())
+            .map_err_to_code(ErrorCode::FileDamaged, || {
+                format!("LocalFS: fail to open: {:?}", path)
+            })?;
+        f.seek(SeekFrom::Start(offset))
+            .map_err_to_code(ErrorCode::FileDamaged, || {
+                format!("LocalFS: fail to seek: {:?} to {}", path, offset)
+            })?;
+
+        let mut data = vec![];
+        f.take(len)
+            .read_to_end(&mut data)
+            .map_err_to_code(ErrorCode::FileDamaged, || {
+                format!("LocalFS: fail to read: {:?}", path)
+            })?;
+        Ok(data)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn file_size(&self, path: &str) -> common_exception::Result<u64> {
+        let p = self.local_path(path)?;
+        let meta = std::fs::metadata(p.as_path())
+            .map_err_to_code(ErrorCode::FileDamaged, || {
+                format!("LocalFS: fail to stat: {:?}", path)
+            })?;
+        Ok(meta.len())
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn list(&self, path: &str) -> common_exception::Result<ListResult> {
-        let p = Path::new(self.root.as_path()).join(path);
+        // An empty path lists the root dir.
+        let p = match path {
+            "" => self.root.clone(),
+            _ => self.local_path(path)?,
+        };
         let entries = std::fs::read_dir(p.as_path())
             .with_context(|| format!("LocalFS: fail to list {}", path))?;
 
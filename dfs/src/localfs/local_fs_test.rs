@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use common_base::tokio;
+use common_exception::ErrorCode;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 use tempfile::tempdir;
 
@@ -80,3 +82,82 @@ async fn test_localfs_read_all() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_localfs_read_range() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    let root = dir.path();
+
+    let f = LocalFS::try_create(root.to_str().unwrap().to_string())?;
+    {
+        // read absent file
+        let got = f.read_range("foo.txt", 0, 1).await;
+        assert_eq!(
+            "LocalFS: fail to open: \"foo.txt\", cause: No such file or directory (os error 2)",
+            got.err().unwrap().message()
+        );
+    }
+
+    f.add("foo.txt", "0123456789".as_bytes()).await?;
+    assert_eq!(10, f.file_size("foo.txt").await?);
+
+    let cases: Vec<(u64, u64, &str)> = vec![
+        (0, 3, "012"),
+        (3, 4, "3456"),
+        (8, 10, "89"),
+        (10, 1, ""),
+        (20, 1, ""),
+    ];
+    for (offset, len, want) in cases.iter() {
+        let got = f.read_range("foo.txt", *offset, *len).await?;
+        assert_eq!(*want, std::str::from_utf8(&got)?, "read {} {}", offset, len);
+    }
+
+    {
+        // stream read in chunks
+        let got: Vec<Vec<u8>> = f.read_stream("foo.txt", 2, None, 3).try_collect().await?;
+        assert_eq!(vec![b"234".to_vec(), b"567".to_vec(), b"89".to_vec()], got);
+
+        let got: Vec<Vec<u8>> = f
+            .read_stream("foo.txt", 2, Some(4), 3)
+            .try_collect()
+            .await?;
+        assert_eq!(vec![b"234".to_vec(), b"5".to_vec()], got);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_localfs_illegal_path() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    let root = dir.path().join("root");
+
+    let f = LocalFS::try_create(root.to_str().unwrap().to_string())?;
+    let escaped = dir.path().join("escaped");
+    let escaped = escaped.to_str().unwrap();
+
+    for path in ["../escaped", "a/../../escaped", escaped, ""] {
+        let got = f.add(path, b"123").await;
+        assert_eq!(
+            ErrorCode::IllegalFileName("").code(),
+            got.unwrap_err().code(),
+            "add {:?}",
+            path
+        );
+        assert!(f.read_all(path).await.is_err());
+        assert!(f.remove_replica(path).await.is_err());
+        assert!(!f.exists(path));
+    }
+    assert!(!dir.path().join("escaped").exists());
+
+    f.add("a/./b", b"123").await?;
+    assert!(f.exists("a/b"));
+    f.remove_replica("a/b").await?;
+    assert!(!f.exists("a/b"));
+
+    // Removing an absent copy is ok.
+    f.remove_replica("a/b").await?;
+
+    Ok(())
+}
@@ -14,6 +14,7 @@
 
 pub mod local_fs;
 
+pub use local_fs::check_path;
 pub use local_fs::LocalFS;
 
 #[cfg(test)]
@@ -90,6 +90,9 @@ pub fn new_test_context() -> StoreTestContext {
 
     config.meta_config.config_id = format!("{}", config_id);
 
+    config.replication_username = "replication".to_string();
+    config.replication_password = "replication-password".to_string();
+
     // By default, create a meta node instead of open an existent one.
     config.meta_config.single = true;
 
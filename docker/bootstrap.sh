@@ -1,5 +1,9 @@
 #!/bin/bash
 
+# The replication user of databend-dfs must be configured, override them in production.
+export STORE_REPLICATION_USERNAME=${STORE_REPLICATION_USERNAME:-replication}
+export STORE_REPLICATION_PASSWORD=${STORE_REPLICATION_PASSWORD:-replication}
+
 /databend-dfs --single true &> /tmp/databend-dfs.log  &
 P1=$!
 /databend-query -c databend-query.toml &> /tmp/databend-query.log  &
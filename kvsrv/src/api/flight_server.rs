@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_arrow::arrow_flight::flight_service_server::FlightServiceServer;
 use common_base::tokio;
 use common_base::tokio::sync::oneshot;
@@ -32,11 +34,12 @@ use crate::meta_service::MetaNode;
 
 pub struct FlightServer {
     conf: Config,
+    meta_node: Arc<MetaNode>,
 }
 
 impl FlightServer {
-    pub fn create(conf: Config) -> Self {
-        Self { conf }
+    pub fn create(conf: Config, meta_node: Arc<MetaNode>) -> Self {
+        Self { conf, meta_node }
     }
 
     /// Start kvsrv and returns two channel to send shutdown signal and receive signal when shutdown finished.
@@ -82,28 +85,7 @@ impl FlightServer {
 
         tracing::info!("flight addr: {}", addr);
 
-        // - boot mode: create the first node in a new cluster.
-        // - TODO(xp): join mode: create a new node to join a cluster.
-        // - open mode: open an existent node.
-        tracing::info!(
-            "Starting MetaNode boot:{} single: {} with config: {:?}",
-            self.conf.raft_config.boot,
-            self.conf.raft_config.single,
-            self.conf
-        );
-
-        let meta_config = &self.conf.raft_config;
-
-        let mn = if meta_config.boot {
-            MetaNode::boot(0, meta_config).await?
-        } else if meta_config.single {
-            let (mn, _is_open) =
-                MetaNode::open_create_boot(meta_config, Some(()), Some(()), Some(())).await?;
-            mn
-        } else {
-            MetaNode::open(meta_config).await?
-        };
-        tracing::info!("Done starting MetaNode: {:?}", self.conf);
+        let mn = self.meta_node.clone();
 
         let flight_impl = MetaFlightImpl::create(self.conf.clone(), mn.clone());
         let flight_srv = FlightServiceServer::new(flight_impl);
@@ -0,0 +1,68 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use axum::extract::Extension;
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use common_exception::ErrorCode;
+use common_metatypes::NodeId;
+
+use crate::meta_service::MetaNode;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MembershipParams {
+    pub node_id: NodeId,
+    /// The raft api address of the node, only required to add a node that is not yet in the cluster.
+    pub address: Option<String>,
+}
+
+fn to_response(res: common_exception::Result<()>) -> (StatusCode, String) {
+    match res {
+        Ok(_) => (StatusCode::OK, "".to_string()),
+        Err(e) if e.code() == ErrorCode::UnknownNode("").code() => {
+            (StatusCode::BAD_REQUEST, e.to_string())
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+// POST /v1/cluster/add_voter?node_id=1&address=127.0.0.1:28004
+// Promote a node to voter, the request is forwarded to the leader.
+pub async fn add_voter_handler(
+    meta_node: Extension<Arc<MetaNode>>,
+    Query(params): Query<MembershipParams>,
+) -> impl IntoResponse {
+    to_response(meta_node.add_voter(params.node_id, params.address).await)
+}
+
+// POST /v1/cluster/remove_node?node_id=1
+// Remove a node from the voters and from the cluster, the request is forwarded to the leader.
+pub async fn remove_node_handler(
+    meta_node: Extension<Arc<MetaNode>>,
+    Query(params): Query<MembershipParams>,
+) -> impl IntoResponse {
+    to_response(meta_node.remove_node(params.node_id).await)
+}
+
+// POST /v1/cluster/transfer_leader?node_id=1
+// Transfer the leadership to a voter, the request is forwarded to the leader.
+pub async fn transfer_leader_handler(
+    meta_node: Extension<Arc<MetaNode>>,
+    Query(params): Query<MembershipParams>,
+) -> impl IntoResponse {
+    to_response(meta_node.transfer_leader(params.node_id).await)
+}
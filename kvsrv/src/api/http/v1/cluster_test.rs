@@ -0,0 +1,89 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::handler::post;
+use axum::http;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::AddExtensionLayer;
+use axum::Router;
+use common_base::tokio;
+use pretty_assertions::assert_eq;
+use tower::ServiceExt;
+
+use crate::api::http::v1::cluster::add_voter_handler;
+use crate::api::http::v1::cluster::remove_node_handler;
+use crate::api::http::v1::cluster::transfer_leader_handler;
+use crate::meta_service::MetaNode;
+use crate::tests::assert_meta_connection;
+use crate::tests::service::new_test_context;
+
+// Sends a POST request to the router, returns the status and the body.
+macro_rules! post {
+    ($router: expr, $uri: expr) => {{
+        let response = $router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri($uri)
+                    .method(http::Method::POST)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, String::from_utf8_lossy(&body).to_string())
+    }};
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cluster_membership() -> anyhow::Result<()> {
+    // - Boot a single node cluster.
+    // - Transferring leadership to itself is a no-op.
+    // - Promoting an unknown node without address is a bad request.
+    // - Removing the last voter fails.
+
+    let tc = new_test_context();
+    let mn: Arc<MetaNode> = MetaNode::boot(0, &tc.config.raft_config).await?;
+    assert_meta_connection(&tc.config.raft_config.raft_api_addr()).await?;
+
+    let router = Router::new()
+        .route("/v1/cluster/add_voter", post(add_voter_handler))
+        .route("/v1/cluster/remove_node", post(remove_node_handler))
+        .route("/v1/cluster/transfer_leader", post(transfer_leader_handler))
+        .layer(AddExtensionLayer::new(mn.clone()));
+
+    let (status, _) = post!(router, "/v1/cluster/transfer_leader?node_id=0");
+    assert_eq!(StatusCode::OK, status);
+
+    let (status, body) = post!(router, "/v1/cluster/add_voter?node_id=7");
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert!(body.contains("node 7 is not in cluster, its address is required to add it"));
+
+    let (status, body) = post!(router, "/v1/cluster/remove_node?node_id=0");
+    assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, status);
+    assert!(body.contains("can not remove the last voter 0"));
+
+    let (status, _) = post!(router, "/v1/cluster/transfer_leader");
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+
+    mn.stop().await?;
+    Ok(())
+}
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod cluster;
 pub mod config;
 pub mod health;
 
+#[cfg(test)]
+mod cluster_test;
 #[cfg(test)]
 mod config_test;
 #[cfg(test)]
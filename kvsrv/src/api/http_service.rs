@@ -12,23 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use axum::handler::get;
+use axum::handler::post;
 use axum::AddExtensionLayer;
 use axum::Router;
 use common_exception::Result;
 
 use crate::configs::Config;
+use crate::meta_service::MetaNode;
 
 pub struct HttpService {
     cfg: Config,
+    meta_node: Arc<MetaNode>,
 }
 
 // build axum router
 macro_rules! build_router {
-    ($cfg: expr) => {
+    ($cfg: expr, $meta_node: expr) => {
         Router::new()
             .route("/v1/health", get(super::http::v1::health::health_handler))
             .route("/v1/config", get(super::http::v1::config::config_handler))
+            .route(
+                "/v1/cluster/add_voter",
+                post(super::http::v1::cluster::add_voter_handler),
+            )
+            .route(
+                "/v1/cluster/remove_node",
+                post(super::http::v1::cluster::remove_node_handler),
+            )
+            .route(
+                "/v1/cluster/transfer_leader",
+                post(super::http::v1::cluster::transfer_leader_handler),
+            )
             .route(
                 "/debug/home",
                 get(super::http::debug::home::debug_home_handler),
@@ -38,16 +55,17 @@ macro_rules! build_router {
                 get(super::http::debug::pprof::debug_pprof_handler),
             )
             .layer(AddExtensionLayer::new($cfg.clone()))
+            .layer(AddExtensionLayer::new($meta_node.clone()))
     };
 }
 
 impl HttpService {
-    pub fn create(cfg: Config) -> Box<Self> {
-        Box::new(HttpService { cfg })
+    pub fn create(cfg: Config, meta_node: Arc<MetaNode>) -> Box<Self> {
+        Box::new(HttpService { cfg, meta_node })
     }
 
     pub async fn start(&mut self) -> Result<()> {
-        let app = build_router!(self.cfg.clone());
+        let app = build_router!(self.cfg.clone(), self.meta_node.clone());
 
         let conf = self.cfg.clone();
         let tls_cert = conf.admin_tls_server_cert;
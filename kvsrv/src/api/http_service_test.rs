@@ -19,7 +19,8 @@ use common_base::tokio;
 use common_exception::Result;
 
 use crate::api::HttpService;
-use crate::configs::Config;
+use crate::meta_service::MetaNode;
+use crate::tests::service::new_test_context;
 use crate::tests::tls_constants::TEST_CA_CERT;
 use crate::tests::tls_constants::TEST_CN_NAME;
 use crate::tests::tls_constants::TEST_SERVER_CERT;
@@ -28,14 +29,17 @@ use crate::tests::tls_constants::TEST_SERVER_KEY;
 // TODO(zhihanz) add tls fail case
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_http_service_tls_server() -> Result<()> {
-    let mut conf = Config::empty();
+    let tc = new_test_context();
+    let mn = MetaNode::boot(0, &tc.config.raft_config).await?;
+
+    let mut conf = tc.config.clone();
     let addr_str = "127.0.0.1:0";
 
     conf.admin_tls_server_key = TEST_SERVER_KEY.to_owned();
     conf.admin_tls_server_cert = TEST_SERVER_CERT.to_owned();
     conf.admin_api_address = addr_str.to_owned();
 
-    let mut srv = HttpService::create(conf);
+    let mut srv = HttpService::create(conf, mn);
 
     // test cert is issued for "localhost"
     let url = format!("https://{}:0/v1/health", TEST_CN_NAME);
@@ -18,6 +18,7 @@ use common_tracing::init_tracing_with_file;
 use kvsrv::api::FlightServer;
 use kvsrv::api::HttpService;
 use kvsrv::configs::Config;
+use kvsrv::meta_service::MetaNode;
 use kvsrv::metrics::MetricService;
 use log::info;
 use structopt::StructOpt;
@@ -53,9 +54,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         info!("Metric API server listening on {}", conf.metric_api_address);
     }
 
+    let meta_node = MetaNode::start(&conf.raft_config).await?;
+
     // HTTP API service.
     {
-        let mut srv = HttpService::create(conf.clone());
+        let mut srv = HttpService::create(conf.clone(), meta_node.clone());
         info!("HTTP API server listening on {}", conf.admin_api_address);
         tokio::spawn(async move {
             srv.start().await.expect("HTTP: admin api error");
@@ -64,7 +67,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Flight API service.
     {
-        let srv = FlightServer::create(conf.clone());
+        let srv = FlightServer::create(conf.clone(), meta_node);
         info!(
             "Databend-meta API server listening on {}",
             conf.flight_api_address
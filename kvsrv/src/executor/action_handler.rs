@@ -67,6 +67,11 @@ impl ActionHandler {
             StoreDoAction::GetTable(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::GetTables(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::GetTableExt(a) => s.serialize(self.handle(a).await?),
+
+            // dfs file, which is only served by the dfs nodes
+            StoreDoAction::FileSize(_) | StoreDoAction::RemoveReplica(_) => Err(
+                ErrorCode::UnImplement("kvsrv does not store files, connect to a dfs node"),
+            ),
         }
     }
 }
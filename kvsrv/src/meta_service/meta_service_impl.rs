@@ -13,7 +13,7 @@
 // limitations under the License.
 
 //! Meta service impl a grpc server that serves both raft protocol: append_entries, vote and install_snapshot.
-//! It also serves RPC for user-data access.
+//! It also serves RPC for user-data access and for membership changes.
 
 use std::convert::TryInto;
 use std::sync::Arc;
@@ -23,9 +23,12 @@ use common_tracing::tracing;
 
 use crate::meta_service::GetReply;
 use crate::meta_service::GetReq;
+use crate::meta_service::MembershipReply;
+use crate::meta_service::MembershipReq;
 use crate::meta_service::MetaNode;
 use crate::meta_service::MetaService;
 use crate::meta_service::RaftMes;
+use crate::meta_service::TransferLeaderReq;
 
 pub struct MetaServiceImpl {
     pub meta_node: Arc<MetaNode>,
@@ -86,6 +89,69 @@ impl MetaService for MetaServiceImpl {
         Ok(tonic::Response::new(rst))
     }
 
+    /// Handles a request to promote a node to voter.
+    /// This node must be leader or an error returned.
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn add_voter(
+        &self,
+        request: tonic::Request<MembershipReq>,
+    ) -> Result<tonic::Response<MembershipReply>, tonic::Status> {
+        common_tracing::extract_remote_span_as_parent(&request);
+
+        let req = request.into_inner();
+        let addr = if req.address.is_empty() {
+            None
+        } else {
+            Some(req.address)
+        };
+
+        self.meta_node
+            .add_voter_to_local_leader(req.node_id, addr)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(MembershipReply {}))
+    }
+
+    /// Handles a request to remove a node.
+    /// This node must be leader or an error returned.
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn remove_node(
+        &self,
+        request: tonic::Request<MembershipReq>,
+    ) -> Result<tonic::Response<MembershipReply>, tonic::Status> {
+        common_tracing::extract_remote_span_as_parent(&request);
+
+        let req = request.into_inner();
+        self.meta_node
+            .remove_node_from_local_leader(req.node_id)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(MembershipReply {}))
+    }
+
+    /// Handles a request to transfer leadership.
+    /// A request with `log_index` is sent by the leader to the target node, which starts an election.
+    /// Otherwise it is from an admin client, and is forwarded to the leader if this node is not the leader.
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn transfer_leader(
+        &self,
+        request: tonic::Request<TransferLeaderReq>,
+    ) -> Result<tonic::Response<MembershipReply>, tonic::Status> {
+        common_tracing::extract_remote_span_as_parent(&request);
+
+        let req = request.into_inner();
+        let rst = if req.log_index > 0 {
+            self.meta_node.campaign(req.log_index).await
+        } else {
+            self.meta_node.transfer_leader(req.node_id).await
+        };
+        rst.map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(MembershipReply {}))
+    }
+
     #[tracing::instrument(level = "info", skip(self, request))]
     async fn append_entries(
         &self,
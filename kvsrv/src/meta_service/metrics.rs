@@ -0,0 +1,20 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub static METRIC_RAFT_IS_LEADER: &str = "raft.is_leader";
+pub static METRIC_RAFT_CURRENT_TERM: &str = "raft.current_term";
+pub static METRIC_RAFT_LAST_LOG_INDEX: &str = "raft.last_log_index";
+pub static METRIC_RAFT_LAST_APPLIED: &str = "raft.last_applied";
+pub static METRIC_RAFT_MEMBERS: &str = "raft.members";
+pub static METRIC_RAFT_LEADER_CHANGES: &str = "raft.leader_changes";
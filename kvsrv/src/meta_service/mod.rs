@@ -24,13 +24,18 @@ pub use crate::protobuf::meta_service_server::MetaService;
 pub use crate::protobuf::meta_service_server::MetaServiceServer;
 pub use crate::protobuf::GetReply;
 pub use crate::protobuf::GetReq;
+pub use crate::protobuf::MembershipReply;
+pub use crate::protobuf::MembershipReq;
 pub use crate::protobuf::RaftMes;
+pub use crate::protobuf::TransferLeaderReq;
 
 pub mod errors;
 pub mod meta_service_impl;
 pub mod network;
 pub mod raftmeta;
 
+mod metrics;
+
 #[cfg(test)]
 mod meta_service_impl_test;
 #[cfg(test)]
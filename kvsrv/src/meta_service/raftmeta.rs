@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use std::io::Cursor;
 use std::ops::Bound;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_raft::async_trait::async_trait;
 use async_raft::config::Config;
@@ -24,12 +25,15 @@ use async_raft::raft::ClientWriteRequest;
 use async_raft::raft::Entry;
 use async_raft::raft::EntryPayload;
 use async_raft::raft::MembershipConfig;
+use async_raft::raft::VoteRequest;
 use async_raft::storage::CurrentSnapshotData;
 use async_raft::storage::HardState;
 use async_raft::storage::InitialState;
+use async_raft::ChangeConfigError;
 use async_raft::ClientWriteError;
 use async_raft::Raft;
 use async_raft::RaftMetrics;
+use async_raft::RaftNetwork;
 use async_raft::RaftStorage;
 use async_raft::SnapshotMeta;
 use async_raft::SnapshotPolicy;
@@ -59,13 +63,24 @@ use common_raft_store::state_machine::StateMachine;
 use common_sled_store::get_sled_db;
 use common_tracing::tracing;
 use common_tracing::tracing::Instrument;
-
+use metrics::counter;
+use metrics::gauge;
+use tonic::transport::Channel;
+
+use crate::meta_service::metrics::METRIC_RAFT_CURRENT_TERM;
+use crate::meta_service::metrics::METRIC_RAFT_IS_LEADER;
+use crate::meta_service::metrics::METRIC_RAFT_LAST_APPLIED;
+use crate::meta_service::metrics::METRIC_RAFT_LAST_LOG_INDEX;
+use crate::meta_service::metrics::METRIC_RAFT_LEADER_CHANGES;
+use crate::meta_service::metrics::METRIC_RAFT_MEMBERS;
+use crate::meta_service::MembershipReq;
 use crate::meta_service::MetaServiceClient;
 use crate::meta_service::MetaServiceImpl;
 use crate::meta_service::MetaServiceServer;
 use crate::meta_service::Network;
 use crate::meta_service::RetryableError;
 use crate::meta_service::ShutdownError;
+use crate::meta_service::TransferLeaderReq;
 
 /// An storage system implementing the `async_raft::RaftStorage` trait.
 ///
@@ -543,6 +558,12 @@ impl RaftStorage<LogEntry, AppliedState> for MetaRaftStore {
 // MetaRaft is a impl of the generic Raft handling meta data R/W.
 pub type MetaRaft = Raft<LogEntry, AppliedState, Network, MetaRaftStore>;
 
+/// The number of elections a leader tries to let the target win, when transferring leadership.
+const TRANSFER_LEADER_RETRIES: usize = 3;
+
+/// The time to wait for the target to catch up logs, and for the new leader to be elected.
+const TRANSFER_LEADER_TIMEOUT_MS: u64 = 5000;
+
 // MetaNode is the container of meta data related components and threads, such as storage, the raft node and a raft-state monitor.
 pub struct MetaNode {
     // metrics subscribes raft state changes. The most important field is the leader node id, to which all write operations should be forward.
@@ -735,6 +756,34 @@ impl MetaNode {
         Ok(())
     }
 
+    /// Start a kvsrv node by the mode in config:
+    /// - boot mode: create the first node in a new cluster.
+    /// - single mode: open an existent node, or create and boot a single node cluster.
+    /// - open mode: open an existent node.
+    // TODO(xp): join mode: create a new node to join a cluster.
+    #[tracing::instrument(level = "info", skip(config), fields(config_id=config.config_id.as_str()))]
+    pub async fn start(config: &RaftConfig) -> common_exception::Result<Arc<MetaNode>> {
+        tracing::info!(
+            "Starting MetaNode boot:{} single: {} with config: {:?}",
+            config.boot,
+            config.single,
+            config
+        );
+
+        let mn = if config.boot {
+            Self::boot(0, config).await?
+        } else if config.single {
+            let (mn, _is_open) =
+                Self::open_create_boot(config, Some(()), Some(()), Some(())).await?;
+            mn
+        } else {
+            Self::open(config).await?
+        };
+
+        tracing::info!("Done starting MetaNode: {:?}", config);
+        Ok(mn)
+    }
+
     /// Start a kvsrv node from initialized store.
     #[tracing::instrument(level = "info", skip(config), fields(config_id=config.config_id.as_str()))]
     pub async fn open(config: &RaftConfig) -> common_exception::Result<Arc<MetaNode>> {
@@ -829,6 +878,7 @@ impl MetaNode {
         let h = tokio::task::spawn(
             {
                 async move {
+                    let mut last_leader = None;
                     loop {
                         let changed = tokio::select! {
                             _ = running_rx.changed() => {
@@ -840,6 +890,7 @@ impl MetaNode {
                         };
                         if changed.is_ok() {
                             let mm = metrics_rx.borrow().clone();
+                            MetaNode::report_metrics(&mm, &mut last_leader);
                             if let Some(cur) = mm.current_leader {
                                 if cur == mn.sto.id {
                                     // TODO: check result
@@ -868,6 +919,28 @@ impl MetaNode {
         jh.push(h);
     }
 
+    /// Export the raft state of this node as prometheus metrics.
+    fn report_metrics(mm: &RaftMetrics, last_leader: &mut Option<NodeId>) {
+        let node = mm.id.to_string();
+        let is_leader = if mm.current_leader == Some(mm.id) {
+            1.0
+        } else {
+            0.0
+        };
+        let members = mm.membership_config.members.len();
+
+        gauge!(METRIC_RAFT_IS_LEADER, is_leader, "node" => node.clone());
+        gauge!(METRIC_RAFT_CURRENT_TERM, mm.current_term as f64, "node" => node.clone());
+        gauge!(METRIC_RAFT_LAST_LOG_INDEX, mm.last_log_index as f64, "node" => node.clone());
+        gauge!(METRIC_RAFT_LAST_APPLIED, mm.last_applied as f64, "node" => node.clone());
+        gauge!(METRIC_RAFT_MEMBERS, members as f64, "node" => node.clone());
+
+        if mm.current_leader.is_some() && mm.current_leader != *last_leader {
+            counter!(METRIC_RAFT_LEADER_CHANGES, 1, "node" => node);
+            *last_leader = mm.current_leader;
+        }
+    }
+
     /// Boot up the first node to create a cluster.
     /// For every cluster this func should be called exactly once.
     /// When a node is initialized with boot or boot_non_voter, start it with kvsrv::new().
@@ -949,6 +1022,289 @@ impl MetaNode {
         Ok(())
     }
 
+    /// Promote a node to voter.
+    /// If the node has not yet been added into this cluster, it is added with the raft api address `addr`.
+    /// The request is forwarded to the leader if this node is not the leader.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn add_voter(
+        &self,
+        node_id: NodeId,
+        addr: Option<String>,
+    ) -> common_exception::Result<()> {
+        let leader = self.get_leader().await;
+        if leader == self.sto.id {
+            return self.add_voter_to_local_leader(node_id, addr).await;
+        }
+
+        let mut client = self.node_client(leader).await?;
+        client
+            .add_voter(MembershipReq {
+                node_id,
+                address: addr.unwrap_or_default(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a node, e.g., a dead one, from this cluster.
+    /// It is removed from the voters and then from the nodes in meta data, thus it won't be added back as a non-voter.
+    /// The request is forwarded to the leader if this node is not the leader.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn remove_node(&self, node_id: NodeId) -> common_exception::Result<()> {
+        let leader = self.get_leader().await;
+        if leader == self.sto.id {
+            return self.remove_node_from_local_leader(node_id).await;
+        }
+
+        let mut client = self.node_client(leader).await?;
+        client
+            .remove_node(MembershipReq {
+                node_id,
+                address: "".to_string(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Transfer leadership to voter `node_id`.
+    /// The request is forwarded to the leader if this node is not the leader.
+    /// The target wins an election in most cases but not always, thus the leader retries a few times.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn transfer_leader(&self, node_id: NodeId) -> common_exception::Result<()> {
+        let mut last_err = None;
+
+        for i in 0..TRANSFER_LEADER_RETRIES {
+            let leader = self.get_leader().await;
+            if leader == node_id {
+                return Ok(());
+            }
+
+            if leader != self.sto.id {
+                let mut client = self.node_client(leader).await?;
+                client
+                    .transfer_leader(TransferLeaderReq {
+                        node_id,
+                        log_index: 0,
+                    })
+                    .await?;
+                return Ok(());
+            }
+
+            match self.transfer_leader_from_local_leader(node_id).await {
+                Ok(_) => return Ok(()),
+                Err(e) if e.code() == ErrorCode::UnknownNode("").code() => return Err(e),
+                Err(e) => {
+                    tracing::warn!("transfer leader to {}, attempt {}: {}", node_id, i, e);
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| {
+            ErrorCode::MetaServiceError(format!("fail to transfer leader to {}", node_id))
+        }))
+    }
+
+    /// Promote a node to voter through local raft node, which must be the leader.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn add_voter_to_local_leader(
+        &self,
+        node_id: NodeId,
+        addr: Option<String>,
+    ) -> common_exception::Result<()> {
+        if self.get_node(&node_id).await?.is_none() {
+            let addr = addr.ok_or_else(|| {
+                ErrorCode::UnknownNode(format!(
+                    "node {} is not in cluster, its address is required to add it",
+                    node_id
+                ))
+            })?;
+            self.add_node(node_id, addr).await?;
+        }
+
+        let mut voters = self.voters().await?;
+        if voters.contains(&node_id) {
+            return Ok(());
+        }
+
+        // Let it catch up logs as a non-voter before promoting it,
+        // otherwise the cluster may be unavailable until the new voter is up to date.
+        // `Noop` means it is already a non-voter, e.g., one added by `add_configured_non_voters`.
+        match self.raft.add_non_voter(node_id).await {
+            Ok(_) | Err(ChangeConfigError::Noop) => {}
+            Err(e) => {
+                return Err(ErrorCode::MetaServiceError(format!(
+                    "fail to add non-voter {}: {:?}",
+                    node_id, e
+                )));
+            }
+        }
+
+        voters.insert(node_id);
+        self.raft.change_membership(voters).await.map_err(|e| {
+            ErrorCode::MetaServiceError(format!("fail to add voter {}: {:?}", node_id, e))
+        })?;
+
+        tracing::info!("voter is added: {}", node_id);
+        Ok(())
+    }
+
+    /// Remove a node through local raft node, which must be the leader.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn remove_node_from_local_leader(
+        &self,
+        node_id: NodeId,
+    ) -> common_exception::Result<()> {
+        let mut voters = self.voters().await?;
+        if voters.remove(&node_id) {
+            if voters.is_empty() {
+                return Err(ErrorCode::MetaServiceError(format!(
+                    "can not remove the last voter {}",
+                    node_id
+                )));
+            }
+
+            self.raft.change_membership(voters).await.map_err(|e| {
+                ErrorCode::MetaServiceError(format!("fail to remove voter {}: {:?}", node_id, e))
+            })?;
+            tracing::info!("voter is removed: {}", node_id);
+        }
+
+        // If this node removed itself, the write is forwarded to the new leader.
+        self.write(LogEntry {
+            txid: None,
+            cmd: Cmd::RemoveNode { node_id },
+        })
+        .await?;
+
+        tracing::info!("node is removed: {}", node_id);
+        Ok(())
+    }
+
+    /// Transfer leadership through local raft node, which must be the leader.
+    /// The target node is asked to campaign once it has all the logs of this node,
+    /// then it waits for a new leader to be elected.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn transfer_leader_from_local_leader(
+        &self,
+        node_id: NodeId,
+    ) -> common_exception::Result<()> {
+        let voters = self.voters().await?;
+        if !voters.contains(&node_id) {
+            return Err(ErrorCode::UnknownNode(format!(
+                "node {} is not a voter",
+                node_id
+            )));
+        }
+
+        if node_id == self.sto.id {
+            return Ok(());
+        }
+
+        let metrics = self.metrics_rx.borrow().clone();
+
+        let mut client = self.node_client(node_id).await?;
+        client
+            .transfer_leader(TransferLeaderReq {
+                node_id,
+                log_index: metrics.last_log_index,
+            })
+            .await?;
+
+        let elected = self
+            .raft
+            .wait(Some(Duration::from_millis(TRANSFER_LEADER_TIMEOUT_MS)))
+            .metrics(
+                |x| x.current_term > metrics.current_term && x.current_leader.is_some(),
+                "new leader is elected",
+            )
+            .await
+            .map_err(|e| ErrorCode::MetaServiceError(e.to_string()))?;
+
+        match elected.current_leader {
+            Some(leader) if leader == node_id => {
+                tracing::info!("leadership is transferred to {}", node_id);
+                Ok(())
+            }
+            leader => Err(ErrorCode::MetaServiceError(format!(
+                "leader {:?} is elected instead of {}",
+                leader, node_id
+            ))),
+        }
+    }
+
+    /// Starts an election on this node, when the leader is transferring leadership to it.
+    ///
+    /// async-raft does not provide a way to let a follower start an election at once.
+    /// Instead this node requests votes for the next term from the other voters on behalf of its raft node:
+    /// the leader steps down once it sees the higher term,
+    /// and a voter that granted this node won't vote for another node in that term.
+    /// When its election timeout fires, which is very likely earlier than the others that just granted,
+    /// the raft node campaigns for that term and wins.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn campaign(&self, log_index: u64) -> common_exception::Result<()> {
+        // The voters reject a candidate without the latest logs.
+        self.raft
+            .wait(Some(Duration::from_millis(TRANSFER_LEADER_TIMEOUT_MS)))
+            .log(log_index, "catch up with the leader")
+            .await
+            .map_err(|e| ErrorCode::MetaServiceError(e.to_string()))?;
+
+        let term = self.metrics_rx.borrow().current_term + 1;
+        let last_log_id = match self.sto.log.last()? {
+            Some((_index, ent)) => ent.log_id,
+            None => (0, 0).into(),
+        };
+
+        let voters = self.voters().await?;
+        let network = Network::new(self.sto.clone());
+
+        // This node votes for itself.
+        let mut granted = 1;
+        for voter in voters.iter().filter(|x| **x != self.sto.id) {
+            let req = VoteRequest::new(term, self.sto.id, last_log_id);
+            match network.vote(*voter, req).await {
+                Ok(resp) if resp.vote_granted => granted += 1,
+                Ok(resp) => tracing::info!("vote is rejected by {}: {:?}", voter, resp),
+                Err(e) => tracing::warn!("fail to request vote from {}: {}", voter, e),
+            }
+        }
+
+        if granted <= voters.len() / 2 {
+            return Err(ErrorCode::MetaServiceError(format!(
+                "node {} is granted by {} of {} voters in term {}",
+                self.sto.id,
+                granted,
+                voters.len(),
+                term
+            )));
+        }
+
+        tracing::info!("node {} is granted in term {}", self.sto.id, term);
+        Ok(())
+    }
+
+    /// Returns the voters in the latest membership config.
+    async fn voters(&self) -> common_exception::Result<BTreeSet<NodeId>> {
+        let ms = self
+            .sto
+            .get_membership_config()
+            .await
+            .map_err(|e| ErrorCode::MetaServiceError(e.to_string()))?;
+        Ok(ms.members)
+    }
+
+    async fn node_client(
+        &self,
+        node_id: NodeId,
+    ) -> common_exception::Result<MetaServiceClient<Channel>> {
+        let addr = self.sto.get_node_addr(&node_id).await?;
+        let client = MetaServiceClient::connect(format!("http://{}", addr))
+            .await
+            .map_err(|e| ErrorCode::CannotConnectNode(e.to_string()))?;
+        Ok(client)
+    }
+
     // get a file from local meta state, most business logic without strong consistency requirement should use this to access meta.
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn get_file(&self, key: &str) -> common_exception::Result<Option<String>> {
@@ -19,6 +19,7 @@ use async_raft::RaftMetrics;
 use async_raft::State;
 use common_base::tokio;
 use common_base::tokio::time::Duration;
+use common_exception::ErrorCode;
 use common_metatypes::Cmd;
 use common_metatypes::LogEntry;
 use common_metatypes::MatchSeq;
@@ -517,6 +518,156 @@ async fn test_meta_node_restart_single_node() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 5)]
+async fn test_meta_node_add_voter() -> anyhow::Result<()> {
+    // - Start a leader and 2 non-voters.
+    // - Promote non-voter 1 on the leader.
+    // - Promote non-voter 2 on itself, the request is forwarded to the leader.
+    // - Promoting an unknown node without address fails.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_nid0, tc0) = setup_leader().await?;
+    let mn0 = tc0.meta_nodes[0].clone();
+
+    let (_nid1, tc1) = setup_non_voter(mn0.clone(), 1).await?;
+    let mn1 = tc1.meta_nodes[0].clone();
+
+    let (_nid2, tc2) = setup_non_voter(mn0.clone(), 2).await?;
+    let mn2 = tc2.meta_nodes[0].clone();
+
+    tracing::info!("--- add voter 1 on leader");
+    {
+        mn0.add_voter(1, None).await?;
+        wait_for_state(&mn1, State::Follower).await?;
+        wait_for_voters(&mn0, btreeset![0, 1]).await?;
+    }
+
+    tracing::info!("--- add voter 2 on non-voter 2");
+    {
+        mn2.add_voter(2, None).await?;
+        wait_for_state(&mn2, State::Follower).await?;
+        wait_for_voters(&mn0, btreeset![0, 1, 2]).await?;
+    }
+
+    tracing::info!("--- adding an existent voter is a no-op");
+    {
+        mn1.add_voter(1, None).await?;
+        wait_for_voters(&mn0, btreeset![0, 1, 2]).await?;
+    }
+
+    tracing::info!("--- add unknown node without address");
+    {
+        let got = mn0.add_voter(7, None).await;
+        let err = got.unwrap_err();
+        assert_eq!(ErrorCode::UnknownNode("").code(), err.code());
+        assert_eq!(
+            "node 7 is not in cluster, its address is required to add it",
+            err.message()
+        );
+    }
+
+    assert_set_file_synced(vec![mn0.clone(), mn1.clone(), mn2.clone()], "foo").await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 5)]
+async fn test_meta_node_remove_node() -> anyhow::Result<()> {
+    // - Bring up a cluster of 3 voters.
+    // - Stop node 2 and remove it on a follower, the request is forwarded to the leader.
+    // - The cluster keeps working with 2 voters.
+    // - Removing the last voter fails.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_nlog, tcs) = setup_cluster(btreeset![0, 1, 2], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+    let mn0 = all[0].clone();
+    let mn1 = all[1].clone();
+
+    tracing::info!("--- stop and remove node 2");
+    {
+        all[2].stop().await?;
+
+        mn1.remove_node(2).await?;
+        wait_for_voters(&mn0, btreeset![0, 1]).await?;
+        wait_for_voters(&mn1, btreeset![0, 1]).await?;
+
+        assert!(mn0.get_node(&2).await?.is_none());
+        assert!(!mn0.sto.list_non_voters().await.contains(&2));
+    }
+
+    assert_set_file_synced(vec![mn0.clone(), mn1.clone()], "foo").await?;
+
+    tracing::info!("--- remove node 1, then the last voter");
+    {
+        mn0.remove_node(1).await?;
+        wait_for_voters(&mn0, btreeset![0]).await?;
+
+        let got = mn0.remove_node(0).await;
+        assert_eq!(
+            "can not remove the last voter 0",
+            got.unwrap_err().message()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 5)]
+async fn test_meta_node_transfer_leader() -> anyhow::Result<()> {
+    // - Bring up a cluster of 3 voters and 1 non-voter.
+    // - Transferring to the current leader is a no-op.
+    // - Transfer to node 1 on node 2, the request is forwarded to the leader.
+    // - Transfer back to node 0 on the new leader.
+    // - Transferring to a non-voter fails.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_nlog, tcs) = setup_cluster(btreeset![0, 1, 2], btreeset![3]).await?;
+    let all = test_context_nodes(&tcs);
+
+    tracing::info!("--- transfer to the current leader");
+    {
+        all[0].transfer_leader(0).await?;
+        assert_eq!(0, all[0].get_leader().await);
+    }
+
+    tracing::info!("--- transfer to node 1 on node 2");
+    {
+        all[2].transfer_leader(1).await?;
+        for mn in all.iter() {
+            wait_for_current_leader(mn, 1).await?;
+        }
+    }
+
+    assert_set_file_applied(all.clone(), "foo").await?;
+
+    tracing::info!("--- transfer back to node 0 on node 1");
+    {
+        all[1].transfer_leader(0).await?;
+        for mn in all.iter() {
+            wait_for_current_leader(mn, 0).await?;
+        }
+    }
+
+    assert_set_file_applied(all.clone(), "bar").await?;
+
+    tracing::info!("--- transfer to non-voter 3");
+    {
+        let got = all[1].transfer_leader(3).await;
+        let err = got.unwrap_err();
+        assert_eq!(ErrorCode::UnknownNode("").code(), err.code());
+        assert_eq!("node 3 is not a voter", err.message());
+    }
+
+    Ok(())
+}
+
 /// Setup a cluster with several voter and several non_voter
 /// The node id 0 must be in `voters` and node 0 is elected as leader.
 async fn setup_cluster(
@@ -672,6 +823,30 @@ async fn assert_set_file_synced(meta_nodes: Vec<Arc<MetaNode>>, key: &str) -> an
     Ok(1)
 }
 
+/// Set a file on the leader and check all nodes applied it.
+/// Unlike `assert_set_file_synced`, it does not expect the leader has applied all logs,
+/// e.g., the blank log of a just elected leader.
+async fn assert_set_file_applied(meta_nodes: Vec<Arc<MetaNode>>, key: &str) -> anyhow::Result<()> {
+    let leader_id = meta_nodes[0].get_leader().await;
+    let leader = meta_nodes[leader_id as usize].clone();
+
+    leader
+        .write_to_local_leader(LogEntry {
+            txid: None,
+            cmd: Cmd::SetFile {
+                key: key.to_string(),
+                value: key.to_string(),
+            },
+        })
+        .await??;
+
+    let last_log_index = leader.raft.metrics().borrow().last_log_index;
+    assert_applied_index(meta_nodes.clone(), last_log_index).await?;
+    assert_get_file(meta_nodes.clone(), key, key).await?;
+
+    Ok(())
+}
+
 /// Write one log on every node, check all nodes replicated the log.
 /// Returns the number log committed.
 async fn assert_set_file_on_specified_node_synced(
@@ -762,6 +937,15 @@ where T: Fn(&RaftMetrics) -> bool + Send {
     Ok(metrics)
 }
 
+/// Wait for the voters in membership config to become `voters`.
+async fn wait_for_voters(mn: &MetaNode, voters: BTreeSet<NodeId>) -> anyhow::Result<RaftMetrics> {
+    wait_for(mn, move |m| {
+        m.membership_config.members == voters
+            && m.membership_config.members_after_consensus.is_none()
+    })
+    .await
+}
+
 /// Make a default timeout for wait() for test.
 fn timeout() -> Option<Duration> {
     Some(Duration::from_millis(10000))
@@ -41,7 +41,8 @@ pub async fn start_kvsrv() -> Result<(KVSrvTestContext, String)> {
 }
 
 pub async fn start_kvsrv_with_context(tc: &mut KVSrvTestContext) -> Result<()> {
-    let srv = FlightServer::create(tc.config.clone());
+    let mn = MetaNode::start(&tc.config.raft_config).await?;
+    let srv = FlightServer::create(tc.config.clone(), mn);
     let (stop_tx, fin_rx) = srv.start().await?;
 
     tc.channels = Some((stop_tx, fin_rx));
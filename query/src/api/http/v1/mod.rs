@@ -20,8 +20,12 @@ mod config_test;
 mod health_test;
 #[cfg(test)]
 mod logs_test;
+#[cfg(test)]
+mod query_test;
 
 pub mod cluster;
 pub mod config;
 pub mod health;
 pub mod logs;
+pub mod query;
+pub mod query_manager;
@@ -0,0 +1,178 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::body::Bytes;
+use axum::body::Full;
+use axum::extract::Extension;
+use axum::extract::Json;
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::TypedHeader;
+use axum::headers::authorization::Basic;
+use axum::headers::Authorization;
+use axum::http::Response;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::api::http::v1::query_manager::HttpQueryManagerRef;
+use crate::api::http::v1::query_manager::QueryError;
+use crate::api::http::v1::query_manager::QueryResponse;
+
+const DEFAULT_PAGE_SIZE: usize = 10000;
+const DEFAULT_WAIT_TIME_SECS: u64 = 10;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct QueryRequest {
+    pub sql: String,
+    /// Run the query in the session of a previous query, a new session is created if absent.
+    pub session_id: Option<String>,
+    /// The max number of rows in a page.
+    pub page_size: Option<usize>,
+    /// Seconds to wait for the first page.
+    pub wait_time_secs: Option<u64>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct PageParams {
+    /// Seconds to wait for the page.
+    pub wait_time_secs: Option<u64>,
+}
+
+pub type BasicAuth = Option<TypedHeader<Authorization<Basic>>>;
+
+pub struct QueryTemplate {
+    result: Result<Option<QueryResponse>>,
+}
+
+impl IntoResponse for QueryTemplate {
+    type Body = Full<Bytes>;
+    type BodyError = Infallible;
+
+    fn into_response(self) -> Response<Self::Body> {
+        match self.result {
+            Ok(Some(response)) => Json(response).into_response(),
+            Ok(None) => Response::builder()
+                .status(StatusCode::OK)
+                .body(Full::from(""))
+                .unwrap(),
+            Err(cause) => {
+                let status = match cause.code() {
+                    code if code == ErrorCode::BadArguments("").code() => StatusCode::BAD_REQUEST,
+                    code if code == ErrorCode::AuthenticateFailure("").code() => {
+                        StatusCode::UNAUTHORIZED
+                    }
+                    code if code == ErrorCode::UnknownQuery("").code()
+                        || code == ErrorCode::UnknownSession("").code() =>
+                    {
+                        StatusCode::NOT_FOUND
+                    }
+                    _ => StatusCode::INTERNAL_SERVER_ERROR,
+                };
+
+                let mut response = Json(QueryError::from_error_code(&cause)).into_response();
+                *response.status_mut() = status;
+                response
+            }
+        }
+    }
+}
+
+fn credentials(auth: BasicAuth) -> Option<(String, String)> {
+    auth.map(|TypedHeader(Authorization(basic))| {
+        (basic.username().to_string(), basic.password().to_string())
+    })
+}
+
+// POST /v1/query
+// Start a query, and return its id with the first page of the result.
+// request: QueryRequest, the user is given by the basic auth header
+// return: QueryResponse, `next_uri` is the page to fetch next, none if the result is complete
+pub async fn query_handler(
+    queries: Extension<HttpQueryManagerRef>,
+    auth: BasicAuth,
+    Json(request): Json<QueryRequest>,
+) -> QueryTemplate {
+    let queries = queries.0;
+    QueryTemplate {
+        result: start_query(queries, credentials(auth), request)
+            .await
+            .map(Some),
+    }
+}
+
+// GET /v1/query/{id}/page/{page}
+// Fetch a page of the query result, the last fetched page can be fetched again.
+// return: QueryResponse
+pub async fn query_page_handler(
+    queries: Extension<HttpQueryManagerRef>,
+    auth: BasicAuth,
+    Path((id, page)): Path<(String, usize)>,
+    Query(params): Query<PageParams>,
+) -> QueryTemplate {
+    let queries = queries.0;
+    QueryTemplate {
+        result: fetch_page(queries, credentials(auth), &id, page, params)
+            .await
+            .map(Some),
+    }
+}
+
+// DELETE /v1/query/{id}
+// Cancel the query and release its buffered result.
+pub async fn query_kill_handler(
+    queries: Extension<HttpQueryManagerRef>,
+    auth: BasicAuth,
+    Path(id): Path<String>,
+) -> QueryTemplate {
+    let queries = queries.0;
+    let result = queries
+        .authenticate(credentials(auth))
+        .and_then(|user| queries.kill_query(&id, &user));
+
+    QueryTemplate {
+        result: result.map(|_| None),
+    }
+}
+
+async fn start_query(
+    queries: HttpQueryManagerRef,
+    credentials: Option<(String, String)>,
+    request: QueryRequest,
+) -> Result<QueryResponse> {
+    let user = queries.authenticate(credentials)?;
+    let page_size = request.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    let query = queries.create_query(user, request.session_id, request.sql, page_size)?;
+
+    let wait = request.wait_time_secs.unwrap_or(DEFAULT_WAIT_TIME_SECS);
+    query.get_page(0, Duration::from_secs(wait)).await
+}
+
+async fn fetch_page(
+    queries: HttpQueryManagerRef,
+    credentials: Option<(String, String)>,
+    id: &str,
+    page: usize,
+    params: PageParams,
+) -> Result<QueryResponse> {
+    let user = queries.authenticate(credentials)?;
+    let query = queries.get_query(id, &user)?;
+
+    let wait = params.wait_time_secs.unwrap_or(DEFAULT_WAIT_TIME_SECS);
+    query.get_page(page, Duration::from_secs(wait)).await
+}
@@ -0,0 +1,541 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::tokio;
+use common_base::tokio::sync::mpsc;
+use common_base::tokio::task::JoinHandle;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::DataColumn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_infallible::RwLock;
+use futures::StreamExt;
+use serde_json::Value as JsonValue;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::SessionManagerRef;
+use crate::sessions::SessionRef;
+use crate::sql::PlanParser;
+
+pub type JsonRow = Vec<JsonValue>;
+pub type HttpQueryManagerRef = Arc<HttpQueryManager>;
+
+// The producer stops reading the result stream once this many pages wait for the client.
+const MAX_BUFFERED_PAGES: usize = 4;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub enum ExecuteState {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct QueryColumn {
+    pub name: String,
+    pub data_type: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct QueryError {
+    pub code: u16,
+    pub message: String,
+}
+
+impl QueryError {
+    pub fn from_error_code(cause: &ErrorCode) -> QueryError {
+        QueryError {
+            code: cause.code(),
+            message: cause.message(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct QueryResponse {
+    pub id: String,
+    pub session_id: String,
+    pub state: ExecuteState,
+    pub columns: Vec<QueryColumn>,
+    pub data: Vec<JsonRow>,
+    pub page: usize,
+    pub next_uri: Option<String>,
+    pub error: Option<QueryError>,
+}
+
+/// A session kept alive between the http requests, so that the settings and the current
+/// database persist like a MySQL connection.
+pub struct HttpSession {
+    session: SessionRef,
+    user: String,
+    last_access: Mutex<Instant>,
+}
+
+impl HttpSession {
+    pub fn get_id(&self) -> String {
+        self.session.get_id()
+    }
+
+    fn touch(&self) {
+        *self.last_access.lock() = Instant::now();
+    }
+
+    fn is_expired(&self, now: Instant, expire: Duration) -> bool {
+        now.duration_since(*self.last_access.lock()) > expire
+    }
+}
+
+struct Page {
+    columns: Vec<QueryColumn>,
+    rows: Vec<JsonRow>,
+    last: bool,
+}
+
+struct ResultPages {
+    rx: mpsc::Receiver<Result<Page>>,
+    // The number of the next page to receive from the producer.
+    next_page: usize,
+    // The page served most recently, kept so that a retried request gets it again.
+    last_page: Option<(usize, Arc<Page>)>,
+    error: Option<QueryError>,
+}
+
+/// A query started by `POST /v1/query`, its result is buffered page by page until the client
+/// fetches it.
+pub struct HttpQuery {
+    id: String,
+    session: Arc<HttpSession>,
+    finished: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+    pages: tokio::sync::Mutex<ResultPages>,
+    last_access: Mutex<Instant>,
+}
+
+impl HttpQuery {
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn is_running(&self) -> bool {
+        !self.finished.load(Ordering::Acquire)
+    }
+
+    fn is_expired(&self, now: Instant, expire: Duration) -> bool {
+        now.duration_since(*self.last_access.lock()) > expire
+    }
+
+    fn kill(&self) {
+        self.handle.abort();
+        if self.is_running() {
+            self.session.session.force_kill_query();
+        }
+    }
+
+    /// Get the page `page_no` of the result, waiting at most `wait` for it to be produced.
+    /// Only the next page and the page served last (for retries) are available.
+    pub async fn get_page(&self, page_no: usize, wait: Duration) -> Result<QueryResponse> {
+        *self.last_access.lock() = Instant::now();
+        self.session.touch();
+
+        let mut pages = self.pages.lock().await;
+        if let Some(error) = &pages.error {
+            return Ok(self.failed_response(page_no, error.clone()));
+        }
+
+        if let Some((last_page_no, page)) = &pages.last_page {
+            if *last_page_no == page_no {
+                return Ok(self.page_response(page_no, page));
+            }
+        }
+
+        if page_no != pages.next_page {
+            return Err(ErrorCode::BadArguments(format!(
+                "Page {} of query {} is not available, the next page is {}",
+                page_no, self.id, pages.next_page
+            )));
+        }
+
+        match tokio::time::timeout(wait, pages.rx.recv()).await {
+            Err(_elapsed) => Ok(self.running_response(page_no)),
+            Ok(Some(Ok(page))) => {
+                let page = Arc::new(page);
+                pages.next_page += 1;
+                pages.last_page = Some((page_no, page.clone()));
+                Ok(self.page_response(page_no, &page))
+            }
+            Ok(Some(Err(cause))) => {
+                let error = QueryError::from_error_code(&cause);
+                pages.error = Some(error.clone());
+                Ok(self.failed_response(page_no, error))
+            }
+            Ok(None) => {
+                let cause = ErrorCode::AbortedQuery("The query was aborted before it finished");
+                let error = QueryError::from_error_code(&cause);
+                pages.error = Some(error.clone());
+                Ok(self.failed_response(page_no, error))
+            }
+        }
+    }
+
+    fn page_uri(&self, page_no: usize) -> String {
+        format!("/v1/query/{}/page/{}", self.id, page_no)
+    }
+
+    fn response(&self, page_no: usize, state: ExecuteState) -> QueryResponse {
+        QueryResponse {
+            id: self.id.clone(),
+            session_id: self.session.get_id(),
+            state,
+            columns: vec![],
+            data: vec![],
+            page: page_no,
+            next_uri: None,
+            error: None,
+        }
+    }
+
+    fn page_response(&self, page_no: usize, page: &Page) -> QueryResponse {
+        let (state, next_uri) = match page.last {
+            true => (ExecuteState::Succeeded, None),
+            false => (ExecuteState::Running, Some(self.page_uri(page_no + 1))),
+        };
+
+        QueryResponse {
+            columns: page.columns.clone(),
+            data: page.rows.clone(),
+            next_uri,
+            ..self.response(page_no, state)
+        }
+    }
+
+    fn running_response(&self, page_no: usize) -> QueryResponse {
+        QueryResponse {
+            next_uri: Some(self.page_uri(page_no)),
+            ..self.response(page_no, ExecuteState::Running)
+        }
+    }
+
+    fn failed_response(&self, page_no: usize, error: QueryError) -> QueryResponse {
+        QueryResponse {
+            error: Some(error),
+            ..self.response(page_no, ExecuteState::Failed)
+        }
+    }
+}
+
+/// Keeps the sessions and the queries of the http query API between requests, both of them
+/// expire after `http_query_expire_secs` without access.
+pub struct HttpQueryManager {
+    sessions: SessionManagerRef,
+    expire: Duration,
+    http_sessions: RwLock<HashMap<String, Arc<HttpSession>>>,
+    queries: RwLock<HashMap<String, Arc<HttpQuery>>>,
+}
+
+impl HttpQueryManager {
+    pub fn create(sessions: SessionManagerRef) -> HttpQueryManagerRef {
+        let expire = Duration::from_secs(sessions.get_conf().query.http_query_expire_secs);
+        Arc::new(HttpQueryManager {
+            sessions,
+            expire,
+            http_sessions: RwLock::new(HashMap::new()),
+            queries: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Check the basic auth credentials of the request, returns the authenticated user.
+    /// The credentials are required, a query without a user would skip the privilege checks.
+    pub fn authenticate(&self, credentials: Option<(String, String)>) -> Result<String> {
+        let (user, password) = credentials.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure("The http query API requires basic auth credentials")
+        })?;
+
+        let user_manager = self.sessions.get_user_manager();
+        match user_manager.auth_user(&user, password.as_bytes()) {
+            Ok(true) => Ok(user),
+            _ => Err(ErrorCode::AuthenticateFailure(format!(
+                "Cannot authenticate user {}",
+                user
+            ))),
+        }
+    }
+
+    /// Start a query in the given session, or in a new session if none is given.
+    pub fn create_query(
+        &self,
+        user: String,
+        session_id: Option<String>,
+        sql: String,
+        page_size: usize,
+    ) -> Result<Arc<HttpQuery>> {
+        if page_size == 0 {
+            return Err(ErrorCode::BadArguments("The page size must be positive"));
+        }
+
+        let session = match session_id {
+            Some(session_id) => self.get_session(&session_id, &user)?,
+            None => self.create_session(user)?,
+        };
+
+        let mut queries = self.queries.write();
+        let running = queries
+            .values()
+            .any(|query| query.session.get_id() == session.get_id() && query.is_running());
+        if running {
+            return Err(ErrorCode::BadArguments(format!(
+                "Session {} is running another query",
+                session.get_id()
+            )));
+        }
+
+        let (tx, rx) = mpsc::channel(MAX_BUFFERED_PAGES);
+        let finished = Arc::new(AtomicBool::new(false));
+        let handle = tokio::spawn(Self::execute(
+            session.session.clone(),
+            sql,
+            page_size,
+            tx,
+            finished.clone(),
+        ));
+
+        let query = Arc::new(HttpQuery {
+            id: uuid::Uuid::new_v4().to_string(),
+            session,
+            finished,
+            handle,
+            pages: tokio::sync::Mutex::new(ResultPages {
+                rx,
+                next_page: 0,
+                last_page: None,
+                error: None,
+            }),
+            last_access: Mutex::new(Instant::now()),
+        });
+        queries.insert(query.get_id(), query.clone());
+        Ok(query)
+    }
+
+    /// Get a query started by the same user.
+    pub fn get_query(&self, id: &str, user: &str) -> Result<Arc<HttpQuery>> {
+        let query = self.queries.read().get(id).cloned();
+        match query {
+            Some(query) if query.session.user == user => Ok(query),
+            Some(_) => Err(ErrorCode::AuthenticateFailure(format!(
+                "Query {} belongs to another user",
+                id
+            ))),
+            None => Err(ErrorCode::UnknownQuery(format!("Unknown query {}", id))),
+        }
+    }
+
+    /// Cancel the query and release its buffered result.
+    pub fn kill_query(&self, id: &str, user: &str) -> Result<()> {
+        let query = self.get_query(id, user)?;
+        self.queries.write().remove(id);
+        query.kill();
+        Ok(())
+    }
+
+    /// Remove the queries and the sessions which are not accessed for a while.
+    pub fn expire(&self) {
+        let now = Instant::now();
+
+        let expired_queries = {
+            let mut queries = self.queries.write();
+            let expired = queries
+                .iter()
+                .filter(|(_, query)| query.is_expired(now, self.expire))
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>();
+            expired
+                .iter()
+                .filter_map(|id| queries.remove(id))
+                .collect::<Vec<_>>()
+        };
+
+        for query in expired_queries {
+            log::info!("Http query {} expired", query.id);
+            query.kill();
+        }
+
+        // Sessions are referenced by their queries, which keep them alive.
+        self.http_sessions.write().retain(|_, session| {
+            Arc::strong_count(session) > 1 || !session.is_expired(now, self.expire)
+        });
+    }
+
+    pub async fn expire_loop(manager: Weak<HttpQueryManager>) {
+        loop {
+            let interval = match manager.upgrade() {
+                None => break,
+                Some(manager) => {
+                    manager.expire();
+                    std::cmp::max(manager.expire / 2, Duration::from_secs(1))
+                }
+            };
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    fn get_session(&self, session_id: &str, user: &str) -> Result<Arc<HttpSession>> {
+        let session = self.http_sessions.read().get(session_id).cloned();
+        match session {
+            Some(session) if session.user == user => {
+                session.touch();
+                Ok(session)
+            }
+            Some(_) => Err(ErrorCode::AuthenticateFailure(format!(
+                "Session {} belongs to another user",
+                session_id
+            ))),
+            None => Err(ErrorCode::UnknownSession(format!(
+                "Unknown session {}",
+                session_id
+            ))),
+        }
+    }
+
+    fn create_session(&self, user: String) -> Result<Arc<HttpSession>> {
+        let session = self.sessions.create_session("HTTPQuery")?;
+        session.set_current_user(user.clone());
+
+        let session = Arc::new(HttpSession {
+            session,
+            user,
+            last_access: Mutex::new(Instant::now()),
+        });
+        let mut http_sessions = self.http_sessions.write();
+        http_sessions.insert(session.get_id(), session.clone());
+        Ok(session)
+    }
+
+    async fn execute(
+        session: SessionRef,
+        sql: String,
+        page_size: usize,
+        tx: mpsc::Sender<Result<Page>>,
+        finished: Arc<AtomicBool>,
+    ) {
+        if let Err(cause) = Self::execute_pages(&session, &sql, page_size, &tx).await {
+            // The client may have gone, nobody reads the error then.
+            let _ = tx.send(Err(cause)).await;
+        }
+        finished.store(true, Ordering::Release);
+    }
+
+    async fn execute_pages(
+        session: &SessionRef,
+        sql: &str,
+        page_size: usize,
+        tx: &mpsc::Sender<Result<Page>>,
+    ) -> Result<()> {
+        let context = session.create_context().await?;
+        context.attach_query_str(sql);
+
+        let res = Self::execute_query(&context, sql, page_size, tx).await;
+        if let Err(cause) = &res {
+            context.attach_query_error(cause);
+        }
+        res
+    }
+
+    async fn execute_query(
+        context: &DatabendQueryContextRef,
+        sql: &str,
+        page_size: usize,
+        tx: &mpsc::Sender<Result<Page>>,
+    ) -> Result<()> {
+        let plan = PlanParser::create(context.clone()).build_from_sql(sql)?;
+        let interpreter = InterpreterFactory::get(context.clone(), plan)?;
+        let columns = interpreter
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| QueryColumn {
+                name: field.name().to_string(),
+                data_type: field.data_type().to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut stream = interpreter.execute().await?;
+        let mut rows = Vec::with_capacity(page_size);
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            context.add_result_block(&block);
+            for row in block_to_rows(&block)? {
+                if rows.len() == page_size {
+                    let page = Page {
+                        columns: columns.clone(),
+                        rows: std::mem::replace(&mut rows, Vec::with_capacity(page_size)),
+                        last: false,
+                    };
+                    if tx.send(Ok(page)).await.is_err() {
+                        // The query is killed or expired.
+                        return Ok(());
+                    }
+                }
+                rows.push(row);
+            }
+        }
+
+        let page = Page {
+            columns,
+            rows,
+            last: true,
+        };
+        let _ = tx.send(Ok(page)).await;
+        Ok(())
+    }
+}
+
+/// Convert the block into rows of json values, the values are the text of them like in the
+/// MySQL handler, and nulls are json nulls.
+pub fn block_to_rows(block: &DataBlock) -> Result<Vec<JsonRow>> {
+    let num_rows = block.num_rows();
+    let mut rows = vec![Vec::with_capacity(block.num_columns()); num_rows];
+    for (column, field) in block.columns().iter().zip(block.schema().fields()) {
+        let texts = match field.data_type().create_serializer(0) {
+            Ok(serializer) => serializer.serialize_strings(column)?,
+            Err(_) => value_texts(column, num_rows)?,
+        };
+
+        for (row_index, text) in texts.into_iter().enumerate() {
+            let value = match text.as_str() {
+                "NULL" if column.try_get(row_index)?.is_null() => JsonValue::Null,
+                _ => JsonValue::String(text),
+            };
+            rows[row_index].push(value);
+        }
+    }
+    Ok(rows)
+}
+
+fn value_texts(column: &DataColumn, num_rows: usize) -> Result<Vec<String>> {
+    (0..num_rows)
+        .map(|row_index| match column.try_get(row_index)? {
+            value if value.is_null() => Ok(String::from("NULL")),
+            value => Ok(value.to_string()),
+        })
+        .collect()
+}
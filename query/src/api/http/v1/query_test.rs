@@ -0,0 +1,273 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::body::Body;
+use axum::handler::delete;
+use axum::handler::get;
+use axum::handler::post;
+use axum::headers::Authorization;
+use axum::headers::HeaderMapExt;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::http::{self};
+use axum::routing::BoxRoute;
+use axum::AddExtensionLayer;
+use axum::Router;
+use common_base::tokio;
+use common_exception::Result;
+use common_management::AuthType;
+use common_management::GrantObject;
+use common_management::UserPrivilegeSet;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tower::ServiceExt;
+
+use crate::api::http::v1::query::query_handler;
+use crate::api::http::v1::query::query_kill_handler;
+use crate::api::http::v1::query::query_page_handler;
+use crate::api::http::v1::query_manager::ExecuteState;
+use crate::api::http::v1::query_manager::HttpQueryManager;
+use crate::api::http::v1::query_manager::QueryResponse;
+use crate::tests::SessionManagerBuilder;
+use crate::users::User;
+
+const USER: &str = "http-query-user";
+const PASSWORD: &str = "http-pwd";
+
+fn create_router(users: &[&str]) -> Result<Router<BoxRoute>> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let user_manager = sessions.get_user_manager();
+    for user in users {
+        let _ = user_manager.drop_user(user);
+        user_manager.add_user(User::new(*user, PASSWORD, AuthType::PlainText).into())?;
+        user_manager.grant_user_privileges(
+            user,
+            GrantObject::Global,
+            UserPrivilegeSet::all_privileges(),
+        )?;
+    }
+
+    Ok(Router::new()
+        .route("/v1/query", post(query_handler))
+        .route("/v1/query/:id", delete(query_kill_handler))
+        .route("/v1/query/:id/page/:page", get(query_page_handler))
+        .layer(AddExtensionLayer::new(HttpQueryManager::create(sessions)))
+        .boxed())
+}
+
+async fn send_as(
+    router: &Router<BoxRoute>,
+    auth: Option<(&str, &str)>,
+    method: http::Method,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, Option<QueryResponse>) {
+    let body = match body {
+        None => Body::empty(),
+        Some(body) => Body::from(body.to_string()),
+    };
+
+    let mut request = Request::builder()
+        .uri(uri)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .method(method)
+        .body(body)
+        .unwrap();
+    if let Some((user, password)) = auth {
+        request
+            .headers_mut()
+            .typed_insert(Authorization::basic(user, password));
+    }
+
+    let response = router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, serde_json::from_slice::<QueryResponse>(&body).ok())
+}
+
+async fn send(
+    router: &Router<BoxRoute>,
+    method: http::Method,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, Option<QueryResponse>) {
+    send_as(router, Some((USER, PASSWORD)), method, uri, body).await
+}
+
+async fn post_query(router: &Router<BoxRoute>, body: serde_json::Value) -> QueryResponse {
+    let (status, response) = send(router, http::Method::POST, "/v1/query", Some(body)).await;
+    assert_eq!(status, StatusCode::OK);
+    response.unwrap()
+}
+
+async fn get_page(router: &Router<BoxRoute>, uri: &str) -> (StatusCode, Option<QueryResponse>) {
+    send(router, http::Method::GET, uri, None).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_pages() -> Result<()> {
+    let router = create_router(&[USER])?;
+
+    let sql = "select number from numbers(10) order by number";
+    let response = post_query(&router, json!({"sql": sql, "page_size": 4})).await;
+    assert_eq!(response.state, ExecuteState::Running);
+    assert_eq!(response.page, 0);
+    assert_eq!(response.columns.len(), 1);
+    assert_eq!(response.columns[0].name, "number");
+    assert_eq!(response.data, vec![
+        vec![json!("0")],
+        vec![json!("1")],
+        vec![json!("2")],
+        vec![json!("3")]
+    ]);
+
+    let page_1 = format!("/v1/query/{}/page/1", response.id);
+    assert_eq!(response.next_uri, Some(page_1.clone()));
+
+    let (status, response) = get_page(&router, &page_1).await;
+    assert_eq!(status, StatusCode::OK);
+    let response = response.unwrap();
+    assert_eq!(response.data.len(), 4);
+    assert_eq!(response.data[0], vec![json!("4")]);
+
+    // The last fetched page can be fetched again, e.g. after a timeout of the client.
+    let (status, retried) = get_page(&router, &page_1).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(retried.unwrap().data, response.data);
+
+    let page_2 = response.next_uri.unwrap();
+    let (status, response) = get_page(&router, &page_2).await;
+    assert_eq!(status, StatusCode::OK);
+    let response = response.unwrap();
+    assert_eq!(response.state, ExecuteState::Succeeded);
+    assert_eq!(response.data, vec![vec![json!("8")], vec![json!("9")]]);
+    assert_eq!(response.next_uri, None);
+
+    // The pages fetched before are released.
+    let page_0 = format!("/v1/query/{}/page/0", response.id);
+    let (status, _) = get_page(&router, &page_0).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_session() -> Result<()> {
+    let router = create_router(&[USER])?;
+
+    let response = post_query(&router, json!({"sql": "use system"})).await;
+    assert_eq!(response.state, ExecuteState::Succeeded);
+
+    // The current database persists in the session.
+    let sql = "select database()";
+    let body = json!({"sql": sql, "session_id": response.session_id});
+    let response = post_query(&router, body).await;
+    assert_eq!(response.state, ExecuteState::Succeeded);
+    assert_eq!(response.data, vec![vec![json!("system")]]);
+
+    // A new session starts from the default database.
+    let response = post_query(&router, json!({ "sql": sql })).await;
+    assert_eq!(response.data, vec![vec![json!("default")]]);
+
+    let body = json!({"sql": sql, "session_id": "unknown"});
+    let (status, _) = send(&router, http::Method::POST, "/v1/query", Some(body)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_error_and_kill() -> Result<()> {
+    let router = create_router(&[USER])?;
+
+    let response = post_query(
+        &router,
+        json!({"sql": "select * from system.no_such_table"}),
+    )
+    .await;
+    assert_eq!(response.state, ExecuteState::Failed);
+    assert_eq!(response.next_uri, None);
+    let error = response.error.unwrap();
+    assert_eq!(error.code, 25);
+
+    let sql = "select number from numbers(100)";
+    let response = post_query(&router, json!({"sql": sql, "page_size": 10})).await;
+    let uri = format!("/v1/query/{}", response.id);
+    let (status, _) = send(&router, http::Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = get_page(&router, &response.next_uri.unwrap()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&router, http::Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_auth() -> Result<()> {
+    let other = "http-query-other-user";
+    let router = create_router(&[USER, other])?;
+    let body = json!({"sql": "select 1"});
+
+    // The credentials are required.
+    let method = http::Method::POST;
+    let (status, _) = send_as(
+        &router,
+        None,
+        method.clone(),
+        "/v1/query",
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let auth = Some((USER, "wrong-pwd"));
+    let (status, _) = send_as(
+        &router,
+        auth,
+        method.clone(),
+        "/v1/query",
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let sql = "select number from numbers(10)";
+    let response = post_query(&router, json!({"sql": sql, "page_size": 2})).await;
+    assert_eq!(response.state, ExecuteState::Running);
+
+    // The query and the session can't be used by another user or without credentials.
+    let next_uri = response.next_uri.unwrap();
+    let auth = Some((other, PASSWORD));
+    let (status, _) = send_as(&router, auth, http::Method::GET, &next_uri, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = send_as(&router, None, http::Method::GET, &next_uri, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let uri = format!("/v1/query/{}", response.id);
+    let (status, _) = send_as(&router, auth, http::Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let body = json!({"sql": "select 1", "session_id": response.session_id});
+    let (status, _) = send_as(&router, auth, method, "/v1/query", Some(body)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // The owner still can.
+    let (status, _) = get_page(&router, &next_uri).await;
+    assert_eq!(status, StatusCode::OK);
+
+    Ok(())
+}
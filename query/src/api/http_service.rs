@@ -19,7 +19,9 @@ use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
 
+use axum::handler::delete;
 use axum::handler::get;
+use axum::handler::post;
 use axum::routing::BoxRoute;
 use axum::AddExtensionLayer;
 use axum::Router;
@@ -30,21 +32,22 @@ use common_base::tokio;
 use common_base::tokio::task::JoinHandle;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use tokio_rustls::rustls::internal::pemfile::certs;
-use tokio_rustls::rustls::internal::pemfile::pkcs8_private_keys;
 use tokio_rustls::rustls::AllowAnyAuthenticatedClient;
-use tokio_rustls::rustls::Certificate;
 use tokio_rustls::rustls::NoClientAuth;
-use tokio_rustls::rustls::PrivateKey;
 use tokio_rustls::rustls::RootCertStore;
 use tokio_rustls::rustls::ServerConfig;
 
+use crate::api::http::v1::query_manager::HttpQueryManager;
+use crate::api::http::v1::query_manager::HttpQueryManagerRef;
 use crate::configs::Config;
+use crate::servers::load_certs;
+use crate::servers::load_keys;
 use crate::servers::Server;
 use crate::sessions::SessionManagerRef;
 
 pub struct HttpService {
     sessions: SessionManagerRef,
+    queries: HttpQueryManagerRef,
     join_handle: Option<JoinHandle<std::io::Result<()>>>,
     abort_handler: Handle,
 }
@@ -52,6 +55,7 @@ pub struct HttpService {
 impl HttpService {
     pub fn create(sessions: SessionManagerRef) -> Box<HttpService> {
         Box::new(HttpService {
+            queries: HttpQueryManager::create(sessions.clone()),
             sessions,
             join_handle: None,
             abort_handler: axum_server::Handle::new(),
@@ -62,8 +66,8 @@ impl HttpService {
         let tls_key = Path::new(config.query.api_tls_server_key.as_str());
         let tls_cert = Path::new(config.query.api_tls_server_cert.as_str());
 
-        let key = HttpService::load_keys(tls_key)?.remove(0);
-        let certs = HttpService::load_certs(tls_cert)?;
+        let key = load_keys(tls_key)?.remove(0);
+        let certs = load_certs(tls_cert)?;
 
         let mut tls_config = ServerConfig::new(NoClientAuth::new());
         if let Err(cause) = tls_config.set_single_cert(certs, key) {
@@ -100,21 +104,6 @@ impl HttpService {
         Ok(tls_config)
     }
 
-    fn load_certs(path: &Path) -> Result<Vec<Certificate>> {
-        match certs(&mut BufReader::new(File::open(path)?)) {
-            Ok(certs) => Ok(certs),
-            Err(_) => Err(ErrorCode::TLSConfigurationFailure("invalid cert")),
-        }
-    }
-
-    // currently only PKCS8 key supports for TLS setup
-    fn load_keys(path: &Path) -> Result<Vec<PrivateKey>> {
-        match pkcs8_private_keys(&mut BufReader::new(File::open(path)?)) {
-            Ok(keys) => Ok(keys),
-            Err(_) => Err(ErrorCode::TLSConfigurationFailure("invalid key")),
-        }
-    }
-
     // Client Auth(mTLS) CA certificate configuration
     fn load_ca(ca_path: &str) -> Option<String> {
         match Path::new(ca_path).exists() {
@@ -125,7 +114,6 @@ impl HttpService {
 
     fn build_router(&self) -> Router<BoxRoute> {
         Router::new()
-            .layer(AddExtensionLayer::new(self.sessions.clone()))
             .route("/v1/health", get(super::http::v1::health::health_handler))
             .route("/v1/config", get(super::http::v1::config::config_handler))
             .route("/v1/logs", get(super::http::v1::logs::logs_handler))
@@ -133,6 +121,15 @@ impl HttpService {
                 "/v1/cluster/list",
                 get(super::http::v1::cluster::cluster_list_handler),
             )
+            .route("/v1/query", post(super::http::v1::query::query_handler))
+            .route(
+                "/v1/query/:id",
+                delete(super::http::v1::query::query_kill_handler),
+            )
+            .route(
+                "/v1/query/:id/page/:page",
+                get(super::http::v1::query::query_page_handler),
+            )
             .route(
                 "/debug/home",
                 get(super::http::debug::home::debug_home_handler),
@@ -141,6 +138,8 @@ impl HttpService {
                 "/debug/pprof/profile",
                 get(super::http::debug::pprof::debug_pprof_handler),
             )
+            .layer(AddExtensionLayer::new(self.sessions.clone()))
+            .layer(AddExtensionLayer::new(self.queries.clone()))
             .boxed()
     }
 
@@ -231,6 +230,9 @@ impl Server for HttpService {
     }
 
     async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
+        let queries = Arc::downgrade(&self.queries);
+        tokio::spawn(HttpQueryManager::expire_loop(queries));
+
         let config = &self.sessions.get_conf().query;
         match config.api_tls_server_key.is_empty() || config.api_tls_server_cert.is_empty() {
             true => self.start_without_tls(listening).await,
@@ -17,10 +17,12 @@
 pub use http_service::HttpService;
 pub use rpc::BroadcastAction;
 pub use rpc::CancelAction;
+pub use rpc::FetchProfileAction;
 pub use rpc::FlightAction;
 pub use rpc::FlightClient;
 pub use rpc::FlightTicket;
 pub use rpc::ShuffleAction;
+pub use rpc::StageProfile;
 pub use rpc_service::RpcService;
 
 mod http;
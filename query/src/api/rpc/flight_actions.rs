@@ -28,6 +28,8 @@ pub struct ShuffleAction {
     pub plan: PlanNode,
     pub sinks: Vec<String>,
     pub scatters_expression: Expression,
+    #[serde(default)]
+    pub profiling: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -36,6 +38,8 @@ pub struct BroadcastAction {
     pub stage_id: String,
     pub plan: PlanNode,
     pub sinks: Vec<String>,
+    #[serde(default)]
+    pub profiling: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -43,6 +47,19 @@ pub struct CancelAction {
     pub query_id: String,
 }
 
+/// Takes the profiles of the stages of the query which ran with profiling on the node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct FetchProfileAction {
+    pub query_id: String,
+}
+
+/// The runtime statistics of a remote stage, one line per pipe and processor.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct StageProfile {
+    pub stage_id: String,
+    pub lines: Vec<String>,
+}
+
 impl TryInto<ShuffleAction> for Vec<u8> {
     type Error = Status;
 
@@ -115,11 +132,36 @@ impl TryInto<Vec<u8>> for CancelAction {
     }
 }
 
+impl TryInto<FetchProfileAction> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<FetchProfileAction, Self::Error> {
+        match std::str::from_utf8(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(utf8_body) => match serde_json::from_str::<FetchProfileAction>(utf8_body) {
+                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+                Ok(action) => Ok(action),
+            },
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for FetchProfileAction {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(ErrorCode::LogicalError, || {
+            "Logical error: cannot serialize FetchProfileAction."
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     PrepareShuffleAction(ShuffleAction),
     BroadcastAction(BroadcastAction),
     CancelAction(CancelAction),
+    FetchProfileAction(FetchProfileAction),
 }
 
 impl FlightAction {
@@ -155,6 +197,33 @@ impl FlightAction {
         }
     }
 
+    pub fn is_profiling(&self) -> bool {
+        match self {
+            FlightAction::BroadcastAction(action) => action.profiling,
+            FlightAction::PrepareShuffleAction(action) => action.profiling,
+            _ => false,
+        }
+    }
+
+    /// Make the stage collect the runtime statistics of its pipeline.
+    pub fn with_profiling(self, profiling: bool) -> FlightAction {
+        match self {
+            FlightAction::BroadcastAction(action) => {
+                FlightAction::BroadcastAction(BroadcastAction {
+                    profiling,
+                    ..action
+                })
+            }
+            FlightAction::PrepareShuffleAction(action) => {
+                FlightAction::PrepareShuffleAction(ShuffleAction {
+                    profiling,
+                    ..action
+                })
+            }
+            other => other,
+        }
+    }
+
     pub fn get_scatter_expression(&self) -> Option<Expression> {
         match self {
             FlightAction::BroadcastAction(_) => None,
@@ -172,6 +241,7 @@ impl TryInto<FlightAction> for Action {
             "PrepareShuffleAction" => Ok(FlightAction::PrepareShuffleAction(self.body.try_into()?)),
             "BroadcastAction" => Ok(FlightAction::BroadcastAction(self.body.try_into()?)),
             "CancelAction" => Ok(FlightAction::CancelAction(self.body.try_into()?)),
+            "FetchProfileAction" => Ok(FlightAction::FetchProfileAction(self.body.try_into()?)),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -197,6 +267,10 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("CancelAction"),
                 body: cancel_action.try_into()?,
             }),
+            FlightAction::FetchProfileAction(fetch_profile_action) => Ok(Action {
+                r#type: String::from("FetchProfileAction"),
+                body: fetch_profile_action.try_into()?,
+            }),
         }
     }
 }
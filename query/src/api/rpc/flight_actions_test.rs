@@ -32,6 +32,7 @@ async fn test_shuffle_action_try_into() -> Result<()> {
         plan: parse_query("SELECT number FROM numbers(5)")?,
         sinks: vec![String::from("stream_id")],
         scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+        profiling: false,
     };
 
     let from_action = FlightAction::PrepareShuffleAction(shuffle_action);
@@ -39,6 +40,7 @@ async fn test_shuffle_action_try_into() -> Result<()> {
     let from_action: FlightAction = to_action.try_into()?;
     match from_action {
         FlightAction::CancelAction(_) => assert!(false),
+        FlightAction::FetchProfileAction(_) => assert!(false),
         FlightAction::BroadcastAction(_) => assert!(false),
         FlightAction::PrepareShuffleAction(action) => {
             assert_eq!(action.query_id, "query_id");
@@ -21,6 +21,7 @@ use common_base::tokio::sync::mpsc::Receiver;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
+use metrics::counter;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::Stream;
 use tokio_stream::StreamExt;
@@ -39,6 +40,9 @@ impl FlightDataStream {
             match flight_data {
                 Err(status) => Err(ErrorCode::UnknownException(status.message())),
                 Ok(flight_data) => {
+                    let bytes = flight_data.data_header.len() + flight_data.data_body.len();
+                    counter!(super::metrics::METRIC_FLIGHT_RECEIVED_BYTES, bytes as u64);
+
                     fn create_data_block(record_batch: RecordBatch) -> DataBlock {
                         let columns = record_batch
                             .columns()
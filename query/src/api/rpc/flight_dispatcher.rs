@@ -25,6 +25,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_infallible::RwLock;
+use common_tracing::tracing;
 use tokio_stream::StreamExt;
 
 use crate::api::rpc::flight_scatter::FlightScatter;
@@ -32,6 +33,8 @@ use crate::api::rpc::flight_scatter_broadcast::BroadcastFlightScatter;
 use crate::api::rpc::flight_scatter_hash::HashFlightScatter;
 use crate::api::rpc::flight_tickets::StreamTicket;
 use crate::api::FlightAction;
+use crate::api::StageProfile;
+use crate::pipelines::processors::Pipeline;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::DatabendQueryContext;
 use crate::sessions::SessionRef;
@@ -46,6 +49,7 @@ struct StreamInfo {
 pub struct DatabendQueryFlightDispatcher {
     streams: Arc<RwLock<HashMap<String, StreamInfo>>>,
     stages_notify: Arc<RwLock<HashMap<String, Arc<Notify>>>>,
+    stages_profile: Arc<RwLock<HashMap<String, Vec<StageProfile>>>>,
     abort: Arc<AtomicBool>,
 }
 
@@ -56,6 +60,7 @@ impl DatabendQueryFlightDispatcher {
         DatabendQueryFlightDispatcher {
             streams: Arc::new(RwLock::new(HashMap::new())),
             stages_notify: Arc::new(RwLock::new(HashMap::new())),
+            stages_profile: Arc::new(RwLock::new(HashMap::new())),
             abort: Arc::new(AtomicBool::new(false)),
         }
     }
@@ -69,6 +74,38 @@ impl DatabendQueryFlightDispatcher {
         self.abort.load(Ordering::Relaxed)
     }
 
+    /// Drop the streams of the cancelled query and wake up its stages waiting to start,
+    /// the stages stop at once because the query context is killed.
+    pub fn cancel(&self, query_id: &str) {
+        let query_prefix = format!("{}/", query_id);
+        self.streams
+            .write()
+            .retain(|stream_name, _| !stream_name.starts_with(&query_prefix));
+
+        self.stages_profile.write().remove(query_id);
+
+        let mut stages_notify = self.stages_notify.write();
+        let cancelled_stages = stages_notify
+            .keys()
+            .filter(|stage_name| stage_name.starts_with(&query_prefix))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for stage_name in cancelled_stages {
+            if let Some(notify) = stages_notify.remove(&stage_name) {
+                notify.notify_waiters();
+            }
+        }
+    }
+
+    /// Take the profiles of the finished stages of the query, for EXPLAIN ANALYZE.
+    pub fn take_profiles(&self, query_id: &str) -> Vec<StageProfile> {
+        self.stages_profile
+            .write()
+            .remove(query_id)
+            .unwrap_or_default()
+    }
+
     pub fn get_stream(&self, ticket: &StreamTicket) -> Result<mpsc::Receiver<Result<DataBlock>>> {
         let stage_name = format!("{}/{}", ticket.query_id, ticket.stage_id);
         if let Some(notify) = self.stages_notify.write().remove(&stage_name) {
@@ -82,6 +119,7 @@ impl DatabendQueryFlightDispatcher {
         }
     }
 
+    #[tracing::instrument(level = "info", skip(self, session, action), fields(query_id = action.get_query_id().as_str(), stage_id = action.get_stage_id().as_str()))]
     pub async fn broadcast_action(&self, session: SessionRef, action: FlightAction) -> Result<()> {
         let query_id = action.get_query_id();
         let stage_id = action.get_stage_id();
@@ -99,6 +137,7 @@ impl DatabendQueryFlightDispatcher {
         }
     }
 
+    #[tracing::instrument(level = "info", skip(self, session, action), fields(query_id = action.get_query_id().as_str(), stage_id = action.get_stage_id().as_str()))]
     pub async fn shuffle_action(&self, session: SessionRef, action: FlightAction) -> Result<()> {
         let query_id = action.get_query_id();
         let stage_id = action.get_stage_id();
@@ -119,7 +158,8 @@ impl DatabendQueryFlightDispatcher {
     async fn one_sink_action(&self, session: SessionRef, action: &FlightAction) -> Result<()> {
         let query_context = session.create_context().await?;
         let action_context = DatabendQueryContext::new(query_context.clone());
-        let pipeline_builder = PipelineBuilder::create(action_context.clone());
+        let pipeline_builder =
+            PipelineBuilder::create(action_context.clone()).with_profiling(action.is_profiling());
 
         let query_plan = action.get_plan();
         action_context.attach_query_plan(&query_plan);
@@ -132,6 +172,8 @@ impl DatabendQueryFlightDispatcher {
         assert_eq!(action_sinks.len(), 1);
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let stages_profile = self.stages_profile.clone();
+        let profiling = action.is_profiling();
 
         let stream_name = format!("{}/{}", stage_name, action_sinks[0]);
         let tx_ref = self.streams.read().get(&stream_name).map(|x| x.tx.clone());
@@ -157,6 +199,18 @@ impl DatabendQueryFlightDispatcher {
                     }
                 }
             };
+
+            // Keep the profile before the sink is closed, the consumer
+            // may fetch it as soon as it reaches the end of the stream.
+            if profiling {
+                save_profile(
+                    &stages_profile,
+                    &action_query_id,
+                    &action_stage_id,
+                    &pipeline,
+                );
+            }
+            drop(tx);
         })?;
         Ok(())
     }
@@ -171,7 +225,8 @@ impl DatabendQueryFlightDispatcher {
     {
         let query_context = session.create_context().await?;
         let action_context = DatabendQueryContext::new(query_context.clone());
-        let pipeline_builder = PipelineBuilder::create(action_context.clone());
+        let pipeline_builder =
+            PipelineBuilder::create(action_context.clone()).with_profiling(action.is_profiling());
 
         let query_plan = action.get_plan();
         action_context.attach_query_plan(&query_plan);
@@ -204,6 +259,8 @@ impl DatabendQueryFlightDispatcher {
 
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let stages_profile = self.stages_profile.clone();
+        let profiling = action.is_profiling();
 
         let flight_scatter = T::try_create(
             action.get_plan().schema(),
@@ -216,8 +273,9 @@ impl DatabendQueryFlightDispatcher {
             wait_start(stage_name, stages_notify).await;
 
             let sinks_tx_ref = &sinks_tx;
+            let pipeline_ref = &mut pipeline;
             let forward_blocks = async move {
-                let mut abortable_stream = pipeline.execute().await?;
+                let mut abortable_stream = pipeline_ref.execute().await?;
                 while let Some(item) = abortable_stream.next().await {
                     let forward_blocks = flight_scatter.execute(&item?)?;
 
@@ -244,6 +302,16 @@ impl DatabendQueryFlightDispatcher {
                     }
                 }
             }
+
+            if profiling {
+                save_profile(
+                    &stages_profile,
+                    &action_query_id,
+                    &action_stage_id,
+                    &pipeline,
+                );
+            }
+            drop(sinks_tx);
         })?;
 
         Ok(())
@@ -276,6 +344,27 @@ impl DatabendQueryFlightDispatcher {
     }
 }
 
+fn save_profile(
+    stages_profile: &RwLock<HashMap<String, Vec<StageProfile>>>,
+    query_id: &str,
+    stage_id: &str,
+    pipeline: &Pipeline,
+) {
+    let lines = format!("{}", pipeline.display_profile())
+        .lines()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+
+    stages_profile
+        .write()
+        .entry(query_id.to_string())
+        .or_default()
+        .push(StageProfile {
+            stage_id: stage_id.to_string(),
+            lines,
+        });
+}
+
 async fn wait_start(stage_name: String, stages_notify: Arc<RwLock<HashMap<String, Arc<Notify>>>>) {
     let notify = {
         let stages_notify = stages_notify.read();
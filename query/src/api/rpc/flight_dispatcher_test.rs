@@ -65,6 +65,7 @@ async fn test_run_shuffle_action_with_no_scatters() -> Result<()> {
                     plan: parse_query("SELECT number FROM numbers(5)")?,
                     sinks: vec![stream_id.clone()],
                     scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+                    profiling: false,
                 }),
             )
             .await?;
@@ -92,6 +93,50 @@ async fn test_run_shuffle_action_with_no_scatters() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_run_shuffle_action_with_profiling() -> Result<()> {
+    if let (Some(query_id), Some(stage_id), Some(stream_id)) = generate_uuids(3) {
+        let flight_dispatcher = DatabendQueryFlightDispatcher::create();
+
+        let sessions = SessionManagerBuilder::create().build()?;
+        let rpc_session = sessions.create_rpc_session(query_id.clone(), false)?;
+
+        flight_dispatcher
+            .shuffle_action(
+                rpc_session,
+                FlightAction::PrepareShuffleAction(ShuffleAction {
+                    query_id: query_id.clone(),
+                    stage_id: stage_id.clone(),
+                    plan: parse_query("SELECT number FROM numbers(5)")?,
+                    sinks: vec![stream_id.clone()],
+                    scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+                    profiling: true,
+                }),
+            )
+            .await?;
+
+        let stream = stream_ticket(&query_id, &stage_id, &stream_id);
+        let receiver = flight_dispatcher.get_stream(&stream)?;
+        let receiver_stream = ReceiverStream::new(receiver);
+        let blocks = receiver_stream.collect::<Result<Vec<_>>>().await?;
+        assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 5);
+
+        // The profile is kept before the end of the stream.
+        let profiles = flight_dispatcher.take_profiles(&query_id);
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].stage_id, stage_id);
+        assert!(profiles[0]
+            .lines
+            .iter()
+            .any(|line| line.contains("rows out: 5")));
+
+        // The profiles are taken only once.
+        assert!(flight_dispatcher.take_profiles(&query_id).is_empty());
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_run_shuffle_action_with_scatter() -> Result<()> {
     if let (Some(query_id), Some(stage_id), None) = generate_uuids(2) {
@@ -109,6 +154,7 @@ async fn test_run_shuffle_action_with_scatter() -> Result<()> {
                     plan: parse_query("SELECT number FROM numbers(5)")?,
                     sinks: vec!["stream_1".to_string(), "stream_2".to_string()],
                     scatters_expression: Expression::Column("number".to_string()),
+                    profiling: false,
                 }),
             )
             .await?;
@@ -150,6 +196,42 @@ async fn test_run_shuffle_action_with_scatter() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cancel_shuffle_action() -> Result<()> {
+    if let (Some(query_id), Some(stage_id), Some(stream_id)) = generate_uuids(3) {
+        let flight_dispatcher = DatabendQueryFlightDispatcher::create();
+
+        let sessions = SessionManagerBuilder::create().build()?;
+        let rpc_session = sessions.create_rpc_session(query_id.clone(), false)?;
+
+        flight_dispatcher
+            .shuffle_action(
+                rpc_session.clone(),
+                FlightAction::PrepareShuffleAction(ShuffleAction {
+                    query_id: query_id.clone(),
+                    stage_id: stage_id.clone(),
+                    plan: parse_query("SELECT number FROM numbers(5)")?,
+                    sinks: vec![stream_id.clone()],
+                    scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+                    profiling: false,
+                }),
+            )
+            .await?;
+
+        // Same as the CancelAction handler.
+        rpc_session.force_kill_session();
+        flight_dispatcher.cancel(&query_id);
+
+        let stream = stream_ticket(&query_id, &stage_id, &stream_id);
+        match flight_dispatcher.get_stream(&stream) {
+            Ok(_) => assert!(false, "Return Ok in test_cancel_shuffle_action."),
+            Err(error) => assert_eq!(error.code(), 29),
+        }
+    }
+
+    Ok(())
+}
+
 fn stream_ticket(query_id: &str, stage_id: &str, stream: &str) -> StreamTicket {
     StreamTicket {
         query_id: query_id.to_string(),
@@ -30,6 +30,7 @@ use common_arrow::arrow_flight::PutResult;
 use common_arrow::arrow_flight::Result as FlightResult;
 use common_arrow::arrow_flight::SchemaResult;
 use common_arrow::arrow_flight::Ticket;
+use common_tracing::tracing;
 use tokio_stream::Stream;
 use tonic::Request;
 use tonic::Response as RawResponse;
@@ -98,7 +99,10 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoGetStream = FlightStream<FlightData>;
 
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_get(&self, request: Request<Ticket>) -> Response<Self::DoGetStream> {
+        common_tracing::extract_remote_span_as_parent(&request);
+
         let ticket: FlightTicket = request.into_inner().try_into()?;
 
         match ticket {
@@ -130,7 +134,11 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoActionStream = FlightStream<FlightResult>;
 
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
+        // The stages started by the action run in this span, chained to the query on the caller.
+        common_tracing::extract_remote_span_as_parent(&request);
+
         let action = request.into_inner();
         let flight_action: FlightAction = action.try_into()?;
 
@@ -139,12 +147,18 @@ impl FlightService for DatabendQueryFlightService {
                 // We only destroy when session is exist
                 let session_id = action.query_id.clone();
                 if let Some(session) = self.sessions.get_session(&session_id) {
-                    // TODO: remove streams
                     session.force_kill_session();
                 }
+                self.dispatcher.cancel(&session_id);
 
                 FlightResult { body: vec![] }
             }
+            FlightAction::FetchProfileAction(action) => {
+                let profiles = self.dispatcher.take_profiles(&action.query_id);
+                let body = serde_json::to_vec(&profiles)
+                    .map_err(|cause| Status::internal(cause.to_string()))?;
+                FlightResult { body }
+            }
             FlightAction::BroadcastAction(action) => {
                 let session_id = action.query_id.clone();
                 let is_aborted = self.dispatcher.is_aborted();
@@ -22,6 +22,7 @@ use common_base::tokio::macros::support::Poll;
 use common_base::tokio::sync::mpsc::Receiver;
 use common_datablocks::DataBlock;
 use futures::task::Context;
+use metrics::counter;
 use tokio_stream::Stream;
 use tonic::Status;
 
@@ -53,7 +54,11 @@ impl Stream for FlightDataStream {
                         flight_data_from_arrow_batch(&record_batch, &self.options);
 
                     match dicts.is_empty() {
-                        true => Some(Ok(values)),
+                        true => {
+                            let bytes = values.data_header.len() + values.data_body.len();
+                            counter!(super::metrics::METRIC_FLIGHT_SENT_BYTES, bytes as u64);
+                            Some(Ok(values))
+                        }
                         false => Some(Err(Status::unimplemented(
                             "DatabendQuery does not implement dicts.",
                         ))),
@@ -170,6 +170,7 @@ fn do_action_request(query_id: &str, stage_id: &str) -> Result<Request<Action>>
         plan: parse_query("SELECT number FROM numbers(5)")?,
         sinks: vec![String::from("stream_id")],
         scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+        profiling: false,
     });
 
     Ok(Request::new(flight_action.try_into()?))
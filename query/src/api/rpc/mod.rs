@@ -26,8 +26,10 @@ mod flight_tickets_test;
 
 pub use flight_actions::BroadcastAction;
 pub use flight_actions::CancelAction;
+pub use flight_actions::FetchProfileAction;
 pub use flight_actions::FlightAction;
 pub use flight_actions::ShuffleAction;
+pub use flight_actions::StageProfile;
 pub use flight_client::FlightClient;
 pub use flight_dispatcher::DatabendQueryFlightDispatcher;
 pub use flight_service::DatabendQueryFlightService;
@@ -43,3 +45,4 @@ mod flight_scatter_hash;
 mod flight_service;
 mod flight_service_stream;
 mod flight_tickets;
+mod metrics;
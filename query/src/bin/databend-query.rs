@@ -43,6 +43,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Prefer to use env variable in cloud native deployment
     // Override configs based on env variables
     conf = Config::load_from_env(&conf)?;
+    conf.check()?;
 
     env_logger::Builder::from_env(
         env_logger::Env::default().default_filter_or(conf.log.log_level.to_lowercase().as_str()),
@@ -71,6 +72,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         cluster_discovery.clone(),
         user_manager.clone(),
     )?;
+    session_manager.start_query_log_flusher();
     let mut shutdown_handle = ShutdownHandle::create(session_manager.clone());
 
     // MySQL handler.
@@ -106,6 +108,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
     }
 
+    // ClickHouse secure handler.
+    if conf.tls_clickhouse_handler_enabled() {
+        let hostname = conf.query.clickhouse_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.clickhouse_tls_handler_port);
+
+        let mut srv = ClickHouseHandler::create_with_tls(session_manager.clone());
+        let listening = srv.start(listening.parse()?).await?;
+        shutdown_handle.add_service(srv);
+
+        info!(
+            "ClickHouse secure handler listening on {}, Usage: clickhouse-client --secure --host {} --port {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // Metric API service.
     {
         let address = conf.query.metric_api_address.clone();
@@ -31,8 +31,11 @@ use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropDatabasePlan;
 use common_planners::DropTablePlan;
+use metrics::counter;
 
 use crate::catalogs::meta_backend::MetaBackend;
+use crate::catalogs::metrics::METRIC_CACHE_HITS;
+use crate::catalogs::metrics::METRIC_CACHE_MISSES;
 use crate::common::StoreApiProvider;
 
 type TableMetaCache = LruCache<(MetaId, MetaVersion), Arc<TableInfo>>;
@@ -100,8 +103,10 @@ impl MetaBackend for RemoteMeteStoreClient {
         if let Some(ver) = table_version {
             let mut cached = self.table_meta_cache.lock();
             if let Some(meta) = cached.get(&(table_id, ver)) {
+                counter!(METRIC_CACHE_HITS, 1, "cache" => "table_meta");
                 return Ok(meta.clone());
             }
+            counter!(METRIC_CACHE_MISSES, 1, "cache" => "table_meta");
         }
 
         let cli = self.store_api_provider.clone();
@@ -29,6 +29,7 @@ pub use crate::datasources::database_engine::DatabaseEngine;
 mod catalog;
 mod database;
 mod meta_id_ranges;
+mod metrics;
 mod table;
 mod table_function;
 mod table_meta;
@@ -125,6 +125,10 @@ impl Cluster {
         self.nodes.len() <= 1
     }
 
+    pub fn local_id(&self) -> String {
+        self.local_id.clone()
+    }
+
     pub fn is_local(&self, node: &NodeInfo) -> bool {
         node.id == self.local_id
     }
@@ -133,6 +133,20 @@ impl Config {
         Ok(mut_config)
     }
 
+    pub fn check(&self) -> Result<()> {
+        if self.query.initial_admin_user.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "initial_admin_user can not be empty",
+            ));
+        }
+        if self.query.query_log_flush_interval_ms == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "query_log_flush_interval_ms must be greater than 0",
+            ));
+        }
+        Ok(())
+    }
+
     pub fn tls_query_client_conf(&self) -> RpcClientTlsConfig {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.query.rpc_tls_query_server_root_ca_cert.to_string(),
@@ -153,4 +167,13 @@ impl Config {
     pub fn tls_rpc_server_enabled(&self) -> bool {
         !self.query.rpc_tls_server_key.is_empty() && !self.query.rpc_tls_server_cert.is_empty()
     }
+
+    pub fn tls_mysql_handler_enabled(&self) -> bool {
+        !self.query.mysql_tls_server_key.is_empty() && !self.query.mysql_tls_server_cert.is_empty()
+    }
+
+    pub fn tls_clickhouse_handler_enabled(&self) -> bool {
+        !self.query.clickhouse_tls_server_key.is_empty()
+            && !self.query.clickhouse_tls_server_cert.is_empty()
+    }
 }
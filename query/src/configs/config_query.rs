@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+
 use structopt::StructOpt;
 use structopt_toml::StructOptToml;
 
@@ -26,23 +28,35 @@ const QUERY_MYSQL_HANDLER_PORT: &str = "QUERY_MYSQL_HANDLER_PORT";
 const QUERY_MAX_ACTIVE_SESSIONS: &str = "QUERY_MAX_ACTIVE_SESSIONS";
 const QUERY_CLICKHOUSE_HANDLER_HOST: &str = "QUERY_CLICKHOUSE_HANDLER_HOST";
 const QUERY_CLICKHOUSE_HANDLER_PORT: &str = "QUERY_CLICKHOUSE_HANDLER_PORT";
+const QUERY_CLICKHOUSE_TLS_HANDLER_PORT: &str = "QUERY_CLICKHOUSE_TLS_HANDLER_PORT";
 const QUERY_FLIGHT_API_ADDRESS: &str = "QUERY_FLIGHT_API_ADDRESS";
 const QUERY_HTTP_API_ADDRESS: &str = "QUERY_HTTP_API_ADDRESS";
+const QUERY_HTTP_QUERY_EXPIRE_SECS: &str = "QUERY_HTTP_QUERY_EXPIRE_SECS";
 const QUERY_METRICS_API_ADDRESS: &str = "QUERY_METRIC_API_ADDRESS";
 const QUERY_API_TLS_SERVER_CERT: &str = "QUERY_API_TLS_SERVER_CERT";
 const QUERY_API_TLS_SERVER_KEY: &str = "QUERY_API_TLS_SERVER_KEY";
 const QUERY_API_TLS_SERVER_ROOT_CA_CERT: &str = "QUERY_API_TLS_SERVER_ROOT_CA_CERT";
 
+const QUERY_LOG_ENGINE: &str = "QUERY_LOG_ENGINE";
+const QUERY_LOG_DATABASE: &str = "QUERY_LOG_DATABASE";
+const QUERY_LOG_FLUSH_INTERVAL_MS: &str = "QUERY_LOG_FLUSH_INTERVAL_MS";
+
+const QUERY_INITIAL_ADMIN_USER: &str = "QUERY_INITIAL_ADMIN_USER";
+const QUERY_INITIAL_ADMIN_PASSWORD: &str = "QUERY_INITIAL_ADMIN_PASSWORD";
+
 const QUERY_RPC_TLS_SERVER_CERT: &str = "QUERY_RPC_TLS_SERVER_CERT";
 const QUERY_RPC_TLS_SERVER_KEY: &str = "QUERY_RPC_TLS_SERVER_KEY";
 const QUERY_RPC_TLS_SERVER_ROOT_CA_CERT: &str = "QUERY_RPC_TLS_SERVER_ROOT_CA_CERT";
 const QUERY_RPC_TLS_SERVICE_DOMAIN_NAME: &str = "QUERY_RPC_TLS_SERVICE_DOMAIN_NAME";
 
+const QUERY_MYSQL_TLS_SERVER_CERT: &str = "QUERY_MYSQL_TLS_SERVER_CERT";
+const QUERY_MYSQL_TLS_SERVER_KEY: &str = "QUERY_MYSQL_TLS_SERVER_KEY";
+const QUERY_CLICKHOUSE_TLS_SERVER_CERT: &str = "QUERY_CLICKHOUSE_TLS_SERVER_CERT";
+const QUERY_CLICKHOUSE_TLS_SERVER_KEY: &str = "QUERY_CLICKHOUSE_TLS_SERVER_KEY";
+
 /// Query config group.
 /// serde(default) make the toml de to default working.
-#[derive(
-    Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, StructOpt, StructOptToml,
-)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, PartialEq, StructOpt, StructOptToml)]
 pub struct QueryConfig {
     #[structopt(long, env = QUERY_TENANT, default_value = "", help = "Tenant id for get the information from the MetaStore")]
     #[serde(default)]
@@ -92,6 +106,15 @@ pub struct QueryConfig {
     #[serde(default)]
     pub clickhouse_handler_port: u16,
 
+    #[structopt(
+        long,
+        env = QUERY_CLICKHOUSE_TLS_HANDLER_PORT,
+        default_value = "9440",
+        help = "Secure native port, only listened when the clickhouse tls cert and key are set"
+    )]
+    #[serde(default)]
+    pub clickhouse_tls_handler_port: u16,
+
     #[structopt(
     long,
     env = QUERY_FLIGHT_API_ADDRESS,
@@ -108,6 +131,15 @@ pub struct QueryConfig {
     #[serde(default)]
     pub http_api_address: String,
 
+    #[structopt(
+        long,
+        env = QUERY_HTTP_QUERY_EXPIRE_SECS,
+        default_value = "300",
+        help = "Seconds an idle http query session and its buffered results are kept"
+    )]
+    #[serde(default)]
+    pub http_query_expire_secs: u64,
+
     #[structopt(
     long,
     env = QUERY_METRICS_API_ADDRESS,
@@ -161,6 +193,77 @@ pub struct QueryConfig {
     )]
     #[serde(default)]
     pub rpc_tls_query_service_domain_name: String,
+
+    #[structopt(
+        long,
+        env = QUERY_MYSQL_TLS_SERVER_CERT,
+        default_value = "",
+        help = "Cert for the mysql handler, enables STARTTLS together with the key"
+    )]
+    #[serde(default)]
+    pub mysql_tls_server_cert: String,
+
+    #[structopt(long, env = QUERY_MYSQL_TLS_SERVER_KEY, default_value = "")]
+    #[serde(default)]
+    pub mysql_tls_server_key: String,
+
+    #[structopt(
+        long,
+        env = QUERY_CLICKHOUSE_TLS_SERVER_CERT,
+        default_value = "",
+        help = "Cert for the clickhouse secure native port, enables it together with the key"
+    )]
+    #[serde(default)]
+    pub clickhouse_tls_server_cert: String,
+
+    #[structopt(long, env = QUERY_CLICKHOUSE_TLS_SERVER_KEY, default_value = "")]
+    #[serde(default)]
+    pub clickhouse_tls_server_key: String,
+
+    #[structopt(
+        long,
+        env = QUERY_LOG_ENGINE,
+        default_value = "",
+        help = "Table engine to persist the query log, empty keeps it in memory only"
+    )]
+    #[serde(default)]
+    pub query_log_engine: String,
+
+    #[structopt(
+        long,
+        env = QUERY_LOG_DATABASE,
+        default_value = "system_history",
+        help = "Database of the persisted query log table"
+    )]
+    #[serde(default)]
+    pub query_log_database: String,
+
+    #[structopt(
+        long,
+        env = QUERY_LOG_FLUSH_INTERVAL_MS,
+        default_value = "7500",
+        help = "Interval in milliseconds to flush the buffered query log"
+    )]
+    #[serde(default)]
+    pub query_log_flush_interval_ms: u64,
+
+    #[structopt(
+        long,
+        env = QUERY_INITIAL_ADMIN_USER,
+        default_value = "default",
+        help = "The user created with all the privileges if the tenant has no such user yet"
+    )]
+    #[serde(default)]
+    pub initial_admin_user: String,
+
+    #[structopt(
+        long,
+        env = QUERY_INITIAL_ADMIN_PASSWORD,
+        default_value = "",
+        help = "Password of the initial admin user, empty for no password"
+    )]
+    #[serde(default)]
+    pub initial_admin_password: String,
 }
 
 impl QueryConfig {
@@ -174,8 +277,10 @@ impl QueryConfig {
             max_active_sessions: 256,
             clickhouse_handler_host: "127.0.0.1".to_string(),
             clickhouse_handler_port: 9000,
+            clickhouse_tls_handler_port: 9440,
             flight_api_address: "127.0.0.1:9090".to_string(),
             http_api_address: "127.0.0.1:8080".to_string(),
+            http_query_expire_secs: 300,
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
             api_tls_server_key: "".to_string(),
@@ -184,6 +289,15 @@ impl QueryConfig {
             rpc_tls_server_key: "".to_string(),
             rpc_tls_query_server_root_ca_cert: "".to_string(),
             rpc_tls_query_service_domain_name: "localhost".to_string(),
+            mysql_tls_server_cert: "".to_string(),
+            mysql_tls_server_key: "".to_string(),
+            clickhouse_tls_server_cert: "".to_string(),
+            clickhouse_tls_server_key: "".to_string(),
+            query_log_engine: "".to_string(),
+            query_log_database: "system_history".to_string(),
+            query_log_flush_interval_ms: 7500,
+            initial_admin_user: "default".to_string(),
+            initial_admin_password: "".to_string(),
         }
     }
 
@@ -226,6 +340,13 @@ impl QueryConfig {
             u16,
             QUERY_CLICKHOUSE_HANDLER_PORT
         );
+        env_helper!(
+            mut_config,
+            query,
+            clickhouse_tls_handler_port,
+            u16,
+            QUERY_CLICKHOUSE_TLS_HANDLER_PORT
+        );
         env_helper!(
             mut_config,
             query,
@@ -240,6 +361,13 @@ impl QueryConfig {
             String,
             QUERY_HTTP_API_ADDRESS
         );
+        env_helper!(
+            mut_config,
+            query,
+            http_query_expire_secs,
+            u64,
+            QUERY_HTTP_QUERY_EXPIRE_SECS
+        );
         env_helper!(
             mut_config,
             query,
@@ -297,5 +425,126 @@ impl QueryConfig {
             String,
             QUERY_RPC_TLS_SERVICE_DOMAIN_NAME
         );
+
+        // for mysql and clickhouse handlers
+        env_helper!(
+            mut_config,
+            query,
+            mysql_tls_server_cert,
+            String,
+            QUERY_MYSQL_TLS_SERVER_CERT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            mysql_tls_server_key,
+            String,
+            QUERY_MYSQL_TLS_SERVER_KEY
+        );
+        env_helper!(
+            mut_config,
+            query,
+            clickhouse_tls_server_cert,
+            String,
+            QUERY_CLICKHOUSE_TLS_SERVER_CERT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            clickhouse_tls_server_key,
+            String,
+            QUERY_CLICKHOUSE_TLS_SERVER_KEY
+        );
+
+        // for query log
+        env_helper!(
+            mut_config,
+            query,
+            query_log_engine,
+            String,
+            QUERY_LOG_ENGINE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            query_log_database,
+            String,
+            QUERY_LOG_DATABASE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            query_log_flush_interval_ms,
+            u64,
+            QUERY_LOG_FLUSH_INTERVAL_MS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            initial_admin_user,
+            String,
+            QUERY_INITIAL_ADMIN_USER
+        );
+        env_helper!(
+            mut_config,
+            query,
+            initial_admin_password,
+            String,
+            QUERY_INITIAL_ADMIN_PASSWORD
+        );
+    }
+}
+
+impl fmt::Debug for QueryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QueryConfig")
+            .field("tenant", &self.tenant)
+            .field("namespace", &self.namespace)
+            .field("num_cpus", &self.num_cpus)
+            .field("mysql_handler_host", &self.mysql_handler_host)
+            .field("mysql_handler_port", &self.mysql_handler_port)
+            .field("max_active_sessions", &self.max_active_sessions)
+            .field("clickhouse_handler_host", &self.clickhouse_handler_host)
+            .field("clickhouse_handler_port", &self.clickhouse_handler_port)
+            .field(
+                "clickhouse_tls_handler_port",
+                &self.clickhouse_tls_handler_port,
+            )
+            .field("flight_api_address", &self.flight_api_address)
+            .field("http_api_address", &self.http_api_address)
+            .field("http_query_expire_secs", &self.http_query_expire_secs)
+            .field("metric_api_address", &self.metric_api_address)
+            .field("api_tls_server_cert", &self.api_tls_server_cert)
+            .field("api_tls_server_key", &self.api_tls_server_key)
+            .field(
+                "api_tls_server_root_ca_cert",
+                &self.api_tls_server_root_ca_cert,
+            )
+            .field("rpc_tls_server_cert", &self.rpc_tls_server_cert)
+            .field("rpc_tls_server_key", &self.rpc_tls_server_key)
+            .field(
+                "rpc_tls_query_server_root_ca_cert",
+                &self.rpc_tls_query_server_root_ca_cert,
+            )
+            .field(
+                "rpc_tls_query_service_domain_name",
+                &self.rpc_tls_query_service_domain_name,
+            )
+            .field("mysql_tls_server_cert", &self.mysql_tls_server_cert)
+            .field("mysql_tls_server_key", &self.mysql_tls_server_key)
+            .field(
+                "clickhouse_tls_server_cert",
+                &self.clickhouse_tls_server_cert,
+            )
+            .field("clickhouse_tls_server_key", &self.clickhouse_tls_server_key)
+            .field("query_log_engine", &self.query_log_engine)
+            .field("query_log_database", &self.query_log_database)
+            .field(
+                "query_log_flush_interval_ms",
+                &self.query_log_flush_interval_ms,
+            )
+            .field("initial_admin_user", &self.initial_admin_user)
+            .field("initial_admin_password", &"******")
+            .finish()
     }
 }
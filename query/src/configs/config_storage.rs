@@ -23,6 +23,7 @@ const STORAGE_TYPE: &str = "STORAGE_TYPE";
 
 // Disk Storage env.
 const DISK_STORAGE_DATA_PATH: &str = "DISK_STORAGE_DATA_PATH";
+const DISK_STORAGE_UNLOAD_PATH: &str = "DISK_STORAGE_UNLOAD_PATH";
 
 // S3 Storage env.
 const S3_STORAGE_REGION: &str = "S3_STORAGE_REGION";
@@ -43,12 +44,17 @@ pub struct DiskStorageConfig {
     #[structopt(long, env = DISK_STORAGE_DATA_PATH, default_value = "", help = "Disk storage backend address")]
     #[serde(default)]
     pub data_path: String,
+
+    #[structopt(long, env = DISK_STORAGE_UNLOAD_PATH, default_value = "", help = "Base dir of the file:// locations of COPY INTO, which are disabled if empty")]
+    #[serde(default)]
+    pub unload_path: String,
 }
 
 impl DiskStorageConfig {
     pub fn default() -> Self {
         DiskStorageConfig {
             data_path: "".to_string(),
+            unload_path: "".to_string(),
         }
     }
 }
@@ -130,6 +136,13 @@ impl StorageConfig {
             String,
             DISK_STORAGE_DATA_PATH
         );
+        env_helper!(
+            mut_config.storage,
+            disk,
+            unload_path,
+            String,
+            DISK_STORAGE_UNLOAD_PATH
+        );
 
         // S3.
         env_helper!(mut_config.storage, s3, region, String, S3_STORAGE_REGION);
@@ -45,8 +45,10 @@ mysql_handler_port = 3307
 max_active_sessions = 256
 clickhouse_handler_host = \"127.0.0.1\"
 clickhouse_handler_port = 9000
+clickhouse_tls_handler_port = 9440
 flight_api_address = \"127.0.0.1:9090\"
 http_api_address = \"127.0.0.1:8080\"
+http_query_expire_secs = 300
 metric_api_address = \"127.0.0.1:7070\"
 api_tls_server_cert = \"\"
 api_tls_server_key = \"\"
@@ -55,6 +57,15 @@ rpc_tls_server_cert = \"\"
 rpc_tls_server_key = \"\"
 rpc_tls_query_server_root_ca_cert = \"\"
 rpc_tls_query_service_domain_name = \"localhost\"
+mysql_tls_server_cert = \"\"
+mysql_tls_server_key = \"\"
+clickhouse_tls_server_cert = \"\"
+clickhouse_tls_server_key = \"\"
+query_log_engine = \"\"
+query_log_database = \"system_history\"
+query_log_flush_interval_ms = 7500
+initial_admin_user = \"default\"
+initial_admin_password = \"\"
 
 [log]
 log_level = \"INFO\"
@@ -72,6 +83,7 @@ storage_type = \"disk\"
 
 [storage.disk]
 data_path = \"\"
+unload_path = \"\"
 
 [storage.s3]
 region = \"\"
@@ -154,6 +166,34 @@ fn test_env_config() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_check_config() -> Result<()> {
+    let mut conf = Config::default();
+    assert!(conf.check().is_ok());
+    conf.query.initial_admin_user = "".to_string();
+    assert_eq!(
+        conf.check().unwrap_err().message(),
+        "initial_admin_user can not be empty"
+    );
+    conf.query.initial_admin_user = "default".to_string();
+    conf.query.query_log_flush_interval_ms = 0;
+    assert_eq!(
+        conf.check().unwrap_err().message(),
+        "query_log_flush_interval_ms must be greater than 0"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_debug_config_masks_passwords() -> Result<()> {
+    let mut conf = Config::default();
+    conf.query.initial_admin_password = "admin-pwd".to_string();
+    let debug = format!("{:?}", conf);
+    assert!(debug.contains("initial_admin_password: \"******\""));
+    assert!(!debug.contains("admin-pwd"));
+    Ok(())
+}
+
 #[test]
 fn test_fuse_commit_version() -> Result<()> {
     let v = &crate::configs::config::DATABEND_COMMIT_VERSION;
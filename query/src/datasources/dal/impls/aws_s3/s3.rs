@@ -38,7 +38,6 @@ pub struct S3 {
 }
 
 impl S3 {
-    #[allow(dead_code)]
     pub fn new(region: Region, bucket: String) -> Self {
         let client = S3Client::new(region);
         S3 { client, bucket }
@@ -12,3 +12,39 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 //
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::datasources::dal::StorageScheme;
+
+/// Splits an external location into its storage scheme, bucket and key prefix.
+///
+/// `s3://bucket/path/` => (S3, "bucket", "path/")
+/// `file:///tmp/path/` => (LocalFs, "", "/tmp/path/")
+pub fn parse_location(location: &str) -> Result<(StorageScheme, String, String)> {
+    let (scheme, rest) = location.split_once("://").ok_or_else(|| {
+        ErrorCode::BadArguments(format!(
+            "Location {} must be prefixed with a scheme, such as s3://",
+            location
+        ))
+    })?;
+
+    match scheme.to_lowercase().as_str() {
+        "s3" => {
+            let (bucket, prefix) = rest.split_once('/').unwrap_or((rest, ""));
+            if bucket.is_empty() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Location {} has no bucket",
+                    location
+                )));
+            }
+            Ok((StorageScheme::S3, bucket.to_string(), prefix.to_string()))
+        }
+        "file" => Ok((StorageScheme::LocalFs, "".to_string(), rest.to_string())),
+        other => Err(ErrorCode::BadArguments(format!(
+            "Unsupported location scheme {}",
+            other
+        ))),
+    }
+}
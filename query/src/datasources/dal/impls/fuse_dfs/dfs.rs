@@ -0,0 +1,96 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::io::Write;
+use std::sync::Arc;
+
+use common_dfs_api::StorageApi;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::datasources::dal::impls::fuse_dfs::DfsInputStream;
+use crate::datasources::dal::Bytes;
+use crate::datasources::dal::DataAccessor;
+use crate::datasources::dal::InputStream;
+use crate::datasources::dal::SeekableReader;
+
+/// DataAccessor on DatabendStore dfs.
+/// Reads only transfer the requested ranges of a file, thus reading a column chunk does not fetch the whole file.
+pub struct FuseDfs {
+    storage_api: Arc<dyn StorageApi>,
+}
+
+impl FuseDfs {
+    pub fn new(storage_api: Arc<dyn StorageApi>) -> FuseDfs {
+        FuseDfs { storage_api }
+    }
+}
+
+#[async_trait::async_trait]
+impl DataAccessor for FuseDfs {
+    fn get_reader(&self, _path: &str, _len: Option<u64>) -> Result<Box<dyn SeekableReader>> {
+        Err(ErrorCode::UnImplement(
+            "FuseDfs does not support sync reader, use get_input_stream instead",
+        ))
+    }
+
+    fn get_writer(&self, _path: &str) -> Result<Box<dyn Write>> {
+        Err(ErrorCode::UnImplement(
+            "FuseDfs does not support sync writer, use put or put_stream instead",
+        ))
+    }
+
+    async fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream> {
+        Ok(Box::new(DfsInputStream::new(
+            self.storage_api.clone(),
+            path,
+            stream_len,
+        )))
+    }
+
+    async fn get(&self, path: &str) -> Result<Bytes> {
+        let chunks: Vec<Vec<u8>> = self
+            .storage_api
+            .read_file(path, 0, None)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(chunks.concat())
+    }
+
+    async fn put(&self, path: &str, content: Vec<u8>) -> Result<()> {
+        self.storage_api.add_file(path, content).await
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        input_stream: Box<
+            dyn Stream<Item = std::result::Result<Bytes, std::io::Error>> + Send + Unpin + 'static,
+        >,
+        stream_len: usize,
+    ) -> Result<()> {
+        // dfs adds a file atomically, thus the content is collected before sending.
+        let mut content = Vec::with_capacity(stream_len);
+        let mut s = input_stream;
+        while let Some(chunk) = s.next().await {
+            content.extend_from_slice(&chunk?);
+        }
+        self.storage_api.add_file(path, content).await
+    }
+}
@@ -0,0 +1,235 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::BufMut;
+use common_dfs_api::StorageApi;
+use common_dfs_api_vo::FileStream;
+use futures::ready;
+use futures::Future;
+use futures::FutureExt;
+use futures::Stream;
+
+type FileSizeFuture = Pin<Box<dyn Future<Output = Result<u64, Error>> + Send>>;
+type FileStreamFuture = Pin<Box<dyn Future<Output = Result<FileStream, Error>> + Send>>;
+
+enum State {
+    Bare,
+    GettingBody(FileStreamFuture),
+    GotBody(FileStream),
+    Seeking(FileSizeFuture),
+}
+
+/// A seekable input stream of a dfs file.
+/// Only the bytes after the cursor are transferred from dfs, and seeking discards the pending read.
+pub struct DfsInputStream {
+    storage_api: Arc<dyn StorageApi>,
+    key: String,
+
+    state: State,
+
+    buffer: bytes::BytesMut,
+    /// where reading begins
+    cursor_pos: u64,
+    /// total length of target file
+    stream_len: Option<u64>,
+}
+
+impl DfsInputStream {
+    pub fn new(storage_api: Arc<dyn StorageApi>, key: &str, len_hint: Option<u64>) -> Self {
+        Self {
+            storage_api,
+            key: key.to_owned(),
+            state: State::Bare,
+            buffer: bytes::BytesMut::new(),
+            cursor_pos: 0,
+            stream_len: len_hint,
+        }
+    }
+}
+
+impl futures::AsyncRead for DfsInputStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        loop {
+            let empty = { self.buffer.is_empty() };
+            match &mut self.state {
+                State::Bare => {
+                    let storage_api = self.storage_api.clone();
+                    let key = self.key.clone();
+                    let offset = self.cursor_pos;
+                    let resp = async move {
+                        storage_api
+                            .read_file(&key, offset, None)
+                            .await
+                            .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))
+                    };
+                    self.state = State::GettingBody(resp.boxed());
+                }
+                State::GettingBody(resp) => {
+                    let resp = Pin::new(resp);
+                    match ready!(resp.poll(cx)) {
+                        Ok(v) => {
+                            self.state = State::GotBody(v);
+                        }
+                        Err(e) => return Poll::Ready(Err(e)),
+                    }
+                }
+                State::GotBody(stream) => {
+                    if !empty {
+                        return self.do_read(buf);
+                    }
+
+                    match ready!(stream.as_mut().poll_next(cx)) {
+                        Some(Ok(chunk)) => {
+                            self.buffer.put(chunk.as_slice());
+                            return self.do_read(buf);
+                        }
+                        Some(Err(e)) => {
+                            return Poll::Ready(Err(Error::new(ErrorKind::Other, e.to_string())));
+                        }
+                        None => {
+                            return Poll::Ready(Ok(0));
+                        }
+                    }
+                }
+                State::Seeking(_f) => {
+                    // read while seeking is NOT allowed
+                    return Poll::Ready(Err(Error::new(
+                        ErrorKind::Other,
+                        "read while seeking NOT allowed",
+                    )));
+                }
+            }
+        }
+    }
+}
+
+impl futures::AsyncSeek for DfsInputStream {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        match self.stream_len {
+            Some(len) => Poll::Ready(self.seek_with_stream_len(pos, len)),
+            None => loop {
+                match &mut self.state {
+                    State::Seeking(f) => match ready!(Pin::new(f).poll(cx)) {
+                        Ok(len) => {
+                            self.stream_len = Some(len);
+                            self.state = State::Bare;
+                            return Poll::Ready(self.seek_with_stream_len(pos, len));
+                        }
+                        Err(e) => return Poll::Ready(Err(e)),
+                    },
+                    State::Bare => {
+                        let storage_api = self.storage_api.clone();
+                        let key = self.key.clone();
+                        let res = async move {
+                            storage_api
+                                .file_size(&key)
+                                .await
+                                .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))
+                        };
+                        self.state = State::Seeking(res.boxed());
+                    }
+                    State::GettingBody(_) | State::GotBody(_) => self.reset(),
+                };
+            },
+        }
+    }
+}
+
+impl DfsInputStream {
+    /// Drops the pending read and the buffered bytes.
+    /// The next read starts from `cursor_pos` again.
+    fn reset(&mut self) {
+        self.state = State::Bare;
+        self.buffer.clear();
+    }
+
+    fn seek_with_stream_len(
+        mut self: Pin<&mut Self>,
+        pos: SeekFrom,
+        stream_len: u64,
+    ) -> std::io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(start) => (start, 0),
+            SeekFrom::End(end) => (stream_len, end),
+            SeekFrom::Current(current) => (self.cursor_pos, current),
+        };
+
+        let new_pos = if offset >= 0 {
+            base.checked_add(offset as u64)
+        } else {
+            base.checked_sub(offset.wrapping_neg() as u64)
+        };
+
+        // invalid position
+        let new_pos = new_pos.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid seeking operation, current offset {}, SeekFrom {:?}",
+                    self.cursor_pos, pos
+                ),
+            )
+        })?;
+
+        // For this read-only stream, we treat a seeking beyonds end of stream as an error
+        if new_pos > stream_len {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seeking operation",
+            ));
+        }
+        if self.cursor_pos != new_pos {
+            // stop pending read
+            self.reset();
+        }
+        self.cursor_pos = new_pos;
+        Ok(self.cursor_pos)
+    }
+
+    fn do_read(mut self: Pin<&mut Self>, buf: &mut [u8]) -> Poll<std::io::Result<usize>> {
+        let available = std::cmp::min(buf.len(), self.buffer.len());
+        let bytes = self.buffer.split_to(available);
+        let mut new_buf = buf;
+        new_buf.write_all(bytes.as_ref())?;
+        let new_pos = self.cursor_pos.checked_add(available as u64);
+        match new_pos {
+            Some(v) => self.cursor_pos = v,
+            None => {
+                return Poll::Ready(Err(Error::new(
+                    ErrorKind::Other,
+                    "u64 addition overflow detected while moving cursor forward",
+                )))
+            }
+        }
+        Poll::Ready(Ok(available))
+    }
+}
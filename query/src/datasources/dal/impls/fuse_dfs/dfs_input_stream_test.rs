@@ -0,0 +1,166 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datavalues::DataSchemaRef;
+use common_dfs_api::StorageApi;
+use common_dfs_api_vo::AppendResult;
+use common_dfs_api_vo::BlockStream;
+use common_dfs_api_vo::FileStream;
+use common_dfs_api_vo::ReadAction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_streams::SendableDataBlockStream;
+use futures::AsyncReadExt;
+use futures::AsyncSeekExt;
+
+use crate::datasources::dal::DataAccessor;
+use crate::datasources::dal::FuseDfs;
+
+/// Fakes dfs files in memory and records the offsets of every read.
+#[derive(Default)]
+struct FakeStorageApi {
+    files: Mutex<HashMap<String, Vec<u8>>>,
+    read_offsets: Mutex<Vec<u64>>,
+}
+
+#[async_trait::async_trait]
+impl StorageApi for FakeStorageApi {
+    async fn read_partition(
+        &self,
+        _schema: DataSchemaRef,
+        _read_action: &ReadAction,
+    ) -> Result<SendableDataBlockStream> {
+        unimplemented!()
+    }
+
+    async fn append_data(
+        &self,
+        _db_name: String,
+        _tbl_name: String,
+        _scheme_ref: DataSchemaRef,
+        _block_stream: BlockStream,
+    ) -> Result<AppendResult> {
+        unimplemented!()
+    }
+
+    async fn read_file(&self, key: &str, offset: u64, len: Option<u64>) -> Result<FileStream> {
+        let data = self
+            .files
+            .lock()
+            .get(key)
+            .cloned()
+            .ok_or_else(|| ErrorCode::FileMetaNotFound(format!("not found: {}", key)))?;
+        self.read_offsets.lock().push(offset);
+
+        let start = std::cmp::min(offset as usize, data.len());
+        let end = match len {
+            Some(len) => std::cmp::min(start + len as usize, data.len()),
+            None => data.len(),
+        };
+        // stream back in chunks of 3 bytes
+        let chunks: Vec<Result<Vec<u8>>> =
+            data[start..end].chunks(3).map(|x| Ok(x.to_vec())).collect();
+        Ok(Box::pin(futures::stream::iter(chunks)))
+    }
+
+    async fn file_size(&self, key: &str) -> Result<u64> {
+        self.files
+            .lock()
+            .get(key)
+            .map(|x| x.len() as u64)
+            .ok_or_else(|| ErrorCode::FileMetaNotFound(format!("not found: {}", key)))
+    }
+
+    async fn add_file(&self, key: &str, data: Vec<u8>) -> Result<()> {
+        let mut files = self.files.lock();
+        if files.contains_key(key) {
+            return Err(ErrorCode::UnknownException(format!("exists: {}", key)));
+        }
+        files.insert(key.to_string(), data);
+        Ok(())
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fuse_dfs_get_put() -> Result<()> {
+    let api = Arc::new(FakeStorageApi::default());
+    let dfs = FuseDfs::new(api.clone());
+
+    dfs.put("foo", b"0123456789".to_vec()).await?;
+    assert_eq!(b"0123456789".to_vec(), dfs.get("foo").await?);
+
+    let got = dfs.put("foo", b"bar".to_vec()).await;
+    assert!(got.is_err());
+
+    let got = dfs.get("absent").await;
+    assert_eq!("not found: absent", got.unwrap_err().message());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_dfs_input_stream_read_and_seek() -> Result<()> {
+    let api = Arc::new(FakeStorageApi::default());
+    let dfs = FuseDfs::new(api.clone());
+    dfs.put("foo", b"0123456789".to_vec()).await?;
+
+    {
+        // read all
+        let mut input = dfs.get_input_stream("foo", None).await?;
+        let mut buffer = vec![];
+        input.read_to_end(&mut buffer).await?;
+        assert_eq!(b"0123456789".to_vec(), buffer);
+    }
+    {
+        // seek without length hint, the file size is fetched
+        let mut input = dfs.get_input_stream("foo", None).await?;
+        let pos = input.seek(SeekFrom::End(-4)).await?;
+        assert_eq!(6, pos);
+
+        let mut buffer = vec![];
+        input.read_to_end(&mut buffer).await?;
+        assert_eq!(b"6789".to_vec(), buffer);
+
+        let r = input.seek(SeekFrom::End(1)).await;
+        assert!(r.is_err());
+    }
+    {
+        // seek back and forth, the buffered bytes must be discarded
+        let mut input = dfs.get_input_stream("foo", Some(10)).await?;
+        let mut buffer = [0u8; 2];
+
+        input.seek(SeekFrom::Start(3)).await?;
+        input.read_exact(&mut buffer).await?;
+        assert_eq!(b"34", &buffer);
+
+        input.seek(SeekFrom::Current(2)).await?;
+        input.read_exact(&mut buffer).await?;
+        assert_eq!(b"78", &buffer);
+
+        input.seek(SeekFrom::Start(0)).await?;
+        input.read_exact(&mut buffer).await?;
+        assert_eq!(b"01", &buffer);
+    }
+
+    // only the bytes after the cursor are requested
+    assert_eq!(vec![0, 6, 3, 7, 0], *api.read_offsets.lock());
+
+    Ok(())
+}
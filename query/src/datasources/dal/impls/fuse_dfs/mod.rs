@@ -12,3 +12,12 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 //
+
+#[cfg(test)]
+mod dfs_input_stream_test;
+
+mod dfs;
+mod dfs_input_stream;
+
+pub use dfs::FuseDfs;
+pub use dfs_input_stream::DfsInputStream;
@@ -16,6 +16,8 @@
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Write;
+use std::path::Component;
+use std::path::Path;
 use std::path::PathBuf;
 
 use async_compat::CompatExt;
@@ -46,8 +48,9 @@ impl Local {
 
 impl Local {
     fn prefix_with_root(&self, path: &str) -> Result<PathBuf> {
-        let path = self.root.join(path).canonicalize()?;
-        if path.starts_with(&self.root) {
+        let path = self.root.join(path);
+        let escaped = path.components().any(|c| c == Component::ParentDir);
+        if path.starts_with(&self.root) && !escaped && self.resolved_under_root(&path)? {
             Ok(path)
         } else {
             // TODO customize error code
@@ -57,6 +60,28 @@ impl Local {
             )))
         }
     }
+
+    // The path may not exist yet (e.g. put), so the deepest existing ancestor is canonicalized
+    // instead, a symlink inside the root must not lead outside of it.
+    fn resolved_under_root(&self, path: &Path) -> Result<bool> {
+        let root = match self.root.canonicalize() {
+            Ok(root) => root,
+            // nothing exists under a missing root
+            Err(_) => return Ok(true),
+        };
+
+        for ancestor in path.ancestors() {
+            if ancestor.symlink_metadata().is_err() {
+                continue;
+            }
+            // a dangling symlink fails to be canonicalized, it's rejected as well
+            return Ok(match ancestor.canonicalize() {
+                Ok(ancestor) => ancestor.starts_with(&root),
+                Err(_) => false,
+            });
+        }
+        Ok(false)
+    }
 }
 
 #[async_trait::async_trait]
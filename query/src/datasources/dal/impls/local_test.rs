@@ -0,0 +1,47 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+
+use crate::datasources::dal::DataAccessor;
+use crate::datasources::dal::Local;
+
+#[tokio::test]
+async fn test_local_confined_to_root() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path().join("root");
+    let outside = dir.path().join("outside");
+    std::fs::create_dir_all(&root)?;
+    std::fs::create_dir_all(&outside)?;
+    std::fs::write(outside.join("secret"), b"secret")?;
+
+    let local = Local::new(root.to_str().unwrap());
+    local.put("a/b", b"ok".to_vec()).await?;
+    assert_eq!(local.get("a/b").await?, b"ok".to_vec());
+
+    // escaping by the parent dir
+    assert!(local.get("../outside/secret").await.is_err());
+
+    // escaping by a symlink inside the root, to an existing or a new file
+    std::os::unix::fs::symlink(&outside, root.join("link"))?;
+    assert!(local.get("link/secret").await.is_err());
+    assert!(local.put("link/new", b"x".to_vec()).await.is_err());
+    assert!(!outside.join("new").exists());
+
+    // a dangling symlink is rejected too
+    std::os::unix::fs::symlink(outside.join("missing"), root.join("dangling"))?;
+    assert!(local.put("dangling", b"x".to_vec()).await.is_err());
+    Ok(())
+}
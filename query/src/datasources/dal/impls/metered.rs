@@ -0,0 +1,114 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_exception::Result;
+use futures::Stream;
+use metrics::counter;
+use metrics::histogram;
+
+use crate::datasources::dal::metrics::METRIC_DAL_READ_BYTES;
+use crate::datasources::dal::metrics::METRIC_DAL_REQUESTS;
+use crate::datasources::dal::metrics::METRIC_DAL_REQUEST_DURATION;
+use crate::datasources::dal::metrics::METRIC_DAL_WRITE_BYTES;
+use crate::datasources::dal::Bytes;
+use crate::datasources::dal::DataAccessor;
+use crate::datasources::dal::InputStream;
+use crate::datasources::dal::SeekableReader;
+
+/// Wraps a data accessor and records the latency and outcome of every request,
+/// labelled by backend and operation.
+pub struct MeteredAccessor {
+    inner: Arc<dyn DataAccessor>,
+    backend: &'static str,
+}
+
+impl MeteredAccessor {
+    pub fn create(inner: Arc<dyn DataAccessor>, backend: &'static str) -> Arc<dyn DataAccessor> {
+        Arc::new(MeteredAccessor { inner, backend })
+    }
+
+    fn record<T>(&self, operation: &'static str, start: Instant, res: &Result<T>) {
+        let status = if res.is_ok() { "ok" } else { "error" };
+        counter!(METRIC_DAL_REQUESTS, 1,
+            "backend" => self.backend, "operation" => operation, "status" => status);
+        histogram!(METRIC_DAL_REQUEST_DURATION, start.elapsed(),
+            "backend" => self.backend, "operation" => operation);
+    }
+}
+
+#[async_trait::async_trait]
+impl DataAccessor for MeteredAccessor {
+    fn get_reader(&self, path: &str, len: Option<u64>) -> Result<Box<dyn SeekableReader>> {
+        let start = Instant::now();
+        let res = self.inner.get_reader(path, len);
+        self.record("get_reader", start, &res);
+        res
+    }
+
+    fn get_writer(&self, path: &str) -> Result<Box<dyn Write>> {
+        let start = Instant::now();
+        let res = self.inner.get_writer(path);
+        self.record("get_writer", start, &res);
+        res
+    }
+
+    async fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream> {
+        let start = Instant::now();
+        let res = self.inner.get_input_stream(path, stream_len).await;
+        self.record("get_input_stream", start, &res);
+        res
+    }
+
+    async fn get(&self, path: &str) -> Result<Bytes> {
+        let start = Instant::now();
+        let res = self.inner.get(path).await;
+        self.record("get", start, &res);
+        if let Ok(bytes) = &res {
+            counter!(METRIC_DAL_READ_BYTES, bytes.len() as u64, "backend" => self.backend);
+        }
+        res
+    }
+
+    async fn put(&self, path: &str, content: Vec<u8>) -> Result<()> {
+        let len = content.len();
+        let start = Instant::now();
+        let res = self.inner.put(path, content).await;
+        self.record("put", start, &res);
+        if res.is_ok() {
+            counter!(METRIC_DAL_WRITE_BYTES, len as u64, "backend" => self.backend);
+        }
+        res
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        input_stream: Box<
+            dyn Stream<Item = std::result::Result<Bytes, std::io::Error>> + Send + Unpin + 'static,
+        >,
+        stream_len: usize,
+    ) -> Result<()> {
+        let start = Instant::now();
+        let res = self.inner.put_stream(path, input_stream, stream_len).await;
+        self.record("put_stream", start, &res);
+        if res.is_ok() {
+            counter!(METRIC_DAL_WRITE_BYTES, stream_len as u64, "backend" => self.backend);
+        }
+        res
+    }
+}
@@ -13,13 +13,20 @@
 //  limitations under the License.
 //
 
+#[cfg(test)]
+mod local_test;
+
 mod aws_s3;
 mod azure_blob;
 mod builders;
 mod fuse_dfs;
 mod local;
+mod metered;
 mod schemes;
 
 pub use aws_s3::S3;
+pub use builders::parse_location;
+pub use fuse_dfs::FuseDfs;
 pub use local::Local;
+pub use metered::MeteredAccessor;
 pub use schemes::StorageScheme;
@@ -19,3 +19,14 @@ pub enum StorageScheme {
     FuseDfs,
     S3,
 }
+
+impl StorageScheme {
+    /// Short backend name, used as a metric label.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StorageScheme::LocalFs => "local",
+            StorageScheme::FuseDfs => "fusedfs",
+            StorageScheme::S3 => "s3",
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub static METRIC_DAL_REQUESTS: &str = "dal.requests";
+pub static METRIC_DAL_REQUEST_DURATION: &str = "dal.request_duration";
+pub static METRIC_DAL_READ_BYTES: &str = "dal.read_bytes";
+pub static METRIC_DAL_WRITE_BYTES: &str = "dal.write_bytes";
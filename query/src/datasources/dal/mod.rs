@@ -15,12 +15,16 @@
 
 mod blob_accessor;
 mod impls;
+mod metrics;
 
 pub use blob_accessor::AsyncSeekableReader;
 pub use blob_accessor::Bytes;
 pub use blob_accessor::DataAccessor;
 pub use blob_accessor::InputStream;
 pub use blob_accessor::SeekableReader;
+pub use impls::parse_location;
+pub use impls::FuseDfs;
 pub use impls::Local;
+pub use impls::MeteredAccessor;
 pub use impls::StorageScheme;
 pub use impls::S3;
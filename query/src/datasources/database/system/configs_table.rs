@@ -57,7 +57,12 @@ impl ConfigsTable {
         for (k, v) in config_value.as_object().unwrap().into_iter() {
             names.push(k.to_string());
             if let Value::String(s) = v {
-                values.push(s.to_string());
+                // The passwords are not shown, only whether they are set.
+                if k.ends_with("password") && !s.is_empty() {
+                    values.push("******".to_string());
+                } else {
+                    values.push(s.to_string());
+                }
             } else if let Value::Number(n) = v {
                 values.push(n.to_string());
             }
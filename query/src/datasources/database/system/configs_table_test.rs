@@ -24,7 +24,8 @@ use crate::tests::try_create_context_with_config;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_configs_table() -> Result<()> {
-    let config = Config::default();
+    let mut config = Config::default();
+    config.query.initial_admin_password = "pwd".to_string();
     let ctx = try_create_context_with_config(config)?;
     ctx.get_settings().set_max_threads(8)?;
 
@@ -39,7 +40,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 25);
+    assert_eq!(block.num_rows(), 35);
 
     let expected = vec![
         "+-----------------------------------+----------------+-------+-------------+",
@@ -50,8 +51,14 @@ async fn test_configs_table() -> Result<()> {
         "| api_tls_server_root_ca_cert       |                | query |             |",
         "| clickhouse_handler_host           | 127.0.0.1      | query |             |",
         "| clickhouse_handler_port           | 9000           | query |             |",
+        "| clickhouse_tls_handler_port       | 9440           | query |             |",
+        "| clickhouse_tls_server_cert        |                | query |             |",
+        "| clickhouse_tls_server_key         |                | query |             |",
         "| flight_api_address                | 127.0.0.1:9090 | query |             |",
         "| http_api_address                  | 127.0.0.1:8080 | query |             |",
+        "| http_query_expire_secs            | 300            | query |             |",
+        "| initial_admin_password            | ******         | query |             |",
+        "| initial_admin_user                | default        | query |             |",
         "| log_dir                           | ./_logs        | log   |             |",
         "| log_level                         | INFO           | log   |             |",
         "| max_active_sessions               | 256            | query |             |",
@@ -61,8 +68,13 @@ async fn test_configs_table() -> Result<()> {
         "| metric_api_address                | 127.0.0.1:7070 | query |             |",
         "| mysql_handler_host                | 127.0.0.1      | query |             |",
         "| mysql_handler_port                | 3307           | query |             |",
+        "| mysql_tls_server_cert             |                | query |             |",
+        "| mysql_tls_server_key              |                | query |             |",
         "| namespace                         |                | query |             |",
         "| num_cpus                          | 8              | query |             |",
+        "| query_log_database                | system_history | query |             |",
+        "| query_log_engine                  |                | query |             |",
+        "| query_log_flush_interval_ms       | 7500           | query |             |",
         "| rpc_tls_meta_server_root_ca_cert  |                | meta  |             |",
         "| rpc_tls_meta_service_domain_name  | localhost      | meta  |             |",
         "| rpc_tls_query_server_root_ca_cert |                | query |             |",
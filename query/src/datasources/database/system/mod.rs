@@ -21,6 +21,9 @@ pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
+pub use query_log_table::QueryLogTable;
+pub use quotas_table::QuotasTable;
+pub use resource_groups_table::ResourceGroupsTable;
 pub use settings_table::SettingsTable;
 pub use system_database::SystemDatabase;
 pub use tables_table::TablesTable;
@@ -42,6 +45,12 @@ mod engines_table_test;
 #[cfg(test)]
 mod functions_table_test;
 #[cfg(test)]
+mod query_log_table_test;
+#[cfg(test)]
+mod quotas_table_test;
+#[cfg(test)]
+mod resource_groups_table_test;
+#[cfg(test)]
 mod settings_table_test;
 #[cfg(test)]
 mod tables_table_test;
@@ -57,6 +66,9 @@ mod engines_table;
 mod functions_table;
 mod one_table;
 mod processes_table;
+mod query_log_table;
+mod quotas_table;
+mod resource_groups_table;
 mod settings_table;
 mod system_database;
 mod tables_table;
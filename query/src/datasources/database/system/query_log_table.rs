@@ -0,0 +1,122 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_planners::Extras;
+use common_planners::Part;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
+
+use crate::catalogs::Table;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::QueryLog;
+
+pub struct QueryLogTable {
+    table_id: u64,
+    schema: DataSchemaRef,
+}
+
+impl QueryLogTable {
+    pub fn create(table_id: u64) -> Self {
+        QueryLogTable {
+            table_id,
+            schema: QueryLog::schema(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryLogTable {
+    fn name(&self) -> &str {
+        "query_log"
+    }
+
+    fn engine(&self) -> &str {
+        "SystemQueryLog"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn get_id(&self) -> u64 {
+        self.table_id
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _push_downs: Option<Extras>,
+        _partition_num_hint: Option<usize>,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            table_id: self.table_id,
+            table_version: None,
+            schema: self.schema.clone(),
+            parts: vec![Part {
+                name: "".to_string(),
+                version: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.query_log table)".to_string(),
+            scan_plan: Default::default(), // scan_plan will be removed form ReadSourcePlan soon
+            remote: false,
+            tbl_args: None,
+            push_downs: None,
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let sessions_manager = ctx.get_sessions_manager();
+        let query_log = sessions_manager.get_query_log();
+        let records = query_log.buffered_records();
+
+        let schema = self.schema.clone();
+        let buffered =
+            DataBlockStream::create(schema.clone(), None, vec![QueryLog::to_block(&records)]);
+
+        // The flushed records are read from the query log table, with its own partitions.
+        match sessions_manager.get_query_log_table(&ctx)? {
+            None => Ok(Box::pin(buffered)),
+            Some(table) => {
+                let table_ctx = DatabendQueryContext::new(ctx.clone());
+                let plan = table.raw().read_plan(table_ctx.clone(), None, None)?;
+                table_ctx.try_set_partitions(plan.parts.clone())?;
+                let flushed = table.raw().read(table_ctx, &plan).await?;
+                Ok(Box::pin(flushed.chain(buffered)))
+            }
+        }
+    }
+}
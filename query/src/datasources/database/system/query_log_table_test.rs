@@ -0,0 +1,91 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::catalogs::Table;
+use crate::clusters::Cluster;
+use crate::datasources::database::system::QueryLogTable;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextShared;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let sessions = ctx.get_sessions_manager();
+    let session = sessions.create_session("TestSession")?;
+    session.set_current_user(String::from("default"));
+
+    // The query is recorded when its context is destroyed.
+    let run_query = |query: &str, error: Option<ErrorCode>| {
+        let query_ctx = DatabendQueryContext::from_shared(DatabendQueryContextShared::try_create(
+            sessions.get_conf().clone(),
+            Arc::new(session.as_ref().clone()),
+            Cluster::empty(),
+        ));
+        query_ctx.attach_query_str(query);
+        match error {
+            Some(error) => query_ctx.attach_query_error(&error),
+            None => query_ctx.add_result_block(&DataBlock::create_by_array(
+                DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+                vec![Series::new(vec![1u64, 2, 3])],
+            )),
+        }
+    };
+    run_query("SELECT 1", None);
+    run_query(
+        "SELECT * FROM t",
+        Some(ErrorCode::UnknownTable("Unknown table: 't'")),
+    );
+
+    let table = QueryLogTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None, None)?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 14);
+    assert_eq!(block.num_rows(), 2);
+
+    let values = |name: &str| block.try_column_by_name(name)?.to_values();
+    assert_eq!(values("query_text")?, vec![
+        DataValue::String(Some(b"SELECT 1".to_vec())),
+        DataValue::String(Some(b"SELECT * FROM t".to_vec())),
+    ]);
+    assert_eq!(values("user")?, vec![
+        DataValue::String(Some(b"default".to_vec())),
+        DataValue::String(Some(b"default".to_vec())),
+    ]);
+    assert_eq!(values("result_rows")?, vec![
+        DataValue::UInt64(Some(3)),
+        DataValue::UInt64(Some(0)),
+    ]);
+    assert_eq!(values("error_code")?, vec![
+        DataValue::UInt16(Some(0)),
+        DataValue::UInt16(Some(25)),
+    ]);
+    assert_eq!(values("exception")?, vec![
+        DataValue::String(None),
+        DataValue::String(Some(b"Unknown table: 't'".to_vec())),
+    ]);
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_datavalues::series::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_management::QuotaUsage;
+use common_management::UserQuota;
+use common_planners::Extras;
+use common_planners::Part;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Table;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct QuotasTable {
+    table_id: u64,
+    schema: DataSchemaRef,
+}
+
+impl QuotasTable {
+    pub fn create(table_id: u64) -> Self {
+        QuotasTable {
+            table_id,
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("name", DataType::String, false),
+                DataField::new("type", DataType::String, false),
+                DataField::new("interval_secs", DataType::UInt64, false),
+                DataField::new("max_queries", DataType::UInt64, false),
+                DataField::new("max_read_rows", DataType::UInt64, false),
+                DataField::new("max_read_bytes", DataType::UInt64, false),
+                DataField::new("max_execution_time_ms", DataType::UInt64, false),
+                DataField::new("queries", DataType::UInt64, true),
+                DataField::new("read_rows", DataType::UInt64, true),
+                DataField::new("read_bytes", DataType::UInt64, true),
+                DataField::new("execution_time_ms", DataType::UInt64, true),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QuotasTable {
+    fn name(&self) -> &str {
+        "quotas"
+    }
+
+    fn engine(&self) -> &str {
+        "SystemQuotas"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn get_id(&self) -> u64 {
+        self.table_id
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _push_downs: Option<Extras>,
+        _partition_num_hint: Option<usize>,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            table_id: self.table_id,
+            table_version: None,
+            schema: self.schema.clone(),
+            parts: vec![Part {
+                name: "".to_string(),
+                version: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.quotas table)".to_string(),
+            scan_plan: Default::default(), // scan_plan will be removed form ReadSourcePlan soon
+            remote: false,
+            tbl_args: None,
+            push_downs: None,
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let user_manager = ctx.get_sessions_manager().get_user_manager();
+
+        // The usage of the current interval is of the users, the roles have no usage.
+        let mut quotas: Vec<(String, &str, UserQuota, Option<QuotaUsage>)> = vec![];
+        for user in user_manager.get_users()? {
+            if let Some(quota) = user.quota {
+                let usage = user_manager.get_quota_usage(&user.name, &quota)?;
+                quotas.push((user.name, "USER", quota, Some(usage)));
+            }
+        }
+        for role in user_manager.get_roles()? {
+            if let Some(quota) = role.quota {
+                quotas.push((role.name, "ROLE", quota, None));
+            }
+        }
+
+        let names: Vec<&[u8]> = quotas.iter().map(|q| q.0.as_bytes()).collect();
+        let types: Vec<&[u8]> = quotas.iter().map(|q| q.1.as_bytes()).collect();
+        let intervals: Vec<u64> = quotas.iter().map(|q| q.2.interval_secs).collect();
+        let max_queries: Vec<u64> = quotas.iter().map(|q| q.2.max_queries).collect();
+        let max_read_rows: Vec<u64> = quotas.iter().map(|q| q.2.max_read_rows).collect();
+        let max_read_bytes: Vec<u64> = quotas.iter().map(|q| q.2.max_read_bytes).collect();
+        let max_execution_time_ms: Vec<u64> =
+            quotas.iter().map(|q| q.2.max_execution_time_ms).collect();
+        let usage = |f: fn(&QuotaUsage) -> u64| -> Vec<Option<u64>> {
+            quotas.iter().map(|q| q.3.as_ref().map(f)).collect()
+        };
+
+        let schema = self.schema.clone();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(names),
+            Series::new(types),
+            Series::new(intervals),
+            Series::new(max_queries),
+            Series::new(max_read_rows),
+            Series::new(max_read_bytes),
+            Series::new(max_execution_time_ms),
+            Series::new(usage(|u| u.queries)),
+            Series::new(usage(|u| u.read_rows)),
+            Series::new(usage(|u| u.read_bytes)),
+            Series::new(usage(|u| u.execution_time_ms)),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
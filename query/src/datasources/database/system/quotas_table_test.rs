@@ -0,0 +1,69 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::AuthType;
+use common_management::QuotaUsage;
+use common_management::RoleInfo;
+use common_management::UserQuota;
+use futures::TryStreamExt;
+
+use crate::catalogs::Table;
+use crate::datasources::database::system::QuotasTable;
+use crate::users::User;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_quotas_table() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_manager = ctx.get_sessions_manager().get_user_manager();
+    user_manager.add_user(User::new("test", "", AuthType::None).into())?;
+    user_manager.add_role(RoleInfo::new("analyst".to_string()))?;
+
+    let mut user_quota = UserQuota::new(0);
+    user_quota.max_queries = 100;
+    user_manager.set_user_quota("test", Some(user_quota))?;
+
+    let mut role_quota = UserQuota::new(3600);
+    role_quota.max_read_rows = 1000;
+    role_quota.max_execution_time_ms = 60000;
+    user_manager.set_role_quota("analyst", Some(role_quota))?;
+
+    user_manager.record_quota_usage("test", &QuotaUsage {
+        queries: 1,
+        read_rows: 10,
+        read_bytes: 80,
+        execution_time_ms: 5,
+        ..Default::default()
+    })?;
+
+    let table = QuotasTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None, None)?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 11);
+
+    let expected = vec![
+        "+---------+------+---------------+-------------+---------------+----------------+-----------------------+---------+-----------+------------+-------------------+",
+        "| name    | type | interval_secs | max_queries | max_read_rows | max_read_bytes | max_execution_time_ms | queries | read_rows | read_bytes | execution_time_ms |",
+        "+---------+------+---------------+-------------+---------------+----------------+-----------------------+---------+-----------+------------+-------------------+",
+        "| analyst | ROLE | 3600          | 0           | 1000          | 0              | 60000                 | NULL    | NULL      | NULL       | NULL              |",
+        "| test    | USER | 0             | 100         | 0             | 0              | 0                     | 1       | 10        | 80         | 5                 |",
+        "+---------+------+---------------+-------------+---------------+----------------+-----------------------+---------+-----------+------------+-------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
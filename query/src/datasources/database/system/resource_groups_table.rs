@@ -0,0 +1,142 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_datavalues::series::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_planners::Extras;
+use common_planners::Part;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Table;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct ResourceGroupsTable {
+    table_id: u64,
+    schema: DataSchemaRef,
+}
+
+impl ResourceGroupsTable {
+    pub fn create(table_id: u64) -> Self {
+        ResourceGroupsTable {
+            table_id,
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("name", DataType::String, false),
+                DataField::new("max_concurrency", DataType::UInt64, false),
+                DataField::new("max_queued", DataType::UInt64, false),
+                DataField::new("running", DataType::UInt64, false),
+                DataField::new("queued", DataType::UInt64, false),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ResourceGroupsTable {
+    fn name(&self) -> &str {
+        "resource_groups"
+    }
+
+    fn engine(&self) -> &str {
+        "SystemResourceGroups"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn get_id(&self) -> u64 {
+        self.table_id
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _push_downs: Option<Extras>,
+        _partition_num_hint: Option<usize>,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            table_id: self.table_id,
+            table_version: None,
+            schema: self.schema.clone(),
+            parts: vec![Part {
+                name: "".to_string(),
+                version: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.resource_groups table)".to_string(),
+            scan_plan: Default::default(), // scan_plan will be removed form ReadSourcePlan soon
+            remote: false,
+            tbl_args: None,
+            push_downs: None,
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let sessions_manager = ctx.get_sessions_manager();
+        let resource_groups = sessions_manager.get_user_manager().get_resource_groups()?;
+        // The running and queued queries are of this node.
+        let queues = sessions_manager.get_resource_group_queues();
+
+        let mut names = Vec::with_capacity(resource_groups.len());
+        let mut max_concurrencies = Vec::with_capacity(resource_groups.len());
+        let mut max_queued = Vec::with_capacity(resource_groups.len());
+        let mut running = Vec::with_capacity(resource_groups.len());
+        let mut queued = Vec::with_capacity(resource_groups.len());
+
+        for resource_group in &resource_groups {
+            let queue = queues.get(&resource_group.name);
+            names.push(resource_group.name.as_bytes());
+            max_concurrencies.push(resource_group.max_concurrency);
+            max_queued.push(resource_group.max_queued);
+            running.push(queue.map(|queue| queue.running()).unwrap_or(0));
+            queued.push(queue.map(|queue| queue.queued()).unwrap_or(0));
+        }
+
+        let schema = self.schema.clone();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(names),
+            Series::new(max_concurrencies),
+            Series::new(max_queued),
+            Series::new(running),
+            Series::new(queued),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::ResourceGroupInfo;
+use futures::TryStreamExt;
+
+use crate::catalogs::Table;
+use crate::datasources::database::system::ResourceGroupsTable;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resource_groups_table() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let sessions = ctx.get_sessions_manager();
+    let user_manager = sessions.get_user_manager();
+    user_manager.add_resource_group(ResourceGroupInfo::new("etl".to_string(), 2, 10))?;
+    user_manager.add_resource_group(ResourceGroupInfo::new("dashboard".to_string(), 8, 0))?;
+
+    let queue = sessions.get_resource_group_queue("etl")?;
+    let _permit = queue.acquire().await?;
+
+    let table = ResourceGroupsTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None, None)?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 5);
+
+    let expected = vec![
+        "+-----------+-----------------+------------+---------+--------+",
+        "| name      | max_concurrency | max_queued | running | queued |",
+        "+-----------+-----------------+------------+---------+--------+",
+        "| dashboard | 8               | 0          | 0       | 0      |",
+        "| etl       | 2               | 10         | 1       | 0      |",
+        "+-----------+-----------------+------------+---------+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -40,7 +40,9 @@ impl SettingsTable {
                 DataField::new("name", DataType::String, false),
                 DataField::new("value", DataType::String, false),
                 DataField::new("default_value", DataType::String, false),
+                DataField::new("level", DataType::String, false),
                 DataField::new("description", DataType::String, false),
+                DataField::new("type", DataType::String, false),
             ]),
         }
     }
@@ -107,25 +109,31 @@ impl Table for SettingsTable {
         let mut names: Vec<String> = vec![];
         let mut values: Vec<String> = vec![];
         let mut default_values: Vec<String> = vec![];
+        let mut levels: Vec<String> = vec![];
         let mut descs: Vec<String> = vec![];
+        let mut types: Vec<String> = vec![];
         for setting in settings.iter() {
-            if let DataValue::Struct(vals) = setting {
-                names.push(format!("{:?}", vals[0]));
-                values.push(format!("{:?}", vals[1]));
-                default_values.push(format!("{:?}", vals[2]));
-                descs.push(format!("{:?}", vals[3]));
-            }
+            names.push(setting.name.to_string());
+            values.push(format!("{:?}", setting.value));
+            default_values.push(format!("{:?}", setting.default_value));
+            levels.push(setting.level.to_string());
+            descs.push(setting.desc.to_string());
+            types.push(setting.type_name().to_string());
         }
 
         let names: Vec<&[u8]> = names.iter().map(|x| x.as_bytes()).collect();
         let values: Vec<&[u8]> = values.iter().map(|x| x.as_bytes()).collect();
         let default_values: Vec<&[u8]> = default_values.iter().map(|x| x.as_bytes()).collect();
+        let levels: Vec<&[u8]> = levels.iter().map(|x| x.as_bytes()).collect();
         let descs: Vec<&[u8]> = descs.iter().map(|x| x.as_bytes()).collect();
+        let types: Vec<&[u8]> = types.iter().map(|x| x.as_bytes()).collect();
         let block = DataBlock::create_by_array(self.schema.clone(), vec![
             Series::new(names),
             Series::new(values),
             Series::new(default_values),
+            Series::new(levels),
             Series::new(descs),
+            Series::new(types),
         ]);
         Ok(Box::pin(DataBlockStream::create(
             self.schema.clone(),
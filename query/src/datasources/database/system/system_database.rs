@@ -65,6 +65,9 @@ impl SystemDatabase {
             Arc::new(system::TracingTable::create(next_id())),
             Arc::new(system::ProcessesTable::create(next_id())),
             Arc::new(system::ConfigsTable::create(next_id())),
+            Arc::new(system::QueryLogTable::create(next_id())),
+            Arc::new(system::ResourceGroupsTable::create(next_id())),
+            Arc::new(system::QuotasTable::create(next_id())),
         ];
 
         let tbl_meta_list = table_list.into_iter().map(|t| {
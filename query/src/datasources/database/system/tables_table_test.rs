@@ -35,22 +35,25 @@ async fn test_tables_table() -> Result<()> {
     assert_eq!(block.num_columns(), 3);
 
     let expected = vec![
-        "+----------+--------------+--------------------+",
-        "| database | name         | engine             |",
-        "+----------+--------------+--------------------+",
-        "| system   | clusters     | SystemClusters     |",
-        "| system   | configs      | SystemConfigs      |",
-        "| system   | contributors | SystemContributors |",
-        "| system   | credits      | SystemCredits      |",
-        "| system   | databases    | SystemDatabases    |",
-        "| system   | engines      | SystemEngines      |",
-        "| system   | functions    | SystemFunctions    |",
-        "| system   | one          | SystemOne          |",
-        "| system   | processes    | SystemProcesses    |",
-        "| system   | settings     | SystemSettings     |",
-        "| system   | tables       | SystemTables       |",
-        "| system   | tracing      | SystemTracing      |",
-        "+----------+--------------+--------------------+",
+        "+----------+-----------------+----------------------+",
+        "| database | name            | engine               |",
+        "+----------+-----------------+----------------------+",
+        "| system   | clusters        | SystemClusters       |",
+        "| system   | configs         | SystemConfigs        |",
+        "| system   | contributors    | SystemContributors   |",
+        "| system   | credits         | SystemCredits        |",
+        "| system   | databases       | SystemDatabases      |",
+        "| system   | engines         | SystemEngines        |",
+        "| system   | functions       | SystemFunctions      |",
+        "| system   | one             | SystemOne            |",
+        "| system   | processes       | SystemProcesses      |",
+        "| system   | query_log       | SystemQueryLog       |",
+        "| system   | quotas          | SystemQuotas         |",
+        "| system   | resource_groups | SystemResourceGroups |",
+        "| system   | settings        | SystemSettings       |",
+        "| system   | tables          | SystemTables         |",
+        "| system   | tracing         | SystemTracing        |",
+        "+----------+-----------------+----------------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
@@ -15,15 +15,11 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use common_arrow::arrow::datatypes::Schema as ArrowSchema;
-use common_arrow::arrow::io::parquet::write::WriteOptions;
-use common_arrow::arrow::io::parquet::write::*;
-use common_arrow::arrow::record_batch::RecordBatch;
 use common_datablocks::DataBlock;
+use common_datablocks::ParquetWriteOptions;
 use common_datavalues::columns::DataColumn;
 use common_datavalues::DataType;
 use common_dfs_api_vo::BlockStream;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::StreamExt;
 use uuid::Uuid;
@@ -52,9 +48,9 @@ impl FuseTable {
         let mut summary_block_count = 0u64;
         let mut summary_uncompressed_byte_size = 0u64;
         let mut summary_compressed_byte_size = 0u64;
+        let parquet_options = ParquetWriteOptions::from_table_options(&self.tbl_info.options)?;
 
         while let Some(block) = stream.next().await {
-            let schema = block.schema().to_arrow();
             let blk_stats = block_stats(&block)?;
 
             let row_count = block.num_rows() as u64;
@@ -65,7 +61,7 @@ impl FuseTable {
             let part_uuid = Uuid::new_v4().to_simple().to_string() + ".parquet";
             let location = block_location(&part_uuid);
 
-            let file_size = save_block(&schema, block, data_accessor, &location)?;
+            let file_size = save_block(block, data_accessor, &location, &parquet_options)?;
 
             // TODO gather parquet meta
             let meta_size = 0u64;
@@ -83,6 +79,7 @@ impl FuseTable {
                 row_count,
                 block_size: block_in_memory_size,
                 col_stats,
+                parquet_options,
             };
 
             block_metas.push(block_info);
@@ -113,6 +110,8 @@ pub fn block_stats(data_block: &DataBlock) -> Result<HashMap<ColumnId, (DataType
     // TODO column id is FAKED, this is OK as long as table schema is NOT changed, which is not realistic
     // we should extend DataField with column_id ...
 
+    // The min/max of a Variant column are computed on its JSON text, min/max per
+    // extracted path are not implemented.
     let row_count = data_block.num_rows();
     (0..).into_iter().zip(data_block.columns().iter()).try_fold(
         HashMap::new(),
@@ -153,38 +152,11 @@ pub fn block_stats(data_block: &DataBlock) -> Result<HashMap<ColumnId, (DataType
 }
 
 pub(crate) fn save_block(
-    arrow_schema: &ArrowSchema,
     block: DataBlock,
     data_accessor: Arc<dyn DataAccessor>,
     location: &str,
+    options: &ParquetWriteOptions,
 ) -> Result<u64> {
-    // TODO pick proper compression / encoding algos
-    let options = WriteOptions {
-        write_statistics: true,
-        compression: Compression::Uncompressed,
-        version: Version::V2,
-    };
-    use std::iter::repeat;
-    let encodings: Vec<_> = repeat(Encoding::Plain).take(block.num_columns()).collect();
-
-    let batch = RecordBatch::try_from(block)?;
-    let iter = vec![Ok(batch)];
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), arrow_schema, options, encodings)?;
-    let parquet_schema = row_groups.parquet_schema().clone();
     let mut writer = data_accessor.get_writer(location)?;
-
-    // arrow2 convert schema to metadata, is it required?
-    // -- let key_value_metadata = Some(vec![schema_to_metadata_key(schema)]);
-
-    let len = common_arrow::parquet::write::write_file(
-        &mut writer,
-        row_groups,
-        parquet_schema,
-        options,
-        None,
-        None,
-    )
-    .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
-
-    Ok(len)
+    DataBlock::write_parquet(block, &mut writer, options)
 }
@@ -13,23 +13,37 @@
 //  limitations under the License.
 //
 
+use std::io::SeekFrom;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::datatypes::DataType;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::read::decompress;
 use common_arrow::arrow::io::parquet::read::page_stream_to_array;
 use common_arrow::arrow::io::parquet::read::read_metadata_async;
+use common_arrow::parquet::metadata::ColumnChunkMetaData;
 use common_arrow::parquet::read::get_page_stream;
 use common_base::tokio::sync::mpsc::Sender;
+use common_base::tokio::task;
 use common_cache::LruCache;
 use common_datablocks::DataBlock;
 use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::IntoSeries;
 use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_planners::Part;
+use futures::io::Cursor;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+use futures::AsyncSeek;
+use futures::AsyncSeekExt;
 use futures::StreamExt;
 
 use crate::datasources::dal::DataAccessor;
@@ -84,40 +98,105 @@ pub(crate) async fn read_part(
         .await
         .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
-    // only onw page in the the parquet
-    let row_group = 0;
-    let cols = projection
-        .iter()
-        .map(|idx| (metadata.row_groups[row_group].column(*idx), *idx));
-
+    // one block for each row group
     let fields = arrow_schema.fields();
-    let mut arrays: Vec<Arc<dyn common_arrow::arrow::array::Array>> = vec![];
-    for (col_meta, idx) in cols {
-        // NOTE: here the page filter is !Send
-        let pages = get_page_stream(col_meta, &mut reader, vec![], Arc::new(|_, _| true))
-            .await
-            .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
-        let pages = pages.map(|compressed_page| decompress(compressed_page?, &mut vec![]));
+    let schema = Arc::new(DataSchema::from(arrow_schema));
+    for row_group in &metadata.row_groups {
+        // Only the column chunks of the projection are read.
+        let mut chunks = Vec::with_capacity(projection.len());
+        for idx in &projection {
+            let col_meta = row_group.column(*idx).clone();
+            let (start, len) = col_meta.byte_range();
+            let mut bytes = vec![0; len as usize];
+            reader.seek(SeekFrom::Start(start)).await?;
+            reader.read_exact(&mut bytes).await?;
+
+            let chunk = ColumnChunk::create(start, bytes);
+            chunks.push((col_meta, fields[*idx].data_type.clone(), chunk));
+        }
+
         // QUOTE(from arrow2): deserialize the pages. This is CPU bounded and SHOULD be done in a dedicated thread pool (e.g. Rayon)
-        let array = page_stream_to_array(
-            pages,
-            &metadata.row_groups[0].columns()[idx],
-            fields[idx].data_type.clone(),
-        )
-        .await?;
-        arrays.push(array.into());
+        let schema = schema.clone();
+        let block = task::spawn_blocking(move || decode_row_group(schema, chunks))
+            .await
+            .map_err(|e| ErrorCode::TokioError(e.to_string()))??;
+
+        sender
+            .send(Ok(block))
+            .await
+            .map_err(|e| ErrorCode::BrokenChannel(e.to_string()))?;
     }
 
-    let ser = arrays
-        .into_iter()
-        .map(|a| DataColumn::Array(a.into_series()))
-        .collect::<Vec<_>>();
+    Ok(())
+}
 
-    let block = DataBlock::create(Arc::new(DataSchema::from(arrow_schema)), ser);
-    sender
-        .send(Ok(block))
-        .await
-        .map_err(|e| ErrorCode::BrokenChannel(e.to_string()))?;
+// The column chunks are in memory, so the page streams never wait on IO. They're polled to
+// the end on the blocking thread, where the page filter, which is !Send, doesn't matter.
+fn decode_row_group(
+    schema: DataSchemaRef,
+    chunks: Vec<(ColumnChunkMetaData, DataType, ColumnChunk)>,
+) -> Result<DataBlock> {
+    let mut columns = Vec::with_capacity(chunks.len());
+    for (col_meta, data_type, mut chunk) in chunks {
+        let array: Arc<dyn Array> = futures::executor::block_on(async {
+            let pages = get_page_stream(&col_meta, &mut chunk, vec![], Arc::new(|_, _| true))
+                .await
+                .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+            let pages = pages.map(|compressed_page| decompress(compressed_page?, &mut vec![]));
+            let array = page_stream_to_array(pages, &col_meta, data_type).await?;
+            Result::Ok(array.into())
+        })?;
+        columns.push(DataColumn::Array(array.into_series()));
+    }
 
-    Ok(())
+    Ok(DataBlock::create(schema, columns))
+}
+
+/// A column chunk read from a block, it's seeked by the offsets in the block like the block.
+struct ColumnChunk {
+    start: u64,
+    cursor: Cursor<Vec<u8>>,
+}
+
+impl ColumnChunk {
+    fn create(start: u64, bytes: Vec<u8>) -> ColumnChunk {
+        ColumnChunk {
+            start,
+            cursor: Cursor::new(bytes),
+        }
+    }
+}
+
+impl AsyncRead for ColumnChunk {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.cursor).poll_read(cx, buf)
+    }
+}
+
+impl AsyncSeek for ColumnChunk {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        let pos = match pos {
+            SeekFrom::Start(offset) if offset < self.start => {
+                return Poll::Ready(Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "seek before the column chunk",
+                )));
+            }
+            SeekFrom::Start(offset) => SeekFrom::Start(offset - self.start),
+            pos => pos,
+        };
+
+        let start = self.start;
+        Pin::new(&mut self.cursor)
+            .poll_seek(cx, pos)
+            .map(|res| res.map(|offset| offset + start))
+    }
 }
@@ -15,6 +15,8 @@
 
 mod meta_info_reader;
 mod table_snapshot;
+#[cfg(test)]
+mod table_snapshot_test;
 
 pub use meta_info_reader::MetaInfoReader;
 pub use table_snapshot::*;
@@ -16,6 +16,7 @@
 use std::collections::HashMap;
 
 use common_arrow::parquet::statistics::Statistics;
+use common_datablocks::ParquetWriteOptions;
 use common_datavalues::DataSchema;
 use common_datavalues::DataValue;
 use uuid::Uuid;
@@ -69,6 +70,9 @@ pub struct BlockMeta {
     pub block_size: u64,
     pub col_stats: HashMap<ColumnId, ColStats>,
     pub location: BlockLocation,
+    /// How the block is compressed and encoded, absent in the blocks written before it is recorded
+    #[serde(default)]
+    pub parquet_options: ParquetWriteOptions,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
@@ -0,0 +1,39 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::ParquetCompression;
+use common_datablocks::ParquetWriteOptions;
+use common_exception::Result;
+
+use crate::datasources::table::fuse::BlockMeta;
+
+#[test]
+fn test_block_meta_without_parquet_options() -> Result<()> {
+    // Block metas written before the parquet options are recorded
+    let json = r#"{
+        "row_count": 1,
+        "block_size": 10,
+        "col_stats": {},
+        "location": {"location": "_b/1.parquet", "meta_size": 0}
+    }"#;
+
+    let meta: BlockMeta = serde_json::from_str(json)?;
+    assert_eq!(meta.parquet_options, ParquetWriteOptions::default());
+    assert_eq!(
+        meta.parquet_options.compression,
+        ParquetCompression::Uncompressed
+    );
+
+    Ok(())
+}
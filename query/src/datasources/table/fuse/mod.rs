@@ -15,6 +15,7 @@
 
 mod io;
 mod meta;
+mod metrics;
 mod table;
 mod util;
 
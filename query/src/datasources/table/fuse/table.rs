@@ -28,11 +28,14 @@ use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
+use metrics::counter;
 use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
 
 use crate::catalogs::Table;
 use crate::datasources::dal::DataAccessor;
+use crate::datasources::table::fuse::metrics::METRIC_STORAGE_PARTITIONS_PRUNED;
+use crate::datasources::table::fuse::metrics::METRIC_STORAGE_PARTITIONS_TOTAL;
 use crate::datasources::table::fuse::range_filter;
 use crate::datasources::table::fuse::read_part;
 use crate::datasources::table::fuse::read_table_snapshot;
@@ -114,7 +117,13 @@ impl Table for FuseTable {
             let da = self.data_accessor(&ctx)?;
             let meta_reader = MetaInfoReader::new(da, ctx.clone());
             let block_locations = range_filter(&snapshot, &push_downs, meta_reader)?;
-            let (statistics, parts) = self.to_partitions(&block_locations);
+            let (mut statistics, parts) = self.to_partitions(&block_locations);
+            statistics.partitions_total = snapshot.summary.block_count as usize;
+
+            let pruned = statistics.partitions_total.saturating_sub(parts.len());
+            let engine = self.engine().to_string();
+            counter!(METRIC_STORAGE_PARTITIONS_TOTAL, statistics.partitions_total as u64, "engine" => engine.clone());
+            counter!(METRIC_STORAGE_PARTITIONS_PRUNED, pruned as u64, "engine" => engine);
             let plan = ReadDataSourcePlan {
                 db: self.tbl_info.db.to_string(),
                 table: self.name().to_string(),
@@ -176,20 +185,24 @@ impl Table for FuseTable {
         };
         let da = self.data_accessor(&ctx)?;
         let arrow_schema = self.tbl_info.schema.to_arrow();
-        let _h = common_base::tokio::task::spawn_local(async move {
-            // TODO error handling is buggy
+        let cancellation = ctx.get_cancellation_token();
+        ctx.execute_task(async move {
             for part in &mut iter {
-                read_part(
-                    part,
-                    da.clone(),
-                    projection.clone(),
-                    tx.clone(),
-                    &arrow_schema,
-                )
-                .await?;
+                // Stop reading the parts once the query is cancelled.
+                let read = match cancellation.check() {
+                    Err(cause) => Err(cause),
+                    Ok(_) => {
+                        let sender = tx.clone();
+                        read_part(part, da.clone(), projection.clone(), sender, &arrow_schema).await
+                    }
+                };
+
+                if let Err(cause) = read {
+                    let _ = tx.send(Err(cause)).await;
+                    break;
+                }
             }
-            Ok::<(), ErrorCode>(())
-        });
+        })?;
 
         let progress_callback = ctx.progress_callback()?;
         let receiver = ReceiverStream::new(rx);
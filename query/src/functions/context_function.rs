@@ -16,6 +16,7 @@ use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::scalars::DateFunction;
 use common_functions::scalars::FunctionFactory;
 use common_planners::Expression;
 
@@ -48,4 +49,20 @@ impl ContextFunction {
             _ => vec![],
         })
     }
+
+    // Some function args are appended from context
+    // such as `SELECT toYYYYMMDD(now())`, the last arg is the session timezone
+    // They are hidden from the column name, which is still `toYYYYMMDD(now())`
+    pub fn build_trailing_args_from_ctx(
+        name: &str,
+        ctx: DatabendQueryContextRef,
+    ) -> Result<Vec<Expression>> {
+        if DateFunction::is_timezone_aware(name) {
+            let tz = ctx.get_settings().get_timezone()?;
+            return Ok(vec![Expression::create_hidden_literal(DataValue::String(
+                Some(tz.into_bytes()),
+            ))]);
+        }
+        Ok(vec![])
+    }
 }
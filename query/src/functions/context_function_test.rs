@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Expression;
 
 use crate::functions::ContextFunction;
 
@@ -35,3 +38,37 @@ fn test_context_function_build_arg_from_ctx() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_context_function_build_trailing_args_from_ctx() -> Result<()> {
+    use pretty_assertions::assert_eq;
+    let ctx = crate::tests::try_create_context()?;
+
+    // The session timezone is appended, but not shown in the column name.
+    {
+        let args = ContextFunction::build_trailing_args_from_ctx("toYYYYMM", ctx.clone())?;
+        assert_eq!(1, args.len());
+        assert_eq!("UTC", format!("{:?}", args[0]));
+
+        let expr = Expression::ScalarFunction {
+            op: "toYYYYMM".to_string(),
+            args: vec![col("d"), args[0].clone()],
+        };
+        assert_eq!("toYYYYMM(d)", expr.column_name());
+
+        // An explicit timezone is still shown.
+        let expr = Expression::ScalarFunction {
+            op: "toYYYYMM".to_string(),
+            args: vec![col("d"), lit("Asia/Shanghai".as_bytes()), args[0].clone()],
+        };
+        assert_eq!("toYYYYMM(d, 'Asia/Shanghai')", expr.column_name());
+    }
+
+    // No args for the functions not aware of timezone.
+    {
+        let args = ContextFunction::build_trailing_args_from_ctx("toString", ctx)?;
+        assert!(args.is_empty());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+
+/// Admit the query by the quotas and the resource group of the current user before executing it,
+/// the query waits in the queue of the resource group if it's busy.
+/// The max_execution_time timer starts once the query is admitted.
+///
+/// The quotas are only checked here: a running query is not stopped once a limit is reached,
+/// its usage is added when it finishes, so the last admitted queries may go beyond the limits.
+pub struct AdmissionInterpreter {
+    ctx: DatabendQueryContextRef,
+    inner: InterpreterPtr,
+}
+
+impl AdmissionInterpreter {
+    pub fn create(ctx: DatabendQueryContextRef, inner: InterpreterPtr) -> InterpreterPtr {
+        Arc::new(AdmissionInterpreter { ctx, inner })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AdmissionInterpreter {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        if let Some(user) = self.ctx.get_current_user() {
+            let sessions = self.ctx.get_sessions_manager();
+            let user_mgr = sessions.get_user_manager();
+            user_mgr.check_quota(&user)?;
+            self.ctx.attach_quota_user(&user);
+
+            // The resource group is bound to the user or its roles, the user can't choose it.
+            if let Some(resource_group) = user_mgr.get_user_resource_group(&user)? {
+                let queue = sessions.get_resource_group_queue(&resource_group)?;
+                self.ctx
+                    .attach_resource_group_permit(queue.acquire().await?);
+            }
+        }
+
+        self.ctx.start_execution_timer()?;
+        self.inner.execute().await
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.inner.schema()
+    }
+}
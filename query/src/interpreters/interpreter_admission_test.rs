@@ -0,0 +1,176 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::clusters::Cluster;
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::DatabendQueryContextShared;
+use crate::sessions::SessionRef;
+use crate::sql::*;
+
+async fn execute_sql(ctx: DatabendQueryContextRef, sql: &str) -> Result<()> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute().await?.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
+
+// Every query runs in a new context of the session, like the handlers do.
+fn create_query_context(session: &SessionRef) -> DatabendQueryContextRef {
+    DatabendQueryContext::from_shared(DatabendQueryContextShared::try_create(
+        session.get_sessions_manager().get_conf().clone(),
+        Arc::new(session.as_ref().clone()),
+        Cluster::empty(),
+    ))
+}
+
+#[tokio::test]
+async fn test_admission_interpreter_quota() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    execute_sql(ctx.clone(), "CREATE USER test").await?;
+    execute_sql(ctx.clone(), "GRANT SELECT ON *.* TO test").await?;
+    execute_sql(ctx.clone(), "CREATE QUOTA FOR test WITH max_queries = 1").await?;
+
+    let session = ctx.get_sessions_manager().create_session("TestSession")?;
+    session.set_current_user(String::from("test"));
+
+    // The usage is recorded when the query context is destroyed.
+    execute_sql(create_query_context(&session), "SELECT * FROM numbers(10)").await?;
+
+    let res = execute_sql(create_query_context(&session), "SELECT * FROM numbers(10)").await;
+    assert_eq!(56, res.err().unwrap().code());
+
+    // EXPLAIN ANALYZE runs the query, it's limited like the query.
+    let res = execute_sql(
+        create_query_context(&session),
+        "EXPLAIN ANALYZE SELECT * FROM numbers(10)",
+    )
+    .await;
+    assert_eq!(56, res.err().unwrap().code());
+
+    // The statements not reading data are not limited.
+    execute_sql(create_query_context(&session), "SET max_threads = 4").await?;
+    execute_sql(
+        create_query_context(&session),
+        "EXPLAIN SELECT * FROM numbers(10)",
+    )
+    .await?;
+
+    execute_sql(ctx.clone(), "DROP QUOTA FOR test").await?;
+    execute_sql(create_query_context(&session), "SELECT * FROM numbers(10)").await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admission_interpreter_resource_group() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    execute_sql(ctx.clone(), "CREATE USER test").await?;
+    execute_sql(ctx.clone(), "CREATE ROLE etl_role").await?;
+    execute_sql(ctx.clone(), "GRANT SELECT ON *.* TO test").await?;
+    execute_sql(ctx.clone(), "GRANT ROLE etl_role TO test").await?;
+    execute_sql(
+        ctx.clone(),
+        "CREATE RESOURCE GROUP etl WITH max_concurrency = 1, max_queued = 0",
+    )
+    .await?;
+    execute_sql(ctx.clone(), "GRANT RESOURCE GROUP etl TO ROLE etl_role").await?;
+
+    let session = ctx.get_sessions_manager().create_session("TestSession")?;
+    session.set_current_user(String::from("test"));
+
+    // The slot is kept until the context of the running query is destroyed.
+    let running_ctx = create_query_context(&session);
+    execute_sql(running_ctx.clone(), "SELECT * FROM numbers(10)").await?;
+
+    let res = execute_sql(create_query_context(&session), "SELECT * FROM numbers(10)").await;
+    assert_eq!(57, res.err().unwrap().code());
+
+    drop(running_ctx);
+    execute_sql(create_query_context(&session), "SELECT * FROM numbers(10)").await?;
+
+    // The resource group is dropped after it's granted.
+    execute_sql(ctx.clone(), "DROP RESOURCE GROUP etl").await?;
+    let res = execute_sql(create_query_context(&session), "SELECT * FROM numbers(10)").await;
+    assert_eq!(3006, res.err().unwrap().code());
+
+    execute_sql(ctx.clone(), "REVOKE RESOURCE GROUP FROM ROLE etl_role").await?;
+    execute_sql(create_query_context(&session), "SELECT * FROM numbers(10)").await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admission_interpreter_max_execution_time() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    ctx.get_settings().set_max_execution_time(1)?;
+
+    let res = execute_sql(
+        ctx.clone(),
+        "SELECT sum(number) FROM numbers_mt(100000000000)",
+    )
+    .await;
+    let cause = res.err().unwrap();
+    assert_eq!(ErrorCode::Timeout("").code(), cause.code());
+    assert_eq!(
+        "Query exceeded max_execution_time of 1 seconds",
+        cause.message()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admission_interpreter_explain_analyze_max_execution_time() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    ctx.get_settings().set_max_execution_time(1)?;
+
+    let res = execute_sql(
+        ctx.clone(),
+        "EXPLAIN ANALYZE SELECT sum(number) FROM numbers_mt(100000000000)",
+    )
+    .await;
+    assert_eq!(ErrorCode::Timeout("").code(), res.err().unwrap().code());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admission_interpreter_killed() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    // Kill the query once it runs, the running processors stop with the cancelled reason.
+    let cancellation = ctx.get_cancellation_token();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        cancellation.cancel(ErrorCode::AbortedQuery("killed"));
+    });
+
+    let res = execute_sql(
+        ctx.clone(),
+        "SELECT sum(number) FROM numbers_mt(100000000000)",
+    )
+    .await;
+    assert_eq!(
+        ErrorCode::AbortedQuery("").code(),
+        res.err().unwrap().code()
+    );
+    assert!(ctx.check_cancelled().is_err());
+    Ok(())
+}
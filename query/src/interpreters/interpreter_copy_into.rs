@@ -0,0 +1,102 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_planners::CopyIntoPlan;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::interpreter_select::Scheduled;
+use crate::interpreters::interpreter_select::ScheduledStream;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::transforms::CopyIntoTransform;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct CopyIntoInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: CopyIntoPlan,
+}
+
+impl CopyIntoInterpreter {
+    pub fn try_create(ctx: DatabendQueryContextRef, plan: CopyIntoPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CopyIntoInterpreter { ctx, plan }))
+    }
+
+    async fn copy_into(&self, scheduled: &mut Scheduled) -> Result<SendableDataBlockStream> {
+        let mut pipeline =
+            SelectInterpreter::schedule_pipeline(&self.ctx, &self.plan.input, scheduled, false)
+                .await?;
+        let (data_accessor, prefix) = self
+            .ctx
+            .get_data_accessor_by_location(&self.plan.location)?;
+        // The location is a directory, the files are written into it.
+        let dir = match prefix.is_empty() || prefix.ends_with('/') {
+            true => prefix,
+            false => format!("{}/", prefix),
+        };
+
+        // Each output of the pipeline writes its own files in parallel.
+        let query_id = self.ctx.get_id();
+        let partition = AtomicUsize::new(0);
+        pipeline.add_simple_transform(|| {
+            let file_prefix = format!(
+                "{}{}_{}",
+                dir,
+                query_id,
+                partition.fetch_add(1, Ordering::Relaxed)
+            );
+            Ok(Box::new(CopyIntoTransform::try_create(
+                data_accessor.clone(),
+                file_prefix,
+                self.plan.file_format.clone(),
+                self.plan.format_options.clone(),
+                self.plan.max_file_size,
+                self.plan.schema(),
+            )?))
+        })?;
+
+        pipeline.execute().await
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CopyIntoInterpreter {
+    fn name(&self) -> &str {
+        "CopyIntoInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let mut scheduled = Scheduled::new();
+        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        match self.copy_into(&mut scheduled).await {
+            Ok(stream) => Ok(ScheduledStream::create(scheduled, stream, self.ctx.clone())),
+            Err(error) => {
+                SelectInterpreter::error_handler(scheduled, &self.ctx, timeout).await;
+                Err(error)
+            }
+        }
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::configs::Config;
+use crate::interpreters::*;
+use crate::sql::*;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_copy_into_interpreter() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let mut config = Config::default();
+    config.storage.disk.unload_path = dir.path().display().to_string();
+    let ctx = crate::tests::try_create_context_with_config(config)?;
+
+    // The location without the trailing slash is a directory too.
+    let query = "COPY INTO 'file:///out' FROM (SELECT number FROM numbers_mt(1000)) FILE_FORMAT = (TYPE = CSV)";
+    if let PlanNode::CopyInto(plan) = PlanParser::create(ctx.clone()).build_from_sql(query)? {
+        let executor = CopyIntoInterpreter::try_create(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "CopyIntoInterpreter");
+
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let written_files = result.iter().map(|block| block.num_rows()).sum::<usize>();
+
+        let mut files = 0;
+        let mut lines = 0;
+        for entry in std::fs::read_dir(dir.path().join("out"))? {
+            let content = std::fs::read_to_string(entry?.path())?;
+            lines += content.lines().count();
+            files += 1;
+        }
+        assert_eq!(files, written_files);
+        assert_eq!(lines, 1000);
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_copy_into_local_location() -> Result<()> {
+    // The file:// locations are disabled by default.
+    let ctx = crate::tests::try_create_context()?;
+    let res = ctx.get_data_accessor_by_location("file:///tmp/out/");
+    assert_eq!(
+        "Code: 6, displayText = Location file:///tmp/out/ is disabled, set storage.disk.unload_path to enable the file:// locations.",
+        res.err().unwrap().to_string()
+    );
+
+    // The locations can't escape the unload dir.
+    let dir = tempfile::tempdir()?;
+    let mut config = Config::default();
+    config.storage.disk.unload_path = dir.path().join("unload").display().to_string();
+    let ctx = crate::tests::try_create_context_with_config(config)?;
+
+    let query = "COPY INTO 'file:///../escaped/' FROM (SELECT number FROM numbers(10))";
+    if let PlanNode::CopyInto(plan) = PlanParser::create(ctx.clone()).build_from_sql(query)? {
+        let executor = CopyIntoInterpreter::try_create(ctx.clone(), plan)?;
+        let res = match executor.execute().await {
+            Ok(stream) => stream.try_collect::<Vec<_>>().await.map(|_| ()),
+            Err(cause) => Err(cause),
+        };
+        assert!(res.is_err());
+        assert!(!dir.path().join("escaped").exists());
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
@@ -13,17 +13,25 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_management::NodeInfo;
 use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::PlanNode;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
 
+use crate::interpreters::interpreter_select::Scheduled;
+use crate::interpreters::interpreter_select::ScheduledStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
 use crate::optimizers::Optimizers;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::DatabendQueryContextRef;
@@ -46,6 +54,7 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Analyze => self.explain_analyze().await,
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -101,4 +110,99 @@ impl ExplainInterpreter {
         );
         Ok(DataBlock::create_by_array(schema, vec![formatted_pipeline]))
     }
+
+    /// Runs the query with a profiled pipeline, drops the results and reports
+    /// the statistics each processor collected.
+    async fn explain_analyze(&self) -> Result<DataBlock> {
+        let select = match self.explain.input.as_ref() {
+            PlanNode::Select(select) => select,
+            other => {
+                return Err(ErrorCode::UnImplement(format!(
+                    "EXPLAIN ANALYZE only supports SELECT, but got {}",
+                    other.name()
+                )))
+            }
+        };
+
+        let start = Instant::now();
+        let mut scheduled = Scheduled::new();
+        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        let mut pipeline = match SelectInterpreter::schedule_pipeline(
+            &self.ctx,
+            &select.input,
+            &mut scheduled,
+            true,
+        )
+        .await
+        {
+            Ok(pipeline) => pipeline,
+            Err(error) => {
+                SelectInterpreter::error_handler(scheduled, &self.ctx, timeout).await;
+                return Err(error);
+            }
+        };
+
+        let scheduled_nodes = scheduled.values().cloned().collect::<Vec<_>>();
+        let mut stream = match pipeline.execute().await {
+            Ok(stream) => ScheduledStream::create(scheduled, stream, self.ctx.clone()),
+            Err(error) => {
+                SelectInterpreter::error_handler(scheduled, &self.ctx, timeout).await;
+                return Err(error);
+            }
+        };
+
+        let mut rows = 0;
+        while let Some(block) = stream.next().await {
+            rows += block?.num_rows();
+        }
+
+        let mut lines = vec![format!(
+            "Total: rows: {}, elapsed: {:.3} ms",
+            rows,
+            start.elapsed().as_secs_f64() * 1000.0
+        )];
+        lines.extend(
+            format!("{}", pipeline.display_profile())
+                .lines()
+                .map(|s| s.to_string()),
+        );
+        lines.extend(self.remote_profiles(&scheduled_nodes, timeout).await?);
+
+        let schema = self.schema();
+        let formatted_profile = Series::new(lines.iter().map(|s| s.as_bytes()).collect::<Vec<_>>());
+        Ok(DataBlock::create_by_array(schema, vec![formatted_profile]))
+    }
+
+    /// Fetch the profiles of the stages which ran on the other nodes of the cluster,
+    /// each stage is reported after the local pipeline with the node it ran on.
+    async fn remote_profiles(
+        &self,
+        scheduled_nodes: &[Arc<NodeInfo>],
+        timeout: u64,
+    ) -> Result<Vec<String>> {
+        let query_id = self.ctx.get_id();
+        let config = self.ctx.get_config();
+        let cluster = self.ctx.get_cluster();
+
+        let mut stages = vec![];
+        for node in scheduled_nodes {
+            let mut flight_client = cluster.create_node_conn(&node.id, &config).await?;
+            let profiles = flight_client
+                .fetch_profiles(query_id.clone(), timeout)
+                .await?;
+            for profile in profiles {
+                stages.push((node.id.clone(), profile));
+            }
+        }
+
+        stages
+            .sort_by(|(a_node, a), (b_node, b)| (&a.stage_id, a_node).cmp(&(&b.stage_id, b_node)));
+
+        let mut lines = vec![];
+        for (node, profile) in stages {
+            lines.push(format!("Stage {} on {}:", profile.stage_id, node));
+            lines.extend(profile.lines.iter().map(|line| format!("  {}", line)));
+        }
+        Ok(lines)
+    }
 }
@@ -54,3 +54,56 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    ctx.get_settings().set_max_threads(2)?;
+
+    if let PlanNode::Explain(plan) = PlanParser::create(ctx.clone())
+        .build_from_sql("explain analyze select number from numbers_mt(10) where number > 4")?
+    {
+        let executor = ExplainInterpreter::try_create(ctx.clone(), plan)?;
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let block = &result[0];
+        assert_eq!(block.num_columns(), 1);
+
+        let lines = (0..block.num_rows())
+            .map(|row| block.column(0).try_get(row).map(|value| value.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Timings vary between runs, only check the structure.
+        assert!(lines[0].starts_with("Total: rows: 5, elapsed: "));
+        assert_eq!(lines[1], "MergeProcessor × 1 processor");
+        assert!(lines[2].starts_with("  #0: rows in: 5, rows out: 5, blocks: "));
+        assert_eq!(lines[3], "  ProjectionTransform × 2 processors");
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("SourceTransform × 2 processors, partitions: ")));
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("FilterTransform × 2 processors")));
+    } else {
+        assert!(false)
+    }
+
+    if let PlanNode::Explain(plan) = PlanParser::create(ctx.clone())
+        .build_from_sql("explain analyze create table t(a int) engine = Memory")?
+    {
+        let executor = ExplainInterpreter::try_create(ctx, plan)?;
+        let result = executor.execute().await;
+        assert!(result.is_err());
+        if let Err(error) = result {
+            assert_eq!(error.code(), 2);
+            assert_eq!(
+                error.message(),
+                "EXPLAIN ANALYZE only supports SELECT, but got CreateTablePlan"
+            );
+        }
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
@@ -16,17 +16,36 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::ExplainType;
 use common_planners::PlanNode;
 
 use crate::interpreters::interpreter_kill::KillInterpreter;
+use crate::interpreters::AdmissionInterpreter;
+use crate::interpreters::AlterUserInterpreter;
+use crate::interpreters::CopyIntoInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::CreateQuotaInterpreter;
+use crate::interpreters::CreateResourceGroupInterpreter;
+use crate::interpreters::CreateRoleInterpreter;
 use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::CreateUserInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
+use crate::interpreters::DropQuotaInterpreter;
+use crate::interpreters::DropResourceGroupInterpreter;
+use crate::interpreters::DropRoleInterpreter;
 use crate::interpreters::DropTableInterpreter;
+use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::ExplainInterpreter;
+use crate::interpreters::GrantPrivilegeInterpreter;
+use crate::interpreters::GrantResourceGroupInterpreter;
+use crate::interpreters::GrantRoleInterpreter;
 use crate::interpreters::InsertIntoInterpreter;
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::PlanAccessChecker;
+use crate::interpreters::RevokePrivilegeInterpreter;
+use crate::interpreters::RevokeRoleInterpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::interpreters::SettingInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
@@ -38,8 +57,15 @@ pub struct InterpreterFactory;
 
 impl InterpreterFactory {
     pub fn get(ctx: DatabendQueryContextRef, plan: PlanNode) -> Result<Arc<dyn Interpreter>> {
+        ctx.attach_query_plan(&plan);
+        PlanAccessChecker::create(ctx.clone()).check(&plan)?;
+
         match plan {
-            PlanNode::Select(v) => SelectInterpreter::try_create(ctx, v),
+            PlanNode::Select(v) => Self::admit(ctx.clone(), SelectInterpreter::try_create(ctx, v)),
+            // EXPLAIN ANALYZE runs the query.
+            PlanNode::Explain(v) if v.typ == ExplainType::Analyze => {
+                Self::admit(ctx.clone(), ExplainInterpreter::try_create(ctx, v))
+            }
             PlanNode::Explain(v) => ExplainInterpreter::try_create(ctx, v),
             PlanNode::CreateDatabase(v) => CreateDatabaseInterpreter::try_create(ctx, v),
             PlanNode::DropDatabase(v) => DropDatabaseInterpreter::try_create(ctx, v),
@@ -49,13 +75,40 @@ impl InterpreterFactory {
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx, v),
             PlanNode::UseDatabase(v) => UseDatabaseInterpreter::try_create(ctx, v),
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx, v),
-            PlanNode::InsertInto(v) => InsertIntoInterpreter::try_create(ctx, v),
+            PlanNode::InsertInto(v) => {
+                Self::admit(ctx.clone(), InsertIntoInterpreter::try_create(ctx, v))
+            }
+            PlanNode::CopyInto(v) => {
+                Self::admit(ctx.clone(), CopyIntoInterpreter::try_create(ctx, v))
+            }
             PlanNode::ShowCreateTable(v) => ShowCreateTableInterpreter::try_create(ctx, v),
             PlanNode::Kill(v) => KillInterpreter::try_create(ctx, v),
+            PlanNode::CreateUser(v) => CreateUserInterpreter::try_create(ctx, v),
+            PlanNode::AlterUser(v) => AlterUserInterpreter::try_create(ctx, v),
+            PlanNode::DropUser(v) => DropUserInterpreter::try_create(ctx, v),
+            PlanNode::CreateRole(v) => CreateRoleInterpreter::try_create(ctx, v),
+            PlanNode::DropRole(v) => DropRoleInterpreter::try_create(ctx, v),
+            PlanNode::GrantPrivilege(v) => GrantPrivilegeInterpreter::try_create(ctx, v),
+            PlanNode::RevokePrivilege(v) => RevokePrivilegeInterpreter::try_create(ctx, v),
+            PlanNode::GrantRole(v) => GrantRoleInterpreter::try_create(ctx, v),
+            PlanNode::RevokeRole(v) => RevokeRoleInterpreter::try_create(ctx, v),
+            PlanNode::CreateQuota(v) => CreateQuotaInterpreter::try_create(ctx, v),
+            PlanNode::DropQuota(v) => DropQuotaInterpreter::try_create(ctx, v),
+            PlanNode::CreateResourceGroup(v) => CreateResourceGroupInterpreter::try_create(ctx, v),
+            PlanNode::DropResourceGroup(v) => DropResourceGroupInterpreter::try_create(ctx, v),
+            PlanNode::GrantResourceGroup(v) => GrantResourceGroupInterpreter::try_create(ctx, v),
             _ => Result::Err(ErrorCode::UnknownTypeOfQuery(format!(
                 "Can't get the interpreter by plan:{}",
                 plan.name()
             ))),
         }
     }
+
+    // The queries reading or writing data are admitted by the quotas and the resource groups.
+    fn admit(
+        ctx: DatabendQueryContextRef,
+        interpreter: Result<InterpreterPtr>,
+    ) -> Result<InterpreterPtr> {
+        Ok(AdmissionInterpreter::create(ctx, interpreter?))
+    }
 }
@@ -0,0 +1,65 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::GrantPrivilegePlan;
+use common_planners::PrincipalIdentity;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct GrantPrivilegeInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: GrantPrivilegePlan,
+}
+
+impl GrantPrivilegeInterpreter {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        plan: GrantPrivilegePlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(GrantPrivilegeInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for GrantPrivilegeInterpreter {
+    fn name(&self) -> &str {
+        "GrantPrivilegeInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let plan = self.plan.clone();
+        match &plan.principal {
+            PrincipalIdentity::User(user) => {
+                user_mgr.grant_user_privileges(user, plan.on, plan.privileges)?
+            }
+            PrincipalIdentity::Role(role) => {
+                user_mgr.grant_role_privileges(role, plan.on, plan.privileges)?
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::GrantObject;
+use common_management::UserPrivilegeType;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::*;
+
+async fn execute_sql(ctx: DatabendQueryContextRef, sql: &str) -> Result<()> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grant_privilege_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    execute_sql(ctx.clone(), "CREATE USER test").await?;
+
+    let table = GrantObject::Table("default".to_string(), "t1".to_string());
+
+    // Grant and revoke to the user.
+    {
+        if let PlanNode::GrantPrivilege(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("GRANT SELECT, INSERT ON t1 TO test")?
+        {
+            let executor = GrantPrivilegeInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "GrantPrivilegeInterpreter");
+            executor.execute().await?;
+
+            assert!(user_mgr.verify_privilege("test", &table, UserPrivilegeType::Select)?);
+            assert!(user_mgr.verify_privilege("test", &table, UserPrivilegeType::Insert)?);
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::RevokePrivilege(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("REVOKE INSERT ON t1 FROM test")?
+        {
+            let executor = RevokePrivilegeInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "RevokePrivilegeInterpreter");
+            executor.execute().await?;
+
+            assert!(user_mgr.verify_privilege("test", &table, UserPrivilegeType::Select)?);
+            assert!(!user_mgr.verify_privilege("test", &table, UserPrivilegeType::Insert)?);
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Grant and revoke by role.
+    {
+        execute_sql(ctx.clone(), "CREATE ROLE writer").await?;
+        execute_sql(ctx.clone(), "GRANT INSERT ON default.* TO ROLE writer").await?;
+        assert!(!user_mgr.verify_privilege("test", &table, UserPrivilegeType::Insert)?);
+
+        if let PlanNode::GrantRole(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("GRANT ROLE writer TO test")?
+        {
+            let executor = GrantRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "GrantRoleInterpreter");
+            executor.execute().await?;
+            assert!(user_mgr.verify_privilege("test", &table, UserPrivilegeType::Insert)?);
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::RevokeRole(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("REVOKE ROLE writer FROM test")?
+        {
+            let executor = RevokeRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "RevokeRoleInterpreter");
+            executor.execute().await?;
+            assert!(!user_mgr.verify_privilege("test", &table, UserPrivilegeType::Insert)?);
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Grant to the unknown user or role.
+    {
+        let res = execute_sql(ctx.clone(), "GRANT SELECT ON *.* TO unknown").await;
+        assert_eq!(3000, res.err().unwrap().code());
+        let res = execute_sql(ctx.clone(), "GRANT SELECT ON *.* TO ROLE unknown").await;
+        assert_eq!(3003, res.err().unwrap().code());
+        let res = execute_sql(ctx.clone(), "GRANT ROLE unknown TO test").await;
+        assert_eq!(3003, res.err().unwrap().code());
+    }
+
+    Ok(())
+}
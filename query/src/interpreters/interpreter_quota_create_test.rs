@@ -0,0 +1,76 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::RoleInfo;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+
+#[tokio::test]
+async fn test_create_and_drop_quota_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    user_mgr.add_role(RoleInfo::new("analyst".to_string()))?;
+
+    // Create quota.
+    {
+        if let PlanNode::CreateQuota(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("CREATE QUOTA FOR ROLE analyst WITH interval = 60, max_queries = 10")?
+        {
+            let executor = CreateQuotaInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "CreateQuotaInterpreter");
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+            let quota = user_mgr.get_role("analyst")?.quota.unwrap();
+            assert_eq!(60, quota.interval_secs);
+            assert_eq!(10, quota.max_queries);
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::CreateQuota(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("CREATE QUOTA FOR USER unknown WITH max_queries = 10")?
+        {
+            let executor = CreateQuotaInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute().await;
+            assert_eq!(3000, res.err().unwrap().code());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Drop quota.
+    {
+        if let PlanNode::DropQuota(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP QUOTA FOR ROLE analyst")?
+        {
+            let executor = DropQuotaInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "DropQuotaInterpreter");
+            executor.execute().await?;
+            assert_eq!(None, user_mgr.get_role("analyst")?.quota);
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
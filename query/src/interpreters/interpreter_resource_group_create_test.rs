@@ -0,0 +1,83 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+
+#[tokio::test]
+async fn test_create_and_drop_resource_group_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+
+    // Create resource group.
+    {
+        if let PlanNode::CreateResourceGroup(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("CREATE RESOURCE GROUP etl WITH max_concurrency = 2, max_queued = 10")?
+        {
+            let executor = CreateResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "CreateResourceGroupInterpreter");
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+            let resource_group = user_mgr.get_resource_group("etl")?;
+            assert_eq!(2, resource_group.max_concurrency);
+            assert_eq!(10, resource_group.max_queued);
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::CreateResourceGroup(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("CREATE RESOURCE GROUP etl WITH max_concurrency = 1")?
+        {
+            let executor = CreateResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute().await;
+            assert_eq!(3007, res.err().unwrap().code());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Drop resource group.
+    {
+        if let PlanNode::DropResourceGroup(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP RESOURCE GROUP etl")?
+        {
+            let executor = DropResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "DropResourceGroupInterpreter");
+            executor.execute().await?;
+            assert_eq!(0, user_mgr.get_resource_groups()?.len());
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::DropResourceGroup(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP RESOURCE GROUP IF EXISTS etl")?
+        {
+            let executor = DropResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+            executor.execute().await?;
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
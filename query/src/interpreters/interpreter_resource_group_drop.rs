@@ -0,0 +1,62 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::DropResourceGroupPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct DropResourceGroupInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: DropResourceGroupPlan,
+}
+
+impl DropResourceGroupInterpreter {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        plan: DropResourceGroupPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(DropResourceGroupInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropResourceGroupInterpreter {
+    fn name(&self) -> &str {
+        "DropResourceGroupInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        if let Err(cause) = user_mgr.drop_resource_group(&self.plan.name) {
+            let unknown = cause.code() == ErrorCode::UnknownResourceGroup("").code();
+            if !(unknown && self.plan.if_exists) {
+                return Err(cause);
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::GrantResourceGroupPlan;
+use common_planners::PrincipalIdentity;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct GrantResourceGroupInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: GrantResourceGroupPlan,
+}
+
+impl GrantResourceGroupInterpreter {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        plan: GrantResourceGroupPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(GrantResourceGroupInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for GrantResourceGroupInterpreter {
+    fn name(&self) -> &str {
+        "GrantResourceGroupInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        if let Some(name) = &self.plan.name {
+            // The resource group must exist.
+            user_mgr.get_resource_group(name)?;
+        }
+
+        let resource_group = self.plan.name.clone();
+        match &self.plan.principal {
+            PrincipalIdentity::User(user) => {
+                user_mgr.set_user_resource_group(user, resource_group)?
+            }
+            PrincipalIdentity::Role(role) => {
+                user_mgr.set_role_resource_group(role, resource_group)?
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
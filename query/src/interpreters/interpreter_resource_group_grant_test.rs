@@ -0,0 +1,78 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::ResourceGroupInfo;
+use common_management::RoleInfo;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+
+#[tokio::test]
+async fn test_grant_and_revoke_resource_group_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    user_mgr.add_role(RoleInfo::new("analyst".to_string()))?;
+    user_mgr.add_resource_group(ResourceGroupInfo::new("etl".to_string(), 2, 10))?;
+
+    // Grant resource group.
+    {
+        if let PlanNode::GrantResourceGroup(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("GRANT RESOURCE GROUP etl TO ROLE analyst")?
+        {
+            let executor = GrantResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "GrantResourceGroupInterpreter");
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+            assert_eq!(
+                Some("etl".to_string()),
+                user_mgr.get_role("analyst")?.resource_group
+            );
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::GrantResourceGroup(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("GRANT RESOURCE GROUP unknown TO ROLE analyst")?
+        {
+            let executor = GrantResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute().await;
+            assert_eq!(3006, res.err().unwrap().code());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Revoke resource group.
+    {
+        if let PlanNode::GrantResourceGroup(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("REVOKE RESOURCE GROUP FROM ROLE analyst")?
+        {
+            let executor = GrantResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+            executor.execute().await?;
+            assert_eq!(None, user_mgr.get_role("analyst")?.resource_group);
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::RoleInfo;
+use common_planners::CreateRolePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct CreateRoleInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: CreateRolePlan,
+}
+
+impl CreateRoleInterpreter {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        plan: CreateRolePlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CreateRoleInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateRoleInterpreter {
+    fn name(&self) -> &str {
+        "CreateRoleInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        if let Err(cause) = user_mgr.add_role(RoleInfo::new(self.plan.name.clone())) {
+            let exists = cause.code() == ErrorCode::RoleAlreadyExists("").code();
+            if !(exists && self.plan.if_not_exists) {
+                return Err(cause);
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
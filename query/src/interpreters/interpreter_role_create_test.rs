@@ -0,0 +1,80 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+
+#[tokio::test]
+async fn test_create_and_drop_role_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+
+    // Create role.
+    {
+        if let PlanNode::CreateRole(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("CREATE ROLE writer")?
+        {
+            let executor = CreateRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "CreateRoleInterpreter");
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+            assert_eq!("writer", user_mgr.get_role("writer")?.name);
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::CreateRole(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("CREATE ROLE writer")?
+        {
+            let executor = CreateRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute().await;
+            assert_eq!(3004, res.err().unwrap().code());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Drop role.
+    {
+        if let PlanNode::DropRole(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP ROLE writer")?
+        {
+            let executor = DropRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "DropRoleInterpreter");
+            executor.execute().await?;
+            assert_eq!(0, user_mgr.get_roles()?.len());
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::DropRole(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP ROLE IF EXISTS writer")?
+        {
+            let executor = DropRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            executor.execute().await?;
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
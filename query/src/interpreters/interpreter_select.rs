@@ -24,6 +24,7 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_management::NodeInfo;
+use common_planners::PlanNode;
 use common_planners::SelectPlan;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
@@ -36,6 +37,7 @@ use crate::interpreters::plan_scheduler::PlanScheduler;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
+use crate::pipelines::processors::Pipeline;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::DatabendQueryContextRef;
 
@@ -75,33 +77,52 @@ impl Interpreter for SelectInterpreter {
     }
 }
 
-type Scheduled = HashMap<String, Arc<NodeInfo>>;
+pub(crate) type Scheduled = HashMap<String, Arc<NodeInfo>>;
 
 impl SelectInterpreter {
     async fn schedule_query(&self, scheduled: &mut Scheduled) -> Result<SendableDataBlockStream> {
-        let optimized_plan = Optimizers::create(self.ctx.clone()).optimize(&self.select.input)?;
+        let mut in_local_pipeline =
+            Self::schedule_pipeline(&self.ctx, &self.select.input, scheduled, false).await?;
+        in_local_pipeline.execute().await
+    }
 
-        let scheduler = PlanScheduler::try_create(self.ctx.clone())?;
+    /// Optimize and schedule the plan, send the remote stages to the cluster
+    /// and build the pipeline of the local stage, profiling collects the runtime statistics
+    /// of the local pipeline and of the remote stages.
+    #[tracing::instrument(level = "info", skip(ctx, plan, scheduled), fields(ctx.id = ctx.get_id().as_str()))]
+    pub(crate) async fn schedule_pipeline(
+        ctx: &DatabendQueryContextRef,
+        plan: &PlanNode,
+        scheduled: &mut Scheduled,
+        profiling: bool,
+    ) -> Result<Pipeline> {
+        let optimized_plan = Optimizers::create(ctx.clone()).optimize(plan)?;
+
+        let scheduler = PlanScheduler::try_create(ctx.clone())?;
         let scheduled_tasks = scheduler.reschedule(&optimized_plan)?;
         let remote_stage_actions = scheduled_tasks.get_tasks()?;
 
-        let config = self.ctx.get_config();
-        let cluster = self.ctx.get_cluster();
-        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        let config = ctx.get_config();
+        let cluster = ctx.get_cluster();
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
         for (node, action) in remote_stage_actions {
             let mut flight_client = cluster.create_node_conn(&node.id, &config).await?;
-            let executing_action = flight_client.execute_action(action.clone(), timeout);
+            let action = action.clone().with_profiling(profiling);
+            let executing_action = flight_client.execute_action(action, timeout);
 
             executing_action.await?;
             scheduled.insert(node.id.clone(), node.clone());
         }
 
-        let pipeline_builder = PipelineBuilder::create(self.ctx.clone());
-        let mut in_local_pipeline = pipeline_builder.build(&scheduled_tasks.get_local_task())?;
-        in_local_pipeline.execute().await
+        let pipeline_builder = PipelineBuilder::create(ctx.clone()).with_profiling(profiling);
+        pipeline_builder.build(&scheduled_tasks.get_local_task())
     }
 
-    async fn error_handler(scheduled: Scheduled, context: &DatabendQueryContextRef, timeout: u64) {
+    pub(crate) async fn error_handler(
+        scheduled: Scheduled,
+        context: &DatabendQueryContextRef,
+        timeout: u64,
+    ) {
         let query_id = context.get_id();
         let config = context.get_config();
         let cluster = context.get_cluster();
@@ -135,7 +156,7 @@ impl SelectInterpreter {
     }
 }
 
-struct ScheduledStream {
+pub(crate) struct ScheduledStream {
     scheduled: Scheduled,
     is_success: AtomicBool,
     context: DatabendQueryContextRef,
@@ -158,9 +179,14 @@ impl ScheduledStream {
 
     fn cancel_scheduled_action(&self) -> Result<()> {
         let scheduled = self.scheduled.clone();
+        let context = self.context.clone();
         let timeout = self.context.get_settings().get_flight_client_timeout()?;
-        let error_handler = SelectInterpreter::error_handler(scheduled, &self.context, timeout);
-        futures::executor::block_on(error_handler);
+
+        // The stream may be dropped on a worker thread of the runtime, so cancel the
+        // remote stages in a task rather than blocking the thread.
+        self.context.execute_task(async move {
+            SelectInterpreter::error_handler(scheduled, &context, timeout).await;
+        })?;
         Ok(())
     }
 }
@@ -14,17 +14,21 @@
 
 use std::sync::Arc;
 
+use common_datavalues::prelude::parse_timezone;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_exception::Result;
 use common_planners::SettingPlan;
+use common_planners::SettingScope;
+use common_planners::VarValue;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::Settings;
 
 pub struct SettingInterpreter {
     ctx: DatabendQueryContextRef,
@@ -45,23 +49,69 @@ impl Interpreter for SettingInterpreter {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let plan = self.set.clone();
-        for var in plan.vars {
+        match &plan.scope {
+            SettingScope::Session => self.set_session_settings(plan.vars)?,
+            SettingScope::Global => {
+                let user_manager = self.ctx.get_sessions_manager().get_user_manager();
+                for var in Self::checked_vars(plan.vars)? {
+                    match var.is_default {
+                        true => user_manager.drop_global_setting(&var.variable)?,
+                        false => user_manager.set_global_setting(&var.variable, &var.value)?,
+                    }
+                }
+            }
+            SettingScope::User(user) => {
+                let settings = Self::checked_vars(plan.vars)?
+                    .into_iter()
+                    .map(|var| match var.is_default {
+                        true => (var.variable, None),
+                        false => (var.variable, Some(var.value)),
+                    })
+                    .collect::<Vec<_>>();
+                let user_manager = self.ctx.get_sessions_manager().get_user_manager();
+                user_manager.set_user_settings(user, &settings)?;
+            }
+        }
+
+        let schema = DataSchemaRefExt::create(vec![DataField::new("set", DataType::String, false)]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+    }
+}
+
+impl SettingInterpreter {
+    fn set_session_settings(&self, vars: Vec<VarValue>) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        for var in vars {
             match var.variable.to_lowercase().as_str() {
                 // To be compatible with some drivers
                 "sql_mode" | "autocommit" => {}
-                "max_threads" => {
-                    let threads: u64 = var.value.parse()?;
-                    self.ctx.get_settings().set_max_threads(threads)?;
-                }
-                _ => {
-                    self.ctx
-                        .get_settings()
-                        .update_settings(&var.variable, var.value)?;
+                _ if var.is_default => settings.reset_settings(&var.variable)?,
+                "timezone" => {
+                    // Check the timezone eagerly, the date functions use it later.
+                    parse_timezone(&var.value)?;
+                    settings.set_timezone(var.value)?;
                 }
+                _ => settings.update_settings(&var.variable, var.value)?,
             }
         }
+        Ok(())
+    }
 
-        let schema = DataSchemaRefExt::create(vec![DataField::new("set", DataType::String, false)]);
-        Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+    // The global and user settings are applied to the sessions created later, they are checked
+    // when they are set so that the invalid values don't fail all the new sessions.
+    fn checked_vars(vars: Vec<VarValue>) -> Result<Vec<VarValue>> {
+        let settings = Settings::try_create()?;
+        for var in &vars {
+            match var.is_default {
+                true => settings.reset_settings(&var.variable)?,
+                false => {
+                    if var.variable.eq_ignore_ascii_case("timezone") {
+                        parse_timezone(&var.value)?;
+                    }
+                    settings.check_settings(&var.variable, var.value.clone())?
+                }
+            }
+        }
+        Ok(vars)
     }
 }
@@ -16,9 +16,11 @@ use common_base::tokio;
 use common_exception::Result;
 use common_planners::*;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
 use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
 use crate::sql::*;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -58,3 +60,117 @@ async fn test_setting_interpreter_error() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_timezone() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    assert_eq!(ctx.get_settings().get_timezone()?, "UTC");
+
+    if let PlanNode::SetVariable(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("set timezone='Asia/Shanghai'")?
+    {
+        let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+        let mut stream = executor.execute().await?;
+        while let Some(_block) = stream.next().await {}
+        assert_eq!(ctx.get_settings().get_timezone()?, "Asia/Shanghai");
+    } else {
+        assert!(false)
+    }
+
+    if let PlanNode::SetVariable(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("set timezone='Mars/Olympus'")?
+    {
+        let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+        if let Err(e) = executor.execute().await {
+            let expect = "Code: 6, displayText = Unknown timezone: Mars/Olympus.";
+            assert_eq!(expect, format!("{}", e));
+        } else {
+            assert!(false);
+        }
+        assert_eq!(ctx.get_settings().get_timezone()?, "Asia/Shanghai");
+    }
+
+    Ok(())
+}
+
+async fn execute_sql(ctx: DatabendQueryContextRef, sql: &str) -> Result<()> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute().await?.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_global() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    execute_sql(ctx.clone(), "SET GLOBAL max_block_size = 100").await?;
+
+    // The current session is not changed, the new sessions are.
+    assert_eq!(ctx.get_settings().get_max_block_size()?, 10000);
+    let session = ctx.get_sessions_manager().create_session("TestSession")?;
+    assert_eq!(session.get_settings().get_max_block_size()?, 100);
+
+    let res = execute_sql(ctx.clone(), "SET GLOBAL max_block_size = 'x'").await;
+    assert!(res.is_err());
+    let res = execute_sql(ctx.clone(), "SET GLOBAL copy_file_format = 'json'").await;
+    assert_eq!(res.unwrap_err().code(), 6);
+
+    execute_sql(ctx.clone(), "SET GLOBAL max_block_size = DEFAULT").await?;
+    let session = ctx.get_sessions_manager().create_session("TestSession")?;
+    assert_eq!(session.get_settings().get_max_block_size()?, 10000);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_user() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    execute_sql(ctx.clone(), "CREATE USER test").await?;
+    execute_sql(ctx.clone(), "SET GLOBAL max_block_size = 100").await?;
+    execute_sql(
+        ctx.clone(),
+        "ALTER USER test SETTINGS max_block_size = 200, copy_file_format = 'csv'",
+    )
+    .await?;
+
+    // The user settings override the global settings.
+    let session = ctx.get_sessions_manager().create_session("TestSession")?;
+    session.set_current_user(String::from("test"));
+    assert_eq!(session.get_settings().get_max_block_size()?, 200);
+    assert_eq!(session.get_settings().get_copy_file_format()?, "CSV");
+
+    execute_sql(
+        ctx.clone(),
+        "ALTER USER test SETTINGS max_block_size = DEFAULT",
+    )
+    .await?;
+    let session = ctx.get_sessions_manager().create_session("TestSession")?;
+    session.set_current_user(String::from("test"));
+    assert_eq!(session.get_settings().get_max_block_size()?, 100);
+    assert_eq!(session.get_settings().get_copy_file_format()?, "CSV");
+
+    let res = execute_sql(ctx.clone(), "ALTER USER test SETTINGS unknown = 1").await;
+    assert_eq!(res.unwrap_err().code(), 20);
+    let res = execute_sql(ctx.clone(), "ALTER USER nobody SETTINGS max_threads = 1").await;
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_settings() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let session = ctx.get_sessions_manager().create_session("TestSession")?;
+    session.get_settings().set_max_block_size(100)?;
+
+    let query_ctx = session.create_context().await?;
+    let plan = PlanParser::create(query_ctx.clone())
+        .build_from_sql("SELECT * FROM numbers(10) SETTINGS (max_block_size = 1)")?;
+    assert!(matches!(plan, PlanNode::Select(_)));
+
+    // The settings of the query are not the settings of the session.
+    assert_eq!(query_ctx.get_settings().get_max_block_size()?, 1);
+    assert_eq!(session.get_settings().get_max_block_size()?, 100);
+
+    let res = PlanParser::create(query_ctx).build_from_sql("SELECT 1 SETTINGS (max_threads = 'x')");
+    assert!(res.is_err());
+    Ok(())
+}
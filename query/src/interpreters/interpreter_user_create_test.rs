@@ -0,0 +1,94 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::AuthType;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+use crate::users::encode_password;
+
+#[tokio::test]
+async fn test_create_user_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+
+    // Create user.
+    {
+        if let PlanNode::CreateUser(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("CREATE USER 'test' IDENTIFIED BY 'password'")?
+        {
+            let executor = CreateUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "CreateUserInterpreter");
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+            let user = user_mgr.get_user("test")?;
+            assert_eq!(user.auth_type, AuthType::Sha256);
+            assert_eq!(
+                user.password,
+                encode_password(&AuthType::Sha256, "password")
+            );
+            assert!(user.grants.entries().is_empty());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Create the existing user.
+    {
+        if let PlanNode::CreateUser(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("CREATE USER test")?
+        {
+            let executor = CreateUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute().await;
+            assert_eq!(3001, res.err().unwrap().code());
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::CreateUser(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("CREATE USER IF NOT EXISTS test")?
+        {
+            let executor = CreateUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            executor.execute().await?;
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Alter user.
+    {
+        if let PlanNode::AlterUser(plan) = PlanParser::create(ctx.clone()).build_from_sql(
+            "ALTER USER test IDENTIFIED WITH plaintext_password BY 'new_password'",
+        )? {
+            let executor = AlterUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "AlterUserInterpreter");
+            executor.execute().await?;
+
+            assert!(user_mgr.auth_user("test", "new_password")?);
+            assert!(!user_mgr.auth_user("test", "password")?);
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+
+#[tokio::test]
+async fn test_drop_user_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+
+    // Create user.
+    {
+        if let PlanNode::CreateUser(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("CREATE USER test")?
+        {
+            let executor = CreateUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            executor.execute().await?;
+        }
+    }
+
+    // Drop user.
+    {
+        if let PlanNode::DropUser(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP USER test")?
+        {
+            let executor = DropUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "DropUserInterpreter");
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+            assert_eq!(0, user_mgr.get_users()?.len());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Drop the unknown user.
+    {
+        if let PlanNode::DropUser(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP USER test")?
+        {
+            let executor = DropUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute().await;
+            assert_eq!(3000, res.err().unwrap().code());
+        } else {
+            assert!(false)
+        }
+
+        if let PlanNode::DropUser(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("DROP USER IF EXISTS test")?
+        {
+            let executor = DropUserInterpreter::try_create(ctx.clone(), plan.clone())?;
+            executor.execute().await?;
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
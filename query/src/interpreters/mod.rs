@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod interpreter_admission_test;
+#[cfg(test)]
+mod interpreter_copy_into_test;
 #[cfg(test)]
 mod interpreter_database_create_test;
 #[cfg(test)]
@@ -21,6 +25,16 @@ mod interpreter_describe_table_test;
 #[cfg(test)]
 mod interpreter_explain_test;
 #[cfg(test)]
+mod interpreter_privilege_grant_test;
+#[cfg(test)]
+mod interpreter_quota_create_test;
+#[cfg(test)]
+mod interpreter_resource_group_create_test;
+#[cfg(test)]
+mod interpreter_resource_group_grant_test;
+#[cfg(test)]
+mod interpreter_role_create_test;
+#[cfg(test)]
 mod interpreter_select_test;
 #[cfg(test)]
 mod interpreter_setting_test;
@@ -35,9 +49,17 @@ mod interpreter_truncate_table_test;
 #[cfg(test)]
 mod interpreter_use_database_test;
 #[cfg(test)]
+mod interpreter_user_create_test;
+#[cfg(test)]
+mod interpreter_user_drop_test;
+#[cfg(test)]
+mod plan_access_checker_test;
+#[cfg(test)]
 mod plan_scheduler_test;
 
 mod interpreter;
+mod interpreter_admission;
+mod interpreter_copy_into;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_describe_table;
@@ -45,6 +67,17 @@ mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_insert_into;
 mod interpreter_kill;
+mod interpreter_privilege_grant;
+mod interpreter_privilege_revoke;
+mod interpreter_quota_create;
+mod interpreter_quota_drop;
+mod interpreter_resource_group_create;
+mod interpreter_resource_group_drop;
+mod interpreter_resource_group_grant;
+mod interpreter_role_create;
+mod interpreter_role_drop;
+mod interpreter_role_grant;
+mod interpreter_role_revoke;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_create_table;
@@ -52,17 +85,34 @@ mod interpreter_table_create;
 mod interpreter_table_drop;
 mod interpreter_truncate_table;
 mod interpreter_use_database;
+mod interpreter_user_alter;
+mod interpreter_user_create;
+mod interpreter_user_drop;
+mod plan_access_checker;
 #[allow(clippy::needless_range_loop)]
 mod plan_scheduler;
 
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_admission::AdmissionInterpreter;
+pub use interpreter_copy_into::CopyIntoInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert_into::InsertIntoInterpreter;
+pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
+pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
+pub use interpreter_quota_create::CreateQuotaInterpreter;
+pub use interpreter_quota_drop::DropQuotaInterpreter;
+pub use interpreter_resource_group_create::CreateResourceGroupInterpreter;
+pub use interpreter_resource_group_drop::DropResourceGroupInterpreter;
+pub use interpreter_resource_group_grant::GrantResourceGroupInterpreter;
+pub use interpreter_role_create::CreateRoleInterpreter;
+pub use interpreter_role_drop::DropRoleInterpreter;
+pub use interpreter_role_grant::GrantRoleInterpreter;
+pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_create_table::ShowCreateTableInterpreter;
@@ -70,3 +120,7 @@ pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_truncate_table::TruncateTableInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
+pub use interpreter_user_alter::AlterUserInterpreter;
+pub use interpreter_user_create::CreateUserInterpreter;
+pub use interpreter_user_drop::DropUserInterpreter;
+pub use plan_access_checker::PlanAccessChecker;
@@ -0,0 +1,203 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::GrantObject;
+use common_management::UserPrivilegeSet;
+use common_management::UserPrivilegeType;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::SettingScope;
+
+use crate::sessions::DatabendQueryContextRef;
+
+/// The system tables which require the SELECT privilege on them, e.g. by `GRANT SELECT ON system.*`.
+const PROTECTED_SYSTEM_TABLES: [&str; 6] = [
+    "configs",
+    "processes",
+    "query_log",
+    "quotas",
+    "resource_groups",
+    "tracing",
+];
+
+/// Checks the privileges of the session user on the objects which the plan reads or writes.
+/// The internal queries without the session user are not checked.
+pub struct PlanAccessChecker {
+    ctx: DatabendQueryContextRef,
+}
+
+impl PlanAccessChecker {
+    pub fn create(ctx: DatabendQueryContextRef) -> Self {
+        PlanAccessChecker { ctx }
+    }
+
+    pub fn check(&self, plan: &PlanNode) -> Result<()> {
+        let user = match self.ctx.get_current_user() {
+            None => return Ok(()),
+            Some(user) => user,
+        };
+
+        match plan {
+            PlanNode::Select(_) | PlanNode::Explain(_) => self.check_read_tables(&user, plan),
+            // Unloading writes the files to the storage outside of any table.
+            PlanNode::CopyInto(_) => {
+                self.check_read_tables(&user, plan)?;
+                self.check_privilege(&user, &GrantObject::Global, UserPrivilegeType::Insert)
+            }
+            PlanNode::InsertInto(v) => self.check_privilege(
+                &user,
+                &GrantObject::Table(v.db_name.clone(), v.tbl_name.clone()),
+                UserPrivilegeType::Insert,
+            ),
+            PlanNode::CreateDatabase(_) => {
+                self.check_privilege(&user, &GrantObject::Global, UserPrivilegeType::Create)
+            }
+            PlanNode::DropDatabase(v) => self.check_privilege(
+                &user,
+                &GrantObject::Database(v.db.clone()),
+                UserPrivilegeType::Drop,
+            ),
+            PlanNode::CreateTable(v) => self.check_privilege(
+                &user,
+                &GrantObject::Database(v.db.clone()),
+                UserPrivilegeType::Create,
+            ),
+            PlanNode::DropTable(v) => self.check_privilege(
+                &user,
+                &GrantObject::Table(v.db.clone(), v.table.clone()),
+                UserPrivilegeType::Drop,
+            ),
+            PlanNode::TruncateTable(v) => self.check_privilege(
+                &user,
+                &GrantObject::Table(v.db.clone(), v.table.clone()),
+                UserPrivilegeType::Drop,
+            ),
+            PlanNode::DescribeTable(v) => self.check_privilege(
+                &user,
+                &GrantObject::Table(v.db.clone(), v.table.clone()),
+                UserPrivilegeType::Select,
+            ),
+            PlanNode::ShowCreateTable(v) => self.check_privilege(
+                &user,
+                &GrantObject::Table(v.db.clone(), v.table.clone()),
+                UserPrivilegeType::Select,
+            ),
+            // Managing the users, the privileges, the workloads and the settings of the other
+            // sessions requires all the privileges on *.*
+            PlanNode::SetVariable(v) if v.scope != SettingScope::Session => {
+                self.check_all_privileges(&user)
+            }
+            PlanNode::CreateUser(_)
+            | PlanNode::AlterUser(_)
+            | PlanNode::DropUser(_)
+            | PlanNode::CreateRole(_)
+            | PlanNode::DropRole(_)
+            | PlanNode::GrantPrivilege(_)
+            | PlanNode::RevokePrivilege(_)
+            | PlanNode::GrantRole(_)
+            | PlanNode::RevokeRole(_)
+            | PlanNode::CreateQuota(_)
+            | PlanNode::DropQuota(_)
+            | PlanNode::CreateResourceGroup(_)
+            | PlanNode::DropResourceGroup(_)
+            | PlanNode::GrantResourceGroup(_) => self.check_all_privileges(&user),
+            PlanNode::Kill(v) => self.check_kill(&user, &v.id),
+            // Switching the database and the settings of the own session need no privileges.
+            PlanNode::UseDatabase(_) | PlanNode::SetVariable(_) => Ok(()),
+            // The parts of a query plan, they are checked by the tables they read.
+            PlanNode::Empty(_)
+            | PlanNode::Stage(_)
+            | PlanNode::Broadcast(_)
+            | PlanNode::Remote(_)
+            | PlanNode::Projection(_)
+            | PlanNode::Expression(_)
+            | PlanNode::AggregatorPartial(_)
+            | PlanNode::AggregatorFinal(_)
+            | PlanNode::Filter(_)
+            | PlanNode::Having(_)
+            | PlanNode::Sort(_)
+            | PlanNode::Limit(_)
+            | PlanNode::LimitBy(_)
+            | PlanNode::Scan(_)
+            | PlanNode::ReadSource(_)
+            | PlanNode::SubQueryExpression(_) => self.check_read_tables(&user, plan),
+        }
+    }
+
+    fn check_all_privileges(&self, user: &str) -> Result<()> {
+        for privilege in UserPrivilegeSet::all_privileges().iter() {
+            self.check_privilege(user, &GrantObject::Global, privilege)?;
+        }
+        Ok(())
+    }
+
+    // The sessions of the user itself can be killed without any privileges.
+    fn check_kill(&self, user: &str, session_id: &str) -> Result<()> {
+        let sessions = self.ctx.get_sessions_manager();
+        match sessions.get_session(&session_id.to_string()) {
+            Some(session) if session.get_current_user().as_deref() == Some(user) => Ok(()),
+            // The unknown session is reported by the interpreter.
+            None => Ok(()),
+            Some(_) => self.check_all_privileges(user),
+        }
+    }
+
+    fn check_read_tables(&self, user: &str, plan: &PlanNode) -> Result<()> {
+        let mut tables = ReadTablesCollector { tables: vec![] };
+        tables.visit_plan_node(plan)?;
+        for (db, table) in tables.tables {
+            // The system tables are readable, except the ones showing the queries,
+            // the settings and the logs of all the users.
+            if db == "system" && !PROTECTED_SYSTEM_TABLES.contains(&table.as_str()) {
+                continue;
+            }
+            self.check_privilege(
+                user,
+                &GrantObject::Table(db, table),
+                UserPrivilegeType::Select,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn check_privilege(
+        &self,
+        user: &str,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        let user_manager = self.ctx.get_sessions_manager().get_user_manager();
+        match user_manager.verify_privilege(user, object, privilege)? {
+            true => Ok(()),
+            false => Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, user '{}' requires {} privilege on {}",
+                user, privilege, object
+            ))),
+        }
+    }
+}
+
+struct ReadTablesCollector {
+    tables: Vec<(String, String)>,
+}
+
+impl PlanVisitor for ReadTablesCollector {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        self.tables.push((plan.db.clone(), plan.table.clone()));
+        Ok(())
+    }
+}
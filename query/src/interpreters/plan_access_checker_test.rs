@@ -0,0 +1,149 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::clusters::Cluster;
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::DatabendQueryContextShared;
+use crate::sql::*;
+
+async fn execute_sql(ctx: DatabendQueryContextRef, sql: &str) -> Result<()> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute().await?;
+    Ok(())
+}
+
+fn check_sql(ctx: DatabendQueryContextRef, sql: &str) -> Result<()> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    PlanAccessChecker::create(ctx).check(&plan)
+}
+
+#[tokio::test]
+async fn test_plan_access_checker() -> Result<()> {
+    // The internal context without the session user is not checked.
+    let ctx = crate::tests::try_create_context()?;
+    execute_sql(
+        ctx.clone(),
+        "CREATE TABLE default.t1(a bigint) Engine = Null",
+    )
+    .await?;
+    execute_sql(ctx.clone(), "CREATE USER test").await?;
+
+    let sessions = ctx.get_sessions_manager();
+    let session = sessions.create_session("TestSession")?;
+    session.set_current_user(String::from("test"));
+    let user_ctx = DatabendQueryContext::from_shared(DatabendQueryContextShared::try_create(
+        sessions.get_conf().clone(),
+        Arc::new(session.as_ref().clone()),
+        Cluster::empty(),
+    ));
+
+    // The system tables are readable, except the ones of all the users.
+    check_sql(user_ctx.clone(), "SELECT * FROM numbers(10)")?;
+    check_sql(user_ctx.clone(), "SELECT * FROM system.settings")?;
+    {
+        let res = check_sql(user_ctx.clone(), "SELECT * FROM system.query_log");
+        assert_eq!(
+            "Code: 55, displayText = Permission denied, user 'test' requires SELECT privilege on 'system'.'query_log'.",
+            format!("{}", res.err().unwrap())
+        );
+        let res = check_sql(user_ctx.clone(), "SELECT * FROM system.processes");
+        assert_eq!(55, res.err().unwrap().code());
+    }
+
+    // The user can kill its own session, but not the others.
+    let admin_session = sessions.create_session("TestSession")?;
+    admin_session.set_current_user(String::from("default"));
+    {
+        check_sql(
+            user_ctx.clone(),
+            &format!("KILL QUERY `{}`", session.get_id()),
+        )?;
+        let kill_admin = format!("KILL QUERY `{}`", admin_session.get_id());
+        let res = check_sql(user_ctx.clone(), &kill_admin);
+        assert_eq!(55, res.err().unwrap().code());
+    }
+
+    // The user has no privileges after created.
+    {
+        let res = check_sql(user_ctx.clone(), "SELECT * FROM default.t1");
+        assert_eq!(
+            "Code: 55, displayText = Permission denied, user 'test' requires SELECT privilege on 'default'.'t1'.",
+            format!("{}", res.err().unwrap())
+        );
+        let res = check_sql(user_ctx.clone(), "EXPLAIN SELECT * FROM default.t1");
+        assert_eq!(55, res.err().unwrap().code());
+        let res = check_sql(user_ctx.clone(), "DROP TABLE default.t1");
+        assert_eq!(55, res.err().unwrap().code());
+        let res = check_sql(user_ctx.clone(), "CREATE DATABASE db1");
+        assert_eq!(55, res.err().unwrap().code());
+        let res = check_sql(user_ctx.clone(), "GRANT ALL ON *.* TO test");
+        assert_eq!(55, res.err().unwrap().code());
+    }
+
+    // Unloading reads the tables and writes to the storage.
+    let copy_into = "COPY INTO 's3://bucket/out/' FROM (SELECT * FROM default.t1)";
+    {
+        let res = check_sql(user_ctx.clone(), copy_into);
+        assert_eq!(55, res.err().unwrap().code());
+    }
+
+    // The privileges on the database apply to its tables.
+    {
+        execute_sql(ctx.clone(), "GRANT SELECT, DROP ON default.* TO test").await?;
+        check_sql(user_ctx.clone(), "SELECT * FROM default.t1")?;
+        check_sql(user_ctx.clone(), "DROP TABLE default.t1")?;
+        check_sql(user_ctx.clone(), "DESC default.t1")?;
+
+        let res = check_sql(user_ctx.clone(), copy_into);
+        assert_eq!(
+            "Code: 55, displayText = Permission denied, user 'test' requires INSERT privilege on *.*.",
+            format!("{}", res.err().unwrap())
+        );
+
+        let res = check_sql(user_ctx.clone(), "CREATE TABLE default.t2(a bigint)");
+        assert_eq!(55, res.err().unwrap().code());
+    }
+
+    // The privileges granted by the role.
+    {
+        execute_sql(ctx.clone(), "CREATE ROLE admin").await?;
+        execute_sql(ctx.clone(), "GRANT ALL ON *.* TO ROLE admin").await?;
+        execute_sql(ctx.clone(), "GRANT ROLE admin TO test").await?;
+        check_sql(user_ctx.clone(), "CREATE DATABASE db1")?;
+        check_sql(user_ctx.clone(), "CREATE USER test2")?;
+        check_sql(user_ctx.clone(), "SELECT * FROM system.query_log")?;
+        check_sql(user_ctx.clone(), copy_into)?;
+        check_sql(
+            user_ctx.clone(),
+            &format!("KILL QUERY `{}`", admin_session.get_id()),
+        )?;
+    }
+
+    // The initial admin user has all the privileges.
+    {
+        session.set_current_user(String::from("default"));
+        check_sql(user_ctx.clone(), "DROP USER test")?;
+    }
+
+    Ok(())
+}
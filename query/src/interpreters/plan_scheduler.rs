@@ -172,6 +172,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            profiling: false,
         }
     }
 
@@ -212,6 +213,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            profiling: false,
         }
     }
 
@@ -255,6 +257,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: vec![self.cluster_nodes[self.local_pos].clone()],
             scatters_expression: stage.scatters_expr.clone(),
+            profiling: false,
         }
     }
 
@@ -328,6 +331,7 @@ impl PlanScheduler {
             schema: plan.schema(),
             aggr_expr: plan.aggr_expr.clone(),
             group_expr: plan.group_expr.clone(),
+            grouping_sets: plan.grouping_sets.clone(),
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
         });
     }
@@ -338,6 +342,7 @@ impl PlanScheduler {
                 schema: plan.schema(),
                 aggr_expr: plan.aggr_expr.clone(),
                 group_expr: plan.group_expr.clone(),
+                grouping_sets: plan.grouping_sets.clone(),
                 input: Arc::new(self.nodes_plan[index].clone()),
             });
         }
@@ -358,6 +363,7 @@ impl PlanScheduler {
             schema: plan.schema.clone(),
             aggr_expr: plan.aggr_expr.clone(),
             group_expr: plan.group_expr.clone(),
+            grouping_sets: plan.grouping_sets.clone(),
             schema_before_group_by: plan.schema_before_group_by.clone(),
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
         })
@@ -369,6 +375,7 @@ impl PlanScheduler {
                 schema: plan.schema.clone(),
                 aggr_expr: plan.aggr_expr.clone(),
                 group_expr: plan.group_expr.clone(),
+                grouping_sets: plan.grouping_sets.clone(),
                 schema_before_group_by: plan.schema_before_group_by.clone(),
                 input: Arc::new(self.nodes_plan[index].clone()),
             })
@@ -435,6 +442,7 @@ impl PlanScheduler {
             query_id: self.query_context.get_id(),
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
+            profiling: false,
         }
     }
 
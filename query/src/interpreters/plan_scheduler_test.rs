@@ -70,6 +70,7 @@ async fn test_scheduler_plan_with_one_convergent_stage() -> Result<()> {
     for (node, remote_action) in scheduled_tasks.get_tasks()? {
         match remote_action {
             FlightAction::CancelAction(_) => assert!(false),
+            FlightAction::FetchProfileAction(_) => assert!(false),
             FlightAction::BroadcastAction(_) => assert!(false),
             FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
         }
@@ -151,6 +152,7 @@ async fn test_scheduler_plan_with_convergent_and_expansive_stage() -> Result<()>
     for (node, remote_action) in scheduled_tasks.get_tasks()? {
         match remote_action {
             FlightAction::CancelAction(_) => assert!(false),
+            FlightAction::FetchProfileAction(_) => assert!(false),
             FlightAction::BroadcastAction(_) => assert!(false),
             FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
         }
@@ -252,6 +254,7 @@ async fn test_scheduler_plan_with_convergent_and_normal_stage() -> Result<()> {
     for (node, remote_action) in scheduled_tasks.get_tasks()? {
         match remote_action {
             FlightAction::CancelAction(_) => assert!(false),
+            FlightAction::FetchProfileAction(_) => assert!(false),
             FlightAction::BroadcastAction(_) => assert!(false),
             FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
         }
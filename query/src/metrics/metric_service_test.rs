@@ -18,6 +18,7 @@ use common_base::tokio;
 use metrics::counter;
 
 use crate::metrics::MetricService;
+use crate::tests::try_create_context;
 
 pub static METRIC_TEST: &str = "metrics.test";
 
@@ -35,11 +36,24 @@ async fn test_metric_server() -> common_exception::Result<()> {
     assert_eq!(resp.text().await.unwrap().find("metrics_test 1"), None);
     counter!(METRIC_TEST, 1);
 
-    let resp = client.get(url).send().await;
+    let resp = client.get(url.clone()).send().await;
     assert!(resp.is_ok());
     let resp = resp.unwrap();
     assert!(resp.status().is_success());
     assert!(resp.text().await.unwrap().contains("metrics_test 1"));
 
+    // A finished query is counted by its kind and status.
+    {
+        let ctx = try_create_context()?;
+        ctx.attach_query_str("select 1");
+    }
+
+    let resp = client.get(url).send().await;
+    assert!(resp.is_ok());
+    let text = resp.unwrap().text().await.unwrap();
+    assert!(text.contains("session_active_sessions"));
+    assert!(text.contains("query_query_numbers{"));
+    assert!(text.contains("status=\"success\""));
+
     Ok(())
 }
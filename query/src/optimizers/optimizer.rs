@@ -54,6 +54,7 @@ impl Optimizers {
         }
     }
 
+    #[tracing::instrument(level = "info", skip(self, plan))]
     pub fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
         let start = Instant::now();
         let mut plan = plan.clone();
@@ -108,6 +108,22 @@ mod tests {
                 \n  Expression: String:String (Before Projection)\
                 \n    ReadDataSource: scan partitions: [1], scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1]",
             },
+            Test {
+                name: "Projection math const recursion",
+                query: "SELECT pow(2, 10)",
+                expect: "\
+                Projection: pow(2, 10):Float64\
+                \n  Expression: 1024:Float64 (Before Projection)\
+                \n    ReadDataSource: scan partitions: [1], scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1]",
+            },
+            Test {
+                name: "Projection non deterministic function",
+                query: "SELECT rand()",
+                expect: "\
+                Projection: rand():UInt32\
+                \n  Expression: rand():UInt32 (Before Projection)\
+                \n    ReadDataSource: scan partitions: [1], scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1]",
+            },
         ];
 
         for test in tests {
@@ -149,7 +149,11 @@ impl PlanRewriter for ExprTransformImpl {
                 let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
                 let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
                 PlanBuilder::from(&new_input)
-                    .aggregate_partial(&new_aggr_expr, &new_group_expr)?
+                    .aggregate_grouping_sets_partial(
+                        &new_aggr_expr,
+                        &new_group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()
             }
         }
@@ -166,7 +170,12 @@ impl PlanRewriter for ExprTransformImpl {
                 let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
                 let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
                 PlanBuilder::from(&new_input)
-                    .aggregate_final(schema_before_group_by, &new_aggr_expr, &new_group_expr)?
+                    .aggregate_grouping_sets_final(
+                        schema_before_group_by,
+                        &new_aggr_expr,
+                        &new_group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()
             }
         }
@@ -58,7 +58,11 @@ impl PlanRewriter for ProjectionPushDownImpl {
             None => {
                 self.before_group_by_schema = Some(new_input.schema());
                 PlanBuilder::from(&new_input)
-                    .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                    .aggregate_grouping_sets_partial(
+                        &plan.aggr_expr,
+                        &plan.group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()
             }
         }
@@ -74,7 +78,12 @@ impl PlanRewriter for ProjectionPushDownImpl {
                 "Logical error: before group by schema must be Some",
             )),
             Some(schema_before_group_by) => PlanBuilder::from(&new_input)
-                .aggregate_final(schema_before_group_by, &plan.aggr_expr, &plan.group_expr)?
+                .aggregate_grouping_sets_final(
+                    schema_before_group_by,
+                    &plan.aggr_expr,
+                    &plan.group_expr,
+                    &plan.grouping_sets,
+                )?
                 .build(),
         }
     }
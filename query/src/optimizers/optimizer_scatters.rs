@@ -73,7 +73,11 @@ impl ScattersOptimizerImpl {
             None => Err(ErrorCode::LogicalError("Cluster aggr input is None")),
             Some(input) => Self::convergent_shuffle_stage(
                 PlanBuilder::from(input.as_ref())
-                    .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                    .aggregate_grouping_sets_partial(
+                        &plan.aggr_expr,
+                        &plan.group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()?,
             ),
         }
@@ -88,7 +92,11 @@ impl ScattersOptimizerImpl {
             Some(input) => Self::normal_shuffle_stage(
                 "_group_by_key",
                 PlanBuilder::from(input.as_ref())
-                    .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                    .aggregate_grouping_sets_partial(
+                        &plan.aggr_expr,
+                        &plan.group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()?,
             ),
         }
@@ -105,7 +113,11 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Standalone aggr input is None")),
             Some(input) => PlanBuilder::from(input.as_ref())
-                .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                .aggregate_grouping_sets_partial(
+                    &plan.aggr_expr,
+                    &plan.group_expr,
+                    &plan.grouping_sets,
+                )?
                 .build(),
         }
     }
@@ -247,7 +259,12 @@ impl PlanRewriter for ScattersOptimizerImpl {
         match self.before_group_by_schema.take() {
             None => Ok(PlanNode::AggregatorFinal(plan.clone())),
             Some(schema_before_group_by) => PlanBuilder::from(&new_input)
-                .aggregate_final(schema_before_group_by, &plan.aggr_expr, &plan.group_expr)?
+                .aggregate_grouping_sets_final(
+                    schema_before_group_by,
+                    &plan.aggr_expr,
+                    &plan.group_expr,
+                    &plan.grouping_sets,
+                )?
                 .build(),
         }
     }
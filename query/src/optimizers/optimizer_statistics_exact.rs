@@ -39,6 +39,10 @@ pub struct StatisticsExactOptimizer {
 
 impl PlanRewriter for StatisticsExactImpl<'_> {
     fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
+        if !plan.grouping_sets.is_empty() {
+            return Ok(PlanNode::AggregatorPartial(plan.clone()));
+        }
+
         let new_plan = match (
             &plan.group_expr[..],
             &plan.aggr_expr[..],
@@ -115,6 +119,7 @@ impl PlanRewriter for StatisticsExactImpl<'_> {
             schema_before_group_by: plan.schema_before_group_by.clone(),
             aggr_expr: plan.aggr_expr.clone(),
             group_expr: plan.group_expr.clone(),
+            grouping_sets: plan.grouping_sets.clone(),
             input: Arc::new(self.rewrite_plan_node(plan.input.as_ref())?),
         }))
     }
@@ -36,6 +36,7 @@ mod processor;
 mod processor_empty;
 mod processor_merge;
 mod processor_mixed;
+mod processor_profiling;
 
 pub use pipe::Pipe;
 pub use pipeline::Pipeline;
@@ -45,3 +46,6 @@ pub use processor::Processor;
 pub use processor_empty::EmptyProcessor;
 pub use processor_merge::MergeProcessor;
 pub use processor_mixed::MixedProcessor;
+pub use processor_profiling::ProcessorProfile;
+pub use processor_profiling::ProcessorProfileValues;
+pub use processor_profiling::ProfilingProcessor;
@@ -56,11 +56,30 @@ pub struct PipelineBuilder {
     ctx: DatabendQueryContextRef,
 
     limit: Option<usize>,
+    profiling: bool,
 }
 
 impl PipelineBuilder {
     pub fn create(ctx: DatabendQueryContextRef) -> PipelineBuilder {
-        PipelineBuilder { ctx, limit: None }
+        PipelineBuilder {
+            ctx,
+            limit: None,
+            profiling: false,
+        }
+    }
+
+    /// Build a pipeline which collects the runtime statistics of its processors.
+    pub fn with_profiling(mut self, profiling: bool) -> PipelineBuilder {
+        self.profiling = profiling;
+        self
+    }
+
+    fn create_pipeline(&self) -> Pipeline {
+        let mut pipeline = Pipeline::create(self.ctx.clone());
+        if self.profiling {
+            pipeline.enable_profiling();
+        }
+        pipeline
     }
 
     #[tracing::instrument(level = "info", skip(self))]
@@ -112,7 +131,7 @@ impl PipelineBuilder {
     }
 
     fn visit_remote(&self, plan: &RemotePlan) -> Result<Pipeline> {
-        let mut pipeline = Pipeline::create(self.ctx.clone());
+        let mut pipeline = self.create_pipeline();
 
         for fetch_node in &plan.fetch_nodes {
             let flight_ticket =
@@ -156,7 +175,7 @@ impl PipelineBuilder {
     fn visit_aggregator_partial(&mut self, node: &AggregatorPartialPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
 
-        if node.group_expr.is_empty() {
+        if node.group_expr.is_empty() && node.grouping_sets.is_empty() {
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(AggregatorPartialTransform::try_create(
                     node.schema(),
@@ -171,6 +190,7 @@ impl PipelineBuilder {
                     node.input.schema(),
                     node.aggr_expr.clone(),
                     node.group_expr.clone(),
+                    node.grouping_sets.clone(),
                 )))
             })?;
         }
@@ -181,7 +201,7 @@ impl PipelineBuilder {
         let mut pipeline = self.visit(&*node.input)?;
         pipeline.merge_processor()?;
 
-        if node.group_expr.is_empty() {
+        if node.group_expr.is_empty() && node.grouping_sets.is_empty() {
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(AggregatorFinalTransform::try_create(
                     node.schema(),
@@ -198,6 +218,7 @@ impl PipelineBuilder {
                     node.schema_before_group_by.clone(),
                     node.aggr_expr.clone(),
                     node.group_expr.clone(),
+                    node.grouping_sets.clone(),
                 )))
             })?;
             pipeline.mixed_processor(self.ctx.get_settings().get_max_threads()? as usize)?;
@@ -299,7 +320,7 @@ impl PipelineBuilder {
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
 
-        let mut pipeline = Pipeline::create(self.ctx.clone());
+        let mut pipeline = self.create_pipeline();
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let max_threads = std::cmp::min(max_threads, plan.parts.len());
         let workers = std::cmp::max(max_threads, 1);
@@ -14,8 +14,13 @@
 
 use std::fmt;
 use std::fmt::Display;
+use std::time::Duration;
 
 use crate::pipelines::processors::Pipeline;
+use crate::pipelines::processors::ProcessorProfileValues;
+use crate::pipelines::processors::ProfilingProcessor;
+use crate::pipelines::transforms::RemoteTransform;
+use crate::pipelines::transforms::SourceTransform;
 
 impl Pipeline {
     pub fn display_indent(&self) -> impl fmt::Display + '_ {
@@ -131,6 +136,97 @@ impl Pipeline {
         Wrapper(self)
     }
 
+    /// The pipeline with the runtime statistics of every processor, for EXPLAIN ANALYZE.
+    pub fn display_profile(&self) -> impl fmt::Display + '_ {
+        struct Wrapper<'a>(&'a Pipeline);
+        impl<'a> fmt::Display for Wrapper<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let millis = |d: Duration| format!("{:.3} ms", d.as_secs_f64() * 1000.0);
+                let mut indent = 0;
+
+                self.0.walk_preorder(|pipe| {
+                    if indent > 0 {
+                        writeln!(f)?;
+                    }
+                    let prefix = "  ".repeat(indent);
+                    let ways = pipe.nums();
+                    write!(
+                        f,
+                        "{}{} × {} {}",
+                        prefix,
+                        pipe.name(),
+                        ways,
+                        if ways == 1 { "processor" } else { "processors" },
+                    )?;
+
+                    let processors = pipe.processors();
+                    for (index, processor) in processors.iter().enumerate() {
+                        let profiling = match processor.as_any().downcast_ref::<ProfilingProcessor>()
+                        {
+                            None => continue,
+                            Some(profiling) => profiling,
+                        };
+                        let inner = profiling.inner();
+
+                        // All the sources of a pipe read the same plan.
+                        if index == 0 {
+                            if let Some(source) = inner.as_any().downcast_ref::<SourceTransform>() {
+                                let plan = source.source_plan();
+                                let scanned = plan.parts.len();
+                                match plan.statistics.partitions_total {
+                                    0 => write!(f, ", partitions: {}", scanned)?,
+                                    total => write!(
+                                        f,
+                                        ", partitions: {} of {} ({} pruned)",
+                                        scanned,
+                                        total,
+                                        total.saturating_sub(scanned)
+                                    )?,
+                                }
+                            }
+                        }
+
+                        let mut inputs = ProcessorProfileValues::default();
+                        for input in profiling.inputs() {
+                            if let Some(input) = input.as_any().downcast_ref::<ProfilingProcessor>() {
+                                let values = input.profile().get_values();
+                                inputs.rows += values.rows;
+                                inputs.poll_time += values.poll_time;
+                            }
+                        }
+
+                        let values = profiling.profile().get_values();
+                        writeln!(f)?;
+                        write!(f, "{}  #{}", prefix, index)?;
+                        if let Some(remote) = inner.as_any().downcast_ref::<RemoteTransform>() {
+                            write!(
+                                f,
+                                " (from {}, first block: {})",
+                                remote.fetch_node_name(),
+                                millis(values.first_block_time)
+                            )?;
+                        }
+                        write!(
+                            f,
+                            ": rows in: {}, rows out: {}, blocks: {}, bytes: {}, wall time: {}, input wait: {}",
+                            inputs.rows,
+                            values.rows,
+                            values.blocks,
+                            values.bytes,
+                            millis(values.wall_time),
+                            millis(inputs.poll_time),
+                        )?;
+                    }
+
+                    indent += 1;
+                    Result::<bool, fmt::Error>::Ok(true)
+                })?;
+                Ok(())
+            }
+        }
+        Wrapper(self)
+    }
+
     pub fn display_graphviz(&self) -> impl fmt::Display + '_ {
         struct Wrapper<'a>(&'a Pipeline);
         impl<'a> fmt::Display for Wrapper<'a> {
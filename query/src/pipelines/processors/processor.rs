@@ -43,5 +43,9 @@ pub trait Processor: Sync + Send {
     fn as_any(&self) -> &dyn Any;
 
     /// Execute the processor.
+    /// The sources read through `DatabendQueryContext::try_create_cancellable` and the merged
+    /// streams check the cancellation token between blocks, so a killed or timed out query
+    /// stops with the cancelled reason. A processor that spawns its own tasks must stop them
+    /// with `DatabendQueryContext::check_cancelled` too.
     async fn execute(&self) -> Result<SendableDataBlockStream>;
 }
@@ -52,6 +52,7 @@ impl MergeProcessor {
         for i in 0..len {
             let processor = self.inputs[i].clone();
             let sender = sender.clone();
+            let ctx = self.ctx.clone();
             self.ctx.execute_task(async move {
                 let mut stream = match processor.execute().await {
                     Err(e) => {
@@ -64,7 +65,8 @@ impl MergeProcessor {
                 };
 
                 while let Some(item) = stream.next().await {
-                    match item {
+                    // Stop pulling data once the query is cancelled.
+                    match ctx.check_cancelled().and(item) {
                         Ok(item) => {
                             if let Err(error) = sender.send(Ok(item)).await {
                                 // Stop pulling data
@@ -83,7 +85,8 @@ impl MergeProcessor {
                 }
             })?;
         }
-        Ok(Box::pin(ReceiverStream::new(receiver)))
+        let receiver_stream = Box::pin(ReceiverStream::new(receiver));
+        Ok(Box::pin(self.ctx.try_create_cancellable(receiver_stream)?))
     }
 }
 
@@ -0,0 +1,169 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::tokio::macros::support::Pin;
+use common_base::tokio::macros::support::Poll;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::pipelines::processors::Processor;
+
+/// Runtime statistics of a processor, collected for EXPLAIN ANALYZE.
+#[derive(Default)]
+pub struct ProcessorProfile {
+    rows: AtomicUsize,
+    blocks: AtomicUsize,
+    bytes: AtomicUsize,
+    // Time spent in polling the output stream, the consumer waits on this processor meanwhile.
+    poll_time_ns: AtomicU64,
+    // Time from the start of the execution to the first output block and to the end of the output.
+    first_block_time_ns: AtomicU64,
+    wall_time_ns: AtomicU64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ProcessorProfileValues {
+    pub rows: usize,
+    pub blocks: usize,
+    pub bytes: usize,
+    pub poll_time: Duration,
+    pub first_block_time: Duration,
+    pub wall_time: Duration,
+}
+
+impl ProcessorProfile {
+    pub fn get_values(&self) -> ProcessorProfileValues {
+        ProcessorProfileValues {
+            rows: self.rows.load(Ordering::Relaxed),
+            blocks: self.blocks.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+            poll_time: Duration::from_nanos(self.poll_time_ns.load(Ordering::Relaxed)),
+            first_block_time: Duration::from_nanos(
+                self.first_block_time_ns.load(Ordering::Relaxed),
+            ),
+            wall_time: Duration::from_nanos(self.wall_time_ns.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn add_block(&self, block: &DataBlock, since_start: Duration) {
+        if self.blocks.fetch_add(1, Ordering::Relaxed) == 0 {
+            self.first_block_time_ns
+                .store(since_start.as_nanos() as u64, Ordering::Relaxed);
+        }
+        self.rows.fetch_add(block.num_rows(), Ordering::Relaxed);
+        self.bytes.fetch_add(block.memory_size(), Ordering::Relaxed);
+    }
+
+    fn add_poll_time(&self, elapsed: Duration) {
+        self.poll_time_ns
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn set_wall_time(&self, elapsed: Duration) {
+        self.wall_time_ns
+            .store(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+/// Wraps a processor to collect the runtime statistics of its output.
+pub struct ProfilingProcessor {
+    inner: Arc<dyn Processor>,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl ProfilingProcessor {
+    pub fn create(inner: Arc<dyn Processor>) -> Self {
+        ProfilingProcessor {
+            inner,
+            profile: Arc::new(ProcessorProfile::default()),
+        }
+    }
+
+    pub fn inner(&self) -> Arc<dyn Processor> {
+        self.inner.clone()
+    }
+
+    pub fn profile(&self) -> Arc<ProcessorProfile> {
+        self.profile.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ProfilingProcessor {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        Result::Err(ErrorCode::LogicalError(
+            "Cannot call ProfilingProcessor connect_to",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        self.inner.inputs()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let start = Instant::now();
+        let stream = self.inner.execute().await?;
+        self.profile.add_poll_time(start.elapsed());
+
+        Ok(Box::pin(ProfilingStream {
+            start,
+            inner: stream,
+            profile: self.profile.clone(),
+        }))
+    }
+}
+
+struct ProfilingStream {
+    start: Instant,
+    inner: SendableDataBlockStream,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl Stream for ProfilingStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll_start = Instant::now();
+        let poll = self.inner.poll_next_unpin(cx);
+        self.profile.add_poll_time(poll_start.elapsed());
+
+        match &poll {
+            Poll::Ready(Some(Ok(block))) => self.profile.add_block(block, self.start.elapsed()),
+            Poll::Ready(_) => self.profile.set_wall_time(self.start.elapsed()),
+            Poll::Pending => {}
+        }
+        poll
+    }
+}
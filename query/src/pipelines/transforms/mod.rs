@@ -14,6 +14,7 @@
 
 pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
+pub use transform_copy_into::CopyIntoTransform;
 pub use transform_create_sets::CreateSetsTransform;
 pub use transform_create_sets::SubQueriesPuller;
 pub use transform_expression::ExpressionTransform;
@@ -54,6 +55,7 @@ mod transform_source_test;
 
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
+mod transform_copy_into;
 mod transform_create_sets;
 mod transform_expression;
 mod transform_expression_executor;
@@ -69,3 +71,4 @@ mod transform_sort_partial;
 mod transform_source;
 
 mod group_by;
+mod metrics;
@@ -0,0 +1,223 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::csv::write as csv_write;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_datablocks::DataBlock;
+use common_datablocks::ParquetWriteOptions;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CopyFormatOptions;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::datasources::dal::DataAccessor;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+
+/// Unloads the blocks of its input into files of the target location.
+///
+/// The blocks are appended to the current file as they arrive, the file is uploaded by
+/// `put_stream` once its size reaches `max_file_size`. A CSV file is the concatenation of
+/// its encoded blocks, so each block is encoded on arrival and only the encoded chunks are kept.
+/// The footer of a parquet file depends on all its row groups, so its blocks are encoded
+/// together when the file is uploaded.
+/// Outputs one row (file, rows, bytes) per written file.
+pub struct CopyIntoTransform {
+    data_accessor: Arc<dyn DataAccessor>,
+    /// All the files are named as `{file_prefix}_{sequence}.{extension}`
+    file_prefix: String,
+    file_format: String,
+    format_options: CopyFormatOptions,
+    /// Compression, encoding and row group size of the parquet files, taken from `format_options`
+    parquet_options: ParquetWriteOptions,
+    max_file_size: u64,
+    schema: DataSchemaRef,
+    input: Arc<dyn Processor>,
+}
+
+/// The file being written.
+#[derive(Default)]
+struct UnloadFile {
+    /// The encoded chunks of a CSV file
+    chunks: Vec<Vec<u8>>,
+    /// The blocks of a parquet file, encoded when the file is uploaded
+    blocks: Vec<DataBlock>,
+    /// The encoded bytes of a CSV file, or the in-memory bytes of the parquet blocks
+    size: u64,
+    rows: u64,
+}
+
+impl CopyIntoTransform {
+    pub fn try_create(
+        data_accessor: Arc<dyn DataAccessor>,
+        file_prefix: String,
+        file_format: String,
+        format_options: CopyFormatOptions,
+        max_file_size: u64,
+        schema: DataSchemaRef,
+    ) -> Result<Self> {
+        let parquet_options = ParquetWriteOptions::from_table_options(&format_options)?;
+        Ok(CopyIntoTransform {
+            data_accessor,
+            file_prefix,
+            file_format,
+            format_options,
+            parquet_options,
+            max_file_size,
+            schema,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+
+    // The file format is PARQUET or CSV, which is checked by the plan parser.
+    fn append(&self, file: &mut UnloadFile, block: DataBlock) -> Result<()> {
+        file.rows += block.num_rows() as u64;
+        if self.file_format == "CSV" {
+            let chunk = self.encode_csv(&block, file.chunks.is_empty())?;
+            file.size += chunk.len() as u64;
+            file.chunks.push(chunk);
+        } else {
+            file.size += block.memory_size() as u64;
+            file.blocks.push(block);
+        }
+        Ok(())
+    }
+
+    async fn write_file(&self, sequence: usize, file: UnloadFile) -> Result<(String, u64, u64)> {
+        let mut chunks = file.chunks;
+        if !file.blocks.is_empty() {
+            chunks.push(self.encode_parquet(file.blocks)?);
+        }
+
+        let path = format!(
+            "{}_{}.{}",
+            self.file_prefix,
+            sequence,
+            self.file_format.to_lowercase()
+        );
+        let len = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+        let stream = futures::stream::iter(chunks.into_iter().map(Ok));
+        self.data_accessor
+            .put_stream(&path, Box::new(stream), len)
+            .await?;
+        Ok((path, file.rows, len as u64))
+    }
+
+    fn encode_parquet(&self, blocks: Vec<DataBlock>) -> Result<Vec<u8>> {
+        let block = DataBlock::concat_blocks(&blocks)?;
+        let mut buffer = vec![];
+        DataBlock::write_parquet(block, &mut buffer, &self.parquet_options)?;
+        Ok(buffer)
+    }
+
+    /// Encodes a block into a chunk of the CSV file, the first chunk has the header if required.
+    fn encode_csv(&self, block: &DataBlock, first_chunk: bool) -> Result<Vec<u8>> {
+        let delimiter = match self.format_options.get("field_delimiter") {
+            Some(v) if v.len() == 1 => v.as_bytes()[0],
+            Some(v) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "FIELD_DELIMITER must be a single character, but got '{}'",
+                    v
+                )))
+            }
+            None => b',',
+        };
+        let with_header = matches!(
+            self.format_options.get("header").map(|v| v.to_lowercase()),
+            Some(v) if v == "true"
+        );
+
+        let mut writer = csv_write::WriterBuilder::new()
+            .delimiter(delimiter)
+            .from_writer(vec![]);
+        if with_header && first_chunk {
+            csv_write::write_header(&mut writer, &block.schema().to_arrow())?;
+        }
+
+        let options = csv_write::SerializeOptions::default();
+        let batch = RecordBatch::try_from(block.clone())?;
+        csv_write::write_batch(&mut writer, &batch, &options)?;
+
+        writer
+            .into_inner()
+            .map_err(|e| ErrorCode::UnknownException(format!("Cannot flush csv writer: {}", e)))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for CopyIntoTransform {
+    fn name(&self) -> &str {
+        "CopyIntoTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let mut stream = self.input.execute().await?;
+        let mut file = UnloadFile::default();
+        let mut written = vec![];
+
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if block.is_empty() {
+                continue;
+            }
+
+            self.append(&mut file, block)?;
+            if file.size >= self.max_file_size {
+                let file = std::mem::take(&mut file);
+                written.push(self.write_file(written.len(), file).await?);
+            }
+        }
+
+        if file.rows > 0 {
+            written.push(self.write_file(written.len(), file).await?);
+        }
+
+        let paths: Vec<&[u8]> = written.iter().map(|(p, _, _)| p.as_bytes()).collect();
+        let rows: Vec<u64> = written.iter().map(|(_, r, _)| *r).collect();
+        let bytes: Vec<u64> = written.iter().map(|(_, _, b)| *b).collect();
+        let block = DataBlock::create_by_array(self.schema.clone(), vec![
+            Series::new(paths),
+            Series::new(rows),
+            Series::new(bytes),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            vec![block],
+        )))
+    }
+}
@@ -20,8 +20,11 @@ use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::ArrayJoinFunction;
+use common_planners::ActionLambda;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
 use common_planners::ExpressionChain;
@@ -81,7 +84,8 @@ impl ExpressionExecutor {
             column_map.insert(f.name().clone(), column);
         }
 
-        let rows = block.num_rows();
+        let mut rows = block.num_rows();
+        let mut lambda_map: HashMap<String, &ActionLambda> = HashMap::new();
 
         for action in self.chain.actions.iter() {
             if let ExpressionAction::Alias(alias) = action {
@@ -110,6 +114,12 @@ impl ExpressionExecutor {
                     let mut arg_columns = Vec::with_capacity(f.arg_names.len());
 
                     for arg in f.arg_names.iter() {
+                        if let Some(lambda) = lambda_map.get(arg) {
+                            let lists = &f.arg_names[1..];
+                            arg_columns.push(Self::eval_lambda(lambda, lists, &column_map)?);
+                            continue;
+                        }
+
                         let column = column_map.get(arg).cloned().ok_or_else(|| {
                             ErrorCode::LogicalError(
                                 "Arguments must be prepared before function transform",
@@ -121,6 +131,11 @@ impl ExpressionExecutor {
                     let func = f.to_function()?;
                     let column = func.eval(&arg_columns, rows)?;
 
+                    // arrayJoin unfolds the arrays into rows, the other columns are replicated
+                    if ArrayJoinFunction::is_array_join(&f.func_name) {
+                        rows = Self::unfold_columns(&arg_columns[0], &mut column_map)?;
+                    }
+
                     let column = DataColumnWithField::new(
                         column,
                         DataField::new(&f.name, f.return_type.clone(), f.is_nullable),
@@ -142,6 +157,9 @@ impl ExpressionExecutor {
 
                     column_map.insert(constant.name.clone(), column);
                 }
+                ExpressionAction::Lambda(lambda) => {
+                    lambda_map.insert(lambda.name.clone(), lambda);
+                }
                 _ => {}
             }
         }
@@ -175,4 +193,70 @@ impl ExpressionExecutor {
             project_columns,
         ))
     }
+
+    /// Evaluates the lambda body over the flattened items of the arrays `lists`, the i-th
+    /// lambda parameter is bound to the items of the i-th array.
+    fn eval_lambda(
+        lambda: &ActionLambda,
+        lists: &[String],
+        column_map: &HashMap<String, DataColumnWithField>,
+    ) -> Result<DataColumnWithField> {
+        let mut param_columns = Vec::with_capacity(lists.len());
+        let mut lambda_offsets: Option<Vec<i64>> = None;
+        for name in lists {
+            let column = column_map.get(name).ok_or_else(|| {
+                ErrorCode::LogicalError("Arguments must be prepared before lambda transform")
+            })?;
+            let array = column.column().to_array()?;
+            let (values, offsets) = array.list()?.flatten();
+            match &lambda_offsets {
+                Some(expected) if expected != &offsets => {
+                    return Err(ErrorCode::BadArguments(
+                        "The arrays of a lambda must have the same sizes",
+                    ));
+                }
+                _ => lambda_offsets = Some(offsets),
+            }
+            param_columns.push(DataColumn::Array(values));
+        }
+
+        let params_schema = DataSchemaRefExt::create(lambda.params.clone());
+        let output_schema =
+            DataSchemaRefExt::create(vec![lambda.body.to_data_field(&params_schema)?]);
+        let executor = ExpressionExecutor::try_create(
+            "lambda executor",
+            params_schema.clone(),
+            output_schema,
+            vec![lambda.body.clone()],
+            false,
+        )?;
+        let block = executor.execute(&DataBlock::create(params_schema, param_columns))?;
+        Ok(DataColumnWithField::new(
+            block.column(0).clone(),
+            DataField::new(&lambda.name, lambda.return_type.clone(), true),
+        ))
+    }
+
+    /// Replicates the columns for the unfolded rows of the arrays `list`, returns the number
+    /// of the unfolded rows.
+    fn unfold_columns(
+        list: &DataColumnWithField,
+        column_map: &mut HashMap<String, DataColumnWithField>,
+    ) -> Result<usize> {
+        let array = list.column().to_array()?;
+        let unfolded_rows = ArrayJoinFunction::unfolded_rows(array.list()?);
+        for column in column_map.values_mut() {
+            let unfolded = match column.column() {
+                DataColumn::Constant(value, _) => {
+                    DataColumn::Constant(value.clone(), unfolded_rows.len())
+                }
+                DataColumn::Array(series) => {
+                    let mut indices = unfolded_rows.iter().copied();
+                    DataColumn::Array(unsafe { series.take_iter_unchecked(&mut indices)? })
+                }
+            };
+            *column = DataColumnWithField::new(unfolded, column.field().clone());
+        }
+        Ok(unfolded_rows.len())
+    }
 }
@@ -25,6 +25,7 @@ use common_exception::Result;
 use common_functions::aggregates::get_layout_offsets;
 use common_functions::aggregates::StateAddr;
 use common_infallible::RwLock;
+use common_planners::grouping_sets_key_fields;
 use common_planners::Expression;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -38,6 +39,7 @@ pub struct GroupByFinalTransform {
     max_block_size: usize,
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
+    grouping_sets: Vec<Vec<usize>>,
     schema: DataSchemaRef,
     schema_before_group_by: DataSchemaRef,
     input: Arc<dyn Processor>,
@@ -50,16 +52,38 @@ impl GroupByFinalTransform {
         schema_before_group_by: DataSchemaRef,
         aggr_exprs: Vec<Expression>,
         group_exprs: Vec<Expression>,
+        grouping_sets: Vec<Vec<usize>>,
     ) -> Self {
         Self {
             max_block_size,
             aggr_exprs,
             group_exprs,
+            grouping_sets,
             schema,
             schema_before_group_by,
             input: Arc::new(EmptyProcessor::create()),
         }
     }
+
+    /// The keys which are rolled up in the grouping set of a row are NULL, the grouping id
+    /// comes first in the group columns and is moved behind the keys.
+    fn restore_rolled_up_keys(mut group_columns: Vec<Series>) -> Result<Vec<Series>> {
+        let grouping_ids = group_columns.remove(0);
+        let ids = grouping_ids.u32()?;
+
+        let mut columns = Vec::with_capacity(group_columns.len() + 1);
+        for (index, key) in group_columns.into_iter().enumerate() {
+            let rolled_up = DFBooleanArray::new_from_iter(
+                ids.into_no_null_iter().map(|id| id & (1 << index) != 0),
+            );
+            let rolled_up: DataColumn = rolled_up.into_series().into();
+            let null = DataColumn::Constant(DataValue::Null, key.len());
+            let key = rolled_up.if_then_else(&null, &key.into())?;
+            columns.push(key.to_array()?);
+        }
+        columns.push(grouping_ids);
+        Ok(columns)
+    }
 }
 
 #[async_trait::async_trait]
@@ -92,23 +116,33 @@ impl Processor for GroupByFinalTransform {
         let aggr_funcs_len = funcs.len();
         let group_expr_len = self.group_exprs.len();
 
-        let group_cols = self
+        let mut group_cols = self
             .group_exprs
             .iter()
             .map(|x| x.column_name())
             .collect::<Vec<_>>();
 
-        let group_fields = self
+        let mut group_fields = self
             .group_exprs
             .iter()
             .map(|c| c.to_data_field(&self.schema_before_group_by))
             .collect::<Result<Vec<_>>>()?;
 
+        let mut sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
+
+        // With grouping sets the key is the grouping id and the hidden keys, see the partial.
+        if !self.grouping_sets.is_empty() {
+            group_fields =
+                grouping_sets_key_fields(&self.schema_before_group_by, &self.group_exprs)?;
+            group_cols = group_fields.iter().map(|f| f.name().clone()).collect();
+            sample_block =
+                DataBlock::empty_with_schema(DataSchemaRefExt::create(group_fields.clone()));
+        }
+
         let start = Instant::now();
         let arena = Bump::new();
 
         let mut stream = self.input.execute().await?;
-        let sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
         let method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
 
         let (layout, offsets_aggregate_states) = unsafe { get_layout_offsets(&funcs) };
@@ -198,7 +232,8 @@ impl Processor for GroupByFinalTransform {
                 }
 
                 // Build final state block.
-                let mut columns: Vec<Series> = Vec::with_capacity(aggr_funcs_len + group_expr_len);
+                let mut columns: Vec<Series> =
+                    Vec::with_capacity(aggr_funcs_len + group_expr_len + 1);
 
                 for (i, value) in aggr_values.iter().enumerate() {
                     columns.push(DataValue::try_into_data_array(
@@ -209,7 +244,10 @@ impl Processor for GroupByFinalTransform {
 
                 {
                     let group_columns = $hash_method.de_group_columns(keys, &group_fields)?;
-                    columns.extend_from_slice(&group_columns);
+                    match self.grouping_sets.is_empty() {
+                        true => columns.extend_from_slice(&group_columns),
+                        false => columns.extend(Self::restore_rolled_up_keys(group_columns)?),
+                    }
                 }
 
                 let mut blocks = vec![];
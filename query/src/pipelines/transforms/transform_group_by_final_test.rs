@@ -55,6 +55,7 @@ async fn test_transform_final_group_by() -> Result<()> {
             source_schema.clone(),
             aggr_exprs.to_vec(),
             group_exprs.to_vec(),
+            vec![],
         )))
     })?;
     pipeline.merge_processor()?;
@@ -67,6 +68,7 @@ async fn test_transform_final_group_by() -> Result<()> {
             source_schema.clone(),
             aggr_exprs.to_vec(),
             group_exprs.to_vec(),
+            vec![],
         )))
     })?;
 
@@ -92,3 +94,78 @@ async fn test_transform_final_group_by() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_final_group_by_grouping_sets() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // sum(number)
+    let aggr_exprs = &[sum(col("number"))];
+
+    // GROUP BY ROLLUP(number)
+    let group_exprs = &[col("number")];
+    let grouping_sets = &[vec![0], vec![]];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_grouping_sets_partial(aggr_exprs, group_exprs, grouping_sets)?
+        .build()?;
+
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_grouping_sets_final(
+            test_source.number_schema_for_test()?,
+            aggr_exprs,
+            group_exprs,
+            grouping_sets,
+        )?
+        .build()?;
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(5)?;
+    let source_schema = test_source.number_schema_for_test()?;
+    pipeline.add_source(Arc::new(source))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByPartialTransform::create(
+            aggr_partial.schema(),
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+            grouping_sets.to_vec(),
+        )))
+    })?;
+    pipeline.merge_processor()?;
+
+    let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByFinalTransform::create(
+            aggr_final.schema(),
+            max_block_size,
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+            grouping_sets.to_vec(),
+        )))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 3);
+
+    // SELECT SUM(number), number from numbers(5) group by rollup(number);
+    let expected = vec![
+        "+-------------+--------+--------------+",
+        "| sum(number) | number | _grouping_id |",
+        "+-------------+--------+--------------+",
+        "| 0           | 0      | 0            |",
+        "| 1           | 1      | 0            |",
+        "| 10          | NULL   | 1            |",
+        "| 2           | 2      | 0            |",
+        "| 3           | 3      | 0            |",
+        "| 4           | 4      | 0            |",
+        "+-------------+--------+--------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
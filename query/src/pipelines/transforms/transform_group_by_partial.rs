@@ -21,9 +21,12 @@ use common_datablocks::HashMethod;
 use common_datablocks::HashMethodKind;
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_planners::grouping_set_id;
+use common_planners::grouping_sets_key_fields;
 use common_planners::Expression;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
@@ -34,6 +37,7 @@ use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
 pub struct GroupByPartialTransform {
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
+    grouping_sets: Vec<Vec<usize>>,
 
     schema: DataSchemaRef,
     schema_before_group_by: DataSchemaRef,
@@ -46,10 +50,12 @@ impl GroupByPartialTransform {
         schema_before_group_by: DataSchemaRef,
         aggr_exprs: Vec<Expression>,
         group_exprs: Vec<Expression>,
+        grouping_sets: Vec<Vec<usize>>,
     ) -> Self {
         Self {
             aggr_exprs,
             group_exprs,
+            grouping_sets,
             schema,
             schema_before_group_by,
             input: Arc::new(EmptyProcessor::create()),
@@ -63,15 +69,58 @@ impl GroupByPartialTransform {
             .collect::<Vec<_>>()
     }
 
+    /// The input schema with the hidden grouping key columns appended.
+    fn grouping_sets_schema(&self) -> Result<DataSchemaRef> {
+        let mut fields = self.schema_before_group_by.fields().clone();
+        fields.extend(grouping_sets_key_fields(
+            &self.schema_before_group_by,
+            &self.group_exprs,
+        )?);
+        Ok(DataSchemaRefExt::create(fields))
+    }
+
+    /// Repeats the block once for every grouping set, with the grouping id and the keys of
+    /// the grouping set appended, the rolled up keys are a constant so they group together.
+    fn expand_grouping_sets(
+        block: &DataBlock,
+        schema: &DataSchemaRef,
+        group_cols: &[String],
+        grouping_sets: &[Vec<usize>],
+    ) -> Result<Vec<DataBlock>> {
+        let rows = block.num_rows();
+        let mut blocks = Vec::with_capacity(grouping_sets.len());
+        for grouping_set in grouping_sets {
+            let grouping_id = grouping_set_id(grouping_set, group_cols.len());
+            let mut columns = block.columns().to_vec();
+            columns.push(DataColumn::Constant(
+                DataValue::UInt32(Some(grouping_id)),
+                rows,
+            ));
+
+            for (index, name) in group_cols.iter().enumerate() {
+                let column = block.try_column_by_name(name)?;
+                match grouping_set.contains(&index) {
+                    true => columns.push(column.clone()),
+                    false => columns.push(
+                        DataColumn::Constant(DataValue::UInt8(Some(0)), rows)
+                            .cast_with_type(&column.data_type())?,
+                    ),
+                }
+            }
+            blocks.push(DataBlock::create(schema.clone(), columns));
+        }
+        Ok(blocks)
+    }
+
     #[inline]
     async fn aggregate<Method: HashMethod + PolymorphicKeysHelper<Method>>(
         &self,
         method: Method,
         group_cols: Vec<String>,
+        stream: SendableDataBlockStream,
     ) -> Result<SendableDataBlockStream> {
         let start = Instant::now();
 
-        let stream = self.input.execute().await?;
         let aggr_exprs = &self.aggr_exprs;
         let schema = self.schema_before_group_by.clone();
         let aggregator_params = AggregatorParams::try_create(schema, aggr_exprs)?;
@@ -127,16 +176,42 @@ impl Processor for GroupByPartialTransform {
     /// 1.2)  serialize the state to the output block
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         tracing::debug!("execute...");
-        let group_cols = self.extract_group_columns();
-        let sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
-        let hash_method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
+        let mut group_cols = self.extract_group_columns();
+        let mut stream = self.input.execute().await?;
+        let mut sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
+
+        // With grouping sets, group by the grouping id and the hidden keys of each grouping set.
+        if !self.grouping_sets.is_empty() {
+            let schema = self.grouping_sets_schema()?;
+            let grouping_sets = self.grouping_sets.clone();
+            let keys = group_cols;
+            group_cols = schema.fields()[self.schema_before_group_by.fields().len()..]
+                .iter()
+                .map(|f| f.name().clone())
+                .collect();
+            sample_block = DataBlock::empty_with_schema(schema.clone());
+            stream = Box::pin(
+                stream
+                    .map(move |block| {
+                        let blocks = block.and_then(|block| {
+                            Self::expand_grouping_sets(&block, &schema, &keys, &grouping_sets)
+                        });
+                        match blocks {
+                            Ok(blocks) => blocks.into_iter().map(Ok).collect::<Vec<_>>(),
+                            Err(cause) => vec![Err(cause)],
+                        }
+                    })
+                    .flat_map(futures::stream::iter),
+            );
+        }
 
+        let hash_method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
         match hash_method {
-            HashMethodKind::KeysU8(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::KeysU16(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::KeysU32(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::KeysU64(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::Serializer(method) => self.aggregate(method, group_cols).await,
+            HashMethodKind::KeysU8(method) => self.aggregate(method, group_cols, stream).await,
+            HashMethodKind::KeysU16(method) => self.aggregate(method, group_cols, stream).await,
+            HashMethodKind::KeysU32(method) => self.aggregate(method, group_cols, stream).await,
+            HashMethodKind::KeysU64(method) => self.aggregate(method, group_cols, stream).await,
+            HashMethodKind::Serializer(method) => self.aggregate(method, group_cols, stream).await,
         }
     }
 }
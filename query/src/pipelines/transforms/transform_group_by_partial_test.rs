@@ -48,6 +48,7 @@ async fn test_transform_partial_group_by() -> Result<()> {
             source_schema.clone(),
             aggr_exprs.clone(),
             group_exprs.clone(),
+            vec![],
         )))
     })?;
     pipeline.merge_processor()?;
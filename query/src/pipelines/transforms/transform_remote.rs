@@ -49,6 +49,10 @@ impl RemoteTransform {
         })
     }
 
+    pub fn fetch_node_name(&self) -> &str {
+        &self.fetch_node_name
+    }
+
     async fn flight_client(&self) -> Result<FlightClient> {
         let context = self.ctx.clone();
         let node_name = self.fetch_node_name.clone();
@@ -94,7 +98,7 @@ impl Processor for RemoteTransform {
         let mut flight_client = self.flight_client().await?;
         let fetch_stream = flight_client.fetch_stream(fetch_ticket, data_schema, timeout);
         Ok(Box::pin(
-            self.ctx.try_create_abortable(fetch_stream.await?)?,
+            self.ctx.try_create_cancellable(fetch_stream.await?)?,
         ))
     }
 }
@@ -21,6 +21,8 @@ use common_planners::ReadDataSourcePlan;
 use common_streams::CorrectWithSchemaStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
+use metrics::counter;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
@@ -39,6 +41,10 @@ impl SourceTransform {
         Ok(SourceTransform { ctx, source_plan })
     }
 
+    pub fn source_plan(&self) -> &ReadDataSourcePlan {
+        &self.source_plan
+    }
+
     async fn read_table(&self, db: &str) -> Result<SendableDataBlockStream> {
         let table_id = self.source_plan.table_id;
         let table_ver = self.source_plan.table_version;
@@ -53,9 +59,27 @@ impl SourceTransform {
                 .get_table_function(&self.source_plan.table, self.source_plan.tbl_args.clone())?;
             func_meta.raw().clone().as_table()
         };
-        let table_stream = table.read(self.ctx.clone(), &self.source_plan);
+        let table_stream = table.read(self.ctx.clone(), &self.source_plan).await?;
+
+        // Count what each engine actually produced, labelled by engine name.
+        let engine = table.engine().to_string();
+        let table_stream = table_stream.map(move |block| {
+            if let Ok(block) = &block {
+                counter!(
+                    super::metrics::METRIC_STORAGE_SCAN_ROWS,
+                    block.num_rows() as u64,
+                    "engine" => engine.clone()
+                );
+                counter!(
+                    super::metrics::METRIC_STORAGE_SCAN_BYTES,
+                    block.memory_size() as u64,
+                    "engine" => engine.clone()
+                );
+            }
+            block
+        });
         Ok(Box::pin(
-            self.ctx.try_create_abortable(table_stream.await?)?,
+            self.ctx.try_create_cancellable(Box::pin(table_stream))?,
         ))
     }
 }
@@ -26,17 +26,21 @@ use futures::future::AbortRegistration;
 use futures::stream::Abortable;
 use futures::Future;
 use futures::StreamExt;
+use tokio_rustls::TlsAcceptor;
 use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::servers::clickhouse::clickhouse_session::ClickHouseConnection;
 use crate::servers::clickhouse::reject_connection::RejectCHConnection;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
+use crate::servers::tls::build_tls_server_config;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionManagerRef;
 
 pub struct ClickHouseHandler {
     sessions: SessionManagerRef,
+    // Serve the secure native protocol, TLS from the first byte.
+    with_tls: bool,
 
     abort_handle: AbortHandle,
     abort_registration: Option<AbortRegistration>,
@@ -45,15 +49,38 @@ pub struct ClickHouseHandler {
 
 impl ClickHouseHandler {
     pub fn create(sessions: SessionManagerRef) -> Box<dyn Server> {
+        Self::create_impl(sessions, false)
+    }
+
+    pub fn create_with_tls(sessions: SessionManagerRef) -> Box<dyn Server> {
+        Self::create_impl(sessions, true)
+    }
+
+    fn create_impl(sessions: SessionManagerRef, with_tls: bool) -> Box<dyn Server> {
         let (abort_handle, registration) = AbortHandle::new_pair();
         Box::new(ClickHouseHandler {
             sessions,
+            with_tls,
             abort_handle,
             abort_registration: Some(registration),
             join_handle: None,
         })
     }
 
+    fn tls_acceptor(&self) -> Result<Option<TlsAcceptor>> {
+        if !self.with_tls {
+            return Ok(None);
+        }
+
+        let conf = self.sessions.get_conf();
+        let tls_config = build_tls_server_config(
+            "clickhouse handler",
+            &conf.query.clickhouse_tls_server_cert,
+            &conf.query.clickhouse_tls_server_key,
+        )?;
+        Ok(Some(TlsAcceptor::from(tls_config)))
+    }
+
     async fn listener_tcp(socket: SocketAddr) -> Result<(TcpListenerStream, SocketAddr)> {
         let listener = tokio::net::TcpListener::bind(socket).await.map_err(|e| {
             ErrorCode::TokioError(format!(
@@ -67,23 +94,42 @@ impl ClickHouseHandler {
         Ok((TcpListenerStream::new(listener), listener_addr))
     }
 
-    fn listen_loop(&self, stream: ListeningStream, r: Arc<Runtime>) -> impl Future<Output = ()> {
+    fn listen_loop(
+        &self,
+        stream: ListeningStream,
+        r: Arc<Runtime>,
+        tls: Option<TlsAcceptor>,
+    ) -> impl Future<Output = ()> {
         let sessions = self.sessions.clone();
         stream.for_each(move |accept_socket| {
             let executor = r.clone();
             let sessions = sessions.clone();
+            let tls = tls.clone();
             async move {
                 match accept_socket {
                     Err(error) => log::error!("Broken session connection: {}", error),
-                    Ok(socket) => ClickHouseHandler::accept_socket(sessions, executor, socket),
+                    Ok(socket) => ClickHouseHandler::accept_socket(sessions, executor, socket, tls),
                 };
             }
         })
     }
 
-    fn reject_connection(stream: TcpStream, executor: Arc<Runtime>, error: ErrorCode) {
+    fn reject_connection(
+        stream: TcpStream,
+        executor: Arc<Runtime>,
+        error: ErrorCode,
+        tls: Option<TlsAcceptor>,
+    ) {
         executor.spawn(async move {
-            if let Err(error) = RejectCHConnection::reject(stream, error).await {
+            let rejected = match tls {
+                None => RejectCHConnection::reject(stream, error).await,
+                Some(acceptor) => match acceptor.accept(stream).await {
+                    Ok(tls_stream) => RejectCHConnection::reject(tls_stream, error).await,
+                    Err(cause) => Err(ErrorCode::from(cause)),
+                },
+            };
+
+            if let Err(error) = rejected {
                 log::error!(
                     "Unexpected error occurred during reject connection: {:?}",
                     error
@@ -92,12 +138,17 @@ impl ClickHouseHandler {
         });
     }
 
-    fn accept_socket(sessions: Arc<SessionManager>, executor: Arc<Runtime>, socket: TcpStream) {
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        tls: Option<TlsAcceptor>,
+    ) {
         match sessions.create_session("ClickHouseSession") {
-            Err(error) => Self::reject_connection(socket, executor, error),
+            Err(error) => Self::reject_connection(socket, executor, error, tls),
             Ok(session) => {
                 log::info!("ClickHouse connection coming: {:?}", socket.peer_addr());
-                if let Err(error) = ClickHouseConnection::run_on_stream(session, socket) {
+                if let Err(error) = ClickHouseConnection::run_on_stream(session, socket, tls) {
                     log::error!("Unexpected error occurred during query: {:?}", error);
                 }
             }
@@ -126,10 +177,12 @@ impl Server for ClickHouseHandler {
                 "ClickHouseHandler already running.",
             )),
             Some(registration) => {
+                let tls = self.tls_acceptor()?;
                 let rejected_rt = Arc::new(Runtime::with_worker_threads(1)?);
                 let (stream, listener) = Self::listener_tcp(listening).await?;
                 let stream = Abortable::new(stream, registration);
-                self.join_handle = Some(tokio::spawn(self.listen_loop(stream, rejected_rt)));
+                let listen_loop = self.listen_loop(stream, rejected_rt, tls);
+                self.join_handle = Some(tokio::spawn(listen_loop));
                 Ok(listener)
             }
         }
@@ -99,6 +99,9 @@ impl ClickHouseSession for InteractiveWorker {
     fn authenticate(&self, user: &str, password: &[u8]) -> bool {
         let user_mgr = self.session.get_user_manager();
         if let Ok(res) = user_mgr.auth_user(user, password) {
+            if res {
+                self.session.set_current_user(user.to_string());
+            }
             return res;
         }
         false
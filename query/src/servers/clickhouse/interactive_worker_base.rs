@@ -29,6 +29,8 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::InsertIntoPlan;
 use common_planners::PlanNode;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
 use futures::channel::mpsc;
 use futures::channel::mpsc::Receiver;
 use futures::SinkExt;
@@ -42,6 +44,7 @@ use crate::interpreters::InterpreterFactory;
 use crate::sessions::DatabendQueryContextRef;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
+use crate::users::mask_passwords;
 
 pub struct InteractiveWorkerBase;
 
@@ -57,12 +60,31 @@ impl InteractiveWorkerBase {
         ch_ctx: &mut CHContext,
         session: SessionRef,
     ) -> Result<Receiver<BlockItem>> {
-        let query = &ch_ctx.state.query;
-        log::debug!("{}", query);
+        let query = ch_ctx.state.query.clone();
+        log::debug!("{}", mask_passwords(&query));
 
         let ctx = session.create_context().await?;
-        ctx.attach_query_str(query);
+        ctx.attach_query_str(&query);
+
+        // The root span of the trace, the remote stages and storage calls are chained to it.
+        let query_span = tracing::info_span!("query", query_id = ctx.get_id().as_str());
+        match Self::do_query_with_context(&query, ch_ctx, ctx.clone())
+            .instrument(query_span)
+            .await
+        {
+            Ok(receiver) => Ok(receiver),
+            Err(cause) => {
+                ctx.attach_query_error(&cause);
+                Err(cause)
+            }
+        }
+    }
 
+    async fn do_query_with_context(
+        query: &str,
+        ch_ctx: &mut CHContext,
+        ctx: DatabendQueryContextRef,
+    ) -> Result<Receiver<BlockItem>> {
         let plan = PlanParser::create(ctx.clone()).build_from_sql(query)?;
 
         match plan {
@@ -86,6 +108,7 @@ impl InteractiveWorkerBase {
                 let cancel_clone = cancel.clone();
 
                 let progress_ctx = ctx.clone();
+                let result_ctx = ctx.clone();
                 tokio::spawn(async move {
                     while !cancel.load(Ordering::Relaxed) {
                         let _ = interval_stream.next().await;
@@ -96,6 +119,10 @@ impl InteractiveWorkerBase {
 
                 ctx.execute_task(async move {
                     while let Some(block) = data_stream.next().await {
+                        match &block {
+                            Ok(block) => result_ctx.add_result_block(block),
+                            Err(cause) => result_ctx.attach_query_error(cause),
+                        }
                         tx2.send(BlockItem::Block(block)).await.ok();
                     }
 
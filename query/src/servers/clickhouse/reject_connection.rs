@@ -14,8 +14,8 @@
 
 use std::sync::Arc;
 
-use common_base::tokio::net::TcpStream;
 use common_clickhouse_srv::connection::Connection;
+use common_clickhouse_srv::connection::ConnectionStream;
 use common_clickhouse_srv::error_codes::NO_FREE_CONNECTION;
 use common_clickhouse_srv::errors::Error;
 use common_clickhouse_srv::errors::Result as CHResult;
@@ -30,11 +30,11 @@ use common_exception::Result;
 pub struct RejectCHConnection;
 
 impl RejectCHConnection {
-    pub async fn reject(stream: TcpStream, error: ErrorCode) -> Result<()> {
+    pub async fn reject<S: ConnectionStream + 'static>(stream: S, error: ErrorCode) -> Result<()> {
         let mut ctx = CHContext::new(QueryState::default());
 
         let dummy_session = DummyCHSession::create();
-        match Connection::new(stream, dummy_session, String::from("UTC")) {
+        match Connection::new(Box::new(stream), dummy_session, String::from("UTC")) {
             Err(_) => Err(ErrorCode::LogicalError("Cannot create connection")),
             Ok(mut connection) => {
                 if let Ok(Some(Packet::Hello(_))) = connection.read_packet(&mut ctx).await {
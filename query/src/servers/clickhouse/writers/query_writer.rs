@@ -23,7 +23,10 @@ use common_clickhouse_srv::errors::Error as CHError;
 use common_clickhouse_srv::errors::Result as CHResult;
 use common_clickhouse_srv::errors::ServerError;
 use common_clickhouse_srv::types::Block;
+use common_clickhouse_srv::types::DateTime64Column;
 use common_clickhouse_srv::types::DateTimeType;
+use common_clickhouse_srv::types::Decimal;
+use common_clickhouse_srv::types::DecimalColumn;
 use common_clickhouse_srv::types::SqlType;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -203,10 +206,22 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
 
                     result.column(name, c)
                 }
+                DataType::DateTime64(precision, tz) => result.column(name, DateTime64Column {
+                    precision: *precision as u32,
+                    tz: parse_timezone(tz.as_deref().unwrap_or("UTC"))?,
+                    values: column.i64()?.collect_values(),
+                }),
                 DataType::UInt64 => result.column(name, column.u64()?.collect_values()),
                 DataType::Float32 => result.column(name, column.f32()?.collect_values()),
                 DataType::Float64 => result.column(name, column.f64()?.collect_values()),
-                DataType::String => result.column(name, column.string()?.collect_values()),
+                DataType::Decimal(precision, scale) => result.column(name, DecimalColumn {
+                    precision: *precision,
+                    scale: *scale,
+                    values: column.i64()?.collect_values(),
+                }),
+                DataType::String | DataType::Variant => {
+                    result.column(name, column.string()?.collect_values())
+                }
                 DataType::Boolean => {
                     let v: Vec<Option<u8>> = column
                         .bool()?
@@ -279,6 +294,12 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
                     result.column(name, c)
                 }
 
+                DataType::DateTime64(precision, tz) => result.column(name, DateTime64Column {
+                    precision: *precision as u32,
+                    tz: parse_timezone(tz.as_deref().unwrap_or("UTC"))?,
+                    values: column.i64()?.inner().values().as_slice().to_vec(),
+                }),
+
                 DataType::UInt64 => {
                     result.column(name, column.u64()?.inner().values().as_slice().to_vec())
                 }
@@ -288,7 +309,12 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
                 DataType::Float64 => {
                     result.column(name, column.f64()?.inner().values().as_slice().to_vec())
                 }
-                DataType::String => {
+                DataType::Decimal(precision, scale) => result.column(name, DecimalColumn {
+                    precision: *precision,
+                    scale: *scale,
+                    values: column.i64()?.inner().values().as_slice().to_vec(),
+                }),
+                DataType::String | DataType::Variant => {
                     let vs: Vec<&[u8]> = column.string()?.into_no_null_iter().collect();
                     result.column(name, vs)
                 }
@@ -316,9 +342,58 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
 }
 
 pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<DataBlock> {
+    // Decimals are rescaled to the scale of the target column.
+    let get_decimal_series =
+        |index: usize, from_scale: u8, values: Vec<Option<Decimal>>| -> Result<Series> {
+            let array: DFInt64Array = values
+                .iter()
+                .map(|v| v.as_ref().map(|v| v.internal::<i64>()))
+                .collect();
+            match schema.field(index).data_type() {
+                DataType::Decimal(precision, scale) => {
+                    Ok(decimal_rescale(&array, from_scale, *precision, *scale)?.into_series())
+                }
+                _ => Ok(array.into_series()),
+            }
+        };
+
+    // DateTime64 are converted to the ticks of the target column.
+    let get_datetime64_series = |index: usize, values: Vec<Option<DateTime<Tz>>>| -> Series {
+        match schema.field(index).data_type() {
+            DataType::DateTime64(precision, _) => values
+                .iter()
+                .map(|v| v.map(|v| datetime_to_ticks(&v, *precision)))
+                .collect::<DFInt64Array>()
+                .into_series(),
+            _ => values
+                .iter()
+                .map(|v| v.map(|v| v.timestamp() as u32))
+                .collect::<DFUInt32Array>()
+                .into_series(),
+        }
+    };
+
     let get_series = |block: &Block, index: usize| -> CHResult<Series> {
         let col = &block.columns()[index];
         match col.sql_type() {
+            SqlType::DateTime(DateTimeType::DateTime64(_, _)) => {
+                let values = col.iter::<DateTime<Tz>>()?.map(Some).collect();
+                Ok(get_datetime64_series(index, values))
+            }
+            SqlType::Nullable(SqlType::DateTime(DateTimeType::DateTime64(_, _))) => {
+                let values = col.iter::<Option<DateTime<Tz>>>()?.collect();
+                Ok(get_datetime64_series(index, values))
+            }
+            SqlType::Decimal(_, scale) => {
+                let values = col.iter::<Decimal>()?.map(Some).collect();
+                get_decimal_series(index, scale, values)
+                    .map_err(|e| CHError::Other(Cow::from(e.message())))
+            }
+            SqlType::Nullable(SqlType::Decimal(_, scale)) => {
+                let values = col.iter::<Option<Decimal>>()?.collect();
+                get_decimal_series(index, *scale, values)
+                    .map_err(|e| CHError::Other(Cow::from(e.message())))
+            }
             SqlType::UInt8 => {
                 Ok(DFUInt8Array::new_from_iter(col.iter::<u8>()?.copied()).into_series())
             }
@@ -17,6 +17,9 @@
 pub use clickhouse::ClickHouseHandler;
 pub use server::Server;
 pub use server::ShutdownHandle;
+pub use tls::build_tls_server_config;
+pub use tls::load_certs;
+pub use tls::load_keys;
 
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLHandler;
@@ -24,3 +27,4 @@ pub use self::mysql::MySQLHandler;
 mod clickhouse;
 mod mysql;
 pub(crate) mod server;
+mod tls;
@@ -17,8 +17,11 @@ pub use self::mysql_session::MySQLConnection;
 
 #[cfg(test)]
 mod mysql_handler_test;
+#[cfg(test)]
+mod mysql_handshake_test;
 
 mod mysql_handler;
+mod mysql_handshake;
 mod mysql_interactive_worker;
 mod mysql_metrics;
 mod mysql_session;
@@ -27,12 +27,14 @@ use futures::future::AbortRegistration;
 use futures::future::Abortable;
 use futures::StreamExt;
 use msql_srv::*;
+use tokio_rustls::rustls::ServerConfig;
 use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::servers::mysql::mysql_session::MySQLConnection;
 use crate::servers::mysql::reject_connection::RejectConnection;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
+use crate::servers::tls::build_tls_server_config;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionManagerRef;
 
@@ -69,26 +71,53 @@ impl MySQLHandler {
         Ok((TcpListenerStream::new(listener), listener_addr))
     }
 
-    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+    // STARTTLS is offered once the cert and key are configured.
+    fn tls_config(&self) -> Result<Option<Arc<ServerConfig>>> {
+        let conf = self.sessions.get_conf();
+        if !conf.tls_mysql_handler_enabled() {
+            return Ok(None);
+        }
+
+        log::info!("MySQL handler STARTTLS enabled");
+        let tls_config = build_tls_server_config(
+            "mysql handler",
+            &conf.query.mysql_tls_server_cert,
+            &conf.query.mysql_tls_server_key,
+        )?;
+        Ok(Some(tls_config))
+    }
+
+    fn listen_loop(
+        &self,
+        stream: ListeningStream,
+        rt: Arc<Runtime>,
+        tls: Option<Arc<ServerConfig>>,
+    ) -> impl Future<Output = ()> {
         let sessions = self.sessions.clone();
         stream.for_each(move |accept_socket| {
             let executor = rt.clone();
             let sessions = sessions.clone();
+            let tls = tls.clone();
             async move {
                 match accept_socket {
                     Err(error) => log::error!("Broken session connection: {}", error),
-                    Ok(socket) => MySQLHandler::accept_socket(sessions, executor, socket),
+                    Ok(socket) => MySQLHandler::accept_socket(sessions, executor, socket, tls),
                 };
             }
         })
     }
 
-    fn accept_socket(sessions: Arc<SessionManager>, executor: Arc<Runtime>, socket: TcpStream) {
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+    ) {
         match sessions.create_session("MySQL") {
             Err(error) => Self::reject_session(socket, executor, error),
             Ok(session) => {
                 log::info!("MySQL connection coming: {:?}", socket.peer_addr());
-                if let Err(error) = MySQLConnection::run_on_stream(session, socket) {
+                if let Err(error) = MySQLConnection::run_on_stream(session, socket, tls) {
                     log::error!("Unexpected error occurred during query: {:?}", error);
                 };
             }
@@ -133,10 +162,12 @@ impl Server for MySQLHandler {
         match self.abort_registration.take() {
             None => Err(ErrorCode::LogicalError("MySQLHandler already running.")),
             Some(registration) => {
+                let tls = self.tls_config()?;
                 let rejected_rt = Arc::new(Runtime::with_worker_threads(1)?);
                 let (stream, listener) = Self::listener_tcp(listening).await?;
                 let stream = Abortable::new(stream, registration);
-                self.join_handle = Some(tokio::spawn(self.listen_loop(stream, rejected_rt)));
+                let listen_loop = self.listen_loop(stream, rejected_rt, tls);
+                self.join_handle = Some(tokio::spawn(listen_loop));
                 Ok(listener)
             }
         }
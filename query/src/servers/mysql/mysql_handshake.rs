@@ -0,0 +1,379 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_infallible::Mutex;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::ServerSession;
+use tokio_rustls::rustls::StreamOwned;
+
+const HEADER_LEN: usize = 4;
+const SSL_REQUEST_LEN: usize = 32;
+// capability flags(4), max packet size(4), character set(1) and filler(23).
+const HANDSHAKE_RESPONSE_FIXED_LEN: usize = 32;
+
+const CLIENT_CONNECT_WITH_DB: u32 = 0x0000_0008;
+const CLIENT_PROTOCOL_41: u32 = 0x0000_0200;
+const CLIENT_SSL: u32 = 0x0000_0800;
+const CLIENT_SECURE_CONNECTION: u32 = 0x0000_8000;
+const CLIENT_PLUGIN_AUTH: u32 = 0x0008_0000;
+const CLIENT_PLUGIN_AUTH_LENENC_CLIENT_DATA: u32 = 0x0020_0000;
+
+const OK_HEADER: u8 = 0x00;
+const AUTH_MORE_DATA_HEADER: u8 = 0x01;
+const AUTH_SWITCH_HEADER: u8 = 0xFE;
+const ERR_HEADER: u8 = 0xFF;
+const FAST_AUTH_SUCCESS: u8 = 0x03;
+
+const NATIVE_PASSWORD: &str = "mysql_native_password";
+const CACHING_SHA2_PASSWORD: &str = "caching_sha2_password";
+
+#[derive(Clone, Copy)]
+enum Phase {
+    // The server greeting has not been sent, CLIENT_SSL is added into it if TLS is configured.
+    Greeting,
+    // The connection phase after the greeting, until the server sends OK or ERR.
+    Auth,
+    // The handshake completed, the packets pass through.
+    Command,
+}
+
+/// The connection phase of the MySQL wire protocol underneath msql-srv, which only speaks
+/// plaintext and checks the scramble of `caching_sha2_password` without sending the result.
+///
+/// STARTTLS: if TLS is configured, the greeting advertises CLIENT_SSL. When the client
+/// answers with an SSLRequest, the request is swallowed, the TLS handshake runs on the socket
+/// and the sequence ids of the rest of the connection phase are shifted by one, so msql-srv
+/// sees a plain handshake.
+///
+/// caching_sha2_password: a client which authenticates by the SHA256 scramble expects the
+/// fast authentication result `0x01 0x03` before the OK packet, it's inserted when msql-srv
+/// accepts the scramble, and the sequence id of the OK packet is shifted by one.
+pub struct HandshakeStream {
+    state: Arc<Mutex<HandshakeState>>,
+    secure: Arc<AtomicBool>,
+}
+
+impl HandshakeStream {
+    pub fn create(stream: TcpStream, tls_config: Option<Arc<ServerConfig>>) -> (Self, Self) {
+        let secure = Arc::new(AtomicBool::new(false));
+        let state = Arc::new(Mutex::new(HandshakeState {
+            phase: Phase::Greeting,
+            tcp: stream,
+            tls: None,
+            tls_config,
+            secure: secure.clone(),
+            client_packets: 0,
+            client_seq_shift: 0,
+            server_seq_shift: 0,
+            auth_plugin: NATIVE_PASSWORD.to_string(),
+            empty_auth_data: true,
+            read_buf: vec![],
+            read_pos: 0,
+            write_buf: vec![],
+        }));
+
+        (
+            HandshakeStream {
+                state: state.clone(),
+                secure: secure.clone(),
+            },
+            HandshakeStream { state, secure },
+        )
+    }
+
+    /// Whether TLS is established, set once the client upgrades the connection.
+    pub fn secure(&self) -> Arc<AtomicBool> {
+        self.secure.clone()
+    }
+}
+
+impl Read for HandshakeStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.state.lock().read(buf)
+    }
+}
+
+impl Write for HandshakeStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.state.lock().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.state.lock().flush()
+    }
+}
+
+struct HandshakeState {
+    phase: Phase,
+    tcp: TcpStream,
+    tls: Option<StreamOwned<ServerSession, TcpStream>>,
+    tls_config: Option<Arc<ServerConfig>>,
+    secure: Arc<AtomicBool>,
+    // The client packets of the connection phase, the swallowed SSLRequest excluded.
+    client_packets: usize,
+    // The sequence ids the client is ahead of msql-srv, for the swallowed SSLRequest.
+    client_seq_shift: u8,
+    // The sequence ids the client is ahead of msql-srv, also for the inserted packets.
+    server_seq_shift: u8,
+    // The auth plugin of the last auth data sent by the client.
+    auth_plugin: String,
+    empty_auth_data: bool,
+    // The rewritten client packet not read by msql-srv yet.
+    read_buf: Vec<u8>,
+    read_pos: usize,
+    // The incomplete server packet while the packets need rewriting.
+    write_buf: Vec<u8>,
+}
+
+impl HandshakeState {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.read_pos == self.read_buf.len() {
+            match self.phase {
+                Phase::Auth => self.fill_read_buf()?,
+                Phase::Greeting | Phase::Command => return self.transport().read(buf),
+            }
+        }
+
+        let remaining = &self.read_buf[self.read_pos..];
+        let size = std::cmp::min(buf.len(), remaining.len());
+        buf[..size].copy_from_slice(&remaining[..size]);
+        self.read_pos += size;
+        Ok(size)
+    }
+
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self.phase {
+            Phase::Greeting | Phase::Auth => {
+                self.write_buf.extend_from_slice(buf);
+                self.write_packets()?;
+                Ok(buf.len())
+            }
+            Phase::Command => self.transport().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.transport().flush()
+    }
+
+    fn transport(&mut self) -> &mut dyn ReadWrite {
+        match &mut self.tls {
+            Some(tls) => tls,
+            None => &mut self.tcp,
+        }
+    }
+
+    fn read_packet(&mut self) -> std::io::Result<Vec<u8>> {
+        let mut packet = vec![0; HEADER_LEN];
+        self.transport().read_exact(&mut packet)?;
+        packet.resize(HEADER_LEN + payload_len(&packet), 0);
+        self.transport().read_exact(&mut packet[HEADER_LEN..])?;
+        Ok(packet)
+    }
+
+    fn fill_read_buf(&mut self) -> std::io::Result<()> {
+        let mut packet = self.read_packet()?;
+        if self.client_packets == 0 && is_ssl_request(&packet[HEADER_LEN..]) {
+            if let Some(tls_config) = self.tls_config.clone() {
+                let session = ServerSession::new(&tls_config);
+                self.tls = Some(StreamOwned::new(session, self.tcp.try_clone()?));
+                self.secure.store(true, Ordering::Relaxed);
+                self.client_seq_shift = 1;
+                self.server_seq_shift = 1;
+                packet = self.read_packet()?;
+            }
+        }
+
+        let payload = &packet[HEADER_LEN..];
+        match self.client_packets {
+            0 => {
+                let (auth_plugin, auth_data) = parse_handshake_response(payload);
+                self.auth_plugin = auth_plugin;
+                self.empty_auth_data = auth_data.map_or(true, is_empty_auth_data);
+            }
+            // The auth data for the plugin the server switched to.
+            _ => self.empty_auth_data = is_empty_auth_data(payload),
+        }
+        self.client_packets += 1;
+
+        packet[3] = packet[3].wrapping_sub(self.client_seq_shift);
+        self.read_buf = packet;
+        self.read_pos = 0;
+        Ok(())
+    }
+
+    fn write_packets(&mut self) -> std::io::Result<()> {
+        while self.write_buf.len() >= HEADER_LEN {
+            if let Phase::Command = self.phase {
+                let remaining = std::mem::take(&mut self.write_buf);
+                return self.transport().write_all(&remaining);
+            }
+
+            let packet_len = HEADER_LEN + payload_len(&self.write_buf);
+            if self.write_buf.len() < packet_len {
+                break;
+            }
+
+            let mut packet = self.write_buf.drain(..packet_len).collect::<Vec<_>>();
+            match self.phase {
+                Phase::Greeting => {
+                    if self.tls_config.is_some() {
+                        advertise_ssl(&mut packet[HEADER_LEN..]);
+                    }
+                    self.phase = Phase::Auth;
+                }
+                Phase::Auth => self.rewrite_auth_packet(&mut packet)?,
+                Phase::Command => {}
+            }
+            self.transport().write_all(&packet)?;
+        }
+
+        Ok(())
+    }
+
+    fn rewrite_auth_packet(&mut self, packet: &mut [u8]) -> std::io::Result<()> {
+        match packet.get(HEADER_LEN) {
+            Some(&AUTH_SWITCH_HEADER) => {
+                self.auth_plugin = null_terminated(&packet[HEADER_LEN + 1..]);
+            }
+            // msql-srv sends the fast authentication result itself.
+            Some(&AUTH_MORE_DATA_HEADER) => self.auth_plugin = String::new(),
+            Some(&OK_HEADER) => {
+                // The clients skip the result of an empty password, like MySQL does.
+                if self.auth_plugin == CACHING_SHA2_PASSWORD && !self.empty_auth_data {
+                    let seq = packet[3].wrapping_add(self.server_seq_shift);
+                    let fast_auth = [2, 0, 0, seq, AUTH_MORE_DATA_HEADER, FAST_AUTH_SUCCESS];
+                    self.transport().write_all(&fast_auth)?;
+                    self.server_seq_shift += 1;
+                }
+                self.phase = Phase::Command;
+            }
+            Some(&ERR_HEADER) => self.phase = Phase::Command,
+            _ => {}
+        }
+
+        packet[3] = packet[3].wrapping_add(self.server_seq_shift);
+        Ok(())
+    }
+}
+
+trait ReadWrite: Read + Write {}
+
+impl<T: Read + Write> ReadWrite for T {}
+
+fn payload_len(header: &[u8]) -> usize {
+    header[0] as usize | (header[1] as usize) << 8 | (header[2] as usize) << 16
+}
+
+fn is_ssl_request(payload: &[u8]) -> bool {
+    payload.len() == SSL_REQUEST_LEN
+        && u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]) & CLIENT_SSL != 0
+}
+
+// libmysqlclient sends a single NUL for an empty password.
+fn is_empty_auth_data(auth_data: &[u8]) -> bool {
+    auth_data.is_empty() || auth_data == [0]
+}
+
+fn null_terminated(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).to_string()
+}
+
+// The length encoded integer at the start of the bytes, and the size of it.
+fn length_encoded_int(bytes: &[u8]) -> Option<(usize, usize)> {
+    let size = match *bytes.first()? {
+        0xFC => 3,
+        0xFD => 4,
+        0xFE => 9,
+        first => return Some((first as usize, 1)),
+    };
+    let mut value = 0;
+    for (index, byte) in bytes.get(1..size)?.iter().enumerate() {
+        value |= (*byte as usize) << (8 * index);
+    }
+    Some((value, size))
+}
+
+/// The auth plugin and the auth data of a HandshakeResponse41: capability flags, max packet
+/// size, character set, filler, the NUL terminated user name, the auth data, the NUL
+/// terminated database if CLIENT_CONNECT_WITH_DB and the NUL terminated plugin name if
+/// CLIENT_PLUGIN_AUTH, the clients without it authenticate by mysql_native_password.
+pub fn parse_handshake_response(payload: &[u8]) -> (String, Option<&[u8]>) {
+    let native = || (NATIVE_PASSWORD.to_string(), None);
+    if payload.len() < HANDSHAKE_RESPONSE_FIXED_LEN {
+        return native();
+    }
+    let capability = u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]);
+    if capability & CLIENT_PROTOCOL_41 == 0 {
+        return native();
+    }
+
+    let rest = &payload[HANDSHAKE_RESPONSE_FIXED_LEN..];
+    let user_end = match rest.iter().position(|b| *b == 0) {
+        Some(user_end) => user_end,
+        None => return native(),
+    };
+    let rest = &rest[user_end + 1..];
+    let (auth_data, rest) = if capability & CLIENT_PLUGIN_AUTH_LENENC_CLIENT_DATA != 0 {
+        match length_encoded_int(rest) {
+            Some((len, size)) if rest.len() >= size + len => {
+                (&rest[size..size + len], &rest[size + len..])
+            }
+            _ => return native(),
+        }
+    } else if capability & CLIENT_SECURE_CONNECTION != 0 {
+        match rest.first() {
+            Some(len) if rest.len() > *len as usize => {
+                let len = *len as usize;
+                (&rest[1..1 + len], &rest[1 + len..])
+            }
+            _ => return native(),
+        }
+    } else {
+        let end = rest.iter().position(|b| *b == 0).unwrap_or(rest.len());
+        (&rest[..end], rest.get(end + 1..).unwrap_or_default())
+    };
+
+    let rest = match capability & CLIENT_CONNECT_WITH_DB {
+        0 => rest,
+        _ => match rest.iter().position(|b| *b == 0) {
+            Some(end) => &rest[end + 1..],
+            None => rest,
+        },
+    };
+    let auth_plugin = match capability & CLIENT_PLUGIN_AUTH {
+        0 => NATIVE_PASSWORD.to_string(),
+        _ => null_terminated(rest),
+    };
+    (auth_plugin, Some(auth_data))
+}
+
+// Handshake v10: protocol version, NUL terminated server version, connection id(4),
+// auth plugin data part 1(8), filler(1), then the lower two bytes of the capability flags.
+fn advertise_ssl(greeting: &mut [u8]) {
+    if let Some(version_len) = greeting.iter().skip(1).position(|b| *b == 0) {
+        let capability = 1 + version_len + 1 + 4 + 8 + 1;
+        if greeting.len() > capability + 1 {
+            greeting[capability + 1] |= (CLIENT_SSL >> 8) as u8;
+        }
+    }
+}
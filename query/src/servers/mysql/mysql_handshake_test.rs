@@ -0,0 +1,299 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::Result;
+use tokio_rustls::rustls::ClientConfig;
+use tokio_rustls::rustls::ClientSession;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::StreamOwned;
+use tokio_rustls::webpki::DNSNameRef;
+
+use crate::servers::mysql::mysql_handshake::parse_handshake_response;
+use crate::servers::mysql::mysql_handshake::HandshakeStream;
+use crate::servers::tls::build_tls_server_config;
+use crate::tests::tls_constants::TEST_CA_CERT;
+use crate::tests::tls_constants::TEST_CN_NAME;
+use crate::tests::tls_constants::TEST_SERVER_CERT;
+use crate::tests::tls_constants::TEST_SERVER_KEY;
+
+const CLIENT_CAPABILITY: u32 = 0x0000_0200 | 0x0000_8000 | 0x0008_0000 | 0x0020_0000;
+const CLIENT_SSL: u32 = 0x0000_0800;
+
+#[test]
+fn test_caching_sha2_fast_auth() -> Result<()> {
+    let (mut reader, mut writer, mut client) = connect(None)?;
+    send_greeting(&mut writer, &mut client)?;
+
+    let scramble = [7u8; 32];
+    let response = handshake_response(1, "caching_sha2_password", &scramble);
+    client.write_all(&response)?;
+    assert_eq!(read_packet(&mut reader)?, response);
+
+    writer.write_all(&ok_packet(2))?;
+    writer.flush()?;
+    assert_eq!(read_packet(&mut client)?, packet(2, &[0x01, 0x03]));
+    assert_eq!(read_packet(&mut client)?, ok_packet(3));
+
+    assert_command_pass_through(&mut reader, &mut writer, &mut client)?;
+    assert!(!reader.secure().load(Ordering::Relaxed));
+    Ok(())
+}
+
+#[test]
+fn test_caching_sha2_empty_password() -> Result<()> {
+    let (mut reader, mut writer, mut client) = connect(None)?;
+    send_greeting(&mut writer, &mut client)?;
+
+    let response = handshake_response(1, "caching_sha2_password", &[]);
+    client.write_all(&response)?;
+    assert_eq!(read_packet(&mut reader)?, response);
+
+    // The clients don't expect the fast authentication result for an empty password.
+    writer.write_all(&ok_packet(2))?;
+    writer.flush()?;
+    assert_eq!(read_packet(&mut client)?, ok_packet(2));
+
+    assert_command_pass_through(&mut reader, &mut writer, &mut client)
+}
+
+#[test]
+fn test_native_password() -> Result<()> {
+    let (mut reader, mut writer, mut client) = connect(None)?;
+    send_greeting(&mut writer, &mut client)?;
+
+    let response = handshake_response(1, "mysql_native_password", &[7u8; 20]);
+    client.write_all(&response)?;
+    assert_eq!(read_packet(&mut reader)?, response);
+
+    writer.write_all(&ok_packet(2))?;
+    writer.flush()?;
+    assert_eq!(read_packet(&mut client)?, ok_packet(2));
+
+    assert_command_pass_through(&mut reader, &mut writer, &mut client)
+}
+
+#[test]
+fn test_auth_switch_to_caching_sha2() -> Result<()> {
+    let (mut reader, mut writer, mut client) = connect(None)?;
+    send_greeting(&mut writer, &mut client)?;
+
+    let response = handshake_response(1, "mysql_native_password", &[7u8; 20]);
+    client.write_all(&response)?;
+    assert_eq!(read_packet(&mut reader)?, response);
+
+    let mut switch_request = vec![0xFE];
+    switch_request.extend_from_slice(b"caching_sha2_password\0");
+    switch_request.extend_from_slice(&[9u8; 20]);
+    switch_request.push(0);
+    writer.write_all(&packet(2, &switch_request))?;
+    writer.flush()?;
+    assert_eq!(read_packet(&mut client)?, packet(2, &switch_request));
+
+    client.write_all(&packet(3, &[7u8; 32]))?;
+    assert_eq!(read_packet(&mut reader)?, packet(3, &[7u8; 32]));
+
+    writer.write_all(&ok_packet(4))?;
+    writer.flush()?;
+    assert_eq!(read_packet(&mut client)?, packet(4, &[0x01, 0x03]));
+    assert_eq!(read_packet(&mut client)?, ok_packet(5));
+
+    assert_command_pass_through(&mut reader, &mut writer, &mut client)
+}
+
+#[test]
+fn test_authenticate_failure() -> Result<()> {
+    let (mut reader, mut writer, mut client) = connect(None)?;
+    send_greeting(&mut writer, &mut client)?;
+
+    let response = handshake_response(1, "caching_sha2_password", &[7u8; 32]);
+    client.write_all(&response)?;
+    assert_eq!(read_packet(&mut reader)?, response);
+
+    let error = packet(2, b"\xFF\x15\x04#28000Access denied");
+    writer.write_all(&error)?;
+    writer.flush()?;
+    assert_eq!(read_packet(&mut client)?, error);
+    Ok(())
+}
+
+#[test]
+fn test_starttls_caching_sha2_fast_auth() -> Result<()> {
+    let tls_config = build_tls_server_config("mysql", TEST_SERVER_CERT, TEST_SERVER_KEY)?;
+    let (mut reader, mut writer, mut client) = connect(Some(tls_config))?;
+
+    let server = std::thread::spawn(move || -> Result<bool> {
+        writer.write_all(&greeting())?;
+        writer.flush()?;
+        // msql-srv sees a plain handshake, the SSLRequest is swallowed.
+        let response = read_packet(&mut reader)?;
+        assert_eq!(response[3], 1);
+        writer.write_all(&ok_packet(2))?;
+        writer.flush()?;
+
+        assert_eq!(read_packet(&mut reader)?, packet(0, b"\x03SELECT 1"));
+        Ok(reader.secure().load(Ordering::Relaxed))
+    });
+
+    let greeting = read_packet(&mut client)?;
+    let capability = u16::from_le_bytes([greeting[4 + 21], greeting[4 + 22]]) as u32;
+    assert_ne!(capability & CLIENT_SSL, 0);
+
+    let mut ssl_request = vec![];
+    ssl_request.extend_from_slice(&(CLIENT_CAPABILITY | CLIENT_SSL).to_le_bytes());
+    ssl_request.extend_from_slice(&[0u8; 28]);
+    client.write_all(&packet(1, &ssl_request))?;
+
+    let mut client_config = ClientConfig::new();
+    let mut ca_cert = BufReader::new(File::open(TEST_CA_CERT)?);
+    assert!(client_config.root_store.add_pem_file(&mut ca_cert).is_ok());
+    let dns_name = DNSNameRef::try_from_ascii_str(TEST_CN_NAME).unwrap();
+    let session = ClientSession::new(&Arc::new(client_config), dns_name);
+    let mut client = StreamOwned::new(session, client);
+
+    client.write_all(&handshake_response(2, "caching_sha2_password", &[7u8; 32]))?;
+    client.flush()?;
+    assert_eq!(read_packet(&mut client)?, packet(3, &[0x01, 0x03]));
+    assert_eq!(read_packet(&mut client)?, ok_packet(4));
+
+    client.write_all(&packet(0, b"\x03SELECT 1"))?;
+    client.flush()?;
+    assert!(server.join().unwrap()?);
+    Ok(())
+}
+
+#[test]
+fn test_parse_handshake_response() -> Result<()> {
+    let response = handshake_response(1, "caching_sha2_password", &[7u8; 32]);
+    let (auth_plugin, auth_data) = parse_handshake_response(&response[4..]);
+    assert_eq!(auth_plugin, "caching_sha2_password");
+    assert_eq!(auth_data, Some(&[7u8; 32][..]));
+
+    // CLIENT_SECURE_CONNECTION without CLIENT_PLUGIN_AUTH_LENENC_CLIENT_DATA, with a database.
+    let mut payload = vec![];
+    payload.extend_from_slice(&(0x0000_0200u32 | 0x0000_8000 | 0x0008_0000 | 0x08).to_le_bytes());
+    payload.extend_from_slice(&[0u8; 28]);
+    payload.extend_from_slice(b"user\0");
+    payload.push(20);
+    payload.extend_from_slice(&[7u8; 20]);
+    payload.extend_from_slice(b"default\0mysql_native_password\0");
+    let (auth_plugin, auth_data) = parse_handshake_response(&payload);
+    assert_eq!(auth_plugin, "mysql_native_password");
+    assert_eq!(auth_data, Some(&[7u8; 20][..]));
+
+    // Without CLIENT_PLUGIN_AUTH the client authenticates by mysql_native_password.
+    let mut payload = vec![];
+    payload.extend_from_slice(&(0x0000_0200u32 | 0x0000_8000).to_le_bytes());
+    payload.extend_from_slice(&[0u8; 28]);
+    payload.extend_from_slice(b"user\0");
+    payload.push(0);
+    let (auth_plugin, auth_data) = parse_handshake_response(&payload);
+    assert_eq!(auth_plugin, "mysql_native_password");
+    assert_eq!(auth_data, Some(&[][..]));
+
+    // The truncated packet.
+    let (auth_plugin, auth_data) = parse_handshake_response(&payload[..10]);
+    assert_eq!(auth_plugin, "mysql_native_password");
+    assert_eq!(auth_data, None);
+    Ok(())
+}
+
+fn connect(
+    tls_config: Option<Arc<ServerConfig>>,
+) -> Result<(HandshakeStream, HandshakeStream, TcpStream)> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let client = TcpStream::connect(listener.local_addr()?)?;
+    let (server, _) = listener.accept()?;
+    let (reader, writer) = HandshakeStream::create(server, tls_config);
+    Ok((reader, writer, client))
+}
+
+// The greeting is passed through if TLS isn't configured.
+fn send_greeting(writer: &mut HandshakeStream, client: &mut TcpStream) -> Result<()> {
+    let greeting = greeting();
+    writer.write_all(&greeting)?;
+    writer.flush()?;
+    assert_eq!(read_packet(client)?, greeting);
+    Ok(())
+}
+
+fn assert_command_pass_through(
+    reader: &mut HandshakeStream,
+    writer: &mut HandshakeStream,
+    client: &mut TcpStream,
+) -> Result<()> {
+    client.write_all(&packet(0, b"\x03SELECT 1"))?;
+    assert_eq!(read_packet(reader)?, packet(0, b"\x03SELECT 1"));
+
+    writer.write_all(&ok_packet(1))?;
+    writer.flush()?;
+    assert_eq!(read_packet(client)?, ok_packet(1));
+    Ok(())
+}
+
+fn greeting() -> Vec<u8> {
+    let mut payload = vec![10];
+    payload.extend_from_slice(b"8.0.26\0");
+    payload.extend_from_slice(&8u32.to_le_bytes());
+    payload.extend_from_slice(&[9u8; 8]);
+    payload.push(0);
+    // The lower two bytes of the capability flags, without CLIENT_SSL.
+    payload.extend_from_slice(&[0xFF, 0xF7]);
+    payload.extend_from_slice(&[0x21, 0x02, 0x00, 0xFF, 0x81, 21]);
+    payload.extend_from_slice(&[0u8; 10]);
+    payload.extend_from_slice(&[9u8; 12]);
+    payload.push(0);
+    payload.extend_from_slice(b"mysql_native_password\0");
+    packet(0, &payload)
+}
+
+fn handshake_response(seq: u8, auth_plugin: &str, auth_data: &[u8]) -> Vec<u8> {
+    let mut payload = vec![];
+    payload.extend_from_slice(&CLIENT_CAPABILITY.to_le_bytes());
+    payload.extend_from_slice(&[0u8; 28]);
+    payload.extend_from_slice(b"user\0");
+    payload.push(auth_data.len() as u8);
+    payload.extend_from_slice(auth_data);
+    payload.extend_from_slice(auth_plugin.as_bytes());
+    payload.push(0);
+    packet(seq, &payload)
+}
+
+fn ok_packet(seq: u8) -> Vec<u8> {
+    packet(seq, &[0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00])
+}
+
+fn packet(seq: u8, payload: &[u8]) -> Vec<u8> {
+    let len = payload.len() as u32;
+    let mut packet = vec![len as u8, (len >> 8) as u8, (len >> 16) as u8, seq];
+    packet.extend_from_slice(payload);
+    packet
+}
+
+fn read_packet(stream: &mut impl Read) -> Result<Vec<u8>> {
+    let mut packet = vec![0; 4];
+    stream.read_exact(&mut packet)?;
+    let len = packet[0] as usize | (packet[1] as usize) << 8 | (packet[2] as usize) << 16;
+    packet.resize(4 + len, 0);
+    stream.read_exact(&mut packet[4..])?;
+    Ok(packet)
+}
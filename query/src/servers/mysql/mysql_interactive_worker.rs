@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::marker::PhantomData;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::time::Instant;
 
 use common_base::tokio;
@@ -20,7 +23,10 @@ use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::*;
+use common_management::AuthType;
 use common_planners::PlanNode;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
 use metrics::histogram;
 use msql_srv::ErrorKind;
 use msql_srv::InitWriter;
@@ -37,6 +43,8 @@ use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::sessions::DatabendQueryContextRef;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
+use crate::users::mask_passwords;
+use crate::users::AuthCredential;
 
 struct InteractiveWorkerBase<W: std::io::Write> {
     session: SessionRef,
@@ -48,6 +56,8 @@ pub struct InteractiveWorker<W: std::io::Write> {
     base: InteractiveWorkerBase<W>,
     version: String,
     salt: [u8; 20],
+    // Whether the connection is upgraded to TLS, see HandshakeStream.
+    secure: Arc<AtomicBool>,
 }
 
 impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
@@ -65,8 +75,17 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
         "mysql_native_password"
     }
 
-    fn auth_plugin_for_username(&self, _user: &[u8]) -> &str {
-        "mysql_native_password"
+    // Only a SHA1(SHA1(password)) user needs mysql_native_password, the other users, and the
+    // unknown users so that the plugin doesn't tell whether a user exists, authenticate by
+    // the fast path of caching_sha2_password. The full authentication of it is never
+    // requested, SHA256(password) is recovered from the scramble or the password is stored.
+    fn auth_plugin_for_username(&self, user: &[u8]) -> &str {
+        let user_mgr = self.session.get_user_manager();
+        let user_name = String::from_utf8_lossy(user);
+        match user_mgr.get_user(user_name.as_ref()) {
+            Ok(user) if user.auth_type == AuthType::DoubleSha1 => "mysql_native_password",
+            _ => "caching_sha2_password",
+        }
     }
 
     fn salt(&self) -> [u8; 20] {
@@ -82,37 +101,27 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
     ) -> bool {
         let user_mgr = self.session.get_user_manager();
         let user_name = String::from_utf8_lossy(username);
-        if let Ok(user) = user_mgr.get_user(user_name.as_ref()) {
-            let encode_password = match auth_plugin {
-                "mysql_native_password" => {
-                    if auth_data.is_empty() {
-                        vec![]
-                    } else {
-                        // SHA1( password ) XOR SHA1( "20-bytes random data from server" <concat> SHA1( SHA1( password ) ) )
-                        let mut m = sha1::Sha1::new();
-                        m.update(salt);
-                        m.update(&user.password);
-
-                        let result = m.digest().bytes();
-                        if auth_data.len() != result.len() {
-                            return false;
-                        }
-                        let mut s = Vec::with_capacity(result.len());
-                        for i in 0..result.len() {
-                            s.push(auth_data[i] ^ result[i]);
-                        }
-                        s
-                    }
-                }
-                _ => auth_data.to_vec(),
-            };
-
-            if let Ok(res) = user_mgr.auth_user(user_name.as_ref(), encode_password) {
-                return res;
+        let credential = match auth_plugin {
+            "mysql_native_password" => AuthCredential::NativePassword {
+                salt,
+                scramble: auth_data,
+            },
+            "caching_sha2_password" => AuthCredential::CachingSha2Password {
+                salt,
+                scramble: auth_data,
+            },
+            // The password in clear text is only accepted over TLS.
+            _ if self.secure.load(Ordering::Relaxed) => AuthCredential::Password(auth_data),
+            _ => return false,
+        };
+
+        match user_mgr.auth_user(user_name.as_ref(), credential) {
+            Ok(true) => {
+                self.session.set_current_user(user_name.to_string());
+                true
             }
+            _ => false,
         }
-
-        false
     }
 
     fn on_prepare(&mut self, query: &str, writer: StatementMetaWriter<W>) -> Result<()> {
@@ -232,11 +241,22 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     fn do_close(&mut self, _: u32) {}
 
     async fn do_query(&mut self, query: &str) -> Result<(Vec<DataBlock>, String)> {
-        log::debug!("{}", query);
+        log::debug!("{}", mask_passwords(query));
 
         let context = self.session.create_context().await?;
         context.attach_query_str(query);
 
+        // The root span of the trace, the remote stages and storage calls are chained to it.
+        let query_span = tracing::info_span!("query", query_id = context.get_id().as_str());
+        Self::do_query_with_context(query, &context)
+            .instrument(query_span)
+            .await
+    }
+
+    async fn do_query_with_context(
+        query: &str,
+        context: &DatabendQueryContextRef,
+    ) -> Result<(Vec<DataBlock>, String)> {
         let query_parser = PlanParser::create(context.clone());
         let (plan, hints) = query_parser.build_with_hint_from_sql(query);
 
@@ -245,8 +265,8 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
             .find(|v| v.error_code.is_some())
             .and_then(|x| x.error_code)
         {
-            None => Self::exec_query(plan, &context).await,
-            Some(hint_error_code) => match Self::exec_query(plan, &context).await {
+            None => Self::exec_query(plan, context).await,
+            Some(hint_error_code) => match Self::exec_query(plan, context).await {
                 Ok(_) => Err(ErrorCode::UnexpectedError(format!(
                     "Expected server error code: {} but got: Ok.",
                     hint_error_code
@@ -272,6 +292,19 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     ) -> Result<(Vec<DataBlock>, String)> {
         let instant = Instant::now();
 
+        let query_result = Self::exec_query_blocks(plan, context, instant).await;
+        match &query_result {
+            Ok(blocks) => blocks.iter().for_each(|b| context.add_result_block(b)),
+            Err(cause) => context.attach_query_error(cause),
+        }
+        query_result.map(|data| (data, Self::extra_info(context, instant)))
+    }
+
+    async fn exec_query_blocks(
+        plan: Result<PlanNode>,
+        context: &DatabendQueryContextRef,
+        instant: Instant,
+    ) -> Result<Vec<DataBlock>> {
         let interpreter = InterpreterFactory::get(context.clone(), plan?)?;
         let data_stream = interpreter.execute().await?;
         histogram!(
@@ -280,8 +313,7 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         );
 
         let collector = data_stream.collect::<Result<Vec<DataBlock>>>();
-        let query_result = collector.await;
-        query_result.map(|data| (data, Self::extra_info(context, instant)))
+        collector.await
     }
 
     fn extra_info(context: &DatabendQueryContextRef, instant: Instant) -> String {
@@ -319,7 +351,7 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
 }
 
 impl<W: std::io::Write> InteractiveWorker<W> {
-    pub fn create(session: SessionRef) -> InteractiveWorker<W> {
+    pub fn create(session: SessionRef, secure: Arc<AtomicBool>) -> InteractiveWorker<W> {
         let mut bs = vec![0u8; 20];
         let mut rng = rand::thread_rng();
         rng.fill_bytes(bs.as_mut());
@@ -339,6 +371,7 @@ impl<W: std::io::Write> InteractiveWorker<W> {
                 generic_hold: PhantomData::default(),
             },
             salt: scramble,
+            secure,
             // TODO: version
             version: crate::configs::DATABEND_COMMIT_VERSION.to_string(),
         }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::net::Shutdown;
+use std::sync::Arc;
 
 use common_base::tokio::net::TcpStream;
 use common_exception::exception::ABORT_SESSION;
@@ -20,26 +21,39 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use msql_srv::MysqlIntermediary;
+use tokio_rustls::rustls::ServerConfig;
 
+use crate::servers::mysql::mysql_handshake::HandshakeStream;
 use crate::servers::mysql::mysql_interactive_worker::InteractiveWorker;
 use crate::sessions::SessionRef;
 
 pub struct MySQLConnection;
 
 impl MySQLConnection {
-    pub fn run_on_stream(session: SessionRef, stream: TcpStream) -> Result<()> {
+    pub fn run_on_stream(
+        session: SessionRef,
+        stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+    ) -> Result<()> {
         let blocking_stream = Self::convert_stream(stream)?;
         MySQLConnection::attach_session(&session, &blocking_stream)?;
         std::thread::spawn(move || {
-            MySQLConnection::session_executor(session, blocking_stream);
+            MySQLConnection::session_executor(session, blocking_stream, tls);
         });
 
         Ok(())
     }
 
-    fn session_executor(session: SessionRef, blocking_stream: std::net::TcpStream) {
-        let interactive_worker = InteractiveWorker::create(session);
-        if let Err(error) = MysqlIntermediary::run_on_tcp(interactive_worker, blocking_stream) {
+    fn session_executor(
+        session: SessionRef,
+        blocking_stream: std::net::TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+    ) {
+        let (reader, writer) = HandshakeStream::create(blocking_stream, tls);
+        let interactive_worker = InteractiveWorker::create(session, reader.secure());
+        let res = MysqlIntermediary::run_on(interactive_worker, reader, writer);
+
+        if let Err(error) = res {
             if error.code() != ABORT_SESSION {
                 log::error!(
                     "Unexpected error occurred during query execution: {:?}",
@@ -14,6 +14,9 @@
 
 use chrono_tz::Tz;
 use common_datablocks::DataBlock;
+use common_datavalues::arrays::format_decimal;
+use common_datavalues::arrays::parse_timezone;
+use common_datavalues::arrays::ticks_to_datetime;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
@@ -72,10 +75,14 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
                 DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Decimal(_, _) => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
+                DataType::String | DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::List(_) | DataType::Map(_, _) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::DateTime32(_) | DataType::DateTime64(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_DATETIME)
+                }
                 DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
                 _ => Err(ErrorCode::UnImplement(format!(
@@ -108,6 +115,17 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
 
                 for block in &blocks {
                     let rows_size = block.column(0).len();
+                    // arrays and maps are written as their text like `[1,2]`
+                    let mut nested_texts = Vec::with_capacity(columns_size);
+                    for (col_index, field) in block.schema().fields().iter().enumerate() {
+                        nested_texts.push(match field.data_type() {
+                            DataType::List(_) | DataType::Map(_, _) => {
+                                let serializer = field.data_type().create_serializer(0)?;
+                                serializer.serialize_strings(block.column(col_index))?
+                            }
+                            _ => vec![],
+                        });
+                    }
                     for row_index in 0..rows_size {
                         for col_index in 0..columns_size {
                             let val = block.column(col_index).try_get(row_index)?;
@@ -162,9 +180,26 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let tz: Tz = tz.parse().unwrap();
                                     row_writer.write_col(v.to_date_time(&tz).naive_local())?
                                 }
-                                (DataType::String, DataValue::String(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
+                                (
+                                    DataType::DateTime64(precision, tz),
+                                    DataValue::Int64(Some(v)),
+                                ) => {
+                                    let tz = parse_timezone(tz.as_deref().unwrap_or("UTC"))?;
+                                    let dt = ticks_to_datetime(v, *precision, &tz)?;
+                                    row_writer.write_col(dt.naive_local())?
+                                }
+                                (DataType::Decimal(_, scale), DataValue::Int64(Some(v))) => {
+                                    row_writer.write_col(format_decimal(v, *scale))?
+                                }
+                                (
+                                    DataType::String | DataType::Variant,
+                                    DataValue::String(Some(v)),
+                                ) => row_writer.write_col(v)?,
+                                (
+                                    DataType::List(_) | DataType::Map(_, _),
+                                    DataValue::List(Some(_), _),
+                                ) => row_writer
+                                    .write_col(nested_texts[col_index][row_index].as_str())?,
                                 (_, v) => {
                                     return Err(ErrorCode::BadDataValueType(format!(
                                         "Unsupported column type:{:?}",
@@ -0,0 +1,66 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use tokio_rustls::rustls::internal::pemfile::certs;
+use tokio_rustls::rustls::internal::pemfile::pkcs8_private_keys;
+use tokio_rustls::rustls::Certificate;
+use tokio_rustls::rustls::NoClientAuth;
+use tokio_rustls::rustls::PrivateKey;
+use tokio_rustls::rustls::ServerConfig;
+
+/// Build the server side TLS config of a wire protocol handler from its cert and key files.
+pub fn build_tls_server_config(service: &str, cert: &str, key: &str) -> Result<Arc<ServerConfig>> {
+    let certs = load_certs(Path::new(cert))?;
+    let key = match load_keys(Path::new(key))?.into_iter().next() {
+        Some(key) => key,
+        None => {
+            return Err(ErrorCode::TLSConfigurationFailure(format!(
+                "Cannot build TLS config for {}, cause no PKCS8 key found",
+                service
+            )))
+        }
+    };
+
+    let mut tls_config = ServerConfig::new(NoClientAuth::new());
+    if let Err(cause) = tls_config.set_single_cert(certs, key) {
+        return Err(ErrorCode::TLSConfigurationFailure(format!(
+            "Cannot build TLS config for {}, cause {}",
+            service, cause
+        )));
+    }
+
+    Ok(Arc::new(tls_config))
+}
+
+pub fn load_certs(path: &Path) -> Result<Vec<Certificate>> {
+    match certs(&mut BufReader::new(File::open(path)?)) {
+        Ok(certs) => Ok(certs),
+        Err(_) => Err(ErrorCode::TLSConfigurationFailure("invalid cert")),
+    }
+}
+
+// currently only PKCS8 key supports for TLS setup
+pub fn load_keys(path: &Path) -> Result<Vec<PrivateKey>> {
+    match pkcs8_private_keys(&mut BufReader::new(File::open(path)?)) {
+        Ok(keys) => Ok(keys),
+        Err(_) => Err(ErrorCode::TLSConfigurationFailure("invalid key")),
+    }
+}
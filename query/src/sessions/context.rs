@@ -19,8 +19,10 @@ use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
 
 use common_base::tokio::task::JoinHandle;
+use common_base::CancellationToken;
 use common_base::ProgressCallback;
 use common_base::ProgressValues;
+use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
@@ -30,21 +32,29 @@ use common_planners::Part;
 use common_planners::Partitions;
 use common_planners::PlanNode;
 use common_planners::Statistics;
-use common_streams::AbortStream;
+use common_planners::VarValue;
+use common_streams::CancellableStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing::Instrument;
+use rusoto_core::Region;
 
 use crate::catalogs::impls::DatabaseCatalog;
 use crate::catalogs::Catalog;
 use crate::catalogs::TableFunctionMeta;
 use crate::catalogs::TableMeta;
 use crate::clusters::ClusterRef;
+use crate::common::StoreApiProvider;
 use crate::configs::Config;
+use crate::datasources::dal::parse_location;
 use crate::datasources::dal::DataAccessor;
+use crate::datasources::dal::FuseDfs;
 use crate::datasources::dal::Local;
+use crate::datasources::dal::MeteredAccessor;
 use crate::datasources::dal::StorageScheme;
 use crate::datasources::dal::S3;
 use crate::datasources::table_func_engine::TableArgs;
 use crate::sessions::context_shared::DatabendQueryContextShared;
+use crate::sessions::ResourceGroupPermit;
 use crate::sessions::SessionManagerRef;
 use crate::sessions::Settings;
 
@@ -79,13 +89,14 @@ impl DatabendQueryContext {
     }
 
     /// Spawns a new asynchronous task, returning a tokio::JoinHandle for it.
-    /// The task will run in the current context thread_pool not the global.
+    /// The task will run in the current context thread_pool not the global,
+    /// and inside the current tracing span so that it stays in the trace of the query.
     pub fn execute_task<T>(&self, task: T) -> Result<JoinHandle<T::Output>>
     where
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
-        Ok(self.shared.try_get_runtime()?.spawn(task))
+        Ok(self.shared.try_get_runtime()?.spawn(task.in_current_span()))
     }
 
     /// Set progress callback to context.
@@ -93,8 +104,10 @@ impl DatabendQueryContext {
     /// Note that the callback can be called from different threads.
     pub fn progress_callback(&self) -> Result<ProgressCallback> {
         let current_progress = self.shared.progress.clone();
+        let query_progress = self.shared.query_progress.clone();
         Ok(Box::new(move |value: &ProgressValues| {
             current_progress.incr(value);
+            query_progress.incr(value);
         }))
     }
 
@@ -182,16 +195,36 @@ impl DatabendQueryContext {
         self.shared.init_query_id.as_ref().read().clone()
     }
 
-    pub fn try_create_abortable(&self, input: SendableDataBlockStream) -> Result<AbortStream> {
-        let (abort_handle, abort_stream) = AbortStream::try_create(input)?;
-        self.shared.add_source_abort_handle(abort_handle);
-        Ok(abort_stream)
+    /// Wrap the stream to stop with the cancelled reason when the query is cancelled.
+    pub fn try_create_cancellable(
+        &self,
+        input: SendableDataBlockStream,
+    ) -> Result<CancellableStream> {
+        CancellableStream::try_create(input, self.shared.get_cancellation_token())
+    }
+
+    pub fn get_cancellation_token(&self) -> CancellationToken {
+        self.shared.get_cancellation_token()
+    }
+
+    /// Returns the cancelled reason as the error if the query is killed or timed out.
+    pub fn check_cancelled(&self) -> Result<()> {
+        self.shared.get_cancellation_token().check()
+    }
+
+    pub fn start_execution_timer(&self) -> Result<()> {
+        self.shared.start_execution_timer()
     }
 
     pub fn get_current_database(&self) -> String {
         self.shared.get_current_database()
     }
 
+    /// The authenticated user of the session, None for the internal queries.
+    pub fn get_current_user(&self) -> Option<String> {
+        self.shared.get_current_user()
+    }
+
     pub fn set_current_database(&self, new_database_name: String) -> Result<()> {
         match self.get_catalog().get_database(new_database_name.as_str()) {
             Ok(_) => self.shared.set_current_database(new_database_name),
@@ -214,6 +247,10 @@ impl DatabendQueryContext {
         self.shared.get_settings()
     }
 
+    pub fn apply_query_settings(&self, vars: &[VarValue]) -> Result<()> {
+        self.shared.apply_query_settings(vars)
+    }
+
     pub fn get_config(&self) -> Config {
         self.shared.conf.clone()
     }
@@ -231,6 +268,23 @@ impl DatabendQueryContext {
         self.shared.attach_query_plan(query_plan);
     }
 
+    /// Records a block of the query result, for the query log.
+    pub fn add_result_block(&self, block: &DataBlock) {
+        self.shared.add_result_rows(block.num_rows());
+    }
+
+    pub fn attach_quota_user(&self, user: &str) {
+        self.shared.attach_quota_user(user)
+    }
+
+    pub fn attach_resource_group_permit(&self, permit: ResourceGroupPermit) {
+        self.shared.attach_resource_group_permit(permit)
+    }
+
+    pub fn attach_query_error(&self, error: &ErrorCode) {
+        self.shared.attach_query_error(error);
+    }
+
     pub fn get_sessions_manager(self: &Arc<Self>) -> SessionManagerRef {
         self.shared.session.get_sessions_manager()
     }
@@ -239,10 +293,55 @@ impl DatabendQueryContext {
         &self,
         storage_scheme: &StorageScheme,
     ) -> Result<Arc<dyn DataAccessor>> {
-        match storage_scheme {
-            StorageScheme::S3 => Ok(Arc::new(S3::fake_new())),
-            StorageScheme::LocalFs => Ok(Arc::new(Local::new("/tmp"))),
-            _ => todo!(),
+        let accessor: Arc<dyn DataAccessor> = match storage_scheme {
+            StorageScheme::S3 => Arc::new(S3::fake_new()),
+            StorageScheme::LocalFs => Arc::new(Local::new("/tmp")),
+            StorageScheme::FuseDfs => {
+                let store_api_provider = StoreApiProvider::new(&self.get_config());
+                let storage_api = store_api_provider.sync_try_get_storage_client()?;
+                Arc::new(FuseDfs::new(storage_api))
+            }
+        };
+        Ok(MeteredAccessor::create(accessor, storage_scheme.name()))
+    }
+
+    /// Data accessor of an external location (such as `s3://bucket/path/`) and the key prefix inside it.
+    pub fn get_data_accessor_by_location(
+        &self,
+        location: &str,
+    ) -> Result<(Arc<dyn DataAccessor>, String)> {
+        let (scheme, bucket, prefix) = parse_location(location)?;
+        match scheme {
+            StorageScheme::S3 => {
+                let conf = self.get_config().storage.s3;
+                let region = match conf.region.is_empty() {
+                    true => Region::default(),
+                    false => conf
+                        .region
+                        .parse::<Region>()
+                        .map_err(|e| ErrorCode::BadArguments(e.to_string()))?,
+                };
+                let accessor = Arc::new(S3::new(region, bucket));
+                Ok((MeteredAccessor::create(accessor, scheme.name()), prefix))
+            }
+            // The path of `file:///path/` is relative to the configured unload dir,
+            // the local locations are disabled if it's not configured.
+            StorageScheme::LocalFs => {
+                let unload_path = self.get_config().storage.disk.unload_path;
+                if unload_path.is_empty() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Location {} is disabled, set storage.disk.unload_path to enable the file:// locations",
+                        location
+                    )));
+                }
+                let accessor = Arc::new(Local::new(&unload_path));
+                let prefix = prefix.trim_start_matches('/').to_string();
+                Ok((MeteredAccessor::create(accessor, scheme.name()), prefix))
+            }
+            other => Err(ErrorCode::BadArguments(format!(
+                "Unsupported storage scheme {:?} for location {}",
+                other, location
+            ))),
         }
     }
 }
@@ -264,6 +363,25 @@ impl DatabendQueryContextShared {
         if self.ref_count.fetch_sub(1, Ordering::Release) == 1 {
             std::sync::atomic::fence(Acquire);
             log::info!("Destroy DatabendQueryContext");
+            if let Some(record) = self.query_log_record() {
+                record.record_metrics();
+                if self.get_settings().get_enable_query_log().unwrap_or(true) {
+                    self.session
+                        .get_sessions_manager()
+                        .get_query_log()
+                        .append(record);
+                }
+            }
+            if let Some((user, usage)) = self.quota_usage() {
+                let user_manager = self.session.get_sessions_manager().get_user_manager();
+                if let Err(cause) = user_manager.record_quota_usage(&user, &usage) {
+                    log::warn!("Cannot record the quota usage of user {}: {}", user, cause);
+                }
+            }
+            self.resource_group_permit.write().take();
+            if let Some(execution_timer) = self.execution_timer.write().take() {
+                execution_timer.abort();
+            }
             self.session.destroy_context_shared();
         }
     }
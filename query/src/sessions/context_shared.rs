@@ -13,21 +13,34 @@
 // limitations under the License.
 
 use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use common_base::tokio;
+use common_base::CancellationToken;
 use common_base::Progress;
 use common_base::Runtime;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
+use common_management::QuotaUsage;
 use common_planners::PlanNode;
+use common_planners::VarValue;
 use futures::future::AbortHandle;
 use uuid::Uuid;
 
 use crate::catalogs::impls::DatabaseCatalog;
 use crate::clusters::ClusterRef;
 use crate::configs::Config;
+use crate::sessions::QueryLogRecord;
+use crate::sessions::ResourceGroupPermit;
+use crate::sessions::ScopeLevel;
 use crate::sessions::Session;
 use crate::sessions::Settings;
+use crate::users::mask_passwords;
 
 /// Data that needs to be shared in a query context.
 /// This is very useful, for example, for queries:
@@ -41,15 +54,27 @@ use crate::sessions::Settings;
 pub struct DatabendQueryContextShared {
     pub(in crate::sessions) conf: Config,
     pub(in crate::sessions) progress: Arc<Progress>,
+    // Same as progress but never reset, for the query log.
+    pub(in crate::sessions) query_progress: Arc<Progress>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: ClusterRef,
-    pub(in crate::sessions) sources_abort_handle: Arc<RwLock<Vec<AbortHandle>>>,
+    // Cancelled when the query is killed or exceeds max_execution_time.
+    pub(in crate::sessions) cancellation: CancellationToken,
+    pub(in crate::sessions) execution_timer: Arc<RwLock<Option<AbortHandle>>>,
     pub(in crate::sessions) ref_count: Arc<AtomicUsize>,
     pub(in crate::sessions) subquery_index: Arc<AtomicUsize>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
+    pub(in crate::sessions) created_time: SystemTime,
+    pub(in crate::sessions) result_rows: Arc<AtomicUsize>,
+    pub(in crate::sessions) query_error: Arc<RwLock<Option<ErrorCode>>>,
+    // The user whose quota usage the query is counted in, set when the query is admitted.
+    pub(in crate::sessions) quota_user: Arc<RwLock<Option<String>>>,
+    pub(in crate::sessions) resource_group_permit: Arc<RwLock<Option<ResourceGroupPermit>>>,
+    // The session settings with the settings of the query, set by `<query> SETTINGS (...)`.
+    pub(in crate::sessions) query_settings: Arc<RwLock<Option<Arc<Settings>>>>,
 }
 
 impl DatabendQueryContextShared {
@@ -62,25 +87,59 @@ impl DatabendQueryContextShared {
             conf,
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             progress: Arc::new(Progress::create()),
+            query_progress: Arc::new(Progress::create()),
             session,
             cluster_cache,
             runtime: Arc::new(RwLock::new(None)),
-            sources_abort_handle: Arc::new(RwLock::new(Vec::new())),
+            cancellation: CancellationToken::create(),
+            execution_timer: Arc::new(RwLock::new(None)),
             ref_count: Arc::new(AtomicUsize::new(0)),
             subquery_index: Arc::new(AtomicUsize::new(1)),
             running_query: Arc::new(RwLock::new(None)),
             running_plan: Arc::new(RwLock::new(None)),
+            created_time: SystemTime::now(),
+            result_rows: Arc::new(AtomicUsize::new(0)),
+            query_error: Arc::new(RwLock::new(None)),
+            quota_user: Arc::new(RwLock::new(None)),
+            resource_group_permit: Arc::new(RwLock::new(None)),
+            query_settings: Arc::new(RwLock::new(None)),
         })
     }
 
     pub fn kill(&self) {
-        let mut sources_abort_handle = self.sources_abort_handle.write();
+        self.cancellation.cancel(ErrorCode::AbortedQuery(
+            "Aborted query, because the server is shutting down or the query was killed",
+        ));
 
-        while let Some(source_abort_handle) = sources_abort_handle.pop() {
-            source_abort_handle.abort();
+        // TODO: Wait for the query to be processed (write out the last error)
+    }
+
+    pub fn get_cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Cancel the query with a timeout error once it runs longer than max_execution_time,
+    /// the timer is stopped when the query context is destroyed.
+    pub fn start_execution_timer(&self) -> Result<()> {
+        let max_execution_time = self.get_settings().get_max_execution_time()?;
+        if max_execution_time == 0 {
+            return Ok(());
         }
 
-        // TODO: Wait for the query to be processed (write out the last error)
+        let cancellation = self.cancellation.clone();
+        let (timer, timer_handle) = futures::future::abortable(async move {
+            tokio::time::sleep(Duration::from_secs(max_execution_time)).await;
+            cancellation.cancel(ErrorCode::Timeout(format!(
+                "Query exceeded max_execution_time of {} seconds",
+                max_execution_time
+            )));
+        });
+
+        self.try_get_runtime()?.spawn(timer);
+        if let Some(previous_timer) = self.execution_timer.write().replace(timer_handle) {
+            previous_timer.abort();
+        }
+        Ok(())
     }
 
     pub fn get_cluster(&self) -> ClusterRef {
@@ -91,12 +150,37 @@ impl DatabendQueryContextShared {
         self.session.get_current_database()
     }
 
+    pub fn get_current_user(&self) -> Option<String> {
+        self.session.get_current_user()
+    }
+
     pub fn set_current_database(&self, new_database_name: String) {
         self.session.set_current_database(new_database_name);
     }
 
     pub fn get_settings(&self) -> Arc<Settings> {
-        self.session.get_settings()
+        match &*self.query_settings.read() {
+            Some(query_settings) => query_settings.clone(),
+            None => self.session.get_settings(),
+        }
+    }
+
+    /// Apply the settings of the query on a copy of the session settings, the session
+    /// settings are not changed.
+    pub fn apply_query_settings(&self, vars: &[VarValue]) -> Result<()> {
+        let settings = self.get_settings().try_clone()?;
+        for var in vars {
+            match var.is_default {
+                true => settings.reset_settings(&var.variable)?,
+                false => settings.update_settings_at_level(
+                    &var.variable,
+                    var.value.clone(),
+                    ScopeLevel::Query,
+                )?,
+            }
+        }
+        *self.query_settings.write() = Some(settings);
+        Ok(())
     }
 
     pub fn get_catalog(&self) -> Arc<DatabaseCatalog> {
@@ -121,7 +205,7 @@ impl DatabendQueryContextShared {
 
     pub fn attach_query_str(&self, query: &str) {
         let mut running_query = self.running_query.write();
-        *running_query = Some(query.to_string());
+        *running_query = Some(mask_passwords(query));
     }
 
     pub fn attach_query_plan(&self, plan: &PlanNode) {
@@ -129,9 +213,78 @@ impl DatabendQueryContextShared {
         *running_plan = Some(plan.clone());
     }
 
-    pub fn add_source_abort_handle(&self, handle: AbortHandle) {
-        let mut sources_abort_handle = self.sources_abort_handle.write();
-        sources_abort_handle.push(handle);
+    pub fn add_result_rows(&self, rows: usize) {
+        self.result_rows.fetch_add(rows, Ordering::Relaxed);
+    }
+
+    pub fn attach_query_error(&self, error: &ErrorCode) {
+        let mut query_error = self.query_error.write();
+        *query_error = Some(error.clone());
+    }
+
+    pub fn attach_quota_user(&self, user: &str) {
+        let mut quota_user = self.quota_user.write();
+        *quota_user = Some(user.to_string());
+    }
+
+    pub fn attach_resource_group_permit(&self, permit: ResourceGroupPermit) {
+        let mut resource_group_permit = self.resource_group_permit.write();
+        *resource_group_permit = Some(permit);
+    }
+
+    /// The quota usage of the admitted query, None if the query is not admitted by the quotas.
+    pub(in crate::sessions) fn quota_usage(&self) -> Option<(String, QuotaUsage)> {
+        let user = self.quota_user.read().clone()?;
+        let duration = SystemTime::now()
+            .duration_since(self.created_time)
+            .unwrap_or_default();
+
+        let progress = self.query_progress.get_values();
+        Some((user, QuotaUsage {
+            interval_start: 0,
+            queries: 1,
+            read_rows: progress.read_rows as u64,
+            read_bytes: progress.read_bytes as u64,
+            execution_time_ms: duration.as_millis() as u64,
+        }))
+    }
+
+    /// The query log record of the attached query, None if no query is attached (e.g. a
+    /// partial query stage of a cluster query, which is recorded by the initiator node).
+    pub(in crate::sessions) fn query_log_record(&self) -> Option<QueryLogRecord> {
+        let query_text = self.running_query.read().clone()?;
+        let query_kind = match &*self.running_plan.read() {
+            Some(plan) => plan.name().to_string(),
+            None => String::from("Unknown"),
+        };
+
+        let end_time = SystemTime::now();
+        let unix_seconds = |time: SystemTime| match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as u32,
+            Err(_) => 0,
+        };
+        let duration = end_time
+            .duration_since(self.created_time)
+            .unwrap_or_default();
+
+        let progress = self.query_progress.get_values();
+        let query_error = self.query_error.read();
+        Some(QueryLogRecord {
+            query_id: self.init_query_id.read().clone(),
+            user: self.session.get_current_user(),
+            client_address: self.session.get_client_host().map(|s| s.to_string()),
+            query_text,
+            query_kind,
+            query_start_time: unix_seconds(self.created_time),
+            query_end_time: unix_seconds(end_time),
+            query_duration_ms: duration.as_millis() as u64,
+            read_rows: progress.read_rows as u64,
+            read_bytes: progress.read_bytes as u64,
+            result_rows: self.result_rows.load(Ordering::Relaxed) as u64,
+            error_code: query_error.as_ref().map(|e| e.code()).unwrap_or(0),
+            exception: query_error.as_ref().map(|e| e.message()),
+            node: self.cluster_cache.local_id(),
+        })
     }
 }
 
@@ -14,7 +14,7 @@
 
 // for settings getter setter
 macro_rules! apply_getter_setter_settings {
-    ($(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr)),* ) => {
+    ($(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr, [$($VARIANT: expr),*])),* ) => {
         $(
             paste::paste!{
                 pub fn [< get_ $NAME >](&self) -> Result<$TYPE> {
@@ -22,20 +22,31 @@ macro_rules! apply_getter_setter_settings {
                 }
 
                 pub fn [< set_ $NAME >](&self, value: $TYPE) -> Result<()> {
-                    self.inner.[<try_update_ $TYPE:lower>]($NAME, value)
+                    self.inner.[<try_update_ $TYPE:lower>]($NAME, value, ScopeLevel::Session)
                 }
             }
         )*
     };
 }
 
+macro_rules! apply_possible_values {
+    () => {
+        None
+    };
+
+    ($($VARIANT: expr),+) => {
+        Some(vec![$($VARIANT),+])
+    };
+}
+
 macro_rules! apply_initial_settings {
-    ($(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr)),* ) => {
+    ($(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr, [$($VARIANT: expr),*])),* ) => {
 
         pub fn initial_settings(&self) -> Result<()> {
             paste::paste! {
                 $(
-                    self.inner.[<try_set_ $TYPE:lower>]($NAME, $VALUE, $DESC)?;
+                    let possible_values = apply_possible_values!($($VARIANT),*);
+                    self.inner.[<try_set_ $TYPE:lower>]($NAME, $VALUE, $DESC, possible_values)?;
                 )*
             }
             Ok(())
@@ -44,23 +55,31 @@ macro_rules! apply_initial_settings {
 }
 
 macro_rules! apply_parse_value {
-    ($VALUE: expr, String) => {
+    ($NAME: expr, $VALUE: expr, String) => {
         $VALUE
     };
 
-    ($VALUE: expr, $TYPE: tt) => {
+    ($NAME: expr, $VALUE: expr, bool) => {
+        parse_bool_setting($NAME, &$VALUE)?
+    };
+
+    ($NAME: expr, $VALUE: expr, $TYPE: tt) => {
         $VALUE.parse::<$TYPE>().map_err(ErrorCode::from)?
     };
 }
 
 macro_rules! apply_update_settings {
-    ($(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr)),* ) => {
+    ($(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr, [$($VARIANT: expr),*])),* ) => {
         pub fn update_settings(&self, key: &str, value: String) -> Result<()> {
+            self.update_settings_at_level(key, value, ScopeLevel::Session)
+        }
+
+        pub fn update_settings_at_level(&self, key: &str, value: String, level: ScopeLevel) -> Result<()> {
             paste::paste! {
                 $(
                     if (key.to_lowercase().as_str() == $NAME) {
-                        let v = apply_parse_value!{value, $TYPE};
-                        return self.inner.[<try_update_ $TYPE:lower>]($NAME, v);
+                        let v = apply_parse_value!{$NAME, value, $TYPE};
+                        return self.inner.[<try_update_ $TYPE:lower>]($NAME, v, level);
                     }
                 )*
             }
@@ -71,10 +90,11 @@ macro_rules! apply_update_settings {
     };
 }
 
+// A setting is (NAME, TYPE, VALUE, DESC) or (NAME, String, VALUE, DESC, [VARIANTS]) of an enum.
 macro_rules! apply_macros {
-    ($MACRO_A: ident, $MACRO_B: ident, $MACRO_C: ident, $(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr)),* ) => {
-        $MACRO_A! { $( ($NAME, $TYPE, $VALUE, $DESC) ), * }
-        $MACRO_B! { $( ($NAME, $TYPE, $VALUE, $DESC) ), * }
-        $MACRO_C! { $( ($NAME, $TYPE, $VALUE, $DESC) ), * }
+    ($MACRO_A: ident, $MACRO_B: ident, $MACRO_C: ident, $(($NAME: expr, $TYPE: tt, $VALUE:expr, $DESC: expr $(, [$($VARIANT: expr),*])?)),* ) => {
+        $MACRO_A! { $( ($NAME, $TYPE, $VALUE, $DESC, [$($($VARIANT),*)?]) ), * }
+        $MACRO_B! { $( ($NAME, $TYPE, $VALUE, $DESC, [$($($VARIANT),*)?]) ), * }
+        $MACRO_C! { $( ($NAME, $TYPE, $VALUE, $DESC, [$($($VARIANT),*)?]) ), * }
     };
 }
@@ -14,3 +14,7 @@
 
 pub static METRIC_SESSION_CONNECT_NUMBERS: &str = "session.connect_numbers";
 pub static METRIC_SESSION_CLOSE_NUMBERS: &str = "session.close_numbers";
+pub static METRIC_SESSION_ACTIVE_SESSIONS: &str = "session.active_sessions";
+pub static METRIC_QUERY_NUMBERS: &str = "query.query_numbers";
+pub static METRIC_QUERY_DURATION: &str = "query.query_duration";
+pub static METRIC_QUERY_RESULT_ROWS: &str = "query.result_rows";
@@ -12,12 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod resource_group_test;
+#[cfg(test)]
+mod settings_test;
+
 #[macro_use]
 mod macros;
 
 mod context;
 mod context_shared;
 mod metrics;
+mod query_log;
+mod resource_group;
 mod session;
 mod session_info;
 mod session_ref;
@@ -29,9 +36,16 @@ mod settings;
 pub use context::DatabendQueryContext;
 pub use context::DatabendQueryContextRef;
 pub use context_shared::DatabendQueryContextShared;
+pub use query_log::QueryLog;
+pub use query_log::QueryLogRecord;
+pub use query_log::QUERY_LOG_TABLE_NAME;
+pub use resource_group::ResourceGroupPermit;
+pub use resource_group::ResourceGroupQueue;
 pub use session::Session;
 pub use session_info::ProcessInfo;
 pub use session_ref::SessionRef;
 pub use sessions::SessionManager;
 pub use sessions::SessionManagerRef;
+pub use settings::ScopeLevel;
+pub use settings::SettingValue;
 pub use settings::Settings;
@@ -0,0 +1,284 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_datavalues::series::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::RwLock;
+use common_planners::CreateDatabasePlan;
+use common_planners::CreateTablePlan;
+use common_planners::InsertIntoPlan;
+use metrics::counter;
+use metrics::histogram;
+
+use crate::catalogs::Catalog;
+use crate::catalogs::TableMeta;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::SessionManager;
+
+pub const QUERY_LOG_TABLE_NAME: &str = "query_log";
+
+// The oldest records are dropped when the buffer is full, e.g. the query log is not persisted.
+const MAX_BUFFERED_QUERY_LOGS: usize = 100000;
+
+/// One finished query, recorded when its context is destroyed.
+#[derive(Clone, Debug)]
+pub struct QueryLogRecord {
+    pub query_id: String,
+    pub user: Option<String>,
+    pub client_address: Option<String>,
+    pub query_text: String,
+    pub query_kind: String,
+    // Seconds since the unix epoch.
+    pub query_start_time: u32,
+    pub query_end_time: u32,
+    pub query_duration_ms: u64,
+    pub read_rows: u64,
+    pub read_bytes: u64,
+    pub result_rows: u64,
+    // Zero if the query succeeded.
+    pub error_code: u16,
+    pub exception: Option<String>,
+    pub node: String,
+}
+
+impl QueryLogRecord {
+    /// Count the query in the metrics by its kind (the plan name) and status.
+    pub fn record_metrics(&self) {
+        let kind = self.query_kind.clone();
+        let status = match self.error_code {
+            0 => "success",
+            code if code == ErrorCode::AbortedQuery("").code() => "aborted",
+            code if code == ErrorCode::Timeout("").code() => "timeout",
+            _ => "error",
+        };
+
+        counter!(super::metrics::METRIC_QUERY_NUMBERS, 1, "kind" => kind.clone(), "status" => status);
+        histogram!(
+            super::metrics::METRIC_QUERY_DURATION,
+            Duration::from_millis(self.query_duration_ms),
+            "kind" => kind.clone()
+        );
+        counter!(super::metrics::METRIC_QUERY_RESULT_ROWS, self.result_rows, "kind" => kind);
+    }
+}
+
+/// The query log records which are not flushed into the query log table yet.
+pub struct QueryLog {
+    buffer: RwLock<VecDeque<QueryLogRecord>>,
+}
+
+impl QueryLog {
+    pub fn create() -> Arc<QueryLog> {
+        Arc::new(QueryLog {
+            buffer: RwLock::new(VecDeque::new()),
+        })
+    }
+
+    pub fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("query_id", DataType::String, false),
+            DataField::new("user", DataType::String, true),
+            DataField::new("client_address", DataType::String, true),
+            DataField::new("query_text", DataType::String, false),
+            DataField::new("query_kind", DataType::String, false),
+            DataField::new("query_start_time", DataType::DateTime32(None), false),
+            DataField::new("query_end_time", DataType::DateTime32(None), false),
+            DataField::new("query_duration_ms", DataType::UInt64, false),
+            DataField::new("read_rows", DataType::UInt64, false),
+            DataField::new("read_bytes", DataType::UInt64, false),
+            DataField::new("result_rows", DataType::UInt64, false),
+            DataField::new("error_code", DataType::UInt16, false),
+            DataField::new("exception", DataType::String, true),
+            DataField::new("node", DataType::String, false),
+        ])
+    }
+
+    pub fn append(&self, record: QueryLogRecord) {
+        let mut buffer = self.buffer.write();
+        if buffer.len() == MAX_BUFFERED_QUERY_LOGS {
+            buffer.pop_front();
+        }
+        buffer.push_back(record);
+    }
+
+    pub fn buffered_records(&self) -> Vec<QueryLogRecord> {
+        self.buffer.read().iter().cloned().collect()
+    }
+
+    pub fn take_buffered_records(&self) -> Vec<QueryLogRecord> {
+        self.buffer.write().drain(..).collect()
+    }
+
+    /// Puts the records back in front of the buffer, e.g. after a failed flush.
+    pub fn restore_records(&self, records: Vec<QueryLogRecord>) {
+        let mut buffer = self.buffer.write();
+        for record in records.into_iter().rev() {
+            if buffer.len() == MAX_BUFFERED_QUERY_LOGS {
+                break;
+            }
+            buffer.push_front(record);
+        }
+    }
+
+    pub fn to_block(records: &[QueryLogRecord]) -> DataBlock {
+        let strings = |f: &dyn Fn(&QueryLogRecord) -> String| -> Series {
+            Series::new(
+                records
+                    .iter()
+                    .map(|r| f(r).into_bytes())
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let opt_strings = |f: &dyn Fn(&QueryLogRecord) -> Option<String>| -> Series {
+            Series::new(
+                records
+                    .iter()
+                    .map(|r| f(r).map(|s| s.into_bytes()))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let numbers = |f: &dyn Fn(&QueryLogRecord) -> u64| -> Series {
+            Series::new(records.iter().map(f).collect::<Vec<_>>())
+        };
+
+        DataBlock::create_by_array(QueryLog::schema(), vec![
+            strings(&|r| r.query_id.clone()),
+            opt_strings(&|r| r.user.clone()),
+            opt_strings(&|r| r.client_address.clone()),
+            strings(&|r| r.query_text.clone()),
+            strings(&|r| r.query_kind.clone()),
+            Series::new(
+                records
+                    .iter()
+                    .map(|r| r.query_start_time)
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(records.iter().map(|r| r.query_end_time).collect::<Vec<_>>()),
+            numbers(&|r| r.query_duration_ms),
+            numbers(&|r| r.read_rows),
+            numbers(&|r| r.read_bytes),
+            numbers(&|r| r.result_rows),
+            Series::new(records.iter().map(|r| r.error_code).collect::<Vec<_>>()),
+            opt_strings(&|r| r.exception.clone()),
+            strings(&|r| r.node.clone()),
+        ])
+    }
+}
+
+impl SessionManager {
+    pub fn get_query_log(self: &Arc<Self>) -> Arc<QueryLog> {
+        self.query_log.clone()
+    }
+
+    /// The table the query log is persisted into, None if the query log is kept in memory only.
+    pub fn get_query_log_table(
+        self: &Arc<Self>,
+        ctx: &DatabendQueryContextRef,
+    ) -> Result<Option<Arc<TableMeta>>> {
+        let engine = &self.conf.query.query_log_engine;
+        if engine.is_empty() {
+            return Ok(None);
+        }
+
+        let db = &self.conf.query.query_log_database;
+        let catalog = ctx.get_catalog();
+        if catalog.get_database(db).is_err() {
+            catalog.create_database(CreateDatabasePlan {
+                if_not_exists: true,
+                db: db.clone(),
+                engine: "default".to_string(),
+                options: Default::default(),
+            })?;
+        }
+
+        let database = catalog.get_database(db)?;
+        if database.get_table(QUERY_LOG_TABLE_NAME).is_err() {
+            database.create_table(CreateTablePlan {
+                if_not_exists: true,
+                db: db.clone(),
+                table: QUERY_LOG_TABLE_NAME.to_string(),
+                schema: QueryLog::schema(),
+                engine: engine.clone(),
+                options: Default::default(),
+            })?;
+        }
+        Ok(Some(database.get_table(QUERY_LOG_TABLE_NAME)?))
+    }
+
+    /// Appends the buffered query log into the query log table.
+    pub async fn flush_query_log(self: &Arc<Self>) -> Result<()> {
+        if self.conf.query.query_log_engine.is_empty() {
+            return Ok(());
+        }
+
+        let records = self.query_log.take_buffered_records();
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let block = QueryLog::to_block(&records);
+        let flushed = async move {
+            let session = self.create_session("QueryLogSession")?;
+            let ctx = session.create_context().await?;
+            if let Some(table) = self.get_query_log_table(&ctx)? {
+                let plan = InsertIntoPlan {
+                    db_name: self.conf.query.query_log_database.clone(),
+                    tbl_name: QUERY_LOG_TABLE_NAME.to_string(),
+                    tbl_id: table.meta_id(),
+                    schema: QueryLog::schema(),
+                    input_stream: InsertIntoPlan::empty_stream(),
+                };
+                plan.set_input_stream(Box::pin(futures::stream::iter(vec![block])));
+                table.raw().append_data(ctx, plan).await?;
+            }
+            Ok(())
+        };
+
+        let result: Result<()> = flushed.await;
+        if result.is_err() {
+            self.query_log.restore_records(records);
+        }
+        result
+    }
+
+    /// Flushes the query log into the query log table periodically.
+    pub fn start_query_log_flusher(self: &Arc<Self>) {
+        if self.conf.query.query_log_engine.is_empty() {
+            return;
+        }
+
+        let sessions = self.clone();
+        let interval = Duration::from_millis(self.conf.query.query_log_flush_interval_ms);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if let Err(cause) = sessions.flush_query_log().await {
+                    log::warn!("Cannot flush the query log: {}", cause);
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::tokio::sync::OwnedSemaphorePermit;
+use common_base::tokio::sync::Semaphore;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::ResourceGroupInfo;
+
+/// The queue of a resource group on this node, at most `max_concurrency` queries run at the
+/// same time and at most `max_queued` queries wait for a slot, the others are rejected.
+pub struct ResourceGroupQueue {
+    info: ResourceGroupInfo,
+    semaphore: Arc<Semaphore>,
+    running: AtomicU64,
+    queued: AtomicU64,
+}
+
+/// The slot of a running query, it's released when the query context is destroyed.
+pub struct ResourceGroupPermit {
+    queue: Arc<ResourceGroupQueue>,
+    _permit: OwnedSemaphorePermit,
+}
+
+// Decrease the queued number even if the waiting query is cancelled.
+struct QueuedGuard<'a>(&'a AtomicU64);
+
+impl ResourceGroupQueue {
+    pub fn create(info: ResourceGroupInfo) -> Arc<ResourceGroupQueue> {
+        Arc::new(ResourceGroupQueue {
+            semaphore: Arc::new(Semaphore::new(info.max_concurrency as usize)),
+            info,
+            running: AtomicU64::new(0),
+            queued: AtomicU64::new(0),
+        })
+    }
+
+    pub fn get_info(&self) -> &ResourceGroupInfo {
+        &self.info
+    }
+
+    pub fn running(&self) -> u64 {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    pub fn queued(&self) -> u64 {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    pub async fn acquire(self: &Arc<Self>) -> Result<ResourceGroupPermit> {
+        let permit = match self.semaphore.clone().try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => {
+                if self.queued.fetch_add(1, Ordering::SeqCst) >= self.info.max_queued {
+                    self.queued.fetch_sub(1, Ordering::SeqCst);
+                    return Err(ErrorCode::ResourceGroupQueueFull(format!(
+                        "The queue of resource group {} is full, max_concurrency: {}, max_queued: {}",
+                        self.info.name, self.info.max_concurrency, self.info.max_queued
+                    )));
+                }
+
+                let _guard = QueuedGuard(&self.queued);
+                self.semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|cause| ErrorCode::UnexpectedError(cause.to_string()))?
+            }
+        };
+
+        self.running.fetch_add(1, Ordering::SeqCst);
+        Ok(ResourceGroupPermit {
+            queue: self.clone(),
+            _permit: permit,
+        })
+    }
+}
+
+impl Drop for ResourceGroupPermit {
+    fn drop(&mut self) {
+        self.queue.running.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<'a> Drop for QueuedGuard<'a> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::ResourceGroupInfo;
+
+use crate::sessions::ResourceGroupQueue;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_resource_group_queue() -> Result<()> {
+    let queue = ResourceGroupQueue::create(ResourceGroupInfo::new("etl".to_string(), 1, 1));
+
+    let permit = queue.acquire().await?;
+    assert_eq!(1, queue.running());
+
+    // The second query waits in the queue.
+    let waiting = {
+        let queue = queue.clone();
+        tokio::spawn(async move { queue.acquire().await.map(|_| ()) })
+    };
+    while queue.queued() == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // The third query is rejected.
+    match queue.acquire().await {
+        Ok(_) => panic!("the queue of the resource group must be full"),
+        Err(cause) => assert_eq!(ErrorCode::ResourceGroupQueueFull("").code(), cause.code()),
+    }
+
+    drop(permit);
+    waiting.await.unwrap()?;
+    assert_eq!(0, queue.queued());
+    assert_eq!(0, queue.running());
+    Ok(())
+}
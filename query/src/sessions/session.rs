@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_infallible::Mutex;
+use common_management::UserSetting;
 use futures::channel::oneshot::Sender;
 use futures::channel::*;
 
@@ -26,6 +27,7 @@ use crate::configs::Config;
 use crate::sessions::context_shared::DatabendQueryContextShared;
 use crate::sessions::DatabendQueryContext;
 use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::ScopeLevel;
 use crate::sessions::SessionManagerRef;
 use crate::sessions::Settings;
 use crate::users::UserManagerRef;
@@ -33,6 +35,7 @@ use crate::users::UserManagerRef;
 pub(in crate::sessions) struct MutableStatus {
     pub(in crate::sessions) abort: bool,
     pub(in crate::sessions) current_database: String,
+    pub(in crate::sessions) current_user: Option<String>,
     pub(in crate::sessions) session_settings: Arc<Settings>,
     pub(in crate::sessions) client_host: Option<SocketAddr>,
     pub(in crate::sessions) io_shutdown_tx: Option<Sender<Sender<()>>>,
@@ -65,6 +68,7 @@ impl Session {
             mutable_state: Arc::new(Mutex::new(MutableStatus {
                 abort: false,
                 current_database: String::from("default"),
+                current_user: None,
                 session_settings: Settings::try_create()?,
                 client_host: None,
                 io_shutdown_tx: None,
@@ -170,6 +174,37 @@ impl Session {
         inner.current_database.clone()
     }
 
+    pub fn set_current_user(self: &Arc<Self>, user: String) {
+        match self.get_user_manager().get_user(&user) {
+            Ok(user_info) => self.apply_settings(&user_info.settings, ScopeLevel::User),
+            Err(cause) => log::warn!("Cannot get the settings of user {}: {}", user, cause),
+        }
+
+        let mut inner = self.mutable_state.lock();
+        inner.current_user = Some(user);
+    }
+
+    /// Apply the global or the user settings, the settings which can't be applied (e.g. removed
+    /// in this version) are skipped.
+    pub fn apply_settings(self: &Arc<Self>, settings: &[UserSetting], level: ScopeLevel) {
+        let values = settings
+            .iter()
+            .map(|setting| (setting.name.as_str(), setting.value.as_str()));
+        for (name, cause) in self.get_settings().apply_settings(values, level) {
+            log::warn!("Cannot apply the {} setting {}: {}", level, name, cause);
+        }
+    }
+
+    pub fn get_current_user(self: &Arc<Self>) -> Option<String> {
+        let inner = self.mutable_state.lock();
+        inner.current_user.clone()
+    }
+
+    pub fn get_client_host(self: &Arc<Self>) -> Option<SocketAddr> {
+        let inner = self.mutable_state.lock();
+        inner.client_host
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.mutable_state.lock().session_settings.clone()
     }
@@ -26,14 +26,18 @@ use common_exception::Result;
 use common_infallible::RwLock;
 use futures::future::Either;
 use metrics::counter;
+use metrics::gauge;
 
 use crate::catalogs::impls::DatabaseCatalog;
 use crate::catalogs::Catalog;
 use crate::clusters::ClusterDiscoveryRef;
 use crate::configs::Config;
 use crate::datasources::database::example::ExampleDatabaseEngine;
+use crate::sessions::query_log::QueryLog;
+use crate::sessions::resource_group::ResourceGroupQueue;
 use crate::sessions::session::Session;
 use crate::sessions::session_ref::SessionRef;
+use crate::sessions::ScopeLevel;
 use crate::users::UserManagerRef;
 
 pub struct SessionManager {
@@ -41,6 +45,8 @@ pub struct SessionManager {
     pub(in crate::sessions) discovery: ClusterDiscoveryRef,
     pub(in crate::sessions) catalog: Arc<DatabaseCatalog>,
     pub(in crate::sessions) user: UserManagerRef,
+    pub(in crate::sessions) query_log: Arc<QueryLog>,
+    pub(in crate::sessions) resource_groups: RwLock<HashMap<String, Arc<ResourceGroupQueue>>>,
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
@@ -64,6 +70,8 @@ impl SessionManager {
             conf,
             discovery,
             user,
+            query_log: QueryLog::create(),
+            resource_groups: RwLock::new(HashMap::new()),
             max_sessions: max_active_sessions,
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_active_sessions))),
         }))
@@ -86,26 +94,63 @@ impl SessionManager {
         self.catalog.clone()
     }
 
+    // Get the queue of the resource group on this node, the queue is recreated if the resource
+    // group is recreated with other limits, the running queries keep the slots of the old queue.
+    pub fn get_resource_group_queue(
+        self: &Arc<Self>,
+        name: &str,
+    ) -> Result<Arc<ResourceGroupQueue>> {
+        let info = self.user.get_resource_group(name)?;
+
+        let mut resource_groups = self.resource_groups.write();
+        match resource_groups.get(name) {
+            Some(queue) if queue.get_info() == &info => Ok(queue.clone()),
+            _ => {
+                let queue = ResourceGroupQueue::create(info);
+                resource_groups.insert(name.to_string(), queue.clone());
+                Ok(queue)
+            }
+        }
+    }
+
+    // The queues of the resource groups used on this node.
+    pub fn get_resource_group_queues(self: &Arc<Self>) -> HashMap<String, Arc<ResourceGroupQueue>> {
+        self.resource_groups.read().clone()
+    }
+
     pub fn create_session(self: &Arc<Self>, typ: impl Into<String>) -> Result<SessionRef> {
         counter!(super::metrics::METRIC_SESSION_CONNECT_NUMBERS, 1);
 
-        let mut sessions = self.active_sessions.write();
-        match sessions.len() == self.max_sessions {
-            true => Err(ErrorCode::TooManyUserConnections(
-                "The current accept connection has exceeded mysql_handler_thread_num config",
-            )),
-            false => {
-                let session = Session::try_create(
-                    self.conf.clone(),
-                    uuid::Uuid::new_v4().to_string(),
-                    typ.into(),
-                    self.clone(),
-                )?;
-
-                sessions.insert(session.get_id(), session.clone());
-                Ok(SessionRef::create(session))
+        let session = {
+            let mut sessions = self.active_sessions.write();
+            if sessions.len() == self.max_sessions {
+                return Err(ErrorCode::TooManyUserConnections(
+                    "The current accept connection has exceeded mysql_handler_thread_num config",
+                ));
             }
+
+            let session = Session::try_create(
+                self.conf.clone(),
+                uuid::Uuid::new_v4().to_string(),
+                typ.into(),
+                self.clone(),
+            )?;
+
+            sessions.insert(session.get_id(), session.clone());
+            gauge!(
+                super::metrics::METRIC_SESSION_ACTIVE_SESSIONS,
+                sessions.len() as f64
+            );
+            session
+        };
+
+        // The global settings are changed by SET GLOBAL, they apply to the new sessions.
+        match self.user.get_global_settings() {
+            Ok(settings) => session.apply_settings(&settings, ScopeLevel::Global),
+            Err(cause) => log::warn!("Cannot get the global settings: {}", cause),
         }
+
+        Ok(SessionRef::create(session))
     }
 
     pub fn create_rpc_session(self: &Arc<Self>, id: String, aborted: bool) -> Result<SessionRef> {
@@ -124,7 +169,12 @@ impl SessionManager {
                     self.clone(),
                 )?;
 
-                entry.insert(session).clone()
+                let session = entry.insert(session).clone();
+                gauge!(
+                    super::metrics::METRIC_SESSION_ACTIVE_SESSIONS,
+                    sessions.len() as f64
+                );
+                session
             }
         };
 
@@ -143,36 +193,53 @@ impl SessionManager {
     pub fn destroy_session(self: &Arc<Self>, session_id: &String) {
         counter!(super::metrics::METRIC_SESSION_CLOSE_NUMBERS, 1);
 
-        self.active_sessions.write().remove(session_id);
+        let mut sessions = self.active_sessions.write();
+        sessions.remove(session_id);
+        gauge!(
+            super::metrics::METRIC_SESSION_ACTIVE_SESSIONS,
+            sessions.len() as f64
+        );
     }
 
     pub fn shutdown(self: &Arc<Self>, signal: Option<Receiver<()>>) -> impl Future<Output = ()> {
+        let sessions = self.clone();
         let active_sessions = self.active_sessions.clone();
         async move {
-            log::info!("Waiting for current connections to close.");
-            if let Some(mut signal) = signal {
-                let mut signal = Box::pin(signal.recv());
-
-                for _index in 0..5 {
-                    if SessionManager::destroy_idle_sessions(&active_sessions) {
-                        return;
-                    }
-
-                    let interval = Duration::from_secs(1);
-                    let sleep = Box::pin(tokio::time::sleep(interval));
-                    match futures::future::select(sleep, signal).await {
-                        Either::Right((_, _)) => break,
-                        Either::Left((_, reserve_signal)) => signal = reserve_signal,
-                    };
-                }
+            SessionManager::close_sessions(active_sessions, signal).await;
+
+            if let Err(cause) = sessions.flush_query_log().await {
+                log::warn!("Cannot flush the query log: {}", cause);
             }
+        }
+    }
 
-            log::info!("Will shutdown forcefully.");
-            active_sessions
-                .read()
-                .values()
-                .for_each(Session::force_kill_session);
+    async fn close_sessions(
+        active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
+        signal: Option<Receiver<()>>,
+    ) {
+        log::info!("Waiting for current connections to close.");
+        if let Some(mut signal) = signal {
+            let mut signal = Box::pin(signal.recv());
+
+            for _index in 0..5 {
+                if SessionManager::destroy_idle_sessions(&active_sessions) {
+                    return;
+                }
+
+                let interval = Duration::from_secs(1);
+                let sleep = Box::pin(tokio::time::sleep(interval));
+                match futures::future::select(sleep, signal).await {
+                    Either::Right((_, _)) => break,
+                    Either::Left((_, reserve_signal)) => signal = reserve_signal,
+                };
+            }
         }
+
+        log::info!("Will shutdown forcefully.");
+        active_sessions
+            .read()
+            .values()
+            .for_each(Session::force_kill_session);
     }
 
     fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Arc<Session>>>>) -> bool {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
 
 use common_datavalues::DataValue;
@@ -28,10 +29,14 @@ pub struct Settings {
 impl Settings {
     apply_macros! { apply_getter_setter_settings, apply_initial_settings, apply_update_settings,
         ("max_block_size", u64, 10000, "Maximum block size for reading"),
-        ("max_threads", u64, 16, "The maximum number of threads to execute the request. By default, it is determined automatically."),
+        ("max_threads", u64, num_cpus::get() as u64, "The maximum number of threads to execute the request. By default, it is determined automatically."),
         ("flight_client_timeout", u64, 60, "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds"),
         ("min_distributed_rows", u64, 100000000, "Minimum distributed read rows. In cluster mode, when read rows exceeds this value, the local table converted to distributed query."),
-        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query.")
+        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query."),
+        ("max_execution_time", u64, 0, "Maximum query execution time in seconds, the query is cancelled with a timeout error when it's exceeded. By default, it is 0 and unlimited."),
+        ("timezone", String, "UTC", "The timezone to evaluate the date functions in if the datetime has no timezone. By default, it is UTC."),
+        ("enable_query_log", bool, true, "Record the queries in system.query_log. By default, it is enabled."),
+        ("copy_file_format", String, "PARQUET", "The file format of COPY INTO if FILE_FORMAT has no TYPE. By default, it is PARQUET.", ["PARQUET", "CSV"])
     }
 
     pub fn try_create() -> Result<Arc<Settings>> {
@@ -40,11 +45,43 @@ impl Settings {
         });
 
         settings.initial_settings()?;
-        settings.set_max_threads(num_cpus::get() as u64)?;
-
         Ok(settings)
     }
 
+    /// A copy of the settings, changing it doesn't change this one.
+    pub fn try_clone(&self) -> Result<Arc<Settings>> {
+        Ok(Arc::new(Settings {
+            inner: self.inner.deep_clone(),
+        }))
+    }
+
+    /// Apply the values set at the level, the values which can not be applied (e.g. of an
+    /// unknown setting persisted by another version) are returned with the errors.
+    pub fn apply_settings<'a>(
+        &self,
+        values: impl IntoIterator<Item = (&'a str, &'a str)>,
+        level: ScopeLevel,
+    ) -> Vec<(String, ErrorCode)> {
+        let mut errors = vec![];
+        for (name, value) in values {
+            if let Err(cause) = self.update_settings_at_level(name, value.to_string(), level) {
+                errors.push((name.to_string(), cause));
+            }
+        }
+        errors
+    }
+
+    /// Reset the setting to its default value.
+    pub fn reset_settings(&self, key: &str) -> Result<()> {
+        self.inner.try_reset(&key.to_lowercase())
+    }
+
+    /// Check the value is valid for the setting without changing it.
+    pub fn check_settings(&self, key: &str, value: String) -> Result<()> {
+        self.try_clone()?
+            .update_settings_at_level(key, value, ScopeLevel::Session)
+    }
+
     pub fn iter(&self) -> SettingsIterator {
         SettingsIterator {
             settings: self.inner.get_settings(),
@@ -53,10 +90,56 @@ impl Settings {
     }
 }
 
+/// Where the value of a setting comes from, a value of a later level overrides the earlier ones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScopeLevel {
+    Default,
+    Global,
+    User,
+    Session,
+    Query,
+}
+
+impl fmt::Display for ScopeLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScopeLevel::Default => write!(f, "DEFAULT"),
+            ScopeLevel::Global => write!(f, "GLOBAL"),
+            ScopeLevel::User => write!(f, "USER"),
+            ScopeLevel::Session => write!(f, "SESSION"),
+            ScopeLevel::Query => write!(f, "QUERY"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingValue {
+    pub name: &'static str,
+    pub value: DataValue,
+    pub default_value: DataValue,
+    pub level: ScopeLevel,
+    pub desc: &'static str,
+    /// The values of an enum setting.
+    pub possible_values: Option<Vec<&'static str>>,
+}
+
+impl SettingValue {
+    /// The type name of the setting, shown in system.settings.
+    pub fn type_name(&self) -> &'static str {
+        match (&self.value, &self.possible_values) {
+            (_, Some(_)) => "Enum",
+            (DataValue::UInt64(_), _) => "UInt64",
+            (DataValue::Int64(_), _) => "Int64",
+            (DataValue::Float64(_), _) => "Float64",
+            (DataValue::Boolean(_), _) => "Boolean",
+            _ => "String",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SettingsBase {
-    // DataValue is of DataValue::Struct([name, value, default_value, description])
-    settings: Arc<RwLock<HashMap<&'static str, DataValue>>>,
+    settings: Arc<RwLock<HashMap<&'static str, SettingValue>>>,
 }
 
 impl SettingsBase {
@@ -66,50 +149,62 @@ impl SettingsBase {
         }
     }
 
-    // TODO, to use macro generate this codes
-    #[allow(unused)]
-    pub fn try_set_u64(&self, key: &'static str, val: u64, desc: &str) -> Result<()> {
+    fn deep_clone(&self) -> Self {
+        SettingsBase {
+            settings: Arc::new(RwLock::new(self.settings.read().clone())),
+        }
+    }
+
+    fn try_set(
+        &self,
+        key: &'static str,
+        val: DataValue,
+        desc: &'static str,
+        possible_values: Option<Vec<&'static str>>,
+    ) -> Result<()> {
         let mut settings = self.settings.write();
-        let setting_val = DataValue::Struct(vec![
-            DataValue::UInt64(Some(val)),
-            DataValue::UInt64(Some(val)),
-            DataValue::String(Some(desc.as_bytes().to_vec())),
-        ]);
-        settings.insert(key, setting_val);
+        settings.insert(key, SettingValue {
+            name: key,
+            value: val.clone(),
+            default_value: val,
+            level: ScopeLevel::Default,
+            desc,
+            possible_values,
+        });
         Ok(())
     }
 
-    #[allow(unused)]
-    pub fn try_update_u64(&self, key: &'static str, val: u64) -> Result<()> {
+    fn try_update(&self, key: &str, val: DataValue, level: ScopeLevel) -> Result<()> {
         let mut settings = self.settings.write();
-        let setting_val = settings
-            .get(key)
+        let setting = settings
+            .get_mut(key)
             .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
 
-        if let DataValue::Struct(values) = setting_val {
-            let v = DataValue::Struct(vec![
-                DataValue::UInt64(Some(val)),
-                values[1].clone(),
-                values[2].clone(),
-            ]);
-            settings.insert(key, v);
-        }
+        setting.value = val;
+        setting.level = level;
         Ok(())
     }
 
-    #[allow(unused)]
-    pub fn try_get_u64(&self, key: &str) -> Result<u64> {
+    fn try_reset(&self, key: &str) -> Result<()> {
+        let mut settings = self.settings.write();
+        let setting = settings
+            .get_mut(key)
+            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+
+        setting.value = setting.default_value.clone();
+        setting.level = ScopeLevel::Default;
+        Ok(())
+    }
+
+    fn try_get(&self, key: &str) -> Result<DataValue> {
         let settings = self.settings.read();
-        let setting_val = settings
+        let setting = settings
             .get(key)
             .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+        Ok(setting.value.clone())
+    }
 
-        if let DataValue::Struct(values) = setting_val {
-            if let DataValue::UInt64(Some(result)) = values[0].clone() {
-                return Ok(result);
-            }
-        }
-
+    fn unknown_variable<T>(key: &str) -> Result<T> {
         Result::Err(ErrorCode::UnknownVariable(format!(
             "Unknown variable: {:?}",
             key
@@ -117,179 +212,175 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_set_i64(&self, key: &'static str, val: i64, desc: &str) -> Result<()> {
-        let mut settings = self.settings.write();
-        let setting_val = DataValue::Struct(vec![
-            DataValue::Int64(Some(val)),
-            DataValue::Int64(Some(val)),
-            DataValue::String(Some(desc.as_bytes().to_vec())),
-        ]);
-        settings.insert(key, setting_val);
-        Ok(())
+    pub fn try_set_u64(
+        &self,
+        key: &'static str,
+        val: u64,
+        desc: &'static str,
+        possible_values: Option<Vec<&'static str>>,
+    ) -> Result<()> {
+        self.try_set(key, DataValue::UInt64(Some(val)), desc, possible_values)
     }
 
     #[allow(unused)]
-    pub fn try_update_i64(&self, key: &'static str, val: i64) -> Result<()> {
-        let mut settings = self.settings.write();
-        let setting_val = settings
-            .get(key)
-            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+    pub fn try_update_u64(&self, key: &str, val: u64, level: ScopeLevel) -> Result<()> {
+        self.try_update(key, DataValue::UInt64(Some(val)), level)
+    }
 
-        if let DataValue::Struct(values) = setting_val {
-            let v = DataValue::Struct(vec![
-                DataValue::Int64(Some(val)),
-                values[1].clone(),
-                values[2].clone(),
-            ]);
-            settings.insert(key, v);
+    #[allow(unused)]
+    pub fn try_get_u64(&self, key: &str) -> Result<u64> {
+        match self.try_get(key)? {
+            DataValue::UInt64(Some(result)) => Ok(result),
+            _ => Self::unknown_variable(key),
         }
-        Ok(())
     }
 
     #[allow(unused)]
-    pub fn try_get_i64(&self, key: &str) -> Result<i64> {
-        let settings = self.settings.read();
-        let setting_val = settings
-            .get(key)
-            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
-
-        if let DataValue::Struct(values) = setting_val {
-            if let DataValue::Int64(Some(result)) = values[0].clone() {
-                return Ok(result);
-            }
-        }
+    pub fn try_set_i64(
+        &self,
+        key: &'static str,
+        val: i64,
+        desc: &'static str,
+        possible_values: Option<Vec<&'static str>>,
+    ) -> Result<()> {
+        self.try_set(key, DataValue::Int64(Some(val)), desc, possible_values)
+    }
 
-        Result::Err(ErrorCode::UnknownVariable(format!(
-            "Unknown variable: {:?}",
-            key
-        )))
+    #[allow(unused)]
+    pub fn try_update_i64(&self, key: &str, val: i64, level: ScopeLevel) -> Result<()> {
+        self.try_update(key, DataValue::Int64(Some(val)), level)
     }
 
     #[allow(unused)]
-    pub fn try_set_f64(&self, key: &'static str, val: f64, desc: &str) -> Result<()> {
-        let mut settings = self.settings.write();
-        let setting_val = DataValue::Struct(vec![
-            DataValue::Float64(Some(val)),
-            DataValue::Float64(Some(val)),
-            DataValue::String(Some(desc.as_bytes().to_vec())),
-        ]);
-        settings.insert(key, setting_val);
-        Ok(())
+    pub fn try_get_i64(&self, key: &str) -> Result<i64> {
+        match self.try_get(key)? {
+            DataValue::Int64(Some(result)) => Ok(result),
+            _ => Self::unknown_variable(key),
+        }
     }
 
     #[allow(unused)]
-    pub fn try_update_f64(&self, key: &'static str, val: f64) -> Result<()> {
-        let mut settings = self.settings.write();
-        let setting_val = settings
-            .get(key)
-            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+    pub fn try_set_f64(
+        &self,
+        key: &'static str,
+        val: f64,
+        desc: &'static str,
+        possible_values: Option<Vec<&'static str>>,
+    ) -> Result<()> {
+        self.try_set(key, DataValue::Float64(Some(val)), desc, possible_values)
+    }
 
-        if let DataValue::Struct(values) = setting_val {
-            let v = DataValue::Struct(vec![
-                DataValue::Float64(Some(val)),
-                values[1].clone(),
-                values[2].clone(),
-            ]);
-            settings.insert(key, v);
-        }
-        Ok(())
+    #[allow(unused)]
+    pub fn try_update_f64(&self, key: &str, val: f64, level: ScopeLevel) -> Result<()> {
+        self.try_update(key, DataValue::Float64(Some(val)), level)
     }
 
     #[allow(unused)]
     pub fn try_get_f64(&self, key: &str) -> Result<f64> {
-        let settings = self.settings.read();
-        let setting_val = settings
-            .get(key)
-            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
-
-        if let DataValue::Struct(values) = setting_val {
-            if let DataValue::Float64(Some(result)) = values[0].clone() {
-                return Ok(result);
-            }
+        match self.try_get(key)? {
+            DataValue::Float64(Some(result)) => Ok(result),
+            _ => Self::unknown_variable(key),
         }
-
-        Result::Err(ErrorCode::UnknownVariable(format!(
-            "Unknown variable: {:?}",
-            key
-        )))
     }
 
     #[allow(unused)]
-    pub fn try_set_string(&self, key: &'static str, val: &str, desc: &str) -> Result<()> {
-        let mut settings = self.settings.write();
-        let default_value = val;
-        let setting_val = DataValue::Struct(vec![
-            DataValue::String(Some(val.as_bytes().to_vec())),
-            DataValue::String(Some(default_value.as_bytes().to_vec())),
-            DataValue::String(Some(desc.as_bytes().to_vec())),
-        ]);
-        settings.insert(key, setting_val);
-        Ok(())
+    pub fn try_set_bool(
+        &self,
+        key: &'static str,
+        val: bool,
+        desc: &'static str,
+        possible_values: Option<Vec<&'static str>>,
+    ) -> Result<()> {
+        self.try_set(key, DataValue::Boolean(Some(val)), desc, possible_values)
     }
 
     #[allow(unused)]
-    pub fn try_update_string(&self, key: &'static str, val: &str) -> Result<()> {
-        let mut settings = self.settings.write();
-        let setting_val = settings
-            .get(key)
-            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+    pub fn try_update_bool(&self, key: &str, val: bool, level: ScopeLevel) -> Result<()> {
+        self.try_update(key, DataValue::Boolean(Some(val)), level)
+    }
 
-        if let DataValue::Struct(values) = setting_val {
-            let v = DataValue::Struct(vec![
-                DataValue::String(Some(val.as_bytes().to_vec())),
-                values[1].clone(),
-                values[2].clone(),
-            ]);
-            settings.insert(key, v);
+    #[allow(unused)]
+    pub fn try_get_bool(&self, key: &str) -> Result<bool> {
+        match self.try_get(key)? {
+            DataValue::Boolean(Some(result)) => Ok(result),
+            _ => Self::unknown_variable(key),
         }
-        Ok(())
     }
 
     #[allow(unused)]
-    pub fn try_get_string(&self, key: &str) -> Result<Vec<u8>> {
-        let settings = self.settings.read();
-        let setting_val = settings
-            .get(key)
-            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+    pub fn try_set_string(
+        &self,
+        key: &'static str,
+        val: &str,
+        desc: &'static str,
+        possible_values: Option<Vec<&'static str>>,
+    ) -> Result<()> {
+        let val = DataValue::String(Some(val.as_bytes().to_vec()));
+        self.try_set(key, val, desc, possible_values)
+    }
 
-        if let DataValue::Struct(values) = setting_val {
-            if let DataValue::String(Some(result)) = values[0].clone() {
-                return Ok(result);
-            }
-        }
+    /// The value of an enum setting is one of the possible values, ignoring the case.
+    #[allow(unused)]
+    pub fn try_update_string(&self, key: &str, val: String, level: ScopeLevel) -> Result<()> {
+        let possible_values = match self.settings.read().get(key) {
+            None => return Self::unknown_variable(key),
+            Some(setting) => setting.possible_values.clone(),
+        };
+
+        let val = match possible_values {
+            None => val,
+            Some(possible_values) => possible_values
+                .iter()
+                .find(|possible| possible.eq_ignore_ascii_case(&val))
+                .map(|possible| possible.to_string())
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid value {:?} of setting {:?}, possible values: {}",
+                        val,
+                        key,
+                        possible_values.join(", ")
+                    ))
+                })?,
+        };
+
+        self.try_update(key, DataValue::String(Some(val.into_bytes())), level)
+    }
 
-        Result::Err(ErrorCode::UnknownVariable(format!(
-            "Unknown variable: {:?}",
-            key
-        )))
+    #[allow(unused)]
+    pub fn try_get_string(&self, key: &str) -> Result<String> {
+        match self.try_get(key)? {
+            DataValue::String(Some(result)) => String::from_utf8(result).map_err(ErrorCode::from),
+            _ => Self::unknown_variable(key),
+        }
     }
 
-    pub fn get_settings(&self) -> Vec<DataValue> {
+    pub fn get_settings(&self) -> Vec<SettingValue> {
         let settings = self.settings.read();
-
-        let mut result = vec![];
-        for (k, v) in settings.iter() {
-            if let DataValue::Struct(values) = v {
-                let res = DataValue::Struct(vec![
-                    DataValue::String(Some(k.as_bytes().to_vec())),
-                    values[0].clone(),
-                    values[1].clone(),
-                    values[2].clone(),
-                ]);
-                result.push(res);
-            }
-        }
+        let mut result = settings.values().cloned().collect::<Vec<_>>();
+        result.sort_by(|a, b| a.name.cmp(b.name));
         result
     }
 }
 
+/// Parse the value of a bool setting, 1, 0, true and false are accepted.
+pub(in crate::sessions) fn parse_bool_setting(key: &str, value: &str) -> Result<bool> {
+    match value.to_lowercase().as_str() {
+        "1" | "true" => Ok(true),
+        "0" | "false" => Ok(false),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "Invalid value {:?} of setting {:?}, it must be 1, 0, true or false",
+            value, key
+        ))),
+    }
+}
+
 pub struct SettingsIterator {
-    settings: Vec<DataValue>,
+    settings: Vec<SettingValue>,
     index: usize,
 }
 
 impl Iterator for SettingsIterator {
-    type Item = DataValue;
+    type Item = SettingValue;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index == self.settings.len() {
@@ -0,0 +1,127 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::sessions::ScopeLevel;
+use crate::sessions::Settings;
+
+fn setting_level(settings: &Settings, name: &str) -> ScopeLevel {
+    settings
+        .iter()
+        .find(|setting| setting.name == name)
+        .map(|setting| setting.level)
+        .unwrap()
+}
+
+#[test]
+fn test_settings_levels() -> Result<()> {
+    let settings = Settings::try_create()?;
+    assert_eq!(
+        setting_level(&settings, "max_block_size"),
+        ScopeLevel::Default
+    );
+
+    settings.update_settings_at_level("max_block_size", "100".to_string(), ScopeLevel::Global)?;
+    assert_eq!(settings.get_max_block_size()?, 100);
+    assert_eq!(
+        setting_level(&settings, "max_block_size"),
+        ScopeLevel::Global
+    );
+
+    settings.update_settings("MAX_BLOCK_SIZE", "200".to_string())?;
+    assert_eq!(settings.get_max_block_size()?, 200);
+    assert_eq!(
+        setting_level(&settings, "max_block_size"),
+        ScopeLevel::Session
+    );
+
+    settings.reset_settings("max_block_size")?;
+    assert_eq!(settings.get_max_block_size()?, 10000);
+    assert_eq!(
+        setting_level(&settings, "max_block_size"),
+        ScopeLevel::Default
+    );
+
+    // The copy doesn't change the settings.
+    let query_settings = settings.try_clone()?;
+    query_settings.update_settings_at_level(
+        "max_block_size",
+        "1".to_string(),
+        ScopeLevel::Query,
+    )?;
+    assert_eq!(query_settings.get_max_block_size()?, 1);
+    assert_eq!(settings.get_max_block_size()?, 10000);
+    Ok(())
+}
+
+#[test]
+fn test_settings_types() -> Result<()> {
+    let settings = Settings::try_create()?;
+
+    assert!(settings.get_enable_query_log()?);
+    settings.update_settings("enable_query_log", "0".to_string())?;
+    assert!(!settings.get_enable_query_log()?);
+    settings.update_settings("enable_query_log", "TRUE".to_string())?;
+    assert!(settings.get_enable_query_log()?);
+    let res = settings.update_settings("enable_query_log", "yes".to_string());
+    assert_eq!(res.unwrap_err().code(), 6);
+
+    // The enum values are case insensitive, the canonical variant is stored.
+    assert_eq!(settings.get_copy_file_format()?, "PARQUET");
+    settings.update_settings("copy_file_format", "csv".to_string())?;
+    assert_eq!(settings.get_copy_file_format()?, "CSV");
+    let res = settings.update_settings("copy_file_format", "json".to_string());
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Invalid value \"json\" of setting \"copy_file_format\", possible values: PARQUET, CSV"
+    );
+    assert_eq!(settings.get_copy_file_format()?, "CSV");
+
+    let res = settings.update_settings("max_threads", "-1".to_string());
+    assert!(res.is_err());
+    let res = settings.update_settings("unknown", "1".to_string());
+    assert_eq!(res.unwrap_err().code(), 20);
+
+    let types = settings
+        .iter()
+        .map(|setting| (setting.name, setting.type_name()))
+        .collect::<Vec<_>>();
+    assert!(types.contains(&("copy_file_format", "Enum")));
+    assert!(types.contains(&("enable_query_log", "Boolean")));
+    assert!(types.contains(&("max_threads", "UInt64")));
+    assert!(types.contains(&("timezone", "String")));
+    Ok(())
+}
+
+#[test]
+fn test_settings_apply() -> Result<()> {
+    let settings = Settings::try_create()?;
+    let values = vec![
+        ("max_threads", "2"),
+        ("no_such_setting", "1"),
+        ("enable_query_log", "x"),
+    ];
+
+    let errors = settings.apply_settings(values, ScopeLevel::User);
+    let names = errors
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["no_such_setting", "enable_query_log"]);
+    assert_eq!(settings.get_max_threads()?, 2);
+    assert_eq!(setting_level(&settings, "max_threads"), ScopeLevel::User);
+    Ok(())
+}
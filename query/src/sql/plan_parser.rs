@@ -16,12 +16,15 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datablocks::ParquetWriteOptions;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_infallible::Mutex;
+use common_management::GrantObject;
 use common_planners::expand_aggregate_arg_exprs;
+use common_planners::expand_grouping_sets;
 use common_planners::expand_wildcard;
 use common_planners::expr_as_column_expr;
 use common_planners::extract_aliases;
@@ -29,27 +32,46 @@ use common_planners::find_aggregate_exprs;
 use common_planners::find_columns_not_satisfy_exprs;
 use common_planners::rebase_expr;
 use common_planners::rebase_expr_from_input;
+use common_planners::rebase_grouping_exprs;
 use common_planners::resolve_aliases_to_exprs;
 use common_planners::sort_to_inner_expr;
 use common_planners::unwrap_alias_exprs;
+use common_planners::AlterUserPlan;
+use common_planners::CopyIntoPlan;
 use common_planners::CreateDatabasePlan;
+use common_planners::CreateQuotaPlan;
+use common_planners::CreateResourceGroupPlan;
+use common_planners::CreateRolePlan;
 use common_planners::CreateTablePlan;
+use common_planners::CreateUserPlan;
 use common_planners::DescribeTablePlan;
 use common_planners::DropDatabasePlan;
+use common_planners::DropQuotaPlan;
+use common_planners::DropResourceGroupPlan;
+use common_planners::DropRolePlan;
 use common_planners::DropTablePlan;
+use common_planners::DropUserPlan;
 use common_planners::ExplainPlan;
 use common_planners::Expression;
+use common_planners::GrantPrivilegePlan;
+use common_planners::GrantResourceGroupPlan;
+use common_planners::GrantRolePlan;
 use common_planners::InsertIntoPlan;
 use common_planners::KillPlan;
 use common_planners::PlanBuilder;
 use common_planners::PlanNode;
+use common_planners::PrincipalIdentity;
+use common_planners::RevokePrivilegePlan;
+use common_planners::RevokeRolePlan;
 use common_planners::SelectPlan;
 use common_planners::SettingPlan;
+use common_planners::SettingScope;
 use common_planners::ShowCreateTablePlan;
 use common_planners::TableScanInfo;
 use common_planners::TruncateTablePlan;
 use common_planners::UseDatabasePlan;
 use common_planners::VarValue;
+use common_planners::GROUPING_ID_COLUMN_NAME;
 use common_streams::Source;
 use common_streams::ValueSource;
 use common_tracing::tracing;
@@ -69,19 +91,37 @@ use crate::sessions::DatabendQueryContextRef;
 use crate::sql::sql_statement::DfCreateTable;
 use crate::sql::sql_statement::DfDropDatabase;
 use crate::sql::sql_statement::DfUseDatabase;
+use crate::sql::DfAlterUser;
+use crate::sql::DfCopyInto;
 use crate::sql::DfCreateDatabase;
+use crate::sql::DfCreateQuota;
+use crate::sql::DfCreateResourceGroup;
+use crate::sql::DfCreateRole;
+use crate::sql::DfCreateUser;
 use crate::sql::DfDescribeTable;
+use crate::sql::DfDropQuota;
+use crate::sql::DfDropResourceGroup;
+use crate::sql::DfDropRole;
 use crate::sql::DfDropTable;
+use crate::sql::DfDropUser;
 use crate::sql::DfExplain;
+use crate::sql::DfGrantObject;
+use crate::sql::DfGrantPrivilege;
+use crate::sql::DfGrantResourceGroup;
+use crate::sql::DfGrantRole;
 use crate::sql::DfHint;
 use crate::sql::DfKillStatement;
 use crate::sql::DfParser;
+use crate::sql::DfPrincipal;
+use crate::sql::DfRevokePrivilege;
+use crate::sql::DfRevokeRole;
 use crate::sql::DfShowCreateTable;
 use crate::sql::DfShowDatabases;
 use crate::sql::DfShowTables;
 use crate::sql::DfStatement;
 use crate::sql::DfTruncateTable;
 use crate::sql::SQLCommon;
+use crate::users::encode_password;
 
 pub struct PlanParser {
     ctx: DatabendQueryContextRef,
@@ -92,6 +132,7 @@ impl PlanParser {
         Self { ctx }
     }
 
+    #[tracing::instrument(level = "info", skip(self, query), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub fn build_from_sql(&self, query: &str) -> Result<PlanNode> {
         tracing::debug!(query);
         DfParser::parse_sql(query).and_then(|(stmts, _)| {
@@ -104,6 +145,7 @@ impl PlanParser {
         })
     }
 
+    #[tracing::instrument(level = "info", skip(self, query), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub fn build_with_hint_from_sql(&self, query: &str) -> (Result<PlanNode>, Vec<DfHint>) {
         tracing::debug!(query);
         let stmt_hints = DfParser::parse_sql(query);
@@ -132,6 +174,7 @@ impl PlanParser {
             DfStatement::TruncateTable(v) => self.sql_truncate_table_to_plan(v),
             DfStatement::UseDatabase(v) => self.sql_use_database_to_plan(v),
             DfStatement::ShowCreateTable(v) => self.sql_show_create_table_to_plan(v),
+            DfStatement::CopyInto(v) => self.sql_copy_into_to_plan(v),
             DfStatement::ShowTables(df) => {
                 let show_sql = match df {
                     DfShowTables::All => {
@@ -162,11 +205,33 @@ impl PlanParser {
                 self.build_from_sql(show_sql.as_str())
             }
             DfStatement::ShowSettings(_) => self.build_from_sql("SELECT name FROM system.settings"),
+            DfStatement::SetSettings(v) => Ok(PlanNode::SetVariable(SettingPlan {
+                scope: v.scope.clone(),
+                vars: v.vars.clone(),
+            })),
+            DfStatement::WithSettings(v) => {
+                self.ctx.apply_query_settings(&v.settings)?;
+                self.statement_to_plan(&v.statement)
+            }
             DfStatement::ShowProcessList(_) => {
                 self.build_from_sql("SELECT * FROM system.processes")
             }
             DfStatement::KillQuery(v) => self.sql_kill_query_to_plan(v),
             DfStatement::KillConn(v) => self.sql_kill_connection_to_plan(v),
+            DfStatement::CreateUser(v) => self.sql_create_user_to_plan(v),
+            DfStatement::AlterUser(v) => self.sql_alter_user_to_plan(v),
+            DfStatement::DropUser(v) => self.sql_drop_user_to_plan(v),
+            DfStatement::CreateRole(v) => self.sql_create_role_to_plan(v),
+            DfStatement::DropRole(v) => self.sql_drop_role_to_plan(v),
+            DfStatement::GrantPrivilege(v) => self.sql_grant_privilege_to_plan(v),
+            DfStatement::RevokePrivilege(v) => self.sql_revoke_privilege_to_plan(v),
+            DfStatement::GrantRole(v) => self.sql_grant_role_to_plan(v),
+            DfStatement::RevokeRole(v) => self.sql_revoke_role_to_plan(v),
+            DfStatement::CreateQuota(v) => self.sql_create_quota_to_plan(v),
+            DfStatement::DropQuota(v) => self.sql_drop_quota_to_plan(v),
+            DfStatement::CreateResourceGroup(v) => self.sql_create_resource_group_to_plan(v),
+            DfStatement::DropResourceGroup(v) => self.sql_drop_resource_group_to_plan(v),
+            DfStatement::GrantResourceGroup(v) => self.sql_grant_resource_group_to_plan(v),
         }
     }
 
@@ -282,6 +347,154 @@ impl PlanParser {
         }))
     }
 
+    /// DfCreateUser to plan, the password is encoded by the auth type here.
+    #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_create_user_to_plan(&self, create: &DfCreateUser) -> Result<PlanNode> {
+        Ok(PlanNode::CreateUser(CreateUserPlan {
+            if_not_exists: create.if_not_exists,
+            name: create.name.clone(),
+            password: encode_password(&create.auth_type, &create.password),
+            auth_type: create.auth_type.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, alter), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_alter_user_to_plan(&self, alter: &DfAlterUser) -> Result<PlanNode> {
+        Ok(PlanNode::AlterUser(AlterUserPlan {
+            name: alter.name.clone(),
+            password: encode_password(&alter.auth_type, &alter.password),
+            auth_type: alter.auth_type.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, drop), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_drop_user_to_plan(&self, drop: &DfDropUser) -> Result<PlanNode> {
+        Ok(PlanNode::DropUser(DropUserPlan {
+            if_exists: drop.if_exists,
+            name: drop.name.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_create_role_to_plan(&self, create: &DfCreateRole) -> Result<PlanNode> {
+        Ok(PlanNode::CreateRole(CreateRolePlan {
+            if_not_exists: create.if_not_exists,
+            name: create.name.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, drop), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_drop_role_to_plan(&self, drop: &DfDropRole) -> Result<PlanNode> {
+        Ok(PlanNode::DropRole(DropRolePlan {
+            if_exists: drop.if_exists,
+            name: drop.name.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, grant), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_grant_privilege_to_plan(&self, grant: &DfGrantPrivilege) -> Result<PlanNode> {
+        Ok(PlanNode::GrantPrivilege(GrantPrivilegePlan {
+            principal: Self::principal_identity(&grant.principal),
+            on: self.grant_object(&grant.on),
+            privileges: grant.privileges,
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, revoke), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_revoke_privilege_to_plan(&self, revoke: &DfRevokePrivilege) -> Result<PlanNode> {
+        Ok(PlanNode::RevokePrivilege(RevokePrivilegePlan {
+            principal: Self::principal_identity(&revoke.principal),
+            on: self.grant_object(&revoke.on),
+            privileges: revoke.privileges,
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, grant), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_grant_role_to_plan(&self, grant: &DfGrantRole) -> Result<PlanNode> {
+        Ok(PlanNode::GrantRole(GrantRolePlan {
+            role: grant.role.clone(),
+            user: grant.user.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, revoke), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_revoke_role_to_plan(&self, revoke: &DfRevokeRole) -> Result<PlanNode> {
+        Ok(PlanNode::RevokeRole(RevokeRolePlan {
+            role: revoke.role.clone(),
+            user: revoke.user.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_create_quota_to_plan(&self, create: &DfCreateQuota) -> Result<PlanNode> {
+        Ok(PlanNode::CreateQuota(CreateQuotaPlan {
+            principal: Self::principal_identity(&create.principal),
+            quota: create.quota.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, drop), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_drop_quota_to_plan(&self, drop: &DfDropQuota) -> Result<PlanNode> {
+        Ok(PlanNode::DropQuota(DropQuotaPlan {
+            principal: Self::principal_identity(&drop.principal),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_create_resource_group_to_plan(
+        &self,
+        create: &DfCreateResourceGroup,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateResourceGroup(CreateResourceGroupPlan {
+            if_not_exists: create.if_not_exists,
+            name: create.name.clone(),
+            max_concurrency: create.max_concurrency,
+            max_queued: create.max_queued,
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, drop), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_drop_resource_group_to_plan(&self, drop: &DfDropResourceGroup) -> Result<PlanNode> {
+        Ok(PlanNode::DropResourceGroup(DropResourceGroupPlan {
+            if_exists: drop.if_exists,
+            name: drop.name.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, grant), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_grant_resource_group_to_plan(
+        &self,
+        grant: &DfGrantResourceGroup,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::GrantResourceGroup(GrantResourceGroupPlan {
+            name: grant.name.clone(),
+            principal: Self::principal_identity(&grant.principal),
+        }))
+    }
+
+    // Resolve the omitted database of the grant object to the current database.
+    fn grant_object(&self, object: &DfGrantObject) -> GrantObject {
+        match object {
+            DfGrantObject::Global => GrantObject::Global,
+            DfGrantObject::Database(db) => GrantObject::Database(
+                db.clone()
+                    .unwrap_or_else(|| self.ctx.get_current_database()),
+            ),
+            DfGrantObject::Table(db, table) => GrantObject::Table(
+                db.clone()
+                    .unwrap_or_else(|| self.ctx.get_current_database()),
+                table.clone(),
+            ),
+        }
+    }
+
+    fn principal_identity(principal: &DfPrincipal) -> PrincipalIdentity {
+        match principal {
+            DfPrincipal::User(name) => PrincipalIdentity::User(name.clone()),
+            DfPrincipal::Role(name) => PrincipalIdentity::Role(name.clone()),
+        }
+    }
+
     #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub fn sql_create_table_to_plan(&self, create: &DfCreateTable) -> Result<PlanNode> {
         let mut db = self.ctx.get_current_database();
@@ -314,6 +527,9 @@ impl PlanParser {
             );
         }
 
+        // Rejects the invalid parquet writing options early
+        ParquetWriteOptions::from_table_options(&options)?;
+
         let schema = DataSchemaRefExt::create(fields);
         Ok(PlanNode::CreateTable(CreateTablePlan {
             if_not_exists: create.if_not_exists,
@@ -418,6 +634,60 @@ impl PlanParser {
         Ok(PlanNode::TruncateTable(TruncateTablePlan { db, table }))
     }
 
+    /// DfCopyInto to plan.
+    #[tracing::instrument(level = "info", skip(self, copy), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_copy_into_to_plan(&self, copy: &DfCopyInto) -> Result<PlanNode> {
+        let input = match self.query_to_plan(&copy.query)? {
+            PlanNode::Select(select) => select.input,
+            other => Arc::new(other),
+        };
+
+        let option_value = |value: &sqlparser::ast::Value| {
+            value
+                .to_string()
+                .trim_matches(|s| s == '\'' || s == '"')
+                .to_string()
+        };
+
+        let mut format_options = HashMap::new();
+        for p in copy.file_format.iter() {
+            format_options.insert(p.name.value.to_lowercase(), option_value(&p.value));
+        }
+
+        let file_format = match format_options.remove("type") {
+            Some(file_format) => file_format.to_uppercase(),
+            None => self.ctx.get_settings().get_copy_file_format()?,
+        };
+        if file_format != "PARQUET" && file_format != "CSV" {
+            return Result::Err(ErrorCode::SyntaxException(format!(
+                "Unsupported file format for COPY INTO: {}",
+                file_format
+            )));
+        }
+
+        // 16MB by default.
+        let mut max_file_size = 16 * 1024 * 1024;
+        for p in copy.options.iter() {
+            match p.name.value.to_lowercase().as_str() {
+                "max_file_size" => max_file_size = option_value(&p.value).parse::<u64>()?,
+                other => {
+                    return Result::Err(ErrorCode::SyntaxException(format!(
+                        "Unsupported option for COPY INTO: {}",
+                        other
+                    )))
+                }
+            }
+        }
+
+        Ok(PlanNode::CopyInto(CopyIntoPlan {
+            location: copy.location.clone(),
+            file_format,
+            format_options,
+            max_file_size,
+            input,
+        }))
+    }
+
     #[tracing::instrument(level = "info", skip(self, table_name, columns, source), fields(ctx.id = self.ctx.get_id().as_str()))]
     fn insert_to_plan(
         &self,
@@ -539,6 +809,18 @@ impl PlanParser {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Grouping sets of ROLLUP, CUBE and GROUPING SETS over the distinct group by expressions
+        // In example: ROLLUP(a, b) => GroupBy=[a, b], GroupingSets=[[0, 1], [0], []]
+        let (group_by_exprs, grouping_sets) = expand_grouping_sets(&group_by_exprs)?;
+        let has_grouping_sets = !grouping_sets.is_empty();
+
+        // Grouping function against the group by expressions
+        // In example: grouping(a) => grouping(_grouping_id, 0)
+        let projection_exprs = projection_exprs
+            .iter()
+            .map(|expr| rebase_grouping_exprs(expr, &group_by_exprs, &aliases, has_grouping_sets))
+            .collect::<Result<Vec<_>>>()?;
+
         // Having Expression after against aliases
         // In example: Having=((number % 3) > 1)
         let having_expr_opt = select
@@ -547,6 +829,12 @@ impl PlanParser {
             .map::<Result<Expression>, _>(|having_expr| {
                 let having_expr = self.sql_to_rex(having_expr, &plan.schema(), Some(select))?;
                 let having_expr = resolve_aliases_to_exprs(&having_expr, &aliases)?;
+                let having_expr = rebase_grouping_exprs(
+                    &having_expr,
+                    &group_by_exprs,
+                    &aliases,
+                    has_grouping_sets,
+                )?;
 
                 Ok(having_expr)
             })
@@ -560,7 +848,15 @@ impl PlanParser {
                 Ok(Expression::Sort {
                     expr: Box::new(
                         self.sql_to_rex(&e.expr, &plan.schema(), Some(select))
-                            .and_then(|expr| resolve_aliases_to_exprs(&expr, &aliases))?,
+                            .and_then(|expr| resolve_aliases_to_exprs(&expr, &aliases))
+                            .and_then(|expr| {
+                                rebase_grouping_exprs(
+                                    &expr,
+                                    &group_by_exprs,
+                                    &aliases,
+                                    has_grouping_sets,
+                                )
+                            })?,
                     ),
                     asc: e.asc.unwrap_or(true),
                     nulls_first: e.nulls_first.unwrap_or(true),
@@ -583,7 +879,7 @@ impl PlanParser {
         // In example: aggr=[[sum((number + 1))]]
         let aggr_exprs = find_aggregate_exprs(&expression_exprs);
 
-        let has_aggr = aggr_exprs.len() + group_by_exprs.len() > 0;
+        let has_aggr = aggr_exprs.len() + group_by_exprs.len() > 0 || has_grouping_sets;
         let (plan, having_expr_post_aggr_opt) = if has_aggr {
             let aggr_projection_exprs = group_by_exprs
                 .iter()
@@ -598,14 +894,20 @@ impl PlanParser {
             // inner expression=[(number + 1), (number % 3)]
             let plan = self
                 .expression(&plan, &before_aggr_exprs, "Before GroupBy")
-                .and_then(|input| self.aggregate(&input, &aggr_exprs, &group_by_exprs))?;
+                .and_then(|input| {
+                    self.aggregate(&input, &aggr_exprs, &group_by_exprs, &grouping_sets)
+                })?;
 
             // After aggregation, these are all of the columns that will be
             // available to next phases of planning.
-            let column_exprs_post_aggr = aggr_projection_exprs
+            let mut column_exprs_post_aggr = aggr_projection_exprs
                 .iter()
                 .map(|expr| expr_as_column_expr(expr))
                 .collect::<Result<Vec<_>>>()?;
+            if has_grouping_sets {
+                column_exprs_post_aggr
+                    .push(Expression::Column(GROUPING_ID_COLUMN_NAME.to_string()));
+            }
 
             // Rewrite the SELECT expression to use the columns produced by the aggregation.
             // In example:[col("number + 1"), col("number % 3")]
@@ -940,6 +1242,62 @@ impl PlanParser {
         }
     }
 
+    /// toDateTime64(expr[, precision[, timezone]]) is a cast to DateTime64, the precision and
+    /// the timezone must be literals.
+    fn datetime64_cast_to_rex(mut args: Vec<Expression>) -> Result<Expression> {
+        if args.is_empty() || args.len() > 3 {
+            return Result::Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "toDateTime64 expect 1 to 3 arguments, but got {}",
+                args.len()
+            )));
+        }
+
+        let tz = match args.len() {
+            3 => match args.pop() {
+                Some(Expression::Literal {
+                    value: DataValue::String(Some(tz)),
+                    ..
+                }) => {
+                    let tz = String::from_utf8(tz)?;
+                    parse_timezone(&tz)?;
+                    Some(tz)
+                }
+                other => {
+                    return Result::Err(ErrorCode::BadArguments(format!(
+                        "The timezone of toDateTime64 must be a string literal, but got {:?}",
+                        other
+                    )))
+                }
+            },
+            _ => None,
+        };
+        let precision = match args.len() {
+            2 => match args.pop() {
+                Some(Expression::Literal { value, .. }) if is_integer(&value.data_type()) => {
+                    value.as_u64()?
+                }
+                other => {
+                    return Result::Err(ErrorCode::BadArguments(format!(
+                        "The precision of toDateTime64 must be an integer literal, but got {:?}",
+                        other
+                    )))
+                }
+            },
+            _ => 3,
+        };
+        if precision > DATETIME64_MAX_PRECISION as u64 {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "The precision of toDateTime64 must be in the range [0, {}], but got {}",
+                DATETIME64_MAX_PRECISION, precision
+            )));
+        }
+
+        Ok(Expression::Cast {
+            expr: Box::new(args.remove(0)),
+            data_type: DataType::DateTime64(precision as u8, tz),
+        })
+    }
+
     fn value_to_rex(value: &sqlparser::ast::Value) -> Result<Expression> {
         match value {
             sqlparser::ast::Value::Number(ref n, _) => {
@@ -1024,6 +1382,63 @@ impl PlanParser {
                 op: "EXISTS".to_lowercase(),
                 args: vec![self.subquery_to_rex(q)?],
             }),
+            sqlparser::ast::Expr::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let mut args = Vec::with_capacity(list.len() + 1);
+                args.push(self.sql_to_rex(expr, schema, select)?);
+                for item in list {
+                    args.push(self.sql_to_rex(item, schema, select)?);
+                }
+                Ok(Expression::ScalarFunction {
+                    op: Self::in_function_name(*negated),
+                    args,
+                })
+            }
+            sqlparser::ast::Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => Ok(Expression::ScalarFunction {
+                op: Self::in_function_name(*negated),
+                args: vec![
+                    self.sql_to_rex(expr, schema, select)?,
+                    self.subquery_to_rex(subquery)?,
+                ],
+            }),
+            sqlparser::ast::Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                // CASE [x] WHEN c1 THEN r1 ... ELSE e END => multiIf([x =] c1, r1, ..., e)
+                let operand = match operand {
+                    Some(operand) => Some(self.sql_to_rex(operand, schema, select)?),
+                    None => None,
+                };
+
+                let mut args = Vec::with_capacity(conditions.len() * 2 + 1);
+                for (condition, result) in conditions.iter().zip(results.iter()) {
+                    let condition = self.sql_to_rex(condition, schema, select)?;
+                    args.push(match &operand {
+                        Some(operand) => operand.eq(condition),
+                        None => condition,
+                    });
+                    args.push(self.sql_to_rex(result, schema, select)?);
+                }
+                args.push(match else_result {
+                    Some(else_result) => self.sql_to_rex(else_result, schema, select)?,
+                    None => Expression::create_literal(DataValue::Null),
+                });
+
+                Ok(Expression::ScalarFunction {
+                    op: "multiIf".to_string(),
+                    args,
+                })
+            }
             sqlparser::ast::Expr::Subquery(q) => Ok(self.scalar_subquery_to_rex(q)?),
             sqlparser::ast::Expr::Nested(e) => self.sql_to_rex(e, schema, select),
             sqlparser::ast::Expr::CompoundIdentifier(ids) => {
@@ -1054,7 +1469,17 @@ impl PlanParser {
                     }
                 }
 
+                // 3. Get the trailing args from context. such as the session timezone
+                let trailing_args = ContextFunction::build_trailing_args_from_ctx(
+                    e.name.to_string().as_str(),
+                    self.ctx.clone(),
+                )?;
+                args.extend(trailing_args);
+
                 let op = e.name.to_string();
+                if op.eq_ignore_ascii_case("toDateTime64") {
+                    return Self::datetime64_cast_to_rex(args);
+                }
                 if AggregateFunctionFactory::check(&op) {
                     let args = match op.to_lowercase().as_str() {
                         "count" => args
@@ -1156,6 +1581,13 @@ impl PlanParser {
         }
     }
 
+    fn in_function_name(negated: bool) -> String {
+        match negated {
+            true => "notIn".to_string(),
+            false => "in".to_string(),
+        }
+    }
+
     pub fn subquery_to_rex(&self, subquery: &Query) -> Result<Expression> {
         let subquery = self.query_to_plan(subquery)?;
         let subquery_name = self.ctx.get_subquery_name(&subquery);
@@ -1181,14 +1613,27 @@ impl PlanParser {
     ) -> Result<PlanNode> {
         let mut vars = vec![];
         for value in values {
-            let variable = variable.value.clone();
-            let value = match value {
-                sqlparser::ast::SetVariableValue::Ident(v) => v.value.clone(),
-                sqlparser::ast::SetVariableValue::Literal(v) => v.to_string(),
+            let variable = &variable.value;
+            let var = match value {
+                sqlparser::ast::SetVariableValue::Ident(v)
+                    if v.quote_style.is_none() && v.value.eq_ignore_ascii_case("DEFAULT") =>
+                {
+                    VarValue::create_default(variable)
+                }
+                sqlparser::ast::SetVariableValue::Ident(v) => VarValue::create(variable, &v.value),
+                sqlparser::ast::SetVariableValue::Literal(
+                    sqlparser::ast::Value::SingleQuotedString(v),
+                ) => VarValue::create(variable, v),
+                sqlparser::ast::SetVariableValue::Literal(v) => {
+                    VarValue::create(variable, &v.to_string())
+                }
             };
-            vars.push(VarValue { variable, value });
+            vars.push(var);
         }
-        Ok(PlanNode::SetVariable(SettingPlan { vars }))
+        Ok(PlanNode::SetVariable(SettingPlan {
+            scope: SettingScope::Session,
+            vars,
+        }))
     }
 
     /// Apply a filter to the plan
@@ -1239,6 +1684,7 @@ impl PlanParser {
         input: &PlanNode,
         aggr_exprs: &[Expression],
         group_by_exprs: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<PlanNode> {
         let aggr_exprs = aggr_exprs
             .iter()
@@ -1254,9 +1700,14 @@ impl PlanParser {
         // S1: Apply a fragment plan for distributed planners split.
         // S2: Apply a final aggregator plan.
         PlanBuilder::from(input)
-            .aggregate_partial(&aggr_exprs, &group_by_exprs)
+            .aggregate_grouping_sets_partial(&aggr_exprs, &group_by_exprs, grouping_sets)
             .and_then(|builder| {
-                builder.aggregate_final(input.schema(), &aggr_exprs, &group_by_exprs)
+                builder.aggregate_grouping_sets_final(
+                    input.schema(),
+                    &aggr_exprs,
+                    &group_by_exprs,
+                    grouping_sets,
+                )
             })
             .and_then(|builder| builder.build())
     }
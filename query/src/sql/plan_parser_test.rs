@@ -39,6 +39,18 @@ fn test_plan_parser() -> Result<()> {
             expect: "Create database db1, engine: Default, if_not_exists:true, option: {}",
             error: "",
         },
+        Test {
+            name: "create-table-datetime64-passed",
+            sql: "CREATE TABLE t(c1 DateTime64(6, 'Asia/Shanghai'), c2 DateTime64) ENGINE = Null",
+            expect: "Create table default.t DataField { name: \"c1\", data_type: DateTime64(6, \"Asia/Shanghai\"), nullable: false }, DataField { name: \"c2\", data_type: DateTime64(3), nullable: false }, engine: Null, if_not_exists:false, option: {}",
+            error: "",
+        },
+        Test {
+            name: "create-table-datetime64-unknown-timezone",
+            sql: "CREATE TABLE t(c1 DateTime64(6, 'Mars/Olympus')) ENGINE = Null",
+            expect: "",
+            error: "Code: 6, displayText = Unknown timezone: Mars/Olympus.",
+        },
         Test {
             name: "drop-database-passed",
             sql: "DROP DATABASE db1",
@@ -63,6 +75,30 @@ fn test_plan_parser() -> Result<()> {
             expect: "Create table default.t DataField { name: \"c1\", data_type: Int32, nullable: false }, DataField { name: \"c2\", data_type: Int64, nullable: false }, DataField { name: \"c3\", data_type: String, nullable: false }, engine: Parquet, if_not_exists:true, option: {\"location\": \"foo.parquet\"}",
             error: "",
         },
+        Test {
+            name: "create-table-with-decimal-passed",
+            sql: "CREATE TABLE t(c1 decimal(12, 2), c2 decimal) ENGINE = Parquet location = 'foo.parquet' ",
+            expect: "Create table default.t DataField { name: \"c1\", data_type: Decimal(12, 2), nullable: false }, DataField { name: \"c2\", data_type: Decimal(10, 0), nullable: false }, engine: Parquet, if_not_exists:false, option: {\"location\": \"foo.parquet\"}",
+            error: "",
+        },
+        Test {
+            name: "create-table-with-too-large-decimal",
+            sql: "CREATE TABLE t(c1 decimal(38, 2)) ENGINE = Parquet location = 'foo.parquet' ",
+            expect: "",
+            error: "Code: 7, displayText = The SQL data type Decimal(Some(38), Some(2)) is not implemented, the max precision of decimal is 18.",
+        },
+        Test {
+            name: "create-table-with-parquet-options-passed",
+            sql: "CREATE TABLE t(c1 int) ENGINE = FUSE compression = 'zstd'",
+            expect: "Create table default.t DataField { name: \"c1\", data_type: Int32, nullable: false }, engine: FUSE, if_not_exists:false, option: {\"compression\": \"zstd\"}",
+            error: "",
+        },
+        Test {
+            name: "create-table-with-unsupported-compression",
+            sql: "CREATE TABLE t(c1 int) ENGINE = FUSE compression = 'gzip'",
+            expect: "",
+            error: "Code: 22, displayText = Unsupported compression: gzip, expects one of 'none', 'snappy', 'lz4', 'zstd'.",
+        },
         Test {
             name: "drop-table-passed",
             sql: "DROP TABLE t1",
@@ -199,6 +235,141 @@ fn test_plan_parser() -> Result<()> {
             \n  Filter: (NULL AND true)\
             \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
             error: "",
+        },
+        Test {
+            name: "in-list-passed",
+            sql: "select * from numbers(10) where number not in (1, 2)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: notIn(number, 1, 2)\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "case-when-passed",
+            sql: "select * from numbers(10) where case number when 1 then true end",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: multiIf((number = 1), true, NULL)\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "group-by-rollup-passed",
+            sql: "select number%3 as id, grouping(id) from numbers(10) group by rollup(id)",
+            expect: "\
+            Projection: (number % 3) as id:UInt8, grouping(_grouping_id, 0):UInt32\
+            \n  Expression: (number % 3):UInt8, grouping(_grouping_id, 0):UInt32 (Before Projection)\
+            \n    AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[]], groupingSets=[[0], []]\
+            \n      AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[]], groupingSets=[[0], []]\
+            \n        Expression: (number % 3):UInt8 (Before GroupBy)\
+            \n          ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "grouping-sets-passed",
+            sql: "select sum(number) from numbers(10) group by grouping sets ((number % 2, number % 3), (number % 2), ())",
+            expect: "\
+            Projection: sum(number):UInt64\
+            \n  Expression: sum(number):UInt64 (Before Projection)\
+            \n    AggregatorFinal: groupBy=[[(number % 2), (number % 3)]], aggr=[[sum(number)]], groupingSets=[[0, 1], [0], []]\
+            \n      AggregatorPartial: groupBy=[[(number % 2), (number % 3)]], aggr=[[sum(number)]], groupingSets=[[0, 1], [0], []]\
+            \n        Expression: (number % 2):UInt8, (number % 3):UInt8, number:UInt64 (Before GroupBy)\
+            \n          ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "grouping-not-group-by-key",
+            sql: "select grouping(number) from numbers(10) group by rollup(number % 3)",
+            expect: "",
+            error: "Code: 6, displayText = Argument of grouping must be a GROUP BY expression, but got number.",
+        },
+        Test {
+            name: "copy-into-passed",
+            sql: "COPY INTO 's3://bucket/out/' FROM (SELECT number FROM numbers(10)) FILE_FORMAT = (TYPE = CSV) MAX_FILE_SIZE = 1024",
+            expect: "\
+            Copy into s3://bucket/out/, file_format: CSV, max_file_size: 1024, option: {}\
+            \n  Projection: number:UInt64\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "copy-into-unsupported-format",
+            sql: "COPY INTO 's3://bucket/out/' FROM (SELECT number FROM numbers(10)) FILE_FORMAT = (TYPE = ORC)",
+            expect: "",
+            error: "Code: 5, displayText = Unsupported file format for COPY INTO: ORC.",
+        },
+        Test {
+            name: "create-user-passed",
+            sql: "CREATE USER IF NOT EXISTS 'test' IDENTIFIED BY 'password'",
+            expect: "Create user test, auth_type: Sha256, if_not_exists:true",
+            error: "",
+        },
+        Test {
+            name: "alter-user-passed",
+            sql: "ALTER USER test IDENTIFIED WITH plaintext_password BY 'password'",
+            expect: "Alter user test, auth_type: PlainText",
+            error: "",
+        },
+        Test {
+            name: "drop-user-passed",
+            sql: "DROP USER IF EXISTS test",
+            expect: "Drop user test, if_exists:true",
+            error: "",
+        },
+        Test {
+            name: "create-role-passed",
+            sql: "CREATE ROLE writer",
+            expect: "Create role writer, if_not_exists:false",
+            error: "",
+        },
+        Test {
+            name: "grant-privilege-current-database-passed",
+            sql: "GRANT SELECT, INSERT ON t1 TO test",
+            expect: "Grant SELECT,INSERT on 'default'.'t1' to USER 'test'",
+            error: "",
+        },
+        Test {
+            name: "grant-all-privileges-passed",
+            sql: "GRANT ALL ON db1.* TO ROLE writer",
+            expect: "Grant ALL on 'db1'.* to ROLE 'writer'",
+            error: "",
+        },
+        Test {
+            name: "revoke-privilege-passed",
+            sql: "REVOKE DROP ON *.* FROM test",
+            expect: "Revoke DROP on *.* from USER 'test'",
+            error: "",
+        },
+        Test {
+            name: "grant-role-passed",
+            sql: "GRANT ROLE writer TO test",
+            expect: "Grant role writer to user test",
+            error: "",
+        },
+        Test {
+            name: "create-quota-passed",
+            sql: "CREATE QUOTA FOR ROLE writer WITH interval = 60, max_queries = 10",
+            expect: "Create quota for ROLE 'writer' with interval = 60, max_queries = 10, max_read_rows = 0, max_read_bytes = 0, max_execution_time = 0",
+            error: "",
+        },
+        Test {
+            name: "create-resource-group-passed",
+            sql: "CREATE RESOURCE GROUP etl WITH max_concurrency = 2, max_queued = 10",
+            expect: "Create resource group etl, max_concurrency:2, max_queued:10, if_not_exists:false",
+            error: "",
+        },
+        Test {
+            name: "grant-resource-group-passed",
+            sql: "GRANT RESOURCE GROUP etl TO ROLE analyst",
+            expect: "Grant resource group etl to ROLE 'analyst'",
+            error: "",
+        },
+        Test {
+            name: "revoke-resource-group-passed",
+            sql: "REVOKE RESOURCE GROUP FROM USER test",
+            expect: "Revoke resource group from USER 'test'",
+            error: "",
         }
     ];
 
@@ -16,6 +16,9 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::DataType as SQLDataType;
+use sqlparser::ast::Ident;
+
+use crate::sql::DfParser;
 
 pub struct SQLCommon;
 
@@ -31,7 +34,18 @@ impl SQLCommon {
             SQLDataType::Varchar(_) => Ok(DataType::String),
             SQLDataType::String => Ok(DataType::String),
             SQLDataType::Text => Ok(DataType::String),
-            SQLDataType::Decimal(_, _) => Ok(DataType::Float64),
+            SQLDataType::Decimal(precision, scale) => {
+                // Same as MySQL, the default precision is 10 and the default scale is 0
+                let precision = precision.unwrap_or(10);
+                let scale = scale.unwrap_or(0);
+                if precision == 0 || precision > DECIMAL_MAX_PRECISION as u64 || scale > precision {
+                    return Result::Err(ErrorCode::IllegalDataType(format!(
+                        "The SQL data type {:?} is not implemented, the max precision of decimal is {}",
+                        sql_type, DECIMAL_MAX_PRECISION
+                    )));
+                }
+                Ok(DataType::Decimal(precision as u8, scale as u8))
+            }
             SQLDataType::Float(_) => Ok(DataType::Float32),
             SQLDataType::Real | SQLDataType::Double => Ok(DataType::Float64),
             SQLDataType::Boolean => Ok(DataType::Boolean),
@@ -58,6 +72,23 @@ impl SQLCommon {
                     "DATE32" => Ok(DataType::Date32),
                     "DATETIME" => Ok(DataType::DateTime32(None)),
                     "DATETIME32" => Ok(DataType::DateTime32(None)),
+                    "DATETIME64" => Self::make_datetime64_type(sql_type, &obj.0[1..]),
+                    "VARIANT" | "JSON" => Ok(DataType::Variant),
+                    "ARRAY" => match &obj.0[1..] {
+                        [item] => {
+                            let item = Self::make_nested_data_type(item)?;
+                            let field = DataField::new("item", item, true);
+                            Ok(DataType::List(Box::new(field)))
+                        }
+                        _ => Self::illegal_nested_type(sql_type, "Array(item_type)"),
+                    },
+                    "MAP" => match &obj.0[1..] {
+                        [key, value] => Ok(DataType::Map(
+                            Box::new(Self::make_nested_data_type(key)?),
+                            Box::new(Self::make_nested_data_type(value)?),
+                        )),
+                        _ => Self::illegal_nested_type(sql_type, "Map(key_type, value_type)"),
+                    },
 
                     _ => Result::Err(ErrorCode::IllegalDataType(format!(
                         "The SQL data type {:?} is not implemented",
@@ -71,4 +102,49 @@ impl SQLCommon {
             ))),
         }
     }
+
+    /// The nested type of Array and Map, which is kept as the text by the parser.
+    fn make_nested_data_type(sql: &Ident) -> Result<DataType> {
+        let sql_type = DfParser::parse_data_type_sql(&sql.value)
+            .map_err(|e| ErrorCode::SyntaxException(e.to_string()))?;
+        Self::make_data_type(&sql_type)
+    }
+
+    fn illegal_nested_type(sql_type: &SQLDataType, expected: &str) -> Result<DataType> {
+        Result::Err(ErrorCode::IllegalDataType(format!(
+            "The SQL data type {:?} is not implemented, expected {}",
+            sql_type, expected
+        )))
+    }
+
+    /// DateTime64(precision[, 'timezone']), the default precision is 3 as ClickHouse.
+    fn make_datetime64_type(sql_type: &SQLDataType, params: &[Ident]) -> Result<DataType> {
+        let illegal = || {
+            ErrorCode::IllegalDataType(format!(
+                "The SQL data type {:?} is not implemented, expected DateTime64(precision[, 'timezone']) with the max precision {}",
+                sql_type, DATETIME64_MAX_PRECISION
+            ))
+        };
+
+        let precision = match params.get(0) {
+            None => 3,
+            Some(ident) if ident.quote_style.is_none() => {
+                ident.value.parse::<u8>().map_err(|_| illegal())?
+            }
+            Some(_) => return Err(illegal()),
+        };
+        if precision > DATETIME64_MAX_PRECISION {
+            return Err(illegal());
+        }
+
+        let tz = match params.get(1) {
+            None => None,
+            Some(ident) if ident.quote_style.is_some() && params.len() == 2 => {
+                parse_timezone(&ident.value)?;
+                Some(ident.value.clone())
+            }
+            Some(_) => return Err(illegal()),
+        };
+        Ok(DataType::DateTime64(precision, tz))
+    }
 }
@@ -18,11 +18,18 @@
 use std::time::Instant;
 
 use common_exception::ErrorCode;
+use common_management::AuthType;
+use common_management::UserPrivilegeSet;
+use common_management::UserPrivilegeType;
+use common_management::UserQuota;
 use common_planners::ExplainType;
+use common_planners::SettingScope;
+use common_planners::VarValue;
 use metrics::histogram;
 use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
+use sqlparser::ast::DataType;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::SqlOption;
@@ -37,14 +44,32 @@ use sqlparser::tokenizer::Token;
 use sqlparser::tokenizer::Tokenizer;
 use sqlparser::tokenizer::Whitespace;
 
+use crate::sql::DfAlterUser;
+use crate::sql::DfCopyInto;
 use crate::sql::DfCreateDatabase;
+use crate::sql::DfCreateQuota;
+use crate::sql::DfCreateResourceGroup;
+use crate::sql::DfCreateRole;
 use crate::sql::DfCreateTable;
+use crate::sql::DfCreateUser;
 use crate::sql::DfDescribeTable;
 use crate::sql::DfDropDatabase;
+use crate::sql::DfDropQuota;
+use crate::sql::DfDropResourceGroup;
+use crate::sql::DfDropRole;
 use crate::sql::DfDropTable;
+use crate::sql::DfDropUser;
 use crate::sql::DfExplain;
+use crate::sql::DfGrantObject;
+use crate::sql::DfGrantPrivilege;
+use crate::sql::DfGrantResourceGroup;
+use crate::sql::DfGrantRole;
 use crate::sql::DfHint;
 use crate::sql::DfKillStatement;
+use crate::sql::DfPrincipal;
+use crate::sql::DfRevokePrivilege;
+use crate::sql::DfRevokeRole;
+use crate::sql::DfSetSettings;
 use crate::sql::DfShowCreateTable;
 use crate::sql::DfShowDatabases;
 use crate::sql::DfShowProcessList;
@@ -53,6 +78,7 @@ use crate::sql::DfShowTables;
 use crate::sql::DfStatement;
 use crate::sql::DfTruncateTable;
 use crate::sql::DfUseDatabase;
+use crate::sql::DfWithSettings;
 
 // Use `Parser::expected` instead, if possible
 macro_rules! parser_err {
@@ -76,7 +102,9 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = rewrite_variant_access(tokenizer.tokenize()?);
+        let tokens = rewrite_regexp(rewrite_lambda(rewrite_array_access(tokens)));
+        let tokens = rewrite_query_settings(rewrite_grouping_sets(tokens));
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
@@ -142,9 +170,29 @@ impl<'a> DfParser<'a> {
 
     /// Parse a new expression
     pub fn parse_statement(&mut self) -> Result<DfStatement, ParserError> {
+        // The settings of the query are moved to the front by rewrite_query_settings.
+        if is_word(&self.parser.peek_token(), "SETTINGS") {
+            self.parser.next_token();
+            return self.parse_with_settings();
+        }
+
         match self.parser.peek_token() {
             Token::Word(w) => {
                 match w.keyword {
+                    Keyword::ALTER => {
+                        self.parser.next_token();
+                        if self.consume_token("USER") {
+                            self.parse_alter_user()
+                        } else {
+                            // use the native parser
+                            self.parser.prev_token();
+                            Ok(DfStatement::Statement(self.parser.parse_statement()?))
+                        }
+                    }
+                    Keyword::COPY => {
+                        self.parser.next_token();
+                        self.parse_copy()
+                    }
                     Keyword::CREATE => {
                         self.parser.next_token();
                         self.parse_create()
@@ -165,6 +213,24 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_explain()
                     }
+                    Keyword::GRANT => {
+                        self.parser.next_token();
+                        self.parse_grant()
+                    }
+                    Keyword::REVOKE => {
+                        self.parser.next_token();
+                        self.parse_revoke()
+                    }
+                    Keyword::SET => {
+                        self.parser.next_token();
+                        if self.consume_token("GLOBAL") {
+                            self.parse_set_global()
+                        } else {
+                            // use the native parser
+                            self.parser.prev_token();
+                            Ok(DfStatement::Statement(self.parser.parse_statement()?))
+                        }
+                    }
                     Keyword::SHOW => {
                         self.parser.next_token();
                         if self.consume_token("TABLES") {
@@ -236,6 +302,10 @@ impl<'a> DfParser<'a> {
                     self.parser.next_token();
                     ExplainType::Graph
                 }
+                "ANALYZE" => {
+                    self.parser.next_token();
+                    ExplainType::Analyze
+                }
                 _ => ExplainType::Syntax,
             },
             _ => ExplainType::Syntax,
@@ -313,6 +383,70 @@ impl<'a> DfParser<'a> {
         Ok((columns, constraints))
     }
 
+    /// Parse a standalone SQL datatype, such as the item type `Int64` of `Array(Int64)`.
+    pub fn parse_data_type_sql(sql: &str) -> Result<DataType, ParserError> {
+        DfParser::new(sql)?.parse_data_type()
+    }
+
+    /// Parse a SQL datatype, the parameters of the custom types like `DateTime64(3, 'UTC')`
+    /// are kept as the trailing identifiers of the type name.
+    fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
+        match self.parser.parse_data_type()? {
+            DataType::Custom(mut obj) if self.parser.consume_token(&Token::LParen) => {
+                let name = obj.to_string().to_uppercase();
+                if name == "ARRAY" || name == "MAP" {
+                    // the nested types are kept as text, see `parse_data_type_sql`
+                    obj.0.extend(self.parse_nested_type_params()?);
+                    return Ok(DataType::Custom(obj));
+                }
+
+                loop {
+                    match self.parse_value()? {
+                        Value::Number(n, _) => obj.0.push(Ident::new(n)),
+                        Value::SingleQuotedString(s) => obj.0.push(Ident::with_quote('\'', s)),
+                        other => {
+                            return parser_err!(format!(
+                                "Unsupported parameter {} of data type {}",
+                                other, obj
+                            ))
+                        }
+                    }
+                    if self.parser.consume_token(&Token::RParen) {
+                        break;
+                    }
+                    self.parser.expect_token(&Token::Comma)?;
+                }
+                Ok(DataType::Custom(obj))
+            }
+            data_type => Ok(data_type),
+        }
+    }
+
+    /// Parse the parameters of `Array(T)` and `Map(K, V)` until the closing parenthesis,
+    /// each parameter is the text of a data type.
+    fn parse_nested_type_params(&mut self) -> Result<Vec<Ident>, ParserError> {
+        let mut params = vec![];
+        let mut param: Vec<String> = vec![];
+        let mut depth = 0;
+        loop {
+            match self.parser.next_token() {
+                Token::EOF => return self.expected("')' after the nested types", Token::EOF),
+                Token::RParen if depth == 0 => break,
+                Token::Comma if depth == 0 => params.push(Ident::new(param.split_off(0).join(" "))),
+                token => {
+                    match token {
+                        Token::LParen => depth += 1,
+                        Token::RParen => depth -= 1,
+                        _ => {}
+                    }
+                    param.push(token.to_string());
+                }
+            }
+        }
+        params.push(Ident::new(param.join(" ")));
+        Ok(params)
+    }
+
     /// This is a copy from sqlparser
     /// Parse a literal value (numbers, strings, date/time, booleans)
     fn parse_value(&mut self) -> Result<Value, ParserError> {
@@ -344,7 +478,7 @@ impl<'a> DfParser<'a> {
 
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
         let name = self.parser.parse_identifier()?;
-        let data_type = self.parser.parse_data_type()?;
+        let data_type = self.parse_data_type()?;
         let collation = if self.parser.parse_keyword(Keyword::COLLATE) {
             Some(self.parser.parse_object_name()?)
         } else {
@@ -381,7 +515,13 @@ impl<'a> DfParser<'a> {
             Token::Word(w) => match w.keyword {
                 Keyword::TABLE => self.parse_create_table(),
                 Keyword::DATABASE => self.parse_create_database(),
-                _ => self.expected("create statement", Token::Word(w)),
+                _ => match w.value.to_uppercase().as_str() {
+                    "USER" => self.parse_create_user(),
+                    "ROLE" => self.parse_create_role(),
+                    "QUOTA" => self.parse_create_quota(),
+                    "RESOURCE" => self.parse_create_resource_group(),
+                    _ => self.expected("create statement", Token::Word(w)),
+                },
             },
             unexpected => self.expected("create statement", unexpected),
         }
@@ -410,13 +550,19 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DescribeTable(desc))
     }
 
-    /// Drop database/table.
+    /// Drop database/table/user/role/quota/resource group.
     fn parse_drop(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::DATABASE => self.parse_drop_database(),
                 Keyword::TABLE => self.parse_drop_table(),
-                _ => self.expected("drop statement", Token::Word(w)),
+                _ => match w.value.to_uppercase().as_str() {
+                    "USER" => self.parse_drop_user(),
+                    "ROLE" => self.parse_drop_role(),
+                    "QUOTA" => self.parse_drop_quota(),
+                    "RESOURCE" => self.parse_drop_resource_group(),
+                    _ => self.expected("drop statement", Token::Word(w)),
+                },
             },
             unexpected => self.expected("drop statement", unexpected),
         }
@@ -497,13 +643,24 @@ impl<'a> DfParser<'a> {
         let mut table_properties = vec![];
 
         // parse table options: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
-        if self.consume_token("LOCATION") {
-            self.parser.expect_token(&Token::Eq)?;
-            let value = self.parse_value()?;
-            table_properties.push(SqlOption {
-                name: Ident::new("LOCATION"),
-                value,
-            })
+        'options: loop {
+            for name in [
+                "LOCATION",
+                "COMPRESSION",
+                "DICTIONARY_ENCODING",
+                "ROW_GROUP_SIZE",
+            ] {
+                if self.consume_token(name) {
+                    self.parser.expect_token(&Token::Eq)?;
+                    let value = self.parse_value()?;
+                    table_properties.push(SqlOption {
+                        name: Ident::new(name),
+                        value,
+                    });
+                    continue 'options;
+                }
+            }
+            break;
         }
 
         let create = DfCreateTable {
@@ -528,6 +685,58 @@ impl<'a> DfParser<'a> {
         Ok(self.parser.next_token().to_string())
     }
 
+    /// COPY INTO '<location>' FROM (<query>) [FILE_FORMAT = (TYPE = <type> ...)] [MAX_FILE_SIZE = <n>]
+    fn parse_copy(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::INTO)?;
+        let location = match self.parser.next_token() {
+            Token::SingleQuotedString(s) => s,
+            unexpected => return self.expected("location string", unexpected),
+        };
+
+        self.parser.expect_keyword(Keyword::FROM)?;
+        self.parser.expect_token(&Token::LParen)?;
+        let query = Box::new(self.parser.parse_query()?);
+        self.parser.expect_token(&Token::RParen)?;
+
+        let mut file_format = vec![];
+        let mut options = vec![];
+        loop {
+            if self.consume_token("FILE_FORMAT") {
+                self.parser.expect_token(&Token::Eq)?;
+                self.parser.expect_token(&Token::LParen)?;
+                while !self.parser.consume_token(&Token::RParen) {
+                    file_format.push(self.parse_copy_option()?);
+                    self.parser.consume_token(&Token::Comma);
+                }
+            } else if let Token::Word(_) = self.parser.peek_token() {
+                options.push(self.parse_copy_option()?);
+            } else {
+                break;
+            }
+        }
+
+        Ok(DfStatement::CopyInto(DfCopyInto {
+            location,
+            query,
+            file_format,
+            options,
+        }))
+    }
+
+    // Parse `name = value`, a bare word value is taken as a string, eg: TYPE = PARQUET
+    fn parse_copy_option(&mut self) -> Result<SqlOption, ParserError> {
+        let name = self.parser.parse_identifier()?;
+        self.parser.expect_token(&Token::Eq)?;
+        let value = match self.parser.next_token() {
+            Token::Word(w) if w.quote_style.is_none() => Value::SingleQuotedString(w.value),
+            _ => {
+                self.parser.prev_token();
+                self.parse_value()?
+            }
+        };
+        Ok(SqlOption { name, value })
+    }
+
     fn parse_show_create(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
@@ -557,6 +766,376 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    /// CREATE USER [IF NOT EXISTS] <name> [IDENTIFIED [WITH <auth_type>] BY '<password>']
+    fn parse_create_user(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_principal_name()?;
+        let (auth_type, password) = if self.consume_token("IDENTIFIED") {
+            self.parse_auth_options()?
+        } else {
+            (AuthType::None, String::new())
+        };
+
+        Ok(DfStatement::CreateUser(DfCreateUser {
+            if_not_exists,
+            name,
+            auth_type,
+            password,
+        }))
+    }
+
+    /// ALTER USER <name> IDENTIFIED [WITH <auth_type>] BY '<password>'
+    /// ALTER USER <name> SETTINGS <setting> = {<value> | DEFAULT} [, ...]
+    fn parse_alter_user(&mut self) -> Result<DfStatement, ParserError> {
+        let name = self.parse_principal_name()?;
+        if self.consume_token("SETTINGS") {
+            return Ok(DfStatement::SetSettings(DfSetSettings {
+                scope: SettingScope::User(name),
+                vars: self.parse_setting_values()?,
+            }));
+        }
+        if !self.consume_token("IDENTIFIED") {
+            return self.expected("IDENTIFIED or SETTINGS", self.parser.peek_token());
+        }
+        let (auth_type, password) = self.parse_auth_options()?;
+
+        Ok(DfStatement::AlterUser(DfAlterUser {
+            name,
+            auth_type,
+            password,
+        }))
+    }
+
+    /// SET GLOBAL <setting> = {<value> | DEFAULT} [, ...]
+    fn parse_set_global(&mut self) -> Result<DfStatement, ParserError> {
+        Ok(DfStatement::SetSettings(DfSetSettings {
+            scope: SettingScope::Global,
+            vars: self.parse_setting_values()?,
+        }))
+    }
+
+    /// SETTINGS (<setting> = {<value> | DEFAULT} [, ...]) <statement>
+    fn parse_with_settings(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_token(&Token::LParen)?;
+        let settings = self.parse_setting_values()?;
+        self.parser.expect_token(&Token::RParen)?;
+        let statement = Box::new(self.parse_statement()?);
+        Ok(DfStatement::WithSettings(DfWithSettings {
+            settings,
+            statement,
+        }))
+    }
+
+    // Parse `<setting> = {<value> | DEFAULT} [, ...]`
+    fn parse_setting_values(&mut self) -> Result<Vec<VarValue>, ParserError> {
+        let mut vars = vec![];
+        loop {
+            let variable = self.parser.parse_identifier()?.value;
+            self.parser.expect_token(&Token::Eq)?;
+            let var = match self.parser.next_token() {
+                Token::Word(w) if w.quote_style.is_none() && w.keyword == Keyword::DEFAULT => {
+                    VarValue::create_default(&variable)
+                }
+                Token::Word(w) => VarValue::create(&variable, &w.value),
+                Token::SingleQuotedString(value) => VarValue::create(&variable, &value),
+                Token::Number(value, _) => VarValue::create(&variable, &value),
+                Token::Minus => match self.parser.next_token() {
+                    Token::Number(value, _) => VarValue::create(&variable, &format!("-{}", value)),
+                    unexpected => return self.expected("setting value", unexpected),
+                },
+                unexpected => return self.expected("setting value", unexpected),
+            };
+            vars.push(var);
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        Ok(vars)
+    }
+
+    /// DROP USER [IF EXISTS] <name>
+    fn parse_drop_user(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_principal_name()?;
+        Ok(DfStatement::DropUser(DfDropUser { if_exists, name }))
+    }
+
+    /// CREATE ROLE [IF NOT EXISTS] <name>
+    fn parse_create_role(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_principal_name()?;
+        Ok(DfStatement::CreateRole(DfCreateRole {
+            if_not_exists,
+            name,
+        }))
+    }
+
+    /// DROP ROLE [IF EXISTS] <name>
+    fn parse_drop_role(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_principal_name()?;
+        Ok(DfStatement::DropRole(DfDropRole { if_exists, name }))
+    }
+
+    /// CREATE QUOTA FOR [USER | ROLE] <name> WITH interval = <secs> [, <limit> = <value>]
+    fn parse_create_quota(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::FOR)?;
+        let principal = self.parse_principal()?;
+        self.parser.expect_keyword(Keyword::WITH)?;
+
+        let mut quota = UserQuota::default();
+        for (name, value) in self.parse_uint_options()? {
+            match name.as_str() {
+                "interval" => quota.interval_secs = value,
+                "max_queries" => quota.max_queries = value,
+                "max_read_rows" => quota.max_read_rows = value,
+                "max_read_bytes" => quota.max_read_bytes = value,
+                "max_execution_time" => quota.max_execution_time_ms = value * 1000,
+                _ => return parser_err!(format!("Unknown quota option: {}", name)),
+            }
+        }
+
+        Ok(DfStatement::CreateQuota(DfCreateQuota { principal, quota }))
+    }
+
+    /// DROP QUOTA FOR [USER | ROLE] <name>
+    fn parse_drop_quota(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::FOR)?;
+        let principal = self.parse_principal()?;
+        Ok(DfStatement::DropQuota(DfDropQuota { principal }))
+    }
+
+    /// CREATE RESOURCE GROUP [IF NOT EXISTS] <name> WITH max_concurrency = <n> [, max_queued = <n>]
+    fn parse_create_resource_group(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.consume_token("GROUP") {
+            return self.expected("GROUP", self.parser.peek_token());
+        }
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_principal_name()?;
+        self.parser.expect_keyword(Keyword::WITH)?;
+
+        let (mut max_concurrency, mut max_queued) = (0, 0);
+        for (option, value) in self.parse_uint_options()? {
+            match option.as_str() {
+                "max_concurrency" => max_concurrency = value,
+                "max_queued" => max_queued = value,
+                _ => return parser_err!(format!("Unknown resource group option: {}", option)),
+            }
+        }
+        if max_concurrency == 0 {
+            return parser_err!("max_concurrency of resource group must be greater than 0");
+        }
+
+        Ok(DfStatement::CreateResourceGroup(DfCreateResourceGroup {
+            if_not_exists,
+            name,
+            max_concurrency,
+            max_queued,
+        }))
+    }
+
+    /// DROP RESOURCE GROUP [IF EXISTS] <name>
+    fn parse_drop_resource_group(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.consume_token("GROUP") {
+            return self.expected("GROUP", self.parser.peek_token());
+        }
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_principal_name()?;
+        Ok(DfStatement::DropResourceGroup(DfDropResourceGroup {
+            if_exists,
+            name,
+        }))
+    }
+
+    // Parse `<name> = <unsigned integer> [, <name> = <unsigned integer>]`, the names are lowercase.
+    fn parse_uint_options(&mut self) -> Result<Vec<(String, u64)>, ParserError> {
+        let mut options = vec![];
+        loop {
+            let name = self.parser.parse_identifier()?.value.to_lowercase();
+            self.parser.expect_token(&Token::Eq)?;
+            let value = self.parser.parse_literal_uint()?;
+            options.push((name, value));
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        Ok(options)
+    }
+
+    // Parse `[WITH <auth_type>] BY '<password>'` after IDENTIFIED, the default auth type is sha256_password.
+    fn parse_auth_options(&mut self) -> Result<(AuthType, String), ParserError> {
+        let auth_type = if self.consume_token("WITH") {
+            match self.parser.next_token() {
+                Token::Word(w) => match w.value.to_lowercase().as_str() {
+                    "no_password" => AuthType::None,
+                    "plaintext_password" => AuthType::PlainText,
+                    "double_sha1_password" => AuthType::DoubleSha1,
+                    "sha256_password" => AuthType::Sha256,
+                    _ => return self.expected("auth type", Token::Word(w)),
+                },
+                unexpected => return self.expected("auth type", unexpected),
+            }
+        } else {
+            AuthType::Sha256
+        };
+
+        if auth_type == AuthType::None {
+            return Ok((auth_type, String::new()));
+        }
+
+        if !self.consume_token("BY") {
+            return self.expected("BY '<password>'", self.parser.peek_token());
+        }
+        match self.parser.next_token() {
+            Token::SingleQuotedString(password) => Ok((auth_type, password)),
+            unexpected => self.expected("password string", unexpected),
+        }
+    }
+
+    // The name of user or role, an identifier or a string: test_user, 'test_user'
+    fn parse_principal_name(&mut self) -> Result<String, ParserError> {
+        match self.parser.next_token() {
+            Token::Word(w) => Ok(w.value),
+            Token::SingleQuotedString(s) => Ok(s),
+            unexpected => self.expected("user or role name", unexpected),
+        }
+    }
+
+    /// GRANT ROLE <role> TO <user>
+    /// GRANT RESOURCE GROUP <name> TO [USER | ROLE] <name>
+    /// GRANT {ALL [PRIVILEGES] | <privilege> [, <privilege>]} ON <object> TO [USER | ROLE] <name>
+    fn parse_grant(&mut self) -> Result<DfStatement, ParserError> {
+        if self.consume_token("ROLE") {
+            let role = self.parse_principal_name()?;
+            self.parser.expect_keyword(Keyword::TO)?;
+            self.consume_token("USER");
+            let user = self.parse_principal_name()?;
+            return Ok(DfStatement::GrantRole(DfGrantRole { role, user }));
+        }
+
+        if self.consume_token("RESOURCE") {
+            if !self.consume_token("GROUP") {
+                return self.expected("GROUP", self.parser.peek_token());
+            }
+            let name = self.parse_principal_name()?;
+            self.parser.expect_keyword(Keyword::TO)?;
+            let principal = self.parse_principal()?;
+            return Ok(DfStatement::GrantResourceGroup(DfGrantResourceGroup {
+                name: Some(name),
+                principal,
+            }));
+        }
+
+        let privileges = self.parse_privileges()?;
+        self.parser.expect_keyword(Keyword::ON)?;
+        let on = self.parse_grant_object()?;
+        self.parser.expect_keyword(Keyword::TO)?;
+        let principal = self.parse_principal()?;
+
+        Ok(DfStatement::GrantPrivilege(DfGrantPrivilege {
+            privileges,
+            on,
+            principal,
+        }))
+    }
+
+    /// REVOKE ROLE <role> FROM <user>
+    /// REVOKE RESOURCE GROUP FROM [USER | ROLE] <name>
+    /// REVOKE {ALL [PRIVILEGES] | <privilege> [, <privilege>]} ON <object> FROM [USER | ROLE] <name>
+    fn parse_revoke(&mut self) -> Result<DfStatement, ParserError> {
+        if self.consume_token("ROLE") {
+            let role = self.parse_principal_name()?;
+            self.parser.expect_keyword(Keyword::FROM)?;
+            self.consume_token("USER");
+            let user = self.parse_principal_name()?;
+            return Ok(DfStatement::RevokeRole(DfRevokeRole { role, user }));
+        }
+
+        if self.consume_token("RESOURCE") {
+            if !self.consume_token("GROUP") {
+                return self.expected("GROUP", self.parser.peek_token());
+            }
+            self.parser.expect_keyword(Keyword::FROM)?;
+            let principal = self.parse_principal()?;
+            return Ok(DfStatement::GrantResourceGroup(DfGrantResourceGroup {
+                name: None,
+                principal,
+            }));
+        }
+
+        let privileges = self.parse_privileges()?;
+        self.parser.expect_keyword(Keyword::ON)?;
+        let on = self.parse_grant_object()?;
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let principal = self.parse_principal()?;
+
+        Ok(DfStatement::RevokePrivilege(DfRevokePrivilege {
+            privileges,
+            on,
+            principal,
+        }))
+    }
+
+    fn parse_privileges(&mut self) -> Result<UserPrivilegeSet, ParserError> {
+        if self.consume_token("ALL") {
+            self.consume_token("PRIVILEGES");
+            return Ok(UserPrivilegeSet::all_privileges());
+        }
+
+        let mut privileges = UserPrivilegeSet::empty();
+        loop {
+            match self.parser.next_token() {
+                Token::Word(w) => match UserPrivilegeType::try_from_name(&w.value) {
+                    Ok(privilege) => privileges.set_privilege(privilege),
+                    Err(_) => return self.expected("privilege", Token::Word(w)),
+                },
+                unexpected => return self.expected("privilege", unexpected),
+            }
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        Ok(privileges)
+    }
+
+    // Parse the object of GRANT/REVOKE: *.*, *, db.*, db.table or table
+    fn parse_grant_object(&mut self) -> Result<DfGrantObject, ParserError> {
+        let first = match self.parser.next_token() {
+            Token::Mult => None,
+            Token::Word(w) => Some(w.value),
+            unexpected => return self.expected("grant object", unexpected),
+        };
+
+        if !self.parser.consume_token(&Token::Period) {
+            return Ok(match first {
+                None => DfGrantObject::Database(None),
+                Some(table) => DfGrantObject::Table(None, table),
+            });
+        }
+
+        match (first, self.parser.next_token()) {
+            (None, Token::Mult) => Ok(DfGrantObject::Global),
+            (Some(db), Token::Mult) => Ok(DfGrantObject::Database(Some(db))),
+            (Some(db), Token::Word(w)) => Ok(DfGrantObject::Table(Some(db), w.value)),
+            (_, unexpected) => self.expected("grant object", unexpected),
+        }
+    }
+
+    // Parse `[USER | ROLE] <name>`, the name is a user if it's omitted.
+    fn parse_principal(&mut self) -> Result<DfPrincipal, ParserError> {
+        if self.consume_token("ROLE") {
+            return Ok(DfPrincipal::Role(self.parse_principal_name()?));
+        }
+        self.consume_token("USER");
+        Ok(DfPrincipal::User(self.parse_principal_name()?))
+    }
+
     fn consume_token(&mut self, expected: &str) -> bool {
         if self.parser.peek_token().to_string().to_uppercase() == *expected.to_uppercase() {
             self.parser.next_token();
@@ -593,3 +1172,586 @@ impl<'a> DfParser<'a> {
         }
     }
 }
+
+/// Rewrites the variant access `v:a.b[0]` into the function call `get(v, 'a.b[0]')`,
+/// the variant is a column name which may be qualified by the table name.
+fn rewrite_variant_access(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let is_access = tokens[pos] == Token::Colon
+            && matches!(result.last(), Some(Token::Word(_)))
+            && matches!(tokens.get(pos + 1), Some(Token::Word(_)));
+        if !is_access {
+            result.push(tokens[pos].clone());
+            pos += 1;
+            continue;
+        }
+
+        let mut start = result.len() - 1;
+        while start >= 2
+            && result[start - 1] == Token::Period
+            && matches!(result[start - 2], Token::Word(_))
+        {
+            start -= 2;
+        }
+        let column = result.split_off(start);
+
+        let mut path = String::new();
+        pos += 1;
+        loop {
+            match (tokens.get(pos), tokens.get(pos + 1), tokens.get(pos + 2)) {
+                (Some(Token::Word(w)), _, _) if path.is_empty() => {
+                    path.push_str(&w.value);
+                    pos += 1;
+                }
+                (Some(Token::Period), Some(Token::Word(w)), _) => {
+                    path.push('.');
+                    path.push_str(&w.value);
+                    pos += 2;
+                }
+                (Some(Token::LBracket), Some(Token::Number(n, _)), Some(Token::RBracket)) => {
+                    path.push_str(&format!("[{}]", n));
+                    pos += 3;
+                }
+                (
+                    Some(Token::LBracket),
+                    Some(Token::SingleQuotedString(key)),
+                    Some(Token::RBracket),
+                ) => {
+                    path.push_str(&format!("['{}']", key));
+                    pos += 3;
+                }
+                _ => break,
+            }
+        }
+
+        result.push(Token::make_word("get", None));
+        result.push(Token::LParen);
+        result.extend(column);
+        result.push(Token::Comma);
+        result.push(Token::SingleQuotedString(path));
+        result.push(Token::RParen);
+    }
+    result
+}
+
+/// The keywords after which `[` starts an array literal instead of a subscript.
+const ARRAY_LITERAL_KEYWORDS: &[&str] = &[
+    "SELECT", "WHERE", "AND", "OR", "NOT", "IN", "THEN", "ELSE", "WHEN", "HAVING", "ON", "BY",
+    "VALUES", "DISTINCT",
+];
+
+/// The index of the last token which is not a whitespace.
+fn last_token_index(tokens: &[Token]) -> Option<usize> {
+    tokens
+        .iter()
+        .rposition(|token| !matches!(token, Token::Whitespace(_)))
+}
+
+/// The index of the `(` matching the `)` at `end`.
+fn matching_lparen_index(tokens: &[Token], end: usize) -> Option<usize> {
+    let mut depth = 0;
+    for index in (0..=end).rev() {
+        match tokens[index] {
+            Token::RParen => depth += 1,
+            Token::LParen if depth == 1 => return Some(index),
+            Token::LParen => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The index of the first token of the operand before a subscript, which is a (qualified)
+/// identifier, a function call or a parenthesized expression.
+fn subscript_operand_index(tokens: &[Token]) -> Option<usize> {
+    let last = last_token_index(tokens)?;
+    match &tokens[last] {
+        Token::Word(w)
+            if w.quote_style.is_some()
+                || !ARRAY_LITERAL_KEYWORDS.contains(&w.value.to_uppercase().as_str()) =>
+        {
+            let mut start = last;
+            while start >= 2
+                && tokens[start - 1] == Token::Period
+                && matches!(tokens[start - 2], Token::Word(_))
+            {
+                start -= 2;
+            }
+            Some(start)
+        }
+        Token::RParen => {
+            let start = matching_lparen_index(tokens, last)?;
+            match last_token_index(&tokens[..start]) {
+                Some(name) if matches!(tokens[name], Token::Word(_)) => Some(name),
+                _ => Some(start),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Rewrites the array literal `[a, b]` to `array(a, b)` and the subscript `arr[i]` to
+/// `arrayElement(arr, i)`.
+fn rewrite_array_access(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result: Vec<Token> = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        match token {
+            Token::LBracket => match subscript_operand_index(&result) {
+                Some(start) => {
+                    let operand = result.split_off(start);
+                    result.push(Token::make_word("arrayElement", None));
+                    result.push(Token::LParen);
+                    result.extend(operand);
+                    result.push(Token::Comma);
+                }
+                None => {
+                    result.push(Token::make_word("array", None));
+                    result.push(Token::LParen);
+                }
+            },
+            Token::RBracket => result.push(Token::RParen),
+            token => result.push(token),
+        }
+    }
+    result
+}
+
+/// The keywords which are not part of a `REGEXP` operand, an operand bounded by any other
+/// keyword must be parenthesized.
+const REGEXP_BOUNDARY_KEYWORDS: &[&str] = &[
+    "SELECT", "FROM", "WHERE", "AND", "OR", "NOT", "XOR", "IN", "IS", "LIKE", "BETWEEN", "CASE",
+    "WHEN", "THEN", "ELSE", "END", "HAVING", "ON", "BY", "AS", "VALUES", "DISTINCT", "LIMIT",
+];
+
+fn is_word(token: &Token, keyword: &str) -> bool {
+    matches!(token, Token::Word(w) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(keyword))
+}
+
+fn is_boundary_keyword(token: &Token) -> bool {
+    REGEXP_BOUNDARY_KEYWORDS
+        .iter()
+        .any(|keyword| is_word(token, keyword))
+}
+
+/// Whether the token may be part of an operand binding tighter than a comparison, which are
+/// identifiers, literals and arithmetic, bitwise, concat and cast operators.
+fn is_operand_token(token: &Token) -> bool {
+    match token {
+        Token::Word(_) => !is_boundary_keyword(token),
+        Token::SingleQuotedString(_)
+        | Token::Number(_, _)
+        | Token::Period
+        | Token::Plus
+        | Token::Minus
+        | Token::Mul
+        | Token::Div
+        | Token::Mod
+        | Token::Ampersand
+        | Token::Pipe
+        | Token::Caret
+        | Token::StringConcat
+        | Token::DoubleColon
+        | Token::Whitespace(_) => true,
+        _ => false,
+    }
+}
+
+/// Whether an operand may end with the token, the left of `REGEXP` must end with one for
+/// `REGEXP` to be an operator rather than an identifier.
+fn is_operand_end(token: &Token) -> bool {
+    match token {
+        Token::Word(_) => !is_boundary_keyword(token),
+        Token::SingleQuotedString(_) | Token::Number(_, _) | Token::RParen => true,
+        _ => false,
+    }
+}
+
+/// The index of the `)` matching the `(` at `start`.
+fn matching_rparen_index(tokens: &[Token], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen if depth == 1 => return Some(index),
+            Token::RParen => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The index of the first token of the operand ending at `end` (exclusive), the operand
+/// extends over the operators binding tighter than a comparison.
+fn left_operand_start(tokens: &[Token], end: usize) -> Option<usize> {
+    let mut start = end;
+    while start > 0 {
+        match &tokens[start - 1] {
+            Token::RParen => start = matching_lparen_index(tokens, start - 1)?,
+            token if is_operand_token(token) => start -= 1,
+            _ => break,
+        }
+    }
+    let first = (start..end).find(|i| !matches!(tokens[*i], Token::Whitespace(_)))?;
+    let last = last_token_index(&tokens[..end])?;
+    if is_operand_end(&tokens[last]) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// The end (exclusive) of the operand starting at `start`, the operand extends over the
+/// operators binding tighter than a comparison.
+fn right_operand_end(tokens: &[Token], start: usize) -> Option<usize> {
+    match tokens.get(start)? {
+        Token::LParen | Token::Plus | Token::Minus => {}
+        token if is_operand_end(token) => {}
+        _ => return None,
+    }
+
+    let mut end = start;
+    while end < tokens.len() {
+        match &tokens[end] {
+            Token::LParen => end = matching_rparen_index(tokens, end)? + 1,
+            token if is_operand_token(token) => end += 1,
+            _ => break,
+        }
+    }
+    let last = last_token_index(&tokens[..end])?;
+    if is_operand_end(&tokens[last]) {
+        Some(last + 1)
+    } else {
+        None
+    }
+}
+
+/// Rewrites the `REGEXP` or `RLIKE` operator at `pos` into the result, returns the position
+/// after the right operand or None if `pos` is not in an operator position, e.g. a column
+/// named `rlike`.
+fn rewrite_regexp_operator(result: &mut Vec<Token>, tokens: &[Token], pos: usize) -> Option<usize> {
+    let right_start =
+        (pos + 1..tokens.len()).find(|i| !matches!(tokens[*i], Token::Whitespace(_)))?;
+    let right_end = right_operand_end(tokens, right_start)?;
+
+    let mut left_end = result.len();
+    let negated = match last_token_index(result) {
+        Some(last) if is_word(&result[last], "NOT") => {
+            left_end = last;
+            true
+        }
+        _ => false,
+    };
+    let left_start = left_operand_start(result, left_end)?;
+
+    let left = result[left_start..left_end].to_vec();
+    result.truncate(left_start);
+    if negated {
+        result.push(Token::make_word("NOT", None));
+        result.push(Token::Whitespace(Whitespace::Space));
+    }
+    result.push(Token::make_word("regexp_like", None));
+    result.push(Token::LParen);
+    result.extend(left);
+    result.push(Token::Comma);
+    result.extend_from_slice(&tokens[right_start..right_end]);
+    result.push(Token::RParen);
+    Some(right_end)
+}
+
+/// Rewrites `a REGEXP b` and `a RLIKE b` to `regexp_like(a, b)` and the negated forms to
+/// `NOT regexp_like(a, b)`. Like a comparison, the operands extend over arithmetic, bitwise,
+/// concat and cast operators and stop at comparisons, logical operators and other keywords.
+fn rewrite_regexp(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut pos = 0;
+    while pos < tokens.len() {
+        if is_word(&tokens[pos], "REGEXP") || is_word(&tokens[pos], "RLIKE") {
+            if let Some(next) = rewrite_regexp_operator(&mut result, &tokens, pos) {
+                pos = next;
+                continue;
+            }
+        }
+        result.push(tokens[pos].clone());
+        pos += 1;
+    }
+    result
+}
+
+/// The lambda parameters before `->`, which are an identifier `x` or a parenthesized list
+/// of identifiers `(x, y)`, returns the index of the first token and the parameter names.
+fn lambda_params(tokens: &[Token]) -> Option<(usize, Vec<String>)> {
+    let last = last_token_index(tokens)?;
+    match &tokens[last] {
+        Token::Word(w) if w.quote_style.is_none() => Some((last, vec![w.value.clone()])),
+        Token::RParen => {
+            let start = matching_lparen_index(tokens, last)?;
+            let mut params = vec![];
+            let mut expect_param = true;
+            for token in &tokens[start + 1..last] {
+                match token {
+                    Token::Whitespace(_) => {}
+                    Token::Word(w) if expect_param && w.quote_style.is_none() => {
+                        params.push(w.value.clone());
+                        expect_param = false;
+                    }
+                    Token::Comma if !expect_param => expect_param = true,
+                    _ => return None,
+                }
+            }
+            match expect_param {
+                true => None,
+                false => Some((start, params)),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Rewrites the lambda `(x, y) -> body` to `lambda('x', 'y', body)`, the body ends at the
+/// first `,`, `;` or the unmatched `)` after the arrow.
+fn rewrite_lambda(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result: Vec<Token> = Vec::with_capacity(tokens.len());
+    // the parenthesis depths of the unclosed lambdas
+    let mut lambdas: Vec<usize> = vec![];
+    let mut depth = 0;
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let arrow_len = match (&tokens[pos], tokens.get(pos + 1)) {
+            (Token::Minus, Some(Token::Gt)) => 2,
+            (token, _) if token.to_string() == "->" => 1,
+            _ => 0,
+        };
+        if arrow_len > 0 {
+            if let Some((start, params)) = lambda_params(&result) {
+                result.truncate(start);
+                result.push(Token::make_word("lambda", None));
+                result.push(Token::LParen);
+                for param in params {
+                    result.push(Token::SingleQuotedString(param));
+                    result.push(Token::Comma);
+                }
+                depth += 1;
+                lambdas.push(depth);
+                pos += arrow_len;
+                continue;
+            }
+        }
+
+        match &tokens[pos] {
+            Token::Comma | Token::RParen | Token::SemiColon | Token::EOF => {
+                while lambdas.last() == Some(&depth) {
+                    lambdas.pop();
+                    result.push(Token::RParen);
+                    depth -= 1;
+                }
+                if tokens[pos] == Token::RParen {
+                    depth -= 1;
+                }
+            }
+            Token::LParen => depth += 1,
+            _ => {}
+        }
+        result.push(tokens[pos].clone());
+        pos += 1;
+    }
+    for _ in lambdas {
+        result.push(Token::RParen);
+    }
+    result
+}
+
+/// The keywords ending a GROUP BY clause.
+const GROUP_BY_END_KEYWORDS: &[&str] = &[
+    "HAVING",
+    "ORDER",
+    "LIMIT",
+    "OFFSET",
+    "UNION",
+    "EXCEPT",
+    "INTERSECT",
+    "WINDOW",
+    "FORMAT",
+    "SETTINGS",
+];
+
+/// The index of the first token from `start` which is not a whitespace.
+fn next_token_index(tokens: &[Token], start: usize) -> Option<usize> {
+    (start..tokens.len()).find(|i| !matches!(tokens[*i], Token::Whitespace(_)))
+}
+
+/// Rewrites the GROUP BY element `ROLLUP (...)`, `CUBE (...)` or `GROUPING SETS (...)` at
+/// `pos` into the result, returns the position after the element or None if the element is
+/// an expression.
+fn rewrite_grouping_element(
+    result: &mut Vec<Token>,
+    tokens: &[Token],
+    pos: usize,
+) -> Option<usize> {
+    let (name, lparen) = if is_word(&tokens[pos], "ROLLUP") || is_word(&tokens[pos], "CUBE") {
+        (tokens[pos].clone(), next_token_index(tokens, pos + 1)?)
+    } else if is_word(&tokens[pos], "GROUPING") {
+        let sets = next_token_index(tokens, pos + 1).filter(|i| is_word(&tokens[*i], "SETS"))?;
+        (
+            Token::make_word("groupingSets", None),
+            next_token_index(tokens, sets + 1)?,
+        )
+    } else {
+        return None;
+    };
+    if tokens[lparen] != Token::LParen {
+        return None;
+    }
+    let rparen = matching_rparen_index(tokens, lparen)?;
+
+    result.push(name);
+    result.push(Token::LParen);
+    // a parenthesized item of the element is a grouping set rather than an expression
+    let mut depth = 0;
+    let mut item_start = true;
+    for token in &tokens[lparen + 1..rparen] {
+        match token {
+            Token::Whitespace(_) => {}
+            Token::LParen if depth == 0 && item_start => {
+                result.push(Token::make_word("groupingSet", None));
+                depth += 1;
+            }
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            Token::Comma if depth == 0 => {
+                result.push(Token::Comma);
+                item_start = true;
+                continue;
+            }
+            _ => {}
+        }
+        if !matches!(token, Token::Whitespace(_)) {
+            item_start = false;
+        }
+        result.push(token.clone());
+    }
+    result.push(Token::RParen);
+    Some(rparen + 1)
+}
+
+/// Rewrites the `GROUPING SETS (...)`, `ROLLUP (...)` and `CUBE (...)` elements of the GROUP
+/// BY clauses to the functions `groupingSets`, `rollup` and `cube` whose parenthesized items
+/// are `groupingSet(...)`, for example `GROUP BY a, GROUPING SETS ((a, b), ())` becomes
+/// `GROUP BY a, groupingSets(groupingSet(a, b), groupingSet())`. Outside of a GROUP BY
+/// clause, and in expressions of it, the tokens are left as they are.
+fn rewrite_grouping_sets(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result: Vec<Token> = Vec::with_capacity(tokens.len());
+    // the parenthesis depths of the unclosed GROUP BY clauses
+    let mut clauses: Vec<usize> = vec![];
+    // whether the next token starts an element of a GROUP BY clause
+    let mut element_start = false;
+    let mut depth = 0;
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let token = &tokens[pos];
+        if matches!(token, Token::Whitespace(_)) {
+            result.push(token.clone());
+            pos += 1;
+            continue;
+        }
+        if std::mem::take(&mut element_start) {
+            if let Some(next) = rewrite_grouping_element(&mut result, &tokens, pos) {
+                pos = next;
+                continue;
+            }
+        }
+
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => {
+                depth -= 1;
+                clauses.retain(|clause| *clause <= depth);
+            }
+            Token::SemiColon => clauses.clear(),
+            Token::Comma => element_start = clauses.last() == Some(&depth),
+            token if is_word(token, "GROUP") => {
+                if let Some(by) =
+                    next_token_index(&tokens, pos + 1).filter(|next| is_word(&tokens[*next], "BY"))
+                {
+                    result.extend_from_slice(&tokens[pos..=by]);
+                    clauses.push(depth);
+                    element_start = true;
+                    pos = by + 1;
+                    continue;
+                }
+            }
+            token
+                if clauses.last() == Some(&depth)
+                    && GROUP_BY_END_KEYWORDS
+                        .iter()
+                        .any(|keyword| is_word(token, keyword)) =>
+            {
+                clauses.pop();
+            }
+            _ => {}
+        }
+        result.push(token.clone());
+        pos += 1;
+    }
+    result
+}
+
+/// Whether the tokens are a list of settings `<name> = <value>, ...`.
+fn is_setting_list(tokens: &[Token]) -> bool {
+    let tokens = tokens
+        .iter()
+        .filter(|token| !matches!(token, Token::Whitespace(_)))
+        .collect::<Vec<_>>();
+    let mut pos = 0;
+    loop {
+        match (tokens.get(pos), tokens.get(pos + 1)) {
+            (Some(Token::Word(_)), Some(Token::Eq)) => pos += 2,
+            _ => return false,
+        }
+        match (tokens.get(pos), tokens.get(pos + 1)) {
+            (Some(Token::Minus), Some(Token::Number(_, _))) => pos += 2,
+            (Some(Token::Word(_) | Token::Number(_, _) | Token::SingleQuotedString(_)), _) => {
+                pos += 1
+            }
+            _ => return false,
+        }
+        match tokens.get(pos) {
+            None => return true,
+            Some(Token::Comma) => pos += 1,
+            Some(_) => return false,
+        }
+    }
+}
+
+/// Moves the settings of a query `<statement> SETTINGS (<name> = <value>, ...)` to the front
+/// of the statement, where the parser can take them before the native parser parses it.
+fn rewrite_query_settings(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result: Vec<Token> = Vec::with_capacity(tokens.len());
+    for statement in tokens.split_inclusive(|token| *token == Token::SemiColon) {
+        let body_end = match statement.last() {
+            Some(Token::SemiColon) => statement.len() - 1,
+            _ => statement.len(),
+        };
+        let rparen = last_token_index(&statement[..body_end])
+            .filter(|index| statement[*index] == Token::RParen);
+        let lparen = rparen.and_then(|rparen| matching_lparen_index(statement, rparen));
+        let keyword = lparen
+            .and_then(|lparen| last_token_index(&statement[..lparen]))
+            .filter(|keyword| is_word(&statement[*keyword], "SETTINGS"))
+            .filter(|keyword| last_token_index(&statement[..*keyword]).is_some());
+
+        match (keyword, lparen, rparen) {
+            (Some(keyword), Some(lparen), Some(rparen))
+                if is_setting_list(&statement[lparen + 1..rparen]) =>
+            {
+                result.extend_from_slice(&statement[keyword..=rparen]);
+                result.push(Token::Whitespace(Whitespace::Space));
+                result.extend_from_slice(&statement[..keyword]);
+                result.extend_from_slice(&statement[rparen + 1..]);
+            }
+            _ => result.extend_from_slice(statement),
+        }
+    }
+    result
+}
@@ -13,6 +13,12 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_management::AuthType;
+use common_management::UserPrivilegeSet;
+use common_management::UserPrivilegeType;
+use common_management::UserQuota;
+use common_planners::SettingScope;
+use common_planners::VarValue;
 use sqlparser::ast::*;
 
 use crate::sql::sql_statement::DfDropDatabase;
@@ -150,6 +156,81 @@ fn create_table() -> Result<()> {
     });
     expect_parse_ok(sql, expected)?;
 
+    // positive case: parquet writing options
+    let sql = "CREATE TABLE t(c1 int) ENGINE = FUSE compression = 'zstd' dictionary_encoding = true row_group_size = 4096";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", DataType::Int(None))],
+        engine: "FUSE".to_string(),
+        options: vec![
+            SqlOption {
+                name: Ident::new("COMPRESSION".to_string()),
+                value: Value::SingleQuotedString("zstd".into()),
+            },
+            SqlOption {
+                name: Ident::new("DICTIONARY_ENCODING".to_string()),
+                value: Value::Boolean(true),
+            },
+            SqlOption {
+                name: Ident::new("ROW_GROUP_SIZE".to_string()),
+                value: Value::Number("4096".to_string(), false),
+            },
+        ],
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // positive case: parameters of the custom types
+    let sql = "CREATE TABLE t(c1 DateTime64(3, 'Asia/Shanghai'), c2 DateTime64) ENGINE = Null";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def(
+                "c1",
+                DataType::Custom(ObjectName(vec![
+                    Ident::new("DateTime64"),
+                    Ident::new("3"),
+                    Ident::with_quote('\'', "Asia/Shanghai"),
+                ])),
+            ),
+            make_column_def(
+                "c2",
+                DataType::Custom(ObjectName(vec![Ident::new("DateTime64")])),
+            ),
+        ],
+        engine: "Null".to_string(),
+        options: vec![],
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // positive case: the nested types are kept as text
+    let sql = "CREATE TABLE t(c1 Array(DateTime64(3)), c2 Map(String, Array(Int64))) ENGINE = Null";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def(
+                "c1",
+                DataType::Custom(ObjectName(vec![
+                    Ident::new("Array"),
+                    Ident::new("DateTime64 ( 3 )"),
+                ])),
+            ),
+            make_column_def(
+                "c2",
+                DataType::Custom(ObjectName(vec![
+                    Ident::new("Map"),
+                    Ident::new("String"),
+                    Ident::new("Array ( Int64 )"),
+                ])),
+            ),
+        ],
+        engine: "Null".to_string(),
+        options: vec![],
+    });
+    expect_parse_ok(sql, expected)?;
+
     Ok(())
 }
 
@@ -298,6 +379,161 @@ fn truncate_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn copy_into() -> Result<()> {
+    let query = match &sqlparser::parser::Parser::parse_sql(
+        &sqlparser::dialect::GenericDialect {},
+        "SELECT a FROM t",
+    )?[0]
+    {
+        Statement::Query(query) => query.clone(),
+        _ => unreachable!(),
+    };
+
+    {
+        let sql = "COPY INTO 's3://bucket/out/' FROM (SELECT a FROM t)";
+        let expected = DfStatement::CopyInto(DfCopyInto {
+            location: "s3://bucket/out/".to_string(),
+            query: query.clone(),
+            file_format: vec![],
+            options: vec![],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "COPY INTO 's3://bucket/out/' FROM (SELECT a FROM t) FILE_FORMAT = (TYPE = CSV, FIELD_DELIMITER = '|') MAX_FILE_SIZE = 1024";
+        let expected = DfStatement::CopyInto(DfCopyInto {
+            location: "s3://bucket/out/".to_string(),
+            query,
+            file_format: vec![
+                SqlOption {
+                    name: Ident::new("TYPE"),
+                    value: Value::SingleQuotedString("CSV".to_string()),
+                },
+                SqlOption {
+                    name: Ident::new("FIELD_DELIMITER"),
+                    value: Value::SingleQuotedString("|".to_string()),
+                },
+            ],
+            options: vec![SqlOption {
+                name: Ident::new("MAX_FILE_SIZE"),
+                value: Value::Number("1024".to_string(), false),
+            }],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn variant_access() -> Result<()> {
+    let expect_same = |sql: &str, rewritten: &str| -> Result<()> {
+        let (statements, _) = DfParser::parse_sql(rewritten)?;
+        expect_parse_ok(sql, statements[0].clone())
+    };
+
+    expect_same("SELECT v:a.b[0] FROM t", "SELECT get(v, 'a.b[0]') FROM t")?;
+    expect_same(
+        "SELECT t.v:a['b c'] = 1 FROM t",
+        "SELECT get(t.v, 'a[''b c'']') = 1 FROM t",
+    )?;
+    expect_same("SELECT v::String FROM t", "SELECT v::String FROM t")?;
+
+    Ok(())
+}
+
+#[test]
+fn array_access_and_lambda() -> Result<()> {
+    let expect_same = |sql: &str, rewritten: &str| -> Result<()> {
+        let (statements, _) = DfParser::parse_sql(rewritten)?;
+        expect_parse_ok(sql, statements[0].clone())
+    };
+
+    expect_same("SELECT [1, 2], []", "SELECT array(1, 2), array()")?;
+    expect_same(
+        "SELECT a[1], t.a[-1][2], f(a)[1] FROM t",
+        "SELECT arrayElement(a, 1), arrayElement(arrayElement(t.a, -1), 2), arrayElement(f(a), 1) FROM t",
+    )?;
+    expect_same(
+        "SELECT m['k'] FROM t WHERE [1] = a",
+        "SELECT arrayElement(m, 'k') FROM t WHERE array(1) = a",
+    )?;
+    expect_same(
+        "SELECT arrayMap(x -> x * 2, a), arrayFilter((x, y) -> x > y, a, b) FROM t",
+        "SELECT arrayMap(lambda('x', x * 2), a), arrayFilter(lambda('x', 'y', x > y), a, b) FROM t",
+    )?;
+    expect_same(
+        "SELECT arrayMap(x -> [x, x + 1][2], [1, 2])",
+        "SELECT arrayMap(lambda('x', arrayElement(array(x, x + 1), 2)), array(1, 2))",
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn regexp_operators() -> Result<()> {
+    let expect_same = |sql: &str, rewritten: &str| -> Result<()> {
+        let (statements, _) = DfParser::parse_sql(rewritten)?;
+        expect_parse_ok(sql, statements[0].clone())
+    };
+
+    expect_same(
+        "SELECT a REGEXP '^x', t.b RLIKE lower(c) FROM t",
+        "SELECT regexp_like(a, '^x'), regexp_like(t.b, lower(c)) FROM t",
+    )?;
+    expect_same(
+        "SELECT * FROM t WHERE a = 1 AND f(a) NOT REGEXP (concat('a', b))",
+        "SELECT * FROM t WHERE a = 1 AND NOT regexp_like(f(a), (concat('a', b)))",
+    )?;
+    expect_same(
+        "SELECT 'abc' rlike 'b' FROM t",
+        "SELECT regexp_like('abc', 'b') FROM t",
+    )?;
+    expect_same(
+        "SELECT a + 1 REGEXP '^1' AND b * 2 NOT RLIKE c || 'x' FROM t",
+        "SELECT regexp_like(a + 1, '^1') AND NOT regexp_like(b * 2, c || 'x') FROM t",
+    )?;
+    expect_same(
+        "SELECT * FROM t WHERE a = b REGEXP 'x' OR NOT c REGEXP 'y'",
+        "SELECT * FROM t WHERE a = regexp_like(b, 'x') OR NOT regexp_like(c, 'y')",
+    )?;
+    expect_same(
+        "SELECT rlike, t.regexp, rlike REGEXP 'x' FROM t WHERE rlike = 1",
+        "SELECT rlike, t.regexp, regexp_like(rlike, 'x') FROM t WHERE rlike = 1",
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn grouping_sets() -> Result<()> {
+    let expect_same = |sql: &str, rewritten: &str| -> Result<()> {
+        let (statements, _) = DfParser::parse_sql(rewritten)?;
+        expect_parse_ok(sql, statements[0].clone())
+    };
+
+    expect_same(
+        "SELECT a, b, count(*) FROM t GROUP BY GROUPING SETS ((a, b), a, ())",
+        "SELECT a, b, count(*) FROM t GROUP BY groupingSets(groupingSet(a, b), a, groupingSet())",
+    )?;
+    expect_same(
+        "SELECT count(*) FROM t GROUP BY a, ROLLUP((a, b), c), CUBE(f(a), (b))",
+        "SELECT count(*) FROM t GROUP BY a, ROLLUP(groupingSet(a, b), c), CUBE(f(a), groupingSet(b))",
+    )?;
+    expect_same(
+        "SELECT rollup, cube((a)) FROM t GROUP BY rollup, f(ROLLUP((a))), CUBE (b) HAVING cube((a)) > 1",
+        "SELECT rollup, cube((a)) FROM t GROUP BY rollup, f(ROLLUP((a))), CUBE(b) HAVING cube((a)) > 1",
+    )?;
+    expect_same(
+        "SELECT * FROM (SELECT a FROM t GROUP BY ROLLUP((a))) WHERE a IN (SELECT rollup((a)) FROM t)",
+        "SELECT * FROM (SELECT a FROM t GROUP BY ROLLUP(groupingSet(a))) WHERE a IN (SELECT rollup((a)) FROM t)",
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
@@ -401,3 +637,319 @@ fn show_databases_test() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn create_and_drop_user() -> Result<()> {
+    expect_parse_ok(
+        "CREATE USER 'test' IDENTIFIED BY 'password'",
+        DfStatement::CreateUser(DfCreateUser {
+            if_not_exists: false,
+            name: "test".to_string(),
+            auth_type: AuthType::Sha256,
+            password: "password".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE USER IF NOT EXISTS test IDENTIFIED WITH plaintext_password BY 'password'",
+        DfStatement::CreateUser(DfCreateUser {
+            if_not_exists: true,
+            name: "test".to_string(),
+            auth_type: AuthType::PlainText,
+            password: "password".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE USER test IDENTIFIED WITH no_password",
+        DfStatement::CreateUser(DfCreateUser {
+            if_not_exists: false,
+            name: "test".to_string(),
+            auth_type: AuthType::None,
+            password: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER USER test IDENTIFIED WITH double_sha1_password BY 'new_password'",
+        DfStatement::AlterUser(DfAlterUser {
+            name: "test".to_string(),
+            auth_type: AuthType::DoubleSha1,
+            password: "new_password".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP USER IF EXISTS 'test'",
+        DfStatement::DropUser(DfDropUser {
+            if_exists: true,
+            name: "test".to_string(),
+        }),
+    )?;
+
+    assert!(DfParser::parse_sql("CREATE USER test IDENTIFIED WITH sha256_password").is_err());
+    assert!(DfParser::parse_sql("CREATE USER test IDENTIFIED WITH md5 BY 'pwd'").is_err());
+    Ok(())
+}
+
+#[test]
+fn create_and_drop_role() -> Result<()> {
+    expect_parse_ok(
+        "CREATE ROLE IF NOT EXISTS reader",
+        DfStatement::CreateRole(DfCreateRole {
+            if_not_exists: true,
+            name: "reader".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP ROLE reader",
+        DfStatement::DropRole(DfDropRole {
+            if_exists: false,
+            name: "reader".to_string(),
+        }),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn grant_and_revoke() -> Result<()> {
+    let mut select_insert = UserPrivilegeSet::empty();
+    select_insert.set_privilege(UserPrivilegeType::Select);
+    select_insert.set_privilege(UserPrivilegeType::Insert);
+
+    expect_parse_ok(
+        "GRANT SELECT, INSERT ON db1.t1 TO 'test'",
+        DfStatement::GrantPrivilege(DfGrantPrivilege {
+            privileges: select_insert,
+            on: DfGrantObject::Table(Some("db1".to_string()), "t1".to_string()),
+            principal: DfPrincipal::User("test".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT ALL PRIVILEGES ON *.* TO USER test",
+        DfStatement::GrantPrivilege(DfGrantPrivilege {
+            privileges: UserPrivilegeSet::all_privileges(),
+            on: DfGrantObject::Global,
+            principal: DfPrincipal::User("test".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT CREATE ON db1.* TO ROLE writer",
+        DfStatement::GrantPrivilege(DfGrantPrivilege {
+            privileges: UserPrivilegeType::Create.into(),
+            on: DfGrantObject::Database(Some("db1".to_string())),
+            principal: DfPrincipal::Role("writer".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE DROP ON * FROM ROLE writer",
+        DfStatement::RevokePrivilege(DfRevokePrivilege {
+            privileges: UserPrivilegeType::Drop.into(),
+            on: DfGrantObject::Database(None),
+            principal: DfPrincipal::Role("writer".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE ALL ON t1 FROM test",
+        DfStatement::RevokePrivilege(DfRevokePrivilege {
+            privileges: UserPrivilegeSet::all_privileges(),
+            on: DfGrantObject::Table(None, "t1".to_string()),
+            principal: DfPrincipal::User("test".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT ROLE writer TO test",
+        DfStatement::GrantRole(DfGrantRole {
+            role: "writer".to_string(),
+            user: "test".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE ROLE writer FROM test",
+        DfStatement::RevokeRole(DfRevokeRole {
+            role: "writer".to_string(),
+            user: "test".to_string(),
+        }),
+    )?;
+
+    assert!(DfParser::parse_sql("GRANT UPDATE ON db1.t1 TO test").is_err());
+    assert!(DfParser::parse_sql("GRANT SELECT ON *.t1 TO test").is_err());
+    Ok(())
+}
+
+#[test]
+fn create_and_drop_quota() -> Result<()> {
+    expect_parse_ok(
+        "CREATE QUOTA FOR USER test WITH interval = 3600, max_queries = 100, max_execution_time = 60",
+        DfStatement::CreateQuota(DfCreateQuota {
+            principal: DfPrincipal::User("test".to_string()),
+            quota: UserQuota {
+                interval_secs: 3600,
+                max_queries: 100,
+                max_execution_time_ms: 60000,
+                ..Default::default()
+            },
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE QUOTA FOR ROLE analyst WITH max_read_rows = 1000, max_read_bytes = 4096",
+        DfStatement::CreateQuota(DfCreateQuota {
+            principal: DfPrincipal::Role("analyst".to_string()),
+            quota: UserQuota {
+                max_read_rows: 1000,
+                max_read_bytes: 4096,
+                ..Default::default()
+            },
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP QUOTA FOR test",
+        DfStatement::DropQuota(DfDropQuota {
+            principal: DfPrincipal::User("test".to_string()),
+        }),
+    )?;
+
+    assert!(DfParser::parse_sql("CREATE QUOTA FOR test WITH max_memory = 1").is_err());
+    assert!(DfParser::parse_sql("CREATE QUOTA FOR test WITH max_queries = -1").is_err());
+    assert!(DfParser::parse_sql("CREATE QUOTA test WITH max_queries = 1").is_err());
+    Ok(())
+}
+
+#[test]
+fn create_and_drop_resource_group() -> Result<()> {
+    expect_parse_ok(
+        "CREATE RESOURCE GROUP IF NOT EXISTS etl WITH max_concurrency = 2, max_queued = 10",
+        DfStatement::CreateResourceGroup(DfCreateResourceGroup {
+            if_not_exists: true,
+            name: "etl".to_string(),
+            max_concurrency: 2,
+            max_queued: 10,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE RESOURCE GROUP dashboard WITH max_concurrency = 8",
+        DfStatement::CreateResourceGroup(DfCreateResourceGroup {
+            if_not_exists: false,
+            name: "dashboard".to_string(),
+            max_concurrency: 8,
+            max_queued: 0,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP RESOURCE GROUP IF EXISTS etl",
+        DfStatement::DropResourceGroup(DfDropResourceGroup {
+            if_exists: true,
+            name: "etl".to_string(),
+        }),
+    )?;
+
+    assert!(DfParser::parse_sql("CREATE RESOURCE GROUP etl WITH max_queued = 1").is_err());
+    assert!(DfParser::parse_sql("CREATE RESOURCE etl WITH max_concurrency = 1").is_err());
+    Ok(())
+}
+
+#[test]
+fn grant_and_revoke_resource_group() -> Result<()> {
+    expect_parse_ok(
+        "GRANT RESOURCE GROUP etl TO USER 'test'",
+        DfStatement::GrantResourceGroup(DfGrantResourceGroup {
+            name: Some("etl".to_string()),
+            principal: DfPrincipal::User("test".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT RESOURCE GROUP dashboard TO ROLE analyst",
+        DfStatement::GrantResourceGroup(DfGrantResourceGroup {
+            name: Some("dashboard".to_string()),
+            principal: DfPrincipal::Role("analyst".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE RESOURCE GROUP FROM test",
+        DfStatement::GrantResourceGroup(DfGrantResourceGroup {
+            name: None,
+            principal: DfPrincipal::User("test".to_string()),
+        }),
+    )?;
+
+    assert!(DfParser::parse_sql("GRANT RESOURCE etl TO test").is_err());
+    assert!(DfParser::parse_sql("REVOKE RESOURCE GROUP etl FROM test").is_err());
+    Ok(())
+}
+
+#[test]
+fn set_settings() -> Result<()> {
+    expect_parse_ok(
+        "SET GLOBAL max_threads = 4, timezone = 'Asia/Shanghai'",
+        DfStatement::SetSettings(DfSetSettings {
+            scope: SettingScope::Global,
+            vars: vec![
+                VarValue::create("max_threads", "4"),
+                VarValue::create("timezone", "Asia/Shanghai"),
+            ],
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SET GLOBAL max_threads = DEFAULT",
+        DfStatement::SetSettings(DfSetSettings {
+            scope: SettingScope::Global,
+            vars: vec![VarValue::create_default("max_threads")],
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER USER test SETTINGS max_block_size = 100, copy_file_format = csv",
+        DfStatement::SetSettings(DfSetSettings {
+            scope: SettingScope::User("test".to_string()),
+            vars: vec![
+                VarValue::create("max_block_size", "100"),
+                VarValue::create("copy_file_format", "csv"),
+            ],
+        }),
+    )?;
+
+    assert!(DfParser::parse_sql("SET GLOBAL max_threads").is_err());
+    assert!(DfParser::parse_sql("ALTER USER test SETTINGS").is_err());
+    Ok(())
+}
+
+#[test]
+fn query_settings() -> Result<()> {
+    let (statements, _) = DfParser::parse_sql("SELECT 1")?;
+    expect_parse_ok(
+        "SELECT 1 SETTINGS (max_threads = 1, enable_query_log = false)",
+        DfStatement::WithSettings(DfWithSettings {
+            settings: vec![
+                VarValue::create("max_threads", "1"),
+                VarValue::create("enable_query_log", "false"),
+            ],
+            statement: Box::new(statements[0].clone()),
+        }),
+    )?;
+
+    // Only the settings at the end of the statement are the query settings.
+    let (statements, _) = DfParser::parse_sql("CREATE TABLE settings(a int)")?;
+    assert!(matches!(statements[0], DfStatement::CreateTable(_)));
+
+    let (statements, _) = DfParser::parse_sql("SELECT 1 SETTINGS (max_threads = 1); SELECT 2")?;
+    assert_eq!(statements.len(), 2);
+    assert!(matches!(statements[0], DfStatement::WithSettings(_)));
+    assert!(matches!(statements[1], DfStatement::Statement(_)));
+
+    assert!(DfParser::parse_sql("SELECT 1 SETTINGS (max_threads)").is_err());
+    Ok(())
+}
@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_management::AuthType;
+use common_management::UserPrivilegeSet;
+use common_management::UserQuota;
 use common_planners::ExplainType;
+use common_planners::SettingScope;
+use common_planners::VarValue;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_till1;
 use nom::character::complete::digit1;
@@ -23,6 +28,7 @@ use sqlparser::ast::ColumnDef;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
 use sqlparser::ast::SqlOption;
 use sqlparser::ast::Statement as SQLStatement;
 
@@ -106,6 +112,139 @@ pub struct DfKillStatement {
     pub object_id: Ident,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCopyInto {
+    /// Target location, such as 's3://bucket/path/'
+    pub location: String,
+    pub query: Box<Query>,
+    /// FILE_FORMAT = (TYPE = PARQUET ...)
+    pub file_format: Vec<SqlOption>,
+    /// Copy options, such as MAX_FILE_SIZE
+    pub options: Vec<SqlOption>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateUser {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub auth_type: AuthType,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterUser {
+    pub name: String,
+    pub auth_type: AuthType,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDropUser {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateRole {
+    pub if_not_exists: bool,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDropRole {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+/// The object of GRANT/REVOKE, the database is the current database if it's omitted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfGrantObject {
+    /// *.*
+    Global,
+    /// db.* or *
+    Database(Option<String>),
+    /// db.table or table
+    Table(Option<String>, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfPrincipal {
+    User(String),
+    Role(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfGrantPrivilege {
+    pub privileges: UserPrivilegeSet,
+    pub on: DfGrantObject,
+    pub principal: DfPrincipal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfRevokePrivilege {
+    pub privileges: UserPrivilegeSet,
+    pub on: DfGrantObject,
+    pub principal: DfPrincipal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfGrantRole {
+    pub role: String,
+    pub user: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfRevokeRole {
+    pub role: String,
+    pub user: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateQuota {
+    pub principal: DfPrincipal,
+    pub quota: UserQuota,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDropQuota {
+    pub principal: DfPrincipal,
+}
+
+/// SET GLOBAL <name> = <value> or ALTER USER <name> SETTINGS <name> = <value>
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfSetSettings {
+    pub scope: SettingScope,
+    pub vars: Vec<VarValue>,
+}
+
+/// A statement with the settings of the query: <statement> SETTINGS (<name> = <value>, ...)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfWithSettings {
+    pub settings: Vec<VarValue>,
+    pub statement: Box<DfStatement>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateResourceGroup {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub max_concurrency: u64,
+    pub max_queued: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDropResourceGroup {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+/// GRANT RESOURCE GROUP <name> TO ..., or REVOKE RESOURCE GROUP FROM ... if name is None.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfGrantResourceGroup {
+    pub name: Option<String>,
+    pub principal: DfPrincipal,
+}
+
 /// Tokens parsed by `DFParser` are converted into these values.
 #[derive(Debug, Clone, PartialEq)]
 pub enum DfStatement {
@@ -127,8 +266,13 @@ pub enum DfStatement {
     DropTable(DfDropTable),
     TruncateTable(DfTruncateTable),
 
+    // Unload.
+    CopyInto(DfCopyInto),
+
     // Settings.
     ShowSettings(DfShowSettings),
+    SetSettings(DfSetSettings),
+    WithSettings(DfWithSettings),
 
     // ProcessList
     ShowProcessList(DfShowProcessList),
@@ -136,6 +280,26 @@ pub enum DfStatement {
     // Kill
     KillQuery(DfKillStatement),
     KillConn(DfKillStatement),
+
+    // Users and roles.
+    CreateUser(DfCreateUser),
+    AlterUser(DfAlterUser),
+    DropUser(DfDropUser),
+    CreateRole(DfCreateRole),
+    DropRole(DfDropRole),
+
+    // Privileges.
+    GrantPrivilege(DfGrantPrivilege),
+    RevokePrivilege(DfRevokePrivilege),
+    GrantRole(DfGrantRole),
+    RevokeRole(DfRevokeRole),
+
+    // Quotas and resource groups.
+    CreateQuota(DfCreateQuota),
+    DropQuota(DfDropQuota),
+    CreateResourceGroup(DfCreateResourceGroup),
+    DropResourceGroup(DfDropResourceGroup),
+    GrantResourceGroup(DfGrantResourceGroup),
 }
 
 /// Comment hints from SQL.
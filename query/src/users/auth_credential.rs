@@ -0,0 +1,111 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_management::AuthType;
+use common_management::UserInfo;
+use sha2::Digest;
+
+use crate::users::encode_password;
+
+/// What a client presents to prove it knows the password of a user.
+#[derive(Clone, Copy, Debug)]
+pub enum AuthCredential<'a> {
+    /// The password in clear text, as sent by the ClickHouse handler or `mysql_clear_password`.
+    Password(&'a [u8]),
+    /// `SHA1(password) XOR SHA1(salt + SHA1(SHA1(password)))`, sent by `mysql_native_password`.
+    NativePassword { salt: &'a [u8], scramble: &'a [u8] },
+    /// `SHA256(password) XOR SHA256(SHA256(SHA256(password)) + salt)`, sent by the fast path
+    /// of `caching_sha2_password`.
+    CachingSha2Password { salt: &'a [u8], scramble: &'a [u8] },
+}
+
+impl<'a> AuthCredential<'a> {
+    pub fn verify(&self, user: &UserInfo) -> bool {
+        match (&user.auth_type, *self) {
+            (AuthType::None, _) => true,
+            (auth_type, AuthCredential::Password(password)) => {
+                user.password == encode_password(auth_type, password)
+            }
+            // The clients send an empty scramble for an empty password.
+            (_, AuthCredential::NativePassword { scramble, .. })
+            | (_, AuthCredential::CachingSha2Password { scramble, .. })
+                if scramble.is_empty() =>
+            {
+                AuthCredential::Password(&[]).verify(user)
+            }
+            (AuthType::PlainText, AuthCredential::NativePassword { salt, scramble }) => {
+                let stage2 = encode_password(&AuthType::DoubleSha1, &user.password);
+                verify_native_scramble(&stage2, salt, scramble)
+            }
+            (AuthType::DoubleSha1, AuthCredential::NativePassword { salt, scramble }) => {
+                verify_native_scramble(&user.password, salt, scramble)
+            }
+            (AuthType::PlainText, AuthCredential::CachingSha2Password { salt, scramble }) => {
+                let stage1 = encode_password(&AuthType::Sha256, &user.password);
+                verify_sha2_scramble(&stage1, salt, scramble)
+            }
+            (AuthType::Sha256, AuthCredential::CachingSha2Password { salt, scramble }) => {
+                verify_sha2_scramble(&user.password, salt, scramble)
+            }
+            // The stored digest cannot check the scramble of the other plugin.
+            _ => false,
+        }
+    }
+}
+
+impl<'a> From<&'a [u8]> for AuthCredential<'a> {
+    fn from(password: &'a [u8]) -> Self {
+        AuthCredential::Password(password)
+    }
+}
+
+impl<'a> From<&'a str> for AuthCredential<'a> {
+    fn from(password: &'a str) -> Self {
+        AuthCredential::Password(password.as_bytes())
+    }
+}
+
+// Recover SHA1(password) from the scramble and check it against the stored SHA1(SHA1(password)).
+fn verify_native_scramble(stage2: &[u8], salt: &[u8], scramble: &[u8]) -> bool {
+    let mut m = sha1::Sha1::new();
+    m.update(salt);
+    m.update(stage2);
+    let mask = m.digest().bytes();
+    if scramble.len() != mask.len() {
+        return false;
+    }
+
+    let stage1 = xor(scramble, &mask);
+    let mut m = sha1::Sha1::new();
+    m.update(&stage1);
+    m.digest().bytes()[..] == stage2[..]
+}
+
+// Recover SHA256(password) from the scramble and check it against the stored one.
+fn verify_sha2_scramble(stage1: &[u8], salt: &[u8], scramble: &[u8]) -> bool {
+    let stage2 = sha2::Sha256::digest(stage1);
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(&stage2);
+    hasher.update(salt);
+    let mask = hasher.finalize();
+    if scramble.len() != mask.len() {
+        return false;
+    }
+
+    xor(scramble, &mask) == stage1
+}
+
+fn xor(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    lhs.iter().zip(rhs.iter()).map(|(l, r)| l ^ r).collect()
+}
@@ -12,12 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod password_masker_test;
 #[cfg(test)]
 mod user_mgr_test;
 
+mod auth_credential;
+mod password_masker;
 mod user;
 mod user_mgr;
 
+pub use auth_credential::AuthCredential;
+pub use password_masker::mask_passwords;
 pub use user::User;
+pub use user_mgr::encode_password;
 pub use user_mgr::UserManager;
 pub use user_mgr::UserManagerRef;
@@ -0,0 +1,61 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Replaces the string literals after `IDENTIFIED` of `CREATE USER` and `ALTER USER` by '***',
+/// so the passwords are not kept in the logs, the query log and the processes.
+pub fn mask_passwords(query: &str) -> String {
+    // The ASCII lowercase keeps the byte positions of the query.
+    let lowercase = query.to_ascii_lowercase();
+    let mut words = lowercase.split_whitespace();
+    match (words.next(), words.next()) {
+        (Some("create"), Some("user")) | (Some("alter"), Some("user")) => {}
+        _ => return query.to_string(),
+    }
+
+    let position = match lowercase.find("identified") {
+        None => return query.to_string(),
+        Some(position) => position,
+    };
+
+    let (head, tail) = query.split_at(position);
+    let mut masked = String::with_capacity(query.len());
+    masked.push_str(head);
+
+    let mut chars = tail.chars().peekable();
+    while let Some(c) = chars.next() {
+        masked.push(c);
+        if c != '\'' && c != '"' {
+            continue;
+        }
+
+        // Skip the literal, the quote is escaped by a backslash or by doubling it.
+        masked.push_str("***");
+        while let Some(n) = chars.next() {
+            match n {
+                '\\' => {
+                    chars.next();
+                }
+                n if n == c && chars.peek() == Some(&c) => {
+                    chars.next();
+                }
+                n if n == c => {
+                    masked.push(c);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    }
+    masked
+}
@@ -0,0 +1,46 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pretty_assertions::assert_eq;
+
+use crate::users::mask_passwords;
+
+#[test]
+fn test_mask_passwords() {
+    let tests = vec![
+        ("SELECT 'identified by'", "SELECT 'identified by'"),
+        ("CREATE USER u", "CREATE USER u"),
+        (
+            "CREATE USER u IDENTIFIED BY 'password'",
+            "CREATE USER u IDENTIFIED BY '***'",
+        ),
+        (
+            "create user 'u' identified with sha256_password by \"pass'word\"",
+            "create user 'u' identified with sha256_password by \"***\"",
+        ),
+        (
+            "ALTER USER u IDENTIFIED BY 'it''s \\'secret\\''",
+            "ALTER USER u IDENTIFIED BY '***'",
+        ),
+        // An unterminated literal is masked to the end.
+        (
+            "ALTER USER u IDENTIFIED BY 'pass",
+            "ALTER USER u IDENTIFIED BY '***",
+        ),
+    ];
+
+    for (query, expect) in tests {
+        assert_eq!(mask_passwords(query), expect, "{}", query);
+    }
+}
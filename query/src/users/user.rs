@@ -16,6 +16,8 @@
 use common_management::AuthType;
 use common_management::UserInfo;
 
+use crate::users::encode_password;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct User {
     name: String,
@@ -35,11 +37,11 @@ impl User {
 
 impl From<&User> for UserInfo {
     fn from(user: &User) -> Self {
-        UserInfo {
-            name: user.name.clone(),
-            password: Vec::from(user.password.clone()),
-            auth_type: user.auth_type.clone(),
-        }
+        UserInfo::new(
+            user.name.clone(),
+            encode_password(&user.auth_type, &user.password),
+            user.auth_type.clone(),
+        )
     }
 }
 
@@ -13,23 +13,47 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_kv_api::KVApi;
 use common_management::AuthType;
+use common_management::GrantObject;
+use common_management::QuotaMgr;
+use common_management::QuotaMgrApi;
+use common_management::QuotaUsage;
+use common_management::ResourceGroupInfo;
+use common_management::ResourceGroupMgr;
+use common_management::ResourceGroupMgrApi;
+use common_management::RoleInfo;
+use common_management::RoleMgr;
+use common_management::RoleMgrApi;
+use common_management::SettingMgr;
+use common_management::SettingMgrApi;
 use common_management::UserInfo;
 use common_management::UserMgr;
 use common_management::UserMgrApi;
+use common_management::UserPrivilegeSet;
+use common_management::UserPrivilegeType;
+use common_management::UserQuota;
+use common_management::UserSetting;
 use sha2::Digest;
 
 use crate::common::StoreApiProvider;
 use crate::configs::Config;
+use crate::users::AuthCredential;
 use crate::users::User;
 
 pub type UserManagerRef = Arc<UserManager>;
 
 pub struct UserManager {
     api_provider: Arc<dyn UserMgrApi>,
+    role_api_provider: Arc<dyn RoleMgrApi>,
+    quota_api_provider: Arc<dyn QuotaMgrApi>,
+    resource_group_api_provider: Arc<dyn ResourceGroupMgrApi>,
+    setting_api_provider: Arc<dyn SettingMgrApi>,
 }
 
 impl UserManager {
@@ -44,49 +68,60 @@ impl UserManager {
     pub async fn create_global(cfg: Config) -> Result<UserManagerRef> {
         let client = UserManager::create_kv_client(&cfg).await?;
         let tenant = &cfg.query.tenant;
-        let user_manager = UserMgr::new(client, tenant);
+        let user_manager = UserMgr::new(client.clone(), tenant);
+        let role_manager = RoleMgr::new(client.clone(), tenant);
+        let quota_manager = QuotaMgr::new(client.clone(), tenant);
+        let resource_group_manager = ResourceGroupMgr::new(client.clone(), tenant);
+        let setting_manager = SettingMgr::new(client, tenant);
 
-        Ok(Arc::new(UserManager {
+        let user_manager = UserManager {
             api_provider: Arc::new(user_manager),
-        }))
+            role_api_provider: Arc::new(role_manager),
+            quota_api_provider: Arc::new(quota_manager),
+            resource_group_api_provider: Arc::new(resource_group_manager),
+            setting_api_provider: Arc::new(setting_manager),
+        };
+        user_manager.create_initial_admin(&cfg)?;
+        Ok(Arc::new(user_manager))
+    }
+
+    // Create the configured initial admin user with all the privileges, if the tenant has no
+    // such user yet. It's only written once, so the later ALTER USER, GRANT and REVOKE of it
+    // are kept across restarts.
+    fn create_initial_admin(&self, cfg: &Config) -> Result<()> {
+        let password = &cfg.query.initial_admin_password;
+        let auth_type = if password.is_empty() {
+            AuthType::None
+        } else {
+            AuthType::Sha256
+        };
+        let mut user_info: UserInfo =
+            User::new(&cfg.query.initial_admin_user, password, auth_type).into();
+        user_info
+            .grants
+            .grant_privileges(&GrantObject::Global, UserPrivilegeSet::all_privileges());
+
+        match self.api_provider.add_user(user_info) {
+            Ok(_) => Ok(()),
+            Err(cause) if cause.code() == ErrorCode::UserAlreadyExists("").code() => Ok(()),
+            Err(cause) => Err(cause.add_message_back("(while create the initial admin user).")),
+        }
     }
 
     // Get one user from by tenant.
     pub fn get_user(&self, user: &str) -> Result<UserInfo> {
-        match user {
-            // TODO(BohuTANG): Mock, need removed.
-            "default" | "" | "root" => {
-                let user = User::new(user, "", AuthType::None);
-                Ok(user.into())
-            }
-            _ => Ok(self.api_provider.get_user(user.to_string(), None)?.1),
-        }
+        let (_, user_info) = self.api_provider.get_user(user.to_string(), None)?;
+        Ok(user_info)
     }
 
-    // Auth the user and password for different Auth type.
-    pub fn auth_user(&self, user: &str, password: impl AsRef<[u8]>) -> Result<bool> {
+    // Auth the user with the password or the scramble of it, for different Auth type.
+    pub fn auth_user<'a>(
+        &self,
+        user: &str,
+        credential: impl Into<AuthCredential<'a>>,
+    ) -> Result<bool> {
         let user = self.get_user(user)?;
-
-        match user.auth_type {
-            AuthType::None => Ok(true),
-            AuthType::PlainText => Ok(user.password == password.as_ref()),
-            // MySQL already did x = sha1(x)
-            // so we just check double sha1(x)
-            AuthType::DoubleSha1 => {
-                let mut m = sha1::Sha1::new();
-                m.update(password.as_ref());
-
-                let bs = m.digest().bytes();
-                let mut m = sha1::Sha1::new();
-                m.update(&bs[..]);
-
-                Ok(user.password == m.digest().bytes().to_vec())
-            }
-            AuthType::Sha256 => {
-                let result = sha2::Sha256::digest(password.as_ref());
-                Ok(user.password == result.to_vec())
-            }
-        }
+        Ok(credential.into().verify(&user))
     }
 
     // Get the tenant all users list.
@@ -104,8 +139,327 @@ impl UserManager {
         self.api_provider.add_user(user_info)
     }
 
+    // Change the password and the auth type of the user.
+    pub fn update_user(&self, user: &str, password: Vec<u8>, auth_type: AuthType) -> Result<()> {
+        self.api_provider
+            .update_user(user.to_string(), Some(password), Some(auth_type), None)?;
+        Ok(())
+    }
+
     // Drop a user by name.
     pub fn drop_user(&self, user: &str) -> Result<()> {
         self.api_provider.drop_user(user.to_string(), None)
     }
+
+    pub fn grant_user_privileges(
+        &self,
+        user: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<()> {
+        self.api_provider
+            .grant_user_privileges(user.to_string(), object, privileges, None)?;
+        Ok(())
+    }
+
+    pub fn revoke_user_privileges(
+        &self,
+        user: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<()> {
+        self.api_provider
+            .revoke_user_privileges(user.to_string(), object, privileges, None)?;
+        Ok(())
+    }
+
+    // Grant a role to the user, the role must exist.
+    pub fn grant_user_role(&self, user: &str, role: &str) -> Result<()> {
+        self.get_role(role)?;
+        self.api_provider
+            .grant_user_role(user.to_string(), role.to_string(), None)?;
+        Ok(())
+    }
+
+    pub fn revoke_user_role(&self, user: &str, role: &str) -> Result<()> {
+        self.api_provider
+            .revoke_user_role(user.to_string(), role.to_string(), None)?;
+        Ok(())
+    }
+
+    // Get one role by name.
+    pub fn get_role(&self, role: &str) -> Result<RoleInfo> {
+        Ok(self.role_api_provider.get_role(role.to_string(), None)?.1)
+    }
+
+    // Get the tenant all roles list.
+    pub fn get_roles(&self) -> Result<Vec<RoleInfo>> {
+        let roles = self.role_api_provider.get_roles()?;
+        Ok(roles.into_iter().map(|role| role.1).collect())
+    }
+
+    // Add a new role without any privileges.
+    pub fn add_role(&self, role_info: RoleInfo) -> Result<u64> {
+        self.role_api_provider.add_role(role_info)
+    }
+
+    // Drop a role by name, the users granted the role lose its privileges.
+    pub fn drop_role(&self, role: &str) -> Result<()> {
+        self.role_api_provider.drop_role(role.to_string(), None)
+    }
+
+    pub fn grant_role_privileges(
+        &self,
+        role: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<()> {
+        self.role_api_provider
+            .grant_role_privileges(role.to_string(), object, privileges, None)?;
+        Ok(())
+    }
+
+    pub fn revoke_role_privileges(
+        &self,
+        role: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<()> {
+        self.role_api_provider.revoke_role_privileges(
+            role.to_string(),
+            object,
+            privileges,
+            None,
+        )?;
+        Ok(())
+    }
+
+    // Whether the user has the privilege on the object,
+    // which is granted to the user itself or to one of its roles.
+    pub fn verify_privilege(
+        &self,
+        user: &str,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> Result<bool> {
+        let user_info = self.get_user(user)?;
+        if user_info.grants.verify_privilege(object, privilege) {
+            return Ok(true);
+        }
+
+        for role in &user_info.roles {
+            match self.get_role(role) {
+                Ok(role_info) if role_info.grants.verify_privilege(object, privilege) => {
+                    return Ok(true);
+                }
+                Ok(_) => {}
+                // The role is dropped after it's granted.
+                Err(cause) if cause.code() == ErrorCode::UnknownRole("").code() => {}
+                Err(cause) => return Err(cause),
+            }
+        }
+        Ok(false)
+    }
+
+    // Set or remove(None) the quota of the user.
+    pub fn set_user_quota(&self, user: &str, quota: Option<UserQuota>) -> Result<()> {
+        self.api_provider
+            .set_user_quota(user.to_string(), quota, None)?;
+        Ok(())
+    }
+
+    // Set or remove(None) the quota of the role, it limits every user granted the role.
+    pub fn set_role_quota(&self, role: &str, quota: Option<UserQuota>) -> Result<()> {
+        self.role_api_provider
+            .set_role_quota(role.to_string(), quota, None)?;
+        Ok(())
+    }
+
+    // The quotas limiting the user, which are set to the user itself or to one of its roles.
+    pub fn get_user_quotas(&self, user: &str) -> Result<Vec<UserQuota>> {
+        let user_info = self.get_user(user)?;
+        let mut quotas: Vec<UserQuota> = user_info.quota.into_iter().collect();
+
+        for role in &user_info.roles {
+            match self.get_role(role) {
+                Ok(role_info) => quotas.extend(role_info.quota),
+                // The role is dropped after it's granted.
+                Err(cause) if cause.code() == ErrorCode::UnknownRole("").code() => {}
+                Err(cause) => return Err(cause),
+            }
+        }
+        Ok(quotas)
+    }
+
+    // The usage of the user in the current interval of the quota.
+    pub fn get_quota_usage(&self, user: &str, quota: &UserQuota) -> Result<QuotaUsage> {
+        self.quota_api_provider
+            .get_usage(user.to_string(), quota, Self::now_secs())
+    }
+
+    // Check whether the user is allowed to run a new query by its quotas.
+    pub fn check_quota(&self, user: &str) -> Result<()> {
+        for quota in self.get_user_quotas(user)? {
+            let usage = self.get_quota_usage(user, &quota)?;
+            if let Some(limit) = usage.exceeded(&quota) {
+                return Err(ErrorCode::QuotaExceeded(format!(
+                    "Quota exceeded for user {}: {} in the interval of {} seconds",
+                    user, limit, quota.interval_secs
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // Add the usage of a finished query of the user, once for every interval length of its quotas.
+    pub fn record_quota_usage(&self, user: &str, usage: &QuotaUsage) -> Result<()> {
+        let mut quotas = self.get_user_quotas(user)?;
+        quotas.sort_by_key(|quota| quota.interval_secs);
+        quotas.dedup_by_key(|quota| quota.interval_secs);
+
+        let now = Self::now_secs();
+        for quota in &quotas {
+            self.quota_api_provider
+                .add_usage(user.to_string(), quota, now, usage)?;
+        }
+        Ok(())
+    }
+
+    fn now_secs() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0)
+    }
+
+    // Bind the resource group to the user, or unbind it(None).
+    pub fn set_user_resource_group(
+        &self,
+        user: &str,
+        resource_group: Option<String>,
+    ) -> Result<()> {
+        self.api_provider
+            .set_user_resource_group(user.to_string(), resource_group, None)?;
+        Ok(())
+    }
+
+    // Bind the resource group to the role, or unbind it(None), it queues every user granted the role.
+    pub fn set_role_resource_group(
+        &self,
+        role: &str,
+        resource_group: Option<String>,
+    ) -> Result<()> {
+        self.role_api_provider
+            .set_role_resource_group(role.to_string(), resource_group, None)?;
+        Ok(())
+    }
+
+    // The resource group queueing the queries of the user: the one bound to the user itself,
+    // or else the one bound to the first of its roles having one.
+    pub fn get_user_resource_group(&self, user: &str) -> Result<Option<String>> {
+        let user_info = self.get_user(user)?;
+        if user_info.resource_group.is_some() {
+            return Ok(user_info.resource_group);
+        }
+
+        for role in &user_info.roles {
+            match self.get_role(role) {
+                Ok(role_info) if role_info.resource_group.is_some() => {
+                    return Ok(role_info.resource_group);
+                }
+                Ok(_) => {}
+                // The role is dropped after it's granted.
+                Err(cause) if cause.code() == ErrorCode::UnknownRole("").code() => {}
+                Err(cause) => return Err(cause),
+            }
+        }
+        Ok(None)
+    }
+
+    // Get one resource group by name.
+    pub fn get_resource_group(&self, name: &str) -> Result<ResourceGroupInfo> {
+        Ok(self
+            .resource_group_api_provider
+            .get_resource_group(name.to_string(), None)?
+            .1)
+    }
+
+    // Get the tenant all resource groups list.
+    pub fn get_resource_groups(&self) -> Result<Vec<ResourceGroupInfo>> {
+        let groups = self.resource_group_api_provider.get_resource_groups()?;
+        Ok(groups.into_iter().map(|group| group.1).collect())
+    }
+
+    pub fn add_resource_group(&self, resource_group: ResourceGroupInfo) -> Result<u64> {
+        self.resource_group_api_provider
+            .add_resource_group(resource_group)
+    }
+
+    pub fn drop_resource_group(&self, name: &str) -> Result<()> {
+        self.resource_group_api_provider
+            .drop_resource_group(name.to_string(), None)
+    }
+
+    // Get the tenant global settings, which are applied to the new sessions.
+    pub fn get_global_settings(&self) -> Result<Vec<UserSetting>> {
+        let settings = self.setting_api_provider.get_settings()?;
+        Ok(settings.into_iter().map(|setting| setting.1).collect())
+    }
+
+    pub fn set_global_setting(&self, name: &str, value: &str) -> Result<()> {
+        self.setting_api_provider
+            .set_setting(UserSetting::new(name, value))?;
+        Ok(())
+    }
+
+    // Reset the global setting to the default value, it's fine if it's not set.
+    pub fn drop_global_setting(&self, name: &str) -> Result<()> {
+        match self
+            .setting_api_provider
+            .drop_setting(name.to_lowercase(), None)
+        {
+            Err(cause) if cause.code() == ErrorCode::UnknownVariable("").code() => Ok(()),
+            res => res,
+        }
+    }
+
+    // Set the settings of the user, the value None resets the setting to the default value.
+    pub fn set_user_settings(
+        &self,
+        user: &str,
+        settings: &[(String, Option<String>)],
+    ) -> Result<()> {
+        let mut user_settings = self.get_user(user)?.settings;
+        for (name, value) in settings {
+            let setting_name = name.to_lowercase();
+            user_settings.retain(|setting| setting.name != setting_name);
+            if let Some(value) = value {
+                user_settings.push(UserSetting::new(name, value));
+            }
+        }
+
+        self.api_provider
+            .set_user_settings(user.to_string(), user_settings, None)?;
+        Ok(())
+    }
+}
+
+/// Encode the password as it's stored by the auth type.
+pub fn encode_password(auth_type: &AuthType, password: impl AsRef<[u8]>) -> Vec<u8> {
+    match auth_type {
+        AuthType::None => vec![],
+        AuthType::PlainText => password.as_ref().to_vec(),
+        // MySQL already did x = sha1(x)
+        // so we just check double sha1(x)
+        AuthType::DoubleSha1 => {
+            let mut m = sha1::Sha1::new();
+            m.update(password.as_ref());
+
+            let bs = m.digest().bytes();
+            let mut m = sha1::Sha1::new();
+            m.update(&bs[..]);
+            m.digest().bytes().to_vec()
+        }
+        AuthType::Sha256 => sha2::Sha256::digest(password.as_ref()).to_vec(),
+    }
 }
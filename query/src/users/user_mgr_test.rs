@@ -13,11 +13,22 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_management::AuthType;
+use common_management::GrantObject;
+use common_management::QuotaUsage;
+use common_management::ResourceGroupInfo;
+use common_management::RoleInfo;
+use common_management::UserPrivilegeSet;
+use common_management::UserPrivilegeType;
+use common_management::UserQuota;
 use pretty_assertions::assert_eq;
+use sha2::Digest;
 
 use crate::configs::Config;
+use crate::users::encode_password;
+use crate::users::AuthCredential;
 use crate::users::User;
 use crate::users::UserManager;
 
@@ -36,10 +47,12 @@ async fn test_user_manager() -> Result<()> {
         user_mgr.add_user(user_info.into())?;
     }
 
-    // get all users.
+    // get all users, with the initial admin user.
     {
         let users = user_mgr.get_users()?;
-        assert_eq!(pwd.as_bytes(), users[0].password);
+        assert_eq!(2, users.len());
+        let added = users.iter().find(|u| u.name == user).unwrap();
+        assert_eq!(pwd.as_bytes(), added.password);
     }
 
     // get.
@@ -52,8 +65,285 @@ async fn test_user_manager() -> Result<()> {
     {
         user_mgr.drop_user(user)?;
         let users = user_mgr.get_users()?;
-        assert_eq!(0, users.len());
+        assert_eq!(1, users.len());
+        assert_eq!("default", users[0].name);
     }
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_manager_initial_admin() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant = "tenant-admin".to_string();
+    config.query.initial_admin_user = "admin".to_string();
+    config.query.initial_admin_password = "admin-pwd".to_string();
+
+    let user_mgr = UserManager::create_global(config.clone()).await?;
+    assert!(user_mgr.auth_user("admin", "admin-pwd")?);
+    assert!(!user_mgr.auth_user("admin", "")?);
+    assert!(user_mgr.get_user("default").is_err());
+
+    let table = GrantObject::Table("db1".to_string(), "t1".to_string());
+    assert!(user_mgr.verify_privilege("admin", &table, UserPrivilegeType::Drop)?);
+
+    // the initial admin user is written once, the later changes of it are kept.
+    {
+        let password = encode_password(&AuthType::Sha256, "new-pwd");
+        user_mgr.update_user("admin", password, AuthType::Sha256)?;
+        let user_mgr = UserManager::create_global(config).await?;
+        assert!(user_mgr.auth_user("admin", "new-pwd")?);
+        assert!(!user_mgr.auth_user("admin", "admin-pwd")?);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_manager_privileges() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant = "tenant2".to_string();
+
+    let user = "test-user2";
+    let user_mgr = UserManager::create_global(config).await?;
+    user_mgr.add_user(User::new(user, "test-pwd", AuthType::Sha256).into())?;
+
+    let table = GrantObject::Table("db1".to_string(), "t1".to_string());
+
+    // the initial admin user has all the privileges, and there are no builtin users.
+    {
+        let admin = user_mgr.get_user("default")?;
+        assert!(admin
+            .grants
+            .verify_privilege(&table, UserPrivilegeType::Drop));
+        assert!(user_mgr.auth_user("default", "")?);
+        assert!(user_mgr.get_user("root").is_err());
+        assert!(user_mgr.get_user("").is_err());
+    }
+
+    // the created users have no privileges.
+    {
+        assert!(user_mgr.auth_user(user, "test-pwd")?);
+        assert!(!user_mgr.auth_user(user, "wrong-pwd")?);
+        assert!(!user_mgr.verify_privilege(user, &table, UserPrivilegeType::Select)?);
+    }
+
+    // grant and revoke to the user.
+    {
+        let db = GrantObject::Database("db1".to_string());
+        user_mgr.grant_user_privileges(user, db.clone(), UserPrivilegeType::Select.into())?;
+        assert!(user_mgr.verify_privilege(user, &table, UserPrivilegeType::Select)?);
+        assert!(!user_mgr.verify_privilege(user, &table, UserPrivilegeType::Insert)?);
+
+        user_mgr.revoke_user_privileges(user, db, UserPrivilegeSet::all_privileges())?;
+        assert!(!user_mgr.verify_privilege(user, &table, UserPrivilegeType::Select)?);
+    }
+
+    // grant by role.
+    {
+        user_mgr.add_role(RoleInfo::new("writer".to_string()))?;
+        user_mgr.grant_role_privileges(
+            "writer",
+            table.clone(),
+            UserPrivilegeType::Insert.into(),
+        )?;
+        user_mgr.grant_user_role(user, "writer")?;
+        assert!(user_mgr.verify_privilege(user, &table, UserPrivilegeType::Insert)?);
+
+        user_mgr.drop_role("writer")?;
+        assert!(!user_mgr.verify_privilege(user, &table, UserPrivilegeType::Insert)?);
+
+        let res = user_mgr.grant_user_role(user, "unknown");
+        assert_eq!(3003, res.unwrap_err().code());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_manager_auth_scramble() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant = "tenant3".to_string();
+
+    let user_mgr = UserManager::create_global(config).await?;
+    user_mgr.add_user(User::new("native-user", "pwd", AuthType::DoubleSha1).into())?;
+    user_mgr.add_user(User::new("sha2-user", "pwd", AuthType::Sha256).into())?;
+    user_mgr.add_user(User::new("plain-user", "pwd", AuthType::PlainText).into())?;
+
+    let salt = [7u8; 20];
+
+    // mysql_native_password.
+    {
+        let scramble = native_scramble(b"pwd", &salt);
+        let credential = AuthCredential::NativePassword {
+            salt: &salt,
+            scramble: &scramble,
+        };
+        assert!(user_mgr.auth_user("native-user", credential)?);
+        assert!(user_mgr.auth_user("plain-user", credential)?);
+        // The sha256 digest cannot check the native scramble.
+        assert!(!user_mgr.auth_user("sha2-user", credential)?);
+
+        let scramble = native_scramble(b"wrong-pwd", &salt);
+        let credential = AuthCredential::NativePassword {
+            salt: &salt,
+            scramble: &scramble,
+        };
+        assert!(!user_mgr.auth_user("native-user", credential)?);
+    }
+
+    // caching_sha2_password.
+    {
+        let scramble = sha2_scramble(b"pwd", &salt);
+        let credential = AuthCredential::CachingSha2Password {
+            salt: &salt,
+            scramble: &scramble,
+        };
+        assert!(user_mgr.auth_user("sha2-user", credential)?);
+        assert!(user_mgr.auth_user("plain-user", credential)?);
+        assert!(!user_mgr.auth_user("native-user", credential)?);
+
+        let scramble = sha2_scramble(b"wrong-pwd", &salt);
+        let credential = AuthCredential::CachingSha2Password {
+            salt: &salt,
+            scramble: &scramble,
+        };
+        assert!(!user_mgr.auth_user("sha2-user", credential)?);
+    }
+
+    // clear text and empty scramble.
+    {
+        assert!(user_mgr.auth_user("sha2-user", "pwd")?);
+        assert!(user_mgr.auth_user("native-user", "pwd")?);
+
+        let credential = AuthCredential::CachingSha2Password {
+            salt: &salt,
+            scramble: &[],
+        };
+        assert!(!user_mgr.auth_user("sha2-user", credential)?);
+        assert!(user_mgr.auth_user("default", credential)?);
+    }
+
+    Ok(())
+}
+
+// What the client computes for mysql_native_password.
+fn native_scramble(password: &[u8], salt: &[u8]) -> Vec<u8> {
+    let mut m = sha1::Sha1::new();
+    m.update(password);
+    let stage1 = m.digest().bytes();
+    let mut m = sha1::Sha1::new();
+    m.update(&stage1);
+    let stage2 = m.digest().bytes();
+    let mut m = sha1::Sha1::new();
+    m.update(salt);
+    m.update(&stage2);
+    let mask = m.digest().bytes();
+    stage1.iter().zip(mask.iter()).map(|(l, r)| l ^ r).collect()
+}
+
+// What the client computes for the fast path of caching_sha2_password.
+fn sha2_scramble(password: &[u8], salt: &[u8]) -> Vec<u8> {
+    let stage1 = sha2::Sha256::digest(password);
+    let stage2 = sha2::Sha256::digest(&stage1);
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(&stage2);
+    hasher.update(salt);
+    let mask = hasher.finalize();
+    stage1.iter().zip(mask.iter()).map(|(l, r)| l ^ r).collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_manager_quota() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant = "tenant4".to_string();
+
+    let user = "test-user4";
+    let user_mgr = UserManager::create_global(config).await?;
+    user_mgr.add_user(User::new(user, "", AuthType::None).into())?;
+    user_mgr.add_role(RoleInfo::new("analyst".to_string()))?;
+    user_mgr.grant_user_role(user, "analyst")?;
+
+    // No quotas.
+    user_mgr.check_quota(user)?;
+
+    let mut user_quota = UserQuota::new(3600);
+    user_quota.max_queries = 2;
+    user_mgr.set_user_quota(user, Some(user_quota))?;
+
+    let mut role_quota = UserQuota::new(0);
+    role_quota.max_read_rows = 100;
+    user_mgr.set_role_quota("analyst", Some(role_quota))?;
+    assert_eq!(2, user_mgr.get_user_quotas(user)?.len());
+
+    let usage = QuotaUsage {
+        queries: 1,
+        read_rows: 10,
+        ..Default::default()
+    };
+
+    user_mgr.record_quota_usage(user, &usage)?;
+    user_mgr.check_quota(user)?;
+
+    // The quota of the user is exceeded.
+    user_mgr.record_quota_usage(user, &usage)?;
+    match user_mgr.check_quota(user) {
+        Err(cause) => assert_eq!(ErrorCode::QuotaExceeded("").code(), cause.code()),
+        Ok(_) => panic!("the quota of the user must be exceeded"),
+    }
+
+    // The quota of the role is exceeded.
+    user_mgr.set_user_quota(user, None)?;
+    user_mgr.check_quota(user)?;
+    user_mgr.record_quota_usage(user, &QuotaUsage {
+        read_rows: 100,
+        ..Default::default()
+    })?;
+    assert!(user_mgr.check_quota(user).is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_manager_resource_groups() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant = "tenant5".to_string();
+
+    let user_mgr = UserManager::create_global(config).await?;
+    user_mgr.add_resource_group(ResourceGroupInfo::new("etl".to_string(), 1, 10))?;
+    user_mgr.add_resource_group(ResourceGroupInfo::new("dashboard".to_string(), 8, 0))?;
+    assert!(user_mgr
+        .add_resource_group(ResourceGroupInfo::new("etl".to_string(), 2, 0))
+        .is_err());
+
+    assert_eq!(1, user_mgr.get_resource_group("etl")?.max_concurrency);
+    assert_eq!(2, user_mgr.get_resource_groups()?.len());
+
+    user_mgr.drop_resource_group("etl")?;
+    assert!(user_mgr.get_resource_group("etl").is_err());
+    assert!(user_mgr.drop_resource_group("etl").is_err());
+
+    // The resource group of the user itself goes before the one of its roles.
+    let user = "test-user5";
+    user_mgr.add_user(User::new(user, "", AuthType::None).into())?;
+    user_mgr.add_role(RoleInfo::new("analyst".to_string()))?;
+    user_mgr.grant_user_role(user, "analyst")?;
+    assert_eq!(None, user_mgr.get_user_resource_group(user)?);
+
+    user_mgr.set_role_resource_group("analyst", Some("dashboard".to_string()))?;
+    assert_eq!(
+        Some("dashboard".to_string()),
+        user_mgr.get_user_resource_group(user)?
+    );
+
+    user_mgr.set_user_resource_group(user, Some("etl".to_string()))?;
+    assert_eq!(
+        Some("etl".to_string()),
+        user_mgr.get_user_resource_group(user)?
+    );
+
+    user_mgr.set_user_resource_group(user, None)?;
+    user_mgr.set_role_resource_group("analyst", None)?;
+    assert_eq!(None, user_mgr.get_user_resource_group(user)?);
+    Ok(())
+}
@@ -0,0 +1,5 @@
+SELECT 'query_log_0002', number FROM numbers(3) ORDER BY number;
+SELECT * FROM query_log_0002_unknown; -- {ErrorCode 25}
+
+SELECT query_kind, read_rows, result_rows, error_code FROM system.query_log WHERE query_text LIKE '%query_log_0002%number%';
+SELECT query_kind, result_rows, error_code FROM system.query_log WHERE query_text LIKE '%query_log_0002_unknown%';
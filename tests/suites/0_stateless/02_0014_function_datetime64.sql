@@ -0,0 +1,20 @@
+select toTypeName(toDateTime64(1633017600, 6)) = 'DateTime64(6)';
+select toDateTime64(1633017600, 3) = toDateTime64('2021-09-30 16:00:00', 3);
+select toDateTime64('2021-09-30 16:00:00.123456', 6) > toDateTime64('2021-09-30 16:00:00.123', 3);
+select toInt64(toDateTime64('2021-09-30 16:00:00.123456', 6));
+
+select '===timezone===';
+select toYYYYMMDD(toDateTime(1633017600));
+select toYYYYMMDD(toDateTime(1633017600), 'Asia/Shanghai');
+select toYYYYMMDD(toDateTime64(1633017600, 3, 'Asia/Shanghai'));
+select toStartOfDay(toDateTime64(1633026600, 3, 'Asia/Shanghai')) = toDateTime64(1633017600, 3, 'Asia/Shanghai');
+
+select '===session timezone===';
+set timezone='Asia/Shanghai';
+select toYYYYMMDD(toDateTime(1633017600));
+select toYYYYMMDD(toDateTime64(1633017600, 3, 'UTC'));
+set timezone='UTC';
+
+select '===interval===';
+select addMonths(toDateTime64('2021-01-31 23:30:00.123456', 6, 'Asia/Shanghai'), 1) = toDateTime64('2021-02-28 23:30:00.123456', 6, 'Asia/Shanghai');
+select subtractMinutes(toDateTime64(1633017600, 3), 1) = toDateTime64(1633017540, 3);
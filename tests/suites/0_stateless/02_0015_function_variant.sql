@@ -0,0 +1,16 @@
+select parse_json('{"a": {"b": [1, "x"]}}');
+select toTypeName(parse_json('1'));
+select try_parse_json('{');
+select get(parse_json('{"a": {"b": [1, "x"]}}'), 'a.b[1]');
+select json_extract_path_text('{"a": {"b": [1, "x"]}}', 'a.b[1]');
+select json_extract_path_text('{"a": 1}', 'c');
+select toInt64(get(parse_json('{"a": 12}'), 'a')) + 1;
+select CAST(CAST(1.5 AS Decimal(10, 2)) AS Variant);
+
+select '===column===';
+CREATE TABLE t(id Int32, v Variant) Engine = Memory;
+INSERT INTO t VALUES (1, '{"name": "a", "tags": ["x", "y"]}'), (2, '{"name": "b", "tags": []}');
+select id, v:name, v:tags[0] from t order by id;
+select id from t where v:name = 'b';
+select id, json_extract_path_text(v, concat('tags[', toString(id - 1), ']')) from t order by id;
+DROP TABLE t;
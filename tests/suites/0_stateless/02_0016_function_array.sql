@@ -0,0 +1,17 @@
+select [1, 2, 3], [1, 2, 3][2], [1, 2][-1], [1, 2][3];
+select array('a', 'b'), length([1, 2, 3]), length('abc');
+select has([1, 2, 3], 2), has([1, 2, 3], 5);
+select arrayMap(x -> x * 2, [1, 2, 3]), arrayFilter(x -> x > 1, [1, 2, 3]);
+select arrayMap((x, y) -> x + y, [1, 2], [10, 20]);
+select arrayJoin([1, 2, 3]) as a, 'x' order by a;
+select map('a', 1, 'b', 2), map('a', 1, 'b', 2)['b'], map('a', 1)['c'];
+select mapKeys(map('a', 1, 'b', 2)), mapValues(map('a', 1, 'b', 2));
+select groupArray(number) from numbers(3);
+
+select '===column===';
+CREATE TABLE t(id Int32, tags Array(String), attrs Map(String, Int64)) Engine = Memory;
+INSERT INTO t VALUES (1, '["x", "y"]', '{"a": 1}'), (2, '[]', '{"b": 2}');
+select id, tags, tags[1], length(tags), attrs['a'] from t order by id;
+select id, unnest(tags) from t order by id;
+select id from t where has(tags, 'y');
+DROP TABLE t;
@@ -0,0 +1,15 @@
+select lower('AbC'), upper('AbC'), lowerUTF8('ÄB'), upperUTF8('äb');
+select length('ü'), lengthUTF8('ü'), char_length('abc');
+select concat('a', 'b', 'c'), concat('a', NULL), concat_ws('-', 'a', NULL, 'b');
+select trimBoth('  a  '), ltrim('  a'), rtrim('a  ');
+select replace('abcabc', 'bc', '-'), position('abcabc', 'c'), positionUTF8('äbc', 'c');
+select split('a,b,,c', ','), split('ab', '');
+select lpad('7', 3, '0'), rpad('ab', 5, 'xy'), lpad('abc', 2), rpadUTF8('ä', 3, '.');
+select reverse('abc'), reverseUTF8('äbc'), repeat('ab', 3), repeat('ab', 0);
+select lpad('a', 2000000000, 'xy'); -- {ErrorCode 6}
+select lpad('a', -1), rpadUTF8('a', -1, 'x');
+select startsWith('abc', 'ab'), endsWith('abc', 'ab');
+select base64Encode('abc'), base64Decode('YWJj'), base64Decode('!');
+select hex('abc'), unhex('616263');
+select format('{} + {} = {}', 1, 2, 3), format('{1} {0}', 'a', 'b');
+select upper(concat('x', toString(number))) from numbers(3) order by number;
@@ -0,0 +1,10 @@
+select regexp_like('abc', '^a'), regexp_like('abc', 'x'), regexp_like(NULL, 'a');
+select 'abc' REGEXP 'b.$', 'abc' RLIKE '^b', 'abc' NOT REGEXP '^b';
+select regexp_extract('k1=v1, k2=v2', 'k[0-9]=([a-z0-9]+)'), regexp_extract('k1=v1', '([a-z]+)([0-9])', 2), regexp_extract('abc', 'x');
+select regexp_replace('k1=v1, k2=v2', '([a-z0-9]+)=([a-z0-9]+)', '$2:$1'), regexp_replace('a.b.c', '[.]', '');
+select regexp_count('a1b22c333', '[0-9]+'), regexp_count('abc', '');
+select extractAll('k1=v1, k2=v2', '([a-z0-9]+)='), extractAll('a1b22', '[0-9]+');
+select number from numbers(20) where toString(number) regexp '^1[0-3]$' order by number;
+select regexp_like(toString(number), concat('^', toString(number % 3))) from numbers(4) order by number;
+select rlike regexp '^a', rlike not rlike 'c$' from (select 'abc' as rlike);
+select regexp_like('abc', '(a'); -- {ErrorCode 6}
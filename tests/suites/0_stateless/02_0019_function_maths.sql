@@ -0,0 +1,12 @@
+select abs(-3), abs(toInt8(-128)), abs(-2.5), sign(-7), sign(0), sign(0.5);
+select toTypeName(abs(toInt8(-1))), toTypeName(round(toInt32(1))), toTypeName(sqrt(4)), toTypeName(intDiv(toInt32(7), 2));
+select round(2.5), round(-2.5), round(1.2345, 2), round(1250, -2), floor(-1.5), ceil(1.01), ceiling(-1.5), truncate(-1.99), floor(1234, -2);
+select sqrt(16), cbrt(27), pow(2, 10), power(3, 2), exp(0), ln(1), log2(8), log10(1000), exp2(3);
+select sin(0), cos(0), atan2(0, 1), round(degrees(pi()), 6), round(e(), 5);
+select greatest(1, 3, 2), least(1, 3, 2), greatest('a', 'c', 'b'), greatest(1, NULL), least(number, 1) from numbers(3) order by number;
+select intDiv(7, 2), intDiv(-7, 2), intDivOrZero(7, 0), intDiv(7.9, 2);
+select intDiv(1, 0); -- {ErrorCode 6}
+select bitAnd(12, 10), bitOr(12, 10), bitXor(12, 10), bitNot(toUInt8(0)), bitShiftLeft(toUInt8(1), 7), bitShiftRight(-8, 1), bitCount(255);
+select toTypeName(rand()), toTypeName(randConstant(1));
+select count(distinct rand()) > 1 from numbers(100);
+select sqrt('a'); -- {ErrorCode 10}
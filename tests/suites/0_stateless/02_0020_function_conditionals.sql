@@ -0,0 +1,9 @@
+select case when number = 0 then 'zero' when number = 1 then 'one' else 'many' end from numbers(3) order by number;
+select case number when 0 then 10 when 2 then 20 end from numbers(3) order by number;
+select multiIf(number > 1, 'big', number > 0, 'small', 'none') from numbers(3) order by number;
+select coalesce(NULL, 1, 2), coalesce(NULL, NULL, 'x'), ifNull(NULL, 3), ifNull(4, 3), nullif(1, 1), nullif(1, 2);
+select number from numbers(10) where number in (1, 3, 5) order by number;
+select number from numbers(10) where number not in (0, 1, 2, 3, 4, 5, 6, 7) order by number;
+select 1 in (1, NULL), 2 in (1, NULL), NULL in (1), 2 not in (1, 3);
+select 'b' in ('a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i');
+select multiIf(number > 1, 1) from numbers(3); -- {ErrorCode 28}
@@ -0,0 +1,4 @@
+select number from numbers(10) where number in (select number * 2 from numbers(3)) order by number;
+select count() from numbers(10) where number not in (select number from numbers(5));
+select number, number in (select number from numbers(2)) from numbers(3) order by number;
+select 1 in (select number, number from numbers(2)); -- {ErrorCode 6}
@@ -0,0 +1,9 @@
+select approx_count_distinct(number) between 9500 and 10500 from numbers(10000);
+select approx_count_distinct(number % 7, number % 3) between 19 and 23 from numbers(1000);
+select approx_count_distinct(number) from numbers(0);
+select median(number), quantile(0.9)(number), quantiles(0.25, 0.75)(number) from numbers(11);
+select var_pop(number), var_samp(number), stddev_samp(number) from numbers(5);
+select covar_pop(number, number * 2), covar_samp(number, number * 2), corr(number, number * 2) from numbers(5);
+select number % 3 as k, any(number % 3), anyLast(number % 3) from numbers(9) group by k order by k;
+select groupUniqArray(number % 3), topK(2)(number % 4) from numbers(10);
+select quantile(2)(number) from numbers(1); -- {ErrorCode 6}
@@ -0,0 +1,13 @@
+SELECT '==ROLLUP==';
+SELECT number % 2 AS a, number % 3 AS b, grouping(a, b) AS g, count(*) FROM numbers(10) GROUP BY ROLLUP(a, b) ORDER BY g, a, b;
+SELECT '==CUBE==';
+SELECT number % 2 AS a, number % 3 AS b, count(*) FROM numbers(10) GROUP BY CUBE(a, b) ORDER BY grouping(a, b), a, b;
+SELECT '==GROUPING SETS==';
+SELECT number % 2 AS a, number % 3 AS b, sum(number) FROM numbers(10) GROUP BY GROUPING SETS ((a), (b), ()) ORDER BY grouping(a), grouping(b), a, b;
+SELECT '==MIXED==';
+SELECT number % 2 AS a, number % 3 AS b, count(*) FROM numbers(10) GROUP BY a, ROLLUP(b) ORDER BY grouping(b), a, b;
+SELECT '==GROUPING==';
+SELECT number % 2 AS a, grouping(a) FROM numbers(4) GROUP BY a ORDER BY a;
+SELECT number % 2 AS a, sum(number) FROM numbers(4) GROUP BY ROLLUP(a) HAVING grouping(a) = 1;
+SELECT grouping(number) FROM numbers(3); -- {ErrorCode 6}
+SELECT number % 2 AS a, grouping(number) FROM numbers(3) GROUP BY ROLLUP(a); -- {ErrorCode 6}
@@ -0,0 +1,4 @@
+SET max_execution_time = 1;
+SELECT sum(number) FROM numbers_mt(100000000000); -- {ErrorCode 40}
+SET max_execution_time = 0;
+SELECT sum(number) FROM numbers_mt(1000);
@@ -0,0 +1,13 @@
+SET max_block_size = 100;
+SELECT value, default_value, level FROM system.settings WHERE name = 'max_block_size';
+SELECT value, level FROM system.settings WHERE name = 'max_block_size' SETTINGS (max_block_size = 10);
+SELECT value, level FROM system.settings WHERE name = 'max_block_size';
+SET max_block_size = DEFAULT;
+SELECT value, level FROM system.settings WHERE name = 'max_block_size';
+SET copy_file_format = 'csv';
+SELECT value, type FROM system.settings WHERE name = 'copy_file_format';
+SET copy_file_format = 'json'; -- {ErrorCode 6}
+SET copy_file_format = DEFAULT;
+SET enable_query_log = 2; -- {ErrorCode 6}
+SELECT value, type FROM system.settings WHERE name = 'enable_query_log';
+SET no_such_setting = 1; -- {ErrorCode 20}
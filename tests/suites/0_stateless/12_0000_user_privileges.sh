@@ -0,0 +1,58 @@
+#!/usr/bin/env bash
+
+# The statements run by the initial admin user default, and the access checks run by test_user.
+ADMIN_CLIENT="$QUERY_MYSQL_CLIENT_CONNECT"
+USER_CLIENT="${QUERY_MYSQL_CLIENT_CONNECT/-udefault/-utest_user}"
+
+$ADMIN_CLIENT <<'SQL'
+DROP USER IF EXISTS test_user;
+DROP ROLE IF EXISTS test_role;
+
+CREATE USER test_user IDENTIFIED BY 'password';
+CREATE USER test_user; -- {ErrorCode 3001}
+CREATE USER IF NOT EXISTS test_user;
+ALTER USER test_user IDENTIFIED WITH plaintext_password BY 'new_password';
+
+CREATE ROLE test_role;
+CREATE ROLE test_role; -- {ErrorCode 3004}
+GRANT SELECT, INSERT ON default.* TO ROLE test_role;
+GRANT ROLE test_role TO test_user;
+GRANT ALL ON *.* TO test_user;
+GRANT ROLE unknown_role TO test_user; -- {ErrorCode 3003}
+
+REVOKE ALL ON *.* FROM test_user;
+REVOKE ROLE test_role FROM test_user;
+REVOKE INSERT ON default.* FROM ROLE test_role;
+
+DROP ROLE test_role;
+DROP ROLE test_role; -- {ErrorCode 3003}
+DROP USER test_user;
+DROP USER test_user; -- {ErrorCode 3000}
+
+DROP DATABASE IF EXISTS db12;
+CREATE DATABASE db12;
+CREATE TABLE db12.t1(a Int32) Engine = Memory;
+CREATE TABLE db12.t2(a Int32) Engine = Memory;
+INSERT INTO db12.t1 VALUES(1);
+CREATE USER test_user IDENTIFIED WITH double_sha1_password BY 'password';
+GRANT SELECT ON db12.t1 TO test_user;
+SQL
+
+export MYSQL_PWD=password
+echo "SELECT * FROM db12.t1;" | $USER_CLIENT
+echo "SELECT * FROM db12.t2;" | $USER_CLIENT
+echo "INSERT INTO db12.t1 VALUES(2);" | $USER_CLIENT
+echo "DROP TABLE db12.t1;" | $USER_CLIENT
+echo "COPY INTO 'file:///tmp/db12/' FROM (SELECT * FROM db12.t1);" | $USER_CLIENT
+echo "SELECT count(*) FROM system.one;" | $USER_CLIENT
+echo "SELECT count(*) FROM system.query_log;" | $USER_CLIENT
+echo "SELECT count(*) FROM system.processes;" | $USER_CLIENT
+echo "CREATE USER test_user2;" | $USER_CLIENT
+echo "GRANT ALL ON *.* TO test_user;" | $USER_CLIENT
+unset MYSQL_PWD
+
+$ADMIN_CLIENT <<'SQL'
+SELECT DISTINCT query_text FROM system.query_log WHERE query_text LIKE '%IDENTIFIED%' ORDER BY query_text;
+DROP USER test_user;
+DROP DATABASE db12;
+SQL
@@ -0,0 +1,30 @@
+DROP USER IF EXISTS test_quota_user;
+DROP ROLE IF EXISTS test_quota_role;
+DROP RESOURCE GROUP IF EXISTS test_group;
+
+CREATE USER test_quota_user;
+CREATE ROLE test_quota_role;
+CREATE QUOTA FOR USER test_quota_user WITH interval = 3600, max_queries = 100;
+CREATE QUOTA FOR ROLE test_quota_role WITH max_read_rows = 1000, max_execution_time = 60;
+CREATE QUOTA FOR USER unknown_user WITH max_queries = 1; -- {ErrorCode 3000}
+SELECT name, type, interval_secs, max_queries, max_read_rows, max_execution_time_ms FROM system.quotas ORDER BY name;
+DROP QUOTA FOR USER test_quota_user;
+DROP QUOTA FOR ROLE test_quota_role;
+SELECT count(*) FROM system.quotas;
+
+CREATE RESOURCE GROUP test_group WITH max_concurrency = 2, max_queued = 10;
+CREATE RESOURCE GROUP test_group WITH max_concurrency = 1; -- {ErrorCode 3007}
+CREATE RESOURCE GROUP IF NOT EXISTS test_group WITH max_concurrency = 1;
+SELECT name, max_concurrency, max_queued FROM system.resource_groups;
+GRANT RESOURCE GROUP test_group TO USER test_quota_user;
+GRANT RESOURCE GROUP test_group TO ROLE test_quota_role;
+GRANT RESOURCE GROUP unknown_group TO USER test_quota_user; -- {ErrorCode 3006}
+GRANT RESOURCE GROUP test_group TO USER unknown_user; -- {ErrorCode 3000}
+REVOKE RESOURCE GROUP FROM USER test_quota_user;
+REVOKE RESOURCE GROUP FROM ROLE test_quota_role;
+SET resource_group = 'test_group'; -- {ErrorCode 20}
+
+DROP RESOURCE GROUP test_group;
+DROP RESOURCE GROUP test_group; -- {ErrorCode 3006}
+DROP ROLE test_quota_role;
+DROP USER test_quota_user;